@@ -0,0 +1,369 @@
+//! SQL over stored run results, answered by the DataFusion engine the benchmarks exercise.
+//!
+//! [`ResultsIndex::load`] indexes every `<results_dir>/<label>/<suite>.json` a run wrote, and
+//! [`query`] registers the index as three tables joined on `(label, suite)`:
+//!
+//! - `runs`: one row per result file, with the run context.
+//! - `cases`: one row per case, with its status and elapsed statistics.
+//! - `samples`: one row per measured iteration, with the sample metrics.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use deltalake_core::arrow::array::{
+    ArrayRef, BooleanArray, Float64Array, StringArray, TimestampMicrosecondArray, UInt64Array,
+};
+use deltalake_core::arrow::datatypes::{Field, Schema, SchemaRef};
+use deltalake_core::arrow::record_batch::RecordBatch;
+use deltalake_core::arrow::util::pretty::pretty_format_batches;
+use deltalake_core::datafusion::prelude::SessionContext;
+use deltalake_core::parquet::arrow::ArrowWriter;
+
+use crate::error::{BenchError, BenchResult};
+use crate::results::{BenchRunResult, ElapsedStats, PerfStatus, SampleMetrics};
+
+/// Run results found under a results directory, ordered by label then suite.
+#[derive(Clone, Debug, Default)]
+pub struct ResultsIndex {
+    pub entries: Vec<IndexedResult>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IndexedResult {
+    /// Name of the run directory, which is the run label.
+    pub label: String,
+    pub path: PathBuf,
+    pub result: BenchRunResult,
+}
+
+impl ResultsIndex {
+    /// Reads every `<label>/<suite>.json` under `results_dir`. Sidecars such as
+    /// `<suite>.triage.json` are skipped; a suite result that does not parse is an error.
+    pub fn load(results_dir: &Path) -> BenchResult<Self> {
+        if !results_dir.is_dir() {
+            return Err(BenchError::InvalidArgument(format!(
+                "results directory '{}' does not exist",
+                results_dir.display()
+            )));
+        }
+        let mut entries = Vec::new();
+        for run_dir in sorted_dir_entries(results_dir)? {
+            if !run_dir.is_dir() {
+                continue;
+            }
+            let Some(label) = run_dir.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            for path in sorted_dir_entries(&run_dir)? {
+                let is_suite_result = path.extension().is_some_and(|ext| ext == "json")
+                    && path
+                        .file_stem()
+                        .and_then(|stem| stem.to_str())
+                        .is_some_and(|stem| !stem.contains('.'));
+                if !is_suite_result || !path.is_file() {
+                    continue;
+                }
+                let result = serde_json::from_slice::<BenchRunResult>(&fs::read(&path)?).map_err(
+                    |error| {
+                        BenchError::InvalidArgument(format!(
+                            "failed to parse run result '{}': {error}",
+                            path.display()
+                        ))
+                    },
+                )?;
+                entries.push(IndexedResult {
+                    label: label.to_string(),
+                    path,
+                    result,
+                });
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn runs_batch(&self) -> BenchResult<RecordBatch> {
+        let runs = self
+            .entries
+            .iter()
+            .map(|entry| (entry, &entry.result.context))
+            .collect::<Vec<_>>();
+        let text = |field: fn(&crate::results::BenchContext) -> Option<&str>| {
+            strings(runs.iter().map(|(_, context)| field(context)))
+        };
+        record_batch(vec![
+            (
+                "label",
+                strings(runs.iter().map(|(e, _)| Some(e.label.as_str()))),
+            ),
+            ("suite", text(|c| Some(c.suite.as_str()))),
+            ("path", strings(runs.iter().map(|(e, _)| e.path.to_str()))),
+            ("run_id", text(|c| c.run_id.as_deref())),
+            (
+                "created_at",
+                timestamps(runs.iter().map(|(_, c)| c.created_at.timestamp_micros())),
+            ),
+            ("git_sha", text(|c| c.git_sha.as_deref())),
+            ("git_dirty", bools(runs.iter().map(|(_, c)| c.git_dirty))),
+            ("harness_revision", text(|c| c.harness_revision.as_deref())),
+            ("host", text(|c| Some(c.host.as_str()))),
+            ("scale", text(|c| Some(c.scale.as_str()))),
+            ("dataset_id", text(|c| c.dataset_id.as_deref())),
+            ("runner", text(|c| c.runner.as_deref())),
+            ("storage_backend", text(|c| c.storage_backend.as_deref())),
+            ("backend_profile", text(|c| c.backend_profile.as_deref())),
+            ("benchmark_mode", text(|c| c.benchmark_mode.as_deref())),
+            ("lane", text(|c| c.lane.as_deref())),
+            ("timing_phase", text(|c| c.timing_phase.as_deref())),
+            (
+                "iterations",
+                u64s(runs.iter().map(|(_, c)| Some(u64::from(c.iterations)))),
+            ),
+            (
+                "warmup",
+                u64s(runs.iter().map(|(_, c)| Some(u64::from(c.warmup)))),
+            ),
+        ])
+    }
+
+    pub fn cases_batch(&self) -> BenchResult<RecordBatch> {
+        let cases = self
+            .entries
+            .iter()
+            .flat_map(|entry| entry.result.cases.iter().map(move |case| (entry, case)))
+            .collect::<Vec<_>>();
+        let stat = |field: fn(&ElapsedStats) -> Option<f64>| {
+            f64s(
+                cases
+                    .iter()
+                    .map(|(_, case)| case.elapsed_stats.as_ref().and_then(field)),
+            )
+        };
+        record_batch(vec![
+            (
+                "label",
+                strings(cases.iter().map(|(e, _)| Some(e.label.as_str()))),
+            ),
+            (
+                "suite",
+                strings(
+                    cases
+                        .iter()
+                        .map(|(e, _)| Some(e.result.context.suite.as_str())),
+                ),
+            ),
+            (
+                "case",
+                strings(cases.iter().map(|(_, c)| Some(c.case.as_str()))),
+            ),
+            ("success", bools(cases.iter().map(|(_, c)| Some(c.success)))),
+            (
+                "validation_passed",
+                bools(cases.iter().map(|(_, c)| Some(c.validation_passed))),
+            ),
+            (
+                "perf_status",
+                strings(
+                    cases
+                        .iter()
+                        .map(|(_, c)| Some(perf_status_str(&c.perf_status))),
+                ),
+            ),
+            (
+                "classification",
+                strings(cases.iter().map(|(_, c)| Some(c.classification.as_str()))),
+            ),
+            (
+                "failure_kind",
+                strings(cases.iter().map(|(_, c)| c.failure_kind.as_deref())),
+            ),
+            (
+                "failure_message",
+                strings(
+                    cases
+                        .iter()
+                        .map(|(_, c)| c.failure.as_ref().map(|f| f.message.as_str())),
+                ),
+            ),
+            (
+                "sample_count",
+                u64s(cases.iter().map(|(_, c)| Some(c.samples.len() as u64))),
+            ),
+            ("min_ms", stat(|s| Some(s.min_ms))),
+            ("max_ms", stat(|s| Some(s.max_ms))),
+            ("mean_ms", stat(|s| Some(s.mean_ms))),
+            ("median_ms", stat(|s| Some(s.median_ms))),
+            ("stddev_ms", stat(|s| Some(s.stddev_ms))),
+            ("cv_pct", stat(|s| s.cv_pct)),
+            (
+                "supports_decision",
+                bools(cases.iter().map(|(_, c)| c.supports_decision)),
+            ),
+            ("seed", u64s(cases.iter().map(|(_, c)| c.seed))),
+        ])
+    }
+
+    pub fn samples_batch(&self) -> BenchResult<RecordBatch> {
+        let samples = self
+            .entries
+            .iter()
+            .flat_map(|entry| {
+                entry.result.cases.iter().flat_map(move |case| {
+                    case.samples
+                        .iter()
+                        .enumerate()
+                        .map(move |(idx, sample)| (entry, case, idx, sample))
+                })
+            })
+            .collect::<Vec<_>>();
+        let metric = |field: fn(&SampleMetrics) -> Option<u64>| {
+            u64s(
+                samples
+                    .iter()
+                    .map(|(.., sample)| sample.metrics.as_ref().and_then(field)),
+            )
+        };
+        record_batch(vec![
+            (
+                "label",
+                strings(samples.iter().map(|(e, ..)| Some(e.label.as_str()))),
+            ),
+            (
+                "suite",
+                strings(
+                    samples
+                        .iter()
+                        .map(|(e, ..)| Some(e.result.context.suite.as_str())),
+                ),
+            ),
+            (
+                "case",
+                strings(samples.iter().map(|(_, c, ..)| Some(c.case.as_str()))),
+            ),
+            (
+                "sample_idx",
+                u64s(samples.iter().map(|(_, _, idx, _)| Some(*idx as u64))),
+            ),
+            (
+                "elapsed_ms",
+                f64s(samples.iter().map(|(.., s)| Some(s.elapsed_ms))),
+            ),
+            ("rows", u64s(samples.iter().map(|(.., s)| s.rows))),
+            ("bytes", u64s(samples.iter().map(|(.., s)| s.bytes))),
+            ("rows_processed", metric(|m| m.rows_processed)),
+            ("bytes_processed", metric(|m| m.bytes_processed)),
+            ("operations", metric(|m| m.operations)),
+            ("table_version", metric(|m| m.table_version)),
+            ("files_scanned", metric(|m| m.files_scanned)),
+            ("files_pruned", metric(|m| m.files_pruned)),
+            ("bytes_scanned", metric(|m| m.bytes_scanned)),
+            ("scan_time_ms", metric(|m| m.scan_time_ms)),
+            ("rewrite_time_ms", metric(|m| m.rewrite_time_ms)),
+            ("peak_rss_mb", metric(|m| m.peak_rss_mb)),
+            ("cpu_time_ms", metric(|m| m.cpu_time_ms)),
+        ])
+    }
+
+    /// A session with `runs`, `cases`, and `samples` registered.
+    pub fn session_context(&self) -> BenchResult<SessionContext> {
+        let ctx = SessionContext::new();
+        ctx.register_batch("runs", self.runs_batch()?)?;
+        ctx.register_batch("cases", self.cases_batch()?)?;
+        ctx.register_batch("samples", self.samples_batch()?)?;
+        Ok(ctx)
+    }
+}
+
+/// Rows a [`query`] returned, with the schema kept for results that have no batches.
+#[derive(Clone, Debug)]
+pub struct QueryResult {
+    pub schema: SchemaRef,
+    pub batches: Vec<RecordBatch>,
+}
+
+impl QueryResult {
+    pub fn num_rows(&self) -> usize {
+        self.batches.iter().map(RecordBatch::num_rows).sum()
+    }
+
+    pub fn to_table_string(&self) -> BenchResult<String> {
+        Ok(pretty_format_batches(&self.batches)?.to_string())
+    }
+
+    pub fn write_parquet(&self, path: &Path) -> BenchResult<()> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let mut writer = ArrowWriter::try_new(fs::File::create(path)?, self.schema.clone(), None)
+            .map_err(|error| BenchError::Delta(error.into()))?;
+        for batch in &self.batches {
+            writer
+                .write(batch)
+                .map_err(|error| BenchError::Delta(error.into()))?;
+        }
+        writer
+            .close()
+            .map_err(|error| BenchError::Delta(error.into()))?;
+        Ok(())
+    }
+}
+
+/// Runs `sql` over the results indexed under `results_dir`.
+pub async fn query(results_dir: &Path, sql: &str) -> BenchResult<QueryResult> {
+    let ctx = ResultsIndex::load(results_dir)?.session_context()?;
+    let df = ctx.sql(sql).await?;
+    let schema = df.schema().inner().clone();
+    Ok(QueryResult {
+        schema,
+        batches: df.collect().await?,
+    })
+}
+
+fn sorted_dir_entries(dir: &Path) -> BenchResult<Vec<PathBuf>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+fn strings<'a>(values: impl Iterator<Item = Option<&'a str>>) -> ArrayRef {
+    Arc::new(StringArray::from(values.collect::<Vec<_>>()))
+}
+
+fn timestamps(micros: impl Iterator<Item = i64>) -> ArrayRef {
+    Arc::new(TimestampMicrosecondArray::from(micros.collect::<Vec<_>>()).with_timezone("UTC"))
+}
+
+fn bools(values: impl Iterator<Item = Option<bool>>) -> ArrayRef {
+    Arc::new(BooleanArray::from(values.collect::<Vec<_>>()))
+}
+
+fn u64s(values: impl Iterator<Item = Option<u64>>) -> ArrayRef {
+    Arc::new(UInt64Array::from(values.collect::<Vec<_>>()))
+}
+
+fn f64s(values: impl Iterator<Item = Option<f64>>) -> ArrayRef {
+    Arc::new(Float64Array::from(values.collect::<Vec<_>>()))
+}
+
+/// Every column is nullable, so a metric that no sample recorded is still queryable.
+fn record_batch(columns: Vec<(&str, ArrayRef)>) -> BenchResult<RecordBatch> {
+    let fields = columns
+        .iter()
+        .map(|(name, array)| Field::new(*name, array.data_type().clone(), true))
+        .collect::<Vec<_>>();
+    let arrays = columns.into_iter().map(|(_, array)| array).collect();
+    Ok(RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)?)
+}
+
+fn perf_status_str(status: &PerfStatus) -> &'static str {
+    match status {
+        PerfStatus::Trusted => "trusted",
+        PerfStatus::ValidationOnly => "validation_only",
+        PerfStatus::Invalid => "invalid",
+    }
+}
@@ -1,11 +1,43 @@
-use crate::results::{CaseFailure, CaseResult, PerfStatus, FAILURE_KIND_ASSERTION_MISMATCH};
+use crate::results::{
+    CaseFailure, CaseResult, PerfStatus, SampleMetrics, FAILURE_KIND_ASSERTION_MISMATCH,
+};
+use crate::stats::nearest_rank;
+use crate::validation::validation_summary_rows;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum CaseAssertion {
     ExactResultHash(String),
     SchemaHash(String),
     ExpectedErrorContains(String),
     VersionMonotonicity,
+    /// Absolute latency budget on the median of the case's measured samples.
+    MaxMedianMs(f64),
+    /// Absolute latency budget on the nearest-rank p95 of the case's measured samples.
+    MaxP95Ms(f64),
+    /// Every sample must report at least this many pruned files.
+    MinFilesPruned(u64),
+    /// Every sample must report at most this many scanned files.
+    MaxFilesScanned(u64),
+    /// Every sample must report at most this many scanned bytes.
+    MaxBytesScanned(u64),
+    /// Every sample must leave exactly this many rows: the row count of the table reopened
+    /// after the operation where the case verifies table state, its processed rows otherwise.
+    RowCountEquals(u64),
+}
+
+impl CaseAssertion {
+    /// Latency budgets judge timings, so they only apply where timings are measured.
+    pub fn is_latency_budget(&self) -> bool {
+        matches!(self, Self::MaxMedianMs(_) | Self::MaxP95Ms(_))
+    }
+
+    /// Bounds on scan and prune counters, which depend on the fixture layout of the scale.
+    pub fn is_metric_bound(&self) -> bool {
+        matches!(
+            self,
+            Self::MinFilesPruned(_) | Self::MaxFilesScanned(_) | Self::MaxBytesScanned(_)
+        )
+    }
 }
 
 pub fn apply_case_assertions(case: &mut CaseResult, assertions: &[CaseAssertion]) {
@@ -17,6 +49,50 @@ pub fn apply_case_assertions(case: &mut CaseResult, assertions: &[CaseAssertion]
                 assert_expected_error_contains(case, needle)
             }
             CaseAssertion::VersionMonotonicity => assert_version_monotonicity(case),
+            CaseAssertion::MaxMedianMs(budget) => {
+                let median = case.elapsed_stats.as_ref().map(|stats| stats.median_ms);
+                assert_latency_budget(case, "max_median_ms", "median", median, *budget)
+            }
+            CaseAssertion::MaxP95Ms(budget) => {
+                let p95 = p95_ms(case);
+                assert_latency_budget(case, "max_p95_ms", "p95", p95, *budget)
+            }
+            CaseAssertion::MinFilesPruned(min) => {
+                assert_metric_bound(case, "min_files_pruned", "files_pruned", *min, |metrics| {
+                    metrics.files_pruned
+                })
+            }
+            CaseAssertion::MaxFilesScanned(max) => assert_metric_bound(
+                case,
+                "max_files_scanned",
+                "files_scanned",
+                *max,
+                |metrics| metrics.files_scanned,
+            ),
+            CaseAssertion::MaxBytesScanned(max) => assert_metric_bound(
+                case,
+                "max_bytes_scanned",
+                "bytes_scanned",
+                *max,
+                |metrics| metrics.bytes_scanned,
+            ),
+            CaseAssertion::RowCountEquals(expected) => assert_row_count_equals(case, *expected),
+        }
+    }
+}
+
+/// Evaluates `assertions` without letting them change the case's outcome: each violation is
+/// recorded in the case's `warnings` instead of failing it.
+pub fn observe_case_assertions(case: &mut CaseResult, assertions: &[CaseAssertion]) {
+    for assertion in assertions {
+        let mut observed = case.clone();
+        apply_case_assertions(&mut observed, std::slice::from_ref(assertion));
+        let failure_message =
+            |case: &CaseResult| case.failure.as_ref().map(|failure| failure.message.clone());
+        if observed.failure_kind.as_deref() == Some(FAILURE_KIND_ASSERTION_MISMATCH)
+            && failure_message(&observed) != failure_message(case)
+        {
+            case.warnings.extend(failure_message(&observed));
         }
     }
 }
@@ -113,6 +189,111 @@ fn assert_version_monotonicity(case: &mut CaseResult) {
     }
 }
 
+fn assert_latency_budget(
+    case: &mut CaseResult,
+    assertion: &str,
+    stat: &str,
+    found: Option<f64>,
+    budget: f64,
+) {
+    if !case.validation_passed {
+        return;
+    }
+    let Some(found) = found else {
+        return;
+    };
+    if found > budget {
+        fail_case(
+            case,
+            format!(
+                "{assertion} assertion failed: {stat} {found:.3} ms exceeds the budget of {budget:.3} ms"
+            ),
+        );
+    }
+}
+
+/// `assertion` names its direction: a `min_` bound fails below `bound`, a `max_` bound above it.
+/// A sample that does not report `metric` fails too, since the bound cannot be checked.
+fn assert_metric_bound(
+    case: &mut CaseResult,
+    assertion: &str,
+    metric: &str,
+    bound: u64,
+    read: impl Fn(&SampleMetrics) -> Option<u64>,
+) {
+    if !case.validation_passed {
+        return;
+    }
+    let minimum = assertion.starts_with("min_");
+    for (idx, sample) in case.samples.iter().enumerate() {
+        let found = sample.metrics.as_ref().and_then(&read);
+        let within = match found {
+            Some(found) if minimum => found >= bound,
+            Some(found) => found <= bound,
+            None => false,
+        };
+        if !within {
+            fail_case(
+                case,
+                format!(
+                    "{assertion} assertion failed at sample {}: expected {metric} {} {bound}, found {}",
+                    idx + 1,
+                    if minimum { ">=" } else { "<=" },
+                    found.map_or_else(|| "none".to_string(), |found| found.to_string())
+                ),
+            );
+            return;
+        }
+    }
+}
+
+fn assert_row_count_equals(case: &mut CaseResult, expected: u64) {
+    if !case.validation_passed {
+        return;
+    }
+    for (idx, sample) in case.samples.iter().enumerate() {
+        let table_rows = sample
+            .metrics
+            .as_ref()
+            .and_then(|metrics| metrics.validation_summary.as_deref())
+            .and_then(validation_summary_rows);
+        let (found, source) = match table_rows {
+            Some(rows) => (Some(rows), "rows in the post-run table"),
+            None => (
+                sample
+                    .metrics
+                    .as_ref()
+                    .and_then(|metrics| metrics.rows_processed),
+                "rows processed",
+            ),
+        };
+        if found != Some(expected) {
+            fail_case(
+                case,
+                format!(
+                    "row_count_equals assertion failed at sample {}: expected {expected} {source}, found {}",
+                    idx + 1,
+                    found.map_or_else(|| "none".to_string(), |found| found.to_string())
+                ),
+            );
+            return;
+        }
+    }
+}
+
+fn p95_ms(case: &CaseResult) -> Option<f64> {
+    if case.samples.is_empty() {
+        return None;
+    }
+    let mut elapsed = case
+        .samples
+        .iter()
+        .map(|sample| sample.elapsed_ms)
+        .collect::<Vec<_>>();
+    elapsed.sort_by(f64::total_cmp);
+    Some(nearest_rank(&elapsed, 95))
+}
+
 fn fail_case(case: &mut CaseResult, message: String) {
     case.success = false;
     case.validation_passed = false;
@@ -3,7 +3,14 @@ use std::path::PathBuf;
 
 use clap::{Parser, Subcommand, ValueEnum};
 
+use crate::data::fixtures::{
+    DEFAULT_SMALL_FILES_COUNT, DEFAULT_SMALL_FILES_ROWS_PER_FILE, DEFAULT_WIDE_TABLE_COLUMNS,
+};
 use crate::error::{BenchError, BenchResult};
+use crate::runner::DEFAULT_RUN_SEED;
+use crate::suites::fixture_copy::FixtureCopyStrategy;
+use crate::suites::tpcds::catalog::{DEFAULT_TPCDS_PHASE, MAX_TPCDS_PHASE};
+use crate::suites::tpcds::registration::TpcdsLayout;
 
 #[derive(Debug, Parser)]
 #[command(name = "delta-bench", about = "delta-rs macro benchmark harness")]
@@ -29,6 +36,14 @@ pub struct Args {
     pub storage_options: Vec<String>,
     #[arg(long, env = "DELTA_BENCH_BACKEND_PROFILE")]
     pub backend_profile: Option<String>,
+    /// Format of the tracing output on stderr; `RUST_LOG` selects what is logged.
+    #[arg(
+        long,
+        env = "DELTA_BENCH_LOG_FORMAT",
+        value_enum,
+        default_value_t = LogFormat::Text
+    )]
+    pub log_format: LogFormat,
     #[command(subcommand)]
     pub command: Command,
 }
@@ -39,6 +54,27 @@ pub enum StorageBackend {
     S3,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+/// How `run --capture-plan` records each query case's physical plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PlanFormat {
+    Text,
+    Json,
+}
+
+/// How much of DataFusion's execution metrics a query case keeps: the curated scan metrics in
+/// each sample, or also the complete metrics tree of every measured iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PlanMetricsDetail {
+    Curated,
+    Full,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum RunnerMode {
     Rust,
@@ -122,15 +158,71 @@ pub enum Command {
         #[arg(default_value = "all")]
         target: String,
     },
+    Plan {
+        #[arg(long, default_value = "all")]
+        target: String,
+        #[arg(long)]
+        case_filter: Option<String>,
+        /// Plans only cases carrying one of these manifest tags (comma-separated or repeatable).
+        #[arg(long, value_delimiter = ',')]
+        tags: Vec<String>,
+        /// Leaves out cases carrying any of these manifest tags.
+        #[arg(long, value_delimiter = ',')]
+        exclude_tags: Vec<String>,
+        /// Defaults to the previous run's runner with `--against`, otherwise `all`.
+        #[arg(long, value_enum)]
+        runner: Option<RunnerMode>,
+        /// Previous run directory (`results/<label>`) whose case set the plan is diffed against.
+        #[arg(long)]
+        against: Option<PathBuf>,
+        /// Query directory `--target custom_sql` is planned from, as for `run`.
+        #[arg(long)]
+        custom_sql_dir: Option<PathBuf>,
+    },
+    #[command(args_conflicts_with_subcommands = true)]
     Data {
         #[arg(long, default_value = "sf1")]
         scale: String,
+        #[arg(long, conflicts_with = "scale")]
+        all_scales: bool,
         #[arg(long)]
         dataset_id: Option<String>,
         #[arg(long, default_value_t = 42)]
         seed: u64,
         #[arg(long)]
         force: bool,
+        /// Moves replaced scale directories to `.delta_bench_trash/` instead of deleting them.
+        #[arg(long, conflicts_with = "verify")]
+        trash: bool,
+        /// Also write the generated rows as `narrow_sales/rows.jsonl` for debugging.
+        #[arg(long)]
+        export_rows_jsonl: bool,
+        /// Zipf exponent for `region`; 0 keeps regions uniform.
+        #[arg(long, default_value_t = 0.0)]
+        region_skew: f64,
+        /// Zipf exponent for `id`; above 0, low ids repeat as hot keys.
+        #[arg(long, default_value_t = 0.0)]
+        id_skew: f64,
+        /// Null probability for a nullable column, as `COLUMN=PROBABILITY` (repeatable).
+        #[arg(long = "null-probability")]
+        null_probabilities: Vec<String>,
+        /// Columns in `wide_delta`, including `id` (minimum 4).
+        #[arg(long, default_value_t = DEFAULT_WIDE_TABLE_COLUMNS)]
+        wide_table_columns: usize,
+        /// Files in `small_files_delta` (`small_files` dataset).
+        #[arg(long, default_value_t = DEFAULT_SMALL_FILES_COUNT)]
+        small_files_count: usize,
+        /// Narrow-sales rows per `small_files_delta` file.
+        #[arg(long, default_value_t = DEFAULT_SMALL_FILES_ROWS_PER_FILE)]
+        small_files_rows_per_file: usize,
+        /// Checks the existing fixtures against their manifest instead of generating them.
+        #[arg(long, conflicts_with_all = ["force", "export_rows_jsonl"])]
+        verify: bool,
+        /// Waits for another bench process using the fixtures dir instead of failing.
+        #[arg(long, global = true)]
+        wait: bool,
+        #[command(subcommand)]
+        archive: Option<DataArchiveCommand>,
     },
     Run {
         #[arg(long, default_value = "sf1")]
@@ -141,6 +233,12 @@ pub enum Command {
         target: String,
         #[arg(long)]
         case_filter: Option<String>,
+        /// Runs only cases carrying one of these manifest tags (comma-separated or repeatable).
+        #[arg(long, value_delimiter = ',', conflicts_with = "replay_case")]
+        tags: Vec<String>,
+        /// Leaves out cases carrying any of these manifest tags.
+        #[arg(long, value_delimiter = ',', conflicts_with = "replay_case")]
+        exclude_tags: Vec<String>,
         #[arg(long, value_enum, default_value_t = RunnerMode::All)]
         runner: RunnerMode,
         #[arg(long = "mode", value_enum, default_value_t = BenchmarkMode::Perf)]
@@ -149,14 +247,167 @@ pub enum Command {
         lane: BenchmarkLane,
         #[arg(long, value_enum, default_value_t = TimingPhase::Execute)]
         timing_phase: TimingPhase,
+        /// TPC-DS rollout phase; queries scheduled for later phases are reported as skipped.
+        #[arg(
+            long,
+            default_value_t = DEFAULT_TPCDS_PHASE,
+            value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_TPCDS_PHASE))
+        )]
+        tpcds_phase: u8,
+        /// Fixture layout `store_sales` is registered from: `unpartitioned` or `date_partitioned`.
+        #[arg(long, default_value = "unpartitioned", value_parser = TpcdsLayout::parse)]
+        tpcds_layout: TpcdsLayout,
+        /// Directory of `.sql` files and their `tables.yaml` benchmarked by `--target custom_sql`.
+        #[arg(long)]
+        custom_sql_dir: Option<PathBuf>,
+        /// JSONL operation trace replayed by `--target replay`.
+        #[arg(long)]
+        replay_trace: Option<PathBuf>,
+        /// Runs `tpcds` and `custom_sql` queries in open-loop load windows of this many seconds.
+        #[arg(long, requires = "target_ops_per_sec")]
+        load_duration_secs: Option<u64>,
+        /// Rate at which an open-loop load window starts queries.
+        #[arg(long, requires = "load_duration_secs")]
+        target_ops_per_sec: Option<f64>,
+        /// How setup copies fixture tables: `auto`, `reflink`, `hardlink`, or `copy`.
+        #[arg(long, default_value = "auto", value_parser = FixtureCopyStrategy::parse)]
+        fixture_copy: FixtureCopyStrategy,
         #[arg(long, default_value_t = 1)]
         warmup: u32,
         #[arg(long, default_value_t = 5)]
         iterations: u32,
+        /// Run seed every case derives its seed from. With `--replay-case`, the case seed itself.
+        #[arg(long, default_value_t = DEFAULT_RUN_SEED)]
+        seed: u64,
+        /// Reruns one case by exact id, drawing its randomness from `--seed`.
+        #[arg(long, conflicts_with = "case_filter")]
+        replay_case: Option<String>,
+        /// Fails the run unless the fixtures were generated with this seed.
+        #[arg(long)]
+        fixture_seed: Option<u64>,
+        /// Upstream issue or PR the run validates, as `OWNER/REPO#NUMBER` (repeatable).
+        #[arg(long = "ref-issue")]
+        ref_issues: Vec<String>,
         #[arg(long)]
         no_summary_table: bool,
+        /// Waits for a `bench data` rewriting the fixtures dir instead of failing.
+        #[arg(long)]
+        wait: bool,
+        /// Prints each case start, measured iteration, and case finish to stderr as it happens.
+        #[arg(long)]
+        progress: bool,
+        /// Pushes each trusted case's median and throughput to this Prometheus Pushgateway
+        /// once the result is written.
+        #[arg(long, env = "DELTA_BENCH_PUSH_GATEWAY")]
+        push_gateway: Option<String>,
+        /// Records the physical plan of each scan, tpcds, and custom_sql case in its result.
+        #[arg(long, value_enum)]
+        capture_plan: Option<PlanFormat>,
+        /// With `full`, writes every operator's DataFusion metrics for each measured iteration
+        /// of the scan, tpcds, and custom_sql cases to `<target>.plan_metrics/<case>.json`.
+        #[arg(long, value_enum, default_value_t = PlanMetricsDetail::Curated)]
+        capture_plan_metrics: PlanMetricsDetail,
+        /// Samples each case on CPU and writes its flamegraph to `<target>.profiles/<case>.svg`.
+        /// Needs delta-bench built with the `profiling` feature.
+        #[arg(long)]
+        profile: bool,
+        /// Runs each case once, unwarmed, under dhat and records its peak heap and top allocation
+        /// sites, writing the profile to `<target>.heap/<case>.json`. Needs delta-bench built with
+        /// the `heap-profiling` feature.
+        #[arg(long)]
+        heap_profile: bool,
+        /// Label built at run time from placeholders such as `{date}-{git_sha}-{backend}`;
+        /// replaces `--label`.
+        #[arg(long, env = "DELTA_BENCH_LABEL_TEMPLATE")]
+        label_template: Option<String>,
+    },
+    Clean {
+        #[arg(long)]
+        remote: bool,
+        #[arg(long, default_value_t = 24)]
+        retention_hours: u64,
+        #[arg(long)]
+        dry_run: bool,
     },
     Doctor,
+    /// Runs one read, write, merge, and metadata case on throwaway micro-scale fixtures, to
+    /// check a fresh build end to end.
+    Smoke {
+        /// Generates the fixtures here and keeps them; defaults to a temporary directory that is
+        /// removed afterwards.
+        #[arg(long)]
+        work_dir: Option<PathBuf>,
+    },
+    /// Copies a run result, optionally redacted for sharing in public issues.
+    Export {
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long)]
+        out: PathBuf,
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Renders a run result as markdown, html, csv, or a pull request comment.
+    Report {
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long, default_value = crate::report::DEFAULT_REPORT_FORMAT)]
+        format: String,
+        /// Writes the report here instead of printing it.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Runs SQL over every run result under `--results-dir`, as tables `runs`, `cases`, and
+    /// `samples`.
+    Query {
+        sql: String,
+        /// Writes the rows here as Parquet instead of printing them.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Writes replay traces for suite cases the trace schema can express.
+    Trace {
+        #[arg(long, default_value = "sf1")]
+        scale: String,
+        #[arg(long)]
+        case_filter: Option<String>,
+        #[arg(long)]
+        out_dir: PathBuf,
+    },
+    /// Internal writer process for the `crash_recovery` suite; appends until killed.
+    #[command(hide = true)]
+    CrashWriter {
+        #[arg(long)]
+        table_dir: PathBuf,
+        #[arg(long, default_value_t = crate::suites::crash_recovery::DEFAULT_CRASH_WRITER_APPENDS)]
+        appends: u32,
+    },
+}
+
+/// Moves one generated scale between machines as a checksummed `.tar.zst` archive.
+#[derive(Debug, Subcommand)]
+pub enum DataArchiveCommand {
+    /// Writes the scale's fixtures and their checksums to an archive.
+    Export {
+        #[arg(long)]
+        archive: PathBuf,
+        /// Ignored with `--dataset-id`, which pins its own scale.
+        #[arg(long, default_value = "sf1")]
+        scale: String,
+        #[arg(long)]
+        dataset_id: Option<String>,
+    },
+    /// Extracts an exported archive after checking every file against its checksum.
+    Import {
+        #[arg(long)]
+        archive: PathBuf,
+        /// Replaces fixtures that already exist for the archived scale.
+        #[arg(long)]
+        force: bool,
+        /// Moves the replaced scale to `.delta_bench_trash/` instead of deleting it.
+        #[arg(long)]
+        trash: bool,
+    },
 }
 
 pub fn validate_label(label: &str) -> BenchResult<()> {
@@ -181,6 +432,79 @@ pub fn validate_label(label: &str) -> BenchResult<()> {
     Ok(())
 }
 
+/// Expands `{name}` placeholders in a `--label-template` from `values`. Characters a label
+/// cannot hold are replaced with `_` in each value, as the label contract sanitizes them, so the
+/// result only fails [`validate_label`] because of the template's own text.
+pub fn expand_label_template(template: &str, values: &[(&str, String)]) -> BenchResult<String> {
+    let mut label = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        label.push_str(&rest[..start]);
+        let Some(len) = rest[start + 1..].find('}') else {
+            return Err(BenchError::InvalidArgument(format!(
+                "label template '{template}' has an unclosed '{{'"
+            )));
+        };
+        let name = &rest[start + 1..start + 1 + len];
+        let Some((_, value)) = values.iter().find(|(key, _)| *key == name) else {
+            let known = values
+                .iter()
+                .map(|(key, _)| format!("{{{key}}}"))
+                .collect::<Vec<_>>();
+            return Err(BenchError::InvalidArgument(format!(
+                "unknown label template placeholder '{{{name}}}' (expected one of: {})",
+                known.join(", ")
+            )));
+        };
+        label.extend(value.chars().map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_') {
+                ch
+            } else {
+                '_'
+            }
+        }));
+        rest = &rest[start + len + 2..];
+    }
+    label.push_str(rest);
+    Ok(label)
+}
+
+/// Checks each `OWNER/REPO#NUMBER` reference and drops repeats, keeping the given order.
+pub fn parse_ref_issues(entries: &[String]) -> BenchResult<Vec<String>> {
+    let mut refs = Vec::new();
+    for entry in entries {
+        let entry = entry.trim();
+        let valid = entry
+            .split_once('#')
+            .and_then(|(repo, number)| {
+                let (owner, name) = repo.split_once('/')?;
+                Some(
+                    is_repo_segment(owner)
+                        && is_repo_segment(name)
+                        && number.bytes().all(|b| b.is_ascii_digit())
+                        && number.parse::<u64>().is_ok_and(|number| number > 0),
+                )
+            })
+            .unwrap_or(false);
+        if !valid {
+            return Err(BenchError::InvalidArgument(format!(
+                "invalid ref issue '{entry}'; expected OWNER/REPO#NUMBER (e.g. delta-io/delta-rs#1234)"
+            )));
+        }
+        if !refs.iter().any(|existing| existing == entry) {
+            refs.push(entry.to_string());
+        }
+    }
+    Ok(refs)
+}
+
+fn is_repo_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_'))
+}
+
 pub fn parse_storage_options(entries: &[String]) -> BenchResult<HashMap<String, String>> {
     let mut options = HashMap::new();
     for entry in entries {
@@ -0,0 +1,339 @@
+//! Checksummed fixture archives, so CI runners and teammates can share one generated scale
+//! instead of regenerating it on every machine.
+//!
+//! An archive is a zstd-compressed tar of a scale's fixture directory under a `<scale>/` prefix.
+//! Its first entry, `archive.json`, lists every other file with its size and SHA-256 and pins
+//! the SHA-256 of the scale's `manifest.json`. Import extracts into a staging directory, rejects
+//! any file that is missing, unexpected, or differs from that list, and checks the manifest's
+//! dataset fingerprint before the staged scale replaces the local one.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::datasets::FixtureManifest;
+use super::fixtures::{
+    acquire_fixture_generation_lock, dataset_fixtures_dir, fixture_root, load_manifest,
+    remove_fixture_root, scale_to_row_count, verify_fixture_manifest,
+};
+use crate::error::{BenchError, BenchResult};
+use crate::manifests::DatasetId;
+
+pub const FIXTURE_ARCHIVE_VERSION: u32 = 1;
+/// Name of the index entry at the start of every archive.
+pub const FIXTURE_ARCHIVE_INDEX: &str = "archive.json";
+const FIXTURE_MANIFEST_FILE: &str = "manifest.json";
+const COPY_BUFFER_BYTES: usize = 64 * 1024;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FixtureArchiveIndex {
+    pub archive_version: u32,
+    pub scale: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dataset_id: Option<String>,
+    pub dataset_fingerprint: String,
+    pub manifest_sha256: String,
+    /// Paths are relative to the scale directory, `/`-separated, and sorted.
+    pub files: Vec<FixtureArchiveFile>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FixtureArchiveFile {
+    pub path: String,
+    pub bytes: u64,
+    pub sha256: String,
+}
+
+impl FixtureArchiveIndex {
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.bytes).sum()
+    }
+}
+
+/// Writes one scale's fixtures to `archive`. The manifest must still match its fingerprint, so
+/// a stale or hand-edited fixture tree is not passed on.
+pub fn export_fixture_archive(
+    fixtures_dir: &Path,
+    scale: &str,
+    dataset: Option<DatasetId>,
+    archive: &Path,
+) -> BenchResult<FixtureArchiveIndex> {
+    scale_to_row_count(scale)?;
+    let fixtures_dir = dataset_fixtures_dir(fixtures_dir, dataset);
+    let root = fixture_root(&fixtures_dir, scale);
+    let manifest = load_manifest(&fixtures_dir, scale)?;
+    verify_fixture_manifest(&manifest, dataset, None)?;
+
+    let mut paths = Vec::new();
+    collect_fixture_files(&root, &root, &mut paths)?;
+    paths.sort();
+    let mut files = Vec::with_capacity(paths.len());
+    for path in &paths {
+        let (bytes, sha256) = copy_and_hash(&mut File::open(root.join(path))?, &mut io::sink())?;
+        files.push(FixtureArchiveFile {
+            path: path.clone(),
+            bytes,
+            sha256,
+        });
+    }
+    let manifest_sha256 = files
+        .iter()
+        .find(|file| file.path == FIXTURE_MANIFEST_FILE)
+        .map(|file| file.sha256.clone())
+        .expect("manifest.json was loaded from the fixture root");
+    let index = FixtureArchiveIndex {
+        archive_version: FIXTURE_ARCHIVE_VERSION,
+        scale: scale.to_string(),
+        dataset_id: dataset.map(|dataset| dataset.as_str().to_string()),
+        dataset_fingerprint: manifest.dataset_fingerprint,
+        manifest_sha256,
+        files,
+    };
+
+    let parent = archive
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    fs::create_dir_all(parent)?;
+    // Written beside the destination and renamed into place, so an interrupted export never
+    // leaves a truncated archive under the requested name.
+    let mut partial = tempfile::NamedTempFile::new_in(parent)?;
+    {
+        let encoder = zstd::Encoder::new(partial.as_file_mut(), zstd::DEFAULT_COMPRESSION_LEVEL)?;
+        let mut builder = tar::Builder::new(encoder);
+        builder.mode(tar::HeaderMode::Deterministic);
+        let index_bytes = serde_json::to_vec_pretty(&index)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(index_bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(0);
+        builder.append_data(&mut header, FIXTURE_ARCHIVE_INDEX, index_bytes.as_slice())?;
+        for file in &index.files {
+            builder
+                .append_path_with_name(root.join(&file.path), format!("{scale}/{}", file.path))?;
+        }
+        builder.into_inner()?.finish()?;
+    }
+    partial.persist(archive).map_err(|error| error.error)?;
+    Ok(index)
+}
+
+/// Extracts `archive` into the fixture directory named by its index. Existing fixtures for that
+/// scale are only replaced with `force`, and moved to the fixture trash instead of deleted with
+/// `trash`.
+pub async fn import_fixture_archive(
+    fixtures_dir: &Path,
+    archive: &Path,
+    force: bool,
+    trash: bool,
+) -> BenchResult<FixtureArchiveIndex> {
+    let mut entries_archive = tar::Archive::new(zstd::Decoder::new(File::open(archive)?)?);
+    let mut entries = entries_archive.entries()?;
+    let index = match entries.next() {
+        Some(entry) => {
+            let mut entry = entry?;
+            if entry.path()? != Path::new(FIXTURE_ARCHIVE_INDEX) {
+                return Err(invalid_archive(
+                    archive,
+                    format!("first entry must be {FIXTURE_ARCHIVE_INDEX}"),
+                ));
+            }
+            let mut encoded = Vec::new();
+            entry.read_to_end(&mut encoded)?;
+            serde_json::from_slice::<FixtureArchiveIndex>(&encoded)?
+        }
+        None => return Err(invalid_archive(archive, "archive is empty".to_string())),
+    };
+    if index.archive_version != FIXTURE_ARCHIVE_VERSION {
+        return Err(invalid_archive(
+            archive,
+            format!(
+                "unsupported archive version {} (expected {FIXTURE_ARCHIVE_VERSION})",
+                index.archive_version
+            ),
+        ));
+    }
+    // Also keeps the scale, which names the destination directory, to a known value.
+    scale_to_row_count(&index.scale)?;
+    let dataset = index
+        .dataset_id
+        .as_deref()
+        .map(DatasetId::parse)
+        .transpose()?;
+    let fixtures_dir = dataset_fixtures_dir(fixtures_dir, dataset);
+    let root = fixture_root(&fixtures_dir, &index.scale);
+    if root.exists() && !force {
+        return Err(BenchError::InvalidArgument(format!(
+            "fixtures already exist at {}; pass --force to replace them",
+            root.display()
+        )));
+    }
+    fs::create_dir_all(&fixtures_dir)?;
+    let _scale_lock = acquire_fixture_generation_lock(&fixtures_dir, &index.scale).await?;
+    let staging = tempfile::Builder::new()
+        .prefix(&format!(".{}.import", index.scale))
+        .tempdir_in(&fixtures_dir)?;
+
+    let mut expected = index
+        .files
+        .iter()
+        .map(|file| (file.path.as_str(), file))
+        .collect::<BTreeMap<_, _>>();
+    for entry in entries {
+        let mut entry = entry?;
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_dir() {
+            continue;
+        }
+        let entry_path = entry.path()?.into_owned();
+        if !entry_type.is_file() {
+            return Err(invalid_archive(
+                archive,
+                format!("'{}' is not a regular file", entry_path.display()),
+            ));
+        }
+        let path = scale_relative_path(&entry_path, &index.scale).ok_or_else(|| {
+            invalid_archive(
+                archive,
+                format!(
+                    "'{}' is outside the {}/ directory",
+                    entry_path.display(),
+                    index.scale
+                ),
+            )
+        })?;
+        let file = expected.remove(path.as_str()).ok_or_else(|| {
+            invalid_archive(
+                archive,
+                format!("'{path}' is not listed in {FIXTURE_ARCHIVE_INDEX} or appears twice"),
+            )
+        })?;
+        let target = staging.path().join(&path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let (bytes, sha256) = copy_and_hash(&mut entry, &mut File::create(&target)?)?;
+        if bytes != file.bytes || sha256 != file.sha256 {
+            return Err(invalid_archive(
+                archive,
+                format!(
+                    "checksum mismatch for '{path}': expected {} ({} bytes), found {sha256} ({bytes} bytes)",
+                    file.sha256, file.bytes
+                ),
+            ));
+        }
+    }
+    if let Some(missing) = expected.keys().next() {
+        return Err(invalid_archive(
+            archive,
+            format!(
+                "'{missing}' is listed in {FIXTURE_ARCHIVE_INDEX} but missing ({} file(s) missing)",
+                expected.len()
+            ),
+        ));
+    }
+
+    let manifest_path = staging.path().join(FIXTURE_MANIFEST_FILE);
+    let (_, manifest_sha256) = copy_and_hash(&mut File::open(&manifest_path)?, &mut io::sink())?;
+    if manifest_sha256 != index.manifest_sha256 {
+        return Err(invalid_archive(
+            archive,
+            format!(
+                "manifest checksum mismatch: expected {}, found {manifest_sha256}",
+                index.manifest_sha256
+            ),
+        ));
+    }
+    let manifest = serde_json::from_slice::<FixtureManifest>(&fs::read(&manifest_path)?)?;
+    if manifest.scale != index.scale || manifest.dataset_fingerprint != index.dataset_fingerprint {
+        return Err(invalid_archive(
+            archive,
+            format!(
+                "manifest describes scale {} ({}), but the archive index records scale {} ({})",
+                manifest.scale,
+                manifest.dataset_fingerprint,
+                index.scale,
+                index.dataset_fingerprint
+            ),
+        ));
+    }
+    verify_fixture_manifest(&manifest, dataset, None)?;
+
+    remove_fixture_root(&fixtures_dir, &root, force, trash)?;
+    fs::rename(staging.path(), &root)?;
+    Ok(index)
+}
+
+fn invalid_archive(archive: &Path, reason: String) -> BenchError {
+    BenchError::InvalidArgument(format!(
+        "invalid fixture archive {}: {reason}",
+        archive.display()
+    ))
+}
+
+fn collect_fixture_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> BenchResult<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_fixture_files(root, &path, files)?;
+        } else if file_type.is_file() {
+            let relative = path
+                .strip_prefix(root)
+                .expect("walked paths stay under the fixture root");
+            files.push(portable_relative_path(relative).ok_or_else(|| {
+                BenchError::InvalidArgument(format!(
+                    "fixture file {} has a name that cannot be archived",
+                    path.display()
+                ))
+            })?);
+        } else {
+            return Err(BenchError::InvalidArgument(format!(
+                "fixture path {} is not a regular file or directory",
+                path.display()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// `path` with its leading `<scale>/` removed, or `None` if it would land outside that
+/// directory once extracted.
+fn scale_relative_path(path: &Path, scale: &str) -> Option<String> {
+    let relative = path.strip_prefix(scale).ok()?;
+    portable_relative_path(relative)
+}
+
+fn portable_relative_path(path: &Path) -> Option<String> {
+    let parts = path
+        .components()
+        .map(|component| match component {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+/// Copies `reader` into `writer`, returning the byte count and `sha256:`-prefixed digest.
+fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0_u8; COPY_BUFFER_BYTES];
+    let mut bytes = 0_u64;
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        writer.write_all(&buffer[..read])?;
+        bytes += read as u64;
+    }
+    writer.flush()?;
+    Ok((bytes, format!("sha256:{:x}", hasher.finalize())))
+}
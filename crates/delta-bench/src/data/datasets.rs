@@ -1,5 +1,20 @@
+//! Serialized fixture records, and the [`DatasetGenerator`] extension point for dataset shapes
+//! defined outside the harness.
+//!
+//! Built-in datasets are [`DatasetId`] variants derived from the narrow-sales rows. A custom
+//! dataset brings its own schema and rows and lists the tables written from them; with the
+//! `custom-datasets` feature, crates embedding the harness register generators in a
+//! [`DatasetRegistry`] and look them up by dataset id.
+
+use deltalake_core::arrow::datatypes::SchemaRef;
+use deltalake_core::arrow::record_batch::RecordBatch;
 use serde::{Deserialize, Serialize};
 
+use super::generator::DataDistribution;
+use super::scales::{scale_catalog, SMOKE_SCALE};
+use crate::error::{BenchError, BenchResult};
+use crate::manifests::DatasetId;
+
 fn default_fixture_profile() -> String {
     "standard".to_string()
 }
@@ -17,11 +32,12 @@ pub struct NarrowSaleRow {
     pub id: i64,
     pub ts_ms: i64,
     pub region: String,
-    pub value_i64: i64,
-    pub flag: bool,
+    /// `None` only when the fixture was generated with a null probability for the column.
+    pub value_i64: Option<i64>,
+    pub flag: Option<bool>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FixtureRecipe {
     pub schema_version: u32,
     pub generator_version: u32,
@@ -38,7 +54,11 @@ pub struct FixtureRecipe {
     #[serde(default)]
     pub metadata_compare_history_append_commits: usize,
     #[serde(default)]
+    pub metadata_deep_history_append_commits: usize,
+    #[serde(default)]
     pub metadata_history_chunk_size: usize,
+    #[serde(default)]
+    pub checkpoint_history_append_commits: usize,
     pub read_partition_chunk_size: usize,
     pub merge_partition_chunk_size: usize,
     pub delete_update_partition_chunk_size: usize,
@@ -47,11 +67,34 @@ pub struct FixtureRecipe {
     pub merge_seed_rows: usize,
     pub vacuum_seed_rows: usize,
     pub tpcds_duckdb_chunk_rows: usize,
+    #[serde(default)]
+    pub raw_parquet_files_per_partition: usize,
+    #[serde(default)]
+    pub data_skipping_file_count: usize,
+    #[serde(default)]
+    pub wide_table_columns: usize,
+    #[serde(default)]
+    pub wide_table_rows: usize,
+    #[serde(default)]
+    pub nested_table_rows: usize,
+    /// Set only when the scale catalog overrides the default commit size, so existing recipes
+    /// hash the same.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_max_rows: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub late_arriving_chunk_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub small_files_count: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub small_files_rows_per_file: Option<usize>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile_component_hash: Option<String>,
+    /// Omitted when uniform, so recipes from before the distribution knobs hash the same.
+    #[serde(default, skip_serializing_if = "DataDistribution::is_uniform")]
+    pub distribution: DataDistribution,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FixtureManifest {
     pub schema_version: u32,
     #[serde(default)]
@@ -63,10 +106,194 @@ pub struct FixtureManifest {
     pub profile: String,
     #[serde(default)]
     pub dataset_fingerprint: String,
+    /// Hash of the narrow-sales row stream every table is derived from.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub rows_hash: String,
     #[serde(default = "default_table_inventory")]
     pub table_inventory: Vec<String>,
+    /// What each Delta table of the inventory holds once written.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub table_summaries: Vec<FixtureTableSummary>,
     #[serde(default = "default_fixture_recipe_hash")]
     pub fixture_recipe_hash: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixture_recipe: Option<FixtureRecipe>,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FixtureTableSummary {
+    pub table: String,
+    pub schema_hash: String,
+    pub rows: u64,
+    /// Data files in the table's latest snapshot.
+    #[serde(default)]
+    pub files: u64,
+    /// Total size of those data files; unset in manifests written before sizes were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+}
+
+/// One Delta table of a custom dataset, written under `<dataset_id>/<scale>/<name>`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DatasetTableLayout {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partition_columns: Vec<String>,
+    /// Leading rows of the dataset the table holds; every row when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rows: Option<usize>,
+}
+
+impl DatasetTableLayout {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            partition_columns: Vec::new(),
+            rows: None,
+        }
+    }
+
+    pub fn partitioned_by<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.partition_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+}
+
+pub trait DatasetGenerator: Send + Sync {
+    /// Dataset id accepted by `--dataset-id`; also the fixture directory its tables live under.
+    fn id(&self) -> &'static str;
+
+    /// Bump when the rows or layout change, so existing fixtures regenerate.
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn schema(&self) -> SchemaRef;
+
+    /// Rows `offset..offset + len` for `seed`, matching [`DatasetGenerator::schema`]. The same
+    /// arguments must always produce the same rows.
+    fn generate_batch(&self, seed: u64, offset: usize, len: usize) -> BenchResult<RecordBatch>;
+
+    /// Tables written from the generated rows, in inventory order.
+    fn tables(&self) -> Vec<DatasetTableLayout>;
+}
+
+/// Custom datasets by id. Built-in dataset ids are not listed; they cannot be replaced.
+#[derive(Default)]
+pub struct DatasetRegistry {
+    generators: Vec<Box<dyn DatasetGenerator>>,
+}
+
+impl DatasetRegistry {
+    /// Adds a generator after checking its id and table layout.
+    #[cfg(feature = "custom-datasets")]
+    pub fn register(&mut self, generator: Box<dyn DatasetGenerator>) -> BenchResult<()> {
+        validate_dataset_generator(generator.as_ref())?;
+        if self.names().contains(&generator.id()) {
+            return Err(BenchError::InvalidArgument(format!(
+                "dataset_id '{}' is already registered",
+                generator.id()
+            )));
+        }
+        self.generators.push(generator);
+        Ok(())
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.generators
+            .iter()
+            .map(|generator| generator.id())
+            .collect()
+    }
+
+    pub fn get(&self, dataset_id: &str) -> BenchResult<&dyn DatasetGenerator> {
+        self.generators
+            .iter()
+            .find(|generator| generator.id() == dataset_id)
+            .map(|generator| generator.as_ref())
+            .ok_or_else(|| {
+                BenchError::InvalidArgument(format!(
+                    "unknown custom dataset_id '{dataset_id}'; registered: {}",
+                    if self.generators.is_empty() {
+                        "none".to_string()
+                    } else {
+                        self.names().join(", ")
+                    }
+                ))
+            })
+    }
+}
+
+/// Dataset ids and table names become fixture directories, so both are limited to lowercase
+/// identifiers, and a dataset id may not shadow a built-in dataset or a scale directory.
+pub(crate) fn validate_dataset_generator(generator: &dyn DatasetGenerator) -> BenchResult<()> {
+    let id = generator.id();
+    if !is_lowercase_identifier(id) {
+        return Err(BenchError::InvalidArgument(format!(
+            "dataset_id '{id}' must be a lowercase identifier ([a-z][a-z0-9_]*)"
+        )));
+    }
+    if DatasetId::parse(id).is_ok() {
+        return Err(BenchError::InvalidArgument(format!(
+            "dataset_id '{id}' is a built-in dataset"
+        )));
+    }
+    if id == SMOKE_SCALE || scale_catalog()?.get(id).is_ok() {
+        return Err(BenchError::InvalidArgument(format!(
+            "dataset_id '{id}' collides with a scale name"
+        )));
+    }
+
+    let schema = generator.schema();
+    let tables = generator.tables();
+    if tables.is_empty() {
+        return Err(BenchError::InvalidArgument(format!(
+            "dataset '{id}' defines no tables"
+        )));
+    }
+    for (idx, table) in tables.iter().enumerate() {
+        let name = table.name.as_str();
+        if !is_lowercase_identifier(name) {
+            return Err(BenchError::InvalidArgument(format!(
+                "dataset '{id}' table '{name}' must be a lowercase identifier ([a-z][a-z0-9_]*)"
+            )));
+        }
+        if tables[..idx].iter().any(|other| other.name == table.name) {
+            return Err(BenchError::InvalidArgument(format!(
+                "dataset '{id}' lists table '{name}' more than once"
+            )));
+        }
+        if table.rows == Some(0) {
+            return Err(BenchError::InvalidArgument(format!(
+                "dataset '{id}' table '{name}' must have rows > 0"
+            )));
+        }
+        for column in &table.partition_columns {
+            if schema.field_with_name(column).is_err() {
+                return Err(BenchError::InvalidArgument(format!(
+                    "dataset '{id}' table '{name}' is partitioned by '{column}', which is not in the dataset schema"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_lowercase_identifier(value: &str) -> bool {
+    value
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_lowercase())
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+}
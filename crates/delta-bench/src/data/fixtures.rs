@@ -1,21 +1,52 @@
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
 use std::fs;
+use std::future::Future;
 use std::io::{BufRead, BufReader, Write};
+use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use chrono::{Datelike, NaiveDate};
 use deltalake_core::arrow;
 use deltalake_core::checkpoints;
+use deltalake_core::datafusion::prelude::SessionContext;
+use deltalake_core::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use deltalake_core::parquet::arrow::ArrowWriter;
 use deltalake_core::protocol::SaveMode;
+use futures::future::{BoxFuture, FutureExt};
+use futures::TryStreamExt;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 use url::Url;
 
-use super::datasets::{FixtureManifest, FixtureRecipe, NarrowSaleRow};
-use super::generator::generate_narrow_sales_rows;
+use super::datasets::{
+    validate_dataset_generator, DatasetGenerator, DatasetTableLayout, FixtureManifest,
+    FixtureRecipe, FixtureTableSummary, NarrowSaleRow,
+};
+use super::generator::{DataDistribution, LateArrivingSplitter, NarrowSalesRows, REGIONS};
+use super::scales::{scale_definition, ScaleDefinition};
 use crate::error::{BenchError, BenchResult};
-use crate::fingerprint::{hash_bytes, hash_json};
+use crate::fingerprint::{
+    hash_arrow_schema, hash_bytes, hash_json, hash_json_seq, hash_record_batches_unordered,
+};
+use crate::manifests::DatasetId;
 use crate::storage::StorageConfig;
 
 const NARROW_SALES_TABLE_DIR: &str = "narrow_sales_delta";
+const NARROW_SALES_ROWS_DIR: &str = "narrow_sales";
+const NARROW_SALES_ROWS_FILE: &str = "rows.parquet";
+/// Debug export of the same rows, written only on request by `data --export-rows-jsonl`.
+const NARROW_SALES_ROWS_JSONL_FILE: &str = "rows.jsonl";
+/// Rows per record batch, and so per row group, in the row snapshot files.
+const ROWS_FILE_BATCH_SIZE: usize = 65_536;
+/// Rows requested from a custom dataset generator per call.
+const CUSTOM_DATASET_BATCH_ROWS: usize = 65_536;
+/// Most rows a fixture writer holds at once unless the scale catalog sets `commit_max_rows`.
+/// Tables written in a single commit get one commit per this many rows instead, which only
+/// changes their layout above sf100.
+pub(crate) const FIXTURE_COMMIT_MAX_ROWS: usize = 1_048_576;
 const MERGE_TARGET_TABLE_DIR: &str = "merge_target_delta";
 const READ_PARTITIONED_TABLE_DIR: &str = "read_partitioned_delta";
 const DELETE_UPDATE_SMALL_FILES_TABLE_DIR: &str = "delete_update_small_files_delta";
@@ -26,27 +57,83 @@ const VACUUM_READY_TABLE_DIR: &str = "vacuum_ready_delta";
 const METADATA_LONG_HISTORY_TABLE_DIR: &str = "metadata_long_history_delta";
 const METADATA_CHECKPOINTED_TABLE_DIR: &str = "metadata_checkpointed_delta";
 const METADATA_UNCHECKPOINTED_TABLE_DIR: &str = "metadata_uncheckpointed_delta";
+const METADATA_DEEP_HISTORY_TABLE_DIR: &str = "metadata_deep_history_delta";
+const CHECKPOINT_HISTORY_TABLE_DIR: &str = "checkpoint_history_delta";
+const LATE_ARRIVING_TARGET_TABLE_DIR: &str = "late_arriving_target_delta";
+const SMALL_FILES_TABLE_DIR: &str = "small_files_delta";
+const LATE_ARRIVING_DIR: &str = "late_arriving";
+const LATE_ARRIVING_BACKFILL_ROWS_FILE: &str = "backfill_rows.parquet";
+const RAW_PARQUET_PARTITIONED_DIR: &str = "raw_parquet_partitioned";
+const DATA_SKIPPING_TABLE_DIR: &str = "data_skipping_delta";
+const WIDE_TABLE_DIR: &str = "wide_delta";
+const NESTED_TABLE_DIR: &str = "nested_delta";
 const TPCDS_DIR: &str = "tpcds";
 const TPCDS_STORE_SALES_TABLE_DIR: &str = "store_sales";
-const FIXTURE_SCHEMA_VERSION: u32 = 3;
+const TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR: &str = "store_sales_date_partitioned";
+const TPCDS_STORE_SALES_PARTITION_COLUMN: &str = "ss_sold_date_sk";
+const TPCDS_DATE_DIM_TABLE_DIR: &str = "date_dim";
+const TPCDS_ITEM_TABLE_DIR: &str = "item";
+const TPCDS_CUSTOMER_TABLE_DIR: &str = "customer";
+/// `d_date_sk` values are Julian day numbers. This is the day number of 0000-12-31, so
+/// subtracting it yields chrono's days-from-CE.
+const TPCDS_JULIAN_DAY_CE_OFFSET: i64 = 1_721_425;
+const TPCDS_ITEM_CATEGORIES: [&str; 10] = [
+    "Books",
+    "Children",
+    "Electronics",
+    "Home",
+    "Jewelry",
+    "Men",
+    "Music",
+    "Shoes",
+    "Sports",
+    "Women",
+];
+const FIXTURE_SCHEMA_VERSION: u32 = 4;
 const FIXTURE_GENERATOR_VERSION: u32 = 1;
 const MANY_VERSIONS_APPEND_COMMITS: usize = 12;
 const METADATA_SEED_ROWS: usize = 4_096;
 const METADATA_LONG_HISTORY_APPEND_COMMITS: usize = 48;
 const METADATA_COMPARE_HISTORY_APPEND_COMMITS: usize = 24;
+const METADATA_DEEP_HISTORY_APPEND_COMMITS: usize = 512;
 const METADATA_HISTORY_CHUNK_SIZE: usize = 64;
+const CHECKPOINT_HISTORY_APPEND_COMMITS: usize = 96;
 const METADATA_CHECKPOINT_INTERVAL: &str = "100000";
-const FIXTURE_LOCK_DIR: &str = ".delta_bench_locks";
+pub(crate) const FIXTURE_LOCK_DIR: &str = ".delta_bench_locks";
+/// Where replaced scale directories go with `data --trash`.
+pub const FIXTURE_TRASH_DIR: &str = ".delta_bench_trash";
 const DEFAULT_FIXTURE_LOCK_TIMEOUT_MS: u64 = 120_000;
 const DEFAULT_FIXTURE_LOCK_RETRY_MS: u64 = 50;
 const FIXTURE_LOCK_TIMEOUT_ENV: &str = "DELTA_BENCH_FIXTURE_LOCK_TIMEOUT_MS";
 const FIXTURE_LOCK_RETRY_ENV: &str = "DELTA_BENCH_FIXTURE_LOCK_RETRY_MS";
+/// Tables written at once unless `DELTA_BENCH_FIXTURE_PARALLELISM` is set.
+pub const DEFAULT_FIXTURE_PARALLELISM: usize = 4;
+const FIXTURE_PARALLELISM_ENV: &str = "DELTA_BENCH_FIXTURE_PARALLELISM";
 const DEFAULT_TPCDS_DUCKDB_TIMEOUT_MS: u64 = 600_000;
 const TPCDS_DUCKDB_CHUNK_ROWS: usize = 10_000;
 const READ_PARTITION_CHUNK_SIZE: usize = 128;
 const MERGE_PARTITION_CHUNK_SIZE: usize = 64;
 const DELETE_UPDATE_PARTITION_CHUNK_SIZE: usize = 64;
 const OPTIMIZE_SMALL_FILES_CHUNK_SIZE: usize = 128;
+const LATE_ARRIVING_CHUNK_SIZE: usize = 500;
+/// Fixed per-partition file count keeps the raw Parquet layout independent of region skew.
+const RAW_PARQUET_FILES_PER_PARTITION: usize = 8;
+/// Files in `data_skipping_delta`; one contiguous id range per file.
+pub const DATA_SKIPPING_FILE_COUNT: usize = 64;
+/// Columns in `wide_delta`, including `id`, unless `data --wide-table-columns` says otherwise.
+pub const DEFAULT_WIDE_TABLE_COLUMNS: usize = 200;
+/// `id` plus the three projected columns of the wide suite.
+pub const MIN_WIDE_TABLE_COLUMNS: usize = 4;
+/// Caps `wide_delta` so sf100 stays at a size where width, not row count, dominates.
+pub const WIDE_TABLE_MAX_ROWS: usize = 100_000;
+const WIDE_TABLE_CHUNK_ROWS: usize = 8_192;
+/// Caps `nested_delta`; list and map columns make each row several times wider than it looks.
+pub const NESTED_TABLE_MAX_ROWS: usize = 100_000;
+const NESTED_TABLE_CHUNK_ROWS: usize = 8_192;
+/// Files in `small_files_delta` unless `data --small-files-count` says otherwise.
+pub const DEFAULT_SMALL_FILES_COUNT: usize = 5_000;
+/// Narrow-sales rows per `small_files_delta` file, roughly 100KB of Parquet each.
+pub const DEFAULT_SMALL_FILES_ROWS_PER_FILE: usize = 4_096;
 const TPCDS_DUCKDB_PYTHON_ENV: &str = "DELTA_BENCH_DUCKDB_PYTHON";
 const TPCDS_DUCKDB_SCRIPT_ENV: &str = "DELTA_BENCH_TPCDS_DUCKDB_SCRIPT";
 const TPCDS_DUCKDB_TIMEOUT_ENV: &str = "DELTA_BENCH_TPCDS_DUCKDB_TIMEOUT_MS";
@@ -61,49 +148,140 @@ fn fixture_table_inventory(profile: FixtureProfile) -> Vec<String> {
         OPTIMIZE_SMALL_FILES_TABLE_DIR.to_string(),
         OPTIMIZE_COMPACTED_TABLE_DIR.to_string(),
         VACUUM_READY_TABLE_DIR.to_string(),
+        RAW_PARQUET_PARTITIONED_DIR.to_string(),
+        DATA_SKIPPING_TABLE_DIR.to_string(),
+        WIDE_TABLE_DIR.to_string(),
+        NESTED_TABLE_DIR.to_string(),
         format!("{TPCDS_DIR}/{TPCDS_STORE_SALES_TABLE_DIR}"),
+        format!("{TPCDS_DIR}/{TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR}"),
+        format!("{TPCDS_DIR}/{TPCDS_DATE_DIM_TABLE_DIR}"),
+        format!("{TPCDS_DIR}/{TPCDS_ITEM_TABLE_DIR}"),
+        format!("{TPCDS_DIR}/{TPCDS_CUSTOMER_TABLE_DIR}"),
     ];
     if profile == FixtureProfile::ManyVersions {
         inventory.extend([
             METADATA_LONG_HISTORY_TABLE_DIR.to_string(),
             METADATA_CHECKPOINTED_TABLE_DIR.to_string(),
             METADATA_UNCHECKPOINTED_TABLE_DIR.to_string(),
+            METADATA_DEEP_HISTORY_TABLE_DIR.to_string(),
+            CHECKPOINT_HISTORY_TABLE_DIR.to_string(),
         ]);
     }
+    if profile == FixtureProfile::LateArriving {
+        inventory.push(LATE_ARRIVING_TARGET_TABLE_DIR.to_string());
+    }
+    if profile == FixtureProfile::SmallFiles {
+        inventory.push(SMALL_FILES_TABLE_DIR.to_string());
+    }
     inventory
 }
 
+/// Covers the recipe, the row stream, and what each table holds once written, so fixtures whose
+/// tables drifted from the recipe do not share a fingerprint with a clean generation. Table byte
+/// sizes are left out: they follow the Parquet writer's version, not the data.
 fn compute_dataset_fingerprint(
-    recipe: &FixtureRecipe,
-    data: &[NarrowSaleRow],
+    fixture_recipe_hash: &str,
+    profile: &str,
+    rows_hash: &str,
+    table_summaries: &[FixtureTableSummary],
 ) -> BenchResult<String> {
+    #[derive(serde::Serialize)]
+    struct FingerprintTable<'a> {
+        table: &'a str,
+        schema_hash: &'a str,
+        rows: u64,
+        files: u64,
+    }
+
     #[derive(serde::Serialize)]
     struct FingerprintInput<'a> {
-        fixture_recipe_hash: String,
+        fixture_recipe_hash: &'a str,
         profile: &'a str,
-        rows_hash: String,
+        rows_hash: &'a str,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tables: Vec<FingerprintTable<'a>>,
     }
 
     hash_json(&FingerprintInput {
-        fixture_recipe_hash: hash_json(recipe)?,
-        profile: recipe.profile.as_str(),
-        rows_hash: hash_json(data)?,
+        fixture_recipe_hash,
+        profile,
+        rows_hash,
+        tables: table_summaries
+            .iter()
+            .map(|summary| FingerprintTable {
+                table: &summary.table,
+                schema_hash: &summary.schema_hash,
+                rows: summary.rows,
+                files: summary.files,
+            })
+            .collect(),
     })
 }
 
+/// Recomputes the fingerprint from the components the manifest records. Manifests from before
+/// `rows_hash` was recorded cannot be checked and never match.
+fn manifest_fingerprint_matches(manifest: &FixtureManifest) -> bool {
+    !manifest.rows_hash.is_empty()
+        && compute_dataset_fingerprint(
+            &manifest.fixture_recipe_hash,
+            &manifest.profile,
+            &manifest.rows_hash,
+            &manifest.table_summaries,
+        )
+        .is_ok_and(|fingerprint| fingerprint == manifest.dataset_fingerprint)
+}
+
+/// Schema, row count, file count, and data size of every Delta table in the inventory. The raw
+/// Parquet directory is skipped; it holds the same rows as `narrow_sales_delta`.
+async fn summarize_fixture_tables(
+    fixtures_dir: &Path,
+    scale: &str,
+    table_inventory: &[String],
+    storage: &StorageConfig,
+) -> BenchResult<Vec<FixtureTableSummary>> {
+    let root = fixture_root(fixtures_dir, scale);
+    let ctx = SessionContext::new();
+    let mut summaries = Vec::with_capacity(table_inventory.len());
+    for table_name in table_inventory
+        .iter()
+        .filter(|table_name| table_name.as_str() != RAW_PARQUET_PARTITIONED_DIR)
+    {
+        let table_url = storage.table_url_for(&root.join(table_name), scale, table_name)?;
+        let table = storage.open_table(table_url).await?;
+        let log_data = table.snapshot()?.log_data();
+        let files = log_data.num_files() as u64;
+        let bytes = log_data.iter().map(|file| file.size().max(0) as u64).sum();
+        let frame = ctx.read_table(table.table_provider().await?)?;
+        let schema_hash = hash_arrow_schema(frame.schema().as_arrow())?;
+        summaries.push(FixtureTableSummary {
+            table: table_name.clone(),
+            schema_hash,
+            rows: frame.count().await? as u64,
+            files,
+            bytes: Some(bytes),
+        });
+    }
+    Ok(summaries)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn build_fixture_recipe(
     seed: u64,
-    scale: &str,
-    rows: usize,
+    scale: &ScaleDefinition,
     profile: FixtureProfile,
     table_inventory: Vec<String>,
+    wide_table_columns: usize,
     profile_component_hash: Option<String>,
+    distribution: &DataDistribution,
+    small_files: Option<SmallFilesShape>,
 ) -> FixtureRecipe {
+    let rows = scale.rows;
+    let commit_max_rows = scale.commit_max_rows();
     FixtureRecipe {
         schema_version: FIXTURE_SCHEMA_VERSION,
         generator_version: FIXTURE_GENERATOR_VERSION,
         seed,
-        scale: scale.to_string(),
+        scale: scale.name.clone(),
         rows,
         profile: profile.as_str().to_string(),
         table_inventory,
@@ -111,7 +289,9 @@ fn build_fixture_recipe(
         metadata_seed_rows: METADATA_SEED_ROWS.min(rows),
         metadata_long_history_append_commits: METADATA_LONG_HISTORY_APPEND_COMMITS,
         metadata_compare_history_append_commits: METADATA_COMPARE_HISTORY_APPEND_COMMITS,
+        metadata_deep_history_append_commits: METADATA_DEEP_HISTORY_APPEND_COMMITS,
         metadata_history_chunk_size: METADATA_HISTORY_CHUNK_SIZE,
+        checkpoint_history_append_commits: CHECKPOINT_HISTORY_APPEND_COMMITS,
         read_partition_chunk_size: READ_PARTITION_CHUNK_SIZE,
         merge_partition_chunk_size: MERGE_PARTITION_CHUNK_SIZE,
         delete_update_partition_chunk_size: DELETE_UPDATE_PARTITION_CHUNK_SIZE,
@@ -120,7 +300,18 @@ fn build_fixture_recipe(
         merge_seed_rows: (rows / 4).max(1024),
         vacuum_seed_rows: (rows / 3).max(1024),
         tpcds_duckdb_chunk_rows: TPCDS_DUCKDB_CHUNK_ROWS,
+        raw_parquet_files_per_partition: RAW_PARQUET_FILES_PER_PARTITION,
+        data_skipping_file_count: DATA_SKIPPING_FILE_COUNT,
+        wide_table_columns,
+        wide_table_rows: scale.wide_table_rows(),
+        nested_table_rows: scale.nested_table_rows(),
+        commit_max_rows: (commit_max_rows != FIXTURE_COMMIT_MAX_ROWS).then_some(commit_max_rows),
+        late_arriving_chunk_size: (profile == FixtureProfile::LateArriving)
+            .then_some(LATE_ARRIVING_CHUNK_SIZE),
+        small_files_count: small_files.map(|shape| shape.files),
+        small_files_rows_per_file: small_files.map(|shape| shape.rows_per_file),
         profile_component_hash,
+        distribution: distribution.clone(),
     }
 }
 
@@ -129,6 +320,8 @@ pub enum FixtureProfile {
     Standard,
     ManyVersions,
     TpcdsDuckdb,
+    LateArriving,
+    SmallFiles,
 }
 
 impl FixtureProfile {
@@ -137,21 +330,205 @@ impl FixtureProfile {
             Self::Standard => "standard",
             Self::ManyVersions => "many_versions",
             Self::TpcdsDuckdb => "tpcds_duckdb",
+            Self::LateArriving => "late_arriving",
+            Self::SmallFiles => "small_files",
+        }
+    }
+}
+
+/// Table shapes requested from `data`. Unlike a [`DataDistribution`], a shape changes the
+/// tables a fixture holds rather than the values in them; both are recorded in the recipe.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixtureShape {
+    /// Columns in `wide_delta`, including `id`.
+    pub wide_table_columns: usize,
+    /// Layout of `small_files_delta`; only the `small_files` profile writes that table.
+    pub small_files: SmallFilesShape,
+}
+
+impl Default for FixtureShape {
+    fn default() -> Self {
+        Self {
+            wide_table_columns: DEFAULT_WIDE_TABLE_COLUMNS,
+            small_files: SmallFilesShape::default(),
+        }
+    }
+}
+
+impl FixtureShape {
+    pub fn validate(&self) -> BenchResult<()> {
+        if self.wide_table_columns < MIN_WIDE_TABLE_COLUMNS {
+            return Err(BenchError::InvalidArgument(format!(
+                "wide table columns must be >= {MIN_WIDE_TABLE_COLUMNS} (found {})",
+                self.wide_table_columns
+            )));
+        }
+        self.small_files.validate()
+    }
+}
+
+/// Layout of `small_files_delta`: `files` files of `rows_per_file` narrow-sales rows each.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SmallFilesShape {
+    pub files: usize,
+    pub rows_per_file: usize,
+}
+
+impl Default for SmallFilesShape {
+    fn default() -> Self {
+        Self {
+            files: DEFAULT_SMALL_FILES_COUNT,
+            rows_per_file: DEFAULT_SMALL_FILES_ROWS_PER_FILE,
+        }
+    }
+}
+
+impl SmallFilesShape {
+    pub fn new(files: usize, rows_per_file: usize) -> BenchResult<Self> {
+        let shape = Self {
+            files,
+            rows_per_file,
+        };
+        shape.validate()?;
+        Ok(shape)
+    }
+
+    pub fn validate(self) -> BenchResult<()> {
+        let Self {
+            files,
+            rows_per_file,
+        } = self;
+        if files == 0 || rows_per_file == 0 {
+            return Err(BenchError::InvalidArgument(format!(
+                "small-files fixture needs at least one file of at least one row \
+                 (found {files} file(s) of {rows_per_file} row(s))"
+            )));
+        }
+        if files.checked_mul(rows_per_file).is_none() {
+            return Err(BenchError::InvalidArgument(format!(
+                "small-files fixture of {files} file(s) of {rows_per_file} row(s) is too large"
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn rows(self) -> usize {
+        self.files * self.rows_per_file
+    }
+}
+
+/// Wall-clock time spent writing one fixture table. Tables that are written together, such as
+/// the TPC-DS dimensions, share one entry naming all of them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixtureTableTiming {
+    pub table: String,
+    pub elapsed_ms: f64,
+}
+
+impl FixtureTableTiming {
+    fn since(table: impl Into<String>, started: Instant) -> Self {
+        Self {
+            table: table.into(),
+            elapsed_ms: started.elapsed().as_secs_f64() * 1_000.0,
         }
     }
 }
 
+/// Per-table write timings for one generated scale, sorted by table. `elapsed_ms` is the
+/// wall-clock time for all of them, which is less than their sum when tables overlap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixtureScaleTimings {
+    pub scale: String,
+    pub tables: Vec<FixtureTableTiming>,
+    pub elapsed_ms: f64,
+}
+
+type FixtureJob = BoxFuture<'static, BenchResult<Vec<FixtureTableTiming>>>;
+
+/// Times `write` from its first poll, which comes once the job's task holds a parallelism
+/// permit, so waiting for a permit is not counted.
+fn timed_fixture_job(
+    table: impl Into<String>,
+    write: impl Future<Output = BenchResult<()>> + Send + 'static,
+) -> FixtureJob {
+    let table = table.into();
+    async move {
+        let started = Instant::now();
+        write.await?;
+        Ok(vec![FixtureTableTiming::since(table, started)])
+    }
+    .boxed()
+}
+
+/// A [`timed_fixture_job`] for a synchronous writer, run on the blocking pool so it does not
+/// hold up the runtime's workers.
+fn blocking_fixture_job(
+    table: impl Into<String>,
+    write: impl FnOnce() -> BenchResult<()> + Send + 'static,
+) -> FixtureJob {
+    timed_fixture_job(table, async move {
+        tokio::task::spawn_blocking(write).await.map_err(|error| {
+            BenchError::InvalidArgument(format!("fixture write task failed: {error}"))
+        })?
+    })
+}
+
+/// Runs every job on its own task, at most `parallelism` at a time. The first failure drops
+/// the remaining jobs.
+async fn run_fixture_jobs(
+    jobs: Vec<FixtureJob>,
+    parallelism: usize,
+) -> BenchResult<Vec<FixtureTableTiming>> {
+    let permits = Arc::new(Semaphore::new(parallelism));
+    let mut running = JoinSet::new();
+    for job in jobs {
+        let permits = Arc::clone(&permits);
+        running.spawn(async move {
+            let _permit = permits
+                .acquire_owned()
+                .await
+                .expect("fixture job semaphore is never closed");
+            job.await
+        });
+    }
+
+    let mut tables = Vec::new();
+    while let Some(joined) = running.join_next().await {
+        tables.extend(joined.map_err(|error| {
+            BenchError::InvalidArgument(format!("fixture write task failed: {error}"))
+        })??);
+    }
+    Ok(tables)
+}
+
+/// Narrow-sales row count of a scale defined in the scale catalog (`bench/scales.yaml`).
 pub fn scale_to_row_count(scale: &str) -> BenchResult<usize> {
-    match scale {
-        "sf1" => Ok(10_000),
-        "sf10" => Ok(100_000),
-        "sf100" => Ok(1_000_000),
-        _ => Err(BenchError::InvalidArgument(format!(
-            "unknown scale '{scale}' (expected one of: sf1, sf10, sf100)"
-        ))),
+    Ok(scale_definition(scale)?.rows)
+}
+
+/// Nests fixtures under `<fixtures_dir>/<dataset_id>` when a dataset is selected, so datasets
+/// that share a scale keep separate table directories.
+pub fn dataset_fixtures_dir(fixtures_dir: &Path, dataset: Option<DatasetId>) -> PathBuf {
+    match dataset {
+        Some(dataset) => fixtures_dir.join(dataset.as_str()),
+        None => fixtures_dir.to_path_buf(),
     }
 }
 
+/// URL of a custom dataset table, which lives under `<fixtures_dir>/<dataset_id>/<scale>`.
+pub fn custom_dataset_table_url(
+    fixtures_dir: &Path,
+    dataset_id: &str,
+    scale: &str,
+    table: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    let root = fixture_root(&fixtures_dir.join(dataset_id), scale);
+    storage
+        .for_dataset(Some(dataset_id))
+        .table_url_for(&root.join(table), scale, table)
+}
+
 pub fn fixture_root(fixtures_dir: &Path, scale: &str) -> PathBuf {
     fixtures_dir.join(scale)
 }
@@ -200,10 +577,62 @@ pub fn metadata_uncheckpointed_table_path(fixtures_dir: &Path, scale: &str) -> P
     fixture_root(fixtures_dir, scale).join(METADATA_UNCHECKPOINTED_TABLE_DIR)
 }
 
+pub fn metadata_deep_history_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(METADATA_DEEP_HISTORY_TABLE_DIR)
+}
+
+pub fn checkpoint_history_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(CHECKPOINT_HISTORY_TABLE_DIR)
+}
+
+pub fn late_arriving_target_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(LATE_ARRIVING_TARGET_TABLE_DIR)
+}
+
+/// Plain Hive-partitioned Parquet directory with no `_delta_log`, for `convert_to_delta`.
+/// Always written to the local fixtures directory, whatever the storage backend.
+pub fn raw_parquet_partitioned_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(RAW_PARQUET_PARTITIONED_DIR)
+}
+
+pub fn data_skipping_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(DATA_SKIPPING_TABLE_DIR)
+}
+
+pub fn wide_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(WIDE_TABLE_DIR)
+}
+
+pub fn nested_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(NESTED_TABLE_DIR)
+}
+
+pub fn small_files_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale).join(SMALL_FILES_TABLE_DIR)
+}
+
+pub fn late_arriving_backfill_rows_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale)
+        .join(LATE_ARRIVING_DIR)
+        .join(LATE_ARRIVING_BACKFILL_ROWS_FILE)
+}
+
 pub fn tpcds_store_sales_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    tpcds_table_path(fixtures_dir, scale, TPCDS_STORE_SALES_TABLE_DIR)
+}
+
+pub fn tpcds_date_partitioned_store_sales_table_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    tpcds_table_path(
+        fixtures_dir,
+        scale,
+        TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR,
+    )
+}
+
+pub fn tpcds_table_path(fixtures_dir: &Path, scale: &str, table: &str) -> PathBuf {
     fixture_root(fixtures_dir, scale)
         .join(TPCDS_DIR)
-        .join(TPCDS_STORE_SALES_TABLE_DIR)
+        .join(table)
 }
 
 fn required_local_fixture_tables_exist(root: &Path, profile: FixtureProfile) -> bool {
@@ -215,18 +644,34 @@ fn required_local_fixture_tables_exist(root: &Path, profile: FixtureProfile) ->
         OPTIMIZE_SMALL_FILES_TABLE_DIR,
         OPTIMIZE_COMPACTED_TABLE_DIR,
         VACUUM_READY_TABLE_DIR,
+        DATA_SKIPPING_TABLE_DIR,
+        WIDE_TABLE_DIR,
+        NESTED_TABLE_DIR,
         "tpcds/store_sales",
+        "tpcds/store_sales_date_partitioned",
+        "tpcds/date_dim",
+        "tpcds/item",
+        "tpcds/customer",
     ];
     if profile == FixtureProfile::ManyVersions {
         required_tables.extend([
             METADATA_LONG_HISTORY_TABLE_DIR,
             METADATA_CHECKPOINTED_TABLE_DIR,
             METADATA_UNCHECKPOINTED_TABLE_DIR,
+            METADATA_DEEP_HISTORY_TABLE_DIR,
+            CHECKPOINT_HISTORY_TABLE_DIR,
         ]);
     }
+    if profile == FixtureProfile::LateArriving {
+        required_tables.push(LATE_ARRIVING_TARGET_TABLE_DIR);
+    }
+    if profile == FixtureProfile::SmallFiles {
+        required_tables.push(SMALL_FILES_TABLE_DIR);
+    }
     required_tables
         .iter()
         .all(|table| root.join(table).join("_delta_log").exists())
+        && root.join(RAW_PARQUET_PARTITIONED_DIR).is_dir()
 }
 
 pub fn narrow_sales_table_url(
@@ -265,6 +710,38 @@ pub fn read_partitioned_table_url(
     )
 }
 
+pub fn data_skipping_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    storage.table_url_for(
+        &data_skipping_table_path(fixtures_dir, scale),
+        scale,
+        DATA_SKIPPING_TABLE_DIR,
+    )
+}
+
+pub fn wide_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    storage.table_url_for(&wide_table_path(fixtures_dir, scale), scale, WIDE_TABLE_DIR)
+}
+
+pub fn nested_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    storage.table_url_for(
+        &nested_table_path(fixtures_dir, scale),
+        scale,
+        NESTED_TABLE_DIR,
+    )
+}
+
 pub fn merge_partitioned_target_table_url(
     fixtures_dir: &Path,
     scale: &str,
@@ -361,15 +838,72 @@ pub fn metadata_uncheckpointed_table_url(
     )
 }
 
+pub fn metadata_deep_history_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    storage.table_url_for(
+        &metadata_deep_history_table_path(fixtures_dir, scale),
+        scale,
+        METADATA_DEEP_HISTORY_TABLE_DIR,
+    )
+}
+
+pub fn checkpoint_history_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    storage.table_url_for(
+        &checkpoint_history_table_path(fixtures_dir, scale),
+        scale,
+        CHECKPOINT_HISTORY_TABLE_DIR,
+    )
+}
+
+pub fn late_arriving_target_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    storage.table_url_for(
+        &late_arriving_target_table_path(fixtures_dir, scale),
+        scale,
+        LATE_ARRIVING_TARGET_TABLE_DIR,
+    )
+}
+
+pub fn small_files_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    storage.table_url_for(
+        &small_files_table_path(fixtures_dir, scale),
+        scale,
+        SMALL_FILES_TABLE_DIR,
+    )
+}
+
 pub fn tpcds_store_sales_table_url(
     fixtures_dir: &Path,
     scale: &str,
     storage: &StorageConfig,
+) -> BenchResult<Url> {
+    tpcds_table_url(fixtures_dir, scale, TPCDS_STORE_SALES_TABLE_DIR, storage)
+}
+
+fn tpcds_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    table: &str,
+    storage: &StorageConfig,
 ) -> BenchResult<Url> {
     storage.table_url_for(
-        &tpcds_store_sales_table_path(fixtures_dir, scale),
+        &tpcds_table_path(fixtures_dir, scale, table),
         scale,
-        "tpcds/store_sales",
+        &format!("{TPCDS_DIR}/{table}"),
     )
 }
 
@@ -395,7 +929,38 @@ struct TpcdsStoreSalesRow {
     ss_sold_date_sk: i64,
 }
 
-struct FixtureGenerationLock {
+/// Surrogate-key ranges referenced by `tpcds/store_sales`. Each dimension table gets one row per
+/// key in its range, so every fact row joins regardless of which generator produced it.
+#[derive(Clone, Debug, Default)]
+struct TpcdsDimensionKeys {
+    date_sk: Option<RangeInclusive<i64>>,
+    item_sk: Option<RangeInclusive<i64>>,
+    customer_sk: Option<RangeInclusive<i64>>,
+}
+
+impl TpcdsDimensionKeys {
+    fn observe(&mut self, row: &TpcdsStoreSalesRow) {
+        widen_key_range(&mut self.date_sk, row.ss_sold_date_sk);
+        widen_key_range(&mut self.item_sk, row.ss_item_sk);
+        widen_key_range(&mut self.customer_sk, row.ss_customer_sk);
+    }
+}
+
+type TpcdsDimensionBatchFn =
+    fn(RangeInclusive<i64>) -> BenchResult<arrow::record_batch::RecordBatch>;
+
+/// Non-positive keys are the DuckDB export's stand-in for NULL and have no dimension row.
+fn widen_key_range(range: &mut Option<RangeInclusive<i64>>, key: i64) {
+    if key <= 0 {
+        return;
+    }
+    *range = Some(match range.take() {
+        Some(current) => (*current.start()).min(key)..=(*current.end()).max(key),
+        None => key..=key,
+    });
+}
+
+pub(crate) struct FixtureGenerationLock {
     path: PathBuf,
 }
 
@@ -461,7 +1026,59 @@ fn parse_env_u64(name: &str, default: u64) -> BenchResult<u64> {
     })
 }
 
-async fn acquire_fixture_generation_lock(
+/// Tables written concurrently while generating one scale.
+pub fn fixture_parallelism() -> BenchResult<usize> {
+    let parallelism =
+        parse_env_u64(FIXTURE_PARALLELISM_ENV, DEFAULT_FIXTURE_PARALLELISM as u64)? as usize;
+    if parallelism == 0 {
+        return Err(BenchError::InvalidArgument(format!(
+            "{FIXTURE_PARALLELISM_ENV} must be > 0"
+        )));
+    }
+    Ok(parallelism)
+}
+
+/// File count `small_files_delta` reached when the fixture set was generated, as recorded in
+/// its manifest.
+pub fn fixture_small_files_count(fixtures_dir: &Path, scale: &str) -> BenchResult<u64> {
+    load_manifest(fixtures_dir, scale)?
+        .table_summaries
+        .iter()
+        .find(|summary| summary.table == SMALL_FILES_TABLE_DIR)
+        .map(|summary| summary.files)
+        .ok_or_else(|| {
+            BenchError::InvalidArgument(
+                "fixture manifest does not record a small_files_delta table; regenerate fixtures \
+                 with bench data --dataset-id small_files"
+                    .to_string(),
+            )
+        })
+}
+
+/// Value distribution the narrow-sales rows of an existing fixture set were generated with.
+/// Fixture sets from before the distribution knobs were uniform.
+pub fn fixture_distribution(fixtures_dir: &Path, scale: &str) -> BenchResult<DataDistribution> {
+    Ok(load_manifest(fixtures_dir, scale)?
+        .fixture_recipe
+        .map(|recipe| recipe.distribution)
+        .unwrap_or_default())
+}
+
+/// Column count the `wide_delta` table of an existing fixture set was generated with.
+pub fn fixture_wide_table_columns(fixtures_dir: &Path, scale: &str) -> BenchResult<usize> {
+    load_manifest(fixtures_dir, scale)?
+        .fixture_recipe
+        .map(|recipe| recipe.wide_table_columns)
+        .filter(|columns| *columns >= MIN_WIDE_TABLE_COLUMNS)
+        .ok_or_else(|| {
+            BenchError::InvalidArgument(
+                "fixture manifest does not record a wide table; regenerate fixtures with bench data"
+                    .to_string(),
+            )
+        })
+}
+
+pub(crate) async fn acquire_fixture_generation_lock(
     fixtures_dir: &Path,
     scale: &str,
 ) -> BenchResult<FixtureGenerationLock> {
@@ -481,7 +1098,7 @@ async fn acquire_fixture_generation_lock(
     let locks_root = fixtures_dir.join(FIXTURE_LOCK_DIR);
     fs::create_dir_all(&locks_root)?;
     let lock_path = locks_root.join(format!("{scale}.lock"));
-    let start = std::time::Instant::now();
+    let start = Instant::now();
 
     loop {
         match fs::create_dir(&lock_path) {
@@ -541,20 +1158,20 @@ pub async fn generate_file_selection_fixtures(
     storage: &StorageConfig,
 ) -> BenchResult<()> {
     let root = fixture_root(fixtures_dir, scale);
-    let dataset_dir = root.join("narrow_sales");
-    let data_path = dataset_dir.join("rows.jsonl");
+    let dataset_dir = root.join(NARROW_SALES_ROWS_DIR);
+    let data_path = dataset_dir.join(NARROW_SALES_ROWS_FILE);
     let rows = scale_to_row_count(scale)?;
-    let data = generate_narrow_sales_rows(seed, rows);
+    let data = NarrowSalesRows::new(seed, rows);
 
-    if force && root.exists() {
-        fs::remove_dir_all(&root)?;
+    if force {
+        remove_fixture_root(fixtures_dir, &root, force, false)?;
     }
     fs::create_dir_all(&dataset_dir)?;
-    write_rows_jsonl(&data_path, &data)?;
+    write_rows_parquet(&data_path, data.clone())?;
 
     write_delta_table_partitioned_small_files_with_checkpoint_interval(
         read_partitioned_table_url(fixtures_dir, scale, storage)?,
-        &data,
+        data.clone(),
         READ_PARTITION_CHUNK_SIZE,
         &["region"],
         Some(METADATA_CHECKPOINT_INTERVAL),
@@ -563,7 +1180,7 @@ pub async fn generate_file_selection_fixtures(
     .await?;
     write_delta_table_partitioned_small_files_with_checkpoint_interval(
         delete_update_small_files_table_url(fixtures_dir, scale, storage)?,
-        &data,
+        data,
         DELETE_UPDATE_PARTITION_CHUNK_SIZE,
         &["region"],
         Some(METADATA_CHECKPOINT_INTERVAL),
@@ -581,12 +1198,104 @@ pub async fn generate_fixtures_with_profile(
     profile: FixtureProfile,
     storage: &StorageConfig,
 ) -> BenchResult<()> {
+    generate_scale_fixtures(
+        fixtures_dir,
+        scale,
+        seed,
+        force,
+        false,
+        profile,
+        &DataDistribution::default(),
+        &FixtureShape::default(),
+        storage,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Generates fixtures for every requested scale. Each scale streams its rows from the seed, so
+/// smaller scales get a prefix of the larger scales' rows without any scale's rows being held.
+/// `distribution` shapes the narrow-sales columns and `shape` the tables; both are recorded in
+/// each scale's manifest.
+/// With `trash`, replaced scale directories are moved under [`FIXTURE_TRASH_DIR`] instead of
+/// deleted. Returns write timings for the scales that were (re)generated.
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_fixtures_for_scales(
+    fixtures_dir: &Path,
+    scales: &[String],
+    seed: u64,
+    force: bool,
+    trash: bool,
+    profile: FixtureProfile,
+    distribution: &DataDistribution,
+    shape: &FixtureShape,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<FixtureScaleTimings>> {
+    distribution.validate()?;
+    shape.validate()?;
+    for scale in scales {
+        scale_to_row_count(scale)?;
+    }
+    let mut timings = Vec::new();
+    for scale in scales {
+        let scale_timings = generate_scale_fixtures(
+            fixtures_dir,
+            scale,
+            seed,
+            force,
+            trash,
+            profile,
+            distribution,
+            shape,
+            storage,
+        )
+        .await?;
+        timings.extend(scale_timings);
+    }
+    Ok(timings)
+}
+
+/// Parses a comma-separated `--scale` value such as `sf1,sf10`.
+pub fn parse_scale_list(value: &str) -> BenchResult<Vec<String>> {
+    let mut scales = Vec::new();
+    for scale in value.split(',').map(str::trim) {
+        scale_to_row_count(scale)?;
+        if scales.iter().any(|existing| existing == scale) {
+            return Err(BenchError::InvalidArgument(format!(
+                "duplicate scale '{scale}' in '{value}'"
+            )));
+        }
+        scales.push(scale.to_string());
+    }
+    Ok(scales)
+}
+
+/// Every table is written from a fresh clone of the row stream, so no writer holds more than
+/// one chunk of the scale's rows. Up to `DELTA_BENCH_FIXTURE_PARALLELISM` tables are written at
+/// once. Returns `None` when the existing fixtures already match the request.
+#[allow(clippy::too_many_arguments)]
+async fn generate_scale_fixtures(
+    fixtures_dir: &Path,
+    scale: &str,
+    seed: u64,
+    force: bool,
+    trash: bool,
+    profile: FixtureProfile,
+    distribution: &DataDistribution,
+    shape: &FixtureShape,
+    storage: &StorageConfig,
+) -> BenchResult<Option<FixtureScaleTimings>> {
     let root = fixture_root(fixtures_dir, scale);
-    let dataset_dir = root.join("narrow_sales");
-    let data_path = dataset_dir.join("rows.jsonl");
+    let dataset_dir = root.join(NARROW_SALES_ROWS_DIR);
+    let data_path = dataset_dir.join(NARROW_SALES_ROWS_FILE);
     let manifest_path = root.join("manifest.json");
-    let rows = scale_to_row_count(scale)?;
+    let definition = scale_definition(scale)?;
+    let rows = definition.rows;
+    let commit_max_rows = definition.commit_max_rows();
     let table_inventory = fixture_table_inventory(profile);
+    let wide_table_columns = shape.wide_table_columns;
+    let parallelism = fixture_parallelism()?;
+    let small_files = (profile == FixtureProfile::SmallFiles).then_some(shape.small_files);
 
     if !force
         && profile != FixtureProfile::TpcdsDuckdb
@@ -596,16 +1305,25 @@ pub async fn generate_fixtures_with_profile(
             seed,
             rows,
             profile,
-            &table_inventory,
+            &build_fixture_recipe(
+                seed,
+                definition,
+                profile,
+                table_inventory.clone(),
+                wide_table_columns,
+                None,
+                distribution,
+                small_files,
+            ),
             storage,
         )
     {
-        return Ok(());
+        return Ok(None);
     }
 
     let _scale_lock = acquire_fixture_generation_lock(fixtures_dir, scale).await?;
 
-    let data = generate_narrow_sales_rows(seed, rows);
+    let data = NarrowSalesRows::new(seed, rows).with_distribution(distribution);
     let prepared_tpcds_duckdb = if profile == FixtureProfile::TpcdsDuckdb {
         Some(prepare_tpcds_duckdb_source(scale).await?)
     } else {
@@ -613,16 +1331,18 @@ pub async fn generate_fixtures_with_profile(
     };
     let fixture_recipe = build_fixture_recipe(
         seed,
-        scale,
-        rows,
+        definition,
         profile,
         table_inventory.clone(),
+        wide_table_columns,
         prepared_tpcds_duckdb
             .as_ref()
             .map(|prepared| prepared.source_hash.clone()),
+        distribution,
+        small_files,
     );
     let fixture_recipe_hash = hash_json(&fixture_recipe)?;
-    let dataset_fingerprint = compute_dataset_fingerprint(&fixture_recipe, &data)?;
+    let rows_hash = hash_json_seq(data.clone())?;
 
     if !force
         && existing_fixtures_match_full_request(
@@ -632,154 +1352,486 @@ pub async fn generate_fixtures_with_profile(
             rows,
             profile,
             &fixture_recipe_hash,
-            &dataset_fingerprint,
+            &rows_hash,
             storage,
         )
     {
-        return Ok(());
+        return Ok(None);
     }
 
-    if root.exists() {
-        fs::remove_dir_all(&root)?;
-    }
+    remove_fixture_root(fixtures_dir, &root, force, trash)?;
     fs::create_dir_all(&dataset_dir)?;
-    write_rows_jsonl(&data_path, &data)?;
 
-    write_delta_table(
-        narrow_sales_table_url(fixtures_dir, scale, storage)?,
-        &data,
-        storage,
-    )
-    .await?;
+    // Every job reads its own clone of the row stream and runs on its own task, so derived
+    // tables are written side by side. Tables that build on another table's commits stay ordered
+    // within a single job.
+    let started = Instant::now();
+    let mut jobs: Vec<FixtureJob> = vec![
+        blocking_fixture_job(NARROW_SALES_ROWS_DIR, {
+            let (data_path, data) = (data_path.clone(), data.clone());
+            move || write_rows_parquet(&data_path, data)
+        }),
+        timed_fixture_job(NARROW_SALES_TABLE_DIR, {
+            let table_url = narrow_sales_table_url(fixtures_dir, scale, storage)?;
+            let (data, storage) = (data.clone(), storage.clone());
+            async move {
+                write_delta_table_in_commits(
+                    table_url.clone(),
+                    data.clone(),
+                    commit_max_rows,
+                    &storage,
+                )
+                .await?;
+                if profile == FixtureProfile::ManyVersions {
+                    write_many_narrow_sales_versions(table_url, data, &storage).await?;
+                }
+                Ok(())
+            }
+        }),
+    ];
     if profile == FixtureProfile::ManyVersions {
-        write_many_narrow_sales_versions(
-            narrow_sales_table_url(fixtures_dir, scale, storage)?,
-            &data,
-            storage,
-        )
-        .await?;
-        write_metadata_history_tables(fixtures_dir, scale, &data, &fixture_recipe, storage).await?;
+        jobs.push(timed_fixture_job(
+            [
+                METADATA_LONG_HISTORY_TABLE_DIR,
+                METADATA_CHECKPOINTED_TABLE_DIR,
+                METADATA_UNCHECKPOINTED_TABLE_DIR,
+                METADATA_DEEP_HISTORY_TABLE_DIR,
+                CHECKPOINT_HISTORY_TABLE_DIR,
+            ]
+            .join(", "),
+            {
+                let (fixtures_dir, scale) = (fixtures_dir.to_path_buf(), scale.to_string());
+                let (data, recipe, storage) =
+                    (data.clone(), fixture_recipe.clone(), storage.clone());
+                async move {
+                    write_metadata_history_tables(&fixtures_dir, &scale, data, &recipe, &storage)
+                        .await
+                }
+            },
+        ));
+    }
+    if profile == FixtureProfile::LateArriving {
+        jobs.push(timed_fixture_job(LATE_ARRIVING_TARGET_TABLE_DIR, {
+            let (fixtures_dir, scale) = (fixtures_dir.to_path_buf(), scale.to_string());
+            let (data, storage) = (data.clone(), storage.clone());
+            async move {
+                write_late_arriving_fixtures(&fixtures_dir, &scale, seed, data, &storage).await
+            }
+        }));
+    }
+    if let Some(shape) = small_files {
+        jobs.push(timed_fixture_job(SMALL_FILES_TABLE_DIR, {
+            let table_url = small_files_table_url(fixtures_dir, scale, storage)?;
+            let rows = NarrowSalesRows::new(seed, shape.rows()).with_distribution(distribution);
+            let storage = storage.clone();
+            async move {
+                write_small_files_table(table_url, rows, shape, commit_max_rows, &storage).await
+            }
+        }));
     }
 
-    write_delta_table_partitioned_small_files(
-        read_partitioned_table_url(fixtures_dir, scale, storage)?,
-        &data,
-        READ_PARTITION_CHUNK_SIZE,
-        &["region"],
-        storage,
-    )
-    .await?;
-
-    let merge_rows = data
-        .iter()
-        .take(fixture_recipe.merge_seed_rows)
-        .cloned()
-        .collect::<Vec<_>>();
-    write_delta_table(
-        merge_target_table_url(fixtures_dir, scale, storage)?,
-        &merge_rows,
-        storage,
-    )
-    .await?;
+    let merge_rows = data.clone().take(fixture_recipe.merge_seed_rows);
+    let optimize_rows = data.clone().take(fixture_recipe.optimize_seed_rows);
+    jobs.extend([
+        timed_fixture_job(READ_PARTITIONED_TABLE_DIR, {
+            let table_url = read_partitioned_table_url(fixtures_dir, scale, storage)?;
+            let (rows, storage) = (data.clone(), storage.clone());
+            async move {
+                write_delta_table_partitioned_small_files(
+                    table_url,
+                    rows,
+                    READ_PARTITION_CHUNK_SIZE,
+                    &["region"],
+                    &storage,
+                )
+                .await
+            }
+        }),
+        timed_fixture_job(MERGE_TARGET_TABLE_DIR, {
+            let table_url = merge_target_table_url(fixtures_dir, scale, storage)?;
+            let (rows, storage) = (merge_rows.clone(), storage.clone());
+            async move {
+                write_delta_table_in_commits(table_url, rows, commit_max_rows, &storage).await
+            }
+        }),
+        timed_fixture_job(MERGE_PARTITIONED_TARGET_TABLE_DIR, {
+            let table_url = merge_partitioned_target_table_url(fixtures_dir, scale, storage)?;
+            let storage = storage.clone();
+            async move {
+                write_delta_table_partitioned_small_files(
+                    table_url,
+                    merge_rows,
+                    MERGE_PARTITION_CHUNK_SIZE,
+                    &["region"],
+                    &storage,
+                )
+                .await
+            }
+        }),
+        timed_fixture_job(DELETE_UPDATE_SMALL_FILES_TABLE_DIR, {
+            let table_url = delete_update_small_files_table_url(fixtures_dir, scale, storage)?;
+            let (rows, storage) = (data.clone(), storage.clone());
+            async move {
+                write_delta_table_partitioned_small_files_with_checkpoint_interval(
+                    table_url,
+                    rows,
+                    DELETE_UPDATE_PARTITION_CHUNK_SIZE,
+                    &["region"],
+                    Some(METADATA_CHECKPOINT_INTERVAL),
+                    &storage,
+                )
+                .await
+            }
+        }),
+        timed_fixture_job(OPTIMIZE_SMALL_FILES_TABLE_DIR, {
+            let table_url = optimize_small_files_table_url(fixtures_dir, scale, storage)?;
+            let (rows, storage) = (optimize_rows.clone(), storage.clone());
+            async move {
+                write_delta_table_small_files(
+                    table_url,
+                    rows,
+                    OPTIMIZE_SMALL_FILES_CHUNK_SIZE,
+                    &storage,
+                )
+                .await
+            }
+        }),
+        timed_fixture_job(OPTIMIZE_COMPACTED_TABLE_DIR, {
+            let table_url = optimize_compacted_table_url(fixtures_dir, scale, storage)?;
+            let storage = storage.clone();
+            async move {
+                write_delta_table_in_commits(table_url, optimize_rows, commit_max_rows, &storage)
+                    .await
+            }
+        }),
+        timed_fixture_job(VACUUM_READY_TABLE_DIR, {
+            let table_url = vacuum_ready_table_url(fixtures_dir, scale, storage)?;
+            let rows = data.clone().take(fixture_recipe.vacuum_seed_rows);
+            let storage = storage.clone();
+            async move { write_vacuum_ready_table(table_url, rows, &storage).await }
+        }),
+        blocking_fixture_job(RAW_PARQUET_PARTITIONED_DIR, {
+            let path = raw_parquet_partitioned_path(fixtures_dir, scale);
+            let rows = data.clone();
+            move || write_raw_parquet_partitioned(&path, rows, RAW_PARQUET_FILES_PER_PARTITION)
+        }),
+        timed_fixture_job(DATA_SKIPPING_TABLE_DIR, {
+            let table_url = data_skipping_table_url(fixtures_dir, scale, storage)?;
+            let storage = storage.clone();
+            async move { write_data_skipping_table(table_url, rows, &storage).await }
+        }),
+        timed_fixture_job(WIDE_TABLE_DIR, {
+            let table_url = wide_table_url(fixtures_dir, scale, storage)?;
+            let table_rows = fixture_recipe.wide_table_rows;
+            let storage = storage.clone();
+            async move {
+                write_wide_table(table_url, table_rows, wide_table_columns, &storage).await
+            }
+        }),
+        timed_fixture_job(NESTED_TABLE_DIR, {
+            let table_url = nested_table_url(fixtures_dir, scale, storage)?;
+            let table_rows = fixture_recipe.nested_table_rows;
+            let storage = storage.clone();
+            async move { write_nested_table(table_url, table_rows, &storage).await }
+        }),
+    ]);
+
+    jobs.push({
+        let (fixtures_dir, scale) = (fixtures_dir.to_path_buf(), scale.to_string());
+        let (data, storage) = (data.clone(), storage.clone());
+        async move {
+            write_tpcds_fixture_tables(
+                &fixtures_dir,
+                &scale,
+                profile,
+                data,
+                prepared_tpcds_duckdb.as_ref(),
+                commit_max_rows,
+                &storage,
+            )
+            .await
+        }
+        .boxed()
+    });
 
-    write_delta_table_partitioned_small_files(
-        merge_partitioned_target_table_url(fixtures_dir, scale, storage)?,
-        &merge_rows,
-        MERGE_PARTITION_CHUNK_SIZE,
-        &["region"],
-        storage,
-    )
-    .await?;
+    let mut tables = run_fixture_jobs(jobs, parallelism).await?;
+    tables.sort_by(|left, right| left.table.cmp(&right.table));
+    let timings = FixtureScaleTimings {
+        scale: scale.to_string(),
+        tables,
+        elapsed_ms: started.elapsed().as_secs_f64() * 1_000.0,
+    };
 
-    write_delta_table_partitioned_small_files_with_checkpoint_interval(
-        delete_update_small_files_table_url(fixtures_dir, scale, storage)?,
-        &data,
-        DELETE_UPDATE_PARTITION_CHUNK_SIZE,
-        &["region"],
-        Some(METADATA_CHECKPOINT_INTERVAL),
-        storage,
-    )
-    .await?;
+    let table_summaries =
+        summarize_fixture_tables(fixtures_dir, scale, &table_inventory, storage).await?;
+    let dataset_fingerprint = compute_dataset_fingerprint(
+        &fixture_recipe_hash,
+        profile.as_str(),
+        &rows_hash,
+        &table_summaries,
+    )?;
+    let manifest = FixtureManifest {
+        schema_version: FIXTURE_SCHEMA_VERSION,
+        generator_version: FIXTURE_GENERATOR_VERSION,
+        seed,
+        scale: scale.to_string(),
+        rows,
+        profile: profile.as_str().to_string(),
+        dataset_fingerprint,
+        rows_hash,
+        table_inventory,
+        table_summaries,
+        fixture_recipe_hash,
+        fixture_recipe: Some(fixture_recipe),
+    };
+    fs::write(manifest_path, serde_json::to_vec_pretty(&manifest)?)?;
 
-    let optimize_rows = data
-        .iter()
-        .take(fixture_recipe.optimize_seed_rows)
-        .cloned()
-        .collect::<Vec<_>>();
-    write_delta_table_small_files(
-        optimize_small_files_table_url(fixtures_dir, scale, storage)?,
-        &optimize_rows,
-        OPTIMIZE_SMALL_FILES_CHUNK_SIZE,
-        storage,
-    )
-    .await?;
+    Ok(Some(timings))
+}
 
-    write_delta_table(
-        optimize_compacted_table_url(fixtures_dir, scale, storage)?,
-        &optimize_rows,
-        storage,
-    )
-    .await?;
+/// Everything a custom dataset's tables are generated from.
+#[derive(serde::Serialize)]
+struct CustomDatasetRecipe<'a> {
+    schema_version: u32,
+    dataset_id: &'a str,
+    dataset_version: u32,
+    seed: u64,
+    scale: &'a str,
+    rows: usize,
+    commit_max_rows: usize,
+    schema_hash: String,
+    tables: &'a [DatasetTableLayout],
+}
 
-    let vacuum_rows = data
-        .iter()
-        .take(fixture_recipe.vacuum_seed_rows)
-        .cloned()
-        .collect::<Vec<_>>();
-    write_vacuum_ready_table(
-        vacuum_ready_table_url(fixtures_dir, scale, storage)?,
-        &vacuum_rows,
-        storage,
-    )
-    .await?;
+/// Generates a custom dataset at `scale` under `<fixtures_dir>/<dataset_id>` and writes its
+/// manifest, which [`load_manifest`] reads like a built-in dataset's. The manifest's profile is
+/// the dataset id. Existing fixtures from the same recipe and rows are kept unless `force` is
+/// set.
+pub async fn generate_custom_dataset_fixtures(
+    fixtures_dir: &Path,
+    dataset: &dyn DatasetGenerator,
+    scale: &str,
+    seed: u64,
+    force: bool,
+    storage: &StorageConfig,
+) -> BenchResult<FixtureManifest> {
+    validate_dataset_generator(dataset)?;
+    let dataset_id = dataset.id();
+    let dataset_dir = fixtures_dir.join(dataset_id);
+    let dataset_storage = storage.for_dataset(Some(dataset_id));
+    let root = fixture_root(&dataset_dir, scale);
+    let definition = scale_definition(scale)?;
+    let rows = definition.rows;
+    let commit_max_rows = definition.commit_max_rows();
+    let tables = dataset.tables();
+    let fixture_recipe_hash = hash_json(&CustomDatasetRecipe {
+        schema_version: FIXTURE_SCHEMA_VERSION,
+        dataset_id,
+        dataset_version: dataset.version(),
+        seed,
+        scale,
+        rows,
+        commit_max_rows,
+        schema_hash: hash_arrow_schema(&dataset.schema())?,
+        tables: &tables,
+    })?;
 
-    let tpcds_store_sales_table_url = tpcds_store_sales_table_url(fixtures_dir, scale, storage)?;
-    match profile {
-        FixtureProfile::TpcdsDuckdb => {
-            let prepared = prepared_tpcds_duckdb
-                .as_ref()
-                .expect("prepared DuckDB source for tpcds_duckdb profile");
-            write_tpcds_store_sales_csv_table(
-                tpcds_store_sales_table_url,
-                prepared.csv_path.as_path(),
-                storage,
-            )
-            .await?;
-        }
-        FixtureProfile::Standard | FixtureProfile::ManyVersions => {
-            write_tpcds_store_sales_table(tpcds_store_sales_table_url, &data, storage).await?;
+    let _scale_lock = acquire_fixture_generation_lock(&dataset_dir, scale).await?;
+
+    let rows_hash = hash_json_seq(
+        custom_dataset_batches(dataset, seed, 0..rows)
+            .map(|batch| hash_record_batches_unordered(&[batch?]))
+            .collect::<BenchResult<Vec<_>>>()?,
+    )?;
+    if !force {
+        if let Some(existing) = existing_fixture_manifest(&dataset_dir, scale).filter(|existing| {
+            existing.schema_version == FIXTURE_SCHEMA_VERSION
+                && existing.seed == seed
+                && existing.profile == dataset_id
+                && existing.fixture_recipe_hash == fixture_recipe_hash
+                && existing.rows_hash == rows_hash
+                && manifest_fingerprint_matches(existing)
+        }) {
+            return Ok(existing);
         }
     }
 
+    remove_fixture_root(fixtures_dir, &root, force, false)?;
+    fs::create_dir_all(&root)?;
+    for table in &tables {
+        write_custom_dataset_table(
+            custom_dataset_table_url(fixtures_dir, dataset_id, scale, &table.name, storage)?,
+            dataset,
+            seed,
+            table.rows.unwrap_or(rows).min(rows),
+            commit_max_rows,
+            &table.partition_columns,
+            &dataset_storage,
+        )
+        .await?;
+    }
+
+    let table_inventory = tables
+        .iter()
+        .map(|table| table.name.clone())
+        .collect::<Vec<_>>();
+    let table_summaries =
+        summarize_fixture_tables(&dataset_dir, scale, &table_inventory, &dataset_storage).await?;
+    let dataset_fingerprint = compute_dataset_fingerprint(
+        &fixture_recipe_hash,
+        dataset_id,
+        &rows_hash,
+        &table_summaries,
+    )?;
     let manifest = FixtureManifest {
         schema_version: FIXTURE_SCHEMA_VERSION,
-        generator_version: FIXTURE_GENERATOR_VERSION,
+        generator_version: dataset.version(),
         seed,
         scale: scale.to_string(),
         rows,
-        profile: profile.as_str().to_string(),
+        profile: dataset_id.to_string(),
         dataset_fingerprint,
+        rows_hash,
         table_inventory,
+        table_summaries,
         fixture_recipe_hash,
-        fixture_recipe: Some(fixture_recipe),
+        fixture_recipe: None,
     };
-    fs::write(manifest_path, serde_json::to_vec_pretty(&manifest)?)?;
+    fs::write(
+        root.join("manifest.json"),
+        serde_json::to_vec_pretty(&manifest)?,
+    )?;
+    Ok(manifest)
+}
 
+/// Rows `range` of a custom dataset, requested from the generator in batches of
+/// [`CUSTOM_DATASET_BATCH_ROWS`]. Batches that do not match the declared schema or length are
+/// rejected.
+fn custom_dataset_batches<'a>(
+    dataset: &'a dyn DatasetGenerator,
+    seed: u64,
+    range: std::ops::Range<usize>,
+) -> impl Iterator<Item = BenchResult<arrow::record_batch::RecordBatch>> + 'a {
+    let schema = dataset.schema();
+    let end = range.end;
+    range.step_by(CUSTOM_DATASET_BATCH_ROWS).map(move |offset| {
+        let len = CUSTOM_DATASET_BATCH_ROWS.min(end - offset);
+        let batch = dataset.generate_batch(seed, offset, len)?;
+        if batch.schema() != schema {
+            return Err(BenchError::InvalidArgument(format!(
+                "dataset '{}' generated a batch whose schema differs from its declared schema",
+                dataset.id()
+            )));
+        }
+        if batch.num_rows() != len {
+            return Err(BenchError::InvalidArgument(format!(
+                "dataset '{}' generated {} rows at offset {offset}, expected {len}",
+                dataset.id(),
+                batch.num_rows()
+            )));
+        }
+        Ok(batch)
+    })
+}
+
+/// Writes the first `rows` rows of a custom dataset, one commit per `commit_max_rows` rows.
+async fn write_custom_dataset_table(
+    table_url: Url,
+    dataset: &dyn DatasetGenerator,
+    seed: u64,
+    rows: usize,
+    commit_max_rows: usize,
+    partition_columns: &[String],
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    prepare_local_table_dir(&table_url)?;
+
+    let mut table = storage.try_from_url_for_write(table_url).await?;
+    for (idx, start) in (0..rows).step_by(commit_max_rows.max(1)).enumerate() {
+        let end = (start + commit_max_rows).min(rows);
+        let batches =
+            custom_dataset_batches(dataset, seed, start..end).collect::<BenchResult<Vec<_>>>()?;
+        let mode = if idx == 0 {
+            SaveMode::Overwrite
+        } else {
+            SaveMode::Append
+        };
+        let mut writer = table.write(batches).with_save_mode(mode);
+        if !partition_columns.is_empty() {
+            writer = writer.with_partition_columns(partition_columns.iter().cloned());
+        }
+        table = writer.await?;
+    }
     Ok(())
 }
 
+/// The dimension tables take their keys from `store_sales`, and the date-partitioned copy is
+/// read back from it, so the TPC-DS tables are written in order and timed one by one.
+async fn write_tpcds_fixture_tables(
+    fixtures_dir: &Path,
+    scale: &str,
+    profile: FixtureProfile,
+    data: NarrowSalesRows,
+    prepared_tpcds_duckdb: Option<&PreparedTpcdsDuckdbSource>,
+    commit_max_rows: usize,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<FixtureTableTiming>> {
+    let mut timings = Vec::new();
+    let started = Instant::now();
+    let store_sales_url = tpcds_store_sales_table_url(fixtures_dir, scale, storage)?;
+    let tpcds_keys = match profile {
+        FixtureProfile::TpcdsDuckdb => {
+            let prepared =
+                prepared_tpcds_duckdb.expect("prepared DuckDB source for tpcds_duckdb profile");
+            write_tpcds_store_sales_csv_table(store_sales_url, prepared.csv_path.as_path(), storage)
+                .await?
+        }
+        FixtureProfile::Standard
+        | FixtureProfile::ManyVersions
+        | FixtureProfile::LateArriving
+        | FixtureProfile::SmallFiles => {
+            write_tpcds_store_sales_table(store_sales_url, data, commit_max_rows, storage).await?
+        }
+    };
+    timings.push(FixtureTableTiming::since(
+        format!("{TPCDS_DIR}/{TPCDS_STORE_SALES_TABLE_DIR}"),
+        started,
+    ));
+
+    let started = Instant::now();
+    write_tpcds_dimension_tables(fixtures_dir, scale, &tpcds_keys, storage).await?;
+    timings.push(FixtureTableTiming::since(
+        [
+            TPCDS_DATE_DIM_TABLE_DIR,
+            TPCDS_ITEM_TABLE_DIR,
+            TPCDS_CUSTOMER_TABLE_DIR,
+        ]
+        .map(|table| format!("{TPCDS_DIR}/{table}"))
+        .join(", "),
+        started,
+    ));
+
+    let started = Instant::now();
+    write_tpcds_date_partitioned_store_sales(fixtures_dir, scale, commit_max_rows, storage).await?;
+    timings.push(FixtureTableTiming::since(
+        format!("{TPCDS_DIR}/{TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR}"),
+        started,
+    ));
+    Ok(timings)
+}
+
+/// `fixture_recipe` is the recipe without any profile component hash, which is only known after
+/// preparing the profile's source data.
 fn existing_fixtures_match_static_request(
     fixtures_dir: &Path,
     scale: &str,
     seed: u64,
     rows: usize,
     profile: FixtureProfile,
-    table_inventory: &[String],
+    fixture_recipe: &FixtureRecipe,
     storage: &StorageConfig,
 ) -> bool {
-    let fixture_recipe_hash =
-        build_fixture_recipe(seed, scale, rows, profile, table_inventory.to_vec(), None);
-    let fixture_recipe_hash = hash_json(&fixture_recipe_hash).unwrap_or_default();
+    let fixture_recipe_hash = hash_json(fixture_recipe).unwrap_or_default();
     existing_fixture_manifest(fixtures_dir, scale)
         .map(|existing| {
             existing_fixture_manifest_matches(
@@ -803,7 +1855,7 @@ fn existing_fixtures_match_full_request(
     rows: usize,
     profile: FixtureProfile,
     fixture_recipe_hash: &str,
-    dataset_fingerprint: &str,
+    rows_hash: &str,
     storage: &StorageConfig,
 ) -> bool {
     existing_fixture_manifest(fixtures_dir, scale)
@@ -817,11 +1869,64 @@ fn existing_fixtures_match_full_request(
                 profile,
                 fixture_recipe_hash,
                 storage,
-            ) && existing.dataset_fingerprint == dataset_fingerprint
+            ) && existing.rows_hash == rows_hash
+                && manifest_fingerprint_matches(&existing)
         })
         .unwrap_or(false)
 }
 
+/// Clears a scale directory before it is regenerated or replaced. `root` must resolve to a
+/// directory inside `fixtures_dir`, so a symlink or a mistyped `--fixtures-dir` cannot point
+/// the deletion elsewhere, and without `force` it must hold a readable fixture manifest. With
+/// `trash` the directory is moved under [`FIXTURE_TRASH_DIR`] instead of deleted.
+pub(crate) fn remove_fixture_root(
+    fixtures_dir: &Path,
+    root: &Path,
+    force: bool,
+    trash: bool,
+) -> BenchResult<()> {
+    if fs::symlink_metadata(root).is_err() {
+        return Ok(());
+    }
+    let resolved_root = root.canonicalize()?;
+    let resolved_fixtures_dir = fixtures_dir.canonicalize()?;
+    if resolved_root == resolved_fixtures_dir || !resolved_root.starts_with(&resolved_fixtures_dir)
+    {
+        return Err(BenchError::InvalidArgument(format!(
+            "refusing to delete '{}': it resolves to '{}', outside the fixtures dir '{}'",
+            root.display(),
+            resolved_root.display(),
+            resolved_fixtures_dir.display()
+        )));
+    }
+    let manifest_readable = fs::read(root.join("manifest.json"))
+        .ok()
+        .is_some_and(|bytes| serde_json::from_slice::<FixtureManifest>(&bytes).is_ok());
+    if !force && !manifest_readable {
+        return Err(BenchError::InvalidArgument(format!(
+            "refusing to delete '{}': it has no readable fixture manifest, so it may not hold fixtures (or an earlier generation was interrupted); check --fixtures-dir or pass --force to replace it",
+            root.display()
+        )));
+    }
+
+    if !trash {
+        fs::remove_dir_all(root)?;
+        return Ok(());
+    }
+    let trash_dir = fixtures_dir.join(FIXTURE_TRASH_DIR);
+    fs::create_dir_all(&trash_dir)?;
+    let name = root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "fixtures".to_string());
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    fs::rename(root, trash_dir.join(format!("{name}-{stamp}")))?;
+    Ok(())
+}
+
 fn existing_fixture_manifest(fixtures_dir: &Path, scale: &str) -> Option<FixtureManifest> {
     load_manifest(fixtures_dir, scale).ok()
 }
@@ -839,6 +1944,8 @@ fn existing_fixture_manifest_matches(
     let root = fixture_root(fixtures_dir, scale);
     let local_tables_ready =
         !storage.is_local() || required_local_fixture_tables_exist(&root, profile);
+    // Fixture sets from before the Parquet row snapshot only carry `rows.jsonl`.
+    let rows_ready = narrow_sales_rows_path(fixtures_dir, scale).exists();
 
     existing.schema_version == FIXTURE_SCHEMA_VERSION
         && existing.seed == seed
@@ -847,6 +1954,7 @@ fn existing_fixture_manifest_matches(
         && existing.profile == profile.as_str()
         && recipe_hash_matches(existing, fixture_recipe_hash)
         && local_tables_ready
+        && rows_ready
 }
 
 fn recipe_hash_matches(existing: &FixtureManifest, fixture_recipe_hash: &str) -> bool {
@@ -856,65 +1964,174 @@ fn recipe_hash_matches(existing: &FixtureManifest, fixture_recipe_hash: &str) ->
     existing.generator_version == FIXTURE_GENERATOR_VERSION
 }
 
-fn write_rows_jsonl(path: &Path, rows: &[NarrowSaleRow]) -> BenchResult<()> {
-    let mut file = fs::File::create(path)?;
-    for row in rows {
-        let line = serde_json::to_string(row)?;
-        file.write_all(line.as_bytes())?;
-        file.write_all(b"\n")?;
+/// Splits a row stream into owned chunks of at most `chunk_size` rows, so writers hold one
+/// chunk at a time however long the stream is.
+fn row_chunks<R: Borrow<NarrowSaleRow>>(
+    rows: impl IntoIterator<Item = R>,
+    chunk_size: usize,
+) -> impl Iterator<Item = Vec<NarrowSaleRow>> {
+    let chunk_size = chunk_size.max(1);
+    let mut rows = rows.into_iter();
+    std::iter::from_fn(move || {
+        let chunk = rows
+            .by_ref()
+            .take(chunk_size)
+            .map(|row| row.borrow().clone())
+            .collect::<Vec<_>>();
+        (!chunk.is_empty()).then_some(chunk)
+    })
+}
+
+fn write_rows_parquet<I>(path: &Path, rows: I) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    let nulls = NarrowSalesNulls::of_rows(rows.clone());
+    let file = fs::File::create(path)?;
+    let mut writer = ArrowWriter::try_new(file, narrow_sales_schema(nulls), None)
+        .map_err(|error| BenchError::Delta(error.into()))?;
+    for chunk in row_chunks(rows, ROWS_FILE_BATCH_SIZE) {
+        writer
+            .write(&rows_to_batch_with_nulls(&chunk, nulls)?)
+            .map_err(|error| BenchError::Delta(error.into()))?;
     }
+    writer
+        .close()
+        .map_err(|error| BenchError::Delta(error.into()))?;
     Ok(())
 }
 
-pub(crate) async fn write_delta_table(
-    table_url: Url,
-    rows: &[NarrowSaleRow],
-    storage: &StorageConfig,
+fn write_rows_jsonl(
+    path: &Path,
+    batches: impl Iterator<Item = BenchResult<arrow::record_batch::RecordBatch>>,
 ) -> BenchResult<()> {
-    prepare_local_table_dir(&table_url)?;
-
-    let batch = rows_to_batch(rows)?;
-    let _ = storage
-        .try_from_url_for_write(table_url)
-        .await?
-        .write(vec![batch])
-        .with_save_mode(SaveMode::Overwrite)
-        .await?;
-
+    let mut file = std::io::BufWriter::new(fs::File::create(path)?);
+    for batch in batches {
+        for row in batch_to_rows(&batch?)? {
+            let line = serde_json::to_string(&row)?;
+            file.write_all(line.as_bytes())?;
+            file.write_all(b"\n")?;
+        }
+    }
+    file.flush()?;
     Ok(())
 }
 
-pub(crate) async fn write_delta_table_small_files(
+/// Writes one commit per `commit_rows` rows: the first overwrites the table and carries
+/// `configuration`, the rest append. An empty stream still commits an empty table. `nulls`
+/// covers the whole stream, since the first commit fixes the table schema.
+async fn write_narrow_sales_commits<R: Borrow<NarrowSaleRow>>(
     table_url: Url,
-    rows: &[NarrowSaleRow],
-    chunk_size: usize,
+    rows: impl IntoIterator<Item = R>,
+    commit_rows: usize,
+    partition_columns: &[&str],
+    configuration: &[(&str, Option<&str>)],
+    nulls: NarrowSalesNulls,
     storage: &StorageConfig,
 ) -> BenchResult<()> {
     prepare_local_table_dir(&table_url)?;
 
+    let mut chunks = row_chunks(rows, commit_rows);
+    let first = chunks.next().unwrap_or_default();
     let mut table = storage.try_from_url_for_write(table_url).await?;
-    for (idx, chunk) in rows.chunks(chunk_size).enumerate() {
+    for (idx, chunk) in std::iter::once(first).chain(chunks).enumerate() {
         let mode = if idx == 0 {
             SaveMode::Overwrite
         } else {
             SaveMode::Append
         };
-        table = table
-            .write(vec![rows_to_batch(chunk)?])
-            .with_save_mode(mode)
-            .await?;
+        let mut writer = table
+            .write(vec![rows_to_batch_with_nulls(&chunk, nulls)?])
+            .with_save_mode(mode);
+        if !partition_columns.is_empty() {
+            writer = writer.with_partition_columns(partition_columns.iter().copied());
+        }
+        if idx == 0 && !configuration.is_empty() {
+            writer = writer.with_configuration(configuration.iter().copied());
+        }
+        table = writer.await?;
     }
 
     Ok(())
 }
 
-pub(crate) async fn write_delta_table_partitioned_small_files(
+/// Writes `rows` in a single commit, or one commit per [`FIXTURE_COMMIT_MAX_ROWS`] rows for
+/// scales above that.
+pub(crate) async fn write_delta_table<I>(
     table_url: Url,
-    rows: &[NarrowSaleRow],
+    rows: I,
+    storage: &StorageConfig,
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    write_delta_table_in_commits(table_url, rows, FIXTURE_COMMIT_MAX_ROWS, storage).await
+}
+
+/// Writes `rows` in commits of at most `commit_max_rows` rows, the scale's catalog setting.
+async fn write_delta_table_in_commits<I>(
+    table_url: Url,
+    rows: I,
+    commit_max_rows: usize,
+    storage: &StorageConfig,
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    let nulls = NarrowSalesNulls::of_rows(rows.clone());
+    write_narrow_sales_commits(table_url, rows, commit_max_rows, &[], &[], nulls, storage).await
+}
+
+pub(crate) async fn write_delta_table_with_deletion_vectors<I>(
+    table_url: Url,
+    rows: I,
+    storage: &StorageConfig,
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    let nulls = NarrowSalesNulls::of_rows(rows.clone());
+    write_narrow_sales_commits(
+        table_url,
+        rows,
+        FIXTURE_COMMIT_MAX_ROWS,
+        &[],
+        &[("delta.enableDeletionVectors", Some("true"))],
+        nulls,
+        storage,
+    )
+    .await
+}
+
+pub(crate) async fn write_delta_table_small_files<I>(
+    table_url: Url,
+    rows: I,
+    chunk_size: usize,
+    storage: &StorageConfig,
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    let nulls = NarrowSalesNulls::of_rows(rows.clone());
+    write_narrow_sales_commits(table_url, rows, chunk_size, &[], &[], nulls, storage).await
+}
+
+pub(crate) async fn write_delta_table_partitioned_small_files<I>(
+    table_url: Url,
+    rows: I,
     chunk_size: usize,
     partition_columns: &[&str],
     storage: &StorageConfig,
-) -> BenchResult<()> {
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
     write_delta_table_partitioned_small_files_with_checkpoint_interval(
         table_url,
         rows,
@@ -926,49 +2143,479 @@ pub(crate) async fn write_delta_table_partitioned_small_files(
     .await
 }
 
-async fn write_delta_table_partitioned_small_files_with_checkpoint_interval(
+async fn write_delta_table_partitioned_small_files_with_checkpoint_interval<I>(
     table_url: Url,
-    rows: &[NarrowSaleRow],
+    rows: I,
     chunk_size: usize,
     partition_columns: &[&str],
     checkpoint_interval: Option<&str>,
     storage: &StorageConfig,
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    let nulls = NarrowSalesNulls::of_rows(rows.clone());
+    let configuration = checkpoint_interval
+        .map(|checkpoint_interval| ("delta.checkpointInterval", Some(checkpoint_interval)))
+        .into_iter()
+        .collect::<Vec<_>>();
+    write_narrow_sales_commits(
+        table_url,
+        rows,
+        chunk_size,
+        partition_columns,
+        &configuration,
+        nulls,
+        storage,
+    )
+    .await
+}
+
+/// Open `part-<n>.parquet` file of one region, plus rows buffered for it.
+struct RawParquetPartFile {
+    writer: ArrowWriter<fs::File>,
+    pending: Vec<NarrowSaleRow>,
+    nulls: NarrowSalesNulls,
+}
+
+impl RawParquetPartFile {
+    fn create(path: &Path, nulls: NarrowSalesNulls) -> BenchResult<Self> {
+        let schema = raw_parquet_projection(rows_to_batch_with_nulls(&[], nulls)?)?.schema();
+        let writer = ArrowWriter::try_new(fs::File::create(path)?, schema, None)
+            .map_err(|error| BenchError::Delta(error.into()))?;
+        Ok(Self {
+            writer,
+            pending: Vec::new(),
+            nulls,
+        })
+    }
+
+    fn flush(&mut self) -> BenchResult<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let batch = raw_parquet_projection(rows_to_batch_with_nulls(&self.pending, self.nulls)?)?;
+        self.pending.clear();
+        self.writer
+            .write(&batch)
+            .map_err(|error| BenchError::Delta(error.into()))
+    }
+
+    fn close(mut self) -> BenchResult<()> {
+        self.flush()?;
+        self.writer
+            .close()
+            .map_err(|error| BenchError::Delta(error.into()))?;
+        Ok(())
+    }
+}
+
+/// Drops the `region` column, which the raw layout encodes in the directory name.
+fn raw_parquet_projection(
+    batch: arrow::record_batch::RecordBatch,
+) -> BenchResult<arrow::record_batch::RecordBatch> {
+    let region_idx = batch.schema().index_of("region")?;
+    let columns = (0..batch.num_columns())
+        .filter(|column| *column != region_idx)
+        .collect::<Vec<_>>();
+    Ok(batch.project(&columns)?)
+}
+
+/// One region's file split: contiguous, evenly sized ranges so every region lands exactly
+/// `file_count` files.
+struct RawParquetPartition {
+    dir: PathBuf,
+    rows: usize,
+    file_count: usize,
+    seen: usize,
+    file_idx: usize,
+    file: Option<RawParquetPartFile>,
+    nulls: NarrowSalesNulls,
+}
+
+impl RawParquetPartition {
+    fn file_end(&self) -> usize {
+        self.rows * (self.file_idx + 1) / self.file_count
+    }
+
+    fn push(&mut self, row: NarrowSaleRow) -> BenchResult<()> {
+        while self.seen >= self.file_end() {
+            if let Some(file) = self.file.take() {
+                file.close()?;
+            }
+            self.file_idx += 1;
+        }
+        if self.file.is_none() {
+            let path = self.dir.join(format!("part-{:05}.parquet", self.file_idx));
+            self.file = Some(RawParquetPartFile::create(&path, self.nulls)?);
+        }
+        let file = self.file.as_mut().expect("part file was just opened");
+        file.pending.push(row);
+        if file.pending.len() >= ROWS_FILE_BATCH_SIZE {
+            file.flush()?;
+        }
+        self.seen += 1;
+        Ok(())
+    }
+}
+
+/// Writes `rows` as `region=<value>/part-<n>.parquet` with the partition column dropped from
+/// the files, splitting each region into `files_per_partition` files. The stream is read twice:
+/// once to size each region, once to route rows to their open file.
+fn write_raw_parquet_partitioned<I>(
+    dir: &Path,
+    rows: I,
+    files_per_partition: usize,
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    let mut region_rows = BTreeMap::<String, usize>::new();
+    let mut nulls = NarrowSalesNulls::default();
+    for row in rows.clone() {
+        let row = row.borrow();
+        nulls.observe(row);
+        *region_rows.entry(row.region.clone()).or_default() += 1;
+    }
+
+    let mut partitions = BTreeMap::new();
+    for (region, count) in region_rows {
+        let partition_dir = dir.join(format!("region={region}"));
+        fs::create_dir_all(&partition_dir)?;
+        partitions.insert(
+            region,
+            RawParquetPartition {
+                dir: partition_dir,
+                rows: count,
+                file_count: files_per_partition.clamp(1, count),
+                seen: 0,
+                file_idx: 0,
+                file: None,
+                nulls,
+            },
+        );
+    }
+    for row in rows {
+        let row = row.borrow();
+        if let Some(partition) = partitions.get_mut(&row.region) {
+            partition.push(row.clone())?;
+        }
+    }
+    for partition in partitions.into_values() {
+        if let Some(file) = partition.file {
+            file.close()?;
+        }
+    }
+    Ok(())
+}
+
+/// Distinct values of a `data_skipping_delta` cardinality column.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataSkippingCardinality {
+    /// `card_low`: 4 values.
+    Low,
+    /// `card_medium`: 16 values.
+    Medium,
+    /// `card_high`: unique per row.
+    High,
+}
+
+impl DataSkippingCardinality {
+    pub const fn column(self) -> &'static str {
+        match self {
+            Self::Low => "card_low",
+            Self::Medium => "card_medium",
+            Self::High => "card_high",
+        }
+    }
+
+    fn distinct_values(self, rows: usize) -> usize {
+        match self {
+            Self::Low => 4,
+            Self::Medium => 16,
+            Self::High => rows,
+        }
+    }
+
+    /// Every column is monotonic in `id`, so each value covers one contiguous id range and
+    /// file-level min/max statistics can skip every file outside it.
+    pub fn value_for_id(self, id: usize, rows: usize) -> i64 {
+        (id * self.distinct_values(rows) / rows.max(1)) as i64
+    }
+
+    /// The middle value of the column, used as the equality predicate.
+    pub fn probe_value(self, rows: usize) -> i64 {
+        (self.distinct_values(rows) / 2) as i64
+    }
+}
+
+/// Row range of file `idx` in `data_skipping_delta`.
+fn data_skipping_file_rows(rows: usize, idx: usize) -> std::ops::Range<usize> {
+    rows * idx / DATA_SKIPPING_FILE_COUNT..rows * (idx + 1) / DATA_SKIPPING_FILE_COUNT
+}
+
+/// Files whose value range contains `value`, i.e. the files min/max skipping must still read.
+pub fn data_skipping_files_matching(
+    rows: usize,
+    cardinality: DataSkippingCardinality,
+    value: i64,
+) -> u64 {
+    (0..DATA_SKIPPING_FILE_COUNT)
+        .map(|idx| data_skipping_file_rows(rows, idx))
+        .filter(|range| !range.is_empty())
+        .filter(|range| {
+            let min = cardinality.value_for_id(range.start, rows);
+            let max = cardinality.value_for_id(range.end - 1, rows);
+            (min..=max).contains(&value)
+        })
+        .count() as u64
+}
+
+/// Writes one file per commit so every file carries its own min/max statistics.
+async fn write_data_skipping_table(
+    table_url: Url,
+    rows: usize,
+    storage: &StorageConfig,
 ) -> BenchResult<()> {
     prepare_local_table_dir(&table_url)?;
 
+    let schema = Arc::new(arrow::datatypes::Schema::new(vec![
+        arrow::datatypes::Field::new("id", arrow::datatypes::DataType::Int64, false),
+        arrow::datatypes::Field::new("card_low", arrow::datatypes::DataType::Int64, false),
+        arrow::datatypes::Field::new("card_medium", arrow::datatypes::DataType::Int64, false),
+        arrow::datatypes::Field::new("card_high", arrow::datatypes::DataType::Int64, false),
+    ]));
     let mut table = storage.try_from_url_for_write(table_url).await?;
-    for (idx, chunk) in rows.chunks(chunk_size).enumerate() {
+    for idx in 0..DATA_SKIPPING_FILE_COUNT {
+        let range = data_skipping_file_rows(rows, idx);
+        if range.is_empty() {
+            continue;
+        }
+        let column = |cardinality: DataSkippingCardinality| {
+            Arc::new(arrow::array::Int64Array::from_iter_values(
+                range.clone().map(|id| cardinality.value_for_id(id, rows)),
+            )) as arrow::array::ArrayRef
+        };
+        let batch = arrow::record_batch::RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(arrow::array::Int64Array::from_iter_values(
+                    range.clone().map(|id| id as i64),
+                )),
+                column(DataSkippingCardinality::Low),
+                column(DataSkippingCardinality::Medium),
+                column(DataSkippingCardinality::High),
+            ],
+        )?;
         let mode = if idx == 0 {
             SaveMode::Overwrite
         } else {
             SaveMode::Append
         };
-        let mut writer = table
-            .write(vec![rows_to_batch(chunk)?])
-            .with_save_mode(mode)
-            .with_partition_columns(partition_columns.iter().copied());
-        if let Some(checkpoint_interval) = checkpoint_interval.filter(|_| idx == 0) {
-            writer = writer
-                .with_configuration([("delta.checkpointInterval", Some(checkpoint_interval))]);
-        }
-        table = writer.await?;
+        table = table.write(vec![batch]).with_save_mode(mode).await?;
     }
 
     Ok(())
 }
 
-pub(crate) async fn write_vacuum_ready_table(
+/// `wide_delta` rows for a scale of `rows` rows unless the scale catalog overrides it.
+pub fn wide_table_row_count(rows: usize) -> usize {
+    rows.min(WIDE_TABLE_MAX_ROWS)
+}
+
+/// Name of value column `idx` (1-based) of `wide_delta`; `id` is column 0.
+pub fn wide_table_column_name(idx: usize) -> String {
+    format!("c{idx:03}")
+}
+
+/// Builds `wide_delta` rows for `ids`. Value columns cycle through Int64, Float64, Utf8, and
+/// Boolean so decoding covers the common physical types at every width.
+pub fn wide_table_batch(
+    ids: std::ops::Range<usize>,
+    columns: usize,
+) -> BenchResult<arrow::record_batch::RecordBatch> {
+    use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    let mut fields = vec![Field::new("id", DataType::Int64, false)];
+    let mut arrays: Vec<ArrayRef> = vec![Arc::new(Int64Array::from_iter_values(
+        ids.clone().map(|id| id as i64),
+    ))];
+    for idx in 1..columns {
+        let name = wide_table_column_name(idx);
+        let (data_type, array): (DataType, ArrayRef) = match idx % 4 {
+            0 => (
+                DataType::Int64,
+                Arc::new(Int64Array::from_iter_values(
+                    ids.clone().map(|id| ((id * idx) % 1_000_003) as i64),
+                )),
+            ),
+            1 => (
+                DataType::Float64,
+                Arc::new(Float64Array::from_iter_values(
+                    ids.clone().map(|id| id as f64 / idx as f64),
+                )),
+            ),
+            2 => (
+                DataType::Utf8,
+                Arc::new(StringArray::from_iter_values(
+                    ids.clone().map(|id| format!("v{}", (id + idx) % 1_024)),
+                )),
+            ),
+            _ => (
+                DataType::Boolean,
+                Arc::new(BooleanArray::from_iter(
+                    ids.clone().map(|id| Some((id + idx) % 3 == 0)),
+                )),
+            ),
+        };
+        fields.push(Field::new(name, data_type, false));
+        arrays.push(array);
+    }
+
+    Ok(arrow::record_batch::RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        arrays,
+    )?)
+}
+
+async fn write_wide_table(
     table_url: Url,
-    rows: &[NarrowSaleRow],
+    rows: usize,
+    columns: usize,
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    prepare_local_table_dir(&table_url)?;
+
+    let batches = (0..rows)
+        .step_by(WIDE_TABLE_CHUNK_ROWS)
+        .map(|start| wide_table_batch(start..(start + WIDE_TABLE_CHUNK_ROWS).min(rows), columns))
+        .collect::<BenchResult<Vec<_>>>()?;
+    let table = storage.try_from_url_for_write(table_url).await?;
+    table
+        .write(batches)
+        .with_save_mode(SaveMode::Overwrite)
+        .await?;
+    Ok(())
+}
+
+/// `nested_delta` rows for a scale of `rows` rows unless the scale catalog overrides it.
+pub fn nested_table_row_count(rows: usize) -> usize {
+    rows.min(NESTED_TABLE_MAX_ROWS)
+}
+
+/// Builds `nested_delta` rows for `ids`: an `attrs` struct, a `tags` list of 0-3 strings, and
+/// a `props` string-to-int map of 1-3 entries.
+pub fn nested_table_batch(
+    ids: std::ops::Range<usize>,
+) -> BenchResult<arrow::record_batch::RecordBatch> {
+    use arrow::array::{
+        Array, ArrayRef, BooleanArray, Float64Array, Int64Array, Int64Builder, ListBuilder,
+        MapBuilder, StringArray, StringBuilder, StructArray,
+    };
+    use arrow::datatypes::{DataType, Field, Fields, Schema};
+
+    let attrs_fields = Fields::from(vec![
+        Field::new("region", DataType::Utf8, false),
+        Field::new("score", DataType::Float64, false),
+        Field::new("active", DataType::Boolean, false),
+    ]);
+    let attrs = StructArray::try_new(
+        attrs_fields.clone(),
+        vec![
+            Arc::new(StringArray::from_iter_values(
+                ids.clone().map(|id| REGIONS[id % REGIONS.len()]),
+            )) as ArrayRef,
+            Arc::new(Float64Array::from_iter_values(
+                ids.clone().map(|id| (id % 1_000) as f64 / 10.0),
+            )),
+            Arc::new(BooleanArray::from_iter(
+                ids.clone().map(|id| Some(id % 2 == 0)),
+            )),
+        ],
+        None,
+    )?;
+
+    let mut tags = ListBuilder::new(StringBuilder::new());
+    let mut props = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+    for id in ids.clone() {
+        for tag in 0..id % 4 {
+            tags.values().append_value(format!("t{}", (id + tag) % 16));
+        }
+        tags.append(true);
+        for key in 0..=id % 3 {
+            props.keys().append_value(format!("k{key}"));
+            props.values().append_value((id * (key + 1)) as i64);
+        }
+        props.append(true)?;
+    }
+    let tags = tags.finish();
+    let props = props.finish();
+
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("attrs", DataType::Struct(attrs_fields), false),
+        Field::new("tags", tags.data_type().clone(), false),
+        Field::new("props", props.data_type().clone(), false),
+    ]);
+    Ok(arrow::record_batch::RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(Int64Array::from_iter_values(ids.map(|id| id as i64))),
+            Arc::new(attrs),
+            Arc::new(tags),
+            Arc::new(props),
+        ],
+    )?)
+}
+
+async fn write_nested_table(
+    table_url: Url,
+    rows: usize,
     storage: &StorageConfig,
 ) -> BenchResult<()> {
-    write_delta_table(table_url.clone(), rows, storage).await?;
+    prepare_local_table_dir(&table_url)?;
+
+    let batches = (0..rows)
+        .step_by(NESTED_TABLE_CHUNK_ROWS)
+        .map(|start| nested_table_batch(start..(start + NESTED_TABLE_CHUNK_ROWS).min(rows)))
+        .collect::<BenchResult<Vec<_>>>()?;
+    let table = storage.try_from_url_for_write(table_url).await?;
+    table
+        .write(batches)
+        .with_save_mode(SaveMode::Overwrite)
+        .await?;
+    Ok(())
+}
 
-    let retained = (rows.len() / 3).max(1);
+pub(crate) async fn write_vacuum_ready_table<I>(
+    table_url: Url,
+    rows: I,
+    storage: &StorageConfig,
+) -> BenchResult<()>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<NarrowSaleRow>,
+{
+    let row_count = rows.clone().into_iter().count();
+    let nulls = NarrowSalesNulls::of_rows(rows.clone());
+    write_delta_table(table_url.clone(), rows.clone(), storage).await?;
+
+    let retained = (row_count / 3).max(1);
+    let retained_rows = rows
+        .into_iter()
+        .take(retained)
+        .map(|row| row.borrow().clone())
+        .collect::<Vec<_>>();
     let _ = storage
         .try_from_url_for_write(table_url)
         .await?
-        .write(vec![rows_to_batch(&rows[..retained])?])
+        .write(vec![rows_to_batch_with_nulls(&retained_rows, nulls)?])
         .with_save_mode(SaveMode::Overwrite)
         .await?;
 
@@ -978,14 +2625,13 @@ pub(crate) async fn write_vacuum_ready_table(
 async fn write_metadata_history_tables(
     fixtures_dir: &Path,
     scale: &str,
-    rows: &[NarrowSaleRow],
+    rows: impl IntoIterator<Item = NarrowSaleRow>,
     recipe: &FixtureRecipe,
     storage: &StorageConfig,
 ) -> BenchResult<()> {
     let metadata_rows = rows
-        .iter()
+        .into_iter()
         .take(recipe.metadata_seed_rows.max(1))
-        .cloned()
         .collect::<Vec<_>>();
 
     let long_history_url = metadata_long_history_table_url(fixtures_dir, scale, storage)?;
@@ -1041,6 +2687,115 @@ async fn write_metadata_history_tables(
     )
     .await?;
 
+    // Deep history stays checkpoint-free so head loads, time travel, and history()
+    // enumeration all scale with the number of JSON commits.
+    let deep_history_url = metadata_deep_history_table_url(fixtures_dir, scale, storage)?;
+    write_delta_table_with_checkpoint_interval(
+        deep_history_url.clone(),
+        &metadata_rows,
+        METADATA_CHECKPOINT_INTERVAL,
+        storage,
+    )
+    .await?;
+    append_narrow_sales_versions(
+        deep_history_url,
+        &metadata_rows,
+        recipe.metadata_deep_history_append_commits,
+        recipe.metadata_history_chunk_size,
+        storage,
+    )
+    .await?;
+
+    // Checkpoint benchmarks write their own checkpoint per iteration, so the
+    // source table keeps a long, checkpoint-free JSON log.
+    let checkpoint_history_url = checkpoint_history_table_url(fixtures_dir, scale, storage)?;
+    write_delta_table_with_checkpoint_interval(
+        checkpoint_history_url.clone(),
+        &metadata_rows,
+        METADATA_CHECKPOINT_INTERVAL,
+        storage,
+    )
+    .await?;
+    append_narrow_sales_versions(
+        checkpoint_history_url,
+        &metadata_rows,
+        recipe.checkpoint_history_append_commits,
+        recipe.metadata_history_chunk_size,
+        storage,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Lands the on-time rows in arrival-ordered small files and keeps the CDC backfill batch as
+/// Parquet for the `late_arriving` suite to append or merge. Landed rows stream straight into
+/// the table; only the backfill batch is held.
+async fn write_late_arriving_fixtures(
+    fixtures_dir: &Path,
+    scale: &str,
+    seed: u64,
+    data: impl IntoIterator<Item = NarrowSaleRow> + Clone,
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    let nulls = NarrowSalesNulls::of_rows(data.clone());
+    let mut splitter = LateArrivingSplitter::new(seed);
+    write_narrow_sales_commits(
+        late_arriving_target_table_url(fixtures_dir, scale, storage)?,
+        data.into_iter().filter_map(|row| splitter.route(row)),
+        LATE_ARRIVING_CHUNK_SIZE,
+        &[],
+        &[],
+        nulls,
+        storage,
+    )
+    .await?;
+
+    let backfill_path = late_arriving_backfill_rows_path(fixtures_dir, scale);
+    if let Some(parent) = backfill_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_rows_parquet(&backfill_path, splitter.finish())
+}
+
+/// Writes `shape.files` files of `shape.rows_per_file` rows. Each commit carries as many whole
+/// files as fit in `commit_max_rows` rows, so the file count does not also become a long log.
+async fn write_small_files_table(
+    table_url: Url,
+    rows: NarrowSalesRows,
+    shape: SmallFilesShape,
+    commit_max_rows: usize,
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    prepare_local_table_dir(&table_url)?;
+
+    let nulls = NarrowSalesNulls::of_rows(rows.clone());
+    let files_per_commit = (commit_max_rows / shape.rows_per_file).max(1);
+    let mut files = row_chunks(rows, shape.rows_per_file);
+    let mut table = storage.try_from_url_for_write(table_url).await?;
+    for commit in 0.. {
+        let batches = files
+            .by_ref()
+            .take(files_per_commit)
+            .map(|chunk| rows_to_batch_with_nulls(&chunk, nulls))
+            .collect::<BenchResult<Vec<_>>>()?;
+        if batches.is_empty() {
+            break;
+        }
+        let mode = if commit == 0 {
+            SaveMode::Overwrite
+        } else {
+            SaveMode::Append
+        };
+        table = table
+            .write(batches)
+            .with_save_mode(mode)
+            .with_write_batch_size(shape.rows_per_file)
+            // Any buffered bytes reach the target, so every batch is flushed as its own file.
+            .with_target_file_size(1)
+            .await?;
+    }
+
     Ok(())
 }
 
@@ -1050,29 +2805,32 @@ async fn write_delta_table_with_checkpoint_interval(
     checkpoint_interval: &str,
     storage: &StorageConfig,
 ) -> BenchResult<()> {
-    prepare_local_table_dir(&table_url)?;
-
-    let batch = rows_to_batch(rows)?;
-    let _ = storage
-        .try_from_url_for_write(table_url)
-        .await?
-        .write(vec![batch])
-        .with_save_mode(SaveMode::Overwrite)
-        .with_configuration([("delta.checkpointInterval", Some(checkpoint_interval))])
-        .await?;
-
-    Ok(())
+    write_narrow_sales_commits(
+        table_url,
+        rows,
+        FIXTURE_COMMIT_MAX_ROWS,
+        &[],
+        &[("delta.checkpointInterval", Some(checkpoint_interval))],
+        NarrowSalesNulls::of_rows(rows),
+        storage,
+    )
+    .await
 }
 
+/// Appends only ever read the first `MANY_VERSIONS_APPEND_COMMITS` chunks, so only that prefix
+/// of the stream is materialized.
 async fn write_many_narrow_sales_versions(
     table_url: Url,
-    rows: &[NarrowSaleRow],
+    rows: impl ExactSizeIterator<Item = NarrowSaleRow>,
     storage: &StorageConfig,
 ) -> BenchResult<()> {
     let chunk_size = (rows.len() / 64).clamp(32, 256);
+    let rows = rows
+        .take(MANY_VERSIONS_APPEND_COMMITS * chunk_size)
+        .collect::<Vec<_>>();
     append_narrow_sales_versions(
         table_url,
-        rows,
+        &rows,
         MANY_VERSIONS_APPEND_COMMITS,
         chunk_size,
         storage,
@@ -1116,34 +2874,44 @@ async fn append_narrow_sales_versions(
     Ok(())
 }
 
+fn tpcds_store_sales_row(row: &NarrowSaleRow) -> TpcdsStoreSalesRow {
+    let value_i64 = row.value_i64.unwrap_or_default();
+    TpcdsStoreSalesRow {
+        ss_customer_sk: (row.id.rem_euclid(10_000)) + 1,
+        ss_ext_sales_price: (value_i64.abs() as f64 / 10.0) + 1.0,
+        ss_item_sk: (row.id.rem_euclid(5_000)) + 1,
+        ss_quantity: value_i64.abs().rem_euclid(8) + 1,
+        ss_sold_date_sk: 2_451_545_i64 + row.id.rem_euclid(3_650),
+    }
+}
+
 async fn write_tpcds_store_sales_table(
     table_url: Url,
-    rows: &[NarrowSaleRow],
+    rows: impl IntoIterator<Item = NarrowSaleRow>,
+    commit_max_rows: usize,
     storage: &StorageConfig,
-) -> BenchResult<()> {
+) -> BenchResult<TpcdsDimensionKeys> {
     prepare_local_table_dir(&table_url)?;
 
-    let tpcds_rows: Vec<TpcdsStoreSalesRow> = rows
-        .iter()
-        .map(|row| TpcdsStoreSalesRow {
-            ss_customer_sk: (row.id.rem_euclid(10_000)) + 1,
-            ss_ext_sales_price: (row.value_i64.abs() as f64 / 10.0) + 1.0,
-            ss_item_sk: (row.id.rem_euclid(5_000)) + 1,
-            ss_quantity: row.value_i64.abs().rem_euclid(8) + 1,
-            ss_sold_date_sk: 2_451_545_i64 + row.id.rem_euclid(3_650),
-        })
-        .collect();
-
-    let batch = tpcds_store_sales_rows_to_batch(&tpcds_rows)?;
-
-    let _ = storage
-        .try_from_url_for_write(table_url)
-        .await?
-        .write(vec![batch])
-        .with_save_mode(SaveMode::Overwrite)
-        .await?;
+    let mut keys = TpcdsDimensionKeys::default();
+    let mut chunks = row_chunks(rows, commit_max_rows);
+    let first = chunks.next().unwrap_or_default();
+    let mut table = storage.try_from_url_for_write(table_url).await?;
+    for (idx, chunk) in std::iter::once(first).chain(chunks).enumerate() {
+        let tpcds_rows = chunk.iter().map(tpcds_store_sales_row).collect::<Vec<_>>();
+        tpcds_rows.iter().for_each(|row| keys.observe(row));
+        let mode = if idx == 0 {
+            SaveMode::Overwrite
+        } else {
+            SaveMode::Append
+        };
+        table = table
+            .write(vec![tpcds_store_sales_rows_to_batch(&tpcds_rows)?])
+            .with_save_mode(mode)
+            .await?;
+    }
 
-    Ok(())
+    Ok(keys)
 }
 
 async fn prepare_tpcds_duckdb_source(scale: &str) -> BenchResult<PreparedTpcdsDuckdbSource> {
@@ -1218,7 +2986,7 @@ async fn write_tpcds_store_sales_csv_table(
     table_url: Url,
     csv_path: &Path,
     storage: &StorageConfig,
-) -> BenchResult<()> {
+) -> BenchResult<TpcdsDimensionKeys> {
     prepare_local_table_dir(&table_url)?;
 
     let mut table = storage.try_from_url_for_write(table_url).await?;
@@ -1241,6 +3009,7 @@ async fn write_tpcds_store_sales_csv_table(
     }
 
     let mut has_rows = false;
+    let mut keys = TpcdsDimensionKeys::default();
     let mut mode = SaveMode::Overwrite;
     let mut chunk = Vec::with_capacity(TPCDS_DUCKDB_CHUNK_ROWS);
     for (line_idx, line) in reader.lines().enumerate() {
@@ -1251,6 +3020,7 @@ async fn write_tpcds_store_sales_csv_table(
         }
 
         let row = parse_tpcds_store_sales_row(trimmed, line_idx + 2)?;
+        keys.observe(&row);
         chunk.push(row);
         if chunk.len() >= TPCDS_DUCKDB_CHUNK_ROWS {
             table = table
@@ -1280,7 +3050,7 @@ async fn write_tpcds_store_sales_csv_table(
 
     // Keep the latest table handle in-scope until writes are durably completed.
     let _ = table;
-    Ok(())
+    Ok(keys)
 }
 
 fn parse_tpcds_store_sales_row(line: &str, line_number: usize) -> BenchResult<TpcdsStoreSalesRow> {
@@ -1358,6 +3128,255 @@ fn tpcds_store_sales_rows_to_batch(
     )?)
 }
 
+async fn write_tpcds_dimension_tables(
+    fixtures_dir: &Path,
+    scale: &str,
+    keys: &TpcdsDimensionKeys,
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    let dimensions: [(&str, &Option<RangeInclusive<i64>>, TpcdsDimensionBatchFn); 3] = [
+        (
+            TPCDS_DATE_DIM_TABLE_DIR,
+            &keys.date_sk,
+            tpcds_date_dim_batch,
+        ),
+        (TPCDS_ITEM_TABLE_DIR, &keys.item_sk, tpcds_item_batch),
+        (
+            TPCDS_CUSTOMER_TABLE_DIR,
+            &keys.customer_sk,
+            tpcds_customer_batch,
+        ),
+    ];
+    for (table, key_range, to_batch) in dimensions {
+        let key_range = key_range.clone().ok_or_else(|| {
+            BenchError::InvalidArgument(format!(
+                "TPC-DS store_sales has no keys referencing '{table}'"
+            ))
+        })?;
+        let table_url = tpcds_table_url(fixtures_dir, scale, table, storage)?;
+        prepare_local_table_dir(&table_url)?;
+
+        let (start, end) = key_range.into_inner();
+        let batches = (start..=end)
+            .step_by(TPCDS_DUCKDB_CHUNK_ROWS)
+            .map(|chunk_start| {
+                let chunk_end = chunk_start
+                    .saturating_add(TPCDS_DUCKDB_CHUNK_ROWS as i64 - 1)
+                    .min(end);
+                to_batch(chunk_start..=chunk_end)
+            })
+            .collect::<BenchResult<Vec<_>>>()?;
+        let _ = storage
+            .try_from_url_for_write(table_url)
+            .await?
+            .write(batches)
+            .with_save_mode(SaveMode::Overwrite)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Rewrites `tpcds/store_sales` partitioned by `ss_sold_date_sk`, the usual TPC-DS fact layout,
+/// so star-join queries can compare partition pruning against the unpartitioned table.
+async fn write_tpcds_date_partitioned_store_sales(
+    fixtures_dir: &Path,
+    scale: &str,
+    commit_max_rows: usize,
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    let source = storage
+        .open_table(tpcds_store_sales_table_url(fixtures_dir, scale, storage)?)
+        .await?;
+    let ctx = SessionContext::new();
+    ctx.register_table("store_sales", source.table_provider().await?)?;
+    let mut stream = ctx
+        .sql("SELECT * FROM store_sales")
+        .await?
+        .execute_stream()
+        .await?;
+
+    let table_url = tpcds_table_url(
+        fixtures_dir,
+        scale,
+        TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR,
+        storage,
+    )?;
+    prepare_local_table_dir(&table_url)?;
+    // Re-partitioning streams the source in commits of up to `commit_max_rows` rows.
+    let mut table = storage.try_from_url_for_write(table_url).await?;
+    let mut pending = Vec::new();
+    let mut pending_rows = 0;
+    let mut commits = 0;
+    loop {
+        let batch = stream.try_next().await?;
+        let exhausted = batch.is_none();
+        if let Some(batch) = batch {
+            pending_rows += batch.num_rows();
+            pending.push(batch);
+        }
+        let flush =
+            pending_rows >= commit_max_rows || (exhausted && (!pending.is_empty() || commits == 0));
+        if flush {
+            let mode = if commits == 0 {
+                SaveMode::Overwrite
+            } else {
+                SaveMode::Append
+            };
+            table = table
+                .write(std::mem::take(&mut pending))
+                .with_save_mode(mode)
+                .with_partition_columns([TPCDS_STORE_SALES_PARTITION_COLUMN])
+                .await?;
+            pending_rows = 0;
+            commits += 1;
+        }
+        if exhausted {
+            return Ok(());
+        }
+    }
+}
+
+fn tpcds_date_dim_batch(
+    keys: RangeInclusive<i64>,
+) -> BenchResult<arrow::record_batch::RecordBatch> {
+    let schema = Arc::new(arrow::datatypes::Schema::new(vec![
+        arrow::datatypes::Field::new("d_date_sk", arrow::datatypes::DataType::Int64, false),
+        arrow::datatypes::Field::new("d_date", arrow::datatypes::DataType::Date32, false),
+        arrow::datatypes::Field::new("d_year", arrow::datatypes::DataType::Int32, false),
+        arrow::datatypes::Field::new("d_moy", arrow::datatypes::DataType::Int32, false),
+        arrow::datatypes::Field::new("d_dom", arrow::datatypes::DataType::Int32, false),
+        arrow::datatypes::Field::new("d_qoy", arrow::datatypes::DataType::Int32, false),
+        arrow::datatypes::Field::new("d_day_name", arrow::datatypes::DataType::Utf8, false),
+    ]));
+    let unix_epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid epoch date");
+    let dates = keys
+        .clone()
+        .map(|sk| {
+            i32::try_from(sk - TPCDS_JULIAN_DAY_CE_OFFSET)
+                .ok()
+                .and_then(NaiveDate::from_num_days_from_ce_opt)
+                .ok_or_else(|| {
+                    BenchError::InvalidArgument(format!(
+                        "TPC-DS d_date_sk {sk} is outside the supported date range"
+                    ))
+                })
+        })
+        .collect::<BenchResult<Vec<_>>>()?;
+
+    let d_date = dates
+        .iter()
+        .map(|date| (*date - unix_epoch).num_days() as i32)
+        .collect::<Vec<_>>();
+    let d_year = dates.iter().map(|date| date.year()).collect::<Vec<_>>();
+    let d_moy = dates
+        .iter()
+        .map(|date| date.month() as i32)
+        .collect::<Vec<_>>();
+    let d_dom = dates
+        .iter()
+        .map(|date| date.day() as i32)
+        .collect::<Vec<_>>();
+    let d_qoy = dates
+        .iter()
+        .map(|date| date.month0() as i32 / 3 + 1)
+        .collect::<Vec<_>>();
+    let d_day_name = dates
+        .iter()
+        .map(|date| date.format("%A").to_string())
+        .collect::<Vec<_>>();
+
+    Ok(arrow::record_batch::RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(arrow::array::Int64Array::from_iter_values(keys)),
+            Arc::new(arrow::array::Date32Array::from(d_date)),
+            Arc::new(arrow::array::Int32Array::from(d_year)),
+            Arc::new(arrow::array::Int32Array::from(d_moy)),
+            Arc::new(arrow::array::Int32Array::from(d_dom)),
+            Arc::new(arrow::array::Int32Array::from(d_qoy)),
+            Arc::new(arrow::array::StringArray::from(d_day_name)),
+        ],
+    )?)
+}
+
+fn tpcds_item_batch(keys: RangeInclusive<i64>) -> BenchResult<arrow::record_batch::RecordBatch> {
+    let schema = Arc::new(arrow::datatypes::Schema::new(vec![
+        arrow::datatypes::Field::new("i_item_sk", arrow::datatypes::DataType::Int64, false),
+        arrow::datatypes::Field::new("i_item_id", arrow::datatypes::DataType::Utf8, false),
+        arrow::datatypes::Field::new("i_brand_id", arrow::datatypes::DataType::Int32, false),
+        arrow::datatypes::Field::new("i_category", arrow::datatypes::DataType::Utf8, false),
+        arrow::datatypes::Field::new(
+            "i_current_price",
+            arrow::datatypes::DataType::Float64,
+            false,
+        ),
+    ]));
+    let i_item_id = keys
+        .clone()
+        .map(|sk| format!("ITEM{sk:012}"))
+        .collect::<Vec<_>>();
+    let i_brand_id = keys
+        .clone()
+        .map(|sk| (sk.rem_euclid(1_000) + 1) as i32)
+        .collect::<Vec<_>>();
+    let i_category = keys
+        .clone()
+        .map(|sk| TPCDS_ITEM_CATEGORIES[sk.rem_euclid(TPCDS_ITEM_CATEGORIES.len() as i64) as usize])
+        .collect::<Vec<_>>();
+    let i_current_price = keys
+        .clone()
+        .map(|sk| ((sk * 37).rem_euclid(9_900) + 100) as f64 / 100.0)
+        .collect::<Vec<_>>();
+
+    Ok(arrow::record_batch::RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(arrow::array::Int64Array::from_iter_values(keys)),
+            Arc::new(arrow::array::StringArray::from(i_item_id)),
+            Arc::new(arrow::array::Int32Array::from(i_brand_id)),
+            Arc::new(arrow::array::StringArray::from(i_category)),
+            Arc::new(arrow::array::Float64Array::from(i_current_price)),
+        ],
+    )?)
+}
+
+fn tpcds_customer_batch(
+    keys: RangeInclusive<i64>,
+) -> BenchResult<arrow::record_batch::RecordBatch> {
+    let schema = Arc::new(arrow::datatypes::Schema::new(vec![
+        arrow::datatypes::Field::new("c_customer_sk", arrow::datatypes::DataType::Int64, false),
+        arrow::datatypes::Field::new("c_customer_id", arrow::datatypes::DataType::Utf8, false),
+        arrow::datatypes::Field::new("c_birth_year", arrow::datatypes::DataType::Int32, false),
+        arrow::datatypes::Field::new(
+            "c_preferred_cust_flag",
+            arrow::datatypes::DataType::Utf8,
+            false,
+        ),
+    ]));
+    let c_customer_id = keys
+        .clone()
+        .map(|sk| format!("CUST{sk:012}"))
+        .collect::<Vec<_>>();
+    let c_birth_year = keys
+        .clone()
+        .map(|sk| (1_924 + sk.rem_euclid(69)) as i32)
+        .collect::<Vec<_>>();
+    let c_preferred_cust_flag = keys
+        .clone()
+        .map(|sk| if sk % 2 == 0 { "Y" } else { "N" })
+        .collect::<Vec<_>>();
+
+    Ok(arrow::record_batch::RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(arrow::array::Int64Array::from_iter_values(keys)),
+            Arc::new(arrow::array::StringArray::from(c_customer_id)),
+            Arc::new(arrow::array::Int32Array::from(c_birth_year)),
+            Arc::new(arrow::array::StringArray::from(c_preferred_cust_flag)),
+        ],
+    )?)
+}
+
 fn prepare_local_table_dir(table_url: &Url) -> BenchResult<()> {
     if table_url.scheme() != "file" {
         return Ok(());
@@ -1374,58 +3393,382 @@ fn prepare_local_table_dir(table_url: &Url) -> BenchResult<()> {
     Ok(())
 }
 
-pub(crate) fn rows_to_batch(
-    rows: &[NarrowSaleRow],
-) -> BenchResult<arrow::record_batch::RecordBatch> {
-    let schema = Arc::new(arrow::datatypes::Schema::new(vec![
+/// `amount` is `value_i64` read as cents, so it is null exactly where `value_i64` is.
+const AMOUNT_PRECISION: u8 = 18;
+const AMOUNT_SCALE: i8 = 2;
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Nullable narrow-sales columns that hold a null somewhere in a row set. Columns without
+/// nulls keep a non-null field, so uniform fixtures keep their original schema.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct NarrowSalesNulls {
+    value_i64: bool,
+    flag: bool,
+}
+
+impl NarrowSalesNulls {
+    pub(crate) fn of_rows<R: Borrow<NarrowSaleRow>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut nulls = Self::default();
+        for row in rows {
+            nulls.observe(row.borrow());
+            if nulls.value_i64 && nulls.flag {
+                break;
+            }
+        }
+        nulls
+    }
+
+    fn observe(&mut self, row: &NarrowSaleRow) {
+        self.value_i64 |= row.value_i64.is_none();
+        self.flag |= row.flag.is_none();
+    }
+}
+
+fn narrow_sales_schema(nulls: NarrowSalesNulls) -> arrow::datatypes::SchemaRef {
+    Arc::new(arrow::datatypes::Schema::new(vec![
         arrow::datatypes::Field::new("id", arrow::datatypes::DataType::Int64, false),
         arrow::datatypes::Field::new("ts_ms", arrow::datatypes::DataType::Int64, false),
         arrow::datatypes::Field::new("region", arrow::datatypes::DataType::Utf8, false),
-        arrow::datatypes::Field::new("value_i64", arrow::datatypes::DataType::Int64, false),
-        arrow::datatypes::Field::new("flag", arrow::datatypes::DataType::Boolean, false),
-    ]));
+        arrow::datatypes::Field::new(
+            "value_i64",
+            arrow::datatypes::DataType::Int64,
+            nulls.value_i64,
+        ),
+        arrow::datatypes::Field::new("flag", arrow::datatypes::DataType::Boolean, nulls.flag),
+        arrow::datatypes::Field::new(
+            "ts",
+            arrow::datatypes::DataType::Timestamp(
+                arrow::datatypes::TimeUnit::Microsecond,
+                Some("UTC".into()),
+            ),
+            false,
+        ),
+        arrow::datatypes::Field::new("event_date", arrow::datatypes::DataType::Date32, false),
+        arrow::datatypes::Field::new(
+            "amount",
+            arrow::datatypes::DataType::Decimal128(AMOUNT_PRECISION, AMOUNT_SCALE),
+            nulls.value_i64,
+        ),
+    ]))
+}
+
+pub(crate) fn rows_to_batch(
+    rows: &[NarrowSaleRow],
+) -> BenchResult<arrow::record_batch::RecordBatch> {
+    rows_to_batch_with_nulls(rows, NarrowSalesNulls::of_rows(rows))
+}
 
+/// Builds a batch whose schema marks `nulls` columns nullable even if these rows have none, so
+/// every chunk of a table shares the table's schema.
+pub(crate) fn rows_to_batch_with_nulls(
+    rows: &[NarrowSaleRow],
+    nulls: NarrowSalesNulls,
+) -> BenchResult<arrow::record_batch::RecordBatch> {
     let ids: Vec<i64> = rows.iter().map(|r| r.id).collect();
     let ts_ms: Vec<i64> = rows.iter().map(|r| r.ts_ms).collect();
     let regions: Vec<String> = rows.iter().map(|r| r.region.clone()).collect();
-    let values: Vec<i64> = rows.iter().map(|r| r.value_i64).collect();
-    let flags: Vec<bool> = rows.iter().map(|r| r.flag).collect();
+    let values: Vec<Option<i64>> = rows.iter().map(|r| r.value_i64).collect();
+    let flags: Vec<Option<bool>> = rows.iter().map(|r| r.flag).collect();
+    let ts: Vec<i64> = ts_ms.iter().map(|ts_ms| ts_ms * 1_000).collect();
+    let event_dates = ts_ms
+        .iter()
+        .map(|ts_ms| {
+            i32::try_from(ts_ms.div_euclid(MILLIS_PER_DAY)).map_err(|_| {
+                BenchError::InvalidArgument(format!("ts_ms {ts_ms} is outside the Date32 range"))
+            })
+        })
+        .collect::<BenchResult<Vec<i32>>>()?;
+    let amounts: Vec<Option<i128>> = values.iter().map(|v| v.map(i128::from)).collect();
 
     Ok(arrow::record_batch::RecordBatch::try_new(
-        schema,
+        narrow_sales_schema(nulls),
         vec![
             Arc::new(arrow::array::Int64Array::from(ids)),
             Arc::new(arrow::array::Int64Array::from(ts_ms)),
             Arc::new(arrow::array::StringArray::from(regions)),
             Arc::new(arrow::array::Int64Array::from(values)),
             Arc::new(arrow::array::BooleanArray::from(flags)),
+            Arc::new(arrow::array::TimestampMicrosecondArray::from(ts).with_timezone("UTC")),
+            Arc::new(arrow::array::Date32Array::from(event_dates)),
+            Arc::new(
+                arrow::array::Decimal128Array::from(amounts)
+                    .with_precision_and_scale(AMOUNT_PRECISION, AMOUNT_SCALE)?,
+            ),
         ],
     )?)
 }
 
+fn narrow_sales_rows_path(fixtures_dir: &Path, scale: &str) -> PathBuf {
+    fixture_root(fixtures_dir, scale)
+        .join(NARROW_SALES_ROWS_DIR)
+        .join(NARROW_SALES_ROWS_FILE)
+}
+
 pub fn load_rows(fixtures_dir: &Path, scale: &str) -> BenchResult<Vec<NarrowSaleRow>> {
-    let data_path = fixture_root(fixtures_dir, scale)
-        .join("narrow_sales")
-        .join("rows.jsonl");
+    read_rows_parquet(&narrow_sales_rows_path(fixtures_dir, scale))
+}
+
+/// Streams the narrow-sales row snapshot as Arrow batches in `rows_to_batch` layout, for
+/// callers that only hand the rows to a writer.
+pub fn load_row_batches(
+    fixtures_dir: &Path,
+    scale: &str,
+) -> BenchResult<impl Iterator<Item = BenchResult<arrow::record_batch::RecordBatch>>> {
+    read_row_batches(&narrow_sales_rows_path(fixtures_dir, scale))
+}
+
+pub fn load_late_arriving_backfill_rows(
+    fixtures_dir: &Path,
+    scale: &str,
+) -> BenchResult<Vec<NarrowSaleRow>> {
+    read_rows_parquet(&late_arriving_backfill_rows_path(fixtures_dir, scale))
+}
+
+/// Writes the narrow-sales rows as JSON lines next to the Parquet snapshot for inspection.
+/// Nothing reads the export back.
+pub fn export_rows_jsonl(fixtures_dir: &Path, scale: &str) -> BenchResult<PathBuf> {
+    let path = fixture_root(fixtures_dir, scale)
+        .join(NARROW_SALES_ROWS_DIR)
+        .join(NARROW_SALES_ROWS_JSONL_FILE);
+    write_rows_jsonl(&path, load_row_batches(fixtures_dir, scale)?)?;
+    Ok(path)
+}
+
+fn read_row_batches(
+    data_path: &Path,
+) -> BenchResult<impl Iterator<Item = BenchResult<arrow::record_batch::RecordBatch>>> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(fs::File::open(data_path)?)
+        .map_err(|error| BenchError::Delta(error.into()))?
+        .with_batch_size(ROWS_FILE_BATCH_SIZE)
+        .build()
+        .map_err(|error| BenchError::Delta(error.into()))?;
+    Ok(reader.map(|batch| batch.map_err(BenchError::from)))
+}
 
-    let data = fs::read_to_string(data_path)?;
+fn read_rows_parquet(data_path: &Path) -> BenchResult<Vec<NarrowSaleRow>> {
     let mut rows = Vec::new();
-    for line in data.lines() {
-        if line.trim().is_empty() {
-            continue;
-        }
-        let row: NarrowSaleRow = serde_json::from_str(line)?;
-        rows.push(row);
+    for batch in read_row_batches(data_path)? {
+        rows.extend(batch_to_rows(&batch?)?);
     }
     Ok(rows)
 }
 
+fn batch_to_rows(batch: &arrow::record_batch::RecordBatch) -> BenchResult<Vec<NarrowSaleRow>> {
+    use arrow::array::Array;
+
+    let ids = rows_column::<arrow::array::Int64Array>(batch, "id")?;
+    let ts_ms = rows_column::<arrow::array::Int64Array>(batch, "ts_ms")?;
+    let regions = rows_column::<arrow::array::StringArray>(batch, "region")?;
+    let values = rows_column::<arrow::array::Int64Array>(batch, "value_i64")?;
+    let flags = rows_column::<arrow::array::BooleanArray>(batch, "flag")?;
+    Ok((0..batch.num_rows())
+        .map(|idx| NarrowSaleRow {
+            id: ids.value(idx),
+            ts_ms: ts_ms.value(idx),
+            region: regions.value(idx).to_string(),
+            value_i64: values.is_valid(idx).then(|| values.value(idx)),
+            flag: flags.is_valid(idx).then(|| flags.value(idx)),
+        })
+        .collect())
+}
+
+fn rows_column<'a, T: 'static>(
+    batch: &'a arrow::record_batch::RecordBatch,
+    name: &str,
+) -> BenchResult<&'a T> {
+    batch
+        .column_by_name(name)
+        .and_then(|column| column.as_any().downcast_ref::<T>())
+        .ok_or_else(|| {
+            BenchError::InvalidArgument(format!(
+                "fixture rows file is missing column '{name}' or it has an unexpected type"
+            ))
+        })
+}
+
 pub fn load_manifest(fixtures_dir: &Path, scale: &str) -> BenchResult<FixtureManifest> {
     let path = fixture_root(fixtures_dir, scale).join("manifest.json");
     let manifest: FixtureManifest = serde_json::from_slice(&fs::read(path)?)?;
     Ok(manifest)
 }
 
+/// Rejects fixtures that were not generated for the requested dataset and seed, or whose
+/// manifest no longer matches the fingerprint recorded at generation time.
+pub fn verify_fixture_manifest(
+    manifest: &FixtureManifest,
+    dataset: Option<DatasetId>,
+    seed: Option<u64>,
+) -> BenchResult<()> {
+    let regenerate = match dataset {
+        Some(dataset) => format!("bench.sh data --dataset-id {}", dataset.as_str()),
+        None => format!("bench.sh data --scale {}", manifest.scale),
+    };
+    if let Some(dataset) = dataset {
+        if manifest.profile != dataset.fixture_profile() || manifest.scale != dataset.scale() {
+            return Err(BenchError::InvalidArgument(format!(
+                "fixtures were generated with profile '{}' at scale '{}', but dataset_id '{}' expects profile '{}' at scale '{}'; rerun {regenerate}",
+                manifest.profile,
+                manifest.scale,
+                dataset.as_str(),
+                dataset.fixture_profile(),
+                dataset.scale()
+            )));
+        }
+    }
+    if let Some(seed) = seed.filter(|seed| *seed != manifest.seed) {
+        return Err(BenchError::InvalidArgument(format!(
+            "fixtures were generated with seed {}, not the requested seed {seed}; rerun {regenerate} --seed {seed}",
+            manifest.seed
+        )));
+    }
+    if !manifest.rows_hash.is_empty() && !manifest_fingerprint_matches(manifest) {
+        return Err(BenchError::InvalidArgument(format!(
+            "fixture manifest for scale '{}' does not match its dataset_fingerprint; rerun {regenerate} --force",
+            manifest.scale
+        )));
+    }
+    Ok(())
+}
+
+/// What `data --verify` found for one table of a fixture inventory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FixtureTableCheck {
+    pub table: String,
+    pub rows: Option<u64>,
+    pub files: Option<u64>,
+    pub partition_columns: Vec<String>,
+    /// Empty when the table matches its manifest summary and expected layout.
+    pub problems: Vec<String>,
+}
+
+impl FixtureTableCheck {
+    pub fn passed(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Opens every table of `manifest`'s inventory and checks its row count, file count, and schema
+/// against the recorded summary and its partition columns against the layout the generator
+/// writes. A table that fails to open is reported as a problem rather than an error, so one
+/// pass covers the whole tree.
+pub async fn verify_fixture_tables(
+    fixtures_dir: &Path,
+    manifest: &FixtureManifest,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<FixtureTableCheck>> {
+    let scale = manifest.scale.as_str();
+    let root = fixture_root(fixtures_dir, scale);
+    let ctx = SessionContext::new();
+    let mut checks = Vec::with_capacity(manifest.table_inventory.len());
+    for table_name in &manifest.table_inventory {
+        let mut check = FixtureTableCheck {
+            table: table_name.clone(),
+            ..FixtureTableCheck::default()
+        };
+        if table_name == RAW_PARQUET_PARTITIONED_DIR {
+            if !root.join(table_name).is_dir() {
+                check.problems.push("directory is missing".to_string());
+            }
+            checks.push(check);
+            continue;
+        }
+
+        let summary = manifest
+            .table_summaries
+            .iter()
+            .find(|summary| &summary.table == table_name);
+        match inspect_fixture_table(&ctx, &root, scale, table_name, storage).await {
+            Ok(inspected) => {
+                if let Some(summary) = summary {
+                    if inspected.rows != summary.rows {
+                        check.problems.push(format!(
+                            "{} rows, manifest records {}",
+                            inspected.rows, summary.rows
+                        ));
+                    }
+                    if inspected.files != summary.files {
+                        check.problems.push(format!(
+                            "{} files, manifest records {}",
+                            inspected.files, summary.files
+                        ));
+                    }
+                    if inspected.schema_hash != summary.schema_hash {
+                        check
+                            .problems
+                            .push("schema differs from the manifest".to_string());
+                    }
+                } else {
+                    check
+                        .problems
+                        .push("manifest has no summary for this table".to_string());
+                }
+                let expected = expected_partition_columns(table_name);
+                if inspected.partition_columns != expected {
+                    check.problems.push(format!(
+                        "partitioned by [{}], expected [{}]",
+                        inspected.partition_columns.join(", "),
+                        expected.join(", ")
+                    ));
+                }
+                check.rows = Some(inspected.rows);
+                check.files = Some(inspected.files);
+                check.partition_columns = inspected.partition_columns;
+            }
+            Err(error) => check
+                .problems
+                .push(format!("failed to read table: {error}")),
+        }
+        checks.push(check);
+    }
+    Ok(checks)
+}
+
+struct InspectedFixtureTable {
+    rows: u64,
+    files: u64,
+    partition_columns: Vec<String>,
+    schema_hash: String,
+}
+
+async fn inspect_fixture_table(
+    ctx: &SessionContext,
+    root: &Path,
+    scale: &str,
+    table_name: &str,
+    storage: &StorageConfig,
+) -> BenchResult<InspectedFixtureTable> {
+    let table_url = storage.table_url_for(&root.join(table_name), scale, table_name)?;
+    let table = storage.open_table(table_url).await?;
+    let snapshot = table.snapshot()?;
+    let files = snapshot.log_data().num_files() as u64;
+    let partition_columns = snapshot.metadata().partition_columns().to_vec();
+    let frame = ctx.read_table(table.table_provider().await?)?;
+    let schema_hash = hash_arrow_schema(frame.schema().as_arrow())?;
+    Ok(InspectedFixtureTable {
+        rows: frame.count().await? as u64,
+        files,
+        partition_columns,
+        schema_hash,
+    })
+}
+
+/// Partition columns the generator writes each inventory table with.
+fn expected_partition_columns(table: &str) -> &'static [&'static str] {
+    match table {
+        READ_PARTITIONED_TABLE_DIR
+        | MERGE_PARTITIONED_TARGET_TABLE_DIR
+        | DELETE_UPDATE_SMALL_FILES_TABLE_DIR => &["region"],
+        _ if table
+            .strip_prefix(TPCDS_DIR)
+            .and_then(|table| table.strip_prefix('/'))
+            == Some(TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR) =>
+        {
+            &[TPCDS_STORE_SALES_PARTITION_COLUMN]
+        }
+        _ => &[],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1443,11 +3786,53 @@ mod tests {
             .cloned())
     }
 
+    #[test]
+    fn narrow_sales_batches_derive_typed_columns() {
+        use arrow::array::Array;
+
+        let rows = [
+            NarrowSaleRow {
+                id: 0,
+                ts_ms: 1_700_000_000_000,
+                region: "us".to_string(),
+                value_i64: Some(-1_234),
+                flag: Some(true),
+            },
+            NarrowSaleRow {
+                id: 1,
+                ts_ms: 1_700_006_400_000,
+                region: "eu".to_string(),
+                value_i64: None,
+                flag: Some(false),
+            },
+        ];
+        let batch = rows_to_batch(&rows).expect("build batch");
+
+        let ts = rows_column::<arrow::array::TimestampMicrosecondArray>(&batch, "ts")
+            .expect("ts column");
+        assert_eq!(ts.value(0), 1_700_000_000_000_000);
+        assert_eq!(ts.timezone(), Some("UTC"));
+        let event_date = rows_column::<arrow::array::Date32Array>(&batch, "event_date")
+            .expect("event_date column");
+        // 2023-11-14 and 2023-11-15, the second row sitting exactly on midnight UTC.
+        assert_eq!(event_date.value(0), 19_675);
+        assert_eq!(event_date.value(1), 19_676);
+        let amount =
+            rows_column::<arrow::array::Decimal128Array>(&batch, "amount").expect("amount column");
+        assert_eq!(amount.value_as_string(0), "-12.34");
+        assert!(amount.is_null(1));
+        assert!(batch
+            .schema()
+            .field_with_name("amount")
+            .expect("amount")
+            .is_nullable());
+    }
+
     #[tokio::test]
     async fn partitioned_small_files_writer_keeps_shared_fixture_defaults() {
         let temp = tempfile::tempdir().expect("tempdir should be created");
         let storage = StorageConfig::local();
-        let rows = generate_narrow_sales_rows(42, 128);
+        let rows = NarrowSalesRows::new(42, 128).collect::<Vec<_>>();
         let table_url =
             read_partitioned_table_url(temp.path(), "sf1", &storage).expect("read partitioned URL");
 
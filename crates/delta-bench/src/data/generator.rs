@@ -1,28 +1,292 @@
+use rand::seq::SliceRandom;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 
 use super::datasets::NarrowSaleRow;
+use crate::error::{BenchError, BenchResult};
 
-const REGIONS: [&str; 6] = ["us", "eu", "apac", "latam", "mea", "ca"];
+pub(crate) const REGIONS: [&str; 6] = ["us", "eu", "apac", "latam", "mea", "ca"];
+/// Every `LATE_ARRIVING_ID_STRIDE`-th id is withheld from the landed table and delivered late.
+const LATE_ARRIVING_ID_STRIDE: i64 = 10;
+const LATE_ARRIVING_ID_OFFSET: i64 = 3;
+/// Every `CORRECTION_ID_STRIDE`-th landed id is re-delivered as a CDC correction.
+const CORRECTION_ID_STRIDE: i64 = 20;
+const CORRECTION_ID_OFFSET: i64 = 7;
+const MAX_EVENT_JITTER_MS: i64 = 15 * 60_000;
+const LATE_ARRIVING_SEED_SALT: u64 = 0x1a7e_a221;
 
-pub fn generate_narrow_sales_rows(seed: u64, rows: usize) -> Vec<NarrowSaleRow> {
-    let mut rng = ChaCha8Rng::seed_from_u64(seed);
-    let mut out = Vec::with_capacity(rows);
-    let start_ts: i64 = 1_700_000_000_000;
+/// Time-series rows split by arrival. Selection is keyed by id so batch sizes stay stable
+/// across seeds; the seed only drives event-time jitter and backfill arrival order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateArrivingRows {
+    /// Rows that landed on time, in arrival order. Event timestamps jitter, so files written in
+    /// this order have overlapping `ts_ms` ranges.
+    pub landed: Vec<NarrowSaleRow>,
+    /// CDC backfill batch: withheld late rows interleaved with corrections of landed rows.
+    pub backfill: Vec<NarrowSaleRow>,
+}
+
+const NARROW_SALES_START_TS_MS: i64 = 1_700_000_000_000;
+/// Columns `--null-probability` accepts. `id`, `ts_ms`, and `region` stay non-null because
+/// suites key merges, deletes, and partitions on them.
+pub const NULLABLE_COLUMNS: [&str; 2] = ["value_i64", "flag"];
+
+/// Value distribution of the generated narrow-sales columns. The default is uniform and
+/// null-free, the data every recorded result hash was captured against, and draws exactly the
+/// same random stream as before these knobs existed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataDistribution {
+    /// Zipf exponent for `region`, ranked in `REGIONS` order; 0 is uniform.
+    #[serde(default)]
+    pub region_skew: f64,
+    /// Zipf exponent for `id` over `0..rows`. Above 0, low ids repeat as hot keys, so ids are no
+    /// longer unique.
+    #[serde(default)]
+    pub id_skew: f64,
+    #[serde(default)]
+    pub value_i64_null_probability: f64,
+    #[serde(default)]
+    pub flag_null_probability: f64,
+}
+
+impl DataDistribution {
+    pub fn is_uniform(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub fn validate(&self) -> BenchResult<()> {
+        for (name, skew) in [("region", self.region_skew), ("id", self.id_skew)] {
+            if !skew.is_finite() || skew < 0.0 {
+                return Err(BenchError::InvalidArgument(format!(
+                    "{name} skew must be a finite number >= 0 (found {skew})"
+                )));
+            }
+        }
+        for (column, probability) in [
+            ("value_i64", self.value_i64_null_probability),
+            ("flag", self.flag_null_probability),
+        ] {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(BenchError::InvalidArgument(format!(
+                    "null probability for '{column}' must be within [0, 1] (found {probability})"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies one `--null-probability` value of the form `COLUMN=PROBABILITY`.
+    pub fn set_null_probability(&mut self, spec: &str) -> BenchResult<()> {
+        let (column, probability) = spec.split_once('=').ok_or_else(|| {
+            BenchError::InvalidArgument(format!(
+                "null probability '{spec}' must look like COLUMN=PROBABILITY"
+            ))
+        })?;
+        let probability = probability.trim().parse::<f64>().map_err(|_| {
+            BenchError::InvalidArgument(format!("null probability '{spec}' must end in a number"))
+        })?;
+        match column.trim() {
+            "value_i64" => self.value_i64_null_probability = probability,
+            "flag" => self.flag_null_probability = probability,
+            other => {
+                return Err(BenchError::InvalidArgument(format!(
+                    "column '{other}' cannot be null; nullable columns: {}",
+                    NULLABLE_COLUMNS.join(", ")
+                )))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Zipf sampler over ranks `1..=n` using rejection-inversion (Hörmann and Derflinger), so
+/// drawing from a large id range needs no per-rank table.
+#[derive(Clone, Debug)]
+struct Zipf {
+    n: f64,
+    s: f64,
+    t: f64,
+    q: f64,
+}
+
+impl Zipf {
+    fn new(n: usize, s: f64) -> Self {
+        let n = n.max(1) as f64;
+        let (t, q) = if s == 1.0 {
+            (1.0 + n.ln(), 1.0)
+        } else {
+            let q = 1.0 / (1.0 - s);
+            ((n.powf(1.0 - s) - s) * q, q)
+        };
+        Self { n, s, t, q }
+    }
 
-    for id in 0..rows {
-        let region_idx = rng.gen_range(0..REGIONS.len());
+    fn inv_cdf(&self, p: f64) -> f64 {
+        let pt = p * self.t;
+        if pt <= 1.0 {
+            pt
+        } else if self.s == 1.0 {
+            (pt - 1.0).exp()
+        } else {
+            (pt * (1.0 - self.s) + self.s).powf(self.q)
+        }
+    }
+
+    /// Zero-based rank; 0 is the most frequent.
+    fn sample(&self, rng: &mut ChaCha8Rng) -> usize {
+        loop {
+            let inv_b = self.inv_cdf(rng.gen::<f64>());
+            let x = (inv_b + 1.0).floor();
+            let mut ratio = x.powf(-self.s);
+            if x > 1.0 {
+                ratio *= inv_b.powf(self.s);
+            }
+            if rng.gen::<f64>() < ratio {
+                return (x.min(self.n) as usize) - 1;
+            }
+        }
+    }
+}
+
+/// Narrow-sales rows generated one at a time. Rows depend only on the seed, distribution, and
+/// their position, so a stream for `n` rows is a prefix of any longer stream with the same seed
+/// and no id skew, and cloning the iterator replays the same rows without holding them.
+#[derive(Clone, Debug)]
+pub struct NarrowSalesRows {
+    rng: ChaCha8Rng,
+    next_id: usize,
+    rows: usize,
+    distribution: DataDistribution,
+    region_zipf: Option<Zipf>,
+    id_zipf: Option<Zipf>,
+}
+
+impl NarrowSalesRows {
+    pub fn new(seed: u64, rows: usize) -> Self {
+        Self {
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            next_id: 0,
+            rows,
+            distribution: DataDistribution::default(),
+            region_zipf: None,
+            id_zipf: None,
+        }
+    }
+
+    pub fn with_distribution(mut self, distribution: &DataDistribution) -> Self {
+        self.region_zipf = (distribution.region_skew > 0.0)
+            .then(|| Zipf::new(REGIONS.len(), distribution.region_skew));
+        self.id_zipf =
+            (distribution.id_skew > 0.0).then(|| Zipf::new(self.rows, distribution.id_skew));
+        self.distribution = distribution.clone();
+        self
+    }
+
+    /// Draws a null with `probability`, without touching the stream when it is 0.
+    fn draw_null(&mut self, probability: f64) -> bool {
+        probability > 0.0 && self.rng.gen_bool(probability)
+    }
+}
+
+impl Iterator for NarrowSalesRows {
+    type Item = NarrowSaleRow;
+
+    fn next(&mut self) -> Option<NarrowSaleRow> {
+        if self.next_id >= self.rows {
+            return None;
+        }
+        let position = self.next_id as i64;
+        self.next_id += 1;
+
+        let region_idx = match &self.region_zipf {
+            Some(zipf) => zipf.sample(&mut self.rng),
+            None => self.rng.gen_range(0..REGIONS.len()),
+        };
         let skew = (region_idx as i64) * 7;
-        let value_i64 = rng.gen_range(-5_000..50_000) + skew;
-        let flag = rng.gen_bool(0.35);
-        out.push(NarrowSaleRow {
-            id: id as i64,
-            ts_ms: start_ts + (id as i64 * 60_000),
+        let value_i64 = self.rng.gen_range(-5_000..50_000) + skew;
+        let flag = self.rng.gen_bool(0.35);
+        let id = match &self.id_zipf {
+            Some(zipf) => zipf.sample(&mut self.rng) as i64,
+            None => position,
+        };
+        let value_null = self.draw_null(self.distribution.value_i64_null_probability);
+        let flag_null = self.draw_null(self.distribution.flag_null_probability);
+        Some(NarrowSaleRow {
+            id,
+            ts_ms: NARROW_SALES_START_TS_MS + (position * 60_000),
             region: REGIONS[region_idx].to_string(),
-            value_i64,
-            flag,
-        });
+            value_i64: (!value_null).then_some(value_i64),
+            flag: (!flag_null).then_some(flag),
+        })
     }
 
-    out
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.rows - self.next_id;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for NarrowSalesRows {}
+
+pub fn generate_narrow_sales_rows(seed: u64, rows: usize) -> Vec<NarrowSaleRow> {
+    NarrowSalesRows::new(seed, rows).collect()
+}
+
+pub fn generate_late_arriving_rows(seed: u64, rows: usize) -> LateArrivingRows {
+    split_late_arriving_rows(seed, &generate_narrow_sales_rows(seed, rows))
+}
+
+/// Splits existing narrow-sales rows into landed and backfill batches. Fixture generation uses
+/// [`LateArrivingSplitter`] directly so it never holds the landed rows.
+pub fn split_late_arriving_rows(seed: u64, rows: &[NarrowSaleRow]) -> LateArrivingRows {
+    let mut splitter = LateArrivingSplitter::new(seed);
+    let landed = rows
+        .iter()
+        .filter_map(|row| splitter.route(row.clone()))
+        .collect();
+    LateArrivingRows {
+        landed,
+        backfill: splitter.finish(),
+    }
+}
+
+/// Streaming form of [`split_late_arriving_rows`]: landed rows pass straight through and only
+/// the backfill batch, which has to be shuffled as a whole, is kept.
+pub struct LateArrivingSplitter {
+    rng: ChaCha8Rng,
+    backfill: Vec<NarrowSaleRow>,
+}
+
+impl LateArrivingSplitter {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaCha8Rng::seed_from_u64(seed ^ LATE_ARRIVING_SEED_SALT),
+            backfill: Vec::new(),
+        }
+    }
+
+    /// Returns the row if it landed on time. Rows must arrive in id order.
+    pub fn route(&mut self, mut row: NarrowSaleRow) -> Option<NarrowSaleRow> {
+        row.ts_ms += self
+            .rng
+            .gen_range(-MAX_EVENT_JITTER_MS..=MAX_EVENT_JITTER_MS);
+        if row.id % LATE_ARRIVING_ID_STRIDE == LATE_ARRIVING_ID_OFFSET {
+            self.backfill.push(row);
+            return None;
+        }
+        if row.id % CORRECTION_ID_STRIDE == CORRECTION_ID_OFFSET {
+            let mut correction = row.clone();
+            let delta = self.rng.gen_range(1..1_000);
+            correction.value_i64 = correction.value_i64.map(|value| value + delta);
+            correction.flag = correction.flag.map(|flag| !flag);
+            self.backfill.push(correction);
+        }
+        Some(row)
+    }
+
+    pub fn finish(mut self) -> Vec<NarrowSaleRow> {
+        self.backfill.shuffle(&mut self.rng);
+        self.backfill
+    }
 }
@@ -0,0 +1,115 @@
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use tracing::warn;
+
+use super::fixtures::FIXTURE_LOCK_DIR;
+use crate::error::{BenchError, BenchResult};
+
+const FIXTURES_DIR_LOCK_FILE: &str = "fixtures.lock";
+
+/// How a command uses the fixtures directory while it holds the lock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixturesLockMode {
+    /// Reads fixtures; any number of readers can hold the lock together.
+    Shared,
+    /// Removes or rewrites fixtures; excludes every other holder.
+    Exclusive,
+}
+
+/// Advisory lock on a whole fixtures directory, held for the duration of one `bench`
+/// command. The lock is an OS file lock, so it is released when the process exits, even if
+/// it crashes.
+#[derive(Debug)]
+pub struct FixturesDirLock {
+    file: File,
+    mode: FixturesLockMode,
+}
+
+impl Drop for FixturesDirLock {
+    fn drop(&mut self) {
+        if self.mode == FixturesLockMode::Exclusive {
+            let _ = self.file.set_len(0);
+        }
+        let _ = self.file.unlock();
+    }
+}
+
+pub fn fixtures_dir_lock_path(fixtures_dir: &Path) -> PathBuf {
+    fixtures_dir
+        .join(FIXTURE_LOCK_DIR)
+        .join(FIXTURES_DIR_LOCK_FILE)
+}
+
+/// Locks `fixtures_dir` for `command`. When another process holds a conflicting lock, fails
+/// with a message naming the holder, or blocks until it is released if `wait` is set.
+/// Exclusive holders record their pid and command in the lock file for that message.
+pub async fn lock_fixtures_dir(
+    fixtures_dir: &Path,
+    mode: FixturesLockMode,
+    command: &str,
+    wait: bool,
+) -> BenchResult<FixturesDirLock> {
+    let path = fixtures_dir_lock_path(fixtures_dir);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&path)?;
+
+    let attempt = match mode {
+        FixturesLockMode::Shared => file.try_lock_shared(),
+        FixturesLockMode::Exclusive => file.try_lock(),
+    };
+    match attempt {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => {
+            let holder = describe_holder(&path);
+            if !wait {
+                return Err(BenchError::InvalidArgument(format!(
+                    "fixtures dir '{}' is in use by {holder}; pass --wait to wait for it, or rerun once it finishes",
+                    fixtures_dir.display()
+                )));
+            }
+            warn!(
+                fixtures_dir = %fixtures_dir.display(),
+                %holder,
+                "waiting for the fixtures dir lock"
+            );
+            let blocking = file.try_clone()?;
+            tokio::task::spawn_blocking(move || match mode {
+                FixturesLockMode::Shared => blocking.lock_shared(),
+                FixturesLockMode::Exclusive => blocking.lock(),
+            })
+            .await
+            .map_err(|error| {
+                BenchError::InvalidArgument(format!("fixtures lock wait failed: {error}"))
+            })??;
+        }
+        Err(TryLockError::Error(error)) => return Err(error.into()),
+    }
+
+    if mode == FixturesLockMode::Exclusive {
+        file.set_len(0)?;
+        writeln!(
+            &file,
+            "pid {} (bench {command}, since {})",
+            std::process::id(),
+            Utc::now().to_rfc3339()
+        )?;
+    }
+    Ok(FixturesDirLock { file, mode })
+}
+
+fn describe_holder(path: &Path) -> String {
+    match fs::read_to_string(path) {
+        Ok(holder) if !holder.trim().is_empty() => holder.trim().to_string(),
+        _ => "another bench process reading it".to_string(),
+    }
+}
@@ -1,3 +1,7 @@
+pub mod archive;
 pub mod datasets;
 pub mod fixtures;
 pub mod generator;
+pub mod lock;
+pub mod row_cache;
+pub mod scales;
@@ -0,0 +1,41 @@
+//! Narrow-sales rows shared by the suites of one run.
+//!
+//! Most write-path suites start from the same narrow-sales row snapshot. A planned run creates
+//! one [`FixtureRowCache`] and hands it to every suite through `SuiteRunArgs`, so the first
+//! suite to load a scale reads and decodes it, and every later suite of the run reuses its rows.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use super::datasets::NarrowSaleRow;
+use super::fixtures::load_rows;
+use crate::error::BenchResult;
+
+/// A fixtures directory and scale.
+type RowsKey = (PathBuf, String);
+
+/// Loaded narrow-sales rows keyed by fixtures directory and scale. Clones share one cache.
+#[derive(Clone, Default)]
+pub struct FixtureRowCache {
+    rows: Arc<Mutex<HashMap<RowsKey, Arc<Vec<NarrowSaleRow>>>>>,
+}
+
+impl FixtureRowCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The rows of `scale`, read on the first call and shared afterwards. A failed read is
+    /// not cached, so the next call retries it.
+    pub fn load(&self, fixtures_dir: &Path, scale: &str) -> BenchResult<Arc<Vec<NarrowSaleRow>>> {
+        let mut rows = self.rows.lock().unwrap_or_else(PoisonError::into_inner);
+        let key = (fixtures_dir.to_path_buf(), scale.to_string());
+        if let Some(cached) = rows.get(&key) {
+            return Ok(Arc::clone(cached));
+        }
+        let loaded = Arc::new(load_rows(fixtures_dir, scale)?);
+        rows.insert(key, Arc::clone(&loaded));
+        Ok(loaded)
+    }
+}
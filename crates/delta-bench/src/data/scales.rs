@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use super::fixtures::{nested_table_row_count, wide_table_row_count, FIXTURE_COMMIT_MAX_ROWS};
+use crate::error::{BenchError, BenchResult};
+use crate::manifests::{benchmark_repo_root, DatasetId};
+
+/// Overrides the scale catalog path; defaults to `bench/scales.yaml` in the benchmark repo.
+pub const SCALE_CATALOG_ENV: &str = "DELTA_BENCH_SCALES";
+const SCALE_CATALOG_SCHEMA_VERSION: u32 = 1;
+/// Scale `delta-bench smoke` generates its throwaway fixtures at. It is built in rather than a
+/// catalog entry, so the smoke check does not depend on the catalog it would be checking.
+pub const SMOKE_SCALE: &str = "micro";
+const SMOKE_SCALE_ROWS: usize = 1_024;
+
+/// One fixture scale. The optional sizes fall back to the generator's built-in values, so a
+/// scale only spells out what it changes.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ScaleDefinition {
+    pub name: String,
+    pub rows: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_max_rows: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wide_table_rows: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nested_table_rows: Option<usize>,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl ScaleDefinition {
+    /// Rows per commit for tables that are otherwise written in a single commit.
+    pub fn commit_max_rows(&self) -> usize {
+        self.commit_max_rows.unwrap_or(FIXTURE_COMMIT_MAX_ROWS)
+    }
+
+    pub fn wide_table_rows(&self) -> usize {
+        self.wide_table_rows
+            .unwrap_or_else(|| wide_table_row_count(self.rows))
+    }
+
+    pub fn nested_table_rows(&self) -> usize {
+        self.nested_table_rows
+            .unwrap_or_else(|| nested_table_row_count(self.rows))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ScaleCatalog {
+    pub schema_version: u32,
+    pub scales: Vec<ScaleDefinition>,
+}
+
+impl ScaleCatalog {
+    /// Scale names, smallest first.
+    pub fn names(&self) -> Vec<String> {
+        self.scales.iter().map(|scale| scale.name.clone()).collect()
+    }
+
+    pub fn get(&self, scale: &str) -> BenchResult<&ScaleDefinition> {
+        self.scales
+            .iter()
+            .find(|definition| definition.name == scale)
+            .ok_or_else(|| {
+                BenchError::InvalidArgument(format!(
+                    "unknown scale '{scale}' (expected one of: {})",
+                    self.names().join(", ")
+                ))
+            })
+    }
+}
+
+pub fn scale_catalog_path() -> PathBuf {
+    match std::env::var(SCALE_CATALOG_ENV) {
+        Ok(path) if !path.trim().is_empty() => PathBuf::from(path.trim()),
+        _ => benchmark_repo_root().join("bench/scales.yaml"),
+    }
+}
+
+pub fn load_scale_catalog(path: impl AsRef<Path>) -> BenchResult<ScaleCatalog> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).map_err(|error| {
+        BenchError::InvalidArgument(format!(
+            "failed to read scale catalog '{}': {error}",
+            path.display()
+        ))
+    })?;
+    let catalog = serde_yaml::from_slice::<ScaleCatalog>(&bytes).map_err(|error| {
+        BenchError::InvalidArgument(format!(
+            "invalid scale catalog '{}': {error}",
+            path.display()
+        ))
+    })?;
+    validate_scale_catalog(&catalog).map_err(|error| {
+        BenchError::InvalidArgument(format!(
+            "invalid scale catalog '{}': {error}",
+            path.display()
+        ))
+    })?;
+    Ok(catalog)
+}
+
+/// The catalog at [`scale_catalog_path`], read once per process.
+pub fn scale_catalog() -> BenchResult<&'static ScaleCatalog> {
+    static CATALOG: OnceLock<Result<ScaleCatalog, String>> = OnceLock::new();
+    CATALOG
+        .get_or_init(|| load_scale_catalog(scale_catalog_path()).map_err(|error| error.to_string()))
+        .as_ref()
+        .map_err(|error| BenchError::InvalidArgument(error.clone()))
+}
+
+/// The catalog entry for `scale`, or the built-in [`SMOKE_SCALE`].
+pub fn scale_definition(scale: &str) -> BenchResult<&'static ScaleDefinition> {
+    if scale == SMOKE_SCALE {
+        static SMOKE: OnceLock<ScaleDefinition> = OnceLock::new();
+        return Ok(SMOKE.get_or_init(|| ScaleDefinition {
+            name: SMOKE_SCALE.to_string(),
+            rows: SMOKE_SCALE_ROWS,
+            commit_max_rows: None,
+            wide_table_rows: None,
+            nested_table_rows: None,
+            description: "Built-in micro scale for `delta-bench smoke`.".to_string(),
+        }));
+    }
+    scale_catalog()?.get(scale)
+}
+
+/// Scale names become fixture directories beside the per-dataset directories, so they are
+/// limited to lowercase identifiers that cannot collide with a dataset id. Scales are listed
+/// smallest first because `--all-scales` generates them in catalog order.
+fn validate_scale_catalog(catalog: &ScaleCatalog) -> Result<(), String> {
+    if catalog.schema_version != SCALE_CATALOG_SCHEMA_VERSION {
+        return Err(format!(
+            "unsupported schema_version {} (expected {SCALE_CATALOG_SCHEMA_VERSION})",
+            catalog.schema_version
+        ));
+    }
+    if catalog.scales.is_empty() {
+        return Err("no scales defined".to_string());
+    }
+    let mut previous: Option<&ScaleDefinition> = None;
+    for scale in &catalog.scales {
+        let name = scale.name.as_str();
+        let valid_name = name
+            .chars()
+            .next()
+            .is_some_and(|first| first.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_');
+        if !valid_name {
+            return Err(format!(
+                "scale name '{name}' must be a lowercase identifier ([a-z][a-z0-9_]*)"
+            ));
+        }
+        if name == SMOKE_SCALE {
+            return Err(format!(
+                "scale name '{name}' is reserved for the built-in smoke scale"
+            ));
+        }
+        if DatasetId::parse(name).is_ok() {
+            return Err(format!("scale name '{name}' collides with a dataset id"));
+        }
+        if catalog
+            .scales
+            .iter()
+            .filter(|other| other.name == scale.name)
+            .count()
+            > 1
+        {
+            return Err(format!("duplicate scale '{name}'"));
+        }
+        for (field, value) in [
+            ("rows", Some(scale.rows)),
+            ("commit_max_rows", scale.commit_max_rows),
+            ("wide_table_rows", scale.wide_table_rows),
+            ("nested_table_rows", scale.nested_table_rows),
+        ] {
+            if value == Some(0) {
+                return Err(format!("scale '{name}' must have {field} > 0"));
+            }
+        }
+        if let Some(previous) = previous {
+            if scale.rows <= previous.rows {
+                return Err(format!(
+                    "scale '{name}' ({} rows) must be larger than the scale before it, '{}' ({} rows)",
+                    scale.rows, previous.name, previous.rows
+                ));
+            }
+        }
+        previous = Some(scale);
+    }
+    Ok(())
+}
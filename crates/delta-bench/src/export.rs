@@ -0,0 +1,102 @@
+//! Result export, optionally redacted for sharing outside the machine that produced it.
+//!
+//! Redaction drops what identifies the runner and its infrastructure: hostnames, the run label,
+//! backend profile names, boot parameters, the fixtures and results directories, captured
+//! physical plans, and any paths or endpoints quoted in failure messages. Metrics, case definitions, and the fidelity and fixture hashes are kept, so a
+//! redacted result still compares against other runs.
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::BenchResult;
+use crate::results::BenchRunResult;
+
+/// Written in place of required fields such as `label` and `host`.
+pub const REDACTED: &str = "redacted";
+
+pub fn export_run_result(input: &Path, out: &Path, redact: bool) -> BenchResult<()> {
+    let mut result = serde_json::from_slice::<BenchRunResult>(&fs::read(input)?)?;
+    if redact {
+        redact_run_result(&mut result);
+    }
+    if let Some(parent) = out.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(out, serde_json::to_vec_pretty(&result)?)?;
+    Ok(())
+}
+
+pub fn redact_run_result(result: &mut BenchRunResult) {
+    let context = &mut result.context;
+    context.label = REDACTED.to_string();
+    context.host = REDACTED.to_string();
+    // The run id hashes the label, so it would still link the result to its origin.
+    context.run_id = None;
+    context.backend_profile = None;
+    context.hardening_profile_id = None;
+    context.boot_params = None;
+    context.maintenance_window_id = None;
+    context.fixtures_dir = None;
+    context.results_dir = None;
+
+    for case in &mut result.cases {
+        for summary in case
+            .run_summary
+            .iter_mut()
+            .chain(case.run_summaries.iter_mut().flatten())
+        {
+            summary.host_label = None;
+        }
+        // Plans list the data files they scan, as object store paths without a leading slash
+        // that `redact_text` would not recognize.
+        case.physical_plan = None;
+        if let Some(failure) = case.failure.as_mut() {
+            failure.message = redact_text(&failure.message);
+        }
+    }
+}
+
+/// Replaces every absolute path and URL in `text`, including the value of `key=value` tokens,
+/// keeping surrounding quotes and punctuation.
+pub fn redact_text(text: &str) -> String {
+    text.split_inclusive(char::is_whitespace)
+        .map(|word| {
+            let token = word.trim_end();
+            let mut start = token
+                .find(|c: char| !matches!(c, '\'' | '"' | '`' | '(' | '[' | '<'))
+                .unwrap_or(token.len());
+            let mut core = token[start..].trim_end_matches(|c: char| {
+                matches!(c, '\'' | '"' | '`' | ')' | ']' | '>' | ',' | ';' | '.')
+            });
+            if let Some((key, value)) = core.split_once('=').filter(|(key, _)| is_option_key(key)) {
+                let quoted = value.trim_start_matches(['\'', '"', '`']);
+                start += key.len() + 1 + (value.len() - quoted.len());
+                core = quoted;
+            }
+            if is_path_or_endpoint(core) {
+                let end = start + core.len();
+                format!("{}<{REDACTED}>{}", &word[..start], &word[end..])
+            } else {
+                word.to_string()
+            }
+        })
+        .collect()
+}
+
+fn is_option_key(token: &str) -> bool {
+    !token.is_empty()
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+fn is_path_or_endpoint(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    token.contains("://")
+        || (token.starts_with('/') && token.len() > 1)
+        || token.starts_with("~/")
+        || (bytes.len() > 2
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1] == b':'
+            && matches!(bytes[2], b'\\' | b'/'))
+}
@@ -19,6 +19,20 @@ pub fn hash_json<T: Serialize + ?Sized>(value: &T) -> BenchResult<String> {
     Ok(hash_bytes(&encoded))
 }
 
+/// Same digest as [`hash_json`] over a `Vec` of the items, without collecting them.
+pub fn hash_json_seq<T: Serialize>(items: impl IntoIterator<Item = T>) -> BenchResult<String> {
+    let mut hasher = Sha256::new();
+    hasher.update(b"[");
+    for (idx, item) in items.into_iter().enumerate() {
+        if idx > 0 {
+            hasher.update(b",");
+        }
+        serde_json::to_writer(&mut hasher, &item)?;
+    }
+    hasher.update(b"]");
+    Ok(format!("sha256:{:x}", hasher.finalize()))
+}
+
 pub fn hash_record_batches_unordered(batches: &[RecordBatch]) -> BenchResult<String> {
     let mut rows = Vec::<String>::new();
     for batch in batches {
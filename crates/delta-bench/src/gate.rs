@@ -0,0 +1,321 @@
+//! Typed pass/regress verdicts for embedding the compare gate without shelling out.
+//!
+//! [`evaluate`] applies the exploratory rules of `delta_bench_compare.compare`: each case's
+//! representative sample is compared across the two runs, and a relative change beyond the
+//! policy threshold is a regression or an improvement. Cases that cannot be compared get
+//! [`Verdict::InsufficientData`] with the reason, rather than an error, so one broken case
+//! does not hide the verdicts of the rest.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::error::{BenchError, BenchResult};
+use crate::results::{BenchContext, BenchRunResult, CaseResult, PerfStatus};
+use crate::stats::nearest_rank;
+use crate::suites::{canonical_case_name, load_case_aliases};
+
+/// Which sample of a case stands for it, as `compare.py --aggregation` picks it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregation {
+    Min,
+    #[default]
+    Median,
+    P95,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GatePolicy {
+    /// Relative change, in percent, a case may move in either direction and still pass.
+    pub threshold_pct: f64,
+    pub aggregation: Aggregation,
+    /// Fewer measured samples than this in either run leave a case without a verdict.
+    pub min_samples: usize,
+}
+
+impl Default for GatePolicy {
+    /// The thresholds `compare.py` uses when none are given.
+    fn default() -> Self {
+        Self {
+            threshold_pct: 5.0,
+            aggregation: Aggregation::Median,
+            min_samples: 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Pass,
+    Regress,
+    Improve,
+    InsufficientData,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CaseVerdict {
+    pub case: String,
+    pub verdict: Verdict,
+    pub baseline_ms: Option<f64>,
+    pub candidate_ms: Option<f64>,
+    /// Candidate change relative to the baseline, in percent; positive is slower.
+    pub change_pct: Option<f64>,
+    /// Why the case has no verdict, for [`Verdict::InsufficientData`].
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GateReport {
+    pub policy_threshold_pct: f64,
+    /// One verdict per case of either run, in case name order.
+    pub cases: Vec<CaseVerdict>,
+}
+
+impl GateReport {
+    /// True when no case regressed; cases without a verdict do not fail the gate on their own.
+    pub fn passed(&self) -> bool {
+        self.count(Verdict::Regress) == 0
+    }
+
+    pub fn count(&self, verdict: Verdict) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| case.verdict == verdict)
+            .count()
+    }
+
+    pub fn with_verdict(&self, verdict: Verdict) -> impl Iterator<Item = &CaseVerdict> {
+        self.cases
+            .iter()
+            .filter(move |case| case.verdict == verdict)
+    }
+}
+
+/// Compares every case of `candidate` against `baseline`. Runs of different suites, scales,
+/// datasets, backends, or harness revisions measure different things and are rejected. Cases
+/// renamed through the manifest `aliases` are matched under their current id.
+pub fn evaluate(
+    baseline: &BenchRunResult,
+    candidate: &BenchRunResult,
+    policy: &GatePolicy,
+) -> BenchResult<GateReport> {
+    evaluate_with_aliases(baseline, candidate, policy, &load_case_aliases()?)
+}
+
+/// [`evaluate`] with the retired-to-current case id map given rather than read from the
+/// manifests.
+pub fn evaluate_with_aliases(
+    baseline: &BenchRunResult,
+    candidate: &BenchRunResult,
+    policy: &GatePolicy,
+    aliases: &BTreeMap<String, String>,
+) -> BenchResult<GateReport> {
+    if !policy.threshold_pct.is_finite() || policy.threshold_pct < 0.0 {
+        return Err(BenchError::InvalidArgument(format!(
+            "gate threshold must be a non-negative percentage, got {}",
+            policy.threshold_pct
+        )));
+    }
+    if policy.min_samples == 0 {
+        return Err(BenchError::InvalidArgument(
+            "gate min_samples must be at least 1".to_string(),
+        ));
+    }
+    ensure_matching_contexts(&baseline.context, &candidate.context)?;
+
+    let baseline_cases = cases_by_name(baseline, "baseline", aliases)?;
+    let candidate_cases = cases_by_name(candidate, "candidate", aliases)?;
+    let names = baseline_cases
+        .keys()
+        .chain(candidate_cases.keys())
+        .copied()
+        .collect::<BTreeSet<_>>();
+    let cases = names
+        .into_iter()
+        .map(|name| {
+            case_verdict(
+                name,
+                baseline_cases.get(name).copied(),
+                candidate_cases.get(name).copied(),
+                policy,
+            )
+        })
+        .collect();
+    Ok(GateReport {
+        policy_threshold_pct: policy.threshold_pct,
+        cases,
+    })
+}
+
+/// The run's cases keyed by their current id, as `compare.py` keys them.
+fn cases_by_name<'a>(
+    result: &'a BenchRunResult,
+    side: &str,
+    aliases: &'a BTreeMap<String, String>,
+) -> BenchResult<BTreeMap<&'a str, &'a CaseResult>> {
+    let mut cases = BTreeMap::new();
+    for case in &result.cases {
+        let name = canonical_case_name(&case.case, aliases);
+        if let Some(existing) = cases.insert(name, case) {
+            return Err(BenchError::InvalidArgument(format!(
+                "{side} has both '{}' and '{}' for case '{name}'; a retired alias and its current \
+                 id cannot appear in the same run",
+                existing.case, case.case
+            )));
+        }
+    }
+    Ok(cases)
+}
+
+fn case_verdict(
+    name: &str,
+    baseline: Option<&CaseResult>,
+    candidate: Option<&CaseResult>,
+    policy: &GatePolicy,
+) -> CaseVerdict {
+    let baseline_ms = baseline.and_then(|case| representative_ms(case, policy.aggregation));
+    let candidate_ms = candidate.and_then(|case| representative_ms(case, policy.aggregation));
+    let insufficient = |reason: String| CaseVerdict {
+        case: name.to_string(),
+        verdict: Verdict::InsufficientData,
+        baseline_ms,
+        candidate_ms,
+        change_pct: None,
+        reason: Some(reason),
+    };
+    let (baseline, candidate) = match (baseline, candidate) {
+        (Some(baseline), Some(candidate)) => (baseline, candidate),
+        (None, _) => return insufficient("case is missing from the baseline run".to_string()),
+        (_, None) => return insufficient("case is missing from the candidate run".to_string()),
+    };
+    for (side, case) in [("baseline", baseline), ("candidate", candidate)] {
+        if case.classification == "expected_failure" {
+            return insufficient(format!("{side} case is an expected failure"));
+        }
+        if !case.perf_status.is_trusted() {
+            return insufficient(format!(
+                "{side} perf_status is {}",
+                perf_status_name(&case.perf_status)
+            ));
+        }
+        if case.samples.len() < policy.min_samples {
+            return insufficient(format!(
+                "{side} has {} sample(s), fewer than the {} required",
+                case.samples.len(),
+                policy.min_samples
+            ));
+        }
+    }
+    let (Some(base), Some(cand)) = (baseline_ms, candidate_ms) else {
+        return insufficient("no measured samples to compare".to_string());
+    };
+    if base <= 0.0 || cand <= 0.0 {
+        if base <= 0.0 && cand <= 0.0 {
+            return CaseVerdict {
+                case: name.to_string(),
+                verdict: Verdict::Pass,
+                baseline_ms,
+                candidate_ms,
+                change_pct: None,
+                reason: None,
+            };
+        }
+        return insufficient("a non-positive timing has no relative change".to_string());
+    }
+    let change_pct = (cand - base) / base * 100.0;
+    let verdict = if change_pct.abs() <= policy.threshold_pct {
+        Verdict::Pass
+    } else if cand < base {
+        Verdict::Improve
+    } else {
+        Verdict::Regress
+    };
+    CaseVerdict {
+        case: name.to_string(),
+        verdict,
+        baseline_ms,
+        candidate_ms,
+        change_pct: Some(change_pct),
+        reason: None,
+    }
+}
+
+/// The aggregated sample's `elapsed_ms`; `None` for a failed case or one without samples.
+fn representative_ms(case: &CaseResult, aggregation: Aggregation) -> Option<f64> {
+    if !case.success || case.samples.is_empty() {
+        return None;
+    }
+    let mut elapsed = case
+        .samples
+        .iter()
+        .map(|sample| sample.elapsed_ms)
+        .collect::<Vec<_>>();
+    elapsed.sort_by(f64::total_cmp);
+    Some(match aggregation {
+        Aggregation::Min => elapsed[0],
+        Aggregation::Median => elapsed[elapsed.len() / 2],
+        Aggregation::P95 => nearest_rank(&elapsed, 95),
+    })
+}
+
+fn perf_status_name(status: &PerfStatus) -> &'static str {
+    match status {
+        PerfStatus::Trusted => "trusted",
+        PerfStatus::ValidationOnly => "validation_only",
+        PerfStatus::Invalid => "invalid",
+    }
+}
+
+/// The context fields `delta_bench_compare` requires to match before comparing two runs.
+fn comparison_identity(context: &BenchContext) -> [(&'static str, Option<&str>); 15] {
+    [
+        ("suite", Some(context.suite.as_str())),
+        ("runner", context.runner.as_deref()),
+        ("benchmark_mode", context.benchmark_mode.as_deref()),
+        ("timing_phase", context.timing_phase.as_deref()),
+        ("dataset_id", context.dataset_id.as_deref()),
+        (
+            "dataset_fingerprint",
+            context.dataset_fingerprint.as_deref(),
+        ),
+        ("scale", Some(context.scale.as_str())),
+        ("storage_backend", context.storage_backend.as_deref()),
+        ("backend_profile", context.backend_profile.as_deref()),
+        ("lane", context.lane.as_deref()),
+        ("measurement_kind", context.measurement_kind.as_deref()),
+        ("validation_level", context.validation_level.as_deref()),
+        ("harness_revision", context.harness_revision.as_deref()),
+        (
+            "fixture_recipe_hash",
+            context.fixture_recipe_hash.as_deref(),
+        ),
+        (
+            "fidelity_fingerprint",
+            context.fidelity_fingerprint.as_deref(),
+        ),
+    ]
+}
+
+fn ensure_matching_contexts(baseline: &BenchContext, candidate: &BenchContext) -> BenchResult<()> {
+    let mismatched = comparison_identity(baseline)
+        .into_iter()
+        .zip(comparison_identity(candidate))
+        .filter(|((_, base), (_, cand))| base != cand)
+        .map(|((key, base), (_, cand))| {
+            format!(
+                "{key}='{}'!='{}'",
+                base.unwrap_or("none"),
+                cand.unwrap_or("none")
+            )
+        })
+        .collect::<Vec<_>>();
+    if mismatched.is_empty() {
+        return Ok(());
+    }
+    Err(BenchError::InvalidArgument(format!(
+        "context mismatch across runs: {}",
+        mismatched.join(", ")
+    )))
+}
@@ -1,23 +1,37 @@
+pub mod analysis;
 pub mod assertions;
 pub mod cli;
 pub mod data;
 pub mod error;
+pub mod export;
 #[doc(hidden)]
 pub mod file_selection_bench_support;
 pub mod fingerprint;
+pub mod gate;
+pub mod log_reads;
+pub mod logging;
 pub mod manifests;
 #[doc(hidden)]
 pub mod merge_bench_support;
 #[doc(hidden)]
 pub mod metadata_bench_support;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod paths;
+pub mod pushgateway;
 pub(crate) mod replay_snapshot;
+pub mod report;
 pub mod results;
 pub mod runner;
 #[doc(hidden)]
 pub mod scan_replay_support;
+pub mod secrets;
+pub mod smoke;
 pub mod stats;
 pub mod storage;
 pub mod suites;
 pub mod system;
+pub mod trace;
 pub mod validation;
 pub(crate) mod version_compat;
+pub mod warnings;
@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use deltalake_core::logstore::object_store::path::Path;
+use deltalake_core::logstore::object_store::{
+    CopyOptions, GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore,
+    PutMultipartOptions, PutOptions, PutPayload, PutResult, Result,
+};
+use futures::stream::BoxStream;
+
+use crate::results::LogReadMetrics;
+
+const DELTA_LOG_DIR: &str = "_delta_log";
+const LOG_VERSION_DIGITS: usize = 20;
+
+/// Kind of `_delta_log` file a read was counted against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogFileKind {
+    /// A `<version>.json` commit.
+    Commit,
+    /// A classic, multi-part, or V2 checkpoint file, or a V2 checkpoint sidecar.
+    CheckpointPart,
+}
+
+/// Classifies an object path by its location under `_delta_log`. `_last_checkpoint`, CRC
+/// files, and everything outside the log are not counted.
+pub fn classify_log_path(path: &str) -> Option<LogFileKind> {
+    let mut segments = path.rsplit('/');
+    let file_name = segments.next()?;
+    let parent = segments.next()?;
+    if parent == "_sidecars" {
+        return (segments.next() == Some(DELTA_LOG_DIR) && file_name.ends_with(".parquet"))
+            .then_some(LogFileKind::CheckpointPart);
+    }
+    if parent != DELTA_LOG_DIR {
+        return None;
+    }
+    let (version, rest) = file_name.split_at_checked(LOG_VERSION_DIGITS)?;
+    if !version.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    if rest == ".json" {
+        Some(LogFileKind::Commit)
+    } else if rest.starts_with(".checkpoint.")
+        && (rest.ends_with(".parquet") || rest.ends_with(".json"))
+    {
+        Some(LogFileKind::CheckpointPart)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Default)]
+struct LogReadPaths {
+    commits: BTreeSet<Path>,
+    checkpoint_parts: BTreeSet<Path>,
+}
+
+/// Distinct `_delta_log` files read through the stores it wrapped. A checkpoint Parquet file
+/// read as several byte ranges counts once.
+#[derive(Clone, Debug, Default)]
+pub struct LogReadCounter {
+    paths: Arc<Mutex<LogReadPaths>>,
+}
+
+impl LogReadCounter {
+    /// Wraps `inner` so every read of a log file through it is recorded here.
+    pub fn wrap(&self, inner: Arc<dyn ObjectStore>) -> Arc<dyn ObjectStore> {
+        Arc::new(LogReadCountingStore {
+            inner,
+            counter: self.clone(),
+        })
+    }
+
+    /// Returns the reads recorded so far and starts counting from zero, so untimed setup
+    /// reads can be discarded before the measured operation.
+    pub fn take(&self) -> LogReadMetrics {
+        let paths = std::mem::take(&mut *self.lock());
+        LogReadMetrics {
+            commit_files_read: paths.commits.len() as u64,
+            checkpoint_parts_read: paths.checkpoint_parts.len() as u64,
+        }
+    }
+
+    fn record(&self, location: &Path) {
+        let Some(kind) = classify_log_path(location.as_ref()) else {
+            return;
+        };
+        let mut paths = self.lock();
+        match kind {
+            LogFileKind::Commit => paths.commits.insert(location.clone()),
+            LogFileKind::CheckpointPart => paths.checkpoint_parts.insert(location.clone()),
+        };
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LogReadPaths> {
+        self.paths
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[derive(Debug)]
+struct LogReadCountingStore {
+    inner: Arc<dyn ObjectStore>,
+    counter: LogReadCounter,
+}
+
+impl fmt::Display for LogReadCountingStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LogReadCountingStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for LogReadCountingStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> Result<PutResult> {
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOptions,
+    ) -> Result<Box<dyn MultipartUpload>> {
+        self.inner.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        if !options.head {
+            self.counter.record(location);
+        }
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn get_ranges(&self, location: &Path, ranges: &[Range<u64>]) -> Result<Vec<Bytes>> {
+        self.counter.record(location);
+        self.inner.get_ranges(location, ranges).await
+    }
+
+    fn delete_stream(
+        &self,
+        locations: BoxStream<'static, Result<Path>>,
+    ) -> BoxStream<'static, Result<Path>> {
+        self.inner.delete_stream(locations)
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    fn list_with_offset(
+        &self,
+        prefix: Option<&Path>,
+        offset: &Path,
+    ) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list_with_offset(prefix, offset)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy_opts(&self, from: &Path, to: &Path, options: CopyOptions) -> Result<()> {
+        self.inner.copy_opts(from, to, options).await
+    }
+}
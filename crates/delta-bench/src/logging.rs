@@ -0,0 +1,66 @@
+//! Tracing output for the `delta-bench` binary.
+//!
+//! The harness opens a span per run, target, case, and measured iteration, with the case id and
+//! elapsed time as fields, and logs each table URL a case resolves. Storage clients log retries
+//! inside those spans, so `RUST_LOG=delta_bench=info,object_store=info` ties a slow iteration
+//! to the retries behind it.
+
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+use crate::cli::LogFormat;
+use crate::error::{BenchError, BenchResult};
+
+/// What is logged when `RUST_LOG` is unset: warnings only, so runs stay quiet by default.
+pub const DEFAULT_LOG_FILTER: &str = "warn";
+
+/// Keeps the tracing exporters alive; dropping it flushes what they still buffer.
+#[must_use]
+pub struct TracingGuard {
+    #[cfg(feature = "otel")]
+    otel: Option<crate::otel::OtelExport>,
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "otel")]
+        if let Some(otel) = &self.otel {
+            otel.shutdown();
+        }
+    }
+}
+
+/// Installs the global subscriber, writing to stderr so it never mixes with the run summary
+/// on stdout. Closing spans are logged with their busy and idle time. Built with the `otel`
+/// feature, the spans are also exported over OTLP when an endpoint is configured.
+pub fn init_tracing(format: LogFormat) -> BenchResult<TracingGuard> {
+    let filter = match std::env::var(EnvFilter::DEFAULT_ENV) {
+        Ok(directives) => EnvFilter::try_new(&directives).map_err(|error| {
+            BenchError::InvalidArgument(format!("invalid RUST_LOG '{directives}': {error}"))
+        })?,
+        Err(_) => EnvFilter::new(DEFAULT_LOG_FILTER),
+    };
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr);
+    let fmt_layer = match format {
+        LogFormat::Text => fmt_layer.with_filter(filter).boxed(),
+        LogFormat::Json => fmt_layer.json().with_filter(filter).boxed(),
+    };
+    let registry = tracing_subscriber::registry().with(fmt_layer);
+
+    #[cfg(feature = "otel")]
+    let (registry, otel) = match crate::otel::otlp_layer()? {
+        Some((layer, otel)) => (registry.with(Some(layer)), Some(otel)),
+        None => (registry.with(None), None),
+    };
+    registry.try_init().map_err(|error| {
+        BenchError::InvalidArgument(format!("failed to install tracing subscriber: {error}"))
+    })?;
+    Ok(TracingGuard {
+        #[cfg(feature = "otel")]
+        otel,
+    })
+}
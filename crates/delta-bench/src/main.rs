@@ -1,32 +1,71 @@
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 use chrono::Utc;
-use clap::Parser;
-use serde::Serialize;
+use clap::{Parser, ValueEnum};
 
+use delta_bench::analysis;
 use delta_bench::cli::{
-    parse_storage_options, validate_label, Args, BenchmarkLane, BenchmarkMode, Command, RunnerMode,
+    expand_label_template, parse_ref_issues, parse_storage_options, validate_label, Args,
+    BenchmarkLane, Command, DataArchiveCommand, RunnerMode, StorageBackend,
 };
-use delta_bench::data::fixtures::{generate_fixtures_with_profile, load_manifest, FixtureProfile};
+use delta_bench::data::archive::{export_fixture_archive, import_fixture_archive};
+use delta_bench::data::fixtures::{
+    dataset_fixtures_dir, export_rows_jsonl, fixture_root, generate_fixtures_for_scales,
+    load_manifest, parse_scale_list, verify_fixture_manifest, verify_fixture_tables,
+    FixtureProfile, FixtureShape, FixtureTableCheck, SmallFilesShape,
+};
+use delta_bench::data::generator::DataDistribution;
+use delta_bench::data::lock::{lock_fixtures_dir, FixturesLockMode};
+use delta_bench::data::scales::scale_catalog;
 use delta_bench::error::{BenchError, BenchResult};
-use delta_bench::fingerprint::hash_json;
+use delta_bench::export::export_run_result;
+use delta_bench::logging::init_tracing;
 use delta_bench::manifests::{ensure_required_manifests_exist, DatasetId};
+use delta_bench::paths::{expand_home, prepare_writable_dir};
+use delta_bench::pushgateway::{parse_push_gateway, push_run_metrics};
+use delta_bench::report::{render_run_report, ReporterRegistry};
 use delta_bench::results::{
-    build_run_summary, render_run_summary_table, BenchContext, BenchRunResult,
-    RESULT_SCHEMA_VERSION,
+    build_run_triage, render_run_summary_table, render_run_triage, BenchRunResult, RUN_TRIAGE_TOP_N,
+};
+use delta_bench::runner::{
+    render_progress_event, validate_execution_contract, write_flamegraphs,
+    write_plan_metrics_files, AuditLog, BenchRunner, CaseSeeds, OpenLoopConfig,
+    AUDIT_LOG_FILE_NAME,
 };
-use delta_bench::storage::{load_backend_profile_options, StorageConfig};
+use delta_bench::smoke::run_smoke;
+use delta_bench::storage::{
+    isolated_tables_older_than, load_backend_profile_options, StorageConfig,
+};
+use delta_bench::suites::crash_recovery;
+use delta_bench::suites::data_skipping::pruning_report;
+use delta_bench::suites::scan::selectivity_curve;
+use delta_bench::suites::tpcds;
 use delta_bench::suites::{
-    apply_dataset_assertion_policy, list_targets, plan_run_cases, run_planned_cases,
+    diff_case_sets, list_targets, load_case_aliases, plan_replay_case, plan_run_cases,
+    plan_run_cases_with_options, CaseTagFilter, PlanDiff, SuiteOptions,
 };
 use delta_bench::system::{
-    benchmark_fidelity_info, delta_rs_checkout_info, host_name, probe_python_modules,
+    benchmark_fidelity_info, delta_rs_checkout_info, detect_delta_rs_revision,
+    detect_harness_revision, host_name, probe_python_modules, resolve_revision,
     FidelityEnvOverrides, PYTHON_INTEROP_REQUIRED_MODULES,
 };
+use delta_bench::trace::write_suite_case_traces;
+use delta_bench::warnings::render_run_warnings;
+
+/// dhat only sees allocations made through its allocator, so heap-profiling builds route every
+/// allocation through it, profiled or not.
+#[cfg(feature = "heap-profiling")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
 
 #[tokio::main]
 async fn main() -> BenchResult<()> {
-    let args = Args::parse();
+    let mut args = Args::parse();
+    let _tracing = init_tracing(args.log_format)?;
+    prepare_bench_dirs(&mut args)?;
     if command_requires_manifest_preflight(&args.command) {
         ensure_required_manifests_exist()?;
     }
@@ -47,28 +86,159 @@ async fn main() -> BenchResult<()> {
                 println!("{}", case.id);
             }
         }
+        Command::Plan {
+            target,
+            case_filter,
+            tags,
+            exclude_tags,
+            runner,
+            against,
+            custom_sql_dir,
+        } => {
+            let tags = CaseTagFilter::new(&tags, &exclude_tags)?;
+            let previous = against
+                .as_deref()
+                .map(|dir| load_previous_run(dir, &target))
+                .transpose()?;
+            let runner = match (runner, previous.as_ref()) {
+                (Some(runner), _) => runner,
+                (None, Some((_, previous))) => previous_runner(previous)?,
+                (None, None) => RunnerMode::All,
+            };
+            let run_plan = plan_run_cases_with_options(
+                &target,
+                runner,
+                case_filter.as_deref(),
+                &tags,
+                &SuiteOptions {
+                    custom_sql_dir,
+                    ..SuiteOptions::default()
+                },
+            )?;
+            println!(
+                "plan: target={target} runner={} cases={}",
+                runner.as_str(),
+                run_plan.len()
+            );
+            for case in &run_plan {
+                println!("{} (target={}, lane={})", case.id, case.target, case.lane);
+            }
+            if let Some((path, previous)) = previous {
+                let previous_ids = previous
+                    .cases
+                    .iter()
+                    .map(|case| case.case.clone())
+                    .collect::<Vec<_>>();
+                let planned_ids = run_plan
+                    .iter()
+                    .map(|case| case.id.clone())
+                    .collect::<Vec<_>>();
+                let diff = diff_case_sets(&previous_ids, &planned_ids, &load_case_aliases()?);
+                println!(
+                    "against {} ({} case(s)):",
+                    path.display(),
+                    previous_ids.len()
+                );
+                print!("{}", render_plan_diff(&diff));
+                if !diff.removed.is_empty() {
+                    return Err(BenchError::InvalidArgument(format!(
+                        "{} case(s) from the previous run are missing from the plan",
+                        diff.removed.len()
+                    )));
+                }
+            }
+        }
         Command::Data {
             scale,
+            all_scales,
             dataset_id,
             seed,
             force,
+            trash,
+            export_rows_jsonl,
+            region_skew,
+            id_skew,
+            null_probabilities,
+            wide_table_columns,
+            small_files_count,
+            small_files_rows_per_file,
+            verify,
+            wait,
+            archive,
         } => {
+            let lock_mode = match archive {
+                Some(DataArchiveCommand::Export { .. }) => FixturesLockMode::Shared,
+                Some(DataArchiveCommand::Import { .. }) => FixturesLockMode::Exclusive,
+                None if verify => FixturesLockMode::Shared,
+                None => FixturesLockMode::Exclusive,
+            };
+            let _fixtures_lock =
+                lock_fixtures_dir(&args.fixtures_dir, lock_mode, "data", wait).await?;
+            if let Some(archive) = archive {
+                return run_data_archive_command(&args.fixtures_dir, archive, &storage).await;
+            }
             let dataset = parse_dataset(dataset_id.as_deref())?;
-            let effective_scale = resolve_scale(&scale, dataset)?;
+            let mut distribution = DataDistribution {
+                region_skew,
+                id_skew,
+                ..DataDistribution::default()
+            };
+            for spec in &null_probabilities {
+                distribution.set_null_probability(spec)?;
+            }
+            let shape = FixtureShape {
+                wide_table_columns,
+                small_files: SmallFilesShape {
+                    files: small_files_count,
+                    rows_per_file: small_files_rows_per_file,
+                },
+            };
+            let requested_scales = if all_scales {
+                scale_catalog()?.names()
+            } else {
+                parse_scale_list(&scale)?
+            };
+            let scales = resolve_data_scales(requested_scales, dataset)?;
             let profile = resolve_fixture_profile(dataset)?;
-            generate_fixtures_with_profile(
-                &args.fixtures_dir,
-                effective_scale.as_str(),
+            let fixtures_dir = dataset_fixtures_dir(&args.fixtures_dir, dataset);
+            let storage = storage.for_dataset(dataset.map(DatasetId::as_str));
+            if verify {
+                return verify_fixtures(&fixtures_dir, &scales, dataset, &storage).await;
+            }
+            let timings = generate_fixtures_for_scales(
+                &fixtures_dir,
+                &scales,
                 seed,
                 force,
+                trash,
                 profile,
+                &distribution,
+                &shape,
                 &storage,
             )
             .await?;
+            for scale in &timings {
+                for table in &scale.tables {
+                    println!(
+                        "{}: wrote {} in {:.1} ms",
+                        scale.scale, table.table, table.elapsed_ms
+                    );
+                }
+                println!(
+                    "{}: fixture tables written in {:.1} ms",
+                    scale.scale, scale.elapsed_ms
+                );
+            }
+            if export_rows_jsonl {
+                for scale in &scales {
+                    let path = export_rows_jsonl(&fixtures_dir, scale)?;
+                    println!("exported rows to {}", path.display());
+                }
+            }
             println!(
                 "fixtures ready at {} (scale={}, seed={seed})",
-                args.fixtures_dir.display(),
-                effective_scale
+                fixtures_dir.display(),
+                scales.join(",")
             );
         }
         Command::Run {
@@ -76,108 +246,139 @@ async fn main() -> BenchResult<()> {
             dataset_id,
             target,
             case_filter,
+            tags,
+            exclude_tags,
             runner,
             benchmark_mode,
             lane,
             timing_phase,
+            tpcds_phase,
+            tpcds_layout,
+            custom_sql_dir,
+            replay_trace,
+            load_duration_secs,
+            target_ops_per_sec,
+            fixture_copy,
             warmup,
             iterations,
+            seed,
+            replay_case,
+            fixture_seed,
+            ref_issues,
             no_summary_table,
+            wait,
+            progress,
+            push_gateway,
+            capture_plan,
+            capture_plan_metrics,
+            profile,
+            heap_profile,
+            label_template,
         } => {
             let dataset = parse_dataset(dataset_id.as_deref())?;
             let effective_scale = resolve_scale(&scale, dataset)?;
-            validate_label(&args.label)?;
-            validate_execution_contract(benchmark_mode, lane)?;
-            fs::create_dir_all(&args.results_dir)?;
-            let mut run_plan = plan_run_cases(&target, runner, case_filter.as_deref())?;
-            apply_dataset_assertion_policy(&mut run_plan, dataset);
-            let effective_warmup = if benchmark_mode == BenchmarkMode::Assert
-                || lane == BenchmarkLane::Correctness
-                || lane == BenchmarkLane::Smoke
-            {
-                0
-            } else {
-                warmup
+            let (git_sha, git_dirty) =
+                resolve_revision(args.git_sha.as_deref(), detect_delta_rs_revision);
+            let (harness_revision, harness_dirty) =
+                resolve_revision(args.harness_revision.as_deref(), detect_harness_revision);
+            let label = match label_template.as_deref() {
+                Some(template) => expand_label_template(
+                    template,
+                    &label_template_values(
+                        git_sha.as_deref(),
+                        harness_revision.as_deref(),
+                        args.storage_backend,
+                        &target,
+                        &effective_scale,
+                        dataset_id.as_deref(),
+                        lane,
+                    ),
+                )?,
+                None => args.label.clone(),
             };
-            let effective_iterations = if benchmark_mode == BenchmarkMode::Assert
-                || lane == BenchmarkLane::Correctness
-                || lane == BenchmarkLane::Smoke
-            {
-                1
-            } else {
-                iterations
-            };
-            let cases = run_planned_cases(
-                &args.fixtures_dir,
-                &run_plan,
-                effective_scale.as_str(),
-                lane,
-                timing_phase,
-                effective_warmup,
-                effective_iterations,
-                &storage,
-            )
-            .await?;
-            let fixture_manifest = load_manifest(&args.fixtures_dir, effective_scale.as_str())?;
-            let fidelity = benchmark_fidelity_info(&FidelityEnvOverrides::from_env());
-            let measurement_kind = measurement_kind_for_target(&target);
-            let validation_level = validation_level_for_run_plan(&run_plan, lane);
-            let fidelity_fingerprint = compute_fidelity_fingerprint(&fidelity)?;
-            let run_id = compute_run_id(
-                &args.label,
-                args.git_sha.as_deref(),
-                &target,
-                &effective_scale,
-                lane.as_str(),
-                timing_phase.as_str(),
-            )?;
-            let context = BenchContext {
-                schema_version: RESULT_SCHEMA_VERSION,
-                label: args.label.clone(),
-                git_sha: args.git_sha.clone(),
-                created_at: Utc::now(),
-                host: host_name(),
-                suite: target.clone(),
-                scale: effective_scale.clone(),
-                iterations: effective_iterations,
-                warmup: effective_warmup,
-                timing_phase: Some(timing_phase.as_str().to_string()),
-                dataset_id: dataset_id.clone(),
-                dataset_fingerprint: Some(fixture_manifest.dataset_fingerprint.clone()),
-                runner: Some(runner.as_str().to_string()),
-                storage_backend: Some(args.storage_backend.as_str().to_string()),
-                benchmark_mode: Some(benchmark_mode.as_str().to_string()),
-                lane: Some(lane.as_str().to_string()),
-                measurement_kind: Some(measurement_kind.to_string()),
-                validation_level: Some(validation_level.to_string()),
-                run_id: Some(run_id),
-                harness_revision: args.harness_revision.clone(),
-                fixture_recipe_hash: Some(fixture_manifest.fixture_recipe_hash.clone()),
-                fidelity_fingerprint: Some(fidelity_fingerprint.clone()),
-                backend_profile: args.backend_profile.clone(),
-                image_version: fidelity.image_version,
-                hardening_profile_id: fidelity.hardening_profile_id,
-                hardening_profile_sha256: fidelity.hardening_profile_sha256,
-                cpu_model: fidelity.cpu_model,
-                cpu_microcode: fidelity.cpu_microcode,
-                kernel: fidelity.kernel,
-                boot_params: fidelity.boot_params,
-                cpu_steal_pct: fidelity.cpu_steal_pct,
-                numa_topology: fidelity.numa_topology,
-                egress_policy_sha256: fidelity.egress_policy_sha256,
-                run_mode: fidelity.run_mode,
-                maintenance_window_id: fidelity.maintenance_window_id,
+            validate_label(&label)?;
+            let ref_issues = parse_ref_issues(&ref_issues)?;
+            validate_execution_contract(benchmark_mode, lane)?;
+            let push_gateway = push_gateway
+                .as_deref()
+                .map(parse_push_gateway)
+                .transpose()?;
+            let _fixtures_lock =
+                lock_fixtures_dir(&args.fixtures_dir, FixturesLockMode::Shared, "run", wait)
+                    .await?;
+            let suite_options = SuiteOptions {
+                tpcds_phase,
+                tpcds_layout,
+                custom_sql_dir,
+                replay_trace,
+                load: load_duration_secs
+                    .zip(target_ops_per_sec)
+                    .map(|(secs, rate)| OpenLoopConfig::new(Duration::from_secs(secs), rate))
+                    .transpose()?,
+                ..SuiteOptions::default()
             };
-            let cases = finalize_cases(cases, &run_plan, benchmark_mode, lane, &context)?;
-
-            let output = BenchRunResult {
-                schema_version: RESULT_SCHEMA_VERSION,
-                context,
-                cases,
+            let (run_plan, seeds) = match replay_case.as_deref() {
+                Some(case) => (
+                    plan_replay_case(&target, runner, case, &suite_options)?,
+                    CaseSeeds::replay(case, seed),
+                ),
+                None => (
+                    plan_run_cases_with_options(
+                        &target,
+                        runner,
+                        case_filter.as_deref(),
+                        &CaseTagFilter::new(&tags, &exclude_tags)?,
+                        &suite_options,
+                    )?,
+                    CaseSeeds::new(seed),
+                ),
             };
-
-            let out_dir = args.results_dir.join(&args.label);
+            let out_dir = args.results_dir.join(&label);
             fs::create_dir_all(&out_dir)?;
+            let audit_log = Arc::new(AuditLog::open(&out_dir.join(AUDIT_LOG_FILE_NAME))?);
+            let mut bench_runner = BenchRunner::builder()
+                .fixtures_dir(&args.fixtures_dir)
+                .plan(run_plan)
+                .storage(storage.with_fixture_copy(fixture_copy))
+                .scale(effective_scale)
+                .target(&target)
+                .runner(runner)
+                .benchmark_mode(benchmark_mode)
+                .lane(lane)
+                .timing_phase(timing_phase)
+                .suite_options(suite_options)
+                .warmup(warmup)
+                .iterations(iterations)
+                .seeds(seeds)
+                .label(&label)
+                .git_revision(git_sha, git_dirty)
+                .harness_revision(harness_revision, harness_dirty)
+                .backend_profile(args.backend_profile.clone())
+                .results_dir(&args.results_dir)
+                .ref_issues(ref_issues)
+                .audit_log(audit_log);
+            if let Some(dataset) = dataset {
+                bench_runner = bench_runner.dataset(dataset);
+            }
+            if let Some(fixture_seed) = fixture_seed {
+                bench_runner = bench_runner.fixture_seed(fixture_seed);
+            }
+            if let Some(format) = capture_plan {
+                bench_runner = bench_runner.capture_plan(format);
+            }
+            bench_runner = bench_runner
+                .plan_metrics(capture_plan_metrics)
+                .profile(profile)
+                .heap_profile(heap_profile);
+            if progress {
+                bench_runner =
+                    bench_runner.on_progress(|event| eprintln!("{}", render_progress_event(event)));
+            }
+            let mut output = bench_runner.build()?.run().await?;
+
+            let plan_metrics_files = write_plan_metrics_files(&mut output, &out_dir, &target)?;
+            let flamegraphs = write_flamegraphs(&mut output, &out_dir, &target)?;
+            let heap_profiles = write_heap_profiles(&mut output, &out_dir, &target)?;
             let out_file = out_dir.join(format!("{target}.json"));
             fs::write(out_file.clone(), serde_json::to_vec_pretty(&output)?)?;
             let ok_count = output.cases.iter().filter(|case| case.success).count();
@@ -188,10 +389,181 @@ async fn main() -> BenchResult<()> {
                 ok_count,
                 failed_count
             );
+            if !output.context.ref_issues.is_empty() {
+                println!("ref issues: {}", output.context.ref_issues.join(", "));
+            }
+            if !output.warnings.is_empty() {
+                println!("{}", render_run_warnings(&output.warnings));
+            }
+            let triage = build_run_triage(&output.cases, RUN_TRIAGE_TOP_N);
             if !no_summary_table {
                 println!("{}", render_run_summary_table(&output.cases));
+                if !triage.is_empty() {
+                    println!("{}", render_run_triage(&triage));
+                }
             }
             println!("wrote result: {}", out_file.display());
+            for path in &plan_metrics_files {
+                println!("wrote plan metrics: {}", path.display());
+            }
+            for path in &flamegraphs {
+                println!("wrote flamegraph: {}", path.display());
+            }
+            for path in &heap_profiles {
+                println!("wrote heap profile: {}", path.display());
+            }
+            if !triage.is_empty() {
+                let triage_file = out_dir.join(format!("{target}.triage.json"));
+                fs::write(&triage_file, serde_json::to_vec_pretty(&triage)?)?;
+                println!("wrote triage: {}", triage_file.display());
+            }
+            let curve = selectivity_curve(&output.cases);
+            if !curve.is_empty() {
+                let curve_file = out_dir.join(format!("{target}.selectivity_curve.json"));
+                fs::write(&curve_file, serde_json::to_vec_pretty(&curve)?)?;
+                println!("wrote selectivity curve: {}", curve_file.display());
+            }
+            let pruning = pruning_report(&output.cases);
+            if !pruning.is_empty() {
+                let pruning_file = out_dir.join(format!("{target}.pruning_report.json"));
+                fs::write(&pruning_file, serde_json::to_vec_pretty(&pruning)?)?;
+                println!("wrote pruning report: {}", pruning_file.display());
+            }
+            let tpcds_pruning = tpcds::pruning_report(&output.cases, tpcds_layout);
+            if !tpcds_pruning.is_empty() {
+                let pruning_file = out_dir.join(format!("{target}.tpcds_pruning_report.json"));
+                fs::write(&pruning_file, serde_json::to_vec_pretty(&tpcds_pruning)?)?;
+                println!("wrote TPC-DS pruning report: {}", pruning_file.display());
+            }
+            if let Some(gateway) = push_gateway.as_ref() {
+                let url = push_run_metrics(gateway, &output).await?;
+                println!("pushed metrics: {url}");
+            }
+        }
+        Command::Clean {
+            remote,
+            retention_hours,
+            dry_run,
+        } => {
+            if !remote {
+                return Err(BenchError::InvalidArgument(
+                    "clean currently requires --remote; use scripts/cleanup_local.sh for local artifacts"
+                        .to_string(),
+                ));
+            }
+            if storage.is_local() {
+                return Err(BenchError::InvalidArgument(
+                    "clean --remote requires a non-local storage backend".to_string(),
+                ));
+            }
+            let tables = storage.list_isolated_tables().await?;
+            let found = tables.len();
+            let expired = isolated_tables_older_than(
+                tables,
+                Duration::from_secs(retention_hours.saturating_mul(3600)),
+                SystemTime::now(),
+            );
+            let mut deleted_objects = 0_usize;
+            for table in &expired {
+                if dry_run {
+                    println!(
+                        "would delete {}/{} ({} object(s))",
+                        table.prefix, table.table_name, table.object_count
+                    );
+                } else {
+                    let deleted = storage.delete_isolated_table(table).await?;
+                    deleted_objects += deleted;
+                    println!(
+                        "deleted {}/{} ({deleted} object(s))",
+                        table.prefix, table.table_name
+                    );
+                }
+            }
+            println!(
+                "clean summary: {found} isolated table(s), {} older than {retention_hours}h{}",
+                expired.len(),
+                if dry_run {
+                    " (dry run, nothing deleted)".to_string()
+                } else {
+                    format!(", {deleted_objects} object(s) deleted")
+                }
+            );
+        }
+        Command::Trace {
+            scale,
+            case_filter,
+            out_dir,
+        } => {
+            for path in write_suite_case_traces(&scale, case_filter.as_deref(), &out_dir)? {
+                println!("wrote trace: {}", path.display());
+            }
+        }
+        Command::Export { input, out, redact } => {
+            export_run_result(&input, &out, redact)?;
+            println!("wrote export: {}", out.display());
+        }
+        Command::Report { input, format, out } => {
+            let rendered = render_run_report(&ReporterRegistry::builtin(), &input, &format)?;
+            match out {
+                Some(out) => {
+                    if let Some(parent) =
+                        out.parent().filter(|parent| !parent.as_os_str().is_empty())
+                    {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&out, rendered)?;
+                    println!("wrote {format} report: {}", out.display());
+                }
+                None => print!("{rendered}"),
+            }
+        }
+        Command::Query { sql, out } => {
+            let result = analysis::query(&args.results_dir, &sql).await?;
+            match out {
+                Some(out) => {
+                    result.write_parquet(&out)?;
+                    println!("wrote {} rows: {}", result.num_rows(), out.display());
+                }
+                None => println!("{}", result.to_table_string()?),
+            }
+        }
+        Command::CrashWriter { table_dir, appends } => {
+            crash_recovery::run_crash_writer(&table_dir, appends).await?;
+        }
+        Command::Smoke { work_dir } => {
+            let keep = work_dir.is_some();
+            let temp_dir;
+            let work_dir = match work_dir {
+                Some(work_dir) => work_dir,
+                None => {
+                    temp_dir = tempfile::Builder::new()
+                        .prefix("delta-bench-smoke-")
+                        .tempdir()?;
+                    temp_dir.path().to_path_buf()
+                }
+            };
+            fs::create_dir_all(&work_dir)?;
+            let report = run_smoke(&work_dir).await?;
+            println!("{}", render_run_summary_table(&report.cases));
+            for case in report.cases.iter().filter(|case| !case.success) {
+                if let Some(failure) = &case.failure {
+                    println!("{}: {}", case.case, failure.message);
+                }
+            }
+            println!(
+                "smoke: {} case(s) in {:.1}s ({:.1}s generating fixtures)",
+                report.cases.len(),
+                report.elapsed_ms / 1_000.0,
+                report.fixtures_ms / 1_000.0
+            );
+            if keep {
+                println!("kept smoke fixtures: {}", work_dir.display());
+            }
+            if !report.passed() {
+                return Err(BenchError::InvalidArgument(
+                    "smoke check failed; see the failed cases above".to_string(),
+                ));
+            }
         }
         Command::Doctor => {
             println!("delta-bench doctor");
@@ -336,6 +708,23 @@ fn resolve_scale(scale: &str, dataset: Option<DatasetId>) -> BenchResult<String>
     Ok(dataset.scale().to_string())
 }
 
+fn resolve_data_scales(
+    requested: Vec<String>,
+    dataset: Option<DatasetId>,
+) -> BenchResult<Vec<String>> {
+    let Some(dataset) = dataset else {
+        return Ok(requested);
+    };
+    if requested.len() > 1 {
+        return Err(BenchError::InvalidArgument(format!(
+            "--dataset-id {} pins scale {}; multiple scales are only supported without --dataset-id",
+            dataset.as_str(),
+            dataset.scale()
+        )));
+    }
+    Ok(vec![dataset.scale().to_string()])
+}
+
 fn resolve_fixture_profile(dataset: Option<DatasetId>) -> BenchResult<FixtureProfile> {
     let Some(dataset) = dataset else {
         return Ok(FixtureProfile::Standard);
@@ -343,426 +732,222 @@ fn resolve_fixture_profile(dataset: Option<DatasetId>) -> BenchResult<FixturePro
     Ok(match dataset.fixture_profile() {
         "many_versions" => FixtureProfile::ManyVersions,
         "tpcds_duckdb" => FixtureProfile::TpcdsDuckdb,
+        "late_arriving" => FixtureProfile::LateArriving,
+        "small_files" => FixtureProfile::SmallFiles,
         _ => FixtureProfile::Standard,
     })
 }
 
-fn parse_dataset(dataset_id: Option<&str>) -> BenchResult<Option<DatasetId>> {
-    dataset_id.map(DatasetId::parse).transpose()
+/// Loads the `<target>.json` result a previous `run --target <target>` wrote under `dir`.
+fn load_previous_run(dir: &Path, target: &str) -> BenchResult<(PathBuf, BenchRunResult)> {
+    let path = dir.join(format!("{target}.json"));
+    let bytes = fs::read(&path).map_err(|error| {
+        BenchError::InvalidArgument(format!(
+            "failed to read previous run result '{}': {error}",
+            path.display()
+        ))
+    })?;
+    let previous = serde_json::from_slice(&bytes)?;
+    Ok((path, previous))
 }
 
-fn validate_execution_contract(
-    benchmark_mode: BenchmarkMode,
-    lane: BenchmarkLane,
-) -> BenchResult<()> {
-    if benchmark_mode == BenchmarkMode::Assert && lane != BenchmarkLane::Correctness {
-        return Err(BenchError::InvalidArgument(
-            "--mode assert requires --lane correctness".to_string(),
-        ));
+fn previous_runner(previous: &BenchRunResult) -> BenchResult<RunnerMode> {
+    match previous.context.runner.as_deref() {
+        None => Ok(RunnerMode::All),
+        Some(runner) => RunnerMode::from_str(runner, true).map_err(|_| {
+            BenchError::InvalidArgument(format!(
+                "previous run recorded unknown runner '{runner}'; pass --runner explicitly"
+            ))
+        }),
     }
-    Ok(())
 }
 
-fn finalize_cases(
-    mut cases: Vec<delta_bench::results::CaseResult>,
-    plan: &[delta_bench::suites::PlannedCase],
-    benchmark_mode: BenchmarkMode,
-    lane: BenchmarkLane,
-    context: &BenchContext,
-) -> BenchResult<Vec<delta_bench::results::CaseResult>> {
-    for (case, planned) in cases.iter_mut().zip(plan.iter()) {
-        case.run_summary = Some(build_run_summary(
-            &case.samples,
-            Some(context.host.as_str()),
-            context.fidelity_fingerprint.as_deref(),
-        ));
-        case.suite_manifest_hash = Some(planned.suite_manifest_hash.clone());
-        case.case_definition_hash = Some(planned.case_definition_hash.clone());
-        case.supports_decision = Some(planned.supports_decision);
-        case.required_runs = planned.required_runs;
-        case.decision_threshold_pct = planned.decision_threshold_pct;
-        case.decision_metric = planned.decision_metric.clone();
-        case.compatibility_key =
-            compute_case_compatibility_key(planned, lane, context).map(Some)?;
-        if benchmark_mode == BenchmarkMode::Assert
-            || lane == BenchmarkLane::Correctness
-            || lane == BenchmarkLane::Smoke
-            || (lane == BenchmarkLane::Macro && planned.lane == BenchmarkLane::Correctness.as_str())
-        {
-            case.perf_status = if case.validation_passed {
-                delta_bench::results::PerfStatus::ValidationOnly
-            } else {
-                delta_bench::results::PerfStatus::Invalid
-            };
-            case.elapsed_stats = None;
-        }
+fn render_plan_diff(diff: &PlanDiff) -> String {
+    if diff.is_empty() {
+        return "  no case changes\n".to_string();
     }
-    Ok(cases)
-}
-
-fn measurement_kind_for_target(target: &str) -> &'static str {
-    if matches!(target, "scan" | "tpcds") {
-        "phase_breakdown"
-    } else {
-        "end_to_end"
+    let mut out = String::new();
+    for id in &diff.added {
+        out.push_str(&format!("  + {id}\n"));
     }
-}
-
-fn validation_level_for_run_plan(
-    plan: &[delta_bench::suites::PlannedCase],
-    lane: BenchmarkLane,
-) -> &'static str {
-    if lane != BenchmarkLane::Correctness {
-        return "operational";
+    for id in &diff.removed {
+        out.push_str(&format!("  - {id}\n"));
     }
-    if plan.iter().all(case_supports_semantic_validation) {
-        "semantic"
-    } else {
-        "operational"
+    for (old, new) in &diff.renamed {
+        out.push_str(&format!("  ~ {old} -> {new}\n"));
     }
+    out
 }
 
-fn case_supports_semantic_validation(case: &delta_bench::suites::PlannedCase) -> bool {
-    matches!(
-        case.target.as_str(),
-        "write"
-            | "delete_update"
-            | "delete_update_perf"
-            | "merge"
-            | "merge_perf"
-            | "metadata"
-            | "optimize_perf"
-            | "optimize_vacuum"
-            | "interop_py"
-    )
-}
-
-fn compute_fidelity_fingerprint(
-    fidelity: &delta_bench::system::BenchmarkFidelityInfo,
-) -> BenchResult<String> {
-    hash_json(&serde_json::json!({
-        "image_version": fidelity.image_version,
-        "hardening_profile_id": fidelity.hardening_profile_id,
-        "hardening_profile_sha256": fidelity.hardening_profile_sha256,
-        "cpu_model": fidelity.cpu_model,
-        "cpu_microcode": fidelity.cpu_microcode,
-        "kernel": fidelity.kernel,
-        "boot_params": fidelity.boot_params,
-        "cpu_steal_pct": fidelity.cpu_steal_pct,
-        "numa_topology": fidelity.numa_topology,
-        "egress_policy_sha256": fidelity.egress_policy_sha256,
-        "run_mode": fidelity.run_mode,
-        "maintenance_window_id": fidelity.maintenance_window_id,
-    }))
-}
-
-fn compute_run_id(
-    label: &str,
-    git_sha: Option<&str>,
-    suite: &str,
-    scale: &str,
-    lane: &str,
-    timing_phase: &str,
-) -> BenchResult<String> {
-    hash_json(&serde_json::json!({
-        "label": label,
-        "git_sha": git_sha,
-        "suite": suite,
-        "scale": scale,
-        "lane": lane,
-        "timing_phase": timing_phase,
-        "created_at": Utc::now().to_rfc3339(),
-    }))
+/// Checks every requested scale and fails once all of them are reported, so a single call
+/// lists every broken table.
+async fn run_data_archive_command(
+    fixtures_dir: &Path,
+    command: DataArchiveCommand,
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    if !storage.is_local() {
+        return Err(BenchError::InvalidArgument(
+            "fixture archives hold local fixture directories; use --storage-backend local"
+                .to_string(),
+        ));
+    }
+    match command {
+        DataArchiveCommand::Export {
+            archive,
+            scale,
+            dataset_id,
+        } => {
+            let dataset = parse_dataset(dataset_id.as_deref())?;
+            let scale = resolve_data_scales(vec![scale], dataset)?.remove(0);
+            let index = export_fixture_archive(fixtures_dir, &scale, dataset, &archive)?;
+            println!(
+                "exported {scale} fixtures ({} file(s), {} bytes) to {}",
+                index.files.len(),
+                index.total_bytes(),
+                archive.display()
+            );
+        }
+        DataArchiveCommand::Import {
+            archive,
+            force,
+            trash,
+        } => {
+            let index = import_fixture_archive(fixtures_dir, &archive, force, trash).await?;
+            println!(
+                "imported {} fixtures ({} file(s), {} bytes, {}) from {}",
+                index.scale,
+                index.files.len(),
+                index.total_bytes(),
+                index.dataset_fingerprint,
+                archive.display()
+            );
+        }
+    }
+    Ok(())
 }
 
-fn compute_case_compatibility_key(
-    planned: &delta_bench::suites::PlannedCase,
-    lane: BenchmarkLane,
-    context: &BenchContext,
-) -> BenchResult<String> {
-    if planned
-        .decision_threshold_pct
-        .is_some_and(|threshold| !threshold.is_finite())
-    {
+async fn verify_fixtures(
+    fixtures_dir: &Path,
+    scales: &[String],
+    dataset: Option<DatasetId>,
+    storage: &StorageConfig,
+) -> BenchResult<()> {
+    let mut failed = 0_usize;
+    for scale in scales {
+        let manifest = load_manifest(fixtures_dir, scale)?;
+        verify_fixture_manifest(&manifest, dataset, None)?;
+        let checks = verify_fixture_tables(fixtures_dir, &manifest, storage).await?;
+        println!(
+            "verify {} (scale={scale}, {} table(s)):",
+            fixture_root(fixtures_dir, scale).display(),
+            checks.len()
+        );
+        print!("{}", render_fixture_table_checks(&checks));
+        failed += checks.iter().filter(|check| !check.passed()).count();
+    }
+    if failed > 0 {
         return Err(BenchError::InvalidArgument(format!(
-            "decision_threshold_pct must be finite for compatibility_key generation (case='{}')",
-            planned.id
+            "{failed} fixture table(s) failed verification; rerun bench.sh data with --force"
         )));
     }
+    println!("fixtures verified at {}", fixtures_dir.display());
+    Ok(())
+}
 
-    #[derive(Serialize)]
-    struct CompatibilityKeyInput<'a> {
-        suite_scope: &'a str,
-        target: &'a str,
-        runner: Option<&'a str>,
-        timing_phase: Option<&'a str>,
-        dataset_id: Option<&'a str>,
-        dataset_fingerprint: Option<&'a str>,
-        scale: &'a str,
-        storage_backend: Option<&'a str>,
-        backend_profile: Option<&'a str>,
-        lane: &'a str,
-        measurement_kind: Option<&'a str>,
-        validation_level: Option<&'a str>,
-        harness_revision: Option<&'a str>,
-        fixture_recipe_hash: Option<&'a str>,
-        fidelity_fingerprint: Option<&'a str>,
-        planned_lane: &'a str,
-        suite_manifest_hash: &'a str,
-        case_definition_hash: &'a str,
-        supports_decision: bool,
-        required_runs: Option<u32>,
-        decision_threshold_pct: Option<f64>,
-        decision_metric: Option<&'a str>,
+fn render_fixture_table_checks(checks: &[FixtureTableCheck]) -> String {
+    let width = checks
+        .iter()
+        .map(|check| check.table.len())
+        .max()
+        .unwrap_or_default();
+    let mut out = String::new();
+    for check in checks {
+        let mut line = format!(
+            "  {} {:<width$}",
+            if check.passed() { "ok  " } else { "FAIL" },
+            check.table
+        );
+        if let Some(rows) = check.rows {
+            line.push_str(&format!(" rows={rows}"));
+        }
+        if let Some(files) = check.files {
+            line.push_str(&format!(" files={files}"));
+        }
+        if !check.partition_columns.is_empty() {
+            line.push_str(&format!(
+                " partitions={}",
+                check.partition_columns.join(",")
+            ));
+        }
+        if !check.passed() {
+            line.push_str(&format!(": {}", check.problems.join("; ")));
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
     }
-
-    hash_json(&CompatibilityKeyInput {
-        suite_scope: context.suite.as_str(),
-        target: planned.target.as_str(),
-        runner: context.runner.as_deref(),
-        timing_phase: context.timing_phase.as_deref(),
-        dataset_id: context.dataset_id.as_deref(),
-        dataset_fingerprint: context.dataset_fingerprint.as_deref(),
-        scale: context.scale.as_str(),
-        storage_backend: context.storage_backend.as_deref(),
-        backend_profile: context.backend_profile.as_deref(),
-        lane: lane.as_str(),
-        measurement_kind: context.measurement_kind.as_deref(),
-        validation_level: context.validation_level.as_deref(),
-        harness_revision: context.harness_revision.as_deref(),
-        fixture_recipe_hash: context.fixture_recipe_hash.as_deref(),
-        fidelity_fingerprint: context.fidelity_fingerprint.as_deref(),
-        planned_lane: planned.lane.as_str(),
-        suite_manifest_hash: planned.suite_manifest_hash.as_str(),
-        case_definition_hash: planned.case_definition_hash.as_str(),
-        supports_decision: planned.supports_decision,
-        required_runs: planned.required_runs,
-        decision_threshold_pct: planned.decision_threshold_pct,
-        decision_metric: planned.decision_metric.as_deref(),
-    })
+    out
 }
 
-fn command_requires_manifest_preflight(command: &Command) -> bool {
-    matches!(command, Command::List { .. } | Command::Run { .. })
+fn parse_dataset(dataset_id: Option<&str>) -> BenchResult<Option<DatasetId>> {
+    dataset_id.map(DatasetId::parse).transpose()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{compute_case_compatibility_key, finalize_cases, validate_execution_contract};
-    use chrono::Utc;
-    use delta_bench::cli::{BenchmarkLane, BenchmarkMode};
-    use delta_bench::error::BenchError;
-    use delta_bench::results::{
-        BenchContext, CaseResult, ElapsedStats, IterationSample, PerfStatus,
+/// Values for `--label-template`. Revisions are shortened to 12 characters and missing ones
+/// expand to `unknown`, so a template always yields a label.
+fn label_template_values(
+    git_sha: Option<&str>,
+    harness_revision: Option<&str>,
+    storage_backend: StorageBackend,
+    target: &str,
+    scale: &str,
+    dataset_id: Option<&str>,
+    lane: BenchmarkLane,
+) -> Vec<(&'static str, String)> {
+    let short = |revision: Option<&str>| {
+        revision
+            .map(|sha| sha.chars().take(12).collect())
+            .unwrap_or_else(|| "unknown".to_string())
     };
-    use delta_bench::suites::PlannedCase;
+    let now = Utc::now();
+    vec![
+        ("date", now.format("%Y%m%d").to_string()),
+        ("time", now.format("%H%M%S").to_string()),
+        ("git_sha", short(git_sha)),
+        ("harness_sha", short(harness_revision)),
+        ("backend", storage_backend.as_str().to_string()),
+        ("suite", target.to_string()),
+        ("scale", scale.to_string()),
+        ("dataset", dataset_id.unwrap_or("none").to_string()),
+        ("lane", lane.as_str().to_string()),
+        ("host", host_name()),
+    ]
+}
 
-    fn planned_case(decision_threshold_pct: Option<f64>) -> PlannedCase {
-        PlannedCase {
-            id: "case-a".to_string(),
-            target: "scan".to_string(),
-            lane: "macro".to_string(),
-            assertions: Vec::new(),
-            suite_manifest_hash: "sha256:manifest".to_string(),
-            case_definition_hash: "sha256:case-def".to_string(),
-            supports_decision: true,
-            required_runs: Some(5),
-            decision_threshold_pct,
-            decision_metric: Some("median".to_string()),
+/// Resolves the fixtures and results directories once, so a bad path fails before any work
+/// with the path in the message. Commands that write to a directory get it created, checked
+/// for writability, and canonicalized; the rest only get `~` expanded.
+fn prepare_bench_dirs(args: &mut Args) -> BenchResult<()> {
+    match args.command {
+        Command::Run { .. } => {
+            args.fixtures_dir = prepare_writable_dir("fixtures", &args.fixtures_dir)?;
+            args.results_dir = prepare_writable_dir("results", &args.results_dir)?;
         }
-    }
-
-    fn case_result() -> CaseResult {
-        CaseResult {
-            case: "case-a".to_string(),
-            success: true,
-            validation_passed: true,
-            perf_status: PerfStatus::Trusted,
-            classification: "supported".to_string(),
-            samples: Vec::new(),
-            elapsed_stats: None,
-            run_summary: None,
-            run_summaries: None,
-            suite_manifest_hash: None,
-            case_definition_hash: None,
-            compatibility_key: None,
-            supports_decision: None,
-            required_runs: None,
-            decision_threshold_pct: None,
-            decision_metric: None,
-            failure_kind: None,
-            failure: None,
+        Command::Data { .. } => {
+            args.fixtures_dir = prepare_writable_dir("fixtures", &args.fixtures_dir)?;
+            args.results_dir = expand_home(&args.results_dir)?;
         }
-    }
-
-    fn timed_case_result() -> CaseResult {
-        let mut case = case_result();
-        case.samples = vec![IterationSample {
-            elapsed_ms: 123.0,
-            rows: None,
-            bytes: None,
-            metrics: None,
-        }];
-        case.elapsed_stats = Some(ElapsedStats {
-            min_ms: 123.0,
-            max_ms: 123.0,
-            mean_ms: 123.0,
-            median_ms: 123.0,
-            stddev_ms: 0.0,
-            cv_pct: Some(0.0),
-        });
-        case
-    }
-
-    fn bench_context() -> BenchContext {
-        BenchContext {
-            schema_version: 5,
-            label: "test".to_string(),
-            git_sha: Some("abc123".to_string()),
-            created_at: Utc::now(),
-            host: "host-a".to_string(),
-            suite: "scan".to_string(),
-            scale: "sf1".to_string(),
-            iterations: 5,
-            warmup: 1,
-            timing_phase: Some("execute".to_string()),
-            dataset_id: Some("tiny_smoke".to_string()),
-            dataset_fingerprint: Some("sha256:dataset".to_string()),
-            runner: Some("rust".to_string()),
-            storage_backend: Some("local".to_string()),
-            benchmark_mode: Some("perf".to_string()),
-            lane: Some("macro".to_string()),
-            measurement_kind: Some("phase_breakdown".to_string()),
-            validation_level: Some("operational".to_string()),
-            run_id: Some("sha256:run".to_string()),
-            harness_revision: Some("harness-1".to_string()),
-            fixture_recipe_hash: Some("sha256:recipe-a".to_string()),
-            fidelity_fingerprint: Some("sha256:fidelity".to_string()),
-            backend_profile: Some("local".to_string()),
-            image_version: None,
-            hardening_profile_id: None,
-            hardening_profile_sha256: None,
-            cpu_model: None,
-            cpu_microcode: None,
-            kernel: None,
-            boot_params: None,
-            cpu_steal_pct: None,
-            numa_topology: None,
-            egress_policy_sha256: None,
-            run_mode: None,
-            maintenance_window_id: None,
+        _ => {
+            args.fixtures_dir = expand_home(&args.fixtures_dir)?;
+            args.results_dir = expand_home(&args.results_dir)?;
         }
     }
+    Ok(())
+}
 
-    #[test]
-    fn compatibility_key_rejects_non_finite_decision_thresholds() {
-        let err = compute_case_compatibility_key(
-            &planned_case(Some(f64::NAN)),
-            BenchmarkLane::Macro,
-            &bench_context(),
-        )
-        .expect_err("non-finite thresholds must fail compatibility hashing");
-
-        assert!(
-            matches!(err, BenchError::InvalidArgument(_)),
-            "unexpected error: {err}"
-        );
-    }
-
-    #[test]
-    fn finalize_cases_propagates_compatibility_key_errors() {
-        let err = finalize_cases(
-            vec![case_result()],
-            &[planned_case(Some(f64::NAN))],
-            BenchmarkMode::Perf,
-            BenchmarkLane::Macro,
-            &bench_context(),
-        )
-        .expect_err("finalization must not silently drop compatibility-key failures");
-
-        assert!(
-            matches!(err, BenchError::InvalidArgument(_)),
-            "unexpected error: {err}"
-        );
-    }
-
-    #[test]
-    fn compatibility_key_changes_when_context_identity_changes() {
-        let planned = planned_case(Some(5.0));
-        let baseline_context = bench_context();
-        let baseline =
-            compute_case_compatibility_key(&planned, BenchmarkLane::Macro, &baseline_context)
-                .expect("baseline compatibility key");
-        let mut fixture_context = bench_context();
-        fixture_context.fixture_recipe_hash = Some("sha256:recipe-b".to_string());
-        let fixture_changed =
-            compute_case_compatibility_key(&planned, BenchmarkLane::Macro, &fixture_context)
-                .expect("fixture change must hash");
-        let mut runner_context = bench_context();
-        runner_context.runner = Some("python".to_string());
-        let runner_changed =
-            compute_case_compatibility_key(&planned, BenchmarkLane::Macro, &runner_context)
-                .expect("runner change must hash");
-
-        assert_ne!(baseline, fixture_changed);
-        assert_ne!(baseline, runner_changed);
-    }
-
-    #[test]
-    fn finalize_cases_marks_correctness_tagged_macro_runs_validation_only() {
-        let mut planned = planned_case(Some(5.0));
-        planned.target = "write".to_string();
-        planned.lane = "correctness".to_string();
-
-        let cases = finalize_cases(
-            vec![timed_case_result()],
-            &[planned],
-            BenchmarkMode::Perf,
-            BenchmarkLane::Macro,
-            &bench_context(),
-        )
-        .expect("finalization succeeds");
-
-        let case = &cases[0];
-        assert!(case.success);
-        assert_eq!(case.perf_status, PerfStatus::ValidationOnly);
-        assert!(case.elapsed_stats.is_none());
-        assert_eq!(case.samples.len(), 1);
-    }
-
-    #[test]
-    fn finalize_cases_preserves_invalid_status_for_failed_correctness_runs() {
-        let mut case = timed_case_result();
-        case.success = false;
-        case.validation_passed = false;
-        case.perf_status = PerfStatus::Invalid;
-        case.elapsed_stats = None;
-
-        let cases = finalize_cases(
-            vec![case],
-            &[planned_case(Some(5.0))],
-            BenchmarkMode::Assert,
-            BenchmarkLane::Correctness,
-            &bench_context(),
-        )
-        .expect("finalization succeeds");
-
-        let case = &cases[0];
-        assert!(!case.success);
-        assert_eq!(case.perf_status, PerfStatus::Invalid);
-        assert!(case.elapsed_stats.is_none());
-    }
-
-    #[test]
-    fn assert_mode_requires_correctness_lane() {
-        let err = validate_execution_contract(BenchmarkMode::Assert, BenchmarkLane::Smoke)
-            .expect_err("assert mode outside correctness must fail");
-        assert!(
-            matches!(err, BenchError::InvalidArgument(_)),
-            "unexpected error: {err}"
-        );
-
-        validate_execution_contract(BenchmarkMode::Assert, BenchmarkLane::Correctness)
-            .expect("correctness lane should be allowed");
-    }
+fn command_requires_manifest_preflight(command: &Command) -> bool {
+    matches!(
+        command,
+        Command::List { .. } | Command::Plan { .. } | Command::Run { .. }
+    )
 }
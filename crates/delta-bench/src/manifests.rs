@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
@@ -8,13 +9,26 @@ use crate::error::{BenchError, BenchResult};
 
 pub const DEFAULT_RUST_MANIFEST_PATH: &str = "bench/manifests/core_rust.yaml";
 pub const DEFAULT_PYTHON_MANIFEST_PATH: &str = "bench/manifests/core_python.yaml";
+/// Case ids are embedded in result file paths and exported as metric labels downstream.
+pub const MAX_CASE_ID_LEN: usize = 64;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BenchmarkManifest {
     pub id: String,
     pub description: String,
+    /// Manifests, relative to the benchmark repository root, whose cases and aliases are
+    /// merged in ahead of this manifest's own.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub includes: Vec<String>,
     #[serde(default)]
     pub cases: Vec<ManifestCase>,
+    /// Retired case id -> current case id, so renames keep their history in compare and
+    /// longitudinal reports.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+    /// Every file the manifest was composed from, itself first, in load order.
+    #[serde(skip)]
+    pub source_files: Vec<PathBuf>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -36,9 +50,36 @@ pub struct ManifestCase {
     #[serde(default)]
     pub decision_metric: Option<String>,
     #[serde(default)]
-    pub assertions: Vec<ManifestAssertion>,
+    pub assertions: Vec<ManifestCaseAssertion>,
+    /// Cases of the same target and runner that must run first; this case starts from the
+    /// table state they leave behind.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Overrides the run's `--warmup` for this case in the macro lane.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<u32>,
+    /// Overrides the run's `--iterations` for this case in the macro lane.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iterations: Option<u32>,
+    /// Fails any warmup or measured iteration of this case that runs longer than this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    /// Labels such as `smoke` or `slow` that `bench run --tags` / `--exclude-tags` select on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Names the result this case computes. A `runner=all` run cross-checks a Rust case and a
+    /// Python case that share a workload (see [`crate::suites::interop_consistency`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interop_workload: Option<String>,
+    /// Parameter name -> values to sweep. The case then names one of its suite's matrix
+    /// templates and plans as one case per combination (see [`expand_case_matrix`]).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub matrix: BTreeMap<String, Vec<f64>>,
 }
 
+/// One combination of a matrix case's parameter values.
+pub type MatrixParameters = BTreeMap<String, f64>;
+
 const fn default_enabled() -> bool {
     true
 }
@@ -59,6 +100,31 @@ fn valid_manifest_lanes() -> [&'static str; 3] {
     ]
 }
 
+/// A manifest assertion and how a violation is reported.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ManifestCaseAssertion {
+    #[serde(flatten)]
+    pub assertion: ManifestAssertion,
+    #[serde(default, skip_serializing_if = "AssertionSeverity::is_fail")]
+    pub severity: AssertionSeverity,
+}
+
+/// `warn` rolls a new expectation out in observe-only mode: a violation is recorded in the
+/// case's `warnings` and leaves the case passing, until the assertion is promoted to `fail`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssertionSeverity {
+    #[default]
+    Fail,
+    Warn,
+}
+
+impl AssertionSeverity {
+    pub fn is_fail(&self) -> bool {
+        *self == Self::Fail
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ManifestAssertion {
@@ -66,6 +132,12 @@ pub enum ManifestAssertion {
     SchemaHash { value: String },
     ExpectedErrorContains { value: String },
     VersionMonotonicity,
+    MaxMedianMs { value: f64 },
+    MaxP95Ms { value: f64 },
+    MinFilesPruned { value: u64 },
+    MaxFilesScanned { value: u64 },
+    MaxBytesScanned { value: u64 },
+    RowCountEquals { value: u64 },
 }
 
 impl ManifestAssertion {
@@ -77,6 +149,12 @@ impl ManifestAssertion {
                 CaseAssertion::ExpectedErrorContains(value.clone())
             }
             Self::VersionMonotonicity => CaseAssertion::VersionMonotonicity,
+            Self::MaxMedianMs { value } => CaseAssertion::MaxMedianMs(*value),
+            Self::MaxP95Ms { value } => CaseAssertion::MaxP95Ms(*value),
+            Self::MinFilesPruned { value } => CaseAssertion::MinFilesPruned(*value),
+            Self::MaxFilesScanned { value } => CaseAssertion::MaxFilesScanned(*value),
+            Self::MaxBytesScanned { value } => CaseAssertion::MaxBytesScanned(*value),
+            Self::RowCountEquals { value } => CaseAssertion::RowCountEquals(*value),
         }
     }
 }
@@ -88,6 +166,7 @@ pub enum DatasetId {
     SmallFiles,
     ManyVersions,
     TpcdsDuckdb,
+    LateArriving,
 }
 
 impl DatasetId {
@@ -98,20 +177,34 @@ impl DatasetId {
             "small_files" => Ok(Self::SmallFiles),
             "many_versions" => Ok(Self::ManyVersions),
             "tpcds_duckdb" => Ok(Self::TpcdsDuckdb),
+            "late_arriving" => Ok(Self::LateArriving),
             other => Err(BenchError::InvalidArgument(format!(
-                "unknown dataset_id '{other}' (expected one of: tiny_smoke, medium_selective, small_files, many_versions, tpcds_duckdb)"
+                "unknown dataset_id '{other}' (expected one of: tiny_smoke, medium_selective, small_files, many_versions, tpcds_duckdb, late_arriving)"
             ))),
         }
     }
 
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::TinySmoke => "tiny_smoke",
+            Self::MediumSelective => "medium_selective",
+            Self::SmallFiles => "small_files",
+            Self::ManyVersions => "many_versions",
+            Self::TpcdsDuckdb => "tpcds_duckdb",
+            Self::LateArriving => "late_arriving",
+        }
+    }
+
     pub const fn scale(self) -> &'static str {
         match self {
             Self::TinySmoke => "sf1",
             Self::MediumSelective => "sf10",
-            // P0 maps these scenario IDs to the currently supported scale; suites derive shape.
+            // P0 maps these scenario IDs to the currently supported scale; the fixture profile
+            // adds the shape.
             Self::SmallFiles => "sf1",
             Self::ManyVersions => "sf1",
             Self::TpcdsDuckdb => "sf1",
+            Self::LateArriving => "sf1",
         }
     }
 
@@ -119,7 +212,9 @@ impl DatasetId {
         match self {
             Self::ManyVersions => "many_versions",
             Self::TpcdsDuckdb => "tpcds_duckdb",
-            Self::TinySmoke | Self::MediumSelective | Self::SmallFiles => "standard",
+            Self::LateArriving => "late_arriving",
+            Self::SmallFiles => "small_files",
+            Self::TinySmoke | Self::MediumSelective => "standard",
         }
     }
 }
@@ -138,27 +233,124 @@ impl DatasetId {
             Self::TinySmoke => DatasetAssertionPolicy {
                 relax_exact_result_hash: false,
             },
-            Self::MediumSelective | Self::SmallFiles | Self::ManyVersions | Self::TpcdsDuckdb => {
-                DatasetAssertionPolicy {
-                    relax_exact_result_hash: true,
-                }
-            }
+            Self::MediumSelective
+            | Self::SmallFiles
+            | Self::ManyVersions
+            | Self::TpcdsDuckdb
+            | Self::LateArriving => DatasetAssertionPolicy {
+                relax_exact_result_hash: true,
+            },
         }
     }
 }
 
 pub fn load_manifest(path: impl AsRef<Path>) -> BenchResult<BenchmarkManifest> {
     let path = path.as_ref();
+    let mut stack = Vec::new();
+    let mut source_files = Vec::new();
+    let mut manifest = compose_manifest(path, &mut stack, &mut source_files)?;
+    manifest.source_files = source_files;
+    validate_manifest(path, manifest)
+}
+
+/// Parses `path` and merges in its `includes`, depth first, ahead of its own cases. `stack`
+/// holds the manifests being composed, so an include cycle is rejected; an include already
+/// merged through another branch is skipped.
+fn compose_manifest(
+    path: &Path,
+    stack: &mut Vec<PathBuf>,
+    source_files: &mut Vec<PathBuf>,
+) -> BenchResult<BenchmarkManifest> {
+    let canonical = path.canonicalize()?;
+    if let Some(start) = stack.iter().position(|entry| *entry == canonical) {
+        let cycle = stack[start..]
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|entry| entry.display().to_string())
+            .collect::<Vec<_>>();
+        return Err(BenchError::InvalidArgument(format!(
+            "invalid manifest '{}': manifest includes form a cycle: {}",
+            path.display(),
+            cycle.join(" -> ")
+        )));
+    }
     let bytes = std::fs::read(path)?;
-    let manifest = serde_yaml::from_slice::<BenchmarkManifest>(&bytes).map_err(|error| {
+    let mut manifest = serde_yaml::from_slice::<BenchmarkManifest>(&bytes).map_err(|error| {
         BenchError::InvalidArgument(format!("invalid manifest '{}': {error}", path.display()))
     })?;
-    validate_manifest(path, manifest)
+    source_files.push(canonical.clone());
+    stack.push(canonical);
+
+    let mut cases = Vec::new();
+    let mut aliases = BTreeMap::new();
+    for include in &manifest.includes {
+        let include_path = resolve_manifest_include(include);
+        if !include_path.is_file() {
+            return Err(BenchError::InvalidArgument(format!(
+                "invalid manifest '{}': include '{include}' does not exist",
+                path.display()
+            )));
+        }
+        let canonical_include = include_path.canonicalize()?;
+        if source_files.contains(&canonical_include) && !stack.contains(&canonical_include) {
+            continue;
+        }
+        let included = compose_manifest(&include_path, stack, source_files)?;
+        cases.extend(included.cases);
+        merge_manifest_aliases(path, &mut aliases, included.aliases)?;
+    }
+    stack.pop();
+
+    cases.append(&mut manifest.cases);
+    manifest.cases = cases;
+    let own_aliases = std::mem::take(&mut manifest.aliases);
+    merge_manifest_aliases(path, &mut aliases, own_aliases)?;
+    manifest.aliases = aliases;
+    Ok(manifest)
+}
+
+fn resolve_manifest_include(include: &str) -> PathBuf {
+    let candidate = Path::new(include);
+    if candidate.is_absolute() {
+        return candidate.to_path_buf();
+    }
+    benchmark_repo_root().join(candidate)
+}
+
+fn merge_manifest_aliases(
+    path: &Path,
+    aliases: &mut BTreeMap<String, String>,
+    incoming: BTreeMap<String, String>,
+) -> BenchResult<()> {
+    for (old_id, new_id) in incoming {
+        match aliases.get(&old_id) {
+            Some(existing) if *existing != new_id => {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': alias '{old_id}' maps to both '{existing}' and '{new_id}'",
+                    path.display()
+                )));
+            }
+            _ => {
+                aliases.insert(old_id, new_id);
+            }
+        }
+    }
+    Ok(())
 }
 
 fn validate_manifest(path: &Path, manifest: BenchmarkManifest) -> BenchResult<BenchmarkManifest> {
     let valid_lanes = valid_manifest_lanes();
-    for case in &manifest.cases {
+    for (idx, case) in manifest.cases.iter().enumerate() {
+        if manifest.cases[..idx]
+            .iter()
+            .any(|other| other.id == case.id)
+        {
+            return Err(BenchError::InvalidArgument(format!(
+                "invalid manifest '{}': case '{}' is defined more than once",
+                path.display(),
+                case.id
+            )));
+        }
         if !valid_lanes.contains(&case.lane.as_str()) {
             return Err(BenchError::InvalidArgument(format!(
                 "invalid manifest '{}': case '{}' uses unsupported lane '{}' (expected one of: {})",
@@ -168,10 +360,318 @@ fn validate_manifest(path: &Path, manifest: BenchmarkManifest) -> BenchResult<Be
                 valid_lanes.join(", ")
             )));
         }
+        validate_case_id(&case.id).map_err(|error| {
+            BenchError::InvalidArgument(format!("invalid manifest '{}': {error}", path.display()))
+        })?;
+        for (idx, tag) in case.tags.iter().enumerate() {
+            validate_case_tag(tag).map_err(|error| {
+                BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': case '{}': {error}",
+                    path.display(),
+                    case.id
+                ))
+            })?;
+            if case.tags[..idx].contains(tag) {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': case '{}' lists tag '{tag}' more than once",
+                    path.display(),
+                    case.id
+                )));
+            }
+        }
+        validate_case_matrix(case).map_err(|error| {
+            BenchError::InvalidArgument(format!("invalid manifest '{}': {error}", path.display()))
+        })?;
+        if let Some(workload) = &case.interop_workload {
+            validate_case_id(workload).map_err(|error| {
+                BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': case '{}' interop_workload: {error}",
+                    path.display(),
+                    case.id
+                ))
+            })?;
+            if let Some(other) = manifest.cases[..idx].iter().find(|other| {
+                other.runner == case.runner && other.interop_workload.as_ref() == Some(workload)
+            }) {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': cases '{}' and '{}' both have interop_workload '{workload}'; a workload has one case per runner",
+                    path.display(),
+                    other.id,
+                    case.id
+                )));
+            }
+        }
+        for (field, value) in [
+            ("iterations", case.iterations.map(u64::from)),
+            ("timeout_ms", case.timeout_ms),
+        ] {
+            if value == Some(0) {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': case '{}' must have {field} > 0",
+                    path.display(),
+                    case.id
+                )));
+            }
+        }
+        for ManifestCaseAssertion {
+            assertion,
+            severity,
+        } in &case.assertions
+        {
+            if *severity == AssertionSeverity::Warn
+                && matches!(assertion, ManifestAssertion::ExpectedErrorContains { .. })
+            {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': case '{}' expected_error_contains assertion cannot have severity warn; it decides whether the failure is expected",
+                    path.display(),
+                    case.id
+                )));
+            }
+            if matches!(assertion, ManifestAssertion::MinFilesPruned { value: 0 }) {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': case '{}' min_files_pruned assertion must be > 0; a bound of 0 always holds",
+                    path.display(),
+                    case.id
+                )));
+            }
+            let (kind, budget) = match assertion {
+                ManifestAssertion::MaxMedianMs { value } => ("max_median_ms", *value),
+                ManifestAssertion::MaxP95Ms { value } => ("max_p95_ms", *value),
+                _ => continue,
+            };
+            if !budget.is_finite() || budget <= 0.0 {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': case '{}' {kind} assertion must be a positive number of milliseconds, got {budget}",
+                    path.display(),
+                    case.id
+                )));
+            }
+        }
+    }
+    let mut planned_ids = BTreeMap::new();
+    for case in manifest.cases.iter().filter(|case| !case.matrix.is_empty()) {
+        for (id, _) in expand_case_matrix(case) {
+            validate_case_id(&id).map_err(|error| {
+                BenchError::InvalidArgument(format!(
+                    "invalid manifest '{}': matrix case '{}' generates an invalid id: {error}",
+                    path.display(),
+                    case.id
+                ))
+            })?;
+            planned_ids.insert(id, case.id.as_str());
+        }
+    }
+    for case in &manifest.cases {
+        if let Some(base) = planned_ids.get(&case.id) {
+            return Err(BenchError::InvalidArgument(format!(
+                "invalid manifest '{}': matrix case '{base}' generates id '{}', which is already a case id",
+                path.display(),
+                case.id
+            )));
+        }
+    }
+    validate_case_dependencies(&manifest.cases).map_err(|error| {
+        BenchError::InvalidArgument(format!("invalid manifest '{}': {error}", path.display()))
+    })?;
+    for (old_id, new_id) in &manifest.aliases {
+        validate_case_id(old_id).map_err(|error| {
+            BenchError::InvalidArgument(format!("invalid manifest '{}': {error}", path.display()))
+        })?;
+        if manifest.cases.iter().any(|case| &case.id == old_id) {
+            return Err(BenchError::InvalidArgument(format!(
+                "invalid manifest '{}': alias '{old_id}' is still a case id; retire the case before aliasing it",
+                path.display()
+            )));
+        }
+        if !manifest.cases.iter().any(|case| &case.id == new_id) {
+            return Err(BenchError::InvalidArgument(format!(
+                "invalid manifest '{}': alias '{old_id}' points at unknown case '{new_id}'",
+                path.display()
+            )));
+        }
     }
     Ok(manifest)
 }
 
+/// A dependency must be another case with the same target and runner, since table state is
+/// only handed over within one suite execution, and an enabled case cannot depend on a
+/// disabled one. Dependencies may not form a cycle.
+fn validate_case_dependencies(cases: &[ManifestCase]) -> BenchResult<()> {
+    let by_id = cases
+        .iter()
+        .map(|case| (case.id.as_str(), case))
+        .collect::<BTreeMap<_, _>>();
+    for case in cases {
+        for dependency_id in &case.depends_on {
+            let Some(dependency) = by_id.get(dependency_id.as_str()) else {
+                return Err(BenchError::InvalidArgument(format!(
+                    "case '{}' depends on unknown case '{dependency_id}'",
+                    case.id
+                )));
+            };
+            if dependency.target != case.target || dependency.runner != case.runner {
+                return Err(BenchError::InvalidArgument(format!(
+                    "case '{}' depends on '{dependency_id}', which has a different target or runner",
+                    case.id
+                )));
+            }
+            if !case.matrix.is_empty() || !dependency.matrix.is_empty() {
+                return Err(BenchError::InvalidArgument(format!(
+                    "case '{}' depends on '{dependency_id}', but matrix cases cannot take part in dependencies",
+                    case.id
+                )));
+            }
+            if case.enabled && !dependency.enabled {
+                return Err(BenchError::InvalidArgument(format!(
+                    "case '{}' depends on disabled case '{dependency_id}'",
+                    case.id
+                )));
+            }
+        }
+    }
+
+    let mut resolved = BTreeMap::<&str, bool>::new();
+    for case in cases {
+        let mut path = Vec::new();
+        visit_case_dependencies(case.id.as_str(), &by_id, &mut resolved, &mut path)?;
+    }
+    Ok(())
+}
+
+/// Depth-first walk; `resolved` holds `false` while a case is on the current path.
+fn visit_case_dependencies<'a>(
+    id: &'a str,
+    by_id: &BTreeMap<&'a str, &'a ManifestCase>,
+    resolved: &mut BTreeMap<&'a str, bool>,
+    path: &mut Vec<&'a str>,
+) -> BenchResult<()> {
+    path.push(id);
+    match resolved.get(id) {
+        Some(true) => {}
+        Some(false) => {
+            let start = path.iter().position(|entry| *entry == id).unwrap_or(0);
+            return Err(BenchError::InvalidArgument(format!(
+                "case dependencies form a cycle: {}",
+                path[start..].join(" -> ")
+            )));
+        }
+        None => {
+            resolved.insert(id, false);
+            let case: &'a ManifestCase = by_id[id];
+            for dependency_id in &case.depends_on {
+                visit_case_dependencies(dependency_id.as_str(), by_id, resolved, path)?;
+            }
+            resolved.insert(id, true);
+        }
+    }
+    path.pop();
+    Ok(())
+}
+
+/// Expands a matrix case into `(id, parameters)` pairs, one per combination of values, ordered
+/// by parameter name and then by value as listed. Each parameter appends `_<name>_<value>` to
+/// the case id, with `.` in the value written as `p` and `-` as `m`, so `match_ratio: [0.25]`
+/// on `merge_upsert` plans `merge_upsert_match_ratio_0p25`.
+pub fn expand_case_matrix(case: &ManifestCase) -> Vec<(String, MatrixParameters)> {
+    let mut points = vec![(case.id.clone(), MatrixParameters::new())];
+    for (name, values) in &case.matrix {
+        let mut expanded = Vec::with_capacity(points.len() * values.len());
+        for (id, parameters) in &points {
+            for value in values {
+                let label = value.to_string().replace('.', "p").replace('-', "m");
+                let mut parameters = parameters.clone();
+                parameters.insert(name.clone(), *value);
+                expanded.push((format!("{id}_{name}_{label}"), parameters));
+            }
+        }
+        points = expanded;
+    }
+    points
+}
+
+fn validate_case_matrix(case: &ManifestCase) -> BenchResult<()> {
+    if !case.matrix.is_empty() && !case.depends_on.is_empty() {
+        return Err(BenchError::InvalidArgument(format!(
+            "case '{}' has a matrix and cannot use depends_on",
+            case.id
+        )));
+    }
+    if !case.matrix.is_empty() && case.interop_workload.is_some() {
+        return Err(BenchError::InvalidArgument(format!(
+            "case '{}' has a matrix and cannot set interop_workload",
+            case.id
+        )));
+    }
+    for (name, values) in &case.matrix {
+        validate_case_id(name).map_err(|_| {
+            BenchError::InvalidArgument(format!(
+                "case '{}' matrix parameter '{name}' must be lowercase snake_case",
+                case.id
+            ))
+        })?;
+        if values.is_empty() {
+            return Err(BenchError::InvalidArgument(format!(
+                "case '{}' matrix parameter '{name}' has no values",
+                case.id
+            )));
+        }
+        for (idx, value) in values.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(BenchError::InvalidArgument(format!(
+                    "case '{}' matrix parameter '{name}' has non-finite value {value}",
+                    case.id
+                )));
+            }
+            if values[..idx].contains(value) {
+                return Err(BenchError::InvalidArgument(format!(
+                    "case '{}' matrix parameter '{name}' lists {value} more than once",
+                    case.id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Enforces the case id convention: lowercase `snake_case` segments, where parameter
+/// suffixes such as `_5pct`, `_v0`, or `_sf1` are ordinary segments that may start with a
+/// digit, capped at [`MAX_CASE_ID_LEN`] characters.
+pub fn validate_case_id(id: &str) -> BenchResult<()> {
+    if id.len() > MAX_CASE_ID_LEN {
+        return Err(BenchError::InvalidArgument(format!(
+            "case id '{id}' is {} characters long; the maximum is {MAX_CASE_ID_LEN}",
+            id.len()
+        )));
+    }
+    let starts_with_letter = id.chars().next().is_some_and(|c| c.is_ascii_lowercase());
+    let well_formed_segments = id.split('_').all(|segment| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    });
+    if !starts_with_letter || !well_formed_segments {
+        return Err(BenchError::InvalidArgument(format!(
+            "case id '{id}' must be lowercase snake_case (letters, digits, and single underscores, starting with a letter)"
+        )));
+    }
+    Ok(())
+}
+
+/// Tags are lowercase words that may be joined by `-` or `_`, such as `smoke` or `remote-safe`.
+pub fn validate_case_tag(tag: &str) -> BenchResult<()> {
+    let starts_with_letter = tag.chars().next().is_some_and(|c| c.is_ascii_lowercase());
+    let well_formed = tag
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if !starts_with_letter || !well_formed {
+        return Err(BenchError::InvalidArgument(format!(
+            "case tag '{tag}' must be lowercase letters, digits, '-', or '_', starting with a letter"
+        )));
+    }
+    Ok(())
+}
+
 pub(crate) fn benchmark_repo_root() -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
 }
@@ -0,0 +1,125 @@
+//! OTLP export of benchmark runs, behind the `otel` feature.
+//!
+//! When `OTEL_EXPORTER_OTLP_ENDPOINT` is set, the `run`, `target`, `case`, and `iteration`
+//! spans are exported whatever `RUST_LOG` says, and every finished case records its samples'
+//! `elapsed_ms` and `files_scanned` as histograms. The exporter reads the standard
+//! `OTEL_EXPORTER_OTLP_*` and `OTEL_RESOURCE_ATTRIBUTES` variables, so a hardened host points
+//! it at the collector the same way as its infrastructure agents.
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::{MetricExporter, SpanExporter};
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use tracing::{Level, Subscriber};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::error::{BenchError, BenchResult};
+use crate::results::{BenchContext, CaseResult};
+
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+const SERVICE_NAME: &str = "delta-bench";
+
+/// The providers behind an active export; [`OtelExport::shutdown`] flushes what is buffered.
+pub(crate) struct OtelExport {
+    tracer_provider: SdkTracerProvider,
+    meter_provider: SdkMeterProvider,
+}
+
+impl OtelExport {
+    /// A lost export must not fail the run it describes, so errors are reported and
+    /// otherwise ignored.
+    pub(crate) fn shutdown(&self) {
+        if let Err(error) = self.tracer_provider.shutdown() {
+            eprintln!("warning: failed to flush OTLP spans: {error}");
+        }
+        if let Err(error) = self.meter_provider.shutdown() {
+            eprintln!("warning: failed to flush OTLP metrics: {error}");
+        }
+    }
+}
+
+/// The layer exporting the harness spans, or `None` when no OTLP endpoint is configured.
+pub(crate) fn otlp_layer<S>() -> BenchResult<Option<(impl Layer<S>, OtelExport)>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    if std::env::var_os(OTLP_ENDPOINT_ENV).is_none() {
+        return Ok(None);
+    }
+    let resource = Resource::builder().with_service_name(SERVICE_NAME).build();
+    let span_exporter = SpanExporter::builder()
+        .with_tonic()
+        .build()
+        .map_err(|error| otlp_error("span", error))?;
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_batch_exporter(span_exporter)
+        .with_resource(resource.clone())
+        .build();
+    let metric_exporter = MetricExporter::builder()
+        .with_tonic()
+        .build()
+        .map_err(|error| otlp_error("metric", error))?;
+    let meter_provider = SdkMeterProvider::builder()
+        .with_periodic_exporter(metric_exporter)
+        .with_resource(resource)
+        .build();
+    global::set_meter_provider(meter_provider.clone());
+
+    let layer = tracing_opentelemetry::layer()
+        .with_tracer(tracer_provider.tracer(SERVICE_NAME))
+        .with_filter(Targets::new().with_target("delta_bench", Level::INFO));
+    Ok(Some((
+        layer,
+        OtelExport {
+            tracer_provider,
+            meter_provider,
+        },
+    )))
+}
+
+/// Records `case`'s samples; a no-op until [`otlp_layer`] installed a meter provider.
+pub fn record_case_metrics(context: &BenchContext, case: &CaseResult) {
+    let meter = global::meter_provider().meter(SERVICE_NAME);
+    let elapsed_ms = meter
+        .f64_histogram("delta_bench.case.elapsed_ms")
+        .with_unit("ms")
+        .with_description("Elapsed time of each measured iteration")
+        .build();
+    let files_scanned = meter
+        .u64_histogram("delta_bench.case.files_scanned")
+        .with_description("Data files each measured iteration scanned")
+        .build();
+    let mut attributes = vec![
+        KeyValue::new("case", case.case.clone()),
+        KeyValue::new("suite", context.suite.clone()),
+        KeyValue::new("scale", context.scale.clone()),
+        KeyValue::new("label", context.label.clone()),
+        KeyValue::new("success", case.success),
+    ];
+    for (key, value) in [
+        ("lane", &context.lane),
+        ("storage_backend", &context.storage_backend),
+    ] {
+        if let Some(value) = value {
+            attributes.push(KeyValue::new(key, value.clone()));
+        }
+    }
+    for sample in &case.samples {
+        elapsed_ms.record(sample.elapsed_ms, &attributes);
+        if let Some(files) = sample
+            .metrics
+            .as_ref()
+            .and_then(|metrics| metrics.files_scanned)
+        {
+            files_scanned.record(files, &attributes);
+        }
+    }
+}
+
+fn otlp_error(kind: &str, error: impl std::fmt::Display) -> BenchError {
+    BenchError::InvalidArgument(format!("failed to build the OTLP {kind} exporter: {error}"))
+}
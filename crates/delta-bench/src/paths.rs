@@ -0,0 +1,61 @@
+//! The fixtures and results directories, resolved once at startup.
+//!
+//! A missing parent, a read-only mount, or a dangling symlink otherwise surfaces as a bare IO
+//! error from deep inside a suite, without the path it was about.
+
+use std::env;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::{BenchError, BenchResult};
+
+/// `path` with a leading `~` replaced by `$HOME`. Shells leave `~` unexpanded inside quotes and
+/// environment variables such as `DELTA_BENCH_FIXTURES`, so the harness expands it itself.
+pub fn expand_home(path: &Path) -> BenchResult<PathBuf> {
+    let mut components = path.components();
+    if components.next() != Some(Component::Normal("~".as_ref())) {
+        return Ok(path.to_path_buf());
+    }
+    let home = env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .ok_or_else(|| {
+            BenchError::InvalidArgument(format!(
+                "cannot expand '~' in '{}': HOME is not set",
+                path.display()
+            ))
+        })?;
+    Ok(PathBuf::from(home).join(components.as_path()))
+}
+
+/// Expands, creates, and canonicalizes the `role` directory at `path`, then checks that files
+/// can be created in it. The returned path has every symlink resolved.
+pub fn prepare_writable_dir(role: &str, path: &Path) -> BenchResult<PathBuf> {
+    let expanded = expand_home(path)?;
+    let invalid = |problem: String| {
+        BenchError::InvalidArgument(format!(
+            "{role} directory '{}' {problem}",
+            expanded.display()
+        ))
+    };
+    fs::create_dir_all(&expanded)
+        .map_err(|error| invalid(format!("cannot be created: {error}")))?;
+    let canonical = expanded
+        .canonicalize()
+        .map_err(|error| invalid(format!("cannot be resolved: {error}")))?;
+    if !canonical.is_dir() {
+        return Err(invalid(format!(
+            "resolves to '{}', which is not a directory",
+            canonical.display()
+        )));
+    }
+    tempfile::Builder::new()
+        .prefix(".delta-bench-write-check-")
+        .tempfile_in(&canonical)
+        .map_err(|error| {
+            invalid(format!(
+                "is not writable (resolved to '{}'): {error}",
+                canonical.display()
+            ))
+        })?;
+    Ok(canonical)
+}
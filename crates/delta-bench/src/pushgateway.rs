@@ -0,0 +1,65 @@
+//! Pushes a run's case metrics to a Prometheus Pushgateway.
+//!
+//! The metrics are the [`PrometheusReporter`] rendering of the result. They are pushed under
+//! the grouping key `job="delta_bench"`, `suite`, and `label`, so rerunning a label replaces
+//! its earlier metrics while other labels and suites stay in the gateway for dashboards to
+//! compare.
+
+use url::Url;
+
+use crate::error::{BenchError, BenchResult};
+use crate::report::{PrometheusReporter, Reporter};
+use crate::results::BenchRunResult;
+
+pub const PUSH_GATEWAY_JOB: &str = "delta_bench";
+
+/// Checks `gateway` up front, so a typo fails before the run rather than after it.
+pub fn parse_push_gateway(gateway: &str) -> BenchResult<Url> {
+    let url = Url::parse(gateway).map_err(|error| {
+        BenchError::InvalidArgument(format!("invalid --push-gateway URL '{gateway}': {error}"))
+    })?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(BenchError::InvalidArgument(format!(
+            "--push-gateway URL '{gateway}' must use http or https"
+        )));
+    }
+    Ok(url)
+}
+
+/// The URL `result` is pushed to under `gateway`.
+pub fn push_url(gateway: &Url, result: &BenchRunResult) -> Url {
+    let mut url = gateway.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty().extend([
+            "metrics",
+            "job",
+            PUSH_GATEWAY_JOB,
+            "suite",
+            result.context.suite.as_str(),
+            "label",
+            result.context.label.as_str(),
+        ]);
+    }
+    url
+}
+
+/// Replaces the metrics of `result`'s suite and label in the gateway, returning the URL pushed
+/// to.
+pub async fn push_run_metrics(gateway: &Url, result: &BenchRunResult) -> BenchResult<Url> {
+    let url = push_url(gateway, result);
+    let body = PrometheusReporter.render(result)?;
+    reqwest::Client::new()
+        .put(url.clone())
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )
+        .body(body)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|error| {
+            BenchError::InvalidArgument(format!("failed to push metrics to {url}: {error}"))
+        })?;
+    Ok(url)
+}
@@ -0,0 +1,511 @@
+//! Rendered reports for a single run result.
+//!
+//! Each output format is a [`Reporter`] looked up by name in a [`ReporterRegistry`], so
+//! `report --format` dispatches without knowing the formats ahead of time. With the
+//! `custom-reporters` feature, crates embedding the harness can register their own renderers
+//! next to the built-in ones.
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::{BenchError, BenchResult};
+use crate::results::{
+    case_status, format_stat, BenchContext, BenchRunResult, CaseResult, RunWarning,
+};
+use crate::stats::compute_stats;
+
+/// Format used when `report --format` is not given.
+pub const DEFAULT_REPORT_FORMAT: &str = "markdown";
+
+const CASE_COLUMNS: [&str; 7] = [
+    "case",
+    "status",
+    "mean_ms",
+    "min_ms",
+    "max_ms",
+    "stddev_ms",
+    "cv_pct",
+];
+
+pub trait Reporter: Send + Sync {
+    /// Name accepted by `report --format`.
+    fn name(&self) -> &'static str;
+
+    fn render(&self, result: &BenchRunResult) -> BenchResult<String>;
+}
+
+pub struct ReporterRegistry {
+    reporters: Vec<Box<dyn Reporter>>,
+}
+
+impl Default for ReporterRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl ReporterRegistry {
+    /// The markdown, html, csv, gh-comment, and prometheus reporters.
+    pub fn builtin() -> Self {
+        Self {
+            reporters: vec![
+                Box::new(MarkdownReporter),
+                Box::new(HtmlReporter),
+                Box::new(CsvReporter),
+                Box::new(GhCommentReporter),
+                Box::new(PrometheusReporter),
+            ],
+        }
+    }
+
+    /// Adds a reporter; names must be unique, so a built-in format cannot be replaced.
+    #[cfg(feature = "custom-reporters")]
+    pub fn register(&mut self, reporter: Box<dyn Reporter>) -> BenchResult<()> {
+        if self.names().contains(&reporter.name()) {
+            return Err(BenchError::InvalidArgument(format!(
+                "report format '{}' is already registered",
+                reporter.name()
+            )));
+        }
+        self.reporters.push(reporter);
+        Ok(())
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.reporters
+            .iter()
+            .map(|reporter| reporter.name())
+            .collect()
+    }
+
+    pub fn get(&self, name: &str) -> BenchResult<&dyn Reporter> {
+        self.reporters
+            .iter()
+            .find(|reporter| reporter.name() == name)
+            .map(|reporter| reporter.as_ref())
+            .ok_or_else(|| {
+                BenchError::InvalidArgument(format!(
+                    "unknown report format '{name}'; expected one of: {}",
+                    self.names().join(", ")
+                ))
+            })
+    }
+
+    pub fn render(&self, name: &str, result: &BenchRunResult) -> BenchResult<String> {
+        self.get(name)?.render(result)
+    }
+}
+
+/// Renders the result file at `input`; the format is checked before the file is read.
+pub fn render_run_report(
+    registry: &ReporterRegistry,
+    input: &Path,
+    format: &str,
+) -> BenchResult<String> {
+    let reporter = registry.get(format)?;
+    let result = serde_json::from_slice::<BenchRunResult>(&fs::read(input)?)?;
+    reporter.render(&result)
+}
+
+pub struct MarkdownReporter;
+
+impl Reporter for MarkdownReporter {
+    fn name(&self) -> &'static str {
+        "markdown"
+    }
+
+    fn render(&self, result: &BenchRunResult) -> BenchResult<String> {
+        let mut output = format!("## {}\n\n", report_title(result));
+        for (key, value) in context_lines(result) {
+            output.push_str(&format!("- {key}: `{value}`\n"));
+        }
+        output.push('\n');
+        output.push_str(&markdown_case_table(&result.cases));
+        let failures = failure_lines(&result.cases);
+        if !failures.is_empty() {
+            output.push_str("\n### Failures\n\n");
+            for (case, message) in failures {
+                output.push_str(&format!("- `{case}`: {}\n", markdown_escape(&message)));
+            }
+        }
+        if !result.warnings.is_empty() {
+            output.push_str("\n### Warnings\n\n");
+            for line in warning_lines(&result.warnings) {
+                output.push_str(&format!("- {}\n", markdown_escape(&line)));
+            }
+        }
+        Ok(output)
+    }
+}
+
+pub struct HtmlReporter;
+
+impl Reporter for HtmlReporter {
+    fn name(&self) -> &'static str {
+        "html"
+    }
+
+    fn render(&self, result: &BenchRunResult) -> BenchResult<String> {
+        let title = html_escape(&report_title(result));
+        let mut output = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n</head>\n<body>\n<h1>{title}</h1>\n<dl>\n"
+        );
+        for (key, value) in context_lines(result) {
+            output.push_str(&format!(
+                "<dt>{key}</dt><dd><code>{}</code></dd>\n",
+                html_escape(&value)
+            ));
+        }
+        output.push_str("</dl>\n<table>\n<thead>\n<tr>");
+        for column in CASE_COLUMNS {
+            output.push_str(&format!("<th>{column}</th>"));
+        }
+        output.push_str("</tr>\n</thead>\n<tbody>\n");
+        for row in case_rows(&result.cases) {
+            output.push_str(&format!("<tr class=\"{}\">", row[1]));
+            for value in &row {
+                output.push_str(&format!("<td>{}</td>", html_escape(value)));
+            }
+            output.push_str("</tr>\n");
+        }
+        output.push_str("</tbody>\n</table>\n");
+        let failures = failure_lines(&result.cases);
+        if !failures.is_empty() {
+            output.push_str("<h2>Failures</h2>\n<ul>\n");
+            for (case, message) in failures {
+                output.push_str(&format!(
+                    "<li><code>{}</code>: {}</li>\n",
+                    html_escape(&case),
+                    html_escape(&message)
+                ));
+            }
+            output.push_str("</ul>\n");
+        }
+        if !result.warnings.is_empty() {
+            output.push_str("<h2>Warnings</h2>\n<ul>\n");
+            for line in warning_lines(&result.warnings) {
+                output.push_str(&format!("<li>{}</li>\n", html_escape(&line)));
+            }
+            output.push_str("</ul>\n");
+        }
+        output.push_str("</body>\n</html>\n");
+        Ok(output)
+    }
+}
+
+/// One row per case, with the run's suite, scale, and label repeated so files from several
+/// runs can be concatenated without losing where each row came from.
+pub struct CsvReporter;
+
+impl Reporter for CsvReporter {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn render(&self, result: &BenchRunResult) -> BenchResult<String> {
+        let mut header = vec!["suite", "scale", "label"];
+        header.extend(CASE_COLUMNS);
+        header.push("failure");
+        let mut output = header.join(",");
+        output.push('\n');
+        for (case, row) in result.cases.iter().zip(case_rows(&result.cases)) {
+            let mut fields = vec![
+                result.context.suite.clone(),
+                result.context.scale.clone(),
+                result.context.label.clone(),
+            ];
+            fields.extend(row.into_iter().map(
+                |value| {
+                    if value == "-" {
+                        String::new()
+                    } else {
+                        value
+                    }
+                },
+            ));
+            fields.push(
+                case.failure
+                    .as_ref()
+                    .map(|failure| failure.message.clone())
+                    .unwrap_or_default(),
+            );
+            let fields = fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>();
+            output.push_str(&fields.join(","));
+            output.push('\n');
+        }
+        Ok(output)
+    }
+}
+
+/// Markdown sized for a pull request comment: a one-line status tally up front and the case
+/// table folded into `<details>`. The leading HTML comment names the suite so a bot can find
+/// and update its earlier comment instead of posting a new one.
+pub struct GhCommentReporter;
+
+impl Reporter for GhCommentReporter {
+    fn name(&self) -> &'static str {
+        "gh-comment"
+    }
+
+    fn render(&self, result: &BenchRunResult) -> BenchResult<String> {
+        let context = &result.context;
+        let mut output = format!(
+            "<!-- delta-bench:{}:{} -->\n### {}\n\n",
+            context.suite,
+            context.scale,
+            report_title(result)
+        );
+        output.push_str(&status_tally(&result.cases));
+        output.push('\n');
+        if let Some(git_sha) = context.git_sha.as_deref() {
+            output.push_str(&format!("\nRevision: `{git_sha}`{}", dirty_suffix(context)));
+        }
+        if !context.ref_issues.is_empty() {
+            output.push_str(&format!("\nRefs: {}", context.ref_issues.join(", ")));
+        }
+        output.push_str("\n\n<details>\n<summary>Cases</summary>\n\n");
+        output.push_str(&markdown_case_table(&result.cases));
+        output.push_str("\n</details>\n");
+        let failures = failure_lines(&result.cases);
+        if !failures.is_empty() {
+            output.push_str("\n<details>\n<summary>Failures</summary>\n\n");
+            for (case, message) in failures {
+                output.push_str(&format!("- `{case}`: {}\n", markdown_escape(&message)));
+            }
+            output.push_str("\n</details>\n");
+        }
+        if !result.warnings.is_empty() {
+            output.push_str(&format!(
+                "\n<details>\n<summary>Warnings ({})</summary>\n\n",
+                result.warnings.len()
+            ));
+            for line in warning_lines(&result.warnings) {
+                output.push_str(&format!("- {}\n", markdown_escape(&line)));
+            }
+            output.push_str("\n</details>\n");
+        }
+        Ok(output)
+    }
+}
+
+/// Prometheus text exposition: each trusted case's median and median throughput as gauges
+/// labeled with `suite`, `case`, `scale`, `backend`, and `label`, the form `run --push-gateway`
+/// pushes. Untrusted cases are left out, as in the case table.
+pub struct PrometheusReporter;
+
+impl Reporter for PrometheusReporter {
+    fn name(&self) -> &'static str {
+        "prometheus"
+    }
+
+    fn render(&self, result: &BenchRunResult) -> BenchResult<String> {
+        let context = &result.context;
+        let mut medians = Vec::new();
+        let mut throughputs = Vec::new();
+        for case in &result.cases {
+            if !case.perf_status.is_trusted() {
+                continue;
+            }
+            let Some(stats) = case.elapsed_stats.as_ref() else {
+                continue;
+            };
+            let labels = [
+                ("suite", context.suite.as_str()),
+                ("case", case.case.as_str()),
+                ("scale", context.scale.as_str()),
+                ("backend", context.storage_backend.as_deref().unwrap_or("")),
+                ("label", context.label.as_str()),
+            ]
+            .iter()
+            .map(|(key, value)| format!("{key}=\"{}\"", prometheus_escape(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+            medians.push(format!("{{{labels}}} {}", stats.median_ms));
+            if let Some(rows_per_sec) = median_rows_per_sec(case) {
+                throughputs.push(format!("{{{labels}}} {rows_per_sec}"));
+            }
+        }
+        let mut output = String::new();
+        for (name, help, samples) in [
+            (
+                "delta_bench_case_median_ms",
+                "Median elapsed time of the measured iterations, in milliseconds.",
+                medians,
+            ),
+            (
+                "delta_bench_case_throughput_rows_per_second",
+                "Median rows processed per second across the measured iterations.",
+                throughputs,
+            ),
+        ] {
+            if samples.is_empty() {
+                continue;
+            }
+            output.push_str(&format!("# HELP {name} {help}\n# TYPE {name} gauge\n"));
+            for sample in samples {
+                output.push_str(&format!("{name}{sample}\n"));
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// The median of the per-sample throughputs, or `None` when no sample counted rows.
+fn median_rows_per_sec(case: &CaseResult) -> Option<f64> {
+    let rates = case
+        .samples
+        .iter()
+        .filter(|sample| sample.elapsed_ms > 0.0)
+        .filter_map(|sample| Some(sample.rows? as f64 / (sample.elapsed_ms / 1000.0)))
+        .collect::<Vec<_>>();
+    compute_stats(&rates).map(|stats| stats.median_ms)
+}
+
+fn report_title(result: &BenchRunResult) -> String {
+    let context = &result.context;
+    format!(
+        "delta-bench {} ({}, {})",
+        context.suite, context.scale, context.label
+    )
+}
+
+fn dirty_suffix(context: &BenchContext) -> &'static str {
+    if context.git_dirty == Some(true) {
+        " (dirty)"
+    } else {
+        ""
+    }
+}
+
+fn context_lines(result: &BenchRunResult) -> Vec<(&'static str, String)> {
+    let context = &result.context;
+    let mut lines = vec![
+        ("created_at", context.created_at.to_rfc3339()),
+        ("host", context.host.clone()),
+        (
+            "iterations",
+            format!("{} (warmup {})", context.iterations, context.warmup),
+        ),
+    ];
+    if let Some(git_sha) = context.git_sha.as_deref() {
+        lines.push(("git_sha", format!("{git_sha}{}", dirty_suffix(context))));
+    }
+    for (key, value) in [
+        ("dataset_fingerprint", context.dataset_fingerprint.as_ref()),
+        ("storage_backend", context.storage_backend.as_ref()),
+        ("lane", context.lane.as_ref()),
+    ] {
+        if let Some(value) = value {
+            lines.push((key, value.clone()));
+        }
+    }
+    if !context.ref_issues.is_empty() {
+        lines.push(("ref_issues", context.ref_issues.join(", ")));
+    }
+    lines
+}
+
+/// Stats only for trusted timings, as in the run summary table.
+fn case_rows(cases: &[CaseResult]) -> Vec<Vec<String>> {
+    cases
+        .iter()
+        .map(|case| {
+            let stats = if case.perf_status.is_trusted() {
+                case.elapsed_stats.as_ref()
+            } else {
+                None
+            };
+            vec![
+                case.case.clone(),
+                case_status(case).to_string(),
+                format_stat(stats.map(|s| s.mean_ms)),
+                format_stat(stats.map(|s| s.min_ms)),
+                format_stat(stats.map(|s| s.max_ms)),
+                format_stat(stats.map(|s| s.stddev_ms)),
+                format_stat(stats.and_then(|s| s.cv_pct)),
+            ]
+        })
+        .collect()
+}
+
+fn markdown_case_table(cases: &[CaseResult]) -> String {
+    let mut output = format!("| {} |\n", CASE_COLUMNS.join(" | "));
+    output.push_str("| --- | --- |");
+    output.push_str(&" ---: |".repeat(CASE_COLUMNS.len() - 2));
+    output.push('\n');
+    for row in case_rows(cases) {
+        let cells = row
+            .iter()
+            .map(|value| markdown_escape(value))
+            .collect::<Vec<_>>();
+        output.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    output
+}
+
+fn status_tally(cases: &[CaseResult]) -> String {
+    let mut counts: Vec<(&'static str, usize)> = Vec::new();
+    for case in cases {
+        let status = case_status(case);
+        match counts.iter_mut().find(|(name, _)| *name == status) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((status, 1)),
+        }
+    }
+    let tally = counts
+        .iter()
+        .map(|(status, count)| format!("{count} {status}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("**{} case(s):** {tally}", cases.len())
+}
+
+fn failure_lines(cases: &[CaseResult]) -> Vec<(String, String)> {
+    cases
+        .iter()
+        .filter_map(|case| {
+            let failure = case.failure.as_ref()?;
+            Some((case.case.clone(), failure.message.replace('\n', " ")))
+        })
+        .collect()
+}
+
+fn warning_lines(warnings: &[RunWarning]) -> Vec<String> {
+    warnings
+        .iter()
+        .map(|warning| match warning.case.as_deref() {
+            Some(case) => format!("{} ({case}): {}", warning.kind, warning.message),
+            None => format!("{}: {}", warning.kind, warning.message),
+        })
+        .collect()
+}
+
+fn markdown_escape(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn prometheus_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
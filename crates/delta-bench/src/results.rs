@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{de, Deserialize, Deserializer, Serialize};
 
@@ -6,6 +8,12 @@ pub const FAILURE_KIND_EXECUTION_ERROR: &str = "execution_error";
 pub const FAILURE_KIND_ASSERTION_MISMATCH: &str = "assertion_mismatch";
 pub const FAILURE_KIND_CONTEXT_MISMATCH: &str = "context_mismatch";
 pub const FAILURE_KIND_UNSUPPORTED: &str = "unsupported";
+pub const WARNING_KIND_DEGRADED_FIDELITY: &str = "degraded_fidelity";
+pub const WARNING_KIND_RETRIES_OCCURRED: &str = "retries_occurred";
+pub const WARNING_KIND_OUTLIERS_DETECTED: &str = "outliers_detected";
+pub const WARNING_KIND_FIXTURE_REGENERATED: &str = "fixture_regenerated";
+pub const WARNING_KIND_PARTIAL_METRICS: &str = "partial_metrics";
+pub const WARNING_KIND_ASSERTION_WARNED: &str = "assertion_warned";
 
 fn deserialize_supported_schema_version<'de, D>(deserializer: D) -> Result<u32, D::Error>
 where
@@ -79,16 +87,7 @@ pub fn render_run_summary_table(cases: &[CaseResult]) -> String {
 
     let mut rows = Vec::with_capacity(cases.len());
     for case in cases {
-        let status = match (
-            case.classification.as_str(),
-            case.perf_status.is_trusted(),
-            case.validation_passed,
-        ) {
-            ("expected_failure", _, _) => "expected_failure",
-            (_, true, _) => "ok",
-            (_, false, true) => "validated",
-            _ => "invalid",
-        };
+        let status = case_status(case);
         let stats = if case.perf_status.is_trusted() {
             case.elapsed_stats.as_ref()
         } else {
@@ -144,7 +143,104 @@ pub fn render_run_summary_table(cases: &[CaseResult]) -> String {
     output
 }
 
-fn format_stat(value: Option<f64>) -> String {
+/// Status shown for a case in the summary table and rendered reports: `ok` for trusted
+/// timings, `validated` when only the assertions passed, `invalid`, or `expected_failure`.
+pub fn case_status(case: &CaseResult) -> &'static str {
+    match (
+        case.classification.as_str(),
+        case.perf_status.is_trusted(),
+        case.validation_passed,
+    ) {
+        ("expected_failure", _, _) => "expected_failure",
+        (_, true, _) => "ok",
+        (_, false, true) => "validated",
+        _ => "invalid",
+    }
+}
+
+/// Cases kept in each post-run triage list.
+pub const RUN_TRIAGE_TOP_N: usize = 10;
+
+/// One row of a post-run triage list.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TriageEntry {
+    pub case: String,
+    pub mean_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cv_pct: Option<f64>,
+}
+
+/// Where time and noise went in one run: the slowest cases by mean and the noisiest by CV.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct RunTriage {
+    pub slowest: Vec<TriageEntry>,
+    pub noisiest: Vec<TriageEntry>,
+}
+
+impl RunTriage {
+    pub fn is_empty(&self) -> bool {
+        self.slowest.is_empty() && self.noisiest.is_empty()
+    }
+}
+
+/// Ranks trusted cases only, matching the stats the summary table shows.
+pub fn build_run_triage(cases: &[CaseResult], limit: usize) -> RunTriage {
+    let entries = cases
+        .iter()
+        .filter(|case| case.perf_status.is_trusted())
+        .filter_map(|case| {
+            let stats = case.elapsed_stats.as_ref()?;
+            Some(TriageEntry {
+                case: case.case.clone(),
+                mean_ms: stats.mean_ms,
+                cv_pct: stats.cv_pct,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut slowest = entries.clone();
+    slowest.sort_by(|left, right| right.mean_ms.total_cmp(&left.mean_ms));
+    slowest.truncate(limit);
+
+    let mut noisiest = entries
+        .into_iter()
+        .filter(|entry| entry.cv_pct.is_some())
+        .collect::<Vec<_>>();
+    noisiest.sort_by(|left, right| {
+        right
+            .cv_pct
+            .unwrap_or_default()
+            .total_cmp(&left.cv_pct.unwrap_or_default())
+    });
+    noisiest.truncate(limit);
+
+    RunTriage { slowest, noisiest }
+}
+
+pub fn render_run_triage(triage: &RunTriage) -> String {
+    let mut output = String::new();
+    output.push_str("slowest cases (mean_ms):\n");
+    for (rank, entry) in triage.slowest.iter().enumerate() {
+        output.push_str(&format!(
+            "  {:>2}. {} {:.3}\n",
+            rank + 1,
+            entry.case,
+            entry.mean_ms
+        ));
+    }
+    output.push_str("noisiest cases (cv_pct):");
+    for (rank, entry) in triage.noisiest.iter().enumerate() {
+        output.push_str(&format!(
+            "\n  {:>2}. {} {}",
+            rank + 1,
+            entry.case,
+            format_stat(entry.cv_pct)
+        ));
+    }
+    output
+}
+
+pub(crate) fn format_stat(value: Option<f64>) -> String {
     value
         .map(|v| format!("{v:.3}"))
         .unwrap_or_else(|| "-".to_string())
@@ -204,12 +300,27 @@ fn render_table_row_colored(
     row
 }
 
+/// What one fixture table held when it was generated, copied from the fixture manifest so
+/// throughput can be normalized by actual rows and bytes rather than by the scale name.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureTableStats {
+    pub table: String,
+    pub rows: u64,
+    pub files: u64,
+    /// Total data file size; unset for fixtures generated before sizes were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BenchContext {
     #[serde(deserialize_with = "deserialize_supported_schema_version")]
     pub schema_version: u32,
     pub label: String,
     pub git_sha: Option<String>,
+    /// Whether the `git_sha` checkout had uncommitted changes; unset when the sha was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_dirty: Option<bool>,
     pub created_at: DateTime<Utc>,
     pub host: String,
     pub suite: String,
@@ -239,7 +350,24 @@ pub struct BenchContext {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub harness_revision: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub harness_dirty: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixture_recipe_hash: Option<String>,
+    /// Rows and data size of each table in the fixture set the run read, as generated.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixture_tables: Vec<FixtureTableStats>,
+    /// Canonical path of the fixtures directory the run read, with symlinks resolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixtures_dir: Option<String>,
+    /// Canonical path of the results directory the run wrote to, with symlinks resolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results_dir: Option<String>,
+    /// DataFusion release the suites planned and executed their queries with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datafusion_version: Option<String>,
+    /// Planning and execution options of the session the SQL-executing suites query with.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub datafusion_config: BTreeMap<String, String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fidelity_fingerprint: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -268,6 +396,9 @@ pub struct BenchContext {
     pub run_mode: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maintenance_window_id: Option<String>,
+    /// Upstream issues or PRs the run was produced to validate, as `OWNER/REPO#NUMBER`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ref_issues: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -276,6 +407,9 @@ pub struct SampleMetrics {
     pub bytes_processed: Option<u64>,
     pub operations: Option<u64>,
     pub table_version: Option<u64>,
+    /// Untimed setup before the measured operation, such as copying the fixture table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_ms: Option<f64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub files_scanned: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -287,6 +421,13 @@ pub struct SampleMetrics {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rewrite_time_ms: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_groups_pruned: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_groups_matched: Option<u64>,
+    /// Per scan operator breakdown of the scan and row group pruning totals.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scan_operators: Option<Vec<ScanOperatorMetrics>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub peak_rss_mb: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cpu_time_ms: Option<u64>,
@@ -300,6 +441,9 @@ pub struct SampleMetrics {
     pub files_skipped: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spill_bytes: Option<u64>,
+    /// Active files in the table after the measured operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_file_count: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub result_hash: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -307,6 +451,24 @@ pub struct SampleMetrics {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contention: Option<ContentionMetrics>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintenance_cycle: Option<MaintenanceCycleMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workers: Option<Vec<WorkerMetrics>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_concurrency: Option<ReadConcurrencyMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restore_vacuum: Option<RestoreVacuumMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vacuum_reads: Option<VacuumReadMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_loop: Option<OpenLoopMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mixed_workload: Option<MixedWorkloadMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_reads: Option<LogReadMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interop_attempts: Option<InteropAttemptMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub semantic_state_digest: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub validation_summary: Option<String>,
@@ -321,6 +483,32 @@ pub struct ScanRewriteMetrics {
     pub rewrite_time_ms: Option<u64>,
 }
 
+/// What one scan operator of a query plan read and pruned, by pruning stage.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanOperatorMetrics {
+    pub operator: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub files_scanned: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub files_pruned: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes_scanned: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_groups_pruned_statistics: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_groups_pruned_bloom_filter: Option<u64>,
+    /// Row groups that survived both statistics and bloom filter pruning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_groups_matched: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_index_rows_pruned: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_index_rows_matched: Option<u64>,
+    /// Rows the pushed-down filter dropped while decoding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pushdown_rows_pruned: Option<u64>,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RuntimeIOMetrics {
     pub peak_rss_mb: Option<u64>,
@@ -354,6 +542,104 @@ pub struct ContentionMetrics {
     pub other_errors: u64,
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaintenanceCycleMetrics {
+    pub dml_ms: u64,
+    pub optimize_ms: u64,
+    pub scan_ms: u64,
+    pub files_before_optimize: u64,
+    pub files_after_optimize: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestoreVacuumMetrics {
+    pub restore_ms: u64,
+    pub vacuum_ms: u64,
+    pub files_vacuumed: u64,
+    pub files_referenced: u64,
+}
+
+/// Per-worker outcome inside one multi-worker sample.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkerMetrics {
+    pub worker: u64,
+    pub elapsed_ms: f64,
+    pub succeeded: bool,
+    pub table_version: Option<u64>,
+    pub commit_retries: Option<u64>,
+}
+
+/// Throughput and per-session latency tail for one concurrent read sample.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReadConcurrencyMetrics {
+    pub sessions: u64,
+    pub rows_per_sec: f64,
+    pub session_p50_ms: f64,
+    pub session_max_ms: f64,
+}
+
+/// Read latency before and during one vacuum running under continuous reads.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VacuumReadMetrics {
+    pub readers: u64,
+    pub baseline_read_p50_ms: f64,
+    pub reads_during_vacuum: u64,
+    pub read_p50_ms_during_vacuum: f64,
+    pub read_max_ms_during_vacuum: f64,
+    pub read_latency_inflation: f64,
+}
+
+/// Achieved throughput and latency tail for one open-loop load window. Latencies run from
+/// each operation's scheduled start, so they include time spent queued.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpenLoopMetrics {
+    pub target_ops_per_sec: f64,
+    pub achieved_ops_per_sec: f64,
+    pub duration_ms: u64,
+    pub ops_completed: u64,
+    pub max_in_flight: u64,
+    pub latency_p50_ms: f64,
+    pub latency_p95_ms: f64,
+    pub latency_p99_ms: f64,
+    pub latency_max_ms: f64,
+}
+
+/// Per-operation-type throughput and latency for one steady-state mixed read/append window.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MixedWorkloadMetrics {
+    pub workers: u64,
+    pub duration_ms: u64,
+    pub reads: OperationLatencyMetrics,
+    pub appends: OperationLatencyMetrics,
+}
+
+/// Distinct `_delta_log` files one table open or log replay read.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogReadMetrics {
+    pub commit_files_read: u64,
+    pub checkpoint_parts_read: u64,
+}
+
+/// Attempts one `interop_py` iteration took, counting retries of a failed Python process.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InteropAttemptMetrics {
+    pub attempts: u64,
+    /// Wall time spent in the attempts that failed before the one that succeeded.
+    pub retry_ms: f64,
+    /// Why each retried attempt failed, in attempt order.
+    pub failures: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperationLatencyMetrics {
+    pub ops: u64,
+    pub ops_per_sec: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
 impl SampleMetrics {
     pub fn base(
         rows_processed: Option<u64>,
@@ -366,11 +652,15 @@ impl SampleMetrics {
             bytes_processed,
             operations,
             table_version,
+            setup_ms: None,
             files_scanned: None,
             files_pruned: None,
             bytes_scanned: None,
             scan_time_ms: None,
             rewrite_time_ms: None,
+            row_groups_pruned: None,
+            row_groups_matched: None,
+            scan_operators: None,
             peak_rss_mb: None,
             cpu_time_ms: None,
             bytes_read: None,
@@ -378,9 +668,19 @@ impl SampleMetrics {
             files_touched: None,
             files_skipped: None,
             spill_bytes: None,
+            table_file_count: None,
             result_hash: None,
             schema_hash: None,
             contention: None,
+            maintenance_cycle: None,
+            workers: None,
+            read_concurrency: None,
+            restore_vacuum: None,
+            vacuum_reads: None,
+            open_loop: None,
+            mixed_workload: None,
+            log_reads: None,
+            interop_attempts: None,
             semantic_state_digest: None,
             validation_summary: None,
         }
@@ -427,11 +727,77 @@ impl SampleMetrics {
         self
     }
 
+    pub fn with_row_group_pruning(
+        mut self,
+        row_groups_pruned: Option<u64>,
+        row_groups_matched: Option<u64>,
+    ) -> Self {
+        self.row_groups_pruned = row_groups_pruned;
+        self.row_groups_matched = row_groups_matched;
+        self
+    }
+
+    /// Keeps the per-operator breakdown; a plan without scan operators leaves it unset.
+    pub fn with_scan_operators(mut self, operators: Vec<ScanOperatorMetrics>) -> Self {
+        self.scan_operators = (!operators.is_empty()).then_some(operators);
+        self
+    }
+
+    pub fn with_table_file_count(mut self, table_file_count: u64) -> Self {
+        self.table_file_count = Some(table_file_count);
+        self
+    }
+
     pub fn with_contention(mut self, metrics: ContentionMetrics) -> Self {
         self.contention = Some(metrics);
         self
     }
 
+    pub fn with_maintenance_cycle(mut self, metrics: MaintenanceCycleMetrics) -> Self {
+        self.maintenance_cycle = Some(metrics);
+        self
+    }
+
+    pub fn with_workers(mut self, workers: Vec<WorkerMetrics>) -> Self {
+        self.workers = Some(workers);
+        self
+    }
+
+    pub fn with_read_concurrency(mut self, metrics: ReadConcurrencyMetrics) -> Self {
+        self.read_concurrency = Some(metrics);
+        self
+    }
+
+    pub fn with_restore_vacuum(mut self, metrics: RestoreVacuumMetrics) -> Self {
+        self.restore_vacuum = Some(metrics);
+        self
+    }
+
+    pub fn with_vacuum_reads(mut self, metrics: VacuumReadMetrics) -> Self {
+        self.vacuum_reads = Some(metrics);
+        self
+    }
+
+    pub fn with_open_loop(mut self, metrics: OpenLoopMetrics) -> Self {
+        self.open_loop = Some(metrics);
+        self
+    }
+
+    pub fn with_mixed_workload(mut self, metrics: MixedWorkloadMetrics) -> Self {
+        self.mixed_workload = Some(metrics);
+        self
+    }
+
+    pub fn with_log_reads(mut self, metrics: LogReadMetrics) -> Self {
+        self.log_reads = Some(metrics);
+        self
+    }
+
+    pub fn with_interop_attempts(mut self, metrics: InteropAttemptMetrics) -> Self {
+        self.interop_attempts = Some(metrics);
+        self
+    }
+
     // Builder ergonomics: this mirrors JSON schema fields to keep callsites explicit.
     #[allow(clippy::too_many_arguments)]
     pub fn with_runtime_io_metrics(
@@ -534,6 +900,45 @@ impl PerfStatus {
     }
 }
 
+/// A query case's DataFusion physical plan, kept in the result so a regression can be triaged
+/// by diffing plans between runs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "format", content = "plan", rename_all = "snake_case")]
+pub enum CapturedPlan {
+    /// The `EXPLAIN` rendering, one operator per indented line.
+    Text(String),
+    /// The operator tree as nested `operator`, `description`, and `children` objects.
+    Json(serde_json::Value),
+}
+
+/// Heap usage of a case run once under `run --heap-profile`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeapProfile {
+    /// Bytes live when the heap peaked.
+    pub peak_bytes: u64,
+    pub peak_blocks: u64,
+    /// Bytes allocated over the whole case, freed or not.
+    pub total_bytes: u64,
+    pub total_blocks: u64,
+    /// The sites holding the most bytes at the peak, largest first.
+    pub top_sites: Vec<AllocationSite>,
+    /// Where the full dhat profile was written, relative to the result file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// The dhat profile, held until it is written to `file`.
+    #[serde(skip)]
+    pub dhat_json: Vec<u8>,
+}
+
+/// One allocation site of a [`HeapProfile`], identified by its innermost frames.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AllocationSite {
+    pub peak_bytes: u64,
+    pub total_bytes: u64,
+    pub total_blocks: u64,
+    pub frames: Vec<String>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CaseResult {
     pub case: String,
@@ -565,15 +970,53 @@ pub struct CaseResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub decision_metric: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Physical plan of the last iteration; only recorded with `run --capture-plan`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physical_plan: Option<CapturedPlan>,
+    /// Where `run --capture-plan-metrics full` wrote the case's metrics, relative to the result
+    /// file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_metrics_file: Option<String>,
+    /// The full metrics tree of each measured iteration, held until it is written to
+    /// `plan_metrics_file`.
+    #[serde(skip)]
+    pub plan_metrics: Vec<serde_json::Value>,
+    /// Where `run --profile` wrote the case's flamegraph, relative to the result file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_file: Option<String>,
+    /// The case's flamegraph SVG, held until it is written to `profile_file`.
+    #[serde(skip)]
+    pub flamegraph: Option<Vec<u8>>,
+    /// Peak heap and top allocation sites; only recorded with `run --heap-profile`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heap_profile: Option<HeapProfile>,
+    /// Violations of `severity: warn` assertions, which leave the case's outcome unchanged.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub failure_kind: Option<String>,
     pub failure: Option<CaseFailure>,
 }
 
+/// A soft issue the harness noticed while producing a run: the run still counts, but its
+/// numbers deserve a second look. `kind` is one of the `WARNING_KIND_*` values.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunWarning {
+    pub kind: String,
+    /// The case the warning is about; unset for run-wide warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case: Option<String>,
+    pub message: String,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct BenchRunResult {
     pub schema_version: u32,
     pub context: BenchContext,
     pub cases: Vec<CaseResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<RunWarning>,
 }
 
 #[derive(Deserialize)]
@@ -582,6 +1025,8 @@ struct RawBenchRunResult {
     schema_version: u32,
     context: BenchContext,
     cases: Vec<CaseResult>,
+    #[serde(default)]
+    warnings: Vec<RunWarning>,
 }
 
 fn has_legacy_v2_contention_metrics(cases: &[CaseResult]) -> bool {
@@ -611,6 +1056,7 @@ impl<'de> Deserialize<'de> for BenchRunResult {
             schema_version: raw.schema_version,
             context: raw.context,
             cases: raw.cases,
+            warnings: raw.warnings,
         })
     }
 }
@@ -663,8 +1109,8 @@ pub fn build_run_summary(
 #[cfg(test)]
 mod tests {
     use super::{
-        render_run_summary_table, CaseFailure, CaseResult, ElapsedStats, PerfStatus,
-        FAILURE_KIND_EXECUTION_ERROR,
+        build_run_triage, render_run_summary_table, render_run_triage, CaseFailure, CaseResult,
+        ElapsedStats, PerfStatus, FAILURE_KIND_EXECUTION_ERROR,
     };
 
     fn success_case(name: &str, mean_ms: f64, cv_pct: Option<f64>) -> CaseResult {
@@ -692,6 +1138,14 @@ mod tests {
             required_runs: None,
             decision_threshold_pct: None,
             decision_metric: None,
+            seed: None,
+            physical_plan: None,
+            plan_metrics_file: None,
+            plan_metrics: Vec::new(),
+            profile_file: None,
+            flamegraph: None,
+            heap_profile: None,
+            warnings: Vec::new(),
             failure_kind: None,
             failure: None,
         }
@@ -729,6 +1183,14 @@ mod tests {
             required_runs: None,
             decision_threshold_pct: None,
             decision_metric: None,
+            seed: None,
+            physical_plan: None,
+            plan_metrics_file: None,
+            plan_metrics: Vec::new(),
+            profile_file: None,
+            flamegraph: None,
+            heap_profile: None,
+            warnings: Vec::new(),
             failure_kind: Some(FAILURE_KIND_EXECUTION_ERROR.to_string()),
             failure: Some(CaseFailure {
                 message: "boom".to_string(),
@@ -759,6 +1221,14 @@ mod tests {
             required_runs: None,
             decision_threshold_pct: None,
             decision_metric: None,
+            seed: None,
+            physical_plan: None,
+            plan_metrics_file: None,
+            plan_metrics: Vec::new(),
+            profile_file: None,
+            flamegraph: None,
+            heap_profile: None,
+            warnings: Vec::new(),
             failure_kind: None,
             failure: None,
         }]);
@@ -766,4 +1236,47 @@ mod tests {
         assert!(output.contains("validated"));
         assert!(output.contains("scan_filter_flag"));
     }
+
+    #[test]
+    fn run_triage_ranks_slowest_and_noisiest_trusted_cases() {
+        let mut untrusted = success_case("merge_upsert_10pct", 500.0, Some(90.0));
+        untrusted.perf_status = PerfStatus::ValidationOnly;
+        let cases = vec![
+            success_case("scan_full_narrow", 10.0, Some(2.0)),
+            success_case("write_append_small", 40.0, None),
+            success_case("scan_filter_flag", 25.0, Some(12.0)),
+            success_case("delete_1pct_localized", 30.0, Some(5.0)),
+            untrusted,
+        ];
+
+        let triage = build_run_triage(&cases, 2);
+
+        assert_eq!(
+            triage
+                .slowest
+                .iter()
+                .map(|entry| entry.case.as_str())
+                .collect::<Vec<_>>(),
+            vec!["write_append_small", "delete_1pct_localized"]
+        );
+        assert_eq!(
+            triage
+                .noisiest
+                .iter()
+                .map(|entry| entry.case.as_str())
+                .collect::<Vec<_>>(),
+            vec!["scan_filter_flag", "delete_1pct_localized"]
+        );
+    }
+
+    #[test]
+    fn run_triage_renders_ranked_lists() {
+        let triage = build_run_triage(&[success_case("scan_full_narrow", 10.5, Some(2.4))], 10);
+        let output = render_run_triage(&triage);
+
+        assert!(output.contains("slowest cases (mean_ms):"));
+        assert!(output.contains("   1. scan_full_narrow 10.500"));
+        assert!(output.contains("noisiest cases (cv_pct):"));
+        assert!(output.contains("   1. scan_full_narrow 2.400"));
+    }
 }
@@ -0,0 +1,266 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Instant;
+
+use chrono::Utc;
+use serde::Serialize;
+use tracing::field::Empty;
+use tracing::{debug, info_span, warn, Instrument, Span};
+use url::Url;
+
+use super::plan_capture::capturing_plan;
+use super::progress::{report_case_finished, report_case_started};
+use super::{CaseExecutionResult, CaseHooks};
+use crate::error::BenchResult;
+use crate::results::{CaseResult, FAILURE_KIND_UNSUPPORTED};
+
+pub const AUDIT_LOG_FILE_NAME: &str = "audit.ndjson";
+pub const AUDIT_EVENT_CASE_START: &str = "case_start";
+pub const AUDIT_EVENT_CASE_FINISH: &str = "case_finish";
+
+/// Append-only record of every case a run starts and finishes, flushed as each record is
+/// written so it survives a run that never gets to write its result JSON.
+#[derive(Debug)]
+pub struct AuditLog {
+    file: Mutex<File>,
+    finished: Mutex<HashSet<String>>,
+    /// Redacted table URLs the running case has resolved so far.
+    table_urls: Mutex<BTreeSet<String>>,
+}
+
+#[derive(Debug, Serialize)]
+struct AuditRecord<'a> {
+    event: &'static str,
+    case: &'a str,
+    at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    elapsed_ms: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    table_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outcome: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure_kind: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+}
+
+impl AuditLog {
+    /// Opens `path` for appending, creating it when missing; earlier runs' records are kept.
+    pub fn open(path: &Path) -> BenchResult<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+            finished: Mutex::new(HashSet::new()),
+            table_urls: Mutex::new(BTreeSet::new()),
+        })
+    }
+
+    fn case_started(&self, case: &str) {
+        self.table_urls().clear();
+        self.write(&AuditRecord {
+            event: AUDIT_EVENT_CASE_START,
+            case,
+            at: Utc::now().to_rfc3339(),
+            elapsed_ms: None,
+            table_urls: None,
+            outcome: None,
+            failure_kind: None,
+            message: None,
+        });
+    }
+
+    fn case_finished(&self, case: &CaseResult, elapsed_ms: Option<f64>, table_urls: Vec<String>) {
+        self.write(&AuditRecord {
+            event: AUDIT_EVENT_CASE_FINISH,
+            case: &case.case,
+            at: Utc::now().to_rfc3339(),
+            elapsed_ms,
+            table_urls: Some(table_urls),
+            outcome: Some(case_outcome(case)),
+            failure_kind: case.failure_kind.as_deref(),
+            message: case
+                .failure
+                .as_ref()
+                .map(|failure| failure.message.as_str()),
+        });
+        self.finished
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(case.case.clone());
+    }
+
+    fn table_urls(&self) -> MutexGuard<'_, BTreeSet<String>> {
+        self.table_urls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// A lost audit record must not fail the benchmark it describes, so write errors are
+    /// reported and otherwise ignored.
+    fn write(&self, record: &AuditRecord<'_>) {
+        let mut line = match serde_json::to_vec(record) {
+            Ok(line) => line,
+            Err(error) => {
+                warn!(%error, "failed to encode audit record");
+                return;
+            }
+        };
+        line.push(b'\n');
+        let mut file = self
+            .file
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Err(error) = file.write_all(&line).and_then(|()| file.flush()) {
+            warn!(%error, "failed to append audit record");
+        }
+    }
+}
+
+/// `url` without credentials, query, or fragment, which is where presigned and SAS tokens
+/// end up.
+pub fn redact_table_url(url: &Url) -> String {
+    let mut redacted = url.clone();
+    // URLs without a host cannot carry credentials, so these only fail when there is
+    // nothing to strip.
+    let _ = redacted.set_username("");
+    let _ = redacted.set_password(None);
+    redacted.set_query(None);
+    redacted.set_fragment(None);
+    redacted.to_string()
+}
+
+/// Notes that the running case resolved `url`, in `log` when the run keeps one.
+pub(crate) fn record_table_url(log: Option<&AuditLog>, url: &Url) {
+    let redacted = redact_table_url(url);
+    debug!(table_url = %redacted, "resolved table");
+    if let Some(log) = log {
+        log.table_urls().insert(redacted);
+    }
+}
+
+pub(crate) async fn audited<Fut>(name: &str, hooks: &CaseHooks, fut: Fut) -> CaseExecutionResult
+where
+    Fut: Future<Output = CaseExecutionResult>,
+{
+    let span = case_span(name);
+    #[cfg(feature = "profiling")]
+    let fut = super::profile::profiling(name, hooks, fut);
+    #[cfg(feature = "heap-profiling")]
+    let fut = super::heap_profile::heap_profiling(name, hooks, fut);
+    let fut = capturing_plan(hooks, fut).instrument(span.clone());
+    report_case_started(hooks, name);
+    let start = Instant::now();
+    let Some(log) = &hooks.audit_log else {
+        let result = fut.await;
+        observe_case_finished(
+            &span,
+            hooks,
+            execution_case(&result),
+            elapsed_ms_since(start),
+        );
+        return result;
+    };
+    log.case_started(name);
+    let result = fut.await;
+    let elapsed_ms = elapsed_ms_since(start);
+    observe_case_finished(&span, hooks, execution_case(&result), elapsed_ms);
+    let urls = std::mem::take(&mut *log.table_urls());
+    log.case_finished(
+        execution_case(&result),
+        Some(elapsed_ms),
+        urls.into_iter().collect(),
+    );
+    result
+}
+
+pub(crate) fn audited_sync<F>(name: &str, hooks: &CaseHooks, op: F) -> CaseExecutionResult
+where
+    F: FnOnce() -> CaseExecutionResult,
+{
+    let span = case_span(name);
+    #[cfg(feature = "profiling")]
+    let op = || super::profile::profiling_sync(name, hooks, op);
+    #[cfg(feature = "heap-profiling")]
+    let op = || super::heap_profile::heap_profiling_sync(name, hooks, op);
+    let op = || span.in_scope(op);
+    report_case_started(hooks, name);
+    let start = Instant::now();
+    let Some(log) = &hooks.audit_log else {
+        let result = op();
+        observe_case_finished(
+            &span,
+            hooks,
+            execution_case(&result),
+            elapsed_ms_since(start),
+        );
+        return result;
+    };
+    log.case_started(name);
+    let result = op();
+    let elapsed_ms = elapsed_ms_since(start);
+    observe_case_finished(&span, hooks, execution_case(&result), elapsed_ms);
+    let urls = std::mem::take(&mut *log.table_urls());
+    log.case_finished(
+        execution_case(&result),
+        Some(elapsed_ms),
+        urls.into_iter().collect(),
+    );
+    result
+}
+
+/// Records a finish for each of `cases` that did not run through the shared case runners,
+/// such as cases a suite fails before running; starts the next target with a clean slate.
+pub(crate) fn record_unaudited_cases(hooks: &CaseHooks, cases: &[CaseResult]) {
+    let Some(log) = &hooks.audit_log else {
+        return;
+    };
+    let finished = std::mem::take(
+        &mut *log
+            .finished
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+    for case in cases.iter().filter(|case| !finished.contains(&case.case)) {
+        log.case_finished(case, None, Vec::new());
+    }
+    log.finished
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+fn execution_case(result: &CaseExecutionResult) -> &CaseResult {
+    match result {
+        CaseExecutionResult::Success(case) | CaseExecutionResult::Failure(case) => case,
+    }
+}
+
+fn case_span(name: &str) -> Span {
+    info_span!("case", case = name, outcome = Empty, elapsed_ms = Empty)
+}
+
+/// Reports a finished case to the live observers: its tracing span and the progress callback.
+fn observe_case_finished(span: &Span, hooks: &CaseHooks, case: &CaseResult, elapsed_ms: f64) {
+    span.record("outcome", case_outcome(case));
+    span.record("elapsed_ms", elapsed_ms);
+    report_case_finished(hooks, case, elapsed_ms);
+}
+
+fn elapsed_ms_since(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+pub(super) fn case_outcome(case: &CaseResult) -> &'static str {
+    if case.success {
+        "ok"
+    } else if case.failure_kind.as_deref() == Some(FAILURE_KIND_UNSUPPORTED) {
+        "unsupported"
+    } else {
+        "failed"
+    }
+}
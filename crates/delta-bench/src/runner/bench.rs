@@ -0,0 +1,1020 @@
+//! A programmatic entry point to `delta-bench run` for embedding the harness in other tools.
+//!
+//! [`BenchRunner`] takes an already planned run (see [`crate::suites::plan_run_cases`]) and
+//! returns the same [`BenchRunResult`] the CLI writes to `<results>/<label>/<target>.json`,
+//! without writing it anywhere. Fixture locking, label templates and report files stay with the
+//! caller, so a test can point a runner at a temporary fixtures directory and inspect the result.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::Serialize;
+use tracing::{info_span, Instrument};
+
+use crate::cli::{
+    validate_label, BenchmarkLane, BenchmarkMode, PlanFormat, PlanMetricsDetail, RunnerMode,
+    StorageBackend, TimingPhase,
+};
+use crate::data::fixtures::{
+    dataset_fixtures_dir, load_manifest, verify_fixture_manifest, FixtureManifest,
+};
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::manifests::DatasetId;
+use crate::results::{
+    build_run_summary, BenchContext, BenchRunResult, CaseResult, FixtureTableStats, PerfStatus,
+    RESULT_SCHEMA_VERSION,
+};
+use crate::runner::{
+    AuditLog, CaseHooks, CaseSeeds, ProgressCallback, ProgressEvent, DEFAULT_RUN_SEED,
+};
+use crate::storage::StorageConfig;
+use crate::suites::interop_consistency::interop_consistency_case;
+use crate::suites::{
+    apply_dataset_assertion_policy, apply_fixture_assertion_policy, run_planned_cases_with_seeds,
+    PlannedCase, SuiteOptions,
+};
+use crate::system::{
+    benchmark_fidelity_info, datafusion_session_config, datafusion_version, host_name,
+    BenchmarkFidelityInfo, FidelityEnvOverrides,
+};
+use crate::warnings::{collect_run_warnings, fixture_age};
+
+type CaseCallback = Box<dyn Fn(&CaseResult) + Send + Sync>;
+
+/// A validated run, ready to execute. Built with [`BenchRunner::builder`].
+pub struct BenchRunner {
+    fixtures_dir: PathBuf,
+    plan: Vec<PlannedCase>,
+    storage: StorageConfig,
+    dataset: Option<DatasetId>,
+    fixture_seed: Option<u64>,
+    scale: String,
+    target: String,
+    runner: RunnerMode,
+    benchmark_mode: BenchmarkMode,
+    lane: BenchmarkLane,
+    timing_phase: TimingPhase,
+    suite_options: SuiteOptions,
+    warmup: u32,
+    iterations: u32,
+    seeds: CaseSeeds,
+    label: String,
+    git_sha: Option<String>,
+    git_dirty: Option<bool>,
+    harness_revision: Option<String>,
+    harness_dirty: Option<bool>,
+    backend_profile: Option<String>,
+    results_dir: Option<PathBuf>,
+    ref_issues: Vec<String>,
+    audit_log: Option<Arc<AuditLog>>,
+    capture_plan: Option<PlanFormat>,
+    plan_metrics: PlanMetricsDetail,
+    #[cfg(feature = "profiling")]
+    profile: bool,
+    heap_profile: bool,
+    on_case: Vec<CaseCallback>,
+    on_progress: Vec<ProgressCallback>,
+}
+
+/// Settings for a [`BenchRunner`]. Everything but the fixtures directory and the plan defaults
+/// to what `delta-bench run` uses without flags.
+pub struct BenchRunnerBuilder {
+    fixtures_dir: Option<PathBuf>,
+    plan: Option<Vec<PlannedCase>>,
+    storage: StorageConfig,
+    dataset: Option<DatasetId>,
+    fixture_seed: Option<u64>,
+    scale: String,
+    target: String,
+    runner: RunnerMode,
+    benchmark_mode: BenchmarkMode,
+    lane: BenchmarkLane,
+    timing_phase: TimingPhase,
+    suite_options: SuiteOptions,
+    warmup: u32,
+    iterations: u32,
+    seeds: CaseSeeds,
+    label: String,
+    git_sha: Option<String>,
+    git_dirty: Option<bool>,
+    harness_revision: Option<String>,
+    harness_dirty: Option<bool>,
+    backend_profile: Option<String>,
+    results_dir: Option<PathBuf>,
+    ref_issues: Vec<String>,
+    audit_log: Option<Arc<AuditLog>>,
+    capture_plan: Option<PlanFormat>,
+    plan_metrics: PlanMetricsDetail,
+    profile: bool,
+    heap_profile: bool,
+    on_case: Vec<CaseCallback>,
+    on_progress: Vec<ProgressCallback>,
+}
+
+impl Default for BenchRunnerBuilder {
+    fn default() -> Self {
+        Self {
+            fixtures_dir: None,
+            plan: None,
+            storage: StorageConfig::local(),
+            dataset: None,
+            fixture_seed: None,
+            scale: "sf1".to_string(),
+            target: "all".to_string(),
+            runner: RunnerMode::All,
+            benchmark_mode: BenchmarkMode::Perf,
+            lane: BenchmarkLane::Macro,
+            timing_phase: TimingPhase::Execute,
+            suite_options: SuiteOptions::default(),
+            warmup: 1,
+            iterations: 5,
+            seeds: CaseSeeds::new(DEFAULT_RUN_SEED),
+            label: "local".to_string(),
+            git_sha: None,
+            git_dirty: None,
+            harness_revision: None,
+            harness_dirty: None,
+            backend_profile: None,
+            results_dir: None,
+            ref_issues: Vec::new(),
+            audit_log: None,
+            capture_plan: None,
+            plan_metrics: PlanMetricsDetail::Curated,
+            profile: false,
+            heap_profile: false,
+            on_case: Vec::new(),
+            on_progress: Vec::new(),
+        }
+    }
+}
+
+impl BenchRunnerBuilder {
+    /// The fixtures root, as given to `--fixtures-dir`. A dataset's fixtures are looked up in
+    /// its subdirectory.
+    pub fn fixtures_dir(mut self, fixtures_dir: impl Into<PathBuf>) -> Self {
+        self.fixtures_dir = Some(fixtures_dir.into());
+        self
+    }
+
+    /// The cases to run, in order, as returned by the `plan_run_cases*` functions.
+    pub fn plan(mut self, plan: Vec<PlannedCase>) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+
+    pub fn storage(mut self, storage: StorageConfig) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Runs against a named dataset: its fixtures subdirectory, its pinned scale, and its
+    /// assertion policy.
+    pub fn dataset(mut self, dataset: DatasetId) -> Self {
+        self.dataset = Some(dataset);
+        self
+    }
+
+    /// Fails the run unless the fixtures were generated with this seed.
+    pub fn fixture_seed(mut self, seed: u64) -> Self {
+        self.fixture_seed = Some(seed);
+        self
+    }
+
+    /// Ignored when a dataset is set, since the dataset pins its scale.
+    pub fn scale(mut self, scale: impl Into<String>) -> Self {
+        self.scale = scale.into();
+        self
+    }
+
+    /// The target the plan was built for; recorded as the run's suite.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = target.into();
+        self
+    }
+
+    pub fn runner(mut self, runner: RunnerMode) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    pub fn benchmark_mode(mut self, benchmark_mode: BenchmarkMode) -> Self {
+        self.benchmark_mode = benchmark_mode;
+        self
+    }
+
+    pub fn lane(mut self, lane: BenchmarkLane) -> Self {
+        self.lane = lane;
+        self
+    }
+
+    pub fn timing_phase(mut self, timing_phase: TimingPhase) -> Self {
+        self.timing_phase = timing_phase;
+        self
+    }
+
+    /// The suite registry the plan runs against, and settings only some suites read, such as
+    /// the TPC-DS phase and the `custom_sql` query directory. Plan with the same options, via
+    /// [`crate::suites::plan_run_cases_with_options`].
+    pub fn suite_options(mut self, suite_options: SuiteOptions) -> Self {
+        self.suite_options = suite_options;
+        self
+    }
+
+    /// Assert mode and the correctness and smoke lanes always run one unwarmed iteration.
+    pub fn warmup(mut self, warmup: u32) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    /// See [`BenchRunnerBuilder::warmup`].
+    pub fn iterations(mut self, iterations: u32) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    pub fn seeds(mut self, seeds: CaseSeeds) -> Self {
+        self.seeds = seeds;
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// The delta-rs revision under test and whether its checkout was dirty. Left unset, the
+    /// result records no revision.
+    pub fn git_revision(mut self, git_sha: Option<String>, git_dirty: Option<bool>) -> Self {
+        self.git_sha = git_sha;
+        self.git_dirty = git_dirty;
+        self
+    }
+
+    pub fn harness_revision(
+        mut self,
+        harness_revision: Option<String>,
+        harness_dirty: Option<bool>,
+    ) -> Self {
+        self.harness_revision = harness_revision;
+        self.harness_dirty = harness_dirty;
+        self
+    }
+
+    pub fn backend_profile(mut self, backend_profile: Option<String>) -> Self {
+        self.backend_profile = backend_profile;
+        self
+    }
+
+    /// Recorded in the result's context; the runner does not write there.
+    pub fn results_dir(mut self, results_dir: impl Into<PathBuf>) -> Self {
+        self.results_dir = Some(results_dir.into());
+        self
+    }
+
+    pub fn ref_issues(mut self, ref_issues: Vec<String>) -> Self {
+        self.ref_issues = ref_issues;
+        self
+    }
+
+    /// Records every table URL the cases touch, as `delta-bench run` does in `audit.ndjson`.
+    pub fn audit_log(mut self, audit_log: Arc<AuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Records the physical plan of each scan, tpcds, and custom_sql case in its result.
+    pub fn capture_plan(mut self, format: PlanFormat) -> Self {
+        self.capture_plan = Some(format);
+        self
+    }
+
+    /// With [`PlanMetricsDetail::Full`], keeps the complete DataFusion metrics tree of every
+    /// measured iteration of the scan, tpcds, and custom_sql cases in
+    /// [`CaseResult::plan_metrics`]; write them out with [`crate::runner::write_plan_metrics_files`].
+    pub fn plan_metrics(mut self, detail: PlanMetricsDetail) -> Self {
+        self.plan_metrics = detail;
+        self
+    }
+
+    /// Samples each case on CPU and keeps its flamegraph in [`CaseResult::flamegraph`]; write
+    /// them out with [`crate::runner::write_flamegraphs`]. Needs the `profiling` feature.
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Runs each case once, unwarmed, under dhat and keeps its peak heap and top allocation
+    /// sites in [`CaseResult::heap_profile`]; write the profiles out with
+    /// [`crate::runner::write_heap_profiles`]. Needs the `heap-profiling` feature.
+    pub fn heap_profile(mut self, heap_profile: bool) -> Self {
+        self.heap_profile = heap_profile;
+        self
+    }
+
+    /// Called once per finished case, in plan order, after the case is finalized. Callbacks
+    /// run in the order they were added.
+    pub fn on_case(mut self, callback: impl Fn(&CaseResult) + Send + Sync + 'static) -> Self {
+        self.on_case.push(Box::new(callback));
+        self
+    }
+
+    /// Called while the run is going: when a case starts, after each measured iteration, and
+    /// when a case finishes. Unlike [`BenchRunnerBuilder::on_case`], this sees every case the
+    /// planned suites run, before finalization.
+    pub fn on_progress(
+        mut self,
+        callback: impl Fn(&ProgressEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_progress.push(Arc::new(callback));
+        self
+    }
+
+    pub fn build(self) -> BenchResult<BenchRunner> {
+        let fixtures_dir = self.fixtures_dir.ok_or_else(|| {
+            BenchError::InvalidArgument("BenchRunner requires a fixtures_dir".to_string())
+        })?;
+        let plan = self.plan.ok_or_else(|| {
+            BenchError::InvalidArgument("BenchRunner requires a plan".to_string())
+        })?;
+        validate_label(&self.label)?;
+        validate_execution_contract(self.benchmark_mode, self.lane)?;
+        if self.profile && !cfg!(feature = "profiling") {
+            return Err(BenchError::InvalidArgument(
+                "profiling needs delta-bench built with the `profiling` feature".to_string(),
+            ));
+        }
+        if self.heap_profile && !cfg!(feature = "heap-profiling") {
+            return Err(BenchError::InvalidArgument(
+                "heap profiling needs delta-bench built with the `heap-profiling` feature"
+                    .to_string(),
+            ));
+        }
+        Ok(BenchRunner {
+            fixtures_dir,
+            plan,
+            storage: self.storage,
+            dataset: self.dataset,
+            fixture_seed: self.fixture_seed,
+            scale: self.scale,
+            target: self.target,
+            runner: self.runner,
+            benchmark_mode: self.benchmark_mode,
+            lane: self.lane,
+            timing_phase: self.timing_phase,
+            suite_options: self.suite_options,
+            warmup: self.warmup,
+            iterations: self.iterations,
+            seeds: self.seeds,
+            label: self.label,
+            git_sha: self.git_sha,
+            git_dirty: self.git_dirty,
+            harness_revision: self.harness_revision,
+            harness_dirty: self.harness_dirty,
+            backend_profile: self.backend_profile,
+            results_dir: self.results_dir,
+            ref_issues: self.ref_issues,
+            audit_log: self.audit_log,
+            capture_plan: self.capture_plan,
+            plan_metrics: self.plan_metrics,
+            #[cfg(feature = "profiling")]
+            profile: self.profile,
+            heap_profile: self.heap_profile,
+            on_case: self.on_case,
+            on_progress: self.on_progress,
+        })
+    }
+}
+
+impl BenchRunner {
+    pub fn builder() -> BenchRunnerBuilder {
+        BenchRunnerBuilder::default()
+    }
+
+    /// Runs the plan and returns the result `delta-bench run` would write. Case failures are
+    /// reported in the result; an error means the run itself could not happen, e.g. missing
+    /// fixtures or a plan the timing phase does not apply to.
+    pub async fn run(mut self) -> BenchResult<BenchRunResult> {
+        let scale = match self.dataset {
+            Some(dataset) => dataset.scale().to_string(),
+            None => self.scale.clone(),
+        };
+        let fixtures_dir = dataset_fixtures_dir(&self.fixtures_dir, self.dataset);
+        let storage = self
+            .storage
+            .for_dataset(self.dataset.map(DatasetId::as_str));
+        let fixture_manifest = load_manifest(&fixtures_dir, &scale)?;
+        verify_fixture_manifest(&fixture_manifest, self.dataset, self.fixture_seed)?;
+        apply_dataset_assertion_policy(&mut self.plan, self.dataset);
+        apply_fixture_assertion_policy(
+            &mut self.plan,
+            fixture_manifest
+                .fixture_recipe
+                .as_ref()
+                .map(|recipe| recipe.wide_table_columns),
+        );
+
+        let single_unwarmed_iteration = self.benchmark_mode == BenchmarkMode::Assert
+            || self.lane == BenchmarkLane::Correctness
+            || self.lane == BenchmarkLane::Smoke
+            || self.heap_profile;
+        let (warmup, iterations) = if single_unwarmed_iteration {
+            (0, 1)
+        } else {
+            (self.warmup, self.iterations)
+        };
+        let mut hooks = CaseHooks::new().with_plan_metrics(self.plan_metrics);
+        let storage = match self.audit_log.take() {
+            Some(audit_log) => {
+                hooks = hooks.with_audit_log(Arc::clone(&audit_log));
+                storage.with_audit_log(audit_log)
+            }
+            None => storage,
+        };
+        let on_progress = std::mem::take(&mut self.on_progress);
+        if !on_progress.is_empty() {
+            let callback: ProgressCallback = Arc::new(move |event: &ProgressEvent| {
+                for callback in &on_progress {
+                    callback(event);
+                }
+            });
+            hooks = hooks.with_progress(callback);
+        }
+        if let Some(format) = self.capture_plan {
+            hooks = hooks.with_plan_capture(format);
+        }
+        #[cfg(feature = "profiling")]
+        if self.profile {
+            hooks = hooks.with_profiling();
+        }
+        #[cfg(feature = "heap-profiling")]
+        if self.heap_profile {
+            hooks = hooks.with_heap_profiling();
+        }
+        let cases = run_planned_cases_with_seeds(
+            &fixtures_dir,
+            &self.plan,
+            &scale,
+            self.lane,
+            self.timing_phase,
+            warmup,
+            iterations,
+            &hooks,
+            &storage,
+            &self.seeds,
+            &self.suite_options,
+        )
+        .instrument(info_span!(
+            "run",
+            label = self.label.as_str(),
+            target = self.target.as_str(),
+            scale = scale.as_str(),
+            lane = self.lane.as_str(),
+            storage_backend = storage.backend().as_str(),
+        ))
+        .await?;
+
+        let context = self.context(
+            &fixtures_dir,
+            &scale,
+            storage.backend(),
+            &fixture_manifest,
+            warmup,
+            iterations,
+        )?;
+        let mut cases =
+            finalize_cases(cases, &self.plan, self.benchmark_mode, self.lane, &context)?;
+        cases.extend(interop_consistency_case(&self.plan, &cases));
+        for case in &cases {
+            #[cfg(feature = "otel")]
+            crate::otel::record_case_metrics(&context, case);
+            for callback in &self.on_case {
+                callback(case);
+            }
+        }
+        let warnings = collect_run_warnings(&context, &cases, fixture_age(&fixtures_dir, &scale));
+
+        Ok(BenchRunResult {
+            schema_version: RESULT_SCHEMA_VERSION,
+            context,
+            cases,
+            warnings,
+        })
+    }
+
+    fn context(
+        &mut self,
+        fixtures_dir: &Path,
+        scale: &str,
+        storage_backend: StorageBackend,
+        fixture_manifest: &FixtureManifest,
+        warmup: u32,
+        iterations: u32,
+    ) -> BenchResult<BenchContext> {
+        let fidelity = benchmark_fidelity_info(&FidelityEnvOverrides::from_env());
+        let fidelity_fingerprint = compute_fidelity_fingerprint(&fidelity)?;
+        let run_id = compute_run_id(
+            &self.label,
+            self.git_sha.as_deref(),
+            &self.target,
+            scale,
+            self.lane.as_str(),
+            self.timing_phase.as_str(),
+        )?;
+        Ok(BenchContext {
+            schema_version: RESULT_SCHEMA_VERSION,
+            label: self.label.clone(),
+            git_sha: self.git_sha.take(),
+            git_dirty: self.git_dirty,
+            created_at: Utc::now(),
+            host: host_name(),
+            suite: self.target.clone(),
+            scale: scale.to_string(),
+            iterations,
+            warmup,
+            timing_phase: Some(self.timing_phase.as_str().to_string()),
+            dataset_id: self.dataset.map(|dataset| dataset.as_str().to_string()),
+            dataset_fingerprint: Some(fixture_manifest.dataset_fingerprint.clone()),
+            runner: Some(self.runner.as_str().to_string()),
+            storage_backend: Some(storage_backend.as_str().to_string()),
+            benchmark_mode: Some(self.benchmark_mode.as_str().to_string()),
+            lane: Some(self.lane.as_str().to_string()),
+            measurement_kind: Some(measurement_kind_for_target(&self.target).to_string()),
+            validation_level: Some(
+                validation_level_for_run_plan(&self.plan, self.lane).to_string(),
+            ),
+            run_id: Some(run_id),
+            harness_revision: self.harness_revision.take(),
+            harness_dirty: self.harness_dirty,
+            fixture_recipe_hash: Some(fixture_manifest.fixture_recipe_hash.clone()),
+            fixture_tables: fixture_manifest
+                .table_summaries
+                .iter()
+                .map(|summary| FixtureTableStats {
+                    table: summary.table.clone(),
+                    rows: summary.rows,
+                    files: summary.files,
+                    bytes: summary.bytes,
+                })
+                .collect(),
+            fixtures_dir: Some(fixtures_dir.display().to_string()),
+            results_dir: self
+                .results_dir
+                .as_ref()
+                .map(|results_dir| results_dir.display().to_string()),
+            datafusion_version: Some(datafusion_version().to_string()),
+            datafusion_config: datafusion_session_config(),
+            fidelity_fingerprint: Some(fidelity_fingerprint),
+            backend_profile: self.backend_profile.take(),
+            image_version: fidelity.image_version,
+            hardening_profile_id: fidelity.hardening_profile_id,
+            hardening_profile_sha256: fidelity.hardening_profile_sha256,
+            cpu_model: fidelity.cpu_model,
+            cpu_microcode: fidelity.cpu_microcode,
+            kernel: fidelity.kernel,
+            boot_params: fidelity.boot_params,
+            cpu_steal_pct: fidelity.cpu_steal_pct,
+            numa_topology: fidelity.numa_topology,
+            egress_policy_sha256: fidelity.egress_policy_sha256,
+            run_mode: fidelity.run_mode,
+            maintenance_window_id: fidelity.maintenance_window_id,
+            ref_issues: std::mem::take(&mut self.ref_issues),
+        })
+    }
+}
+
+pub fn validate_execution_contract(
+    benchmark_mode: BenchmarkMode,
+    lane: BenchmarkLane,
+) -> BenchResult<()> {
+    if benchmark_mode == BenchmarkMode::Assert && lane != BenchmarkLane::Correctness {
+        return Err(BenchError::InvalidArgument(
+            "--mode assert requires --lane correctness".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn finalize_cases(
+    mut cases: Vec<CaseResult>,
+    plan: &[PlannedCase],
+    benchmark_mode: BenchmarkMode,
+    lane: BenchmarkLane,
+    context: &BenchContext,
+) -> BenchResult<Vec<CaseResult>> {
+    for (case, planned) in cases.iter_mut().zip(plan.iter()) {
+        case.run_summary = Some(build_run_summary(
+            &case.samples,
+            Some(context.host.as_str()),
+            context.fidelity_fingerprint.as_deref(),
+        ));
+        case.suite_manifest_hash = Some(planned.suite_manifest_hash.clone());
+        case.case_definition_hash = Some(planned.case_definition_hash.clone());
+        case.supports_decision = Some(planned.supports_decision);
+        case.required_runs = planned.required_runs;
+        case.decision_threshold_pct = planned.decision_threshold_pct;
+        case.decision_metric = planned.decision_metric.clone();
+        case.compatibility_key =
+            compute_case_compatibility_key(planned, lane, context).map(Some)?;
+        if benchmark_mode == BenchmarkMode::Assert
+            || lane == BenchmarkLane::Correctness
+            || lane == BenchmarkLane::Smoke
+            || (lane == BenchmarkLane::Macro && planned.lane == BenchmarkLane::Correctness.as_str())
+        {
+            case.perf_status = if case.validation_passed {
+                PerfStatus::ValidationOnly
+            } else {
+                PerfStatus::Invalid
+            };
+            case.elapsed_stats = None;
+        }
+    }
+    Ok(cases)
+}
+
+fn measurement_kind_for_target(target: &str) -> &'static str {
+    if matches!(target, "scan" | "tpcds") {
+        "phase_breakdown"
+    } else {
+        "end_to_end"
+    }
+}
+
+fn validation_level_for_run_plan(plan: &[PlannedCase], lane: BenchmarkLane) -> &'static str {
+    if lane != BenchmarkLane::Correctness {
+        return "operational";
+    }
+    if plan.iter().all(case_supports_semantic_validation) {
+        "semantic"
+    } else {
+        "operational"
+    }
+}
+
+fn case_supports_semantic_validation(case: &PlannedCase) -> bool {
+    matches!(
+        case.target.as_str(),
+        "write"
+            | "delete_update"
+            | "delete_update_perf"
+            | "merge"
+            | "merge_perf"
+            | "deletion_vectors"
+            | "metadata"
+            | "checkpoint"
+            | "late_arriving"
+            | "restore"
+            | "convert_to_delta"
+            | "optimize_perf"
+            | "optimize_vacuum"
+            | "scenario"
+            | "interop_py"
+    )
+}
+
+fn compute_fidelity_fingerprint(fidelity: &BenchmarkFidelityInfo) -> BenchResult<String> {
+    hash_json(&serde_json::json!({
+        "image_version": fidelity.image_version,
+        "hardening_profile_id": fidelity.hardening_profile_id,
+        "hardening_profile_sha256": fidelity.hardening_profile_sha256,
+        "cpu_model": fidelity.cpu_model,
+        "cpu_microcode": fidelity.cpu_microcode,
+        "kernel": fidelity.kernel,
+        "boot_params": fidelity.boot_params,
+        "cpu_steal_pct": fidelity.cpu_steal_pct,
+        "numa_topology": fidelity.numa_topology,
+        "egress_policy_sha256": fidelity.egress_policy_sha256,
+        "run_mode": fidelity.run_mode,
+        "maintenance_window_id": fidelity.maintenance_window_id,
+    }))
+}
+
+fn compute_run_id(
+    label: &str,
+    git_sha: Option<&str>,
+    suite: &str,
+    scale: &str,
+    lane: &str,
+    timing_phase: &str,
+) -> BenchResult<String> {
+    hash_json(&serde_json::json!({
+        "label": label,
+        "git_sha": git_sha,
+        "suite": suite,
+        "scale": scale,
+        "lane": lane,
+        "timing_phase": timing_phase,
+        "created_at": Utc::now().to_rfc3339(),
+    }))
+}
+
+fn compute_case_compatibility_key(
+    planned: &PlannedCase,
+    lane: BenchmarkLane,
+    context: &BenchContext,
+) -> BenchResult<String> {
+    if planned
+        .decision_threshold_pct
+        .is_some_and(|threshold| !threshold.is_finite())
+    {
+        return Err(BenchError::InvalidArgument(format!(
+            "decision_threshold_pct must be finite for compatibility_key generation (case='{}')",
+            planned.id
+        )));
+    }
+
+    #[derive(Serialize)]
+    struct CompatibilityKeyInput<'a> {
+        suite_scope: &'a str,
+        target: &'a str,
+        runner: Option<&'a str>,
+        timing_phase: Option<&'a str>,
+        dataset_id: Option<&'a str>,
+        dataset_fingerprint: Option<&'a str>,
+        scale: &'a str,
+        storage_backend: Option<&'a str>,
+        backend_profile: Option<&'a str>,
+        lane: &'a str,
+        measurement_kind: Option<&'a str>,
+        validation_level: Option<&'a str>,
+        harness_revision: Option<&'a str>,
+        fixture_recipe_hash: Option<&'a str>,
+        fidelity_fingerprint: Option<&'a str>,
+        planned_lane: &'a str,
+        suite_manifest_hash: &'a str,
+        case_definition_hash: &'a str,
+        supports_decision: bool,
+        required_runs: Option<u32>,
+        decision_threshold_pct: Option<f64>,
+        decision_metric: Option<&'a str>,
+    }
+
+    hash_json(&CompatibilityKeyInput {
+        suite_scope: context.suite.as_str(),
+        target: planned.target.as_str(),
+        runner: context.runner.as_deref(),
+        timing_phase: context.timing_phase.as_deref(),
+        dataset_id: context.dataset_id.as_deref(),
+        dataset_fingerprint: context.dataset_fingerprint.as_deref(),
+        scale: context.scale.as_str(),
+        storage_backend: context.storage_backend.as_deref(),
+        backend_profile: context.backend_profile.as_deref(),
+        lane: lane.as_str(),
+        measurement_kind: context.measurement_kind.as_deref(),
+        validation_level: context.validation_level.as_deref(),
+        harness_revision: context.harness_revision.as_deref(),
+        fixture_recipe_hash: context.fixture_recipe_hash.as_deref(),
+        fidelity_fingerprint: context.fidelity_fingerprint.as_deref(),
+        planned_lane: planned.lane.as_str(),
+        suite_manifest_hash: planned.suite_manifest_hash.as_str(),
+        case_definition_hash: planned.case_definition_hash.as_str(),
+        supports_decision: planned.supports_decision,
+        required_runs: planned.required_runs,
+        decision_threshold_pct: planned.decision_threshold_pct,
+        decision_metric: planned.decision_metric.as_deref(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_case_compatibility_key, finalize_cases, validate_execution_contract};
+    use crate::cli::{BenchmarkLane, BenchmarkMode};
+    use crate::error::BenchError;
+    use crate::results::{BenchContext, CaseResult, ElapsedStats, IterationSample, PerfStatus};
+    use crate::runner::CaseBudget;
+    use crate::suites::PlannedCase;
+    use chrono::Utc;
+    use std::collections::BTreeMap;
+
+    fn planned_case(decision_threshold_pct: Option<f64>) -> PlannedCase {
+        PlannedCase {
+            id: "case-a".to_string(),
+            target: "scan".to_string(),
+            lane: "macro".to_string(),
+            assertions: Vec::new(),
+            warn_assertions: Vec::new(),
+            suite_manifest_hash: "sha256:manifest".to_string(),
+            case_definition_hash: "sha256:case-def".to_string(),
+            supports_decision: true,
+            required_runs: Some(5),
+            decision_threshold_pct,
+            decision_metric: Some("median".to_string()),
+            depends_on: Vec::new(),
+            budget: CaseBudget::default(),
+            tags: Vec::new(),
+            interop_workload: None,
+            matrix: None,
+        }
+    }
+
+    fn case_result() -> CaseResult {
+        CaseResult {
+            case: "case-a".to_string(),
+            success: true,
+            validation_passed: true,
+            perf_status: PerfStatus::Trusted,
+            classification: "supported".to_string(),
+            samples: Vec::new(),
+            elapsed_stats: None,
+            run_summary: None,
+            run_summaries: None,
+            suite_manifest_hash: None,
+            case_definition_hash: None,
+            compatibility_key: None,
+            supports_decision: None,
+            required_runs: None,
+            decision_threshold_pct: None,
+            decision_metric: None,
+            seed: None,
+            physical_plan: None,
+            plan_metrics_file: None,
+            plan_metrics: Vec::new(),
+            profile_file: None,
+            flamegraph: None,
+            heap_profile: None,
+            warnings: Vec::new(),
+            failure_kind: None,
+            failure: None,
+        }
+    }
+
+    fn timed_case_result() -> CaseResult {
+        let mut case = case_result();
+        case.samples = vec![IterationSample {
+            elapsed_ms: 123.0,
+            rows: None,
+            bytes: None,
+            metrics: None,
+        }];
+        case.elapsed_stats = Some(ElapsedStats {
+            min_ms: 123.0,
+            max_ms: 123.0,
+            mean_ms: 123.0,
+            median_ms: 123.0,
+            stddev_ms: 0.0,
+            cv_pct: Some(0.0),
+        });
+        case
+    }
+
+    fn bench_context() -> BenchContext {
+        BenchContext {
+            schema_version: 5,
+            label: "test".to_string(),
+            git_sha: Some("abc123".to_string()),
+            git_dirty: None,
+            created_at: Utc::now(),
+            host: "host-a".to_string(),
+            suite: "scan".to_string(),
+            scale: "sf1".to_string(),
+            iterations: 5,
+            warmup: 1,
+            timing_phase: Some("execute".to_string()),
+            dataset_id: Some("tiny_smoke".to_string()),
+            dataset_fingerprint: Some("sha256:dataset".to_string()),
+            runner: Some("rust".to_string()),
+            storage_backend: Some("local".to_string()),
+            benchmark_mode: Some("perf".to_string()),
+            lane: Some("macro".to_string()),
+            measurement_kind: Some("phase_breakdown".to_string()),
+            validation_level: Some("operational".to_string()),
+            run_id: Some("sha256:run".to_string()),
+            harness_revision: Some("harness-1".to_string()),
+            harness_dirty: None,
+            fixture_recipe_hash: Some("sha256:recipe-a".to_string()),
+            fixture_tables: Vec::new(),
+            fixtures_dir: None,
+            results_dir: None,
+            datafusion_version: None,
+            datafusion_config: BTreeMap::new(),
+            fidelity_fingerprint: Some("sha256:fidelity".to_string()),
+            backend_profile: Some("local".to_string()),
+            image_version: None,
+            hardening_profile_id: None,
+            hardening_profile_sha256: None,
+            cpu_model: None,
+            cpu_microcode: None,
+            kernel: None,
+            boot_params: None,
+            cpu_steal_pct: None,
+            numa_topology: None,
+            egress_policy_sha256: None,
+            run_mode: None,
+            maintenance_window_id: None,
+            ref_issues: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn compatibility_key_rejects_non_finite_decision_thresholds() {
+        let err = compute_case_compatibility_key(
+            &planned_case(Some(f64::NAN)),
+            BenchmarkLane::Macro,
+            &bench_context(),
+        )
+        .expect_err("non-finite thresholds must fail compatibility hashing");
+
+        assert!(
+            matches!(err, BenchError::InvalidArgument(_)),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn finalize_cases_propagates_compatibility_key_errors() {
+        let err = finalize_cases(
+            vec![case_result()],
+            &[planned_case(Some(f64::NAN))],
+            BenchmarkMode::Perf,
+            BenchmarkLane::Macro,
+            &bench_context(),
+        )
+        .expect_err("finalization must not silently drop compatibility-key failures");
+
+        assert!(
+            matches!(err, BenchError::InvalidArgument(_)),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn compatibility_key_changes_when_context_identity_changes() {
+        let planned = planned_case(Some(5.0));
+        let baseline_context = bench_context();
+        let baseline =
+            compute_case_compatibility_key(&planned, BenchmarkLane::Macro, &baseline_context)
+                .expect("baseline compatibility key");
+        let mut fixture_context = bench_context();
+        fixture_context.fixture_recipe_hash = Some("sha256:recipe-b".to_string());
+        let fixture_changed =
+            compute_case_compatibility_key(&planned, BenchmarkLane::Macro, &fixture_context)
+                .expect("fixture change must hash");
+        let mut runner_context = bench_context();
+        runner_context.runner = Some("python".to_string());
+        let runner_changed =
+            compute_case_compatibility_key(&planned, BenchmarkLane::Macro, &runner_context)
+                .expect("runner change must hash");
+
+        assert_ne!(baseline, fixture_changed);
+        assert_ne!(baseline, runner_changed);
+    }
+
+    #[test]
+    fn finalize_cases_marks_correctness_tagged_macro_runs_validation_only() {
+        let mut planned = planned_case(Some(5.0));
+        planned.target = "write".to_string();
+        planned.lane = "correctness".to_string();
+
+        let cases = finalize_cases(
+            vec![timed_case_result()],
+            &[planned],
+            BenchmarkMode::Perf,
+            BenchmarkLane::Macro,
+            &bench_context(),
+        )
+        .expect("finalization succeeds");
+
+        let case = &cases[0];
+        assert!(case.success);
+        assert_eq!(case.perf_status, PerfStatus::ValidationOnly);
+        assert!(case.elapsed_stats.is_none());
+        assert_eq!(case.samples.len(), 1);
+    }
+
+    #[test]
+    fn finalize_cases_preserves_invalid_status_for_failed_correctness_runs() {
+        let mut case = timed_case_result();
+        case.success = false;
+        case.validation_passed = false;
+        case.perf_status = PerfStatus::Invalid;
+        case.elapsed_stats = None;
+
+        let cases = finalize_cases(
+            vec![case],
+            &[planned_case(Some(5.0))],
+            BenchmarkMode::Assert,
+            BenchmarkLane::Correctness,
+            &bench_context(),
+        )
+        .expect("finalization succeeds");
+
+        let case = &cases[0];
+        assert!(!case.success);
+        assert_eq!(case.perf_status, PerfStatus::Invalid);
+        assert!(case.elapsed_stats.is_none());
+    }
+
+    #[test]
+    fn assert_mode_requires_correctness_lane() {
+        let err = validate_execution_contract(BenchmarkMode::Assert, BenchmarkLane::Smoke)
+            .expect_err("assert mode outside correctness must fail");
+        assert!(
+            matches!(err, BenchError::InvalidArgument(_)),
+            "unexpected error: {err}"
+        );
+
+        validate_execution_contract(BenchmarkMode::Assert, BenchmarkLane::Correctness)
+            .expect("correctness lane should be allowed");
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// Per-case overrides of the run's warmup and iteration counts, plus an optional limit on
+/// each iteration, from a manifest case's `warmup`, `iterations`, and `timeout_ms`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CaseBudget {
+    pub warmup: Option<u32>,
+    pub iterations: Option<u32>,
+    pub timeout_ms: Option<u64>,
+}
+
+impl CaseBudget {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The warmup, iteration count, and iteration timeout `name` runs with: its budget where one
+/// is set, the run's values otherwise.
+pub(super) fn resolve_case_budget(
+    budgets: &BTreeMap<String, CaseBudget>,
+    name: &str,
+    warmup: u32,
+    iterations: u32,
+) -> (u32, u32, Option<Duration>) {
+    let budget = budgets.get(name).copied().unwrap_or_default();
+    (
+        budget.warmup.unwrap_or(warmup),
+        budget.iterations.unwrap_or(iterations),
+        budget.timeout_ms.map(Duration::from_millis),
+    )
+}
+
+/// Awaits one iteration, failing it once `timeout` elapses.
+pub(crate) async fn within_timeout<T, E, Fut>(
+    timeout: Option<Duration>,
+    fut: Fut,
+) -> Result<T, String>
+where
+    Fut: Future<Output = Result<T, E>>,
+    E: ToString,
+{
+    let Some(timeout) = timeout else {
+        return fut.await.map_err(|error| error.to_string());
+    };
+    match tokio::time::timeout(timeout, fut).await {
+        Ok(result) => result.map_err(|error| error.to_string()),
+        Err(_) => Err(timeout_message(timeout)),
+    }
+}
+
+/// Synchronous iterations cannot be interrupted, so they fail after the fact when they
+/// overran `timeout`.
+pub(crate) fn check_timeout(timeout: Option<Duration>, start: Instant) -> Result<(), String> {
+    match timeout {
+        Some(timeout) if start.elapsed() > timeout => Err(timeout_message(timeout)),
+        _ => Ok(()),
+    }
+}
+
+fn timeout_message(timeout: Duration) -> String {
+    format!(
+        "iteration exceeded the case timeout of {} ms",
+        timeout.as_millis()
+    )
+}
@@ -0,0 +1,201 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::BenchResult;
+use crate::results::{AllocationSite, BenchRunResult, HeapProfile};
+
+#[cfg(feature = "heap-profiling")]
+pub(super) use dhat_profiling::{heap_profiling, heap_profiling_sync};
+
+/// How many allocation sites a [`HeapProfile`] keeps.
+pub const TOP_ALLOCATION_SITES: usize = 10;
+
+#[derive(Deserialize)]
+struct DhatProfile {
+    pps: Vec<DhatSite>,
+    ftbl: Vec<String>,
+}
+
+/// A dhat program point; `gb` and `gbk` are what the site held when the heap peaked.
+#[derive(Deserialize)]
+struct DhatSite {
+    tb: u64,
+    tbk: u64,
+    #[serde(default)]
+    gb: u64,
+    #[serde(default)]
+    gbk: u64,
+    fs: Vec<usize>,
+}
+
+/// Reads the peak, the totals, and the [`TOP_ALLOCATION_SITES`] largest sites at the peak out
+/// of a dhat heap profile, keeping the profile itself for [`write_heap_profiles`].
+pub fn summarize_dhat_profile(dhat_json: Vec<u8>) -> BenchResult<HeapProfile> {
+    let DhatProfile { mut pps, ftbl } = serde_json::from_slice(&dhat_json)?;
+    pps.sort_by(|a, b| b.gb.cmp(&a.gb).then(b.tb.cmp(&a.tb)));
+    let top_sites = pps
+        .iter()
+        .take(TOP_ALLOCATION_SITES)
+        .map(|site| AllocationSite {
+            peak_bytes: site.gb,
+            total_bytes: site.tb,
+            total_blocks: site.tbk,
+            frames: site
+                .fs
+                .iter()
+                .filter_map(|frame| ftbl.get(*frame))
+                .map(|frame| strip_frame_address(frame).to_string())
+                .collect(),
+        })
+        .collect();
+    Ok(HeapProfile {
+        peak_bytes: pps.iter().map(|site| site.gb).sum(),
+        peak_blocks: pps.iter().map(|site| site.gbk).sum(),
+        total_bytes: pps.iter().map(|site| site.tb).sum(),
+        total_blocks: pps.iter().map(|site| site.tbk).sum(),
+        top_sites,
+        file: None,
+        dhat_json,
+    })
+}
+
+/// dhat frames read `0x55d1c2a3: symbol (file:line)`; the address changes every run, so it is
+/// dropped to keep sites comparable between runs.
+fn strip_frame_address(frame: &str) -> &str {
+    match frame.split_once(": ") {
+        Some((address, symbol)) if address.starts_with("0x") => symbol,
+        _ => frame,
+    }
+}
+
+/// Writes each case's dhat profile to `<out_dir>/<target>.heap/<case>.json` and points its
+/// `heap_profile.file` there, relative to `out_dir`. Returns the files written.
+pub fn write_heap_profiles(
+    result: &mut BenchRunResult,
+    out_dir: &Path,
+    target: &str,
+) -> BenchResult<Vec<PathBuf>> {
+    let dir_name = format!("{target}.heap");
+    let mut written = Vec::new();
+    for case in &mut result.cases {
+        let Some(heap) = case
+            .heap_profile
+            .as_mut()
+            .filter(|heap| !heap.dhat_json.is_empty())
+        else {
+            continue;
+        };
+        fs::create_dir_all(out_dir.join(&dir_name))?;
+        let file_name = format!("{dir_name}/{}.json", case.case);
+        let path = out_dir.join(&file_name);
+        fs::write(&path, std::mem::take(&mut heap.dhat_json))?;
+        heap.file = Some(file_name);
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(feature = "heap-profiling")]
+mod dhat_profiling {
+    use std::fs;
+    use std::future::Future;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use tempfile::NamedTempFile;
+    use tracing::warn;
+
+    use super::super::{CaseExecutionResult, CaseHooks};
+    use super::summarize_dhat_profile;
+
+    /// dhat panics when a second profiler starts, so a case that starts while another is being
+    /// profiled runs unprofiled.
+    static PROFILER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+    /// Runs one async case under dhat when heap profiling is on.
+    pub(in crate::runner) async fn heap_profiling<Fut>(
+        name: &str,
+        hooks: &CaseHooks,
+        fut: Fut,
+    ) -> CaseExecutionResult
+    where
+        Fut: Future<Output = CaseExecutionResult>,
+    {
+        let Some(profiler) = start_profiler(name, hooks) else {
+            return fut.await;
+        };
+        let mut result = fut.await;
+        attach_heap_profile(name, profiler, &mut result);
+        result
+    }
+
+    /// Runs one sync case under dhat when heap profiling is on.
+    pub(in crate::runner) fn heap_profiling_sync<F>(
+        name: &str,
+        hooks: &CaseHooks,
+        op: F,
+    ) -> CaseExecutionResult
+    where
+        F: FnOnce() -> CaseExecutionResult,
+    {
+        let Some(profiler) = start_profiler(name, hooks) else {
+            return op();
+        };
+        let mut result = op();
+        attach_heap_profile(name, profiler, &mut result);
+        result
+    }
+
+    /// dhat writes its profile when dropped, so it is pointed at a scratch file read back once
+    /// the case is done.
+    struct RunningProfiler {
+        profiler: dhat::Profiler,
+        file: NamedTempFile,
+    }
+
+    fn start_profiler(name: &str, hooks: &CaseHooks) -> Option<RunningProfiler> {
+        if !hooks.heap_profile {
+            return None;
+        }
+        if PROFILER_RUNNING.swap(true, Ordering::SeqCst) {
+            warn!(
+                case = name,
+                "case started while another case was heap-profiled"
+            );
+            return None;
+        }
+        match NamedTempFile::new() {
+            Ok(file) => Some(RunningProfiler {
+                profiler: dhat::Profiler::builder().file_name(file.path()).build(),
+                file,
+            }),
+            Err(error) => {
+                PROFILER_RUNNING.store(false, Ordering::SeqCst);
+                warn!(case = name, %error, "failed to heap-profile case");
+                None
+            }
+        }
+    }
+
+    fn attach_heap_profile(name: &str, running: RunningProfiler, result: &mut CaseExecutionResult) {
+        let RunningProfiler { profiler, file } = running;
+        drop(profiler);
+        PROFILER_RUNNING.store(false, Ordering::SeqCst);
+        let heap_profile = fs::read(file.path())
+            .map_err(Into::into)
+            .and_then(summarize_dhat_profile);
+        let heap_profile = match heap_profile {
+            Ok(heap_profile) => heap_profile,
+            Err(error) => {
+                warn!(case = name, %error, "failed to read heap profile");
+                return;
+            }
+        };
+        match result {
+            CaseExecutionResult::Success(case) | CaseExecutionResult::Failure(case) => {
+                case.heap_profile = Some(heap_profile);
+            }
+        }
+    }
+}
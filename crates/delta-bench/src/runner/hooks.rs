@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::audit::AuditLog;
+use super::budget::{resolve_case_budget, CaseBudget};
+use super::plan_capture::CaseCapture;
+use super::progress::{ProgressCallback, ProgressReporter};
+use crate::cli::{PlanFormat, PlanMetricsDetail};
+
+/// Run-wide settings the shared case runners apply to every case they run, passed to each
+/// suite through [`crate::suites::SuiteRunArgs::hooks`].
+#[derive(Clone, Default)]
+pub struct CaseHooks {
+    budgets: BTreeMap<String, CaseBudget>,
+    pub(super) progress: Option<Arc<ProgressReporter>>,
+    pub(super) audit_log: Option<Arc<AuditLog>>,
+    pub(super) plan_format: Option<PlanFormat>,
+    pub(super) full_plan_metrics: bool,
+    #[cfg(feature = "profiling")]
+    pub(super) profile: bool,
+    #[cfg(feature = "heap-profiling")]
+    pub(super) heap_profile: bool,
+    pub(super) capture: Arc<Mutex<CaseCapture>>,
+}
+
+impl CaseHooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies `budgets`, keyed by case id, to the cases they name.
+    pub fn with_case_budgets(mut self, budgets: BTreeMap<String, CaseBudget>) -> Self {
+        self.budgets = budgets;
+        self
+    }
+
+    /// Reports every case's start, measured iterations, and finish to `callback`.
+    pub fn with_progress(mut self, callback: ProgressCallback) -> Self {
+        self.progress = Some(Arc::new(ProgressReporter::new(callback)));
+        self
+    }
+
+    /// Appends a start and a finish record for every case to `log`. Finish records list the
+    /// tables a case resolved through a [`StorageConfig`] given the same log.
+    ///
+    /// [`StorageConfig`]: crate::storage::StorageConfig
+    pub fn with_audit_log(mut self, log: Arc<AuditLog>) -> Self {
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Records the physical plan of every query case in its result, rendered as `format`.
+    pub fn with_plan_capture(mut self, format: PlanFormat) -> Self {
+        self.plan_format = Some(format);
+        self
+    }
+
+    /// Keeps the full metrics tree of every measured query iteration in the case result when
+    /// `detail` is [`PlanMetricsDetail::Full`].
+    pub fn with_plan_metrics(mut self, detail: PlanMetricsDetail) -> Self {
+        self.full_plan_metrics = detail == PlanMetricsDetail::Full;
+        self
+    }
+
+    /// Samples every case on CPU, warmup included, and keeps its flamegraph in the case result.
+    #[cfg(feature = "profiling")]
+    pub fn with_profiling(mut self) -> Self {
+        self.profile = true;
+        self
+    }
+
+    /// Profiles every case with dhat and keeps its [`HeapProfile`] in the case result. Only a
+    /// binary with `dhat::Alloc` as its global allocator records anything.
+    ///
+    /// [`HeapProfile`]: crate::results::HeapProfile
+    #[cfg(feature = "heap-profiling")]
+    pub fn with_heap_profiling(mut self) -> Self {
+        self.heap_profile = true;
+        self
+    }
+
+    /// The warmup, iteration count, and iteration timeout `name` runs with.
+    pub(crate) fn case_budget(
+        &self,
+        name: &str,
+        warmup: u32,
+        iterations: u32,
+    ) -> (u32, u32, Option<Duration>) {
+        resolve_case_budget(&self.budgets, name, warmup, iterations)
+    }
+}
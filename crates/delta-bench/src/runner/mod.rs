@@ -1,6 +1,9 @@
 use std::time::Instant;
 use std::{future::Future, time::Duration};
 
+use tracing::field::Empty;
+use tracing::{info_span, Instrument, Span};
+
 pub use crate::cli::TimingPhase;
 use crate::results::{
     build_run_summary, CaseFailure, CaseResult, ElapsedStats, IterationSample, PerfStatus,
@@ -8,6 +11,42 @@ use crate::results::{
 };
 use crate::stats::compute_stats;
 
+mod audit;
+mod bench;
+mod budget;
+mod heap_profile;
+mod hooks;
+mod open_loop;
+mod plan_capture;
+mod profile;
+mod progress;
+mod scenario;
+mod seed;
+
+use audit::{audited, audited_sync};
+pub(crate) use audit::{record_table_url, record_unaudited_cases};
+pub use audit::{
+    redact_table_url, AuditLog, AUDIT_EVENT_CASE_FINISH, AUDIT_EVENT_CASE_START,
+    AUDIT_LOG_FILE_NAME,
+};
+pub use bench::{validate_execution_contract, BenchRunner, BenchRunnerBuilder};
+pub use budget::CaseBudget;
+use budget::{check_timeout, within_timeout};
+pub use heap_profile::{summarize_dhat_profile, write_heap_profiles, TOP_ALLOCATION_SITES};
+pub use hooks::CaseHooks;
+pub use open_loop::{run_case_open_loop, OpenLoopConfig};
+use plan_capture::keep_iteration_plan_metrics;
+pub use plan_capture::{plan_metrics_json, render_physical_plan, write_plan_metrics_files};
+pub(crate) use plan_capture::{record_physical_plan, record_plan_metrics};
+pub use profile::write_flamegraphs;
+#[cfg(feature = "profiling")]
+pub use profile::PROFILE_FREQUENCY_HZ;
+use progress::report_iteration;
+pub(crate) use progress::report_unreported_cases;
+pub use progress::{render_progress_event, ProgressCallback, ProgressEvent};
+pub use scenario::{run_scenario, ScenarioStep};
+pub use seed::{CaseSeeds, DEFAULT_RUN_SEED};
+
 #[derive(Clone, Debug)]
 #[must_use]
 pub enum CaseExecutionResult {
@@ -66,58 +105,74 @@ impl<M> TimedSample<M> {
     }
 }
 
-pub fn run_case<F, M, E>(name: &str, warmup: u32, iterations: u32, mut op: F) -> CaseExecutionResult
+pub fn run_case<F, M, E>(
+    name: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    mut op: F,
+) -> CaseExecutionResult
 where
     F: FnMut() -> Result<M, E>,
     M: Into<SampleMetrics>,
     E: ToString,
 {
-    for warmup_idx in 0..warmup {
-        if let Err(error) = op() {
-            return CaseExecutionResult::Failure(failure_case_result(
-                name,
-                Vec::new(),
-                format!(
-                    "warmup iteration {} failed: {}",
-                    warmup_idx + 1,
-                    error.to_string()
-                ),
-            ));
+    audited_sync(name, hooks, || {
+        let (warmup, iterations, timeout) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            let start = Instant::now();
+            let outcome = op()
+                .map_err(|error| error.to_string())
+                .and_then(|_| check_timeout(timeout, start));
+            if let Err(error) = outcome {
+                return CaseExecutionResult::Failure(failure_case_result(
+                    name,
+                    Vec::new(),
+                    format!("warmup iteration {} failed: {error}", warmup_idx + 1),
+                ));
+            }
         }
-    }
 
-    let mut samples = Vec::new();
-    for _ in 0..iterations {
-        let start = Instant::now();
-        match op() {
-            Ok(metrics) => {
-                let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
-                let metrics = metrics.into();
-                samples.push(IterationSample {
-                    elapsed_ms,
-                    rows: metrics.rows_processed,
-                    bytes: metrics.bytes_processed,
-                    metrics: Some(metrics),
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let span = iteration_span(samples.len());
+            let start = Instant::now();
+            let outcome = span
+                .in_scope(&mut op)
+                .map_err(|error| error.to_string())
+                .and_then(|metrics| {
+                    check_timeout(timeout, start)?;
+                    Ok(metrics)
                 });
-            }
-            Err(e) => {
-                let failure = CaseFailure {
-                    message: e.to_string(),
-                };
-                let case = failure_case_result(name, samples, failure.message);
-                return CaseExecutionResult::Failure(case);
+            match outcome {
+                Ok(metrics) => {
+                    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+                    let metrics = metrics.into();
+                    samples.push(IterationSample {
+                        elapsed_ms,
+                        rows: metrics.rows_processed,
+                        bytes: metrics.bytes_processed,
+                        metrics: Some(metrics),
+                    });
+                    finish_iteration(&span, hooks, name, &samples, iterations);
+                }
+                Err(message) => {
+                    let case = failure_case_result(name, samples, message);
+                    return CaseExecutionResult::Failure(case);
+                }
             }
         }
-    }
 
-    let case = success_case_result(name, samples);
-    CaseExecutionResult::Success(case)
+        let case = success_case_result(name, samples);
+        CaseExecutionResult::Success(case)
+    })
 }
 
 pub async fn run_case_async<F, Fut, M, E>(
     name: &str,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     mut op: F,
 ) -> CaseExecutionResult
 where
@@ -126,41 +181,44 @@ where
     M: Into<SampleMetrics>,
     E: ToString,
 {
-    for warmup_idx in 0..warmup {
-        if let Err(error) = op().await {
-            return CaseExecutionResult::Failure(failure_case_result(
-                name,
-                Vec::new(),
-                format!(
-                    "warmup iteration {} failed: {}",
-                    warmup_idx + 1,
-                    error.to_string()
-                ),
-            ));
+    audited(name, hooks, async move {
+        let (warmup, iterations, timeout) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            if let Err(error) = within_timeout(timeout, op()).await {
+                return CaseExecutionResult::Failure(failure_case_result(
+                    name,
+                    Vec::new(),
+                    format!("warmup iteration {} failed: {error}", warmup_idx + 1),
+                ));
+            }
         }
-    }
 
-    let mut samples = Vec::new();
-    for _ in 0..iterations {
-        let start = Instant::now();
-        match op().await {
-            Ok(metrics) => {
-                append_sample(&mut samples, start.elapsed(), metrics, None);
-            }
-            Err(e) => {
-                let case = failure_case_result(name, samples, e.to_string());
-                return CaseExecutionResult::Failure(case);
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let span = iteration_span(samples.len());
+            let start = Instant::now();
+            match within_timeout(timeout, op().instrument(span.clone())).await {
+                Ok(metrics) => {
+                    append_sample(&mut samples, start.elapsed(), metrics, None, None);
+                    finish_iteration(&span, hooks, name, &samples, iterations);
+                }
+                Err(e) => {
+                    let case = failure_case_result(name, samples, e);
+                    return CaseExecutionResult::Failure(case);
+                }
             }
         }
-    }
 
-    CaseExecutionResult::Success(success_case_result(name, samples))
+        CaseExecutionResult::Success(success_case_result(name, samples))
+    })
+    .await
 }
 
 pub async fn run_case_async_with_timing_phase<F, Fut, M, E>(
     name: &str,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     timing_phase: TimingPhase,
     mut op: F,
 ) -> CaseExecutionResult
@@ -170,55 +228,59 @@ where
     M: Into<SampleMetrics>,
     E: ToString,
 {
-    for warmup_idx in 0..warmup {
-        if let Err(error) = op().await {
-            return CaseExecutionResult::Failure(failure_case_result(
-                name,
-                Vec::new(),
-                format!(
-                    "warmup iteration {} failed: {}",
-                    warmup_idx + 1,
-                    error.to_string()
-                ),
-            ));
+    audited(name, hooks, async move {
+        let (warmup, iterations, timeout) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            if let Err(error) = within_timeout(timeout, op()).await {
+                return CaseExecutionResult::Failure(failure_case_result(
+                    name,
+                    Vec::new(),
+                    format!("warmup iteration {} failed: {error}", warmup_idx + 1),
+                ));
+            }
         }
-    }
 
-    let mut samples = Vec::new();
-    for _ in 0..iterations {
-        match op().await {
-            Ok(sample) => {
-                let Some(elapsed_ms) = sample.timing.elapsed_ms_for(timing_phase) else {
-                    return CaseExecutionResult::Failure(unsupported_case_result(
-                        name,
-                        samples,
-                        format!(
-                            "requested timing phase '{}' is unavailable for this case",
-                            timing_phase.as_str()
-                        ),
-                    ));
-                };
-                append_sample(
-                    &mut samples,
-                    Duration::from_secs(0),
-                    sample.metrics,
-                    Some(elapsed_ms),
-                );
-            }
-            Err(e) => {
-                let case = failure_case_result(name, samples, e.to_string());
-                return CaseExecutionResult::Failure(case);
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let span = iteration_span(samples.len());
+            match within_timeout(timeout, op().instrument(span.clone())).await {
+                Ok(sample) => {
+                    let Some(elapsed_ms) = sample.timing.elapsed_ms_for(timing_phase) else {
+                        return CaseExecutionResult::Failure(unsupported_case_result(
+                            name,
+                            samples,
+                            format!(
+                                "requested timing phase '{}' is unavailable for this case",
+                                timing_phase.as_str()
+                            ),
+                        ));
+                    };
+                    append_sample(
+                        &mut samples,
+                        Duration::from_secs(0),
+                        sample.metrics,
+                        Some(elapsed_ms),
+                        None,
+                    );
+                    finish_iteration(&span, hooks, name, &samples, iterations);
+                }
+                Err(e) => {
+                    let case = failure_case_result(name, samples, e);
+                    return CaseExecutionResult::Failure(case);
+                }
             }
         }
-    }
 
-    CaseExecutionResult::Success(success_case_result(name, samples))
+        CaseExecutionResult::Success(success_case_result(name, samples))
+    })
+    .await
 }
 
 pub async fn run_case_async_custom_timing<F, Fut, M, E>(
     name: &str,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     mut op: F,
 ) -> CaseExecutionResult
 where
@@ -227,41 +289,50 @@ where
     M: Into<SampleMetrics>,
     E: ToString,
 {
-    for warmup_idx in 0..warmup {
-        if let Err(error) = op().await {
-            return CaseExecutionResult::Failure(failure_case_result(
-                name,
-                Vec::new(),
-                format!(
-                    "warmup iteration {} failed: {}",
-                    warmup_idx + 1,
-                    error.to_string()
-                ),
-            ));
+    audited(name, hooks, async move {
+        let (warmup, iterations, timeout) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            if let Err(error) = within_timeout(timeout, op()).await {
+                return CaseExecutionResult::Failure(failure_case_result(
+                    name,
+                    Vec::new(),
+                    format!("warmup iteration {} failed: {error}", warmup_idx + 1),
+                ));
+            }
         }
-    }
 
-    let mut samples = Vec::new();
-    for _ in 0..iterations {
-        let start = Instant::now();
-        match op().await {
-            Ok((metrics, elapsed_ms_override)) => {
-                append_sample(&mut samples, start.elapsed(), metrics, elapsed_ms_override);
-            }
-            Err(e) => {
-                let case = failure_case_result(name, samples, e.to_string());
-                return CaseExecutionResult::Failure(case);
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let span = iteration_span(samples.len());
+            let start = Instant::now();
+            match within_timeout(timeout, op().instrument(span.clone())).await {
+                Ok((metrics, elapsed_ms_override)) => {
+                    append_sample(
+                        &mut samples,
+                        start.elapsed(),
+                        metrics,
+                        elapsed_ms_override,
+                        None,
+                    );
+                    finish_iteration(&span, hooks, name, &samples, iterations);
+                }
+                Err(e) => {
+                    let case = failure_case_result(name, samples, e);
+                    return CaseExecutionResult::Failure(case);
+                }
             }
         }
-    }
 
-    CaseExecutionResult::Success(success_case_result(name, samples))
+        CaseExecutionResult::Success(success_case_result(name, samples))
+    })
+    .await
 }
 
 pub async fn run_case_async_with_setup<S, SetupF, F, Fut, M, E>(
     name: &str,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     mut setup: SetupF,
     mut op: F,
 ) -> CaseExecutionResult
@@ -272,69 +343,76 @@ where
     M: Into<SampleMetrics>,
     E: ToString,
 {
-    for warmup_idx in 0..warmup {
-        let input = match setup() {
-            Ok(input) => input,
-            Err(error) => {
+    audited(name, hooks, async move {
+        let (warmup, iterations, timeout) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            let input = match setup() {
+                Ok(input) => input,
+                Err(error) => {
+                    return CaseExecutionResult::Failure(failure_case_result(
+                        name,
+                        Vec::new(),
+                        format!(
+                            "warmup setup iteration {} failed: {}",
+                            warmup_idx + 1,
+                            error.to_string()
+                        ),
+                    ))
+                }
+            };
+            if let Err(error) = within_timeout(timeout, op(input)).await {
                 return CaseExecutionResult::Failure(failure_case_result(
                     name,
                     Vec::new(),
-                    format!(
-                        "warmup setup iteration {} failed: {}",
-                        warmup_idx + 1,
-                        error.to_string()
-                    ),
-                ))
+                    format!("warmup iteration {} failed: {error}", warmup_idx + 1),
+                ));
             }
-        };
-        if let Err(error) = op(input).await {
-            return CaseExecutionResult::Failure(failure_case_result(
-                name,
-                Vec::new(),
-                format!(
-                    "warmup iteration {} failed: {}",
-                    warmup_idx + 1,
-                    error.to_string()
-                ),
-            ));
         }
-    }
 
-    let mut samples = Vec::new();
-    for _ in 0..iterations {
-        let input = match setup() {
-            Ok(input) => input,
-            Err(e) => {
-                return CaseExecutionResult::Failure(failure_case_result(
-                    name,
-                    samples,
-                    e.to_string(),
-                ))
-            }
-        };
-
-        let start = Instant::now();
-        match op(input).await {
-            Ok(metrics) => {
-                append_sample(&mut samples, start.elapsed(), metrics, None);
-            }
-            Err(e) => {
-                return CaseExecutionResult::Failure(failure_case_result(
-                    name,
-                    samples,
-                    e.to_string(),
-                ))
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let span = iteration_span(samples.len());
+            let setup_start = Instant::now();
+            let input = match span.in_scope(&mut setup) {
+                Ok(input) => input,
+                Err(e) => {
+                    return CaseExecutionResult::Failure(failure_case_result(
+                        name,
+                        samples,
+                        e.to_string(),
+                    ))
+                }
+            };
+            let setup_elapsed = setup_start.elapsed();
+
+            let start = Instant::now();
+            match within_timeout(timeout, op(input).instrument(span.clone())).await {
+                Ok(metrics) => {
+                    append_sample(
+                        &mut samples,
+                        start.elapsed(),
+                        metrics,
+                        None,
+                        Some(setup_elapsed),
+                    );
+                    finish_iteration(&span, hooks, name, &samples, iterations);
+                }
+                Err(e) => {
+                    return CaseExecutionResult::Failure(failure_case_result(name, samples, e))
+                }
             }
         }
-    }
 
-    CaseExecutionResult::Success(success_case_result(name, samples))
+        CaseExecutionResult::Success(success_case_result(name, samples))
+    })
+    .await
 }
 
 pub async fn run_case_async_with_async_setup<S, SetupF, SetupFut, F, Fut, M, E>(
     name: &str,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     mut setup: SetupF,
     mut op: F,
 ) -> CaseExecutionResult
@@ -346,69 +424,76 @@ where
     M: Into<SampleMetrics>,
     E: ToString,
 {
-    for warmup_idx in 0..warmup {
-        let input = match setup().await {
-            Ok(input) => input,
-            Err(error) => {
+    audited(name, hooks, async move {
+        let (warmup, iterations, timeout) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            let input = match setup().await {
+                Ok(input) => input,
+                Err(error) => {
+                    return CaseExecutionResult::Failure(failure_case_result(
+                        name,
+                        Vec::new(),
+                        format!(
+                            "warmup setup iteration {} failed: {}",
+                            warmup_idx + 1,
+                            error.to_string()
+                        ),
+                    ))
+                }
+            };
+            if let Err(error) = within_timeout(timeout, op(input)).await {
                 return CaseExecutionResult::Failure(failure_case_result(
                     name,
                     Vec::new(),
-                    format!(
-                        "warmup setup iteration {} failed: {}",
-                        warmup_idx + 1,
-                        error.to_string()
-                    ),
-                ))
+                    format!("warmup iteration {} failed: {error}", warmup_idx + 1),
+                ));
             }
-        };
-        if let Err(error) = op(input).await {
-            return CaseExecutionResult::Failure(failure_case_result(
-                name,
-                Vec::new(),
-                format!(
-                    "warmup iteration {} failed: {}",
-                    warmup_idx + 1,
-                    error.to_string()
-                ),
-            ));
         }
-    }
 
-    let mut samples = Vec::new();
-    for _ in 0..iterations {
-        let input = match setup().await {
-            Ok(input) => input,
-            Err(e) => {
-                return CaseExecutionResult::Failure(failure_case_result(
-                    name,
-                    samples,
-                    e.to_string(),
-                ))
-            }
-        };
-
-        let start = Instant::now();
-        match op(input).await {
-            Ok(metrics) => {
-                append_sample(&mut samples, start.elapsed(), metrics, None);
-            }
-            Err(e) => {
-                return CaseExecutionResult::Failure(failure_case_result(
-                    name,
-                    samples,
-                    e.to_string(),
-                ))
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let span = iteration_span(samples.len());
+            let setup_start = Instant::now();
+            let input = match setup().instrument(span.clone()).await {
+                Ok(input) => input,
+                Err(e) => {
+                    return CaseExecutionResult::Failure(failure_case_result(
+                        name,
+                        samples,
+                        e.to_string(),
+                    ))
+                }
+            };
+            let setup_elapsed = setup_start.elapsed();
+
+            let start = Instant::now();
+            match within_timeout(timeout, op(input).instrument(span.clone())).await {
+                Ok(metrics) => {
+                    append_sample(
+                        &mut samples,
+                        start.elapsed(),
+                        metrics,
+                        None,
+                        Some(setup_elapsed),
+                    );
+                    finish_iteration(&span, hooks, name, &samples, iterations);
+                }
+                Err(e) => {
+                    return CaseExecutionResult::Failure(failure_case_result(name, samples, e))
+                }
             }
         }
-    }
 
-    CaseExecutionResult::Success(success_case_result(name, samples))
+        CaseExecutionResult::Success(success_case_result(name, samples))
+    })
+    .await
 }
 
 pub async fn run_case_async_with_async_setup_custom_timing<S, SetupF, SetupFut, F, Fut, M, E>(
     name: &str,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     mut setup: SetupF,
     mut op: F,
 ) -> CaseExecutionResult
@@ -420,63 +505,88 @@ where
     M: Into<SampleMetrics>,
     E: ToString,
 {
-    for warmup_idx in 0..warmup {
-        let input = match setup().await {
-            Ok(input) => input,
-            Err(error) => {
+    audited(name, hooks, async move {
+        let (warmup, iterations, timeout) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            let input = match setup().await {
+                Ok(input) => input,
+                Err(error) => {
+                    return CaseExecutionResult::Failure(failure_case_result(
+                        name,
+                        Vec::new(),
+                        format!(
+                            "warmup setup iteration {} failed: {}",
+                            warmup_idx + 1,
+                            error.to_string()
+                        ),
+                    ))
+                }
+            };
+            if let Err(error) = within_timeout(timeout, op(input)).await {
                 return CaseExecutionResult::Failure(failure_case_result(
                     name,
                     Vec::new(),
-                    format!(
-                        "warmup setup iteration {} failed: {}",
-                        warmup_idx + 1,
-                        error.to_string()
-                    ),
-                ))
+                    format!("warmup iteration {} failed: {error}", warmup_idx + 1),
+                ));
             }
-        };
-        if let Err(error) = op(input).await {
-            return CaseExecutionResult::Failure(failure_case_result(
-                name,
-                Vec::new(),
-                format!(
-                    "warmup iteration {} failed: {}",
-                    warmup_idx + 1,
-                    error.to_string()
-                ),
-            ));
         }
-    }
 
-    let mut samples = Vec::new();
-    for _ in 0..iterations {
-        let input = match setup().await {
-            Ok(input) => input,
-            Err(e) => {
-                return CaseExecutionResult::Failure(failure_case_result(
-                    name,
-                    samples,
-                    e.to_string(),
-                ))
-            }
-        };
-
-        let start = Instant::now();
-        match op(input).await {
-            Ok((metrics, elapsed_ms_override)) => {
-                append_sample(&mut samples, start.elapsed(), metrics, elapsed_ms_override);
-            }
-            Err(e) => {
-                return CaseExecutionResult::Failure(failure_case_result(
-                    name,
-                    samples,
-                    e.to_string(),
-                ))
+        let mut samples = Vec::new();
+        for _ in 0..iterations {
+            let span = iteration_span(samples.len());
+            let setup_start = Instant::now();
+            let input = match setup().instrument(span.clone()).await {
+                Ok(input) => input,
+                Err(e) => {
+                    return CaseExecutionResult::Failure(failure_case_result(
+                        name,
+                        samples,
+                        e.to_string(),
+                    ))
+                }
+            };
+            let setup_elapsed = setup_start.elapsed();
+
+            let start = Instant::now();
+            match within_timeout(timeout, op(input).instrument(span.clone())).await {
+                Ok((metrics, elapsed_ms_override)) => {
+                    append_sample(
+                        &mut samples,
+                        start.elapsed(),
+                        metrics,
+                        elapsed_ms_override,
+                        Some(setup_elapsed),
+                    );
+                    finish_iteration(&span, hooks, name, &samples, iterations);
+                }
+                Err(e) => {
+                    return CaseExecutionResult::Failure(failure_case_result(name, samples, e))
+                }
             }
         }
-    }
 
-    CaseExecutionResult::Success(success_case_result(name, samples))
+        CaseExecutionResult::Success(success_case_result(name, samples))
+    })
+    .await
+}
+
+/// The span of the measured iteration that will append sample `index`, setup included.
+fn iteration_span(index: usize) -> Span {
+    info_span!("iteration", iteration = index + 1, elapsed_ms = Empty)
+}
+
+fn finish_iteration(
+    span: &Span,
+    hooks: &CaseHooks,
+    name: &str,
+    samples: &[IterationSample],
+    iterations: u32,
+) {
+    if let Some(sample) = samples.last() {
+        span.record("elapsed_ms", sample.elapsed_ms);
+    }
+    report_iteration(hooks, name, samples, iterations);
+    keep_iteration_plan_metrics(hooks);
 }
 
 fn append_sample<M>(
@@ -484,10 +594,14 @@ fn append_sample<M>(
     elapsed: Duration,
     metrics: M,
     elapsed_ms_override: Option<f64>,
+    setup_elapsed: Option<Duration>,
 ) where
     M: Into<SampleMetrics>,
 {
-    let metrics = metrics.into();
+    let mut metrics = metrics.into();
+    if let Some(setup_elapsed) = setup_elapsed {
+        metrics.setup_ms = Some(setup_elapsed.as_secs_f64() * 1000.0);
+    }
     samples.push(IterationSample {
         elapsed_ms: elapsed_ms_override.unwrap_or(elapsed.as_secs_f64() * 1000.0),
         rows: metrics.rows_processed,
@@ -496,7 +610,7 @@ fn append_sample<M>(
     });
 }
 
-fn success_case_result(name: &str, samples: Vec<IterationSample>) -> CaseResult {
+pub(crate) fn success_case_result(name: &str, samples: Vec<IterationSample>) -> CaseResult {
     let run_summary = build_run_summary(&samples, None, None);
     CaseResult {
         case: name.to_string(),
@@ -515,12 +629,24 @@ fn success_case_result(name: &str, samples: Vec<IterationSample>) -> CaseResult
         decision_threshold_pct: None,
         decision_metric: None,
         samples,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: None,
         failure: None,
     }
 }
 
-fn failure_case_result(name: &str, samples: Vec<IterationSample>, message: String) -> CaseResult {
+pub(crate) fn failure_case_result(
+    name: &str,
+    samples: Vec<IterationSample>,
+    message: String,
+) -> CaseResult {
     CaseResult {
         case: name.to_string(),
         success: false,
@@ -538,12 +664,20 @@ fn failure_case_result(name: &str, samples: Vec<IterationSample>, message: Strin
         decision_threshold_pct: None,
         decision_metric: None,
         samples,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: Some(FAILURE_KIND_EXECUTION_ERROR.to_string()),
         failure: Some(CaseFailure { message }),
     }
 }
 
-fn unsupported_case_result(
+pub(crate) fn unsupported_case_result(
     name: &str,
     samples: Vec<IterationSample>,
     message: String,
@@ -565,6 +699,14 @@ fn unsupported_case_result(
         decision_threshold_pct: None,
         decision_metric: None,
         samples,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: Some(FAILURE_KIND_UNSUPPORTED.to_string()),
         failure: Some(CaseFailure { message }),
     }
@@ -0,0 +1,172 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use futures::stream::{FuturesUnordered, StreamExt};
+
+use super::audit::audited;
+use super::{failure_case_result, success_case_result, CaseExecutionResult, CaseHooks};
+use crate::error::{BenchError, BenchResult};
+use crate::results::{IterationSample, OpenLoopMetrics, SampleMetrics};
+use crate::stats::nearest_rank;
+
+/// Issues operations on a fixed schedule for `duration`, whether or not earlier operations
+/// have completed. Latency is measured from each operation's scheduled start, so time spent
+/// queued behind slow operations counts against it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OpenLoopConfig {
+    pub duration: Duration,
+    pub target_ops_per_sec: f64,
+}
+
+impl OpenLoopConfig {
+    pub fn new(duration: Duration, target_ops_per_sec: f64) -> BenchResult<Self> {
+        if duration.is_zero() {
+            return Err(BenchError::InvalidArgument(
+                "open-loop load duration must be greater than zero".to_string(),
+            ));
+        }
+        if !target_ops_per_sec.is_finite() || target_ops_per_sec <= 0.0 {
+            return Err(BenchError::InvalidArgument(format!(
+                "open-loop target rate must be a positive number of ops/sec (found {target_ops_per_sec})"
+            )));
+        }
+        Ok(Self {
+            duration,
+            target_ops_per_sec,
+        })
+    }
+
+    fn interval(self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.target_ops_per_sec)
+    }
+}
+
+/// Open-loop counterpart of [`super::run_case_async`]. Warmup operations run back to back;
+/// each measured iteration is one load window and becomes one sample whose `elapsed_ms` is the
+/// median operation latency. The sample keeps the last completed operation's metrics and adds
+/// [`OpenLoopMetrics`]. Any failed operation fails the case. A case budget's warmup and
+/// iteration counts apply; its timeout does not, since each window already has a fixed length.
+pub async fn run_case_open_loop<F, Fut, M, E>(
+    name: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    config: OpenLoopConfig,
+    mut op: F,
+) -> CaseExecutionResult
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<M, E>>,
+    M: Into<SampleMetrics>,
+    E: ToString,
+{
+    audited(name, hooks, async move {
+        let (warmup, iterations, _) = hooks.case_budget(name, warmup, iterations);
+        for warmup_idx in 0..warmup {
+            if let Err(error) = op().await {
+                return CaseExecutionResult::Failure(failure_case_result(
+                    name,
+                    Vec::new(),
+                    format!(
+                        "warmup iteration {} failed: {}",
+                        warmup_idx + 1,
+                        error.to_string()
+                    ),
+                ));
+            }
+        }
+
+        let mut samples = Vec::new();
+        for window in 0..iterations {
+            match run_window(config, &mut op).await {
+                Ok(sample) => samples.push(sample),
+                Err(message) => {
+                    return CaseExecutionResult::Failure(failure_case_result(
+                        name,
+                        samples,
+                        format!("load window {} failed: {message}", window + 1),
+                    ))
+                }
+            }
+        }
+
+        CaseExecutionResult::Success(success_case_result(name, samples))
+    })
+    .await
+}
+
+async fn run_window<F, Fut, M, E>(
+    config: OpenLoopConfig,
+    op: &mut F,
+) -> Result<IterationSample, String>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<M, E>>,
+    M: Into<SampleMetrics>,
+    E: ToString,
+{
+    let interval = config.interval();
+    let start = Instant::now();
+    let deadline = start + config.duration;
+    let mut next_start = start;
+    let mut ops_issued = 0_u64;
+    let mut max_in_flight = 0_u64;
+    let mut latencies_ms = Vec::new();
+    let mut last_metrics = None;
+    let mut failures = Vec::new();
+    let mut in_flight = FuturesUnordered::new();
+
+    loop {
+        let issuing = next_start < deadline;
+        if !issuing && in_flight.is_empty() {
+            break;
+        }
+        tokio::select! {
+            _ = tokio::time::sleep_until(next_start.into()), if issuing => {
+                let scheduled = next_start;
+                let operation = op();
+                in_flight.push(async move { (scheduled, operation.await) });
+                ops_issued += 1;
+                max_in_flight = max_in_flight.max(in_flight.len() as u64);
+                next_start += interval;
+            }
+            Some((scheduled, outcome)) = in_flight.next() => match outcome {
+                Ok(metrics) => {
+                    latencies_ms.push(scheduled.elapsed().as_secs_f64() * 1000.0);
+                    last_metrics = Some(metrics);
+                }
+                Err(error) => failures.push(error.to_string()),
+            },
+        }
+    }
+
+    if let Some(first) = failures.first() {
+        return Err(format!(
+            "{} of {ops_issued} operations failed; first error: {first}",
+            failures.len()
+        ));
+    }
+    let wall_ms = start.elapsed().as_secs_f64() * 1000.0;
+    latencies_ms.sort_by(|left, right| left.total_cmp(right));
+    let open_loop = OpenLoopMetrics {
+        target_ops_per_sec: config.target_ops_per_sec,
+        achieved_ops_per_sec: latencies_ms.len() as f64 / (wall_ms / 1000.0),
+        duration_ms: config.duration.as_millis() as u64,
+        ops_completed: latencies_ms.len() as u64,
+        max_in_flight,
+        latency_p50_ms: nearest_rank(&latencies_ms, 50),
+        latency_p95_ms: nearest_rank(&latencies_ms, 95),
+        latency_p99_ms: nearest_rank(&latencies_ms, 99),
+        latency_max_ms: latencies_ms.last().copied().unwrap_or_default(),
+    };
+    let metrics = last_metrics
+        .map(Into::into)
+        .unwrap_or_else(|| SampleMetrics::base(None, None, None, None))
+        .with_open_loop(open_loop.clone());
+    Ok(IterationSample {
+        elapsed_ms: open_loop.latency_p50_ms,
+        rows: metrics.rows_processed,
+        bytes: metrics.bytes_processed,
+        metrics: Some(metrics),
+    })
+}
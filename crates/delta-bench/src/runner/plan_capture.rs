@@ -0,0 +1,159 @@
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::MutexGuard;
+
+use deltalake_core::datafusion::physical_plan::{displayable, ExecutionPlan};
+use serde_json::{json, Map, Value};
+
+use super::{CaseExecutionResult, CaseHooks};
+use crate::cli::PlanFormat;
+use crate::error::BenchResult;
+use crate::results::{BenchRunResult, CapturedPlan};
+
+/// What the running case has recorded so far; cases run one at a time, so one capture serves
+/// every case run with the same [`CaseHooks`].
+#[derive(Default)]
+pub(super) struct CaseCapture {
+    plan: Option<CapturedPlan>,
+    /// Metrics of the iteration in flight, kept only if it turns out to be a measured one.
+    pending_metrics: Option<Value>,
+    plan_metrics: Vec<Value>,
+}
+
+/// Renders `plan` as `format`. Metrics are left out, so plans from different runs only differ
+/// where the planner chose differently.
+pub fn render_physical_plan(plan: &dyn ExecutionPlan, format: PlanFormat) -> CapturedPlan {
+    match format {
+        PlanFormat::Text => CapturedPlan::Text(displayable(plan).indent(false).to_string()),
+        PlanFormat::Json => CapturedPlan::Json(plan_json(plan)),
+    }
+}
+
+/// Every metric of every operator in an executed `plan`, per partition, nested like the plan.
+pub fn plan_metrics_json(plan: &dyn ExecutionPlan) -> Value {
+    let metrics = plan
+        .metrics()
+        .map(|metrics| {
+            metrics
+                .iter()
+                .map(|metric| {
+                    let labels = metric
+                        .labels()
+                        .iter()
+                        .map(|label| (label.name().to_string(), json!(label.value())))
+                        .collect::<Map<_, _>>();
+                    json!({
+                        "name": metric.value().name(),
+                        "value": metric.value().as_usize(),
+                        "display": metric.value().to_string(),
+                        "partition": metric.partition(),
+                        "labels": labels,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    json!({
+        "operator": plan.name(),
+        "metrics": metrics,
+        "children": plan
+            .children()
+            .into_iter()
+            .map(|child| plan_metrics_json(child.as_ref()))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Keeps `plan` for the running case, replacing the previous iteration's; a no-op unless plans
+/// are being captured.
+pub(crate) fn record_physical_plan(hooks: &CaseHooks, plan: &dyn ExecutionPlan) {
+    if let Some(format) = hooks.plan_format {
+        case_capture(hooks).plan = Some(render_physical_plan(plan, format));
+    }
+}
+
+/// Keeps the metrics of the executed `plan` for the running iteration; a no-op unless full
+/// plan metrics are being captured.
+pub(crate) fn record_plan_metrics(hooks: &CaseHooks, plan: &dyn ExecutionPlan) {
+    if hooks.full_plan_metrics {
+        case_capture(hooks).pending_metrics = Some(plan_metrics_json(plan));
+    }
+}
+
+/// Moves the metrics recorded since the last measured iteration into the case's list, once
+/// the runner knows the iteration was measured.
+pub(super) fn keep_iteration_plan_metrics(hooks: &CaseHooks) {
+    let mut capture = case_capture(hooks);
+    if let Some(metrics) = capture.pending_metrics.take() {
+        capture.plan_metrics.push(metrics);
+    }
+}
+
+/// Runs one case and attaches the last plan and the measured iterations' metrics it recorded
+/// to its result.
+pub(super) async fn capturing_plan<Fut>(hooks: &CaseHooks, fut: Fut) -> CaseExecutionResult
+where
+    Fut: Future<Output = CaseExecutionResult>,
+{
+    if hooks.plan_format.is_none() && !hooks.full_plan_metrics {
+        return fut.await;
+    }
+    *case_capture(hooks) = CaseCapture::default();
+    let mut result = fut.await;
+    let capture = std::mem::take(&mut *case_capture(hooks));
+    match &mut result {
+        CaseExecutionResult::Success(case) | CaseExecutionResult::Failure(case) => {
+            case.physical_plan = capture.plan;
+            case.plan_metrics = capture.plan_metrics;
+        }
+    }
+    result
+}
+
+/// Writes each case's captured metrics to `<out_dir>/<target>.plan_metrics/<case>.json` and
+/// points its `plan_metrics_file` there, relative to `out_dir`. Returns the files written.
+pub fn write_plan_metrics_files(
+    result: &mut BenchRunResult,
+    out_dir: &Path,
+    target: &str,
+) -> BenchResult<Vec<PathBuf>> {
+    let dir_name = format!("{target}.plan_metrics");
+    let mut written = Vec::new();
+    for case in result
+        .cases
+        .iter_mut()
+        .filter(|case| !case.plan_metrics.is_empty())
+    {
+        fs::create_dir_all(out_dir.join(&dir_name))?;
+        let file_name = format!("{dir_name}/{}.json", case.case);
+        let path = out_dir.join(&file_name);
+        let dump = json!({
+            "case": case.case,
+            "iterations": std::mem::take(&mut case.plan_metrics),
+        });
+        fs::write(&path, serde_json::to_vec_pretty(&dump)?)?;
+        case.plan_metrics_file = Some(file_name);
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn case_capture(hooks: &CaseHooks) -> MutexGuard<'_, CaseCapture> {
+    hooks
+        .capture
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn plan_json(plan: &dyn ExecutionPlan) -> Value {
+    json!({
+        "operator": plan.name(),
+        "description": displayable(plan).one_line().to_string().trim_end(),
+        "children": plan
+            .children()
+            .into_iter()
+            .map(|child| plan_json(child.as_ref()))
+            .collect::<Vec<_>>(),
+    })
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::BenchResult;
+use crate::results::BenchRunResult;
+
+#[cfg(feature = "profiling")]
+pub use sampling::PROFILE_FREQUENCY_HZ;
+#[cfg(feature = "profiling")]
+pub(super) use sampling::{profiling, profiling_sync};
+
+/// Writes each case's flamegraph to `<out_dir>/<target>.profiles/<case>.svg` and points its
+/// `profile_file` there, relative to `out_dir`. Returns the files written.
+pub fn write_flamegraphs(
+    result: &mut BenchRunResult,
+    out_dir: &Path,
+    target: &str,
+) -> BenchResult<Vec<PathBuf>> {
+    let dir_name = format!("{target}.profiles");
+    let mut written = Vec::new();
+    for case in &mut result.cases {
+        let Some(svg) = case.flamegraph.take() else {
+            continue;
+        };
+        fs::create_dir_all(out_dir.join(&dir_name))?;
+        let file_name = format!("{dir_name}/{}.svg", case.case);
+        let path = out_dir.join(&file_name);
+        fs::write(&path, svg)?;
+        case.profile_file = Some(file_name);
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(feature = "profiling")]
+mod sampling {
+    use std::future::Future;
+
+    use pprof::flamegraph::Options;
+    use pprof::{ProfilerGuard, ProfilerGuardBuilder};
+    use tracing::warn;
+
+    use super::super::{CaseExecutionResult, CaseHooks};
+
+    /// Samples per second. Odd, so sampling does not fall into step with periodic work.
+    pub const PROFILE_FREQUENCY_HZ: i32 = 999;
+
+    /// Runs one async case under the profiler when profiling is on.
+    pub(in crate::runner) async fn profiling<Fut>(
+        name: &str,
+        hooks: &CaseHooks,
+        fut: Fut,
+    ) -> CaseExecutionResult
+    where
+        Fut: Future<Output = CaseExecutionResult>,
+    {
+        let Some(guard) = start_profiler(name, hooks) else {
+            return fut.await;
+        };
+        let mut result = fut.await;
+        attach_flamegraph(name, &guard, &mut result);
+        result
+    }
+
+    /// Runs one sync case under the profiler when profiling is on.
+    pub(in crate::runner) fn profiling_sync<F>(
+        name: &str,
+        hooks: &CaseHooks,
+        op: F,
+    ) -> CaseExecutionResult
+    where
+        F: FnOnce() -> CaseExecutionResult,
+    {
+        let Some(guard) = start_profiler(name, hooks) else {
+            return op();
+        };
+        let mut result = op();
+        attach_flamegraph(name, &guard, &mut result);
+        result
+    }
+
+    /// A profiler that fails to start leaves the case unprofiled rather than failed.
+    fn start_profiler(name: &str, hooks: &CaseHooks) -> Option<ProfilerGuard<'static>> {
+        if !hooks.profile {
+            return None;
+        }
+        ProfilerGuardBuilder::default()
+            .frequency(PROFILE_FREQUENCY_HZ)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .map_err(|error| warn!(case = name, %error, "failed to profile case"))
+            .ok()
+    }
+
+    fn attach_flamegraph(name: &str, guard: &ProfilerGuard<'_>, result: &mut CaseExecutionResult) {
+        let mut options = Options::default();
+        options.title = name.to_string();
+        let mut svg = Vec::new();
+        let rendered = guard
+            .report()
+            .build()
+            .and_then(|report| report.flamegraph_with_options(&mut svg, &mut options));
+        if let Err(error) = rendered {
+            warn!(case = name, %error, "failed to render flamegraph");
+            return;
+        }
+        match result {
+            CaseExecutionResult::Success(case) | CaseExecutionResult::Failure(case) => {
+                case.flamegraph = Some(svg);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use crate::results::{CaseResult, IterationSample};
+
+use super::audit::case_outcome;
+use super::CaseHooks;
+
+/// One step of a running case, delivered while the run is still going.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProgressEvent {
+    CaseStarted {
+        case: String,
+    },
+    /// A measured iteration finished; warmup iterations are not reported.
+    IterationFinished {
+        case: String,
+        iteration: u32,
+        iterations: u32,
+        elapsed_ms: f64,
+    },
+    /// `outcome` is `ok`, `failed`, or `unsupported`, as in the audit log. `elapsed_ms` is
+    /// `None` for cases a suite failed without running them.
+    CaseFinished {
+        case: String,
+        outcome: &'static str,
+        elapsed_ms: Option<f64>,
+    },
+}
+
+pub type ProgressCallback = Arc<dyn Fn(&ProgressEvent) + Send + Sync>;
+
+/// Delivers the progress of every case run with the [`CaseHooks`] holding it to one callback.
+pub(super) struct ProgressReporter {
+    callback: ProgressCallback,
+    finished: Mutex<HashSet<String>>,
+}
+
+impl ProgressReporter {
+    pub(super) fn new(callback: ProgressCallback) -> Self {
+        Self {
+            callback,
+            finished: Mutex::new(HashSet::new()),
+        }
+    }
+
+    fn finish(&self, case: &CaseResult, elapsed_ms: Option<f64>) {
+        (self.callback)(&ProgressEvent::CaseFinished {
+            case: case.case.clone(),
+            outcome: case_outcome(case),
+            elapsed_ms,
+        });
+        self.finished
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(case.case.clone());
+    }
+}
+
+/// The one-line form `delta-bench run --progress` prints to stderr.
+pub fn render_progress_event(event: &ProgressEvent) -> String {
+    match event {
+        ProgressEvent::CaseStarted { case } => format!("[progress] {case}: started"),
+        ProgressEvent::IterationFinished {
+            case,
+            iteration,
+            iterations,
+            elapsed_ms,
+        } => format!("[progress] {case}: iteration {iteration}/{iterations} {elapsed_ms:.1} ms"),
+        ProgressEvent::CaseFinished {
+            case,
+            outcome,
+            elapsed_ms: Some(elapsed_ms),
+        } => format!("[progress] {case}: {outcome} in {elapsed_ms:.1} ms"),
+        ProgressEvent::CaseFinished {
+            case,
+            outcome,
+            elapsed_ms: None,
+        } => format!("[progress] {case}: {outcome}"),
+    }
+}
+
+pub(super) fn report_case_started(hooks: &CaseHooks, name: &str) {
+    if let Some(reporter) = &hooks.progress {
+        (reporter.callback)(&ProgressEvent::CaseStarted {
+            case: name.to_string(),
+        });
+    }
+}
+
+/// Reports the sample just appended to `samples`.
+pub(super) fn report_iteration(
+    hooks: &CaseHooks,
+    name: &str,
+    samples: &[IterationSample],
+    iterations: u32,
+) {
+    let (Some(reporter), Some(sample)) = (&hooks.progress, samples.last()) else {
+        return;
+    };
+    (reporter.callback)(&ProgressEvent::IterationFinished {
+        case: name.to_string(),
+        iteration: samples.len() as u32,
+        iterations,
+        elapsed_ms: sample.elapsed_ms,
+    });
+}
+
+pub(super) fn report_case_finished(hooks: &CaseHooks, case: &CaseResult, elapsed_ms: f64) {
+    if let Some(reporter) = &hooks.progress {
+        reporter.finish(case, Some(elapsed_ms));
+    }
+}
+
+/// Reports a finish for each of `cases` that did not run through the shared case runners, and
+/// starts the next target with a clean slate; the progress counterpart of
+/// `record_unaudited_cases`.
+pub(crate) fn report_unreported_cases(hooks: &CaseHooks, cases: &[CaseResult]) {
+    let Some(reporter) = &hooks.progress else {
+        return;
+    };
+    let finished = std::mem::take(
+        &mut *reporter
+            .finished
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+    for case in cases.iter().filter(|case| !finished.contains(&case.case)) {
+        reporter.finish(case, None);
+    }
+    reporter
+        .finished
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
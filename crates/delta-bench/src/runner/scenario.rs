@@ -0,0 +1,157 @@
+use std::future::Future;
+use std::time::Instant;
+
+use super::{append_sample, failure_case_result, success_case_result, CaseExecutionResult};
+use crate::results::{IterationSample, SampleMetrics};
+use crate::suites::dependency_failed_case;
+
+/// One case of a scenario. Each step applies `operation` to the table the previous step left
+/// behind and must commit exactly `commits` new versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScenarioStep<Op> {
+    pub name: &'static str,
+    pub commits: u64,
+    pub operation: Op,
+}
+
+/// Runs a group of cases against one evolving table. Every pass sets the table up once, runs
+/// the steps in order with each step handed the state the previous one returned, and tears
+/// it down by dropping the final state. Only the steps are timed, one sample per step per
+/// measured pass.
+///
+/// `setup` returns the state and the table version it starts at. Each step's sample must
+/// report `table_version`, and it must equal the version before the step plus the step's
+/// `commits`, so a step that commits too little or too much fails where it happened rather
+/// than in a later step's result. After a failing step, the steps that follow are reported
+/// as skipped because they have no table state to start from.
+pub async fn run_scenario<Op, S, SetupF, SetupFut, F, Fut, M, E>(
+    steps: &[ScenarioStep<Op>],
+    warmup: u32,
+    iterations: u32,
+    mut setup: SetupF,
+    mut step: F,
+) -> Vec<CaseExecutionResult>
+where
+    Op: Copy,
+    SetupF: FnMut() -> SetupFut,
+    SetupFut: Future<Output = Result<(S, u64), E>>,
+    F: FnMut(ScenarioStep<Op>, S) -> Fut,
+    Fut: Future<Output = Result<(S, M), E>>,
+    M: Into<SampleMetrics>,
+    E: ToString,
+{
+    let mut samples = steps.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    for pass in 0..warmup.saturating_add(iterations) {
+        let measured = pass >= warmup;
+        let outcome = run_scenario_pass(steps, &mut setup, &mut step, &mut samples, measured).await;
+        if let Err((failed, message)) = outcome {
+            let message = if measured {
+                message
+            } else {
+                format!("warmup iteration {} failed: {message}", pass + 1)
+            };
+            return scenario_failure(steps, samples, failed, message, measured);
+        }
+    }
+
+    steps
+        .iter()
+        .zip(samples)
+        .map(|(step, samples)| {
+            CaseExecutionResult::Success(success_case_result(step.name, samples))
+        })
+        .collect()
+}
+
+/// Runs every step once on a freshly set-up table. On failure, returns the index of the step
+/// that failed and why; a setup failure is charged to the first step.
+async fn run_scenario_pass<Op, S, SetupF, SetupFut, F, Fut, M, E>(
+    steps: &[ScenarioStep<Op>],
+    setup: &mut SetupF,
+    step: &mut F,
+    samples: &mut [Vec<IterationSample>],
+    measured: bool,
+) -> Result<(), (usize, String)>
+where
+    Op: Copy,
+    SetupF: FnMut() -> SetupFut,
+    SetupFut: Future<Output = Result<(S, u64), E>>,
+    F: FnMut(ScenarioStep<Op>, S) -> Fut,
+    Fut: Future<Output = Result<(S, M), E>>,
+    M: Into<SampleMetrics>,
+    E: ToString,
+{
+    let (mut state, mut version) = setup()
+        .await
+        .map_err(|e| (0, format!("scenario setup failed: {}", e.to_string())))?;
+
+    for (index, scenario_step) in steps.iter().enumerate() {
+        let start = Instant::now();
+        let (next_state, metrics) = step(*scenario_step, state)
+            .await
+            .map_err(|e| (index, e.to_string()))?;
+        let elapsed = start.elapsed();
+        let metrics = metrics.into();
+
+        let expected = version + scenario_step.commits;
+        match metrics.table_version {
+            Some(actual) if actual == expected => {}
+            Some(actual) => {
+                return Err((
+                    index,
+                    format!(
+                        "expected table version {expected} after {} ({version} + {} commits), found {actual}",
+                        scenario_step.name, scenario_step.commits
+                    ),
+                ))
+            }
+            None => {
+                return Err((
+                    index,
+                    format!("{} did not report a table version", scenario_step.name),
+                ))
+            }
+        }
+
+        if measured {
+            append_sample(&mut samples[index], elapsed, metrics, None, None);
+        }
+        state = next_state;
+        version = expected;
+    }
+    Ok(())
+}
+
+fn scenario_failure<Op>(
+    steps: &[ScenarioStep<Op>],
+    samples: Vec<Vec<IterationSample>>,
+    failed: usize,
+    message: String,
+    measured: bool,
+) -> Vec<CaseExecutionResult> {
+    let failed_name = steps[failed].name;
+    steps
+        .iter()
+        .zip(samples)
+        .enumerate()
+        .map(|(index, (step, samples))| {
+            if index == failed {
+                CaseExecutionResult::Failure(failure_case_result(
+                    step.name,
+                    samples,
+                    message.clone(),
+                ))
+            } else if index > failed {
+                CaseExecutionResult::Failure(dependency_failed_case(step.name, failed_name))
+            } else if measured {
+                CaseExecutionResult::Success(success_case_result(step.name, samples))
+            } else {
+                CaseExecutionResult::Failure(failure_case_result(
+                    step.name,
+                    samples,
+                    format!("not measured: '{failed_name}' failed during warmup"),
+                ))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use sha2::{Digest, Sha256};
+
+/// Run seed used when `bench run --seed` is not given.
+pub const DEFAULT_RUN_SEED: u64 = 42;
+
+/// Per-case seeds for every source of randomness a case draws from while it runs (kill delays,
+/// sampled sources, shuffled orders). Each case derives its own seed from the run seed and its
+/// id, so adding or filtering cases never shifts another case's workload. A replay pins one case
+/// to the exact seed recorded in an earlier result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaseSeeds {
+    run_seed: u64,
+    replay: Option<(String, u64)>,
+}
+
+impl Default for CaseSeeds {
+    fn default() -> Self {
+        Self::new(DEFAULT_RUN_SEED)
+    }
+}
+
+impl CaseSeeds {
+    pub fn new(run_seed: u64) -> Self {
+        Self {
+            run_seed,
+            replay: None,
+        }
+    }
+
+    /// Seeds for replaying `case` with the `seed` recorded in its result.
+    pub fn replay(case: impl Into<String>, seed: u64) -> Self {
+        Self {
+            run_seed: DEFAULT_RUN_SEED,
+            replay: Some((case.into(), seed)),
+        }
+    }
+
+    pub fn for_case(&self, case: &str) -> u64 {
+        match &self.replay {
+            Some((replay_case, seed)) if replay_case == case => *seed,
+            _ => {
+                let mut hasher = Sha256::new();
+                hasher.update(self.run_seed.to_le_bytes());
+                hasher.update(case.as_bytes());
+                let digest = hasher.finalize();
+                let mut bytes = [0_u8; 8];
+                bytes.copy_from_slice(&digest[..8]);
+                u64::from_le_bytes(bytes)
+            }
+        }
+    }
+}
@@ -14,7 +14,7 @@ use crate::data::fixtures::narrow_sales_table_url;
 use crate::error::BenchResult;
 use crate::fingerprint::{hash_arrow_schema, hash_record_batches_unordered};
 use crate::replay_snapshot::clone_plain_snapshot_from_loaded_table;
-use crate::results::{RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
+use crate::results::{RuntimeIOMetrics, SampleMetrics};
 use crate::storage::StorageConfig;
 use crate::suites::scan_metrics::extract_scan_metrics;
 use crate::version_compat::snapshot_version_arg;
@@ -190,32 +190,14 @@ async fn validate_executed_query(
         .iter()
         .map(|b| b.num_rows() as u64)
         .sum::<u64>();
-    let scan_metrics = extract_scan_metrics(&executed.plan);
-    let files_scanned = scan_metrics.files_scanned.or_else(|| {
-        executed.total_active_files.and_then(|total| {
-            scan_metrics
-                .files_pruned
-                .and_then(|pruned| total.checked_sub(pruned))
-        })
-    });
-    let files_pruned = scan_metrics.files_pruned.or_else(|| {
-        executed
-            .total_active_files
-            .and_then(|total| files_scanned.and_then(|scanned| total.checked_sub(scanned)))
-    });
+    let scan_metrics = extract_scan_metrics(&executed.plan, executed.total_active_files);
     let result_hash = hash_record_batches_unordered(&executed.batches)?;
     let schema_hash = hash_arrow_schema(executed.plan.schema().as_ref())?;
     let validate_elapsed_ms = validate_start.elapsed().as_secs_f64() * 1000.0;
 
     Ok((
-        SampleMetrics::base(Some(rows_processed), None, None, None)
-            .with_scan_rewrite(ScanRewriteMetrics {
-                files_scanned,
-                files_pruned,
-                bytes_scanned: scan_metrics.bytes_scanned,
-                scan_time_ms: scan_metrics.scan_time_ms,
-                rewrite_time_ms: None,
-            })
+        scan_metrics
+            .apply(SampleMetrics::base(Some(rows_processed), None, None, None))
             .with_runtime_io(RuntimeIOMetrics {
                 peak_rss_mb: None,
                 cpu_time_ms: None,
@@ -0,0 +1,237 @@
+use std::path::Path;
+use std::process::Command;
+
+use serde_json::Value;
+
+use crate::error::{BenchError, BenchResult};
+
+pub const SECRET_REFERENCE_PREFIX: &str = "secret://";
+pub const AWS_CLI_ENV: &str = "DELTA_BENCH_AWS_CLI";
+pub const SOPS_BIN_ENV: &str = "DELTA_BENCH_SOPS";
+
+/// Where a `secret://<provider>/<key>` reference is resolved from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretReference {
+    /// `secret://env/NAME` reads the `NAME` environment variable.
+    Env { name: String },
+    /// `secret://aws-sm/<secret-id>[#field]` reads a Secrets Manager secret string,
+    /// optionally picking one field of a JSON secret.
+    AwsSecretsManager {
+        secret_id: String,
+        field: Option<String>,
+    },
+    /// `secret://sops/<file>#<key>` decrypts one top-level key of a sops file.
+    Sops { file: String, key: String },
+}
+
+pub fn is_secret_reference(value: &str) -> bool {
+    value.starts_with(SECRET_REFERENCE_PREFIX)
+}
+
+pub fn parse_secret_reference(reference: &str) -> BenchResult<SecretReference> {
+    let Some(rest) = reference.strip_prefix(SECRET_REFERENCE_PREFIX) else {
+        return Err(BenchError::InvalidArgument(format!(
+            "'{reference}' is not a secret reference; expected {SECRET_REFERENCE_PREFIX}<provider>/<key>"
+        )));
+    };
+    let Some((provider, key)) = rest.split_once('/').filter(|(_, key)| !key.is_empty()) else {
+        return Err(BenchError::InvalidArgument(format!(
+            "invalid secret reference '{reference}'; expected {SECRET_REFERENCE_PREFIX}<provider>/<key>"
+        )));
+    };
+    match provider {
+        "env" => Ok(SecretReference::Env {
+            name: key.to_string(),
+        }),
+        "aws-sm" => {
+            let (secret_id, field) = match key.split_once('#') {
+                Some((secret_id, field)) => (secret_id, Some(field)),
+                None => (key, None),
+            };
+            if secret_id.is_empty() || field.is_some_and(str::is_empty) {
+                return Err(BenchError::InvalidArgument(format!(
+                    "invalid secret reference '{reference}'; expected {SECRET_REFERENCE_PREFIX}aws-sm/<secret-id>[#field]"
+                )));
+            }
+            Ok(SecretReference::AwsSecretsManager {
+                secret_id: secret_id.to_string(),
+                field: field.map(str::to_string),
+            })
+        }
+        "sops" => match key.split_once('#') {
+            Some((file, field)) if !file.is_empty() && !field.is_empty() => {
+                Ok(SecretReference::Sops {
+                    file: file.to_string(),
+                    key: field.to_string(),
+                })
+            }
+            _ => Err(BenchError::InvalidArgument(format!(
+                "invalid secret reference '{reference}'; expected {SECRET_REFERENCE_PREFIX}sops/<file>#<key>"
+            ))),
+        },
+        other => Err(BenchError::InvalidArgument(format!(
+            "unknown secret provider '{other}' in '{reference}'; expected one of: env, aws-sm, sops"
+        ))),
+    }
+}
+
+/// Resolves a secret reference. Relative sops paths are taken from `root`.
+///
+/// Errors name the reference but never include resolved values or provider output.
+pub fn resolve_secret_reference(reference: &str, root: &Path) -> BenchResult<String> {
+    let value = match parse_secret_reference(reference)? {
+        SecretReference::Env { name } => std::env::var(&name).map_err(|_| {
+            BenchError::InvalidArgument(format!(
+                "secret reference '{reference}' requires environment variable '{name}' to be set"
+            ))
+        })?,
+        SecretReference::AwsSecretsManager { secret_id, field } => {
+            let aws = std::env::var(AWS_CLI_ENV).unwrap_or_else(|_| "aws".to_string());
+            let secret = run_secret_command(
+                reference,
+                Command::new(&aws)
+                    .args(["secretsmanager", "get-secret-value", "--secret-id"])
+                    .arg(&secret_id)
+                    .args(["--query", "SecretString", "--output", "text"]),
+                &aws,
+            )?;
+            match field {
+                Some(field) => json_secret_field(reference, &secret, &field)?,
+                None => secret,
+            }
+        }
+        SecretReference::Sops { file, key } => {
+            let sops = std::env::var(SOPS_BIN_ENV).unwrap_or_else(|_| "sops".to_string());
+            let file = root.join(file);
+            if !file.exists() {
+                return Err(BenchError::InvalidArgument(format!(
+                    "secret reference '{reference}' points at a missing sops file: {}",
+                    file.display()
+                )));
+            }
+            let extract = serde_json::to_string(&[key.as_str()])?;
+            run_secret_command(
+                reference,
+                Command::new(&sops)
+                    .args(["--decrypt", "--extract"])
+                    .arg(extract)
+                    .arg(&file),
+                &sops,
+            )?
+        }
+    };
+    if value.is_empty() {
+        return Err(BenchError::InvalidArgument(format!(
+            "secret reference '{reference}' resolved to an empty value"
+        )));
+    }
+    Ok(value)
+}
+
+fn run_secret_command(
+    reference: &str,
+    command: &mut Command,
+    program: &str,
+) -> BenchResult<String> {
+    let output = command.output().map_err(|error| {
+        BenchError::InvalidArgument(format!(
+            "failed to execute '{program}' for secret reference '{reference}': {error}"
+        ))
+    })?;
+    if !output.status.success() {
+        // stderr is dropped on purpose: providers may echo parts of the secret payload.
+        return Err(BenchError::InvalidArgument(format!(
+            "'{program}' exited with status {} while resolving secret reference '{reference}'",
+            output.status
+        )));
+    }
+    let stdout = String::from_utf8(output.stdout).map_err(|_| {
+        BenchError::InvalidArgument(format!(
+            "secret reference '{reference}' resolved to a non-UTF-8 value"
+        ))
+    })?;
+    Ok(stdout.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn json_secret_field(reference: &str, secret: &str, field: &str) -> BenchResult<String> {
+    let payload: Value = serde_json::from_str(secret).map_err(|_| {
+        BenchError::InvalidArgument(format!(
+            "secret reference '{reference}' selects field '{field}', but the secret is not a JSON object"
+        ))
+    })?;
+    match payload.get(field) {
+        Some(Value::String(value)) => Ok(value.clone()),
+        Some(Value::Number(value)) => Ok(value.to_string()),
+        Some(Value::Bool(value)) => Ok(value.to_string()),
+        _ => Err(BenchError::InvalidArgument(format!(
+            "secret reference '{reference}' selects field '{field}', which is missing or not a scalar"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_provider() {
+        assert_eq!(
+            parse_secret_reference("secret://env/AWS_SECRET_ACCESS_KEY").unwrap(),
+            SecretReference::Env {
+                name: "AWS_SECRET_ACCESS_KEY".to_string()
+            }
+        );
+        assert_eq!(
+            parse_secret_reference("secret://aws-sm/bench/s3#secret_key").unwrap(),
+            SecretReference::AwsSecretsManager {
+                secret_id: "bench/s3".to_string(),
+                field: Some("secret_key".to_string())
+            }
+        );
+        assert_eq!(
+            parse_secret_reference("secret://aws-sm/bench/s3").unwrap(),
+            SecretReference::AwsSecretsManager {
+                secret_id: "bench/s3".to_string(),
+                field: None
+            }
+        );
+        assert_eq!(
+            parse_secret_reference("secret://sops/backends/secrets.enc.yaml#AWS_SECRET").unwrap(),
+            SecretReference::Sops {
+                file: "backends/secrets.enc.yaml".to_string(),
+                key: "AWS_SECRET".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_references() {
+        for reference in [
+            "secret://",
+            "secret://env/",
+            "secret://aws-sm/#field",
+            "secret://aws-sm/id#",
+            "secret://sops/file.yaml",
+            "secret://vault/kv/bench",
+        ] {
+            assert!(
+                parse_secret_reference(reference).is_err(),
+                "{reference} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn json_field_errors_do_not_echo_secret() {
+        let err = json_secret_field(
+            "secret://aws-sm/id#missing",
+            r#"{"key":"hunter2"}"#,
+            "missing",
+        )
+        .expect_err("missing field should error");
+        assert!(!err.to_string().contains("hunter2"), "{err}");
+        let err = json_secret_field("secret://aws-sm/id#key", "hunter2", "key")
+            .expect_err("non-json secret should error");
+        assert!(!err.to_string().contains("hunter2"), "{err}");
+    }
+}
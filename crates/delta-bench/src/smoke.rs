@@ -0,0 +1,88 @@
+//! `delta-bench smoke`: a quick end-to-end check of a freshly built harness or delta-rs checkout.
+//!
+//! One read, one write, one merge, and one metadata case run against fixtures generated at the
+//! built-in [`SMOKE_SCALE`] in a throwaway directory, so no `data` step is needed and nothing
+//! outside that directory is touched.
+
+use std::path::Path;
+use std::time::Instant;
+
+use crate::cli::{BenchmarkLane, RunnerMode, TimingPhase};
+use crate::data::fixtures::{generate_fixtures_with_profile, FixtureProfile};
+use crate::data::scales::SMOKE_SCALE;
+use crate::error::BenchResult;
+use crate::results::CaseResult;
+use crate::runner::{CaseHooks, CaseSeeds, DEFAULT_RUN_SEED};
+use crate::storage::StorageConfig;
+use crate::suites::{plan_replay_case, run_planned_cases_with_seeds, SuiteOptions};
+
+/// `(target, case)` pairs the smoke check runs, in order.
+pub const SMOKE_CASES: [(&str, &str); 4] = [
+    ("scan", "scan_full_narrow"),
+    ("write", "write_append_small"),
+    ("merge", "merge_upsert_10pct"),
+    ("metadata", "metadata_load"),
+];
+const SMOKE_FIXTURE_SEED: u64 = 42;
+
+#[derive(Clone, Debug)]
+pub struct SmokeReport {
+    pub cases: Vec<CaseResult>,
+    pub fixtures_ms: f64,
+    pub elapsed_ms: f64,
+}
+
+impl SmokeReport {
+    pub fn passed(&self) -> bool {
+        self.cases
+            .iter()
+            .all(|case| case.success && case.validation_passed)
+    }
+}
+
+/// Generates standard fixtures at [`SMOKE_SCALE`] under `work_dir` and runs [`SMOKE_CASES`] once
+/// each, without warmup, on local storage. Case failures are reported, not returned as errors.
+pub async fn run_smoke(work_dir: &Path) -> BenchResult<SmokeReport> {
+    let started = Instant::now();
+    let storage = StorageConfig::local();
+    let fixtures_dir = work_dir.join("fixtures");
+    generate_fixtures_with_profile(
+        &fixtures_dir,
+        SMOKE_SCALE,
+        SMOKE_FIXTURE_SEED,
+        true,
+        FixtureProfile::Standard,
+        &storage,
+    )
+    .await?;
+    let fixtures_ms = started.elapsed().as_secs_f64() * 1_000.0;
+
+    let mut planned = Vec::with_capacity(SMOKE_CASES.len());
+    for (target, case) in SMOKE_CASES {
+        planned.extend(plan_replay_case(
+            target,
+            RunnerMode::Rust,
+            case,
+            &SuiteOptions::default(),
+        )?);
+    }
+    let cases = run_planned_cases_with_seeds(
+        &fixtures_dir,
+        &planned,
+        SMOKE_SCALE,
+        BenchmarkLane::Smoke,
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &CaseSeeds::new(DEFAULT_RUN_SEED),
+        &SuiteOptions::default(),
+    )
+    .await?;
+    Ok(SmokeReport {
+        cases,
+        fixtures_ms,
+        elapsed_ms: started.elapsed().as_secs_f64() * 1_000.0,
+    })
+}
@@ -53,6 +53,15 @@ pub fn compute_stats(samples_ms: &[f64]) -> Option<SampleStats> {
     })
 }
 
+/// Nearest-rank `pct`th percentile of ascending `sorted`; 0.0 when empty.
+pub fn nearest_rank(sorted: &[f64], pct: usize) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,23 +1,49 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
-
-use deltalake_core::{open_table, open_table_with_storage_options, DeltaTable};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use deltalake_core::logstore::object_store::path::Path as ObjectStorePath;
+use deltalake_core::logstore::object_store::ObjectStore;
+use deltalake_core::{
+    open_table, open_table_with_storage_options, DeltaTable, DeltaTableBuilder, DeltaTableError,
+};
+use futures::{StreamExt, TryStreamExt};
 use url::Url;
 
 use crate::cli::StorageBackend;
 use crate::error::{BenchError, BenchResult};
+use crate::log_reads::LogReadCounter;
+use crate::runner::{record_table_url, AuditLog};
+use crate::secrets::{is_secret_reference, resolve_secret_reference};
+use crate::suites::fixture_copy::FixtureCopyStrategy;
 
 pub const TABLE_ROOT_KEY: &str = "table_root";
+/// Harness-only cap on sessions a case may open against the backend at once.
+pub const MAX_CONCURRENCY_KEY: &str = "max_concurrency";
+const ISOLATED_TABLE_MARKER: &str = "__isolated__";
 static ISOLATION_COUNTER: AtomicU64 = AtomicU64::new(0);
 
+/// A per-iteration table created by [`StorageConfig::isolated_table_url`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IsolatedTable {
+    /// Path under `table_root` holding the table, e.g. `sf1` or `<dataset_id>/sf1`.
+    pub prefix: String,
+    pub table_name: String,
+    pub created_at_nanos: u128,
+    pub object_count: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct StorageConfig {
     backend: StorageBackend,
     options: HashMap<String, String>,
     table_root: Option<Url>,
+    max_concurrency: Option<usize>,
+    fixture_copy: FixtureCopyStrategy,
+    audit_log: Option<Arc<AuditLog>>,
 }
 
 impl StorageConfig {
@@ -26,6 +52,9 @@ impl StorageConfig {
             backend: StorageBackend::Local,
             options: HashMap::new(),
             table_root: None,
+            max_concurrency: None,
+            fixture_copy: FixtureCopyStrategy::default(),
+            audit_log: None,
         }
     }
 
@@ -44,14 +73,51 @@ impl StorageConfig {
             validate_table_root_scheme(backend, &parsed)?;
             Some(parsed)
         };
+        let max_concurrency = options
+            .get(MAX_CONCURRENCY_KEY)
+            .map(|raw| match raw.trim().parse::<usize>() {
+                Ok(value) if value > 0 => Ok(value),
+                _ => Err(BenchError::InvalidArgument(format!(
+                    "storage option '{MAX_CONCURRENCY_KEY}' must be a positive integer, got '{raw}'"
+                ))),
+            })
+            .transpose()?;
 
         Ok(Self {
             backend,
             options,
             table_root,
+            max_concurrency,
+            fixture_copy: FixtureCopyStrategy::default(),
+            audit_log: None,
         })
     }
 
+    /// Sets how suites copy local fixture tables for each iteration.
+    pub fn with_fixture_copy(mut self, fixture_copy: FixtureCopyStrategy) -> Self {
+        self.fixture_copy = fixture_copy;
+        self
+    }
+
+    /// Lists the table URLs each case resolves in the finish records of `log`; see
+    /// [`crate::runner::CaseHooks::with_audit_log`].
+    pub fn with_audit_log(mut self, log: Arc<AuditLog>) -> Self {
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Nests remote fixture and isolated tables under `<table_root>/<dataset_id>/` so datasets
+    /// that share a scale do not overwrite each other. Local storage is unaffected because local
+    /// fixture paths are already scoped by `dataset_fixtures_dir`.
+    pub fn for_dataset(&self, dataset_id: Option<&str>) -> Self {
+        let mut scoped = self.clone();
+        if let (Some(dataset_id), Some(root)) = (dataset_id, scoped.table_root.as_mut()) {
+            let base_path = root.path().trim_end_matches('/').to_string();
+            root.set_path(&format!("{base_path}/{dataset_id}"));
+        }
+        scoped
+    }
+
     pub fn backend(&self) -> StorageBackend {
         self.backend
     }
@@ -60,9 +126,19 @@ impl StorageConfig {
         matches!(self.backend, StorageBackend::Local)
     }
 
+    /// Sessions a single case may run against the backend at once, if the profile caps it.
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.max_concurrency
+    }
+
+    pub fn fixture_copy(&self) -> FixtureCopyStrategy {
+        self.fixture_copy
+    }
+
     pub fn object_store_options(&self) -> HashMap<String, String> {
         let mut out = self.options.clone();
         out.remove(TABLE_ROOT_KEY);
+        out.remove(MAX_CONCURRENCY_KEY);
         out
     }
 
@@ -80,6 +156,7 @@ impl StorageConfig {
             format!("{base_path}/{scale}/{table_name}")
         };
         root.set_path(&joined);
+        record_table_url(self.audit_log.as_deref(), &root);
         Ok(root)
     }
 
@@ -97,7 +174,7 @@ impl StorageConfig {
 
         let sanitized_key = sanitize_path_component(isolation_key);
         let table_name = format!(
-            "{base_table_name}__isolated__{sanitized_key}__{}",
+            "{base_table_name}{ISOLATED_TABLE_MARKER}{sanitized_key}__{}",
             next_isolation_suffix()
         );
         self.fixture_table_url(scale, &table_name)
@@ -115,12 +192,14 @@ impl StorageConfig {
             } else {
                 std::env::current_dir()?.join(local_table_path)
             };
-            Url::from_directory_path(&absolute_path).map_err(|()| {
+            let url = Url::from_directory_path(&absolute_path).map_err(|()| {
                 BenchError::InvalidArgument(format!(
                     "failed to create table URL for {}",
                     absolute_path.display()
                 ))
-            })
+            })?;
+            record_table_url(self.audit_log.as_deref(), &url);
+            Ok(url)
         } else {
             self.fixture_table_url(scale, table_name)
         }
@@ -143,6 +222,109 @@ impl StorageConfig {
             Ok(DeltaTable::try_from_url_with_storage_options(table_url, options).await?)
         }
     }
+
+    /// Like [`Self::try_from_url_for_write`], but every `_delta_log` file the table reads is
+    /// recorded by the returned counter. The table is not loaded.
+    pub async fn try_from_url_counting_log_reads(
+        &self,
+        table_url: Url,
+    ) -> BenchResult<(DeltaTable, LogReadCounter)> {
+        let root_store = self
+            .try_from_url_for_write(table_url.clone())
+            .await?
+            .log_store()
+            .root_object_store(None);
+        let counter = LogReadCounter::default();
+        let mut builder = DeltaTableBuilder::from_url(table_url.clone())?
+            .with_storage_backend(counter.wrap(root_store), table_url);
+        let options = self.object_store_options();
+        if !options.is_empty() {
+            builder = builder.with_storage_options(options);
+        }
+        Ok((builder.build()?, counter))
+    }
+
+    /// Lists isolated tables under `table_root`, grouped by `<prefix>/<table_name>`.
+    pub async fn list_isolated_tables(&self) -> BenchResult<Vec<IsolatedTable>> {
+        let store = self.table_root_object_store().await?;
+        let mut tables = BTreeMap::<(String, String), IsolatedTable>::new();
+        let mut listing = store.list(None);
+        while let Some(meta) = listing.next().await {
+            let meta = meta.map_err(DeltaTableError::from)?;
+            let parts = meta
+                .location
+                .parts()
+                .map(|part| part.as_ref().to_string())
+                .collect::<Vec<_>>();
+            let Some((table_idx, created_at_nanos)) =
+                parts.iter().enumerate().find_map(|(idx, part)| {
+                    parse_isolated_table_created_nanos(part).map(|nanos| (idx, nanos))
+                })
+            else {
+                continue;
+            };
+            if table_idx == 0 {
+                continue;
+            }
+            let key = (parts[..table_idx].join("/"), parts[table_idx].clone());
+            tables
+                .entry(key)
+                .or_insert_with_key(|(prefix, table_name)| IsolatedTable {
+                    prefix: prefix.clone(),
+                    table_name: table_name.clone(),
+                    created_at_nanos,
+                    object_count: 0,
+                })
+                .object_count += 1;
+        }
+        Ok(tables.into_values().collect())
+    }
+
+    /// Deletes every object under an isolated table prefix and returns the number removed.
+    pub async fn delete_isolated_table(&self, table: &IsolatedTable) -> BenchResult<usize> {
+        let store = self.table_root_object_store().await?;
+        let prefix = ObjectStorePath::from(format!("{}/{}", table.prefix, table.table_name));
+        let locations = store
+            .list(Some(&prefix))
+            .map_ok(|meta| meta.location)
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(DeltaTableError::from)?;
+        for location in &locations {
+            store
+                .delete(location)
+                .await
+                .map_err(DeltaTableError::from)?;
+        }
+        Ok(locations.len())
+    }
+
+    async fn table_root_object_store(&self) -> BenchResult<Arc<dyn ObjectStore>> {
+        let root = self.table_root.clone().ok_or_else(|| {
+            BenchError::InvalidArgument(
+                "listing isolated tables requires a non-local storage backend".to_string(),
+            )
+        })?;
+        let table = self.try_from_url_for_write(root).await?;
+        Ok(table.log_store().object_store(None))
+    }
+}
+
+/// Keeps isolated tables created at least `retention` before `now`.
+pub fn isolated_tables_older_than(
+    tables: Vec<IsolatedTable>,
+    retention: Duration,
+    now: SystemTime,
+) -> Vec<IsolatedTable> {
+    let cutoff_nanos = now
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .saturating_sub(retention)
+        .as_nanos();
+    tables
+        .into_iter()
+        .filter(|table| table.created_at_nanos <= cutoff_nanos)
+        .collect()
 }
 
 pub fn load_backend_profile_options(profile: Option<&str>) -> BenchResult<HashMap<String, String>> {
@@ -172,7 +354,18 @@ pub fn load_backend_profile_options_from_root(
         )));
     }
 
-    parse_profile_file(&file)
+    let mut options = parse_profile_file(&file)?;
+    for (key, value) in options.iter_mut() {
+        if is_secret_reference(value) {
+            *value = resolve_secret_reference(value, root).map_err(|error| match error {
+                BenchError::InvalidArgument(message) => BenchError::InvalidArgument(format!(
+                    "backend profile '{profile}' option '{key}': {message}"
+                )),
+                other => other,
+            })?;
+        }
+    }
+    Ok(options)
 }
 
 fn validate_backend_profile_name(profile: &str) -> BenchResult<()> {
@@ -253,6 +446,14 @@ fn sanitize_path_component(value: &str) -> String {
     }
 }
 
+fn parse_isolated_table_created_nanos(table_name: &str) -> Option<u128> {
+    let (_, rest) = table_name.split_once(ISOLATED_TABLE_MARKER)?;
+    let (_, suffix) = rest.rsplit_once("__")?;
+    let (nanos, counter) = suffix.split_once('-')?;
+    counter.parse::<u64>().ok()?;
+    nanos.parse().ok()
+}
+
 fn next_isolation_suffix() -> String {
     let counter = ISOLATION_COUNTER.fetch_add(1, Ordering::Relaxed);
     let nanos = SystemTime::now()
@@ -302,6 +503,105 @@ mod tests {
         assert_eq!(sanitize_path_component("__name__"), "name");
     }
 
+    #[test]
+    fn parse_isolated_table_suffix() {
+        assert_eq!(
+            parse_isolated_table_created_nanos("narrow_sales__isolated__scan_full__1700-3"),
+            Some(1700)
+        );
+        assert_eq!(
+            parse_isolated_table_created_nanos("narrow_sales__isolated__a__b__42-0"),
+            Some(42)
+        );
+    }
+
+    #[test]
+    fn parse_rejects_non_isolated_tables() {
+        assert_eq!(parse_isolated_table_created_nanos("narrow_sales"), None);
+        assert_eq!(
+            parse_isolated_table_created_nanos("narrow_sales__isolated__scan"),
+            None
+        );
+        assert_eq!(
+            parse_isolated_table_created_nanos("narrow_sales__isolated__scan__abc-1"),
+            None
+        );
+    }
+
+    #[test]
+    fn older_than_keeps_only_expired_tables() {
+        let table = |name: &str, created_at_nanos: u128| IsolatedTable {
+            prefix: "sf1".to_string(),
+            table_name: name.to_string(),
+            created_at_nanos,
+            object_count: 1,
+        };
+        let now = UNIX_EPOCH + Duration::from_secs(10 * 3600);
+        let expired = isolated_tables_older_than(
+            vec![
+                table("old", Duration::from_secs(3600).as_nanos()),
+                table("fresh", Duration::from_secs(9 * 3600 + 1).as_nanos()),
+            ],
+            Duration::from_secs(3600),
+            now,
+        );
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].table_name, "old");
+    }
+
+    #[test]
+    fn for_dataset_nests_remote_table_root() {
+        let storage = StorageConfig::new(
+            StorageBackend::S3,
+            HashMap::from([(TABLE_ROOT_KEY.to_string(), "s3://bucket/bench/".to_string())]),
+        )
+        .unwrap();
+        let url = storage
+            .for_dataset(Some("small_files"))
+            .fixture_table_url("sf1", "narrow_sales_delta")
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "s3://bucket/bench/small_files/sf1/narrow_sales_delta"
+        );
+        let unscoped = storage
+            .for_dataset(None)
+            .fixture_table_url("sf1", "narrow_sales_delta")
+            .unwrap();
+        assert_eq!(
+            unscoped.as_str(),
+            "s3://bucket/bench/sf1/narrow_sales_delta"
+        );
+    }
+
+    #[test]
+    fn max_concurrency_is_parsed_and_kept_out_of_object_store_options() {
+        let storage = StorageConfig::new(
+            StorageBackend::S3,
+            HashMap::from([
+                (TABLE_ROOT_KEY.to_string(), "s3://bucket/bench/".to_string()),
+                (MAX_CONCURRENCY_KEY.to_string(), "4".to_string()),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(storage.max_concurrency(), Some(4));
+        assert!(!storage
+            .object_store_options()
+            .contains_key(MAX_CONCURRENCY_KEY));
+
+        for invalid in ["0", "-1", "many"] {
+            let err = StorageConfig::new(
+                StorageBackend::S3,
+                HashMap::from([
+                    (TABLE_ROOT_KEY.to_string(), "s3://bucket/bench/".to_string()),
+                    (MAX_CONCURRENCY_KEY.to_string(), invalid.to_string()),
+                ]),
+            )
+            .expect_err("invalid max_concurrency should be rejected");
+            assert!(err.to_string().contains(MAX_CONCURRENCY_KEY), "{err}");
+        }
+    }
+
     #[test]
     fn validate_mismatched_scheme_rejected() {
         let url = Url::parse("gs://bucket/path").unwrap();
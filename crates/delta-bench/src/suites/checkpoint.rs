@@ -0,0 +1,235 @@
+use std::path::Path;
+
+use deltalake_core::checkpoints;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::BenchmarkLane;
+use crate::data::fixtures::checkpoint_history_table_path;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
+
+#[derive(Clone, Copy)]
+enum CheckpointOperation {
+    /// Time `create_checkpoint` on a long JSON-only log.
+    Write,
+    /// Time snapshot load after an untimed checkpoint was written in setup.
+    LoadWithCheckpoint,
+    /// Time snapshot load by replaying the full JSON log.
+    LoadWithoutCheckpoint,
+}
+
+#[derive(Clone, Copy)]
+struct CheckpointCase {
+    name: &'static str,
+    operation: CheckpointOperation,
+}
+
+const CHECKPOINT_CASES: [CheckpointCase; 3] = [
+    CheckpointCase {
+        name: "checkpoint_write_long_history",
+        operation: CheckpointOperation::Write,
+    },
+    CheckpointCase {
+        name: "checkpoint_load_with_checkpoint",
+        operation: CheckpointOperation::LoadWithCheckpoint,
+    },
+    CheckpointCase {
+        name: "checkpoint_load_without_checkpoint",
+        operation: CheckpointOperation::LoadWithoutCheckpoint,
+    },
+];
+
+struct CheckpointIterationSetup {
+    _temp: tempfile::TempDir,
+    table_url: Url,
+    /// Opened in setup for checkpoint writes, so log replay stays out of the timed section.
+    table: Option<DeltaTable>,
+}
+
+pub fn case_names() -> Vec<String> {
+    CHECKPOINT_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct CheckpointSuite;
+
+impl Suite for CheckpointSuite {
+    fn name(&self) -> &'static str {
+        "checkpoint"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    lane: BenchmarkLane,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "checkpoint suite does not support non-local storage backend yet",
+        ));
+    }
+
+    let source = checkpoint_history_table_path(fixtures_dir, scale);
+    if !source.join("_delta_log").exists() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing checkpoint history fixture table; run bench data --dataset-id many_versions first",
+        ));
+    }
+
+    let mut out = Vec::new();
+    for case in CHECKPOINT_CASES {
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let source = source.clone();
+                let storage = storage.clone();
+                async move {
+                    prepare_checkpoint_iteration(&source, case.operation, &storage)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+            |mut setup| {
+                let storage = storage.clone();
+                async move {
+                    let table_url = setup.table_url.clone();
+                    let table = setup.table.take();
+                    let _keep_temp = setup;
+                    run_checkpoint_case(&storage, table_url, table, case, lane)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+async fn run_checkpoint_case(
+    storage: &StorageConfig,
+    table_url: Url,
+    opened: Option<DeltaTable>,
+    case: CheckpointCase,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
+    let table = match opened {
+        Some(table) => table,
+        None => storage.open_table(table_url).await?,
+    };
+    if matches!(case.operation, CheckpointOperation::Write) {
+        checkpoints::create_checkpoint(&table, None).await?;
+    }
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "table_version": table_version,
+    }))?;
+    let (schema_hash, semantic_state_digest, validation_summary) =
+        checkpoint_validation(&table, lane).await?;
+
+    Ok(
+        SampleMetrics::base(None, None, Some(1), table_version).with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: None,
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest,
+            validation_summary,
+        }),
+    )
+}
+
+async fn checkpoint_validation(
+    table: &DeltaTable,
+    lane: BenchmarkLane,
+) -> BenchResult<(String, Option<String>, Option<String>)> {
+    if !lane_requires_semantic_validation(lane) {
+        let schema_hash = hash_json(&json!(["operation:string", "table_version:u64"]))?;
+        return Ok((schema_hash, None, None));
+    }
+    let validation = validate_table_state(table).await?;
+    Ok((
+        validation.schema_hash,
+        Some(validation.digest),
+        Some(validation.summary),
+    ))
+}
+
+async fn prepare_checkpoint_iteration(
+    source_table_path: &Path,
+    operation: CheckpointOperation,
+    storage: &StorageConfig,
+) -> BenchResult<CheckpointIterationSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("table");
+    copy_dir_with(source_table_path, &table_dir, storage.fixture_copy())?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    let table = match operation {
+        CheckpointOperation::Write => Some(storage.open_table(table_url.clone()).await?),
+        CheckpointOperation::LoadWithCheckpoint => {
+            let table = storage.open_table(table_url.clone()).await?;
+            checkpoints::create_checkpoint(&table, None).await?;
+            None
+        }
+        CheckpointOperation::LoadWithoutCheckpoint => None,
+    };
+    Ok(CheckpointIterationSetup {
+        _temp: temp,
+        table_url,
+        table,
+    })
+}
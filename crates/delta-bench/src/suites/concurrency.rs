@@ -5,27 +5,31 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use deltalake_core::arrow::record_batch::RecordBatch;
+use deltalake_core::datafusion::prelude::SessionContext;
 use deltalake_core::kernel::transaction::{CommitConflictError, TransactionError};
 use deltalake_core::kernel::{DataType, PrimitiveType, StructField, StructType};
 use deltalake_core::protocol::SaveMode;
 use deltalake_core::{DeltaTable, DeltaTableError};
+use futures::future::LocalBoxFuture;
 use serde_json::json;
 use tempfile::TempDir;
 use tokio::sync::Barrier;
 use url::Url;
 
-use super::{copy_dir_all, fixture_error_cases};
+use super::{copy_dir_with, fixture_error_cases, Suite, SuiteRunArgs};
 use crate::data::datasets::NarrowSaleRow;
 use crate::data::fixtures::{
-    delete_update_small_files_table_path, load_rows, optimize_small_files_table_path, rows_to_batch,
+    delete_update_small_files_table_path, optimize_small_files_table_path, rows_to_batch,
 };
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
 use crate::results::{
-    CaseFailure, CaseResult, ContentionMetrics, ElapsedStats, IterationSample, PerfStatus,
-    RuntimeIOMetrics, SampleMetrics,
+    CaseFailure, CaseResult, ContentionMetrics, ElapsedStats, IterationSample,
+    MixedWorkloadMetrics, OperationLatencyMetrics, PerfStatus, RuntimeIOMetrics, SampleMetrics,
+    WorkerMetrics,
 };
-use crate::stats::compute_stats;
+use crate::stats::{compute_stats, nearest_rank};
 use crate::storage::StorageConfig;
 use crate::version_compat::optional_table_version_to_u64;
 
@@ -34,7 +38,19 @@ const APPEND_WORKER_COUNT: usize = 4;
 const CONTENDED_WORKER_COUNT: usize = 2;
 const CONTENDED_RACE_COUNT: usize = 3;
 const APPEND_ROWS_PER_WORKER: usize = 512;
+const APPEND_SWEEP_WORKER_COUNTS: [usize; 3] = [2, 4, 8];
+/// Sequential appends the ingest worker lands while its optimize peer compacts.
+const INGEST_APPENDS_PER_RACE: usize = 4;
 const CONTENDED_OPTIMIZE_TARGET_SIZE: u64 = 1_000_000;
+const MIXED_OLTP_CASE: &str = "mixed_oltp_like";
+const MIXED_OLTP_WORKER_COUNT: usize = 8;
+const MIXED_OLTP_DURATION: Duration = Duration::from_secs(60);
+/// Every worker makes each 10th operation an append and the other nine point reads.
+const MIXED_OLTP_APPEND_EVERY: u64 = 10;
+const MIXED_OLTP_SEED_ROWS: usize = 4_096;
+const MIXED_OLTP_APPEND_ROWS: usize = 64;
+/// Offset for appended ids, so point reads of seeded ids always hit exactly the seeded rows.
+const MIXED_OLTP_NEW_ID_BASE: i64 = 1_000_000_000;
 
 fn update_vs_compaction_predicate() -> &'static str {
     "region = 'us' AND id % 17 = 0"
@@ -48,14 +64,57 @@ fn contended_optimize_target_size() -> NonZeroU64 {
     NonZeroU64::new(CONTENDED_OPTIMIZE_TARGET_SIZE).expect("target size must be non-zero")
 }
 
+fn append_sweep_case_name(worker_count: usize) -> String {
+    format!("concurrent_append_writers_{worker_count}")
+}
+
+fn append_case_names() -> Vec<String> {
+    std::iter::once("concurrent_append_multi".to_string())
+        .chain(
+            APPEND_SWEEP_WORKER_COUNTS
+                .iter()
+                .map(|worker_count| append_sweep_case_name(*worker_count)),
+        )
+        .collect()
+}
+
 pub fn case_names() -> Vec<String> {
-    vec![
-        "concurrent_table_create".to_string(),
-        "concurrent_append_multi".to_string(),
+    let mut names = vec!["concurrent_table_create".to_string()];
+    names.extend(append_case_names());
+    names.extend([
         "update_vs_compaction".to_string(),
         "delete_vs_compaction".to_string(),
         "optimize_vs_optimize_overlap".to_string(),
-    ]
+        "optimize_vs_append_ingest".to_string(),
+        MIXED_OLTP_CASE.to_string(),
+    ]);
+    names
+}
+
+pub struct ConcurrencySuite;
+
+impl Suite for ConcurrencySuite {
+    fn name(&self) -> &'static str {
+        "concurrency"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
 }
 
 pub async fn run(
@@ -64,6 +123,7 @@ pub async fn run(
     warmup: u32,
     iterations: u32,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
 ) -> BenchResult<Vec<CaseResult>> {
     if !storage.is_local() {
         return Ok(fixture_error_cases(
@@ -85,11 +145,26 @@ pub async fn run(
         .await,
     );
 
-    match load_rows(fixtures_dir, scale) {
+    let mut ingest_batches = None;
+    let mut mixed_seed_rows = None;
+    match fixture_rows.load(fixtures_dir, scale) {
         Ok(rows) => {
+            mixed_seed_rows = Some(Arc::new(
+                rows.iter()
+                    .take(MIXED_OLTP_SEED_ROWS)
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            ));
+            ingest_batches = Some(Arc::new(
+                rows.chunks(APPEND_ROWS_PER_WORKER)
+                    .take(INGEST_APPENDS_PER_RACE)
+                    .map(rows_to_batch)
+                    .collect::<BenchResult<Vec<_>>>()?,
+            ));
             let limited_rows = Arc::new(
-                rows.into_iter()
+                rows.iter()
                     .take(APPEND_WORKER_COUNT * APPEND_ROWS_PER_WORKER)
+                    .cloned()
                     .collect::<Vec<_>>(),
             );
             out.push(
@@ -108,11 +183,29 @@ pub async fn run(
                 )
                 .await,
             );
+            for worker_count in APPEND_SWEEP_WORKER_COUNTS {
+                let writer_rows = Arc::new(
+                    rows.iter()
+                        .take(worker_count * APPEND_ROWS_PER_WORKER)
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                );
+                out.push(
+                    run_concurrency_case_with_setup(
+                        &append_sweep_case_name(worker_count),
+                        warmup,
+                        iterations,
+                        move || {
+                            let writer_rows = Arc::clone(&writer_rows);
+                            async move { prepare_append_sample(writer_rows.as_ref()).await }
+                        },
+                        |setup| async move { execute_concurrent_append_writers(setup).await },
+                    )
+                    .await,
+                );
+            }
         }
-        Err(error) => out.extend(fixture_error_cases(
-            vec!["concurrent_append_multi".to_string()],
-            &error.to_string(),
-        )),
+        Err(error) => out.extend(fixture_error_cases(append_case_names(), &error.to_string())),
     }
 
     let delete_update_source = delete_update_small_files_table_path(fixtures_dir, scale);
@@ -169,6 +262,66 @@ pub async fn run(
         ));
     }
 
+    match ingest_batches {
+        Some(batches) if optimize_source.exists() => {
+            let storage = storage.clone();
+            out.push(
+                run_concurrency_case_with_setup(
+                    "optimize_vs_append_ingest",
+                    warmup,
+                    iterations,
+                    move || {
+                        let source = optimize_source.clone();
+                        let storage = storage.clone();
+                        let batches = Arc::clone(&batches);
+                        async move {
+                            Ok(IngestContendedSampleSetup {
+                                contended: prepare_contended_sample(&source, &storage).await?,
+                                batches,
+                            })
+                        }
+                    },
+                    |setup| async move { execute_optimize_vs_append_ingest(setup).await },
+                )
+                .await,
+            );
+        }
+        Some(_) => out.extend(fixture_error_cases(
+            vec!["optimize_vs_append_ingest".to_string()],
+            "missing optimize small-files fixture table; run bench data first",
+        )),
+        None => out.extend(fixture_error_cases(
+            vec!["optimize_vs_append_ingest".to_string()],
+            "missing fixture rows for ingest batches; run bench data first",
+        )),
+    }
+
+    match mixed_seed_rows {
+        Some(seed_rows) => {
+            let storage = storage.clone();
+            out.push(
+                run_concurrency_case_with_setup(
+                    MIXED_OLTP_CASE,
+                    warmup,
+                    iterations,
+                    move || {
+                        let seed_rows = Arc::clone(&seed_rows);
+                        let storage = storage.clone();
+                        async move { prepare_mixed_sample(seed_rows, storage).await }
+                    },
+                    |setup| async move {
+                        execute_mixed_oltp_like(setup, MIXED_OLTP_DURATION).await
+                    },
+                )
+                .await,
+            );
+        }
+        None => out.extend(fixture_error_cases(
+            vec![MIXED_OLTP_CASE.to_string()],
+            "missing fixture rows for mixed read/append workload; run bench data first",
+        )),
+    }
+
     Ok(out)
 }
 
@@ -197,6 +350,26 @@ struct ContendedSampleSetup {
     races: Vec<TwoWorkerRace>,
 }
 
+struct IngestContendedSampleSetup {
+    contended: ContendedSampleSetup,
+    batches: Arc<Vec<RecordBatch>>,
+}
+
+struct MixedSampleSetup {
+    _temp: TempDir,
+    table_url: Url,
+    storage: StorageConfig,
+    seed_rows: Arc<Vec<NarrowSaleRow>>,
+}
+
+#[derive(Default)]
+struct MixedWorkerResult {
+    outcomes: Vec<WorkerOutcome>,
+    read_latencies_ms: Vec<f64>,
+    append_latencies_ms: Vec<f64>,
+    rows_appended: u64,
+}
+
 #[derive(Clone, Debug)]
 struct SampleExecution {
     metrics: SampleMetrics,
@@ -304,7 +477,7 @@ async fn prepare_contended_sample(
     let mut races = Vec::with_capacity(CONTENDED_RACE_COUNT);
     for idx in 0..CONTENDED_RACE_COUNT {
         let race_path = temp.path().join(format!("race_{idx}"));
-        copy_dir_all(source, &race_path)?;
+        copy_dir_with(source, &race_path, storage.fixture_copy())?;
         let table_url = storage.table_url_for(&race_path, "sf1", "ignored")?;
         let left = storage.open_table(table_url.clone()).await?;
         let right = storage.open_table(table_url).await?;
@@ -313,6 +486,35 @@ async fn prepare_contended_sample(
     Ok(ContendedSampleSetup { _temp: temp, races })
 }
 
+async fn prepare_mixed_sample(
+    seed_rows: Arc<Vec<NarrowSaleRow>>,
+    storage: StorageConfig,
+) -> BenchResult<MixedSampleSetup> {
+    if seed_rows.is_empty() {
+        return Err(BenchError::InvalidArgument(format!(
+            "{MIXED_OLTP_CASE} needs fixture rows to seed its table; run bench data first"
+        )));
+    }
+    let temp = tempfile::tempdir()?;
+    let table_url = directory_url(temp.path())?;
+    let schema = concurrency_schema();
+    let table = DeltaTable::try_from_url(table_url.clone())
+        .await?
+        .create()
+        .with_columns(schema.fields().cloned())
+        .await?;
+    let _ = table
+        .write(vec![rows_to_batch(&seed_rows)?])
+        .with_save_mode(SaveMode::Append)
+        .await?;
+    Ok(MixedSampleSetup {
+        _temp: temp,
+        table_url,
+        storage,
+        seed_rows,
+    })
+}
+
 async fn execute_concurrent_table_create(setup: CreateSampleSetup) -> BenchResult<SampleExecution> {
     let schema = Arc::new(concurrency_schema());
     let outcomes = run_barrier_race(
@@ -363,6 +565,64 @@ async fn execute_concurrent_append_multi(setup: AppendSampleSetup) -> BenchResul
     ))
 }
 
+/// Same shared-table append race as `concurrent_append_multi`, but records each writer's
+/// commit latency, committed version, and retry upper bound.
+async fn execute_concurrent_append_writers(
+    setup: AppendSampleSetup,
+) -> BenchResult<SampleExecution> {
+    let worker_count = setup.workers.len();
+    let results = run_barrier_race(
+        setup.workers,
+        Arc::new(|worker: AppendWorker| async move {
+            let read_version = checked_table_version(&worker.table).ok().flatten();
+            let rows = worker.batch.num_rows() as u64;
+            let start = Instant::now();
+            let outcome = classify_table_version_result(
+                worker
+                    .table
+                    .write(vec![worker.batch])
+                    .with_save_mode(SaveMode::Append)
+                    .await
+                    .and_then(|table| checked_table_version(&table)),
+            );
+            (outcome, start.elapsed(), read_version, rows)
+        }),
+    )
+    .await?;
+
+    let mut rows_committed = 0_u64;
+    let mut workers = Vec::with_capacity(results.len());
+    let mut outcomes = Vec::with_capacity(results.len());
+    for (idx, (outcome, elapsed, read_version, rows)) in results.into_iter().enumerate() {
+        let committed_version = match &outcome {
+            WorkerOutcome::Success { table_version } => {
+                rows_committed += rows;
+                *table_version
+            }
+            _ => None,
+        };
+        // delta-rs does not surface its commit retry loop. Every rejected attempt needs another
+        // commit in between, so the versions landed since the writer's read bound its retries.
+        let commit_retries = committed_version
+            .zip(read_version)
+            .and_then(|(committed, read)| committed.checked_sub(read + 1));
+        workers.push(WorkerMetrics {
+            worker: idx as u64,
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            succeeded: matches!(outcome, WorkerOutcome::Success { .. }),
+            table_version: committed_version,
+            commit_retries,
+        });
+        outcomes.push(outcome);
+    }
+
+    let mut sample =
+        aggregate_sample_execution(worker_count, 1, outcomes, TableVersionPolicy::MaxObserved);
+    sample.metrics.rows_processed = Some(rows_committed);
+    sample.metrics = sample.metrics.with_workers(workers);
+    Ok(sample)
+}
+
 async fn execute_update_vs_compaction(setup: ContendedSampleSetup) -> BenchResult<SampleExecution> {
     enum Worker {
         Update(DeltaTable),
@@ -478,6 +738,272 @@ async fn execute_optimize_vs_optimize_overlap(
     ))
 }
 
+/// Optimize races a background ingest worker that lands `INGEST_APPENDS_PER_RACE` sequential
+/// appends. Per-worker metrics keep the two sides' latencies apart: even workers are the
+/// optimize runs, odd workers the ingest streams.
+async fn execute_optimize_vs_append_ingest(
+    setup: IngestContendedSampleSetup,
+) -> BenchResult<SampleExecution> {
+    enum Worker {
+        Compact(DeltaTable),
+        Ingest(DeltaTable, Arc<Vec<RecordBatch>>),
+    }
+
+    let mut outcomes = Vec::new();
+    let mut workers = Vec::new();
+    let mut rows_committed = 0_u64;
+    for race in setup.contended.races {
+        let results = run_barrier_race(
+            vec![
+                Worker::Compact(race.left),
+                Worker::Ingest(race.right, Arc::clone(&setup.batches)),
+            ],
+            Arc::new(|worker| async move {
+                let start = Instant::now();
+                let mut outcomes = Vec::new();
+                let mut rows = 0_u64;
+                match worker {
+                    Worker::Compact(table) => outcomes.push(classify_table_version_result(
+                        table
+                            .optimize()
+                            .with_target_size(contended_optimize_target_size().into())
+                            .await
+                            .and_then(|(table, _)| checked_table_version(&table)),
+                    )),
+                    Worker::Ingest(mut table, batches) => {
+                        for batch in batches.iter() {
+                            let batch_rows = batch.num_rows() as u64;
+                            match table
+                                .write(vec![batch.clone()])
+                                .with_save_mode(SaveMode::Append)
+                                .await
+                            {
+                                Ok(next) => {
+                                    outcomes.push(classify_table_version_result(
+                                        checked_table_version(&next),
+                                    ));
+                                    rows += batch_rows;
+                                    table = next;
+                                }
+                                Err(error) => {
+                                    outcomes.push(classify_delta_error(error));
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+                (outcomes, start.elapsed(), rows)
+            }),
+        )
+        .await?;
+
+        for (worker_outcomes, elapsed, rows) in results {
+            let committed_version =
+                worker_outcomes
+                    .iter()
+                    .rev()
+                    .find_map(|outcome| match outcome {
+                        WorkerOutcome::Success { table_version } => *table_version,
+                        _ => None,
+                    });
+            workers.push(WorkerMetrics {
+                worker: workers.len() as u64,
+                elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+                succeeded: worker_outcomes
+                    .iter()
+                    .all(|outcome| matches!(outcome, WorkerOutcome::Success { .. })),
+                table_version: committed_version,
+                commit_retries: None,
+            });
+            rows_committed += rows;
+            outcomes.extend(worker_outcomes);
+        }
+    }
+
+    let mut sample = aggregate_sample_execution(
+        CONTENDED_WORKER_COUNT,
+        CONTENDED_RACE_COUNT,
+        outcomes,
+        TableVersionPolicy::Omit,
+    );
+    sample.metrics.rows_processed = Some(rows_committed);
+    sample.metrics = sample.metrics.with_workers(workers);
+    Ok(sample)
+}
+
+/// Workers share one seeded table and loop for `duration`. Reads and appends both reopen the
+/// latest snapshot, so reads observe every append committed before them. A read is a successful
+/// operation at the version it read; appends are classified like any other commit.
+async fn execute_mixed_oltp_like(
+    setup: MixedSampleSetup,
+    duration: Duration,
+) -> BenchResult<SampleExecution> {
+    let start = Instant::now();
+    let results =
+        run_barrier_race(
+            (0..MIXED_OLTP_WORKER_COUNT).collect(),
+            Arc::new({
+                let table_url = setup.table_url.clone();
+                let storage = setup.storage.clone();
+                let seed_rows = Arc::clone(&setup.seed_rows);
+                move |worker| {
+                    let table_url = table_url.clone();
+                    let storage = storage.clone();
+                    let seed_rows = Arc::clone(&seed_rows);
+                    async move {
+                        run_mixed_worker(worker, &storage, &table_url, &seed_rows, duration).await
+                    }
+                }
+            }),
+        )
+        .await?;
+    let wall = start.elapsed();
+
+    let mut outcomes = Vec::new();
+    let mut read_latencies_ms = Vec::new();
+    let mut append_latencies_ms = Vec::new();
+    let mut rows_appended = 0_u64;
+    for result in results {
+        outcomes.extend(result.outcomes);
+        read_latencies_ms.extend(result.read_latencies_ms);
+        append_latencies_ms.extend(result.append_latencies_ms);
+        rows_appended += result.rows_appended;
+    }
+
+    let mut sample = aggregate_sample_execution(
+        MIXED_OLTP_WORKER_COUNT,
+        1,
+        outcomes,
+        TableVersionPolicy::MaxObserved,
+    );
+    sample.metrics.rows_processed = Some(rows_appended);
+    sample.metrics = sample.metrics.with_mixed_workload(MixedWorkloadMetrics {
+        workers: MIXED_OLTP_WORKER_COUNT as u64,
+        duration_ms: duration.as_millis() as u64,
+        reads: operation_latency_metrics(read_latencies_ms, wall),
+        appends: operation_latency_metrics(append_latencies_ms, wall),
+    });
+    Ok(sample)
+}
+
+fn is_mixed_append(seq: u64) -> bool {
+    seq % MIXED_OLTP_APPEND_EVERY == MIXED_OLTP_APPEND_EVERY - 1
+}
+
+async fn run_mixed_worker(
+    worker: usize,
+    storage: &StorageConfig,
+    table_url: &Url,
+    seed_rows: &[NarrowSaleRow],
+    duration: Duration,
+) -> MixedWorkerResult {
+    let deadline = Instant::now() + duration;
+    let mut result = MixedWorkerResult::default();
+    let mut seq = 0_u64;
+    while Instant::now() < deadline {
+        let start = Instant::now();
+        if is_mixed_append(seq) {
+            let outcome = match mixed_append_batch(seed_rows, worker, seq) {
+                Ok(batch) => match storage.open_table(table_url.clone()).await {
+                    Ok(table) => classify_table_version_result(
+                        table
+                            .write(vec![batch])
+                            .with_save_mode(SaveMode::Append)
+                            .await
+                            .and_then(|table| checked_table_version(&table)),
+                    ),
+                    Err(error) => WorkerOutcome::Unexpected(error.to_string()),
+                },
+                Err(error) => WorkerOutcome::Unexpected(error.to_string()),
+            };
+            if matches!(outcome, WorkerOutcome::Success { .. }) {
+                result
+                    .append_latencies_ms
+                    .push(start.elapsed().as_secs_f64() * 1000.0);
+                result.rows_appended += MIXED_OLTP_APPEND_ROWS as u64;
+            }
+            result.outcomes.push(outcome);
+        } else {
+            let id =
+                seed_rows[(seq as usize * MIXED_OLTP_WORKER_COUNT + worker) % seed_rows.len()].id;
+            match mixed_point_read(storage, table_url, id).await {
+                Ok(table_version) => {
+                    result
+                        .read_latencies_ms
+                        .push(start.elapsed().as_secs_f64() * 1000.0);
+                    result
+                        .outcomes
+                        .push(WorkerOutcome::Success { table_version });
+                }
+                Err(error) => result
+                    .outcomes
+                    .push(WorkerOutcome::Unexpected(error.to_string())),
+            }
+        }
+        seq += 1;
+    }
+    result
+}
+
+fn mixed_append_batch(
+    seed_rows: &[NarrowSaleRow],
+    worker: usize,
+    seq: u64,
+) -> BenchResult<RecordBatch> {
+    let first_id = MIXED_OLTP_NEW_ID_BASE
+        + ((seq as i64) * MIXED_OLTP_WORKER_COUNT as i64 + worker as i64)
+            * MIXED_OLTP_APPEND_ROWS as i64;
+    let rows = (0..MIXED_OLTP_APPEND_ROWS)
+        .map(|offset| {
+            let mut row = seed_rows[offset % seed_rows.len()].clone();
+            row.id = first_id + offset as i64;
+            row
+        })
+        .collect::<Vec<_>>();
+    rows_to_batch(&rows)
+}
+
+async fn mixed_point_read(
+    storage: &StorageConfig,
+    table_url: &Url,
+    id: i64,
+) -> BenchResult<Option<u64>> {
+    let table = storage.open_table(table_url.clone()).await?;
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let ctx = SessionContext::new();
+    ctx.register_table("bench", table.table_provider().await?)?;
+    let rows = ctx
+        .sql(&format!("SELECT * FROM bench WHERE id = {id}"))
+        .await?
+        .collect()
+        .await?
+        .iter()
+        .map(|batch| batch.num_rows())
+        .sum::<usize>();
+    if rows == 0 {
+        return Err(BenchError::InvalidArgument(format!(
+            "point read of seeded id {id} returned no rows at version {table_version:?}"
+        )));
+    }
+    Ok(table_version)
+}
+
+fn operation_latency_metrics(
+    mut latencies_ms: Vec<f64>,
+    wall: Duration,
+) -> OperationLatencyMetrics {
+    latencies_ms.sort_by(|left, right| left.total_cmp(right));
+    OperationLatencyMetrics {
+        ops: latencies_ms.len() as u64,
+        ops_per_sec: latencies_ms.len() as f64 / wall.as_secs_f64(),
+        p50_ms: nearest_rank(&latencies_ms, 50),
+        p95_ms: nearest_rank(&latencies_ms, 95),
+        p99_ms: nearest_rank(&latencies_ms, 99),
+        max_ms: latencies_ms.last().copied().unwrap_or_default(),
+    }
+}
+
 async fn run_barrier_race<W, O, F, Fut>(workers: Vec<W>, op: Arc<F>) -> BenchResult<Vec<O>>
 where
     W: Send + 'static,
@@ -629,7 +1155,7 @@ fn aggregate_sample_execution(
 }
 
 fn attach_concurrency_schema_hash(mut sample: SampleExecution) -> BenchResult<SampleExecution> {
-    let schema_hash = hash_json(&json!([
+    let mut fields = vec![
         "operations:u64",
         "table_version:u64",
         "contention.worker_count:u64",
@@ -646,7 +1172,37 @@ fn attach_concurrency_schema_hash(mut sample: SampleExecution) -> BenchResult<Sa
         "contention.version_already_exists:u64",
         "contention.max_commit_attempts_exceeded:u64",
         "contention.other_errors:u64",
-    ]))?;
+    ];
+    if sample.metrics.workers.is_some() {
+        fields.extend([
+            "rows_processed:u64",
+            "workers.worker:u64",
+            "workers.elapsed_ms:f64",
+            "workers.succeeded:bool",
+            "workers.table_version:u64",
+            "workers.commit_retries:u64",
+        ]);
+    }
+    if sample.metrics.mixed_workload.is_some() {
+        fields.extend([
+            "rows_processed:u64",
+            "mixed_workload.workers:u64",
+            "mixed_workload.duration_ms:u64",
+            "mixed_workload.reads.ops:u64",
+            "mixed_workload.reads.ops_per_sec:f64",
+            "mixed_workload.reads.p50_ms:f64",
+            "mixed_workload.reads.p95_ms:f64",
+            "mixed_workload.reads.p99_ms:f64",
+            "mixed_workload.reads.max_ms:f64",
+            "mixed_workload.appends.ops:u64",
+            "mixed_workload.appends.ops_per_sec:f64",
+            "mixed_workload.appends.p50_ms:f64",
+            "mixed_workload.appends.p95_ms:f64",
+            "mixed_workload.appends.p99_ms:f64",
+            "mixed_workload.appends.max_ms:f64",
+        ]);
+    }
+    let schema_hash = hash_json(&json!(fields))?;
     sample.metrics = sample.metrics.with_runtime_io(RuntimeIOMetrics {
         peak_rss_mb: None,
         cpu_time_ms: None,
@@ -770,6 +1326,14 @@ fn success_case_result(name: &str, samples: Vec<IterationSample>) -> CaseResult
         decision_threshold_pct: None,
         decision_metric: None,
         samples,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: None,
         failure: None,
     }
@@ -793,6 +1357,14 @@ fn failure_case_result(name: &str, samples: Vec<IterationSample>, message: Strin
         decision_threshold_pct: None,
         decision_metric: None,
         samples,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: Some("execution_error".to_string()),
         failure: Some(CaseFailure { message }),
     }
@@ -830,6 +1402,13 @@ fn concurrency_schema() -> StructType {
         StructField::new("region", DataType::Primitive(PrimitiveType::String), true),
         StructField::new("value_i64", DataType::Primitive(PrimitiveType::Long), true),
         StructField::new("flag", DataType::Primitive(PrimitiveType::Boolean), true),
+        StructField::new("ts", DataType::Primitive(PrimitiveType::Timestamp), true),
+        StructField::new("event_date", DataType::Primitive(PrimitiveType::Date), true),
+        StructField::new(
+            "amount",
+            DataType::decimal(18, 2).expect("static amount decimal type should be valid"),
+            true,
+        ),
     ])
     .expect("static concurrency schema should be valid")
 }
@@ -842,11 +1421,14 @@ mod tests {
     use std::time::{Duration, Instant};
 
     use super::{
-        aggregate_sample_execution, delete_vs_compaction_predicate, run_barrier_race,
-        run_concurrency_case_with_setup, update_vs_compaction_predicate, ContentionErrorKind,
-        TableVersionPolicy, WorkerOutcome,
+        aggregate_sample_execution, delete_vs_compaction_predicate, execute_mixed_oltp_like,
+        is_mixed_append, prepare_mixed_sample, run_barrier_race, run_concurrency_case_with_setup,
+        update_vs_compaction_predicate, ContentionErrorKind, TableVersionPolicy, WorkerOutcome,
+        MIXED_OLTP_APPEND_ROWS,
     };
+    use crate::data::datasets::NarrowSaleRow;
     use crate::results::SampleMetrics;
+    use crate::storage::StorageConfig;
 
     #[tokio::test(flavor = "multi_thread")]
     async fn setup_delay_is_not_counted_in_concurrency_runner_elapsed_time() {
@@ -1076,6 +1658,58 @@ mod tests {
         assert_eq!(metrics.other_errors, 1);
     }
 
+    #[test]
+    fn mixed_workload_appends_on_every_tenth_operation() {
+        assert_eq!((0..100).filter(|seq| is_mixed_append(*seq)).count(), 10);
+        assert!(!is_mixed_append(0));
+        assert!(is_mixed_append(9));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn mixed_workload_reports_read_and_append_latency_separately() {
+        let seed_rows = (0..32)
+            .map(|id| NarrowSaleRow {
+                id,
+                ts_ms: id * 1_000,
+                region: "us".to_string(),
+                value_i64: Some(id),
+                flag: Some(id % 2 == 0),
+            })
+            .collect::<Vec<_>>();
+        let setup = prepare_mixed_sample(Arc::new(seed_rows), StorageConfig::local())
+            .await
+            .expect("seed mixed workload table");
+        let sample = execute_mixed_oltp_like(setup, Duration::from_secs(2))
+            .await
+            .expect("mixed workload should run");
+
+        assert!(sample.failure.is_none(), "{:?}", sample.failure);
+        let mixed = sample
+            .metrics
+            .mixed_workload
+            .as_ref()
+            .expect("mixed workload metrics should be present");
+        let contention = sample
+            .metrics
+            .contention
+            .as_ref()
+            .expect("contention metrics should be present");
+        assert!(mixed.appends.ops > 0, "no append completed in the window");
+        assert!(mixed.reads.ops >= 9 * mixed.appends.ops);
+        assert_eq!(
+            mixed.reads.ops + mixed.appends.ops,
+            contention.ops_attempted
+        );
+        assert!(mixed.reads.p50_ms <= mixed.reads.p99_ms);
+        assert!(mixed.appends.p99_ms <= mixed.appends.max_ms);
+        assert_eq!(
+            sample.metrics.rows_processed,
+            Some(mixed.appends.ops * MIXED_OLTP_APPEND_ROWS as u64)
+        );
+        // Version 0 creates the table and version 1 seeds it; each append lands one more.
+        assert_eq!(sample.metrics.table_version, Some(1 + mixed.appends.ops));
+    }
+
     #[test]
     fn sample_execution_keeps_contention_metrics_attached() {
         let sample = aggregate_sample_execution(
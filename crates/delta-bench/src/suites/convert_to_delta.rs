@@ -0,0 +1,202 @@
+use std::fs;
+use std::path::Path;
+
+use deltalake_core::kernel::{DataType, PrimitiveType, StructField};
+use deltalake_core::operations::convert_to_delta::ConvertToDeltaBuilder;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::fixture_copy::FixtureCopyStrategy;
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::BenchmarkLane;
+use crate::data::fixtures::raw_parquet_partitioned_path;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
+
+const CONVERT_TO_DELTA_PARTITIONED_CASE: &str = "convert_to_delta_partitioned";
+const PARTITION_COLUMN: &str = "region";
+
+struct ConvertIterationSetup {
+    _temp: tempfile::TempDir,
+    table_url: Url,
+    parquet_files: u64,
+}
+
+pub fn case_names() -> Vec<String> {
+    vec![CONVERT_TO_DELTA_PARTITIONED_CASE.to_string()]
+}
+
+pub struct ConvertToDeltaSuite;
+
+impl Suite for ConvertToDeltaSuite {
+    fn name(&self) -> &'static str {
+        "convert_to_delta"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    lane: BenchmarkLane,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "convert_to_delta suite does not support non-local storage backend yet",
+        ));
+    }
+
+    let source = raw_parquet_partitioned_path(fixtures_dir, scale);
+    if !source.is_dir() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing raw Parquet fixture directory; run bench data first",
+        ));
+    }
+
+    let fixture_copy = storage.fixture_copy();
+    let c =
+        run_case_async_with_async_setup(
+            CONVERT_TO_DELTA_PARTITIONED_CASE,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let source = source.clone();
+                async move {
+                    prepare_convert_iteration(&source, fixture_copy).map_err(|e| e.to_string())
+                }
+            },
+            |setup| async move {
+                let _keep_temp = setup._temp;
+                run_convert_case(setup.table_url, setup.parquet_files, lane)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+        )
+        .await;
+
+    Ok(vec![into_case_result(c)])
+}
+
+/// Each iteration converts a fresh copy, since a converted directory already has a `_delta_log`.
+fn prepare_convert_iteration(
+    source: &Path,
+    fixture_copy: FixtureCopyStrategy,
+) -> BenchResult<ConvertIterationSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("table");
+    copy_dir_with(source, &table_dir, fixture_copy)?;
+    let parquet_files = count_parquet_files(&table_dir)?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    Ok(ConvertIterationSetup {
+        _temp: temp,
+        table_url,
+        parquet_files,
+    })
+}
+
+async fn run_convert_case(
+    table_url: Url,
+    parquet_files: u64,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
+    let table = ConvertToDeltaBuilder::new()
+        .with_location(table_url.to_string())
+        .with_partition_schema(vec![StructField::new(
+            PARTITION_COLUMN,
+            DataType::Primitive(PrimitiveType::String),
+            true,
+        )])
+        .await?;
+
+    let files_registered = table.snapshot()?.log_data().num_files() as u64;
+    if files_registered != parquet_files {
+        return Err(BenchError::InvalidArgument(format!(
+            "{CONVERT_TO_DELTA_PARTITIONED_CASE}: registered {files_registered} files but the source directory holds {parquet_files}"
+        )));
+    }
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let result_hash = hash_json(&json!({
+        "operation": CONVERT_TO_DELTA_PARTITIONED_CASE,
+        "files_registered": files_registered,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "files_registered:u64",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        let validation = validate_table_state(&table).await?;
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+
+    Ok(
+        SampleMetrics::base(None, None, Some(1), table_version).with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: Some(files_registered),
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest,
+            validation_summary,
+        }),
+    )
+}
+
+fn count_parquet_files(dir: &Path) -> BenchResult<u64> {
+    let mut count = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            count += count_parquet_files(&path)?;
+        } else if path.extension().is_some_and(|ext| ext == "parquet") {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
@@ -0,0 +1,348 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use deltalake_core::arrow::array::{Int32Array, Int64Array};
+use deltalake_core::arrow::datatypes::{DataType, Field, Schema};
+use deltalake_core::arrow::record_batch::RecordBatch;
+use deltalake_core::datafusion::prelude::SessionContext;
+use deltalake_core::protocol::SaveMode;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde_json::json;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use url::Url;
+
+use super::{fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup_custom_timing, CaseHooks, CaseSeeds};
+use crate::storage::StorageConfig;
+use crate::version_compat::optional_table_version_to_u64;
+
+const KILL_WRITER_CASE: &str = "crash_kill_writer_mid_commit";
+const CRASH_WRITER_BIN_ENV: &str = "DELTA_BENCH_CRASH_WRITER_BIN";
+/// Line the writer prints once its table is open, so the kill delay excludes process startup.
+const CRASH_WRITER_READY_LINE: &str = "crash-writer ready";
+const SEED_ROWS: usize = 4_096;
+const APPEND_ROWS: usize = 4_096;
+/// Each append spreads its rows over this many partitions, so every commit follows several
+/// data-file writes and a kill is likely to land between files and the log entry.
+const APPEND_PARTITIONS: usize = 8;
+const KILL_DELAY_MS: std::ops::RangeInclusive<u64> = 5..=250;
+const WRITER_READY_TIMEOUT: Duration = Duration::from_secs(30);
+/// Upper bound on writer appends so an orphaned writer cannot run forever.
+pub const DEFAULT_CRASH_WRITER_APPENDS: u32 = 1_000;
+
+pub fn case_names() -> Vec<String> {
+    vec![KILL_WRITER_CASE.to_string()]
+}
+
+pub struct CrashRecoverySuite;
+
+impl Suite for CrashRecoverySuite {
+    fn name(&self) -> &'static str {
+        "crash_recovery"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run_with_seeds(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.seeds,
+        ))
+    }
+}
+
+struct CrashIterationSetup {
+    _temp: tempfile::TempDir,
+    table_dir: PathBuf,
+    kill_delay: Duration,
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    run_with_seeds(
+        fixtures_dir,
+        scale,
+        warmup,
+        iterations,
+        hooks,
+        storage,
+        &CaseSeeds::default(),
+    )
+    .await
+}
+
+/// Kill delays are drawn from the case's seed, so a replay with the recorded seed kills the
+/// writer at the same points.
+pub async fn run_with_seeds(
+    _fixtures_dir: &Path,
+    _scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    seeds: &CaseSeeds,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "crash_recovery suite does not support non-local storage backend yet",
+        ));
+    }
+    let writer_bin = match crash_writer_bin() {
+        Ok(bin) => bin,
+        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    };
+
+    let mut rng = ChaCha8Rng::seed_from_u64(seeds.for_case(KILL_WRITER_CASE));
+    let c = run_case_async_with_async_setup_custom_timing(
+        KILL_WRITER_CASE,
+        warmup,
+        iterations,
+        hooks,
+        || {
+            let kill_delay = Duration::from_millis(rng.gen_range(KILL_DELAY_MS));
+            async move {
+                prepare_crash_iteration(kill_delay)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+        |setup| {
+            let writer_bin = writer_bin.clone();
+            async move {
+                run_kill_writer_case(setup, &writer_bin)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+    )
+    .await;
+
+    Ok(vec![into_case_result(c)])
+}
+
+/// The writer re-executes the running `delta-bench` binary unless overridden, e.g. by tests
+/// whose `current_exe` is the test harness.
+fn crash_writer_bin() -> BenchResult<PathBuf> {
+    match std::env::var_os(CRASH_WRITER_BIN_ENV) {
+        Some(bin) => Ok(PathBuf::from(bin)),
+        None => Ok(std::env::current_exe()?),
+    }
+}
+
+async fn prepare_crash_iteration(kill_delay: Duration) -> BenchResult<CrashIterationSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("table");
+    std::fs::create_dir_all(&table_dir)?;
+    DeltaTable::try_from_url(table_dir_url(&table_dir)?)
+        .await?
+        .write(vec![crash_append_batch(0..SEED_ROWS)?])
+        .with_save_mode(SaveMode::Overwrite)
+        .with_partition_columns(["part"])
+        .await?;
+    Ok(CrashIterationSetup {
+        _temp: temp,
+        table_dir,
+        kill_delay,
+    })
+}
+
+/// Kills the writer after the iteration's randomized delay, then times reopening the table and
+/// reading every row. Only that recovery is reported as elapsed time. The recovered table must
+/// land on a committed version whose row count matches the commits that made it into the log.
+async fn run_kill_writer_case(
+    setup: CrashIterationSetup,
+    writer_bin: &Path,
+) -> BenchResult<(SampleMetrics, Option<f64>)> {
+    let _keep_temp = setup._temp;
+    kill_writer_after(writer_bin, &setup.table_dir, setup.kill_delay).await?;
+
+    let recovery_start = Instant::now();
+    let table = StorageConfig::local()
+        .open_table(table_dir_url(&setup.table_dir)?)
+        .await?;
+    let ctx = SessionContext::new();
+    ctx.register_table("bench", table.table_provider().await?)?;
+    let rows = ctx
+        .sql("SELECT * FROM bench")
+        .await?
+        .collect()
+        .await?
+        .iter()
+        .map(|batch| batch.num_rows() as u64)
+        .sum::<u64>();
+    let recovery_elapsed = recovery_start.elapsed();
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let version = table_version.ok_or_else(|| {
+        BenchError::InvalidArgument(format!(
+            "{KILL_WRITER_CASE}: table has no version after writer crash"
+        ))
+    })?;
+    let expected_rows = (SEED_ROWS as u64) + version * (APPEND_ROWS as u64);
+    if rows != expected_rows {
+        return Err(BenchError::InvalidArgument(format!(
+            "{KILL_WRITER_CASE}: recovered version {version} reads {rows} rows but its commits account for {expected_rows}"
+        )));
+    }
+
+    let result_hash = hash_json(&json!({
+        "operation": KILL_WRITER_CASE,
+        "recovered_consistent": true,
+        "seed_rows": SEED_ROWS as u64,
+        "append_rows": APPEND_ROWS as u64,
+    }))?;
+    let schema_hash = hash_json(&json!([
+        "operation:string",
+        "recovered_consistent:bool",
+        "seed_rows:u64",
+        "append_rows:u64",
+    ]))?;
+
+    let metrics = SampleMetrics::base(Some(rows), None, Some(1), table_version).with_runtime_io(
+        RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: Some(table.snapshot()?.log_data().num_files() as u64),
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest: None,
+            validation_summary: None,
+        },
+    );
+    Ok((metrics, Some(recovery_elapsed.as_secs_f64() * 1000.0)))
+}
+
+async fn kill_writer_after(
+    writer_bin: &Path,
+    table_dir: &Path,
+    delay: Duration,
+) -> BenchResult<()> {
+    let mut command = tokio::process::Command::new(writer_bin);
+    command.kill_on_drop(true);
+    command
+        .env_remove("DELTA_BENCH_BACKEND_PROFILE")
+        .arg("--storage-backend")
+        .arg("local")
+        .arg("crash-writer")
+        .arg("--table-dir")
+        .arg(table_dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+    let mut child = command.spawn()?;
+
+    let stdout = child.stdout.take().ok_or_else(|| {
+        BenchError::InvalidArgument("crash writer stdout was not captured".to_string())
+    })?;
+    let mut lines = BufReader::new(stdout).lines();
+    let ready = tokio::time::timeout(WRITER_READY_TIMEOUT, async {
+        while let Some(line) = lines.next_line().await? {
+            if line.trim() == CRASH_WRITER_READY_LINE {
+                return Ok(true);
+            }
+        }
+        Ok::<_, std::io::Error>(false)
+    })
+    .await;
+    if !matches!(ready, Ok(Ok(true))) {
+        return Err(BenchError::InvalidArgument(format!(
+            "crash writer did not report ready within {} s",
+            WRITER_READY_TIMEOUT.as_secs()
+        )));
+    }
+
+    tokio::time::sleep(delay).await;
+    if let Some(status) = child.try_wait()? {
+        return Err(BenchError::InvalidArgument(format!(
+            "crash writer exited before it could be killed: {status}"
+        )));
+    }
+    child.kill().await?;
+    Ok(())
+}
+
+/// Body of the hidden `crash-writer` subcommand: appends to `table_dir` until killed or until
+/// `appends` commits have landed.
+pub async fn run_crash_writer(table_dir: &Path, appends: u32) -> BenchResult<()> {
+    let mut table = StorageConfig::local()
+        .open_table(table_dir_url(table_dir)?)
+        .await?;
+    let mut next_id = SEED_ROWS;
+    {
+        let mut stdout = std::io::stdout().lock();
+        writeln!(stdout, "{CRASH_WRITER_READY_LINE}")?;
+        stdout.flush()?;
+    }
+    for _ in 0..appends {
+        table = table
+            .write(vec![crash_append_batch(next_id..next_id + APPEND_ROWS)?])
+            .with_save_mode(SaveMode::Append)
+            .with_partition_columns(["part"])
+            .await?;
+        next_id += APPEND_ROWS;
+    }
+    Ok(())
+}
+
+fn table_dir_url(table_dir: &Path) -> BenchResult<Url> {
+    Url::from_directory_path(table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })
+}
+
+fn crash_append_batch(ids: std::ops::Range<usize>) -> BenchResult<RecordBatch> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("value_i64", DataType::Int64, false),
+        Field::new("part", DataType::Int32, false),
+    ]));
+    let id_values = ids.clone().map(|id| id as i64).collect::<Vec<_>>();
+    let values = ids
+        .clone()
+        .map(|id| ((id as i64 * 31) % 10_000) - 5_000)
+        .collect::<Vec<_>>();
+    let parts = ids
+        .map(|id| (id % APPEND_PARTITIONS) as i32)
+        .collect::<Vec<_>>();
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(id_values)),
+            Arc::new(Int64Array::from(values)),
+            Arc::new(Int32Array::from(parts)),
+        ],
+    )
+    .map_err(Into::into)
+}
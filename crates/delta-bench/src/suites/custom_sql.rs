@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use futures::future::LocalBoxFuture;
+use serde::Deserialize;
+use serde_json::json;
+
+use super::tpcds::registration::TableUrlResolver;
+use super::tpcds::{open_loop_config, run_sql_query_case};
+use super::{Suite, SuiteOptions, SuiteRunArgs};
+use crate::cli::TimingPhase;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::{hash_bytes, hash_json};
+use crate::manifests::validate_case_id;
+use crate::results::CaseResult;
+use crate::runner::{CaseHooks, OpenLoopConfig};
+use crate::storage::StorageConfig;
+
+pub const CUSTOM_SQL_TABLES_FILE: &str = "tables.yaml";
+const CUSTOM_SQL_CASE_PREFIX: &str = "custom_sql_";
+
+/// `tables.yaml`: table names as referenced by the queries, mapped to Delta table paths.
+/// Relative paths resolve under `<fixtures>/<scale>/`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CustomSqlTablesFile {
+    tables: BTreeMap<String, PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomSqlQuery {
+    pub case: String,
+    pub sql: String,
+}
+
+/// A user query directory: one case per `.sql` file, in file name order.
+#[derive(Clone, Debug)]
+pub struct CustomSqlWorkload {
+    tables: BTreeMap<String, PathBuf>,
+    pub queries: Vec<CustomSqlQuery>,
+}
+
+impl CustomSqlWorkload {
+    pub fn load(dir: &Path) -> BenchResult<Self> {
+        let tables_path = dir.join(CUSTOM_SQL_TABLES_FILE);
+        let bytes = std::fs::read(&tables_path).map_err(|error| {
+            BenchError::InvalidArgument(format!(
+                "failed to read custom SQL table mapping '{}': {error}",
+                tables_path.display()
+            ))
+        })?;
+        let mapping = serde_yaml::from_slice::<CustomSqlTablesFile>(&bytes).map_err(|error| {
+            BenchError::InvalidArgument(format!(
+                "invalid custom SQL table mapping '{}': {error}",
+                tables_path.display()
+            ))
+        })?;
+        let mut tables = BTreeMap::new();
+        for (name, path) in mapping.tables {
+            // Registration matches query table references case-insensitively.
+            if tables.insert(name.to_ascii_lowercase(), path).is_some() {
+                return Err(BenchError::InvalidArgument(format!(
+                    "{CUSTOM_SQL_TABLES_FILE} maps table '{name}' more than once"
+                )));
+            }
+        }
+
+        let mut sql_paths = std::fs::read_dir(dir)
+            .map_err(|error| {
+                BenchError::InvalidArgument(format!(
+                    "failed to read custom SQL directory '{}': {error}",
+                    dir.display()
+                ))
+            })?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        sql_paths.retain(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "sql"));
+        sql_paths.sort();
+
+        let mut queries = Vec::with_capacity(sql_paths.len());
+        for path in sql_paths {
+            let stem = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default();
+            let case = format!("{CUSTOM_SQL_CASE_PREFIX}{stem}");
+            validate_case_id(&case).map_err(|error| {
+                BenchError::InvalidArgument(format!(
+                    "custom SQL file '{}' does not map to a valid case id: {error}",
+                    path.display()
+                ))
+            })?;
+            let sql = std::fs::read_to_string(&path)?;
+            if sql.trim().is_empty() {
+                return Err(BenchError::InvalidArgument(format!(
+                    "custom SQL file '{}' is empty",
+                    path.display()
+                )));
+            }
+            queries.push(CustomSqlQuery { case, sql });
+        }
+        if queries.is_empty() {
+            return Err(BenchError::InvalidArgument(format!(
+                "custom SQL directory '{}' contains no .sql files",
+                dir.display()
+            )));
+        }
+
+        Ok(Self { tables, queries })
+    }
+
+    /// Loads [`SuiteOptions::custom_sql_dir`], which the `custom_sql` target requires.
+    pub fn from_options(options: &SuiteOptions) -> BenchResult<Self> {
+        let dir = options.custom_sql_dir.as_deref().ok_or_else(|| {
+            BenchError::InvalidArgument(format!(
+                "target custom_sql requires --custom-sql-dir, a directory of .sql files and {CUSTOM_SQL_TABLES_FILE}"
+            ))
+        })?;
+        Self::load(dir)
+    }
+
+    pub fn case_names(&self) -> Vec<String> {
+        self.queries
+            .iter()
+            .map(|query| query.case.clone())
+            .collect()
+    }
+
+    /// Fingerprint of the table mapping and every query. Stands in for the manifest hash, so
+    /// results from different query sets never compare as the same suite definition.
+    pub fn definition_hash(&self) -> BenchResult<String> {
+        hash_json(&json!({
+            "tables": self.tables,
+            "queries": self
+                .queries
+                .iter()
+                .map(|query| (query.case.clone(), hash_bytes(query.sql.as_bytes())))
+                .collect::<BTreeMap<_, _>>(),
+        }))
+    }
+
+    fn table_resolver(
+        &self,
+        fixtures_dir: &Path,
+        scale: &str,
+        storage: &StorageConfig,
+    ) -> TableUrlResolver {
+        let tables = self.tables.clone();
+        let fixture_root = fixtures_dir.join(scale);
+        let scale = scale.to_string();
+        let storage = storage.clone();
+        Arc::new(move |table_name| {
+            let path = tables.get(table_name).ok_or_else(|| {
+                BenchError::InvalidArgument(format!(
+                    "query references table '{table_name}' which {CUSTOM_SQL_TABLES_FILE} does not map"
+                ))
+            })?;
+            if path.is_absolute() && !storage.is_local() {
+                return Err(BenchError::InvalidArgument(format!(
+                    "table '{table_name}' maps to absolute path '{}', which only local storage supports",
+                    path.display()
+                )));
+            }
+            let remote_table_name = path.to_string_lossy().replace('\\', "/");
+            storage.table_url_for(&fixture_root.join(path), &scale, &remote_table_name)
+        })
+    }
+}
+
+pub struct CustomSqlSuite;
+
+impl Suite for CustomSqlSuite {
+    fn name(&self) -> &'static str {
+        "custom_sql"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Err(BenchError::InvalidArgument(
+            "custom_sql cases come from the --custom-sql-dir query directory".to_string(),
+        ))
+    }
+
+    fn phase_aware(&self) -> bool {
+        true
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.timing_phase,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.options,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    timing_phase: TimingPhase,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    options: &SuiteOptions,
+) -> BenchResult<Vec<CaseResult>> {
+    let workload = CustomSqlWorkload::from_options(options)?;
+    run_workload(
+        &workload,
+        fixtures_dir,
+        scale,
+        timing_phase,
+        warmup,
+        iterations,
+        hooks,
+        storage,
+        open_loop_config(options, timing_phase)?,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn run_workload(
+    workload: &CustomSqlWorkload,
+    fixtures_dir: &Path,
+    scale: &str,
+    timing_phase: TimingPhase,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    load: Option<OpenLoopConfig>,
+) -> BenchResult<Vec<CaseResult>> {
+    let resolve = workload.table_resolver(fixtures_dir, scale, storage);
+    let mut out = Vec::with_capacity(workload.queries.len());
+    for query in &workload.queries {
+        out.push(
+            run_sql_query_case(
+                &query.case,
+                &query.sql,
+                timing_phase,
+                warmup,
+                iterations,
+                hooks,
+                storage,
+                &resolve,
+                load,
+            )
+            .await,
+        );
+    }
+    Ok(out)
+}
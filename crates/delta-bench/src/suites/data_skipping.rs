@@ -0,0 +1,235 @@
+use std::path::Path;
+
+use futures::future::LocalBoxFuture;
+use serde::Serialize;
+use url::Url;
+
+use super::{fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::TimingPhase;
+use crate::data::fixtures::{
+    data_skipping_files_matching, data_skipping_table_path, data_skipping_table_url,
+    scale_to_row_count, DataSkippingCardinality, DATA_SKIPPING_FILE_COUNT,
+};
+use crate::error::{BenchError, BenchResult};
+use crate::results::{CaseResult, SampleMetrics};
+use crate::runner::{run_case_async_with_timing_phase, CaseHooks, PhaseTiming, TimedSample};
+use crate::storage::StorageConfig;
+use crate::suites::scan::{
+    execute_prepared_query, load_sql_query_context, plan_loaded_sql_query, validate_executed_query,
+};
+
+/// One equality probe against a cardinality column of `data_skipping_delta`.
+#[derive(Clone, Copy)]
+struct DataSkippingCase {
+    name: &'static str,
+    cardinality: DataSkippingCardinality,
+}
+
+const DATA_SKIPPING_CASES: [DataSkippingCase; 3] = [
+    DataSkippingCase {
+        name: "data_skipping_low_cardinality",
+        cardinality: DataSkippingCardinality::Low,
+    },
+    DataSkippingCase {
+        name: "data_skipping_medium_cardinality",
+        cardinality: DataSkippingCardinality::Medium,
+    },
+    DataSkippingCase {
+        name: "data_skipping_high_cardinality",
+        cardinality: DataSkippingCardinality::High,
+    },
+];
+
+impl DataSkippingCase {
+    fn sql(self, rows: usize) -> String {
+        format!(
+            "SELECT COUNT(*) FROM bench WHERE {} = {}",
+            self.cardinality.column(),
+            self.cardinality.probe_value(rows)
+        )
+    }
+
+    /// Files that still overlap the probe value; skipping that reads more than this regressed.
+    fn expected_files_scanned(self, rows: usize) -> u64 {
+        data_skipping_files_matching(rows, self.cardinality, self.cardinality.probe_value(rows))
+    }
+}
+
+/// Pruning outcome of one data skipping case, written next to the suite results.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PruningReportPoint {
+    pub case: String,
+    pub column: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_scanned: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_pruned: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_pruned_ratio: Option<f64>,
+}
+
+pub fn case_names() -> Vec<String> {
+    DATA_SKIPPING_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct DataSkippingSuite;
+
+impl Suite for DataSkippingSuite {
+    fn name(&self) -> &'static str {
+        "data_skipping"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+/// Collects the files-pruned ratio of every data skipping case from a run, low cardinality first.
+pub fn pruning_report(cases: &[CaseResult]) -> Vec<PruningReportPoint> {
+    DATA_SKIPPING_CASES
+        .iter()
+        .filter_map(|spec| {
+            let case = cases.iter().find(|case| case.case == spec.name)?;
+            let metrics = case
+                .samples
+                .first()
+                .and_then(|sample| sample.metrics.as_ref());
+            let files_scanned = metrics.and_then(|metrics| metrics.files_scanned);
+            let files_pruned = metrics.and_then(|metrics| metrics.files_pruned);
+            let files_pruned_ratio = match (files_scanned, files_pruned) {
+                (Some(scanned), Some(pruned)) if scanned + pruned > 0 => {
+                    Some(pruned as f64 / (scanned + pruned) as f64)
+                }
+                _ => None,
+            };
+            Some(PruningReportPoint {
+                case: case.case.clone(),
+                column: spec.cardinality.column().to_string(),
+                success: case.success,
+                files_scanned,
+                files_pruned,
+                files_pruned_ratio,
+            })
+        })
+        .collect()
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if storage.is_local()
+        && !data_skipping_table_path(fixtures_dir, scale)
+            .join("_delta_log")
+            .exists()
+    {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing data skipping fixture table; run bench data first",
+        ));
+    }
+
+    let table_url = data_skipping_table_url(fixtures_dir, scale, storage)?;
+    let rows = scale_to_row_count(scale)?;
+    let mut out = Vec::new();
+    for case in DATA_SKIPPING_CASES {
+        let sql = case.sql(rows);
+        let expected_files_scanned = case.expected_files_scanned(rows);
+        let c = run_case_async_with_timing_phase(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            TimingPhase::Execute,
+            || {
+                let storage = storage.clone();
+                let table_url = table_url.clone();
+                let sql = sql.clone();
+                async move {
+                    run_skipping_query(&storage, table_url, &sql, case, expected_files_scanned)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+async fn run_skipping_query(
+    storage: &StorageConfig,
+    table_url: Url,
+    sql: &str,
+    case: DataSkippingCase,
+    expected_files_scanned: u64,
+) -> BenchResult<TimedSample<SampleMetrics>> {
+    let load_start = std::time::Instant::now();
+    let loaded = load_sql_query_context(storage, table_url).await?;
+    let load_elapsed_ms = load_start.elapsed().as_secs_f64() * 1000.0;
+
+    let planning_start = std::time::Instant::now();
+    let prepared = plan_loaded_sql_query(loaded, sql).await?;
+    let planning_elapsed_ms = planning_start.elapsed().as_secs_f64() * 1000.0;
+
+    let executed = execute_prepared_query(prepared).await?;
+    let execution_elapsed_ms = executed.execution_elapsed_ms;
+
+    let (metrics, validate_elapsed_ms) = validate_executed_query(executed).await?;
+    check_files_scanned(case, &metrics, expected_files_scanned)?;
+
+    Ok(TimedSample::new(
+        metrics,
+        PhaseTiming::default()
+            .with_load_ms(load_elapsed_ms)
+            .with_plan_ms(planning_elapsed_ms)
+            .with_execute_ms(execution_elapsed_ms)
+            .with_validate_ms(validate_elapsed_ms),
+    ))
+}
+
+/// Fails the sample when statistics-based skipping reads more files than overlap the probe.
+fn check_files_scanned(
+    case: DataSkippingCase,
+    metrics: &SampleMetrics,
+    expected_files_scanned: u64,
+) -> BenchResult<()> {
+    let Some(files_scanned) = metrics.files_scanned else {
+        return Err(BenchError::InvalidArgument(format!(
+            "{}: scan plan reported no files_scanned metric, cannot check data skipping",
+            case.name
+        )));
+    };
+    if files_scanned > expected_files_scanned {
+        return Err(BenchError::InvalidArgument(format!(
+            "{}: data skipping regressed on {}: scanned {files_scanned} of {DATA_SKIPPING_FILE_COUNT} files, expected at most {expected_files_scanned}",
+            case.name,
+            case.cardinality.column()
+        )));
+    }
+    Ok(())
+}
@@ -5,18 +5,21 @@ use serde_json::json;
 use url::Url;
 
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 
-use super::{copy_dir_all, fixture_error_cases, into_case_result};
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
 use crate::data::fixtures::{
-    delete_update_small_files_table_path, load_rows, read_partitioned_table_path,
+    delete_update_small_files_table_path, read_partitioned_table_path,
     write_delta_table_partitioned_small_files,
 };
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
 use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
-use crate::runner::run_case_async_with_async_setup;
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
 use crate::storage::StorageConfig;
+use crate::trace::TraceOperation;
 use crate::validation::{lane_requires_semantic_validation, validate_table_state};
 use crate::version_compat::optional_table_version_to_u64;
 
@@ -101,13 +104,44 @@ pub fn case_names() -> Vec<String> {
         .collect()
 }
 
+pub struct DeleteUpdateSuite;
+
+impl Suite for DeleteUpdateSuite {
+    fn name(&self) -> &'static str {
+        "delete_update"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
 ) -> BenchResult<Vec<CaseResult>> {
     if storage.is_local() {
         let standard_source = read_partitioned_table_path(fixtures_dir, scale);
@@ -131,6 +165,7 @@ pub async fn run(
                 case.name,
                 warmup,
                 iterations,
+                hooks,
                 || {
                     let source = source.clone();
                     let storage = storage.clone();
@@ -154,8 +189,8 @@ pub async fn run(
         return Ok(out);
     }
 
-    let rows = match load_rows(fixtures_dir, scale) {
-        Ok(rows) => Arc::new(rows),
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
         Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
     };
 
@@ -165,6 +200,7 @@ pub async fn run(
             case.name,
             warmup,
             iterations,
+            hooks,
             || {
                 let storage = storage.clone();
                 let seed_rows = Arc::clone(&rows);
@@ -206,13 +242,13 @@ pub async fn run(
     Ok(out)
 }
 
-fn usize_metric_to_u64(name: &str, value: usize) -> BenchResult<u64> {
+pub(crate) fn usize_metric_to_u64(name: &str, value: usize) -> BenchResult<u64> {
     u64::try_from(value).map_err(|_| {
         BenchError::InvalidArgument(format!("{name} metric value does not fit in u64"))
     })
 }
 
-trait IntoOptionalRowCount {
+pub(crate) trait IntoOptionalRowCount {
     fn into_optional_row_count(self) -> Option<usize>;
 }
 
@@ -516,6 +552,18 @@ pub(crate) async fn run_delete_update_case(
     }
 }
 
+/// Delete cases as single-step replay traces. Updates have no trace equivalent.
+pub(crate) fn trace_operations() -> Vec<(&'static str, Vec<TraceOperation>)> {
+    DELETE_UPDATE_CASES
+        .iter()
+        .filter(|case| matches!(case.operation, DmlOperation::Delete))
+        .filter_map(|case| {
+            let predicate = case_predicate(*case)?;
+            Some((case.name, vec![TraceOperation::Delete { predicate }]))
+        })
+        .collect()
+}
+
 fn case_predicate(case: DeleteUpdateCase) -> Option<String> {
     let fraction = case.rows_matched_fraction?;
     let scatter_divisor = ((1.0 / fraction).round() as u64).max(1);
@@ -529,9 +577,17 @@ fn case_predicate(case: DeleteUpdateCase) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::delete_result_contract;
-    use crate::fingerprint::hash_json;
+    use deltalake_core::datafusion::prelude::SessionContext;
     use serde_json::json;
+    use url::Url;
+
+    use super::{delete_result_contract, run_delete_update_case, DeleteUpdateCase, DmlOperation};
+    use crate::cli::BenchmarkLane;
+    use crate::data::fixtures::write_delta_table;
+    use crate::data::generator::NarrowSalesRows;
+    use crate::fingerprint::{hash_json, hash_record_batch_schema};
+    use crate::storage::StorageConfig;
+    use crate::validation::validation_summary_rows;
 
     #[test]
     fn delete_result_contract_accepts_legacy_row_count_shape() {
@@ -621,6 +677,53 @@ mod tests {
             .expect("unknown schema hash")
         );
     }
+
+    #[tokio::test]
+    async fn correctness_delete_of_every_row_hashes_the_table_schema() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let storage = StorageConfig::local();
+        let table_url = Url::from_directory_path(temp.path().join("table")).expect("table url");
+        write_delta_table(table_url.clone(), NarrowSalesRows::new(42, 64), &storage)
+            .await
+            .expect("write table");
+        let table = storage.open_table(table_url).await.expect("open table");
+
+        let ctx = SessionContext::new();
+        ctx.register_table("bench", table.table_provider().await.expect("provider"))
+            .expect("register table");
+        let batches = ctx
+            .sql("SELECT * FROM bench")
+            .await
+            .expect("plan scan")
+            .collect()
+            .await
+            .expect("scan table");
+        let table_schema_hash = hash_record_batch_schema(&batches).expect("table schema hash");
+
+        let case = DeleteUpdateCase {
+            name: "delete_every_row",
+            operation: DmlOperation::Delete,
+            rows_matched_fraction: Some(1.0),
+            partition_localized: false,
+            small_files_seed: false,
+        };
+        let metrics = run_delete_update_case(table, case, BenchmarkLane::Correctness)
+            .await
+            .expect("delete every row");
+
+        assert_eq!(
+            metrics
+                .validation_summary
+                .as_deref()
+                .and_then(validation_summary_rows),
+            Some(0)
+        );
+        assert_eq!(metrics.schema_hash, Some(table_schema_hash));
+        assert_ne!(
+            metrics.schema_hash,
+            Some(hash_json(&Vec::<String>::new()).expect("empty schema hash"))
+        );
+    }
 }
 
 async fn prepare_iteration(
@@ -629,7 +732,7 @@ async fn prepare_iteration(
 ) -> BenchResult<IterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("table");
-    copy_dir_all(source_table_path, &table_dir)?;
+    copy_dir_with(source_table_path, &table_dir, storage.fixture_copy())?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
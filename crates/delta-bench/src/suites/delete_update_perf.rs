@@ -4,14 +4,16 @@ use std::time::Duration;
 use url::Url;
 
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 
 use super::delete_update::{run_delete_update_case, DeleteUpdateCase, DmlOperation};
-use super::{copy_dir_all, fixture_error_cases, into_case_result};
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
 use crate::data::fixtures::{delete_update_small_files_table_path, read_partitioned_table_path};
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
 use crate::results::CaseResult;
-use crate::runner::run_case_async_with_async_setup;
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
 use crate::storage::StorageConfig;
 
 const DELETE_UPDATE_PERF_DELAY_ENV: &str = "DELTA_BENCH_DELETE_UPDATE_PERF_DELAY_MS";
@@ -61,13 +63,44 @@ pub fn case_names() -> Vec<String> {
         .collect()
 }
 
+pub struct DeleteUpdatePerfSuite;
+
+impl Suite for DeleteUpdatePerfSuite {
+    fn name(&self) -> &'static str {
+        "delete_update_perf"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
 ) -> BenchResult<Vec<CaseResult>> {
     if storage.is_local() {
         let standard_source = read_partitioned_table_path(fixtures_dir, scale);
@@ -91,6 +124,7 @@ pub async fn run(
                 case.name,
                 warmup,
                 iterations,
+                hooks,
                 || {
                     let source = source.clone();
                     let storage = storage.clone();
@@ -117,8 +151,8 @@ pub async fn run(
         return Ok(out);
     }
 
-    let rows = match crate::data::fixtures::load_rows(fixtures_dir, scale) {
-        Ok(rows) => std::sync::Arc::new(rows),
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
         Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
     };
 
@@ -128,6 +162,7 @@ pub async fn run(
             case.name,
             warmup,
             iterations,
+            hooks,
             || {
                 let storage = storage.clone();
                 let seed_rows = std::sync::Arc::clone(&rows);
@@ -178,7 +213,7 @@ async fn prepare_iteration(
 ) -> BenchResult<IterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("table");
-    copy_dir_all(source_table_path, &table_dir)?;
+    copy_dir_with(source_table_path, &table_dir, storage.fixture_copy())?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
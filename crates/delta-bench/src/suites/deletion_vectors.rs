@@ -0,0 +1,335 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use deltalake_core::datafusion::logical_expr::col;
+use deltalake_core::datafusion::prelude::DataFrame;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::delete_update::{usize_metric_to_u64, IntoOptionalRowCount};
+use super::merge::{build_source_df, MergeMode};
+use super::{fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::BenchmarkLane;
+use crate::data::datasets::NarrowSaleRow;
+use crate::data::fixtures::{write_delta_table, write_delta_table_with_deletion_vectors};
+use crate::data::row_cache::FixtureRowCache;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
+
+/// Prefix for failures caused by missing deletion-vector support in delta-rs. Manifest
+/// `expected_error_contains` assertions match on it so those cases stay `expected_failure`
+/// until support lands.
+pub const DELETION_VECTORS_UNSUPPORTED: &str = "deletion vectors unsupported by delta-rs";
+
+const MATCHED_FRACTION: f64 = 0.05;
+
+#[derive(Clone, Copy)]
+enum DeletionVectorOperation {
+    Delete,
+    MergeDelete,
+}
+
+#[derive(Clone, Copy)]
+struct DeletionVectorCase {
+    name: &'static str,
+    operation: DeletionVectorOperation,
+    /// Seed the target with `delta.enableDeletionVectors=true` instead of copy-on-write.
+    deletion_vectors: bool,
+}
+
+const DELETION_VECTOR_CASES: [DeletionVectorCase; 4] = [
+    DeletionVectorCase {
+        name: "dv_delete_5pct_copy_on_write",
+        operation: DeletionVectorOperation::Delete,
+        deletion_vectors: false,
+    },
+    DeletionVectorCase {
+        name: "dv_delete_5pct_deletion_vectors",
+        operation: DeletionVectorOperation::Delete,
+        deletion_vectors: true,
+    },
+    DeletionVectorCase {
+        name: "dv_merge_delete_5pct_copy_on_write",
+        operation: DeletionVectorOperation::MergeDelete,
+        deletion_vectors: false,
+    },
+    DeletionVectorCase {
+        name: "dv_merge_delete_5pct_deletion_vectors",
+        operation: DeletionVectorOperation::MergeDelete,
+        deletion_vectors: true,
+    },
+];
+
+struct DmlOutcome {
+    table: DeltaTable,
+    operation: &'static str,
+    rows_affected: Option<usize>,
+    files_added: usize,
+    files_removed: usize,
+    scan_time_ms: u64,
+    rewrite_time_ms: u64,
+}
+
+struct DeletionVectorIterationSetup {
+    _temp: Option<tempfile::TempDir>,
+    table: DeltaTable,
+    source: Option<(DataFrame, usize)>,
+}
+
+pub fn case_names() -> Vec<String> {
+    DELETION_VECTOR_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct DeletionVectorsSuite;
+
+impl Suite for DeletionVectorsSuite {
+    fn name(&self) -> &'static str {
+        "deletion_vectors"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    lane: BenchmarkLane,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
+) -> BenchResult<Vec<CaseResult>> {
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
+        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    };
+
+    let mut out = Vec::new();
+    for case in DELETION_VECTOR_CASES {
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let rows = Arc::clone(&rows);
+                let storage = storage.clone();
+                async move {
+                    prepare_deletion_vector_iteration(rows.as_slice(), scale, case, &storage)
+                        .await
+                        .map_err(|e| classify_deletion_vector_error(case, e))
+                }
+            },
+            |setup| async move {
+                let _keep_temp = setup._temp;
+                run_deletion_vector_case(setup.table, setup.source, case, lane)
+                    .await
+                    .map_err(|e| classify_deletion_vector_error(case, e))
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+async fn prepare_deletion_vector_iteration(
+    rows: &[NarrowSaleRow],
+    scale: &str,
+    case: DeletionVectorCase,
+    storage: &StorageConfig,
+) -> BenchResult<DeletionVectorIterationSetup> {
+    let (temp, table_url) = if storage.is_local() {
+        let temp = tempfile::tempdir()?;
+        let table_dir = temp.path().join("target");
+        let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+            BenchError::InvalidArgument(format!(
+                "failed to create table URL for {}",
+                table_dir.display()
+            ))
+        })?;
+        (Some(temp), table_url)
+    } else {
+        let base_table_name = if case.deletion_vectors {
+            "deletion_vectors_delta"
+        } else {
+            "copy_on_write_delta"
+        };
+        let table_url = storage.isolated_table_url(scale, base_table_name, case.name)?;
+        (None, table_url)
+    };
+
+    if case.deletion_vectors {
+        write_delta_table_with_deletion_vectors(table_url.clone(), rows, storage).await?;
+    } else {
+        write_delta_table(table_url.clone(), rows, storage).await?;
+    }
+    let table = storage.open_table(table_url).await?;
+    let source = match case.operation {
+        DeletionVectorOperation::Delete => None,
+        DeletionVectorOperation::MergeDelete => Some(build_source_df(
+            rows,
+            MATCHED_FRACTION,
+            MergeMode::Delete,
+            None,
+        )?),
+    };
+
+    Ok(DeletionVectorIterationSetup {
+        _temp: temp,
+        table,
+        source,
+    })
+}
+
+async fn run_deletion_vector_case(
+    table: DeltaTable,
+    source: Option<(DataFrame, usize)>,
+    case: DeletionVectorCase,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
+    let outcome = match (case.operation, source) {
+        (DeletionVectorOperation::Delete, _) => {
+            let divisor = (1.0 / MATCHED_FRACTION).round() as u64;
+            let predicate = format!("id % {divisor} = 0");
+            let (table, metrics) = table.delete().with_predicate(predicate.as_str()).await?;
+            DmlOutcome {
+                table,
+                operation: "delete",
+                rows_affected: metrics.num_deleted_rows.into_optional_row_count(),
+                files_added: metrics.num_added_files,
+                files_removed: metrics.num_removed_files,
+                scan_time_ms: metrics.scan_time_ms,
+                rewrite_time_ms: metrics.rewrite_time_ms,
+            }
+        }
+        (DeletionVectorOperation::MergeDelete, Some((source, _source_rows))) => {
+            let (table, metrics) = table
+                .merge(source, col("target.id").eq(col("source.id")))
+                .with_source_alias("source")
+                .with_target_alias("target")
+                .when_matched_delete(|delete| delete)?
+                .await?;
+            DmlOutcome {
+                table,
+                operation: "merge_delete",
+                rows_affected: Some(metrics.num_target_rows_deleted),
+                files_added: metrics.num_target_files_added,
+                files_removed: metrics.num_target_files_removed,
+                scan_time_ms: metrics.scan_time_ms,
+                rewrite_time_ms: metrics.rewrite_time_ms,
+            }
+        }
+        (DeletionVectorOperation::MergeDelete, None) => {
+            return Err(BenchError::InvalidArgument(format!(
+                "missing merge source for {}",
+                case.name
+            )));
+        }
+    };
+    let DmlOutcome {
+        table,
+        operation,
+        rows_affected,
+        files_added,
+        files_removed,
+        scan_time_ms,
+        rewrite_time_ms,
+    } = outcome;
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let rows_affected = rows_affected
+        .map(|count| usize_metric_to_u64("rows_affected", count))
+        .transpose()?;
+    // File accounting differs between deletion vectors and copy-on-write, so the result
+    // contract only covers the logical outcome both strategies must agree on.
+    let result_hash = hash_json(&json!({
+        "operation": operation,
+        "rows_affected": rows_affected,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "rows_affected:optional<u64>",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        let validation = validate_table_state(&table).await?;
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+    let file_operations = files_added.checked_add(files_removed).ok_or_else(|| {
+        BenchError::InvalidArgument(format!("{operation} file operation count overflowed usize"))
+    })?;
+
+    Ok(SampleMetrics::base(
+        rows_affected,
+        None,
+        Some(usize_metric_to_u64("file_operations", file_operations)?),
+        table_version,
+    )
+    .with_scan_rewrite(ScanRewriteMetrics {
+        files_scanned: None,
+        files_pruned: None,
+        bytes_scanned: None,
+        scan_time_ms: Some(scan_time_ms),
+        rewrite_time_ms: Some(rewrite_time_ms),
+    })
+    .with_runtime_io(RuntimeIOMetrics {
+        peak_rss_mb: None,
+        cpu_time_ms: None,
+        bytes_read: None,
+        bytes_written: None,
+        files_touched: None,
+        files_skipped: None,
+        spill_bytes: None,
+        result_hash: Some(result_hash),
+        schema_hash: Some(schema_hash),
+        semantic_state_digest,
+        validation_summary,
+    }))
+}
+
+fn classify_deletion_vector_error(case: DeletionVectorCase, error: BenchError) -> String {
+    let message = error.to_string();
+    let normalized = message.to_ascii_lowercase().replace(['_', ' '], "");
+    if case.deletion_vectors && normalized.contains("deletionvector") {
+        format!("{DELETION_VECTORS_UNSUPPORTED}: {message}")
+    } else {
+        message
+    }
+}
@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::{BenchError, BenchResult};
+
+/// How [`copy_dir_with`] materializes each file of a fixture table copy. Reflinks and hard
+/// links make the copy cost independent of the table's size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FixtureCopyStrategy {
+    /// Reflink each file where the filesystem supports copy-on-write clones (`FICLONE` on
+    /// Linux, `clonefile` on macOS) and byte-copy it otherwise.
+    #[default]
+    Auto,
+    /// Reflink every file; fails on filesystems without copy-on-write clones.
+    Reflink,
+    /// Hard-link every file. Delta writers replace files rather than rewrite them, so the
+    /// copy can be changed without touching the fixture, but both must be on one filesystem.
+    Hardlink,
+    /// Byte-copy every file.
+    Copy,
+}
+
+impl FixtureCopyStrategy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Reflink => "reflink",
+            Self::Hardlink => "hardlink",
+            Self::Copy => "copy",
+        }
+    }
+
+    pub fn parse(raw: &str) -> BenchResult<Self> {
+        match raw.trim() {
+            "auto" => Ok(Self::Auto),
+            "reflink" => Ok(Self::Reflink),
+            "hardlink" => Ok(Self::Hardlink),
+            "copy" => Ok(Self::Copy),
+            other => Err(BenchError::InvalidArgument(format!(
+                "unknown fixture copy strategy '{other}'; expected auto, reflink, hardlink, or copy"
+            ))),
+        }
+    }
+
+    fn copy_file(self, from: &Path, to: &Path) -> BenchResult<()> {
+        match self {
+            Self::Auto => {
+                reflink_copy::reflink_or_copy(from, to)?;
+            }
+            Self::Reflink => reflink_copy::reflink(from, to).map_err(|e| {
+                BenchError::InvalidArgument(format!(
+                    "failed to reflink {} (use --fixture-copy auto to fall back to copying): {e}",
+                    from.display()
+                ))
+            })?,
+            Self::Hardlink => fs::hard_link(from, to).map_err(|e| {
+                BenchError::InvalidArgument(format!(
+                    "failed to hard-link {} (fixtures and temp dir must share a filesystem; use --fixture-copy auto to copy instead): {e}",
+                    from.display()
+                ))
+            })?,
+            Self::Copy => {
+                fs::copy(from, to)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Copies the fixture tree at `src` to `dst`, materializing each file with `strategy`.
+pub fn copy_dir_with(src: &Path, dst: &Path, strategy: FixtureCopyStrategy) -> BenchResult<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() {
+            return Err(BenchError::InvalidArgument(format!(
+                "symlinks are not allowed in fixture tree: {}",
+                entry.path().display()
+            )));
+        }
+        let to = dst.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_dir_with(&entry.path(), &to, strategy)?;
+        } else {
+            strategy.copy_file(&entry.path(), &to)?;
+        }
+    }
+    Ok(())
+}
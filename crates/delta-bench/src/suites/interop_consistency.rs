@@ -0,0 +1,124 @@
+//! Cross-validation of the Rust and Python lanes.
+//!
+//! A manifest case may name an `interop_workload`. When a `runner=all` run executes a Rust case
+//! and a Python case with the same workload, both must report the same `result_hash` and
+//! `rows_processed`; [`interop_consistency_case`] turns that comparison into one extra result.
+
+use std::collections::BTreeMap;
+
+use crate::results::{
+    CaseFailure, CaseResult, PerfStatus, SampleMetrics, FAILURE_KIND_ASSERTION_MISMATCH,
+};
+use crate::suites::PlannedCase;
+
+pub const INTEROP_CONSISTENCY_CASE: &str = "interop_consistency";
+
+/// Compares the planned cases that share an `interop_workload` across the Rust and Python
+/// lanes, every Rust case of a workload against every Python case of it. Returns `None` when no
+/// workload has a successful case in both lanes, since a failed or unsupported case is already
+/// reported on its own. A value either lane leaves unset is a mismatch.
+pub fn interop_consistency_case(
+    planned: &[PlannedCase],
+    cases: &[CaseResult],
+) -> Option<CaseResult> {
+    let mut lanes = BTreeMap::<&str, (Vec<&str>, Vec<&str>)>::new();
+    for plan in planned {
+        let Some(workload) = plan.interop_workload.as_deref() else {
+            continue;
+        };
+        let entry = lanes.entry(workload).or_default();
+        if plan.target == "interop_py" {
+            entry.1.push(plan.id.as_str());
+        } else {
+            entry.0.push(plan.id.as_str());
+        }
+    }
+
+    let mut compared = 0usize;
+    let mut mismatches = Vec::new();
+    for (workload, (rust_ids, python_ids)) in lanes {
+        for rust in rust_ids
+            .iter()
+            .filter_map(|id| comparable_metrics(cases, id))
+        {
+            for python in python_ids
+                .iter()
+                .filter_map(|id| comparable_metrics(cases, id))
+            {
+                compared += 1;
+                for (field, rust_value, python_value) in [
+                    (
+                        "result_hash",
+                        rust.1.result_hash.clone(),
+                        python.1.result_hash.clone(),
+                    ),
+                    (
+                        "rows_processed",
+                        rust.1.rows_processed.map(|rows| rows.to_string()),
+                        python.1.rows_processed.map(|rows| rows.to_string()),
+                    ),
+                ] {
+                    if rust_value.is_none() || rust_value != python_value {
+                        mismatches.push(format!(
+                            "workload '{workload}': {field} {} ({}) != {} ({})",
+                            rust_value.as_deref().unwrap_or("missing"),
+                            rust.0,
+                            python_value.as_deref().unwrap_or("missing"),
+                            python.0,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    if compared == 0 {
+        return None;
+    }
+
+    let passed = mismatches.is_empty();
+    Some(CaseResult {
+        case: INTEROP_CONSISTENCY_CASE.to_string(),
+        success: passed,
+        validation_passed: passed,
+        perf_status: if passed {
+            PerfStatus::ValidationOnly
+        } else {
+            PerfStatus::Invalid
+        },
+        classification: "supported".to_string(),
+        samples: Vec::new(),
+        elapsed_stats: None,
+        run_summary: None,
+        run_summaries: None,
+        suite_manifest_hash: None,
+        case_definition_hash: None,
+        compatibility_key: None,
+        supports_decision: None,
+        required_runs: None,
+        decision_threshold_pct: None,
+        decision_metric: None,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
+        failure_kind: (!passed).then(|| FAILURE_KIND_ASSERTION_MISMATCH.to_string()),
+        failure: (!passed).then(|| CaseFailure {
+            message: format!("Rust and Python lanes disagree: {}", mismatches.join("; ")),
+        }),
+    })
+}
+
+/// The first sample's metrics of case `id`, if it ran successfully and is supported.
+fn comparable_metrics<'a>(
+    cases: &'a [CaseResult],
+    id: &'a str,
+) -> Option<(&'a str, &'a SampleMetrics)> {
+    let case = cases
+        .iter()
+        .find(|case| case.case == id && case.success && case.classification == "supported")?;
+    Some((id, case.samples.first()?.metrics.as_ref()?))
+}
@@ -4,14 +4,16 @@ use std::path::PathBuf;
 use std::time::Duration;
 use std::time::Instant;
 
+use futures::future::LocalBoxFuture;
 use serde::Deserialize;
 use serde_json::Value;
 
+use super::{Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
 use crate::error::{BenchError, BenchResult};
 use crate::results::{
-    validate_case_classification, CaseFailure, CaseResult, ElapsedStats, IterationSample,
-    PerfStatus, RuntimeIOMetrics, SampleMetrics, FAILURE_KIND_EXECUTION_ERROR,
+    validate_case_classification, CaseFailure, CaseResult, ElapsedStats, InteropAttemptMetrics,
+    IterationSample, PerfStatus, RuntimeIOMetrics, SampleMetrics, FAILURE_KIND_EXECUTION_ERROR,
 };
 use crate::stats::compute_stats;
 use crate::storage::StorageConfig;
@@ -123,6 +125,32 @@ pub fn case_names() -> Vec<String> {
     CASES.iter().map(|case| (*case).to_string()).collect()
 }
 
+pub struct InteropPySuite;
+
+impl Suite for InteropPySuite {
+    fn name(&self) -> &'static str {
+        "interop_py"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.storage,
+        ))
+    }
+}
+
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
@@ -151,6 +179,14 @@ pub async fn run(
                 required_runs: None,
                 decision_threshold_pct: None,
                 decision_metric: None,
+                seed: None,
+                physical_plan: None,
+                plan_metrics_file: None,
+                plan_metrics: Vec::new(),
+                profile_file: None,
+                flamegraph: None,
+                heap_profile: None,
+                warnings: Vec::new(),
                 failure_kind: Some(FAILURE_KIND_EXECUTION_ERROR.to_string()),
                 failure: Some(CaseFailure {
                     message: "interop_py currently supports local backend only in P0".to_string(),
@@ -203,6 +239,14 @@ fn interop_dependency_mismatch_results(message: &str) -> Vec<CaseResult> {
             required_runs: None,
             decision_threshold_pct: None,
             decision_metric: None,
+            seed: None,
+            physical_plan: None,
+            plan_metrics_file: None,
+            plan_metrics: Vec::new(),
+            profile_file: None,
+            flamegraph: None,
+            heap_profile: None,
+            warnings: Vec::new(),
             failure_kind: Some(FAILURE_KIND_EXECUTION_ERROR.to_string()),
             failure: Some(CaseFailure {
                 message: message.to_string(),
@@ -418,7 +462,7 @@ async fn run_case(
     for _ in 0..iterations {
         let started = Instant::now();
         match run_python_case_with_runtime(case, fixtures_dir, scale, runtime, None).await {
-            Ok(output) => {
+            Ok((output, attempts)) => {
                 classification = output.classification.clone();
                 // Older runners may omit elapsed_ms; preserve the legacy wall-clock fallback.
                 let elapsed_ms = output
@@ -459,7 +503,8 @@ async fn run_case(
                     schema_hash: output.schema_hash,
                     semantic_state_digest,
                     validation_summary,
-                });
+                })
+                .with_interop_attempts(attempts);
                 samples.push(IterationSample {
                     elapsed_ms,
                     rows: metrics.rows_processed,
@@ -485,6 +530,14 @@ async fn run_case(
                     decision_threshold_pct: None,
                     decision_metric: None,
                     samples,
+                    seed: None,
+                    physical_plan: None,
+                    plan_metrics_file: None,
+                    plan_metrics: Vec::new(),
+                    profile_file: None,
+                    flamegraph: None,
+                    heap_profile: None,
+                    warnings: Vec::new(),
                     failure_kind: Some(FAILURE_KIND_EXECUTION_ERROR.to_string()),
                     failure: Some(CaseFailure {
                         message: error.to_string(),
@@ -511,6 +564,14 @@ async fn run_case(
         decision_threshold_pct: None,
         decision_metric: None,
         samples,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: None,
         failure: None,
     })
@@ -538,7 +599,7 @@ async fn run_python_case_with_runtime(
     scale: &str,
     runtime: &InteropRuntimeConfig,
     script_override: Option<&Path>,
-) -> BenchResult<InteropCaseOutput> {
+) -> BenchResult<(InteropCaseOutput, InteropAttemptMetrics)> {
     let script = match script_override {
         Some(path) => path.to_path_buf(),
         None => {
@@ -551,20 +612,39 @@ async fn run_python_case_with_runtime(
     };
 
     let max_attempts = runtime.retries.saturating_add(1);
+    let mut failures = Vec::new();
+    let mut retry_ms = 0.0;
     for attempt in 1..=max_attempts {
+        let started = Instant::now();
         match run_python_case_once(case, fixtures_dir, scale, runtime, &script).await {
-            Ok(output) => return Ok(output),
-            Err(_error) if attempt < max_attempts => continue,
+            Ok(output) => {
+                let attempts = InteropAttemptMetrics {
+                    attempts: u64::from(attempt),
+                    retry_ms,
+                    failures,
+                };
+                return Ok((output, attempts));
+            }
             Err(error) => {
-                return Err(BenchError::InvalidArgument(format!(
-                    "interop case '{case}' failed after {max_attempts} attempt(s): {error}"
-                )));
+                retry_ms += started.elapsed().as_secs_f64() * 1000.0;
+                failures.push(error.to_string());
             }
         }
     }
 
+    if failures.is_empty() {
+        return Err(BenchError::InvalidArgument(format!(
+            "interop case '{case}' did not execute any attempts"
+        )));
+    }
+    let reasons = failures
+        .iter()
+        .enumerate()
+        .map(|(idx, reason)| format!("attempt {}: {reason}", idx + 1))
+        .collect::<Vec<_>>();
     Err(BenchError::InvalidArgument(format!(
-        "interop case '{case}' did not execute any attempts"
+        "interop case '{case}' failed after {max_attempts} attempt(s) in {retry_ms:.1} ms: {}",
+        reasons.join("; ")
     )))
 }
 
@@ -686,6 +766,14 @@ printf '%s' '{"rows_processed":1,"bytes_processed":1,"operations":1,"classificat
             "process startup leaked into measured time: {} ms",
             case.samples[0].elapsed_ms
         );
+        let attempts = case.samples[0]
+            .metrics
+            .as_ref()
+            .and_then(|metrics| metrics.interop_attempts.as_ref())
+            .expect("interop attempt metrics");
+        assert_eq!(attempts.attempts, 1);
+        assert!(attempts.failures.is_empty());
+        assert_eq!(attempts.retry_ms, 0.0);
     }
 
     #[tokio::test]
@@ -748,7 +836,7 @@ print('{{"rows_processed":1,"bytes_processed":1,"operations":1,"classification":
             retries: 1,
             python_executable: "python3".to_string(),
         };
-        let out = run_python_case_with_runtime(
+        let (out, attempts) = run_python_case_with_runtime(
             "retry_case",
             temp.path(),
             "sf1",
@@ -758,6 +846,49 @@ print('{{"rows_processed":1,"bytes_processed":1,"operations":1,"classification":
         .await
         .expect("one retry should recover");
         assert_eq!(out.classification, "supported");
+        assert_eq!(attempts.attempts, 2);
+        assert_eq!(attempts.failures.len(), 1);
+        assert!(
+            attempts.failures[0].contains("first attempt fails"),
+            "{:?}",
+            attempts.failures
+        );
+        assert!(attempts.retry_ms > 0.0);
+    }
+
+    #[tokio::test]
+    async fn python_runtime_reports_every_failed_attempt() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let script = temp.path().join("always_fails.py");
+        fs::write(
+            &script,
+            r#"#!/usr/bin/env python3
+raise SystemExit("environment is flaky")
+"#,
+        )
+        .expect("write script");
+
+        let runtime = InteropRuntimeConfig {
+            timeout: Duration::from_secs(1),
+            retries: 1,
+            python_executable: "python3".to_string(),
+        };
+        let err = run_python_case_with_runtime(
+            "always_fails",
+            temp.path(),
+            "sf1",
+            &runtime,
+            Some(script.as_path()),
+        )
+        .await
+        .expect_err("every attempt fails")
+        .to_string();
+        assert!(err.contains("failed after 2 attempt(s)"), "{err}");
+        assert!(
+            err.contains("attempt 1: interop case 'always_fails' failed: environment is flaky"),
+            "{err}"
+        );
+        assert!(err.contains("attempt 2: "), "{err}");
     }
 
     #[tokio::test]
@@ -0,0 +1,298 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use deltalake_core::datafusion::logical_expr::col;
+use deltalake_core::datafusion::prelude::SessionContext;
+use deltalake_core::protocol::SaveMode;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::BenchmarkLane;
+use crate::data::datasets::NarrowSaleRow;
+use crate::data::fixtures::{
+    late_arriving_target_table_path, load_late_arriving_backfill_rows, rows_to_batch,
+};
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
+
+#[derive(Clone, Copy)]
+enum BackfillOperation {
+    /// Blind append of the backfill batch, leaving duplicates for downstream dedup.
+    Append,
+    /// CDC upsert: corrections update landed rows and late rows are inserted.
+    MergeUpsert,
+    /// Idempotent backfill that only inserts ids the target has not seen.
+    MergeInsertOnly,
+}
+
+#[derive(Clone, Copy)]
+struct LateArrivingCase {
+    name: &'static str,
+    operation: BackfillOperation,
+}
+
+const LATE_ARRIVING_CASES: [LateArrivingCase; 3] = [
+    LateArrivingCase {
+        name: "late_arriving_append_backfill",
+        operation: BackfillOperation::Append,
+    },
+    LateArrivingCase {
+        name: "late_arriving_merge_upsert_backfill",
+        operation: BackfillOperation::MergeUpsert,
+    },
+    LateArrivingCase {
+        name: "late_arriving_merge_insert_only_backfill",
+        operation: BackfillOperation::MergeInsertOnly,
+    },
+];
+
+struct LateArrivingIterationSetup {
+    _temp: tempfile::TempDir,
+    table: DeltaTable,
+}
+
+struct BackfillOutcome {
+    table: DeltaTable,
+    rows_inserted: u64,
+    rows_updated: u64,
+    files_scanned: Option<u64>,
+    files_pruned: Option<u64>,
+    scan_time_ms: Option<u64>,
+    rewrite_time_ms: Option<u64>,
+}
+
+pub fn case_names() -> Vec<String> {
+    LATE_ARRIVING_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct LateArrivingSuite;
+
+impl Suite for LateArrivingSuite {
+    fn name(&self) -> &'static str {
+        "late_arriving"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    lane: BenchmarkLane,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "late_arriving suite does not support non-local storage backend yet",
+        ));
+    }
+
+    let source = late_arriving_target_table_path(fixtures_dir, scale);
+    if !source.join("_delta_log").exists() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing late-arriving fixture table; run bench data --dataset-id late_arriving first",
+        ));
+    }
+    let backfill = match load_late_arriving_backfill_rows(fixtures_dir, scale) {
+        Ok(rows) => Arc::new(rows),
+        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    };
+
+    let mut out = Vec::new();
+    for case in LATE_ARRIVING_CASES {
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let source = source.clone();
+                let storage = storage.clone();
+                async move {
+                    prepare_late_arriving_iteration(&source, &storage)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+            |setup| {
+                let backfill = Arc::clone(&backfill);
+                async move {
+                    let _keep_temp = setup._temp;
+                    run_late_arriving_case(setup.table, backfill.as_slice(), case, lane)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+async fn prepare_late_arriving_iteration(
+    source: &Path,
+    storage: &StorageConfig,
+) -> BenchResult<LateArrivingIterationSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("target");
+    copy_dir_with(source, &table_dir, storage.fixture_copy())?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    let table = storage.open_table(table_url).await?;
+    Ok(LateArrivingIterationSetup { _temp: temp, table })
+}
+
+async fn run_late_arriving_case(
+    table: DeltaTable,
+    backfill: &[NarrowSaleRow],
+    case: LateArrivingCase,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
+    let outcome = match case.operation {
+        BackfillOperation::Append => {
+            let table = table
+                .write(vec![rows_to_batch(backfill)?])
+                .with_save_mode(SaveMode::Append)
+                .await?;
+            BackfillOutcome {
+                table,
+                rows_inserted: backfill.len() as u64,
+                rows_updated: 0,
+                files_scanned: None,
+                files_pruned: None,
+                scan_time_ms: None,
+                rewrite_time_ms: None,
+            }
+        }
+        BackfillOperation::MergeUpsert | BackfillOperation::MergeInsertOnly => {
+            let source = SessionContext::new().read_batch(rows_to_batch(backfill)?)?;
+            let mut merge = table
+                .merge(source, col("target.id").eq(col("source.id")))
+                .with_source_alias("source")
+                .with_target_alias("target");
+            if matches!(case.operation, BackfillOperation::MergeUpsert) {
+                merge = merge.when_matched_update(|update| {
+                    update
+                        .update("ts_ms", col("source.ts_ms"))
+                        .update("ts", col("source.ts"))
+                        .update("event_date", col("source.event_date"))
+                        .update("value_i64", col("source.value_i64"))
+                        .update("amount", col("source.amount"))
+                        .update("flag", col("source.flag"))
+                })?;
+            }
+            let (table, metrics) = merge
+                .when_not_matched_insert(|insert| {
+                    insert
+                        .set("id", col("source.id"))
+                        .set("ts_ms", col("source.ts_ms"))
+                        .set("region", col("source.region"))
+                        .set("value_i64", col("source.value_i64"))
+                        .set("flag", col("source.flag"))
+                        .set("ts", col("source.ts"))
+                        .set("event_date", col("source.event_date"))
+                        .set("amount", col("source.amount"))
+                })?
+                .await?;
+            BackfillOutcome {
+                table,
+                rows_inserted: metrics.num_target_rows_inserted as u64,
+                rows_updated: metrics.num_target_rows_updated as u64,
+                files_scanned: Some(metrics.num_target_files_scanned as u64),
+                files_pruned: Some(metrics.num_target_files_skipped_during_scan as u64),
+                scan_time_ms: Some(metrics.scan_time_ms),
+                rewrite_time_ms: Some(metrics.rewrite_time_ms),
+            }
+        }
+    };
+
+    let table_version = optional_table_version_to_u64(outcome.table.version())?;
+    // Files touched depend on how late rows overlap landed files, which is what the case
+    // measures; the result contract only pins the logical backfill outcome.
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "rows_inserted": outcome.rows_inserted,
+        "rows_updated": outcome.rows_updated,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "rows_inserted:u64",
+        "rows_updated:u64",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        let validation = validate_table_state(&outcome.table).await?;
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+
+    Ok(SampleMetrics::base(
+        Some(outcome.rows_inserted + outcome.rows_updated),
+        None,
+        Some(1),
+        table_version,
+    )
+    .with_scan_rewrite(ScanRewriteMetrics {
+        files_scanned: outcome.files_scanned,
+        files_pruned: outcome.files_pruned,
+        bytes_scanned: None,
+        scan_time_ms: outcome.scan_time_ms,
+        rewrite_time_ms: outcome.rewrite_time_ms,
+    })
+    .with_runtime_io(RuntimeIOMetrics {
+        peak_rss_mb: None,
+        cpu_time_ms: None,
+        bytes_read: None,
+        bytes_written: None,
+        files_touched: None,
+        files_skipped: None,
+        spill_bytes: None,
+        result_hash: Some(result_hash),
+        schema_hash: Some(schema_hash),
+        semantic_state_digest,
+        validation_summary,
+    }))
+}
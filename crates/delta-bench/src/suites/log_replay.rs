@@ -0,0 +1,304 @@
+use std::path::Path;
+
+use deltalake_core::arrow::record_batch::RecordBatch;
+use deltalake_core::kernel::Snapshot;
+use deltalake_core::logstore::LogStoreRef;
+use deltalake_core::DeltaTableConfig;
+use futures::future::LocalBoxFuture;
+use futures::TryStreamExt;
+use serde_json::json;
+use url::Url;
+
+use super::{fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::data::fixtures::{
+    metadata_checkpointed_table_path, metadata_checkpointed_table_url,
+    metadata_deep_history_table_path, metadata_deep_history_table_url,
+    metadata_uncheckpointed_table_path, metadata_uncheckpointed_table_url,
+};
+use crate::error::BenchResult;
+use crate::fingerprint::hash_json;
+use crate::log_reads::LogReadCounter;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::version_compat::table_version_to_u64;
+
+#[derive(Clone, Copy)]
+enum LogReplayFixture {
+    /// 24 appends with a checkpoint at head.
+    Checkpointed,
+    /// The same 24 appends replayed from JSON commits only.
+    Uncheckpointed,
+    /// 512 appends, checkpoint-free.
+    DeepHistory,
+}
+
+impl LogReplayFixture {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Checkpointed => "checkpointed",
+            Self::Uncheckpointed => "uncheckpointed",
+            Self::DeepHistory => "deep_history",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum LogReplayOperation {
+    /// Build the head snapshot (log segment listing plus protocol/metadata replay) without
+    /// materializing the file list.
+    SnapshotState,
+    /// Enumerate the active add actions of an already-built head snapshot.
+    EnumerateAdds,
+}
+
+#[derive(Clone, Copy)]
+struct LogReplayCase {
+    name: &'static str,
+    fixture: LogReplayFixture,
+    operation: LogReplayOperation,
+}
+
+const LOG_REPLAY_CASES: [LogReplayCase; 6] = [
+    LogReplayCase {
+        name: "log_replay_snapshot_checkpointed",
+        fixture: LogReplayFixture::Checkpointed,
+        operation: LogReplayOperation::SnapshotState,
+    },
+    LogReplayCase {
+        name: "log_replay_snapshot_uncheckpointed",
+        fixture: LogReplayFixture::Uncheckpointed,
+        operation: LogReplayOperation::SnapshotState,
+    },
+    LogReplayCase {
+        name: "log_replay_snapshot_deep_history",
+        fixture: LogReplayFixture::DeepHistory,
+        operation: LogReplayOperation::SnapshotState,
+    },
+    LogReplayCase {
+        name: "log_replay_enumerate_adds_checkpointed",
+        fixture: LogReplayFixture::Checkpointed,
+        operation: LogReplayOperation::EnumerateAdds,
+    },
+    LogReplayCase {
+        name: "log_replay_enumerate_adds_uncheckpointed",
+        fixture: LogReplayFixture::Uncheckpointed,
+        operation: LogReplayOperation::EnumerateAdds,
+    },
+    LogReplayCase {
+        name: "log_replay_enumerate_adds_deep_history",
+        fixture: LogReplayFixture::DeepHistory,
+        operation: LogReplayOperation::EnumerateAdds,
+    },
+];
+
+struct LogReplaySetup {
+    log_store: LogStoreRef,
+    /// Counts the log files the timed section reads; setup reads are discarded.
+    log_reads: LogReadCounter,
+    /// Pre-built head snapshot for add enumeration; `None` when the snapshot itself is timed.
+    snapshot: Option<Snapshot>,
+}
+
+pub fn case_names() -> Vec<String> {
+    LOG_REPLAY_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct LogReplaySuite;
+
+impl Suite for LogReplaySuite {
+    fn name(&self) -> &'static str {
+        "log_replay"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if storage.is_local() {
+        let required_sources = [
+            metadata_checkpointed_table_path(fixtures_dir, scale),
+            metadata_uncheckpointed_table_path(fixtures_dir, scale),
+            metadata_deep_history_table_path(fixtures_dir, scale),
+        ];
+        if required_sources
+            .iter()
+            .any(|path| !path.join("_delta_log").exists())
+        {
+            return Ok(fixture_error_cases(
+                case_names(),
+                "missing metadata history fixture tables; run bench data --dataset-id many_versions first",
+            ));
+        }
+    }
+
+    let mut out = Vec::new();
+    for case in LOG_REPLAY_CASES {
+        let table_url = source_table_url(fixtures_dir, scale, case.fixture, storage)?;
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let storage = storage.clone();
+                let table_url = table_url.clone();
+                async move {
+                    prepare_log_replay(&storage, table_url, case.operation)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+            |setup| async move {
+                run_log_replay_case(setup, case)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+/// Resolves the log store without loading the table, so the timed section starts from a cold
+/// snapshot rather than one the table handle already replayed.
+async fn prepare_log_replay(
+    storage: &StorageConfig,
+    table_url: Url,
+    operation: LogReplayOperation,
+) -> BenchResult<LogReplaySetup> {
+    let (table, log_reads) = storage.try_from_url_counting_log_reads(table_url).await?;
+    let log_store = table.log_store();
+    let snapshot = match operation {
+        LogReplayOperation::SnapshotState => None,
+        LogReplayOperation::EnumerateAdds => Some(fileless_head_snapshot(&log_store).await?),
+    };
+    log_reads.take();
+    Ok(LogReplaySetup {
+        log_store,
+        log_reads,
+        snapshot,
+    })
+}
+
+async fn fileless_head_snapshot(log_store: &LogStoreRef) -> BenchResult<Snapshot> {
+    Ok(Snapshot::try_new(
+        log_store.as_ref(),
+        DeltaTableConfig {
+            require_files: false,
+            ..Default::default()
+        },
+        None,
+    )
+    .await?)
+}
+
+async fn run_log_replay_case(
+    setup: LogReplaySetup,
+    case: LogReplayCase,
+) -> BenchResult<SampleMetrics> {
+    let (table_version, add_actions) = match setup.snapshot {
+        None => {
+            let snapshot = fileless_head_snapshot(&setup.log_store).await?;
+            (table_version_to_u64(snapshot.version())?, None)
+        }
+        Some(snapshot) => {
+            let files = snapshot
+                .files(setup.log_store.as_ref(), None)
+                .try_collect::<Vec<RecordBatch>>()
+                .await?;
+            let add_actions = files.iter().map(|batch| batch.num_rows() as u64).sum();
+            (table_version_to_u64(snapshot.version())?, Some(add_actions))
+        }
+    };
+    let log_reads = setup.log_reads.take();
+
+    let (result_hash, schema_hash) = match add_actions {
+        Some(add_actions) => (
+            hash_json(&json!({
+                "operation": case.name,
+                "fixture": case.fixture.name(),
+                "table_version": table_version,
+                "add_actions": add_actions,
+            }))?,
+            hash_json(&json!([
+                "operation:string",
+                "table_version:u64",
+                "add_actions:u64",
+            ]))?,
+        ),
+        None => (
+            hash_json(&json!({
+                "operation": case.name,
+                "fixture": case.fixture.name(),
+                "table_version": table_version,
+            }))?,
+            hash_json(&json!(["operation:string", "table_version:u64"]))?,
+        ),
+    };
+
+    Ok(
+        SampleMetrics::base(None, None, Some(1), Some(table_version))
+            .with_log_reads(log_reads)
+            .with_runtime_io(RuntimeIOMetrics {
+                peak_rss_mb: None,
+                cpu_time_ms: None,
+                bytes_read: None,
+                bytes_written: None,
+                files_touched: add_actions,
+                files_skipped: None,
+                spill_bytes: None,
+                result_hash: Some(result_hash),
+                schema_hash: Some(schema_hash),
+                semantic_state_digest: None,
+                validation_summary: None,
+            }),
+    )
+}
+
+fn source_table_url(
+    fixtures_dir: &Path,
+    scale: &str,
+    fixture: LogReplayFixture,
+    storage: &StorageConfig,
+) -> BenchResult<Url> {
+    match fixture {
+        LogReplayFixture::Checkpointed => {
+            metadata_checkpointed_table_url(fixtures_dir, scale, storage)
+        }
+        LogReplayFixture::Uncheckpointed => {
+            metadata_uncheckpointed_table_url(fixtures_dir, scale, storage)
+        }
+        LogReplayFixture::DeepHistory => {
+            metadata_deep_history_table_url(fixtures_dir, scale, storage)
+        }
+    }
+}
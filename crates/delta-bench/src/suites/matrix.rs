@@ -0,0 +1,32 @@
+use std::collections::BTreeMap;
+
+use crate::manifests::MatrixParameters;
+
+/// A suite case a manifest `matrix:` can sweep, and the parameters it accepts.
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixTemplate {
+    pub name: &'static str,
+    /// The built-in case each point runs, with the point's parameters swapped in.
+    pub base_case: &'static str,
+    pub parameters: &'static [&'static str],
+}
+
+/// A planned case generated from a manifest `matrix:`: the suite template it instantiates
+/// and the parameter values it runs with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixPoint {
+    pub template: String,
+    pub parameters: MatrixParameters,
+}
+
+/// The points of `template` among `points` (keyed by generated case id), in case id order.
+pub(crate) fn matrix_points_for(
+    points: &BTreeMap<String, MatrixPoint>,
+    template: &str,
+) -> Vec<(String, MatrixPoint)> {
+    points
+        .iter()
+        .filter(|(_, point)| point.template == template)
+        .map(|(id, point)| (id.clone(), point.clone()))
+        .collect()
+}
@@ -1,25 +1,33 @@
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 
+use deltalake_core::arrow::array::{Int64Array, StringArray};
+use deltalake_core::arrow::datatypes::{DataType, Field, Schema};
+use deltalake_core::arrow::record_batch::RecordBatch;
 use deltalake_core::datafusion::logical_expr::col;
 use deltalake_core::datafusion::prelude::{DataFrame, SessionContext};
 use serde_json::json;
 use url::Url;
 
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 
-use super::{copy_dir_all, fixture_error_cases, into_case_result};
+use super::matrix::{matrix_points_for, MatrixPoint, MatrixTemplate};
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
 use crate::data::datasets::NarrowSaleRow;
 use crate::data::fixtures::{
-    load_rows, merge_partitioned_target_table_path, merge_target_table_path, rows_to_batch,
-    write_delta_table, write_delta_table_partitioned_small_files,
+    merge_partitioned_target_table_path, merge_target_table_path, rows_to_batch, write_delta_table,
+    write_delta_table_partitioned_small_files,
 };
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
 use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
-use crate::runner::run_case_async_with_async_setup;
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
 use crate::storage::StorageConfig;
+use crate::trace::TraceOperation;
 use crate::validation::{lane_requires_semantic_validation, validate_table_state};
 use crate::version_compat::optional_table_version_to_u64;
 
@@ -36,9 +44,15 @@ pub struct MergeCase {
 #[derive(Clone, Copy, Debug)]
 pub enum MergeMode {
     Upsert,
+    /// Upsert whose source carries `EVOLVED_SOURCE_COLUMNS` the target lacks, merged with
+    /// schema evolution enabled so the commit widens the target schema.
+    UpsertEvolvingSchema,
     Delete,
 }
 
+/// Nullable columns appended to schema-evolution merge sources.
+const EVOLVED_SOURCE_COLUMNS: [&str; 2] = ["ingest_batch", "source_tag"];
+
 #[derive(Clone, Copy, Debug)]
 pub enum MergeTargetProfile {
     Standard,
@@ -103,10 +117,119 @@ const MERGE_CASES: [MergeCase; 6] = [
     },
 ];
 
+/// Cases a manifest `matrix:` can sweep, each with the point's `match_ratio`.
+pub(crate) const MATRIX_TEMPLATES: [MatrixTemplate; 2] = [
+    MatrixTemplate {
+        name: "merge_upsert",
+        base_case: "merge_upsert_10pct",
+        parameters: &["match_ratio"],
+    },
+    MatrixTemplate {
+        name: "merge_delete",
+        base_case: "merge_delete_5pct",
+        parameters: &["match_ratio"],
+    },
+];
+
 pub fn case_names() -> Vec<String> {
     MERGE_CASES.iter().map(|c| c.name.to_string()).collect()
 }
 
+pub struct MergeSuite;
+
+impl Suite for MergeSuite {
+    fn name(&self) -> &'static str {
+        "merge"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+            args.matrix_points,
+        ))
+    }
+}
+
+/// The built-in cases followed by the planned matrix points, each named by its case id.
+fn planned_merge_cases(
+    matrix_points: &BTreeMap<String, MatrixPoint>,
+) -> BenchResult<Vec<(String, MergeCase)>> {
+    let mut cases = MERGE_CASES
+        .iter()
+        .map(|case| (case.name.to_string(), *case))
+        .collect::<Vec<_>>();
+    for template in MATRIX_TEMPLATES {
+        let base = *merge_case_by_name(template.base_case).ok_or_else(|| {
+            BenchError::InvalidArgument(format!(
+                "matrix template '{}' names unknown merge case '{}'",
+                template.name, template.base_case
+            ))
+        })?;
+        for (id, point) in matrix_points_for(matrix_points, template.name) {
+            let match_ratio = point
+                .parameters
+                .get("match_ratio")
+                .copied()
+                .unwrap_or(base.match_ratio);
+            cases.push((
+                id,
+                MergeCase {
+                    match_ratio,
+                    ..base
+                },
+            ));
+        }
+    }
+    Ok(cases)
+}
+
+fn validate_match_ratio(case: &MergeCase) -> Result<(), String> {
+    if case.match_ratio > 0.0 && case.match_ratio <= 1.0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "match_ratio must be in (0, 1], got {}",
+            case.match_ratio
+        ))
+    }
+}
+
+/// Unfiltered upsert cases as single-step replay traces, sized the way `build_source_df` sizes
+/// the source for `rows` fixture rows. Deletes, region-filtered sources, and schema evolution
+/// have no trace equivalent.
+pub(crate) fn trace_operations(rows: usize) -> Vec<(&'static str, Vec<TraceOperation>)> {
+    MERGE_CASES
+        .iter()
+        .filter(|case| matches!(case.mode, MergeMode::Upsert) && case.source_region.is_none())
+        .map(|case| {
+            let matched =
+                (((rows as f64) * case.match_ratio).round() as usize).clamp(1, rows.max(1));
+            let source_rows = matched + (matched / 10).max(1);
+            (
+                case.name,
+                vec![TraceOperation::Merge {
+                    rows: source_rows,
+                    matched_fraction: matched as f64 / source_rows as f64,
+                }],
+            )
+        })
+        .collect()
+}
+
 pub fn merge_case_names() -> Vec<&'static str> {
     MERGE_CASES.iter().map(|c| c.name).collect()
 }
@@ -117,41 +240,52 @@ pub fn merge_case_by_name(name: &str) -> Option<&'static MergeCase> {
         .find(|c| c.name.eq_ignore_ascii_case(name))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
+    matrix_points: &BTreeMap<String, MatrixPoint>,
 ) -> BenchResult<Vec<CaseResult>> {
-    let rows = match load_rows(fixtures_dir, scale) {
-        Ok(rows) => Arc::new(rows),
-        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    let cases = planned_merge_cases(matrix_points)?;
+    let names = cases
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
+        Err(e) => return Ok(fixture_error_cases(names, &e.to_string())),
     };
     if storage.is_local() {
         let standard_fixture = merge_target_table_path(fixtures_dir, scale)?;
         let partitioned_fixture = merge_partitioned_target_table_path(fixtures_dir, scale);
         if !standard_fixture.exists() || !partitioned_fixture.exists() {
             return Ok(fixture_error_cases(
-                case_names(),
+                names,
                 "missing merge fixture tables; run bench data first",
             ));
         }
 
         let mut out = Vec::new();
-        for case in MERGE_CASES {
+        for (name, case) in cases {
             let fixture_table_dir =
                 merge_fixture_table_path(fixtures_dir, scale, case.target_profile)?;
             let c = run_case_async_with_async_setup(
-                case.name,
+                &name,
                 warmup,
                 iterations,
+                hooks,
                 || {
                     let fixture_table_dir = fixture_table_dir.clone();
                     let rows = Arc::clone(&rows);
                     let storage = storage.clone();
                     async move {
+                        validate_match_ratio(&case)?;
                         prepare_merge_iteration(&fixture_table_dir, rows.as_slice(), case, &storage)
                             .await
                             .map_err(|e| e.to_string())
@@ -171,25 +305,33 @@ pub async fn run(
     }
 
     let mut out = Vec::new();
-    for case in MERGE_CASES {
+    for (name, case) in cases {
         let c = run_case_async_with_async_setup(
-            case.name,
+            &name,
             warmup,
             iterations,
+            hooks,
             || {
                 let rows = Arc::clone(&rows);
                 let storage = storage.clone();
+                let name = name.clone();
                 async move {
+                    validate_match_ratio(&case)?;
                     let base_table_name = match case.target_profile {
                         MergeTargetProfile::Standard => "merge_target_delta",
                         MergeTargetProfile::Partitioned => "merge_partitioned_target_delta",
                     };
                     let table_url = storage
-                        .isolated_table_url(scale, base_table_name, case.name)
-                        .map_err(|e| e.to_string())?;
-                    seed_merge_target_table(rows.as_slice(), table_url.clone(), case, &storage)
-                        .await
+                        .isolated_table_url(scale, base_table_name, &name)
                         .map_err(|e| e.to_string())?;
+                    seed_merge_target_table(
+                        rows.as_slice(),
+                        table_url.clone(),
+                        case.target_profile,
+                        &storage,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())?;
                     let table = storage
                         .open_table(table_url)
                         .await
@@ -238,7 +380,7 @@ async fn prepare_merge_iteration(
 ) -> BenchResult<MergeIterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("target");
-    copy_dir_all(fixture_table_dir, &table_dir)?;
+    copy_dir_with(fixture_table_dir, &table_dir, storage.fixture_copy())?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
@@ -278,23 +420,38 @@ pub(crate) async fn run_merge_case(
                 .when_matched_delete(|delete| delete)?
                 .await?
         }
-        MergeMode::Upsert => {
+        MergeMode::Upsert | MergeMode::UpsertEvolvingSchema => {
+            let evolved_columns: &[&str] = match case.mode {
+                MergeMode::UpsertEvolvingSchema => &EVOLVED_SOURCE_COLUMNS,
+                _ => &[],
+            };
             table
                 .merge(source, predicate)
                 .with_source_alias("source")
                 .with_target_alias("target")
+                .with_merge_schema(!evolved_columns.is_empty())
                 .when_matched_update(|update| {
-                    update
+                    let update = update
                         .update("value_i64", col("source.value_i64"))
-                        .update("flag", col("source.flag"))
+                        .update("amount", col("source.amount"))
+                        .update("flag", col("source.flag"));
+                    evolved_columns.iter().fold(update, |update, name| {
+                        update.update(*name, col(format!("source.{name}")))
+                    })
                 })?
                 .when_not_matched_insert(|insert| {
-                    insert
+                    let insert = insert
                         .set("id", col("source.id"))
                         .set("ts_ms", col("source.ts_ms"))
                         .set("region", col("source.region"))
                         .set("value_i64", col("source.value_i64"))
                         .set("flag", col("source.flag"))
+                        .set("ts", col("source.ts"))
+                        .set("event_date", col("source.event_date"))
+                        .set("amount", col("source.amount"));
+                    evolved_columns.iter().fold(insert, |insert, name| {
+                        insert.set(*name, col(format!("source.{name}")))
+                    })
                 })?
                 .await?
         }
@@ -350,7 +507,7 @@ pub(crate) async fn run_merge_case(
 pub(crate) async fn seed_merge_target_table(
     rows: &[NarrowSaleRow],
     table_url: Url,
-    case: MergeCase,
+    profile: MergeTargetProfile,
     storage: &StorageConfig,
 ) -> BenchResult<()> {
     let seed_rows = rows
@@ -358,7 +515,7 @@ pub(crate) async fn seed_merge_target_table(
         .take((rows.len() / 4).max(1024))
         .cloned()
         .collect::<Vec<_>>();
-    match case.target_profile {
+    match profile {
         MergeTargetProfile::Standard => {
             write_delta_table(table_url, &seed_rows, storage).await?;
         }
@@ -382,12 +539,16 @@ pub(crate) fn build_source_df(
     mode: MergeMode,
     source_region: Option<&str>,
 ) -> BenchResult<(DataFrame, usize)> {
+    // Fixtures generated with id skew repeat ids, and a MERGE rejects a target row matched by
+    // more than one source row, so the source keeps the first row of each id.
+    let mut source_ids = HashSet::new();
     let candidate_rows = rows
         .iter()
         .filter(|row| match source_region {
             Some(region) => row.region == region,
             None => true,
         })
+        .filter(|row| source_ids.insert(row.id))
         .collect::<Vec<_>>();
     if candidate_rows.is_empty() {
         return Err(BenchError::InvalidArgument(
@@ -401,11 +562,11 @@ pub(crate) fn build_source_df(
 
     for row in candidate_rows.iter().take(matched) {
         let mut next = (*row).clone();
-        next.value_i64 += 7;
+        next.value_i64 = next.value_i64.map(|value| value + 7);
         source_rows.push(next);
     }
 
-    if matches!(mode, MergeMode::Upsert) {
+    if matches!(mode, MergeMode::Upsert | MergeMode::UpsertEvolvingSchema) {
         for row in candidate_rows.iter().take((matched / 10).max(1)) {
             let mut next = (*row).clone();
             next.id = next.id.saturating_add(1_000_000_000);
@@ -413,7 +574,37 @@ pub(crate) fn build_source_df(
         }
     }
 
-    let batch = rows_to_batch(&source_rows)?;
+    let mut batch = rows_to_batch(&source_rows)?;
+    if matches!(mode, MergeMode::UpsertEvolvingSchema) {
+        batch = with_evolved_source_columns(batch, &source_rows)?;
+    }
     let ctx = SessionContext::new();
     Ok((ctx.read_batch(batch)?, source_rows.len()))
 }
+
+fn with_evolved_source_columns(
+    batch: RecordBatch,
+    source_rows: &[NarrowSaleRow],
+) -> BenchResult<RecordBatch> {
+    let schema = batch.schema();
+    let mut fields = schema.fields().iter().cloned().collect::<Vec<_>>();
+    fields.push(Arc::new(Field::new(
+        EVOLVED_SOURCE_COLUMNS[0],
+        DataType::Int64,
+        true,
+    )));
+    fields.push(Arc::new(Field::new(
+        EVOLVED_SOURCE_COLUMNS[1],
+        DataType::Utf8,
+        true,
+    )));
+    let mut columns = batch.columns().to_vec();
+    columns.push(Arc::new(Int64Array::from_iter(
+        source_rows.iter().map(|row| Some(row.id % 16)),
+    )));
+    columns.push(Arc::new(StringArray::from(vec![
+        Some("cdc");
+        source_rows.len()
+    ])));
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).map_err(Into::into)
+}
@@ -2,25 +2,32 @@ use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
+use serde_json::json;
 use url::Url;
 
-use deltalake_core::datafusion::prelude::DataFrame;
+use deltalake_core::datafusion::logical_expr::col;
+use deltalake_core::datafusion::prelude::{DataFrame, SessionContext};
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 
 use super::merge::{
     build_source_df, merge_fixture_table_path, run_merge_case, seed_merge_target_table, MergeCase,
     MergeMode, MergeTargetProfile,
 };
-use super::{copy_dir_all, fixture_error_cases, into_case_result};
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
 use crate::data::datasets::NarrowSaleRow;
 use crate::data::fixtures::{
-    load_rows, merge_partitioned_target_table_path, merge_target_table_path,
+    merge_partitioned_target_table_path, merge_target_table_path, rows_to_batch,
 };
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
-use crate::results::CaseResult;
-use crate::runner::run_case_async_with_async_setup;
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
 use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
 
 const MERGE_PERF_DELAY_ENV: &str = "DELTA_BENCH_MERGE_PERF_DELAY_MS";
 const MERGE_PERF_ALLOW_DELAY_ENV: &str = "DELTA_BENCH_ALLOW_MERGE_PERF_DELAY";
@@ -33,7 +40,22 @@ struct MergePerfIterationSetup {
     source_rows: usize,
 }
 
-const MERGE_PERF_CASES: [MergeCase; 4] = [
+/// Streaming-style CDC pattern: many tiny upserts committed back to back against one table.
+/// Half of each batch updates existing target rows and half inserts new ids.
+#[derive(Clone, Copy)]
+struct MicroUpsertCase {
+    name: &'static str,
+    merges: usize,
+    rows_per_merge: usize,
+}
+
+struct MicroUpsertIterationSetup {
+    _temp: tempfile::TempDir,
+    table: DeltaTable,
+    sources: Vec<DataFrame>,
+}
+
+const MERGE_PERF_CASES: [MergeCase; 6] = [
     MergeCase {
         name: "merge_perf_upsert_10pct",
         match_ratio: 0.10,
@@ -66,25 +88,82 @@ const MERGE_PERF_CASES: [MergeCase; 4] = [
         source_region: None,
         include_partition_predicate: false,
     },
+    // Same match ratios as the plain upserts above, so each pair isolates the planning and
+    // rewrite cost of evolving the target schema.
+    MergeCase {
+        name: "merge_perf_upsert_10pct_schema_evolution",
+        match_ratio: 0.10,
+        mode: MergeMode::UpsertEvolvingSchema,
+        target_profile: MergeTargetProfile::Standard,
+        source_region: None,
+        include_partition_predicate: false,
+    },
+    MergeCase {
+        name: "merge_perf_upsert_50pct_schema_evolution",
+        match_ratio: 0.50,
+        mode: MergeMode::UpsertEvolvingSchema,
+        target_profile: MergeTargetProfile::Standard,
+        source_region: None,
+        include_partition_predicate: false,
+    },
 ];
 
+const MERGE_PERF_MICRO_UPSERT_CASE: MicroUpsertCase = MicroUpsertCase {
+    name: "merge_perf_micro_upsert_100x10",
+    merges: 100,
+    rows_per_merge: 10,
+};
+
 pub fn case_names() -> Vec<String> {
     MERGE_PERF_CASES
         .iter()
-        .map(|case| case.name.to_string())
+        .map(|case| case.name)
+        .chain(std::iter::once(MERGE_PERF_MICRO_UPSERT_CASE.name))
+        .map(str::to_string)
         .collect()
 }
 
+pub struct MergePerfSuite;
+
+impl Suite for MergePerfSuite {
+    fn name(&self) -> &'static str {
+        "merge_perf"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
 ) -> BenchResult<Vec<CaseResult>> {
-    let rows = match load_rows(fixtures_dir, scale) {
-        Ok(rows) => Arc::new(rows),
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
         Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
     };
     if storage.is_local() {
@@ -105,6 +184,7 @@ pub async fn run(
                 case.name,
                 warmup,
                 iterations,
+                hooks,
                 || {
                     let fixture_table_dir = fixture_table_dir.clone();
                     let rows = Arc::clone(&rows);
@@ -134,6 +214,37 @@ pub async fn run(
             out.push(into_case_result(c));
         }
 
+        let case = MERGE_PERF_MICRO_UPSERT_CASE;
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let standard_fixture = standard_fixture.clone();
+                let rows = Arc::clone(&rows);
+                let storage = storage.clone();
+                async move {
+                    prepare_micro_upsert_iteration(
+                        &standard_fixture,
+                        rows.as_slice(),
+                        case,
+                        &storage,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())
+                }
+            },
+            |setup| async move {
+                let _keep_temp = setup._temp;
+                run_micro_upsert_case(setup.table, setup.sources, case, lane)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+
         return Ok(out);
     }
 
@@ -143,6 +254,7 @@ pub async fn run(
             case.name,
             warmup,
             iterations,
+            hooks,
             || {
                 let rows = Arc::clone(&rows);
                 let storage = storage.clone();
@@ -154,9 +266,14 @@ pub async fn run(
                     let table_url = storage
                         .isolated_table_url(scale, base_table_name, case.name)
                         .map_err(|e| e.to_string())?;
-                    seed_merge_target_table(rows.as_slice(), table_url.clone(), case, &storage)
-                        .await
-                        .map_err(|e| e.to_string())?;
+                    seed_merge_target_table(
+                        rows.as_slice(),
+                        table_url.clone(),
+                        case.target_profile,
+                        &storage,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())?;
                     let table = storage
                         .open_table(table_url)
                         .await
@@ -184,6 +301,45 @@ pub async fn run(
         out.push(into_case_result(c));
     }
 
+    let case = MERGE_PERF_MICRO_UPSERT_CASE;
+    let c = run_case_async_with_async_setup(
+        case.name,
+        warmup,
+        iterations,
+        hooks,
+        || {
+            let rows = Arc::clone(&rows);
+            let storage = storage.clone();
+            async move {
+                let table_url = storage
+                    .isolated_table_url(scale, "merge_target_delta", case.name)
+                    .map_err(|e| e.to_string())?;
+                seed_merge_target_table(
+                    rows.as_slice(),
+                    table_url.clone(),
+                    MergeTargetProfile::Standard,
+                    &storage,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+                let table = storage
+                    .open_table(table_url)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let sources =
+                    build_micro_upsert_sources(rows.as_slice(), case).map_err(|e| e.to_string())?;
+                Ok::<(DeltaTable, Vec<DataFrame>), String>((table, sources))
+            }
+        },
+        |(table, sources)| async move {
+            run_micro_upsert_case(table, sources, case, lane)
+                .await
+                .map_err(|e| e.to_string())
+        },
+    )
+    .await;
+    out.push(into_case_result(c));
+
     Ok(out)
 }
 
@@ -195,7 +351,7 @@ async fn prepare_merge_perf_iteration(
 ) -> BenchResult<MergePerfIterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("target");
-    copy_dir_all(fixture_table_dir, &table_dir)?;
+    copy_dir_with(fixture_table_dir, &table_dir, storage.fixture_copy())?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
@@ -214,6 +370,170 @@ async fn prepare_merge_perf_iteration(
     })
 }
 
+async fn prepare_micro_upsert_iteration(
+    fixture_table_dir: &Path,
+    rows: &[NarrowSaleRow],
+    case: MicroUpsertCase,
+    storage: &StorageConfig,
+) -> BenchResult<MicroUpsertIterationSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("target");
+    copy_dir_with(fixture_table_dir, &table_dir, storage.fixture_copy())?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    let table = storage.open_table(table_url).await?;
+    let sources = build_micro_upsert_sources(rows, case)?;
+
+    Ok(MicroUpsertIterationSetup {
+        _temp: temp,
+        table,
+        sources,
+    })
+}
+
+/// Builds one source frame per merge. Batch `n` updates the `n`-th run of leading fixture rows,
+/// which the merge target is seeded with, and inserts shifted copies of the same rows.
+fn build_micro_upsert_sources(
+    rows: &[NarrowSaleRow],
+    case: MicroUpsertCase,
+) -> BenchResult<Vec<DataFrame>> {
+    let updates_per_merge = case.rows_per_merge / 2;
+    let inserts_per_merge = case.rows_per_merge - updates_per_merge;
+    let chunk_len = updates_per_merge.max(inserts_per_merge);
+    let needed = case.merges * chunk_len;
+    if rows.len() < needed {
+        return Err(BenchError::InvalidArgument(format!(
+            "{} needs at least {needed} fixture rows but only {} are available",
+            case.name,
+            rows.len()
+        )));
+    }
+
+    let ctx = SessionContext::new();
+    rows[..needed]
+        .chunks(chunk_len)
+        .map(|chunk| {
+            let mut source_rows = Vec::with_capacity(case.rows_per_merge);
+            for row in chunk.iter().take(updates_per_merge) {
+                let mut next = row.clone();
+                next.value_i64 = next.value_i64.map(|value| value + 7);
+                next.flag = next.flag.map(|flag| !flag);
+                source_rows.push(next);
+            }
+            for row in chunk.iter().take(inserts_per_merge) {
+                let mut next = row.clone();
+                next.id = next.id.saturating_add(1_000_000_000);
+                source_rows.push(next);
+            }
+            Ok(ctx.read_batch(rows_to_batch(&source_rows)?)?)
+        })
+        .collect()
+}
+
+async fn run_micro_upsert_case(
+    mut table: DeltaTable,
+    sources: Vec<DataFrame>,
+    case: MicroUpsertCase,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
+    let mut rows_inserted = 0_u64;
+    let mut rows_updated = 0_u64;
+    let mut files_scanned = 0_u64;
+    let mut files_pruned = 0_u64;
+    let mut scan_time_ms = 0_u64;
+    let mut rewrite_time_ms = 0_u64;
+    for source in sources {
+        let (next, metrics) = table
+            .merge(source, col("target.id").eq(col("source.id")))
+            .with_source_alias("source")
+            .with_target_alias("target")
+            .when_matched_update(|update| {
+                update
+                    .update("value_i64", col("source.value_i64"))
+                    .update("amount", col("source.amount"))
+                    .update("flag", col("source.flag"))
+            })?
+            .when_not_matched_insert(|insert| {
+                insert
+                    .set("id", col("source.id"))
+                    .set("ts_ms", col("source.ts_ms"))
+                    .set("region", col("source.region"))
+                    .set("value_i64", col("source.value_i64"))
+                    .set("flag", col("source.flag"))
+                    .set("ts", col("source.ts"))
+                    .set("event_date", col("source.event_date"))
+                    .set("amount", col("source.amount"))
+            })?
+            .await?;
+        table = next;
+        rows_inserted += metrics.num_target_rows_inserted as u64;
+        rows_updated += metrics.num_target_rows_updated as u64;
+        files_scanned += metrics.num_target_files_scanned as u64;
+        files_pruned += metrics.num_target_files_skipped_during_scan as u64;
+        scan_time_ms += metrics.scan_time_ms;
+        rewrite_time_ms += metrics.rewrite_time_ms;
+    }
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let final_file_count = table.snapshot()?.log_data().num_files() as u64;
+    // How many files the tiny rewrites leave behind depends on the writer's partitioning, so
+    // the final file count is reported as `table_file_count` and kept out of the result contract.
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "merge_count": case.merges as u64,
+        "rows_inserted": rows_inserted,
+        "rows_updated": rows_updated,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "merge_count:u64",
+        "rows_inserted:u64",
+        "rows_updated:u64",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        let validation = validate_table_state(&table).await?;
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+
+    Ok(SampleMetrics::base(
+        Some(rows_inserted + rows_updated),
+        None,
+        Some(case.merges as u64),
+        table_version,
+    )
+    .with_scan_rewrite(ScanRewriteMetrics {
+        files_scanned: Some(files_scanned),
+        files_pruned: Some(files_pruned),
+        bytes_scanned: None,
+        scan_time_ms: Some(scan_time_ms),
+        rewrite_time_ms: Some(rewrite_time_ms),
+    })
+    .with_runtime_io(RuntimeIOMetrics {
+        peak_rss_mb: None,
+        cpu_time_ms: None,
+        bytes_read: None,
+        bytes_written: None,
+        files_touched: None,
+        files_skipped: None,
+        spill_bytes: None,
+        result_hash: Some(result_hash),
+        schema_hash: Some(schema_hash),
+        semantic_state_digest,
+        validation_summary,
+    })
+    .with_table_file_count(final_file_count))
+}
+
 async fn apply_validation_delay(case_id: &str) -> BenchResult<()> {
     let Some(delay) = parse_validation_delay(case_id)? else {
         return Ok(());
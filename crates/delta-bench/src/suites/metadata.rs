@@ -1,15 +1,17 @@
 use std::path::Path;
 
+use futures::future::LocalBoxFuture;
 use serde_json::json;
 use url::Url;
 
-use super::{copy_dir_all, into_case_result};
+use super::fixture_copy::FixtureCopyStrategy;
+use super::{copy_dir_with, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
 use crate::data::fixtures::{narrow_sales_table_path, narrow_sales_table_url};
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
-use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
-use crate::runner::{run_case_async, run_case_async_with_setup};
+use crate::results::{CaseResult, LogReadMetrics, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async, run_case_async_with_setup, CaseHooks};
 use crate::storage::StorageConfig;
 use crate::validation::{lane_requires_semantic_validation, validate_table_state};
 use crate::version_compat::optional_table_version_to_u64;
@@ -25,20 +27,23 @@ fn metadata_metrics(
     schema_hash: String,
     semantic_state_digest: Option<String>,
     validation_summary: Option<String>,
+    log_reads: LogReadMetrics,
 ) -> SampleMetrics {
-    SampleMetrics::base(None, None, Some(1), table_version).with_runtime_io(RuntimeIOMetrics {
-        peak_rss_mb: None,
-        cpu_time_ms: None,
-        bytes_read: None,
-        bytes_written: None,
-        files_touched: None,
-        files_skipped: None,
-        spill_bytes: None,
-        result_hash: Some(result_hash),
-        schema_hash: Some(schema_hash),
-        semantic_state_digest,
-        validation_summary,
-    })
+    SampleMetrics::base(None, None, Some(1), table_version)
+        .with_log_reads(log_reads)
+        .with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: None,
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest,
+            validation_summary,
+        })
 }
 
 pub fn case_names() -> Vec<String> {
@@ -48,32 +53,64 @@ pub fn case_names() -> Vec<String> {
     ]
 }
 
+pub struct MetadataSuite;
+
+impl Suite for MetadataSuite {
+    fn name(&self) -> &'static str {
+        "metadata"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
 ) -> BenchResult<Vec<CaseResult>> {
     if storage.is_local() {
         let table_path = narrow_sales_table_path(fixtures_dir, scale)?;
+        let fixture_copy = storage.fixture_copy();
         let mut out = Vec::new();
 
         let c1 = run_case_async_with_setup(
             "metadata_load",
             warmup,
             iterations,
-            || prepare_metadata_iteration(&table_path).map_err(|e| e.to_string()),
+            hooks,
+            || prepare_metadata_iteration(&table_path, fixture_copy).map_err(|e| e.to_string()),
             |setup| {
                 let storage = storage.clone();
                 async move {
                     let table_url = setup.table_url.clone();
                     let _keep_temp = setup;
-                    let table = storage
-                        .open_table(table_url)
+                    let (mut table, log_reads) = storage
+                        .try_from_url_counting_log_reads(table_url)
                         .await
                         .map_err(|e| e.to_string())?;
+                    table.load().await.map_err(|e| e.to_string())?;
+                    let log_reads = log_reads.take();
                     let table_version = optional_table_version_to_u64(table.version())
                         .map_err(|e| e.to_string())?;
                     let result_hash = hash_json(&json!({
@@ -100,6 +137,7 @@ pub async fn run(
                         schema_hash,
                         semantic_state_digest,
                         validation_summary,
+                        log_reads,
                     ))
                 }
             },
@@ -111,17 +149,19 @@ pub async fn run(
             "metadata_time_travel_v0",
             warmup,
             iterations,
-            || prepare_metadata_iteration(&table_path).map_err(|e| e.to_string()),
+            hooks,
+            || prepare_metadata_iteration(&table_path, fixture_copy).map_err(|e| e.to_string()),
             |setup| {
                 let storage = storage.clone();
                 async move {
                     let table_url = setup.table_url.clone();
                     let _keep_temp = setup;
-                    let mut table = storage
-                        .try_from_url_for_write(table_url)
+                    let (mut table, log_reads) = storage
+                        .try_from_url_counting_log_reads(table_url)
                         .await
                         .map_err(|e| e.to_string())?;
                     table.load_version(0).await.map_err(|e| e.to_string())?;
+                    let log_reads = log_reads.take();
                     let table_version = optional_table_version_to_u64(table.version())
                         .map_err(|e| e.to_string())?;
                     let result_hash = hash_json(&json!({
@@ -148,6 +188,7 @@ pub async fn run(
                         schema_hash,
                         semantic_state_digest,
                         validation_summary,
+                        log_reads,
                     ))
                 }
             },
@@ -161,14 +202,16 @@ pub async fn run(
     let table_url = narrow_sales_table_url(fixtures_dir, scale, storage)?;
     let mut out = Vec::new();
 
-    let c1 = run_case_async("metadata_load", warmup, iterations, || {
+    let c1 = run_case_async("metadata_load", warmup, iterations, hooks, || {
         let storage = storage.clone();
         let table_url = table_url.clone();
         async move {
-            let table = storage
-                .open_table(table_url)
+            let (mut table, log_reads) = storage
+                .try_from_url_counting_log_reads(table_url)
                 .await
                 .map_err(|e| e.to_string())?;
+            table.load().await.map_err(|e| e.to_string())?;
+            let log_reads = log_reads.take();
             let table_version =
                 optional_table_version_to_u64(table.version()).map_err(|e| e.to_string())?;
             let result_hash = hash_json(&json!({
@@ -194,21 +237,23 @@ pub async fn run(
                 schema_hash,
                 semantic_state_digest,
                 validation_summary,
+                log_reads,
             ))
         }
     })
     .await;
     out.push(into_case_result(c1));
 
-    let c2 = run_case_async("metadata_time_travel_v0", warmup, iterations, || {
+    let c2 = run_case_async("metadata_time_travel_v0", warmup, iterations, hooks, || {
         let storage = storage.clone();
         let table_url = table_url.clone();
         async move {
-            let mut table = storage
-                .try_from_url_for_write(table_url)
+            let (mut table, log_reads) = storage
+                .try_from_url_counting_log_reads(table_url)
                 .await
                 .map_err(|e| e.to_string())?;
             table.load_version(0).await.map_err(|e| e.to_string())?;
+            let log_reads = log_reads.take();
             let table_version =
                 optional_table_version_to_u64(table.version()).map_err(|e| e.to_string())?;
             let result_hash = hash_json(&json!({
@@ -234,6 +279,7 @@ pub async fn run(
                 schema_hash,
                 semantic_state_digest,
                 validation_summary,
+                log_reads,
             ))
         }
     })
@@ -243,10 +289,13 @@ pub async fn run(
     Ok(out)
 }
 
-fn prepare_metadata_iteration(source_table_path: &Path) -> BenchResult<MetadataIterationSetup> {
+fn prepare_metadata_iteration(
+    source_table_path: &Path,
+    fixture_copy: FixtureCopyStrategy,
+) -> BenchResult<MetadataIterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("table");
-    copy_dir_all(source_table_path, &table_dir)?;
+    copy_dir_with(source_table_path, &table_dir, fixture_copy)?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
@@ -5,13 +5,16 @@ use std::time::Duration;
 use deltalake_core::datafusion::datasource::TableProvider;
 use deltalake_core::kernel::Snapshot;
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 use serde_json::json;
 use url::Url;
 
-use super::{copy_dir_all, fixture_error_cases, into_case_result};
+use super::fixture_copy::FixtureCopyStrategy;
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
 use crate::data::fixtures::{
     metadata_checkpointed_table_path, metadata_checkpointed_table_url,
+    metadata_deep_history_table_path, metadata_deep_history_table_url,
     metadata_long_history_table_path, metadata_long_history_table_url,
     metadata_uncheckpointed_table_path, metadata_uncheckpointed_table_url,
 };
@@ -19,7 +22,7 @@ use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
 use crate::replay_snapshot::clone_plain_snapshot_from_loaded_table;
 use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
-use crate::runner::{run_case_async, run_case_async_with_setup};
+use crate::runner::{run_case_async, run_case_async_with_setup, CaseHooks};
 use crate::storage::StorageConfig;
 use crate::validation::{lane_requires_semantic_validation, validate_table_state};
 use crate::version_compat::{optional_table_version_to_u64, snapshot_version_arg};
@@ -27,6 +30,8 @@ use crate::version_compat::{optional_table_version_to_u64, snapshot_version_arg}
 const METADATA_PERF_DELAY_ENV: &str = "DELTA_BENCH_METADATA_PERF_DELAY_MS";
 const METADATA_PERF_ALLOW_DELAY_ENV: &str = "DELTA_BENCH_ALLOW_METADATA_PERF_DELAY";
 const METADATA_PERF_VALIDATION_CANARY_CASE_ID: &str = "metadata_perf_load_head_long_history";
+/// Mid-log time-travel target for the 512-append deep history fixture.
+const DEEP_HISTORY_MID_VERSION: u64 = 256;
 
 #[doc(hidden)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -34,12 +39,14 @@ pub enum MetadataReplayVariant {
     LongHistory,
     CheckpointedHead,
     UncheckpointedHead,
+    DeepHistory,
 }
 
 impl MetadataReplayVariant {
     const fn fixture_name(self) -> &'static str {
         match self {
             Self::LongHistory => "long_history",
+            Self::DeepHistory => "deep_history",
             Self::CheckpointedHead => "checkpointed_head",
             Self::UncheckpointedHead => "uncheckpointed_head",
         }
@@ -49,7 +56,11 @@ impl MetadataReplayVariant {
 #[derive(Clone, Copy)]
 enum MetadataPerfOperation {
     LoadHead,
-    TimeTravelVersionZero,
+    TimeTravel {
+        version: u64,
+    },
+    /// Enumerate every commit through `history()`.
+    History,
 }
 
 #[derive(Clone, Copy)]
@@ -59,7 +70,7 @@ struct MetadataPerfCase {
     operation: MetadataPerfOperation,
 }
 
-const METADATA_PERF_CASES: [MetadataPerfCase; 4] = [
+const METADATA_PERF_CASES: [MetadataPerfCase; 9] = [
     MetadataPerfCase {
         name: "metadata_perf_load_head_long_history",
         variant: MetadataReplayVariant::LongHistory,
@@ -68,7 +79,7 @@ const METADATA_PERF_CASES: [MetadataPerfCase; 4] = [
     MetadataPerfCase {
         name: "metadata_perf_time_travel_v0_long_history",
         variant: MetadataReplayVariant::LongHistory,
-        operation: MetadataPerfOperation::TimeTravelVersionZero,
+        operation: MetadataPerfOperation::TimeTravel { version: 0 },
     },
     MetadataPerfCase {
         name: "metadata_perf_load_checkpointed_head",
@@ -80,6 +91,33 @@ const METADATA_PERF_CASES: [MetadataPerfCase; 4] = [
         variant: MetadataReplayVariant::UncheckpointedHead,
         operation: MetadataPerfOperation::LoadHead,
     },
+    MetadataPerfCase {
+        name: "metadata_perf_load_head_deep_history",
+        variant: MetadataReplayVariant::DeepHistory,
+        operation: MetadataPerfOperation::LoadHead,
+    },
+    MetadataPerfCase {
+        name: "metadata_perf_time_travel_v0_deep_history",
+        variant: MetadataReplayVariant::DeepHistory,
+        operation: MetadataPerfOperation::TimeTravel { version: 0 },
+    },
+    MetadataPerfCase {
+        name: "metadata_perf_time_travel_v256_deep_history",
+        variant: MetadataReplayVariant::DeepHistory,
+        operation: MetadataPerfOperation::TimeTravel {
+            version: DEEP_HISTORY_MID_VERSION,
+        },
+    },
+    MetadataPerfCase {
+        name: "metadata_perf_history_long_history",
+        variant: MetadataReplayVariant::LongHistory,
+        operation: MetadataPerfOperation::History,
+    },
+    MetadataPerfCase {
+        name: "metadata_perf_history_deep_history",
+        variant: MetadataReplayVariant::DeepHistory,
+        operation: MetadataPerfOperation::History,
+    },
 ];
 
 struct MetadataIterationSetup {
@@ -117,12 +155,40 @@ pub fn case_names() -> Vec<String> {
         .collect()
 }
 
+pub struct MetadataPerfSuite;
+
+impl Suite for MetadataPerfSuite {
+    fn name(&self) -> &'static str {
+        "metadata_perf"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
 ) -> BenchResult<Vec<CaseResult>> {
     if storage.is_local() {
@@ -130,6 +196,7 @@ pub async fn run(
             metadata_long_history_table_path(fixtures_dir, scale),
             metadata_checkpointed_table_path(fixtures_dir, scale),
             metadata_uncheckpointed_table_path(fixtures_dir, scale),
+            metadata_deep_history_table_path(fixtures_dir, scale),
         ];
         if required_sources
             .iter()
@@ -141,6 +208,7 @@ pub async fn run(
             ));
         }
 
+        let fixture_copy = storage.fixture_copy();
         let mut out = Vec::new();
         for case in METADATA_PERF_CASES {
             let source = source_table_path(fixtures_dir, scale, case.variant);
@@ -148,7 +216,8 @@ pub async fn run(
                 case.name,
                 warmup,
                 iterations,
-                || prepare_metadata_iteration(&source).map_err(|e| e.to_string()),
+                hooks,
+                || prepare_metadata_iteration(&source, fixture_copy).map_err(|e| e.to_string()),
                 |setup| {
                     let storage = storage.clone();
                     async move {
@@ -173,7 +242,7 @@ pub async fn run(
     let mut out = Vec::new();
     for case in METADATA_PERF_CASES {
         let table_url = source_table_url(fixtures_dir, scale, case.variant, storage)?;
-        let c = run_case_async(case.name, warmup, iterations, || {
+        let c = run_case_async(case.name, warmup, iterations, hooks, || {
             let storage = storage.clone();
             let table_url = table_url.clone();
             async move {
@@ -266,24 +335,45 @@ async fn run_metadata_case(
     case: MetadataPerfCase,
     lane: BenchmarkLane,
 ) -> BenchResult<SampleMetrics> {
-    let (table_version, schema_hash, semantic_state_digest, validation_summary) =
-        match case.operation {
-            MetadataPerfOperation::LoadHead => {
-                let table = storage.open_table(table_url).await?;
-                build_metadata_observation(&table, lane).await?
-            }
-            MetadataPerfOperation::TimeTravelVersionZero => {
-                let mut table = storage.try_from_url_for_write(table_url).await?;
-                table.load_version(0).await?;
-                build_metadata_observation(&table, lane).await?
+    let table = match case.operation {
+        MetadataPerfOperation::LoadHead | MetadataPerfOperation::History => {
+            storage.open_table(table_url).await?
+        }
+        MetadataPerfOperation::TimeTravel { version } => {
+            let mut table = storage.try_from_url_for_write(table_url).await?;
+            table.load_version(snapshot_version_arg(version)?).await?;
+            table
+        }
+    };
+    let commit_count = match case.operation {
+        MetadataPerfOperation::History => Some(table.history(None).await?.count() as u64),
+        MetadataPerfOperation::LoadHead | MetadataPerfOperation::TimeTravel { .. } => None,
+    };
+    let (table_version, mut schema_hash, semantic_state_digest, validation_summary) =
+        build_metadata_observation(&table, lane).await?;
+
+    let result_hash = match commit_count {
+        Some(commit_count) => {
+            if !lane_requires_semantic_validation(lane) {
+                schema_hash = hash_json(&json!([
+                    "operation:string",
+                    "table_version:u64",
+                    "commit_count:u64",
+                ]))?;
             }
-        };
-
-    let result_hash = hash_json(&json!({
-        "operation": case.name,
-        "fixture": case.variant.fixture_name(),
-        "table_version": table_version,
-    }))?;
+            hash_json(&json!({
+                "operation": case.name,
+                "fixture": case.variant.fixture_name(),
+                "table_version": table_version,
+                "commit_count": commit_count,
+            }))?
+        }
+        None => hash_json(&json!({
+            "operation": case.name,
+            "fixture": case.variant.fixture_name(),
+            "table_version": table_version,
+        }))?,
+    };
 
     Ok(metadata_metrics(
         table_version,
@@ -347,6 +437,7 @@ fn source_table_path(fixtures_dir: &Path, scale: &str, variant: MetadataReplayVa
         MetadataReplayVariant::UncheckpointedHead => {
             metadata_uncheckpointed_table_path(fixtures_dir, scale)
         }
+        MetadataReplayVariant::DeepHistory => metadata_deep_history_table_path(fixtures_dir, scale),
     }
 }
 
@@ -366,13 +457,19 @@ fn source_table_url(
         MetadataReplayVariant::UncheckpointedHead => {
             metadata_uncheckpointed_table_url(fixtures_dir, scale, storage)
         }
+        MetadataReplayVariant::DeepHistory => {
+            metadata_deep_history_table_url(fixtures_dir, scale, storage)
+        }
     }
 }
 
-fn prepare_metadata_iteration(source_table_path: &Path) -> BenchResult<MetadataIterationSetup> {
+fn prepare_metadata_iteration(
+    source_table_path: &Path,
+    fixture_copy: FixtureCopyStrategy,
+) -> BenchResult<MetadataIterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("table");
-    copy_dir_all(source_table_path, &table_dir)?;
+    copy_dir_with(source_table_path, &table_dir, fixture_copy)?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
@@ -1,39 +1,34 @@
-use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
 
-use crate::assertions::{apply_case_assertions, CaseAssertion};
+use serde_json::json;
+use tracing::{info_span, Instrument};
+
+use crate::assertions::{apply_case_assertions, observe_case_assertions, CaseAssertion};
 use crate::cli::{BenchmarkLane, RunnerMode, TimingPhase};
+use crate::data::fixtures::DEFAULT_WIDE_TABLE_COLUMNS;
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::{hash_bytes, hash_json};
 use crate::manifests::{
-    load_manifest, DatasetAssertionPolicy, DatasetId, DEFAULT_PYTHON_MANIFEST_PATH,
-    DEFAULT_RUST_MANIFEST_PATH,
+    expand_case_matrix, load_manifest, validate_case_id, validate_case_tag, AssertionSeverity,
+    DatasetAssertionPolicy, DatasetId, ManifestCase, ManifestCaseAssertion,
+    DEFAULT_PYTHON_MANIFEST_PATH, DEFAULT_RUST_MANIFEST_PATH,
 };
 use crate::results::{CaseFailure, CaseResult, PerfStatus, FAILURE_KIND_EXECUTION_ERROR};
-use crate::runner::CaseExecutionResult;
+use crate::runner::{
+    failure_case_result, record_unaudited_cases, report_unreported_cases, CaseBudget,
+    CaseExecutionResult, CaseHooks, CaseSeeds, OpenLoopConfig,
+};
 use crate::storage::StorageConfig;
 
-pub(crate) fn copy_dir_all(src: &Path, dst: &Path) -> BenchResult<()> {
-    fs::create_dir_all(dst)?;
-    for entry in fs::read_dir(src)? {
-        let entry = entry?;
-        let file_type = entry.file_type()?;
-        if file_type.is_symlink() {
-            return Err(BenchError::InvalidArgument(format!(
-                "symlinks are not allowed in fixture tree: {}",
-                entry.path().display()
-            )));
-        }
-        let to = dst.join(entry.file_name());
-        if file_type.is_dir() {
-            copy_dir_all(&entry.path(), &to)?;
-        } else {
-            fs::copy(entry.path(), to)?;
-        }
-    }
-    Ok(())
-}
+pub(crate) use fixture_copy::copy_dir_with;
+pub use matrix::{MatrixPoint, MatrixTemplate};
+use registry::builtin_suite_registry;
+pub use registry::{Suite, SuiteRegistry, SuiteRunArgs};
+use tpcds::catalog::DEFAULT_TPCDS_PHASE;
+use tpcds::registration::TpcdsLayout;
 
 pub(crate) fn into_case_result(result: CaseExecutionResult) -> CaseResult {
     match result {
@@ -61,6 +56,14 @@ pub(crate) fn fixture_error_cases(case_names: Vec<String>, message: &str) -> Vec
             required_runs: None,
             decision_threshold_pct: None,
             decision_metric: None,
+            seed: None,
+            physical_plan: None,
+            plan_metrics_file: None,
+            plan_metrics: Vec::new(),
+            profile_file: None,
+            flamegraph: None,
+            heap_profile: None,
+            warnings: Vec::new(),
             failure_kind: Some(FAILURE_KIND_EXECUTION_ERROR.to_string()),
             failure: Some(CaseFailure {
                 message: format!("fixture load failed: {message}"),
@@ -69,41 +72,80 @@ pub(crate) fn fixture_error_cases(case_names: Vec<String>, message: &str) -> Vec
         .collect()
 }
 
+/// Result for a case that is not run because a case it `depends_on` failed, leaving no table
+/// state to start from.
+pub(crate) fn dependency_failed_case(case: &str, dependency: &str) -> CaseResult {
+    failure_case_result(
+        case,
+        Vec::new(),
+        format!("skipped: depends on '{dependency}', which failed"),
+    )
+}
+
+/// Table a case leaves behind for the cases that `depends_on` it. Each iteration of the
+/// upstream case replaces the kept table, so dependents start from what its last iteration
+/// left; they copy it rather than modify it.
+#[derive(Clone, Default)]
+pub(crate) struct TableStateHandoff {
+    kept: Arc<Mutex<Option<(tempfile::TempDir, PathBuf)>>>,
+}
+
+impl TableStateHandoff {
+    /// Keeps `table_dir`, which must live under `temp`, until the handoff is dropped.
+    pub(crate) fn keep(&self, temp: tempfile::TempDir, table_dir: PathBuf) {
+        *self.kept.lock().unwrap_or_else(PoisonError::into_inner) = Some((temp, table_dir));
+    }
+
+    pub(crate) fn table_dir(&self, case: &str) -> BenchResult<PathBuf> {
+        self.kept
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .map(|(_, table_dir)| table_dir.clone())
+            .ok_or_else(|| {
+                BenchError::InvalidArgument(format!(
+                    "{case} has no table state to start from; the case it depends on kept none"
+                ))
+            })
+    }
+}
+
+pub mod checkpoint;
 pub mod concurrency;
+pub mod convert_to_delta;
+pub mod crash_recovery;
+pub mod custom_sql;
+pub mod data_skipping;
 pub mod delete_update;
 pub mod delete_update_perf;
+pub mod deletion_vectors;
+pub mod fixture_copy;
+pub mod interop_consistency;
 pub mod interop_py;
+pub mod late_arriving;
+pub mod log_replay;
+mod matrix;
 pub mod merge;
 pub mod merge_perf;
 pub mod metadata;
 pub mod metadata_perf;
+pub mod multi_table;
+pub mod nested_types;
 pub mod optimize_perf;
 pub mod optimize_vacuum;
+mod registry;
+pub mod replay;
+pub mod restore;
 pub mod scan;
+pub mod scan_concurrency;
 pub(crate) mod scan_metrics;
+pub mod scenario;
+pub mod snapshot_isolation;
 pub mod tpcds;
+pub mod wide_table;
 pub mod write;
 pub mod write_perf;
 
-/// Single source of truth for suite names. Adding a new suite requires updating
-/// this array, `list_cases_for_target`, and `run_target`.
-const SUITE_NAMES: [&str; 14] = [
-    "scan",
-    "write",
-    "write_perf",
-    "delete_update",
-    "delete_update_perf",
-    "merge",
-    "merge_perf",
-    "metadata",
-    "metadata_perf",
-    "optimize_perf",
-    "optimize_vacuum",
-    "concurrency",
-    "tpcds",
-    "interop_py",
-];
-
 /// `target=all` stays limited to the lightweight default suites; heavier perf
 /// scenarios such as `write_perf` must be requested explicitly.
 const DEFAULT_ALL_TARGETS: [&str; 8] = [
@@ -123,42 +165,225 @@ pub struct PlannedCase {
     pub target: String,
     pub lane: String,
     pub assertions: Vec<CaseAssertion>,
+    /// Assertions with `severity: warn`, whose violations become case warnings.
+    pub warn_assertions: Vec<CaseAssertion>,
     pub suite_manifest_hash: String,
     pub case_definition_hash: String,
     pub supports_decision: bool,
     pub required_runs: Option<u32>,
     pub decision_threshold_pct: Option<f64>,
     pub decision_metric: Option<String>,
+    /// Cases this one starts after, from the manifest's `depends_on`.
+    pub depends_on: Vec<String>,
+    /// The manifest's per-case `warmup`, `iterations`, and `timeout_ms`.
+    pub budget: CaseBudget,
+    pub tags: Vec<String>,
+    /// The manifest's `interop_workload`, shared with the other runner's equivalent case.
+    pub interop_workload: Option<String>,
+    /// Set on cases generated from a manifest `matrix:`.
+    pub matrix: Option<MatrixPoint>,
+}
+
+/// Tag selection for [`plan_run_cases_with_tags`]: a case is selected when it carries one of
+/// the `include` tags, or `include` is empty, and none of the `exclude` tags.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CaseTagFilter {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl CaseTagFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> BenchResult<Self> {
+        let parse = |tags: &[String]| {
+            let mut parsed = Vec::new();
+            for tag in tags
+                .iter()
+                .map(|tag| tag.trim())
+                .filter(|tag| !tag.is_empty())
+            {
+                validate_case_tag(tag)?;
+                if !parsed.iter().any(|existing| existing == tag) {
+                    parsed.push(tag.to_string());
+                }
+            }
+            Ok::<_, BenchError>(parsed)
+        };
+        let filter = Self {
+            include: parse(include)?,
+            exclude: parse(exclude)?,
+        };
+        if let Some(tag) = filter
+            .include
+            .iter()
+            .find(|tag| filter.exclude.contains(tag))
+        {
+            return Err(BenchError::InvalidArgument(format!(
+                "tag '{tag}' is both included and excluded"
+            )));
+        }
+        Ok(filter)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    pub fn matches(&self, tags: &[String]) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|tag| tags.contains(tag)))
+            && !self.exclude.iter().any(|tag| tags.contains(tag))
+    }
 }
 
 pub fn list_targets() -> Vec<&'static str> {
-    let mut targets: Vec<&str> = SUITE_NAMES.to_vec();
-    targets.push("all");
-    targets
+    builtin_suite_registry().targets()
 }
 
 pub fn plan_run_cases(
     target: &str,
     runner: RunnerMode,
     case_filter: Option<&str>,
+) -> BenchResult<Vec<PlannedCase>> {
+    plan_run_cases_with_tags(target, runner, case_filter, &CaseTagFilter::default())
+}
+
+/// Like [`plan_run_cases`], keeping only the cases `tags` selects. Dependencies of a selected
+/// case are planned even when their own tags would leave them out.
+pub fn plan_run_cases_with_tags(
+    target: &str,
+    runner: RunnerMode,
+    case_filter: Option<&str>,
+    tags: &CaseTagFilter,
+) -> BenchResult<Vec<PlannedCase>> {
+    plan_run_cases_with_options(target, runner, case_filter, tags, &SuiteOptions::default())
+}
+
+/// Like [`plan_run_cases_with_tags`], planning `custom_sql` from `options.custom_sql_dir` and
+/// looking targets up in `options.registry`.
+pub fn plan_run_cases_with_options(
+    target: &str,
+    runner: RunnerMode,
+    case_filter: Option<&str>,
+    tags: &CaseTagFilter,
+    options: &SuiteOptions,
 ) -> BenchResult<Vec<PlannedCase>> {
     let canonical_target = canonical_suite_target(target);
     validate_runner_target(runner, canonical_target)?;
-    let mut planned = plan_cases_from_manifest(canonical_target, runner)?;
+    let registry = &options.registry;
+    let planned = if canonical_target == "custom_sql" {
+        plan_custom_sql_cases(&custom_sql::CustomSqlWorkload::from_options(options)?)?
+    } else if let Some(suite) = registry
+        .get(canonical_target)
+        .ok()
+        .filter(|suite| !registry.is_builtin(suite.name()))
+    {
+        plan_registered_suite_cases(suite)?
+    } else {
+        plan_cases_from_manifest(canonical_target, runner)?
+    };
 
-    if let Some(filter) = case_filter.map(str::trim).filter(|value| !value.is_empty()) {
-        planned.retain(|case| case.id.contains(filter));
-    }
+    let filter = case_filter.map(str::trim).filter(|value| !value.is_empty());
+    let planned = with_case_dependencies(planned, |case| {
+        filter.is_none_or(|filter| case.id.contains(filter)) && tags.matches(&case.tags)
+    })?;
     if planned.is_empty() {
+        let selection = if tags.is_empty() {
+            "case filter"
+        } else {
+            "case filter and tags"
+        };
         return Err(BenchError::InvalidArgument(format!(
-            "case filter matched no cases for target='{target}' (canonical='{canonical_target}') and runner='{}'",
+            "{selection} matched no cases for target='{target}' (canonical='{canonical_target}') and runner='{}'",
             runner.as_str()
         )));
     }
-    reject_duplicate_planned_case_ids(&planned)?;
+    validate_planned_case_ids(&planned)?;
     Ok(planned)
 }
 
+/// Plans exactly one case for `bench run --replay-case`, plus the cases it depends on; unlike a
+/// case filter, the id must match in full.
+pub fn plan_replay_case(
+    target: &str,
+    runner: RunnerMode,
+    case: &str,
+    options: &SuiteOptions,
+) -> BenchResult<Vec<PlannedCase>> {
+    let planned = plan_run_cases_with_options(
+        target,
+        runner,
+        Some(case),
+        &CaseTagFilter::default(),
+        options,
+    )?;
+    if !planned.iter().any(|planned_case| planned_case.id == case) {
+        return Err(BenchError::InvalidArgument(format!(
+            "replay case '{case}' is not a case of target='{target}' and runner='{}'",
+            runner.as_str()
+        )));
+    }
+    with_case_dependencies(planned, |planned_case| planned_case.id == case)
+}
+
+/// Keeps the `selected` cases and every case they depend on, transitively, ordered so each
+/// dependency comes before its dependents and otherwise in manifest order.
+fn with_case_dependencies(
+    planned: Vec<PlannedCase>,
+    selected: impl Fn(&PlannedCase) -> bool,
+) -> BenchResult<Vec<PlannedCase>> {
+    let mut needed = planned
+        .iter()
+        .filter(|case| selected(case))
+        .map(|case| case.id.clone())
+        .collect::<HashSet<_>>();
+    let mut pending = needed.iter().cloned().collect::<Vec<_>>();
+    while let Some(id) = pending.pop() {
+        let Some(case) = planned.iter().find(|case| case.id == id) else {
+            continue;
+        };
+        for dependency_id in &case.depends_on {
+            if !planned
+                .iter()
+                .any(|candidate| &candidate.id == dependency_id)
+            {
+                return Err(BenchError::InvalidArgument(format!(
+                    "case '{id}' depends on '{dependency_id}', which is not planned for target '{}'",
+                    case.target
+                )));
+            }
+            if needed.insert(dependency_id.clone()) {
+                pending.push(dependency_id.clone());
+            }
+        }
+    }
+
+    let mut remaining = planned
+        .into_iter()
+        .filter(|case| needed.contains(&case.id))
+        .collect::<Vec<_>>();
+    let mut placed = HashSet::new();
+    let mut ordered = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let Some(next) = remaining.iter().position(|case| {
+            case.depends_on
+                .iter()
+                .all(|dependency_id| placed.contains(dependency_id))
+        }) else {
+            let ids = remaining
+                .iter()
+                .map(|case| case.id.as_str())
+                .collect::<Vec<_>>();
+            return Err(BenchError::InvalidArgument(format!(
+                "planned cases have circular dependencies: {}",
+                ids.join(", ")
+            )));
+        };
+        let case = remaining.remove(next);
+        placed.insert(case.id.clone());
+        ordered.push(case);
+    }
+    Ok(ordered)
+}
+
 pub fn apply_dataset_assertion_policy(planned: &mut [PlannedCase], dataset: Option<DatasetId>) {
     let policy = dataset
         .map(DatasetId::assertion_policy)
@@ -167,8 +392,125 @@ pub fn apply_dataset_assertion_policy(planned: &mut [PlannedCase], dataset: Opti
         return;
     }
     for case in planned.iter_mut() {
-        case.assertions
-            .retain(|assertion| !matches!(assertion, CaseAssertion::ExactResultHash(_)));
+        for assertions in [&mut case.assertions, &mut case.warn_assertions] {
+            assertions.retain(|assertion| {
+                !matches!(
+                    assertion,
+                    CaseAssertion::ExactResultHash(_) | CaseAssertion::RowCountEquals(_)
+                )
+            });
+        }
+    }
+}
+
+/// The manifest pins `wide_table` hashes for the default fixture of
+/// [`DEFAULT_WIDE_TABLE_COLUMNS`] columns. A fixture generated with another
+/// `data --wide-table-columns` changes the rows and schema those scans return, so their
+/// exact result and schema hash assertions are dropped instead of failing every run.
+pub fn apply_fixture_assertion_policy(
+    planned: &mut [PlannedCase],
+    wide_table_columns: Option<usize>,
+) {
+    if matches!(wide_table_columns, None | Some(DEFAULT_WIDE_TABLE_COLUMNS)) {
+        return;
+    }
+    for case in planned
+        .iter_mut()
+        .filter(|case| wide_table::COLUMN_SHAPED_CASES.contains(&case.id.as_str()))
+    {
+        for assertions in [&mut case.assertions, &mut case.warn_assertions] {
+            assertions.retain(|assertion| {
+                !matches!(
+                    assertion,
+                    CaseAssertion::ExactResultHash(_) | CaseAssertion::SchemaHash(_)
+                )
+            });
+        }
+    }
+}
+
+/// Case-set change between a previous run and the current plan.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlanDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// `(previous, planned)` pairs the manifest `aliases` map declares as one case renamed.
+    pub renamed: Vec<(String, String)>,
+}
+
+impl PlanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.renamed.is_empty()
+    }
+}
+
+/// Compares planned case ids against the case ids of a previous run. Only renames declared in
+/// the manifest `aliases` map count as renames; every other id missing from the plan is
+/// removed, however close a new id looks.
+pub fn diff_case_sets(
+    previous: &[String],
+    planned: &[String],
+    aliases: &BTreeMap<String, String>,
+) -> PlanDiff {
+    let previous_set = previous.iter().collect::<HashSet<_>>();
+    let planned_set = planned.iter().collect::<HashSet<_>>();
+    let mut removed = previous
+        .iter()
+        .filter(|id| !planned_set.contains(id))
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut added = planned
+        .iter()
+        .filter(|id| !previous_set.contains(id))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut renamed = Vec::new();
+    for old in removed.clone() {
+        let Some(new) = aliases.get(&old) else {
+            continue;
+        };
+        if added.contains(new) {
+            removed.retain(|id| id != &old);
+            added.retain(|id| id != new);
+            renamed.push((old, new.clone()));
+        }
+    }
+
+    PlanDiff {
+        added,
+        removed,
+        renamed,
+    }
+}
+
+/// Suite settings that only some suites read. The default runs every suite as it ships.
+#[derive(Clone, Debug)]
+pub struct SuiteOptions {
+    /// Suites that targets are planned and run from; the built-in suites by default.
+    pub registry: Arc<SuiteRegistry>,
+    /// Highest TPC-DS rollout phase whose enabled queries the `tpcds` suite executes.
+    pub tpcds_phase: u8,
+    /// Fixture layout the `tpcds` suite registers `store_sales` from.
+    pub tpcds_layout: TpcdsLayout,
+    /// Query directory the `custom_sql` suite plans and runs; that target requires it.
+    pub custom_sql_dir: Option<PathBuf>,
+    /// JSONL operation trace the `replay` suite replays; without it, its cases fail.
+    pub replay_trace: Option<PathBuf>,
+    /// Runs `tpcds` and `custom_sql` queries in open-loop load windows instead of back to back.
+    pub load: Option<OpenLoopConfig>,
+}
+
+impl Default for SuiteOptions {
+    fn default() -> Self {
+        Self {
+            registry: builtin_suite_registry(),
+            tpcds_phase: DEFAULT_TPCDS_PHASE,
+            tpcds_layout: TpcdsLayout::default(),
+            custom_sql_dir: None,
+            replay_trace: None,
+            load: None,
+        }
     }
 }
 
@@ -182,7 +524,40 @@ pub async fn run_planned_cases(
     iterations: u32,
     storage: &StorageConfig,
 ) -> BenchResult<Vec<CaseResult>> {
-    validate_timing_phase_for_planned_cases(planned, timing_phase)?;
+    run_planned_cases_with_seeds(
+        fixtures_dir,
+        planned,
+        scale,
+        requested_lane,
+        timing_phase,
+        warmup,
+        iterations,
+        &CaseHooks::default(),
+        storage,
+        &CaseSeeds::default(),
+        &SuiteOptions::default(),
+    )
+    .await
+}
+
+/// Like [`run_planned_cases`], drawing each case's randomness from `seeds` and recording the
+/// seed on its result, running each case with `hooks` and the plan's case budgets, and running
+/// suites with `options`.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_planned_cases_with_seeds(
+    fixtures_dir: &Path,
+    planned: &[PlannedCase],
+    scale: &str,
+    requested_lane: BenchmarkLane,
+    timing_phase: TimingPhase,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    seeds: &CaseSeeds,
+    options: &SuiteOptions,
+) -> BenchResult<Vec<CaseResult>> {
+    validate_timing_phase_for_planned_cases(planned, timing_phase, &options.registry)?;
 
     let mut target_order = Vec::<String>::new();
     let mut seen_targets = HashSet::<String>::new();
@@ -192,9 +567,18 @@ pub async fn run_planned_cases(
         }
     }
 
+    let hooks = hooks
+        .clone()
+        .with_case_budgets(case_budgets_for_lane(planned, requested_lane));
+    let matrix_points = planned
+        .iter()
+        .filter_map(|case| Some((case.id.clone(), case.matrix.clone()?)))
+        .collect::<BTreeMap<_, _>>();
+    // Suites reading the narrow-sales rows share one read of them for the whole run.
+    let fixture_rows = FixtureRowCache::new();
     let mut by_target_and_case = HashMap::<(String, String), CaseResult>::new();
     for target in target_order {
-        let target_results = run_target(
+        let target_results = run_target_with_seeds(
             fixtures_dir,
             target.as_str(),
             scale,
@@ -202,9 +586,17 @@ pub async fn run_planned_cases(
             timing_phase,
             warmup,
             iterations,
+            &hooks,
             storage,
+            &fixture_rows,
+            &matrix_points,
+            seeds,
+            options,
         )
+        .instrument(info_span!("target", target = target.as_str()))
         .await?;
+        record_unaudited_cases(&hooks, &target_results);
+        report_unreported_cases(&hooks, &target_results);
         for case in target_results {
             by_target_and_case.insert((target.clone(), case.case.clone()), case);
         }
@@ -219,21 +611,51 @@ pub async fn run_planned_cases(
                 plan.id, plan.target
             ))
         })?;
-        let assertions = assertions_for_requested_lane(plan, requested_lane);
+        case.seed = Some(seeds.for_case(&plan.id));
+        let assertions = assertions_for_requested_lane(plan, &plan.assertions, requested_lane);
         if !assertions.is_empty() {
             apply_case_assertions(&mut case, assertions.as_slice());
         }
+        let warn_assertions =
+            assertions_for_requested_lane(plan, &plan.warn_assertions, requested_lane);
+        if !warn_assertions.is_empty() {
+            observe_case_assertions(&mut case, warn_assertions.as_slice());
+        }
         ordered.push(case);
     }
     Ok(ordered)
 }
 
+/// Correctness and smoke runs are single-pass whatever the manifest says, so only a macro run
+/// takes a case's warmup and iteration overrides; its timeout applies in every lane.
+fn case_budgets_for_lane(
+    planned: &[PlannedCase],
+    requested_lane: BenchmarkLane,
+) -> BTreeMap<String, CaseBudget> {
+    planned
+        .iter()
+        .map(|case| {
+            let mut budget = case.budget;
+            if requested_lane != BenchmarkLane::Macro {
+                budget.warmup = None;
+                budget.iterations = None;
+            }
+            (case.id.clone(), budget)
+        })
+        .filter(|(_, budget)| !budget.is_empty())
+        .collect()
+}
+
 fn validate_timing_phase_for_planned_cases(
     planned: &[PlannedCase],
     timing_phase: TimingPhase,
+    registry: &SuiteRegistry,
 ) -> BenchResult<()> {
     for case in planned {
-        if timing_phase != TimingPhase::Execute && !matches!(case.target.as_str(), "scan" | "tpcds")
+        if timing_phase != TimingPhase::Execute
+            && !registry
+                .get(&case.target)
+                .is_ok_and(|suite| suite.phase_aware())
         {
             return Err(BenchError::InvalidArgument(format!(
                 "planned run cannot use timing_phase={} because target='{}' is not phase-aware yet",
@@ -246,33 +668,7 @@ fn validate_timing_phase_for_planned_cases(
 }
 
 pub fn list_cases_for_target(target: &str) -> BenchResult<Vec<String>> {
-    let canonical_target = canonical_suite_target(target);
-    match canonical_target {
-        "scan" => Ok(scan::case_names()),
-        "write" => Ok(write::case_names()),
-        "write_perf" => Ok(write_perf::case_names()),
-        "delete_update" => Ok(delete_update::case_names()),
-        "delete_update_perf" => Ok(delete_update_perf::case_names()),
-        "merge" => Ok(merge::case_names()),
-        "merge_perf" => Ok(merge_perf::case_names()),
-        "metadata" => Ok(metadata::case_names()),
-        "metadata_perf" => Ok(metadata_perf::case_names()),
-        "optimize_perf" => Ok(optimize_perf::case_names()),
-        "optimize_vacuum" => Ok(optimize_vacuum::case_names()),
-        "concurrency" => Ok(concurrency::case_names()),
-        "tpcds" => Ok(tpcds::case_names()),
-        "interop_py" => Ok(interop_py::case_names()),
-        "all" => {
-            let mut names = Vec::new();
-            for suite in DEFAULT_ALL_TARGETS {
-                names.extend(list_cases_for_target(suite)?);
-            }
-            Ok(names)
-        }
-        other => Err(BenchError::InvalidArgument(format!(
-            "unknown suite target: {other}"
-        ))),
-    }
+    builtin_suite_registry().list_cases(canonical_suite_target(target))
 }
 
 fn canonical_suite_target(target: &str) -> &str {
@@ -293,6 +689,21 @@ fn validate_runner_target(runner: RunnerMode, target: &str) -> BenchResult<()> {
     }
 }
 
+/// Merged `aliases` maps of the Rust and Python manifests.
+pub fn load_case_aliases() -> BenchResult<BTreeMap<String, String>> {
+    let mut aliases = BTreeMap::new();
+    for path in [DEFAULT_RUST_MANIFEST_PATH, DEFAULT_PYTHON_MANIFEST_PATH] {
+        let resolved_path = resolve_manifest_path(path);
+        aliases.extend(load_manifest(&resolved_path)?.aliases);
+    }
+    Ok(aliases)
+}
+
+/// The current id of case `name`, following a manifest `aliases` rename.
+pub fn canonical_case_name<'a>(name: &'a str, aliases: &'a BTreeMap<String, String>) -> &'a str {
+    aliases.get(name).map_or(name, String::as_str)
+}
+
 fn plan_cases_from_manifest(target: &str, runner: RunnerMode) -> BenchResult<Vec<PlannedCase>> {
     plan_cases_from_manifest_paths(
         target,
@@ -337,13 +748,18 @@ fn append_manifest_cases(
             "failed to load required manifest '{display_path}': {error}"
         ))
     })?;
-    let manifest_hash = std::fs::read(&resolved_path)
-        .map(|bytes| hash_bytes(&bytes))
-        .map_err(|error| {
+    // Included manifests are hashed with the manifest itself, so editing a shared case
+    // changes the hash of every manifest that includes it.
+    let mut manifest_bytes = Vec::new();
+    for source in &manifest.source_files {
+        let bytes = std::fs::read(source).map_err(|error| {
             BenchError::InvalidArgument(format!(
                 "failed to load required manifest '{display_path}': {error}"
             ))
         })?;
+        manifest_bytes.extend(bytes);
+    }
+    let manifest_hash = hash_bytes(&manifest_bytes);
 
     for case in manifest.cases {
         if !case.enabled {
@@ -358,46 +774,244 @@ fn append_manifest_cases(
         if target != "all" && case.target != target {
             continue;
         }
-        let case_definition_hash = hash_json(&case)?;
-        out.push(PlannedCase {
-            id: case.id,
-            target: case.target,
-            lane: case.lane,
-            assertions: case
-                .assertions
-                .iter()
-                .map(|assertion| assertion.to_case_assertion())
-                .collect(),
-            suite_manifest_hash: manifest_hash.clone(),
-            case_definition_hash,
-            supports_decision: case.supports_decision.unwrap_or(false),
-            required_runs: case.required_runs,
-            decision_threshold_pct: case.decision_threshold_pct,
-            decision_metric: case.decision_metric,
-        });
+        if case.matrix.is_empty() {
+            // Tags only select cases, severity only changes how a violation is reported, and the
+            // interop workload only pairs the case with another runner's, so none of them may
+            // start a new comparison series.
+            let case_definition_hash = hash_json(&ManifestCase {
+                tags: Vec::new(),
+                assertions: assertions_without_severity(&case),
+                interop_workload: None,
+                ..case.clone()
+            })?;
+            let id = case.id.clone();
+            out.push(planned_manifest_case(
+                case,
+                id,
+                &manifest_hash,
+                case_definition_hash,
+                None,
+            ));
+            continue;
+        }
+
+        let template = matrix_template(&case)?;
+        for (id, parameters) in expand_case_matrix(&case) {
+            // Each point is defined by its own parameter values rather than the whole sweep, so
+            // adding a value to the matrix leaves the existing points' series intact.
+            let case_definition_hash = hash_json(&ManifestCase {
+                id: id.clone(),
+                tags: Vec::new(),
+                assertions: assertions_without_severity(&case),
+                matrix: parameters
+                    .iter()
+                    .map(|(name, value)| (name.clone(), vec![*value]))
+                    .collect(),
+                ..case.clone()
+            })?;
+            let point = MatrixPoint {
+                template: template.name.to_string(),
+                parameters,
+            };
+            out.push(planned_manifest_case(
+                case.clone(),
+                id,
+                &manifest_hash,
+                case_definition_hash,
+                Some(point),
+            ));
+        }
     }
     Ok(())
 }
 
+fn planned_manifest_case(
+    case: ManifestCase,
+    id: String,
+    manifest_hash: &str,
+    case_definition_hash: String,
+    matrix: Option<MatrixPoint>,
+) -> PlannedCase {
+    PlannedCase {
+        id,
+        target: case.target,
+        lane: case.lane,
+        assertions: case_assertions(&case, AssertionSeverity::Fail),
+        warn_assertions: case_assertions(&case, AssertionSeverity::Warn),
+        suite_manifest_hash: manifest_hash.to_string(),
+        case_definition_hash,
+        supports_decision: case.supports_decision.unwrap_or(false),
+        required_runs: case.required_runs,
+        decision_threshold_pct: case.decision_threshold_pct,
+        decision_metric: case.decision_metric,
+        depends_on: case.depends_on,
+        budget: CaseBudget {
+            warmup: case.warmup,
+            iterations: case.iterations,
+            timeout_ms: case.timeout_ms,
+        },
+        tags: case.tags,
+        interop_workload: case.interop_workload,
+        matrix,
+    }
+}
+
+fn case_assertions(case: &ManifestCase, severity: AssertionSeverity) -> Vec<CaseAssertion> {
+    case.assertions
+        .iter()
+        .filter(|entry| entry.severity == severity)
+        .map(|entry| entry.assertion.to_case_assertion())
+        .collect()
+}
+
+fn assertions_without_severity(case: &ManifestCase) -> Vec<ManifestCaseAssertion> {
+    case.assertions
+        .iter()
+        .map(|entry| ManifestCaseAssertion {
+            severity: AssertionSeverity::Fail,
+            ..entry.clone()
+        })
+        .collect()
+}
+
+/// Suite cases that manifests can sweep with `matrix:`.
+fn matrix_templates(target: &str) -> &'static [MatrixTemplate] {
+    match target {
+        "merge" => &merge::MATRIX_TEMPLATES,
+        _ => &[],
+    }
+}
+
+/// A matrix case's id names the template it sweeps, and every parameter it sweeps must be
+/// one the template accepts.
+fn matrix_template(case: &ManifestCase) -> BenchResult<MatrixTemplate> {
+    let templates = matrix_templates(&case.target);
+    let Some(template) = templates.iter().find(|template| template.name == case.id) else {
+        let names = templates
+            .iter()
+            .map(|template| template.name)
+            .collect::<Vec<_>>();
+        return Err(BenchError::InvalidArgument(format!(
+            "matrix case '{}' does not name a matrix template of target '{}' (expected one of: {})",
+            case.id,
+            case.target,
+            if names.is_empty() {
+                "none".to_string()
+            } else {
+                names.join(", ")
+            }
+        )));
+    };
+    for name in case.matrix.keys() {
+        if !template.parameters.contains(&name.as_str()) {
+            return Err(BenchError::InvalidArgument(format!(
+                "matrix case '{}' sweeps '{name}', which template '{}' does not accept (expected one of: {})",
+                case.id,
+                template.name,
+                template.parameters.join(", ")
+            )));
+        }
+    }
+    Ok(*template)
+}
+
+/// `custom_sql` cases come from the user's query directory rather than the manifest. The
+/// directory fingerprint stands in for the manifest hash; no case carries assertions or
+/// supports decisions.
+fn plan_custom_sql_cases(
+    workload: &custom_sql::CustomSqlWorkload,
+) -> BenchResult<Vec<PlannedCase>> {
+    let suite_manifest_hash = workload.definition_hash()?;
+    workload
+        .queries
+        .iter()
+        .map(|query| {
+            Ok(PlannedCase {
+                id: query.case.clone(),
+                target: "custom_sql".to_string(),
+                lane: BenchmarkLane::Macro.as_str().to_string(),
+                assertions: Vec::new(),
+                warn_assertions: Vec::new(),
+                suite_manifest_hash: suite_manifest_hash.clone(),
+                case_definition_hash: hash_json(&json!({
+                    "id": query.case,
+                    "sql_hash": hash_bytes(query.sql.as_bytes()),
+                }))?,
+                supports_decision: false,
+                required_runs: None,
+                decision_threshold_pct: None,
+                decision_metric: None,
+                depends_on: Vec::new(),
+                budget: CaseBudget::default(),
+                tags: Vec::new(),
+                interop_workload: None,
+                matrix: None,
+            })
+        })
+        .collect()
+}
+
+/// Suites registered by an embedding crate have no manifest entries, so every case they list is
+/// planned, without assertions, like a `custom_sql` query.
+fn plan_registered_suite_cases(suite: &dyn Suite) -> BenchResult<Vec<PlannedCase>> {
+    let cases = suite.case_names()?;
+    let suite_manifest_hash = hash_json(&json!({
+        "suite": suite.name(),
+        "cases": cases,
+    }))?;
+    cases
+        .into_iter()
+        .map(|case| {
+            Ok(PlannedCase {
+                case_definition_hash: hash_json(&json!({
+                    "suite": suite.name(),
+                    "id": case,
+                }))?,
+                id: case,
+                target: suite.name().to_string(),
+                lane: BenchmarkLane::Macro.as_str().to_string(),
+                assertions: Vec::new(),
+                warn_assertions: Vec::new(),
+                suite_manifest_hash: suite_manifest_hash.clone(),
+                supports_decision: false,
+                required_runs: None,
+                decision_threshold_pct: None,
+                decision_metric: None,
+                depends_on: Vec::new(),
+                budget: CaseBudget::default(),
+                tags: Vec::new(),
+                interop_workload: None,
+                matrix: None,
+            })
+        })
+        .collect()
+}
+
 fn assertions_for_requested_lane(
     plan: &PlannedCase,
+    assertions: &[CaseAssertion],
     requested_lane: BenchmarkLane,
 ) -> Vec<CaseAssertion> {
     match requested_lane {
-        BenchmarkLane::Correctness => plan.assertions.clone(),
-        BenchmarkLane::Macro if plan.lane == BenchmarkLane::Correctness.as_str() => plan
-            .assertions
+        BenchmarkLane::Correctness => assertions
+            .iter()
+            .filter(|assertion| !assertion.is_latency_budget())
+            .cloned()
+            .collect(),
+        BenchmarkLane::Macro if plan.lane == BenchmarkLane::Correctness.as_str() => assertions
             .iter()
             .filter(|assertion| {
-                matches!(
-                    assertion,
-                    CaseAssertion::ExpectedErrorContains(_) | CaseAssertion::VersionMonotonicity
-                )
+                assertion.is_latency_budget()
+                    || assertion.is_metric_bound()
+                    || matches!(
+                        assertion,
+                        CaseAssertion::ExpectedErrorContains(_)
+                            | CaseAssertion::VersionMonotonicity
+                    )
             })
             .cloned()
             .collect(),
-        BenchmarkLane::Smoke => plan
-            .assertions
+        BenchmarkLane::Smoke => assertions
             .iter()
             .filter(|assertion| {
                 matches!(
@@ -407,7 +1021,7 @@ fn assertions_for_requested_lane(
             })
             .cloned()
             .collect(),
-        BenchmarkLane::Macro => plan.assertions.clone(),
+        BenchmarkLane::Macro => assertions.to_vec(),
     }
 }
 
@@ -421,9 +1035,10 @@ fn resolve_manifest_path(path: &str) -> PathBuf {
         .join(candidate)
 }
 
-fn reject_duplicate_planned_case_ids(planned: &[PlannedCase]) -> BenchResult<()> {
+fn validate_planned_case_ids(planned: &[PlannedCase]) -> BenchResult<()> {
     let mut seen = HashSet::new();
     for case in planned {
+        validate_case_id(&case.id)?;
         if !seen.insert(case.id.as_str()) {
             return Err(BenchError::InvalidArgument(format!(
                 "planned case list contains duplicate case id '{}'",
@@ -434,6 +1049,7 @@ fn reject_duplicate_planned_case_ids(planned: &[PlannedCase]) -> BenchResult<()>
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn run_single_suite(
     fixtures_dir: &Path,
     suite: &str,
@@ -442,155 +1058,42 @@ async fn run_single_suite(
     timing_phase: TimingPhase,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
+    matrix_points: &BTreeMap<String, MatrixPoint>,
+    seeds: &CaseSeeds,
+    options: &SuiteOptions,
 ) -> BenchResult<Vec<CaseResult>> {
+    let suite = options.registry.get(suite)?;
     validate_timing_phase_for_suite(suite, timing_phase)?;
-    match suite {
-        "scan" => {
-            scan::run(
-                fixtures_dir,
-                scale,
-                timing_phase,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "write" => {
-            write::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "write_perf" => write_perf::run(fixtures_dir, scale, warmup, iterations, storage).await,
-        "delete_update" => {
-            delete_update::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "delete_update_perf" => {
-            delete_update_perf::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "merge" => {
-            merge::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "merge_perf" => {
-            merge_perf::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "metadata" => {
-            metadata::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "metadata_perf" => {
-            metadata_perf::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "optimize_perf" => {
-            optimize_perf::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "optimize_vacuum" => {
-            optimize_vacuum::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "concurrency" => concurrency::run(fixtures_dir, scale, warmup, iterations, storage).await,
-        "tpcds" => {
-            tpcds::run(
-                fixtures_dir,
-                scale,
-                timing_phase,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        "interop_py" => {
-            interop_py::run(
-                fixtures_dir,
-                scale,
-                requested_lane,
-                warmup,
-                iterations,
-                storage,
-            )
-            .await
-        }
-        other => Err(BenchError::InvalidArgument(format!(
-            "unknown suite target: {other}"
-        ))),
-    }
+    suite
+        .run(SuiteRunArgs {
+            fixtures_dir,
+            scale,
+            requested_lane,
+            timing_phase,
+            warmup,
+            iterations,
+            hooks,
+            storage,
+            fixture_rows,
+            matrix_points,
+            seeds,
+            options,
+        })
+        .await
 }
 
-fn validate_timing_phase_for_suite(suite: &str, timing_phase: TimingPhase) -> BenchResult<()> {
-    if timing_phase != TimingPhase::Execute && !matches!(suite, "scan" | "tpcds") {
+fn validate_timing_phase_for_suite(
+    suite: &dyn Suite,
+    timing_phase: TimingPhase,
+) -> BenchResult<()> {
+    if timing_phase != TimingPhase::Execute && !suite.phase_aware() {
         return Err(BenchError::InvalidArgument(format!(
-            "timing_phase={} is not supported for target='{suite}'",
-            timing_phase.as_str()
+            "timing_phase={} is not supported for target='{}'",
+            timing_phase.as_str(),
+            suite.name()
         )));
     }
     Ok(())
@@ -605,6 +1108,40 @@ pub async fn run_target(
     warmup: u32,
     iterations: u32,
     storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    run_target_with_seeds(
+        fixtures_dir,
+        target,
+        scale,
+        requested_lane,
+        timing_phase,
+        warmup,
+        iterations,
+        &CaseHooks::default(),
+        storage,
+        &FixtureRowCache::new(),
+        &BTreeMap::new(),
+        &CaseSeeds::default(),
+        &SuiteOptions::default(),
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_target_with_seeds(
+    fixtures_dir: &Path,
+    target: &str,
+    scale: &str,
+    requested_lane: BenchmarkLane,
+    timing_phase: TimingPhase,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
+    matrix_points: &BTreeMap<String, MatrixPoint>,
+    seeds: &CaseSeeds,
+    options: &SuiteOptions,
 ) -> BenchResult<Vec<CaseResult>> {
     let canonical_target = canonical_suite_target(target);
     if canonical_target == "all" {
@@ -621,7 +1158,12 @@ pub async fn run_target(
         timing_phase,
         warmup,
         iterations,
+        hooks,
         storage,
+        fixture_rows,
+        matrix_points,
+        seeds,
+        options,
     )
     .await
 }
@@ -728,4 +1270,63 @@ cases:
             vec!["write_append_small"]
         );
     }
+
+    #[test]
+    fn manifest_planning_expands_matrix_cases_into_template_points() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let rust_manifest = temp.path().join("rust.yaml");
+        let python_manifest = temp.path().join("python.yaml");
+        fs::write(
+            &python_manifest,
+            "id: core-python\ndescription: test\ncases: []\n",
+        )
+        .expect("write valid python manifest");
+        let plan = |matrix: &str| {
+            fs::write(
+                &rust_manifest,
+                format!(
+                    "id: core-rust\ndescription: test\ncases:\n  - id: merge_upsert\n    target: merge\n    matrix:\n      {matrix}\n"
+                ),
+            )
+            .expect("write rust manifest");
+            plan_cases_from_manifest_paths(
+                "merge",
+                RunnerMode::Rust,
+                rust_manifest.to_str().expect("utf8 path"),
+                python_manifest.to_str().expect("utf8 path"),
+            )
+        };
+
+        let planned = plan("match_ratio: [0.25, 0.75]").expect("planning should succeed");
+        assert_eq!(
+            planned
+                .iter()
+                .map(|case| case.id.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "merge_upsert_match_ratio_0p25",
+                "merge_upsert_match_ratio_0p75"
+            ]
+        );
+        let point = planned[0].matrix.as_ref().expect("matrix point");
+        assert_eq!(point.template, "merge_upsert");
+        assert_eq!(point.parameters.get("match_ratio"), Some(&0.25));
+        assert_ne!(
+            planned[0].case_definition_hash,
+            planned[1].case_definition_hash
+        );
+
+        let widened = plan("match_ratio: [0.25, 0.5, 0.75]").expect("planning should succeed");
+        assert_eq!(
+            widened[0].case_definition_hash, planned[0].case_definition_hash,
+            "adding a value must not change the hash of existing points"
+        );
+
+        let err = plan("rows: [10]").expect_err("unsupported parameter must fail");
+        assert!(
+            err.to_string()
+                .contains("which template 'merge_upsert' does not accept"),
+            "unexpected error: {err}"
+        );
+    }
 }
@@ -0,0 +1,247 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use deltalake_core::datafusion::physical_plan::collect;
+use deltalake_core::datafusion::prelude::SessionContext;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::into_case_result;
+use super::scan_metrics::extract_scan_metrics;
+use super::tpcds::registration::{register_table, tpcds_table_resolver, TpcdsLayout};
+use super::{Suite, SuiteRunArgs};
+use crate::data::fixtures::{
+    delete_update_small_files_table_url, merge_partitioned_target_table_url,
+    merge_target_table_url, narrow_sales_table_url, optimize_compacted_table_url,
+    read_partitioned_table_url,
+};
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::storage::StorageConfig;
+
+type TableUrlFn = fn(&Path, &str, &StorageConfig) -> BenchResult<Url>;
+
+/// Narrow-schema fixtures and the names they are registered under. All six share the
+/// `narrow_sales` columns, so they can be unioned and joined on `id`.
+const NARROW_TABLES: [(&str, TableUrlFn); 6] = [
+    ("narrow_sales", narrow_sales_table_url),
+    ("read_partitioned", read_partitioned_table_url),
+    ("merge_target", merge_target_table_url),
+    (
+        "merge_partitioned_target",
+        merge_partitioned_target_table_url,
+    ),
+    (
+        "delete_update_small_files",
+        delete_update_small_files_table_url,
+    ),
+    ("optimize_compacted", optimize_compacted_table_url),
+];
+/// TPC-DS fact and dimension tables, registered from the unpartitioned layout.
+const TPCDS_TABLES: [&str; 4] = ["store_sales", "date_dim", "item", "customer"];
+
+#[derive(Clone, Copy)]
+struct MultiTableCase {
+    name: &'static str,
+    sql: &'static str,
+    /// Registered tables the query reads; the rest stay registered but untouched.
+    tables_scanned: u64,
+}
+
+const MULTI_TABLE_CASES: [MultiTableCase; 4] = [
+    MultiTableCase {
+        name: "multi_table_union_all",
+        sql: "SELECT region, COUNT(*) AS row_count, SUM(value_i64) AS value_sum \
+              FROM (\
+                SELECT region, value_i64 FROM narrow_sales \
+                UNION ALL SELECT region, value_i64 FROM read_partitioned \
+                UNION ALL SELECT region, value_i64 FROM merge_target \
+                UNION ALL SELECT region, value_i64 FROM merge_partitioned_target \
+                UNION ALL SELECT region, value_i64 FROM delete_update_small_files \
+                UNION ALL SELECT region, value_i64 FROM optimize_compacted\
+              ) AS unioned \
+              GROUP BY region",
+        tables_scanned: 6,
+    },
+    MultiTableCase {
+        name: "multi_table_join",
+        sql: "SELECT s.region, COUNT(*) AS row_count, SUM(p.value_i64) AS value_sum \
+              FROM narrow_sales s \
+              JOIN read_partitioned p ON s.id = p.id \
+              JOIN delete_update_small_files d ON s.id = d.id \
+              GROUP BY s.region",
+        tables_scanned: 3,
+    },
+    MultiTableCase {
+        name: "multi_table_star_join",
+        sql: "SELECT i.i_category, COUNT(*) AS row_count, SUM(ss.ss_ext_sales_price) AS sales, \
+                MIN(d.d_year) AS first_year, MAX(c.c_birth_year) AS max_birth_year \
+              FROM store_sales ss \
+              JOIN date_dim d ON ss.ss_sold_date_sk = d.d_date_sk \
+              JOIN item i ON ss.ss_item_sk = i.i_item_sk \
+              JOIN customer c ON ss.ss_customer_sk = c.c_customer_sk \
+              GROUP BY i.i_category",
+        tables_scanned: 4,
+    },
+    MultiTableCase {
+        name: "multi_table_count_all",
+        sql: "SELECT 'narrow_sales' AS table_name, COUNT(*) AS row_count FROM narrow_sales \
+              UNION ALL SELECT 'read_partitioned', COUNT(*) FROM read_partitioned \
+              UNION ALL SELECT 'merge_target', COUNT(*) FROM merge_target \
+              UNION ALL SELECT 'merge_partitioned_target', COUNT(*) FROM merge_partitioned_target \
+              UNION ALL SELECT 'delete_update_small_files', COUNT(*) \
+                FROM delete_update_small_files \
+              UNION ALL SELECT 'optimize_compacted', COUNT(*) FROM optimize_compacted \
+              UNION ALL SELECT 'store_sales', COUNT(*) FROM store_sales \
+              UNION ALL SELECT 'date_dim', COUNT(*) FROM date_dim \
+              UNION ALL SELECT 'item', COUNT(*) FROM item \
+              UNION ALL SELECT 'customer', COUNT(*) FROM customer",
+        tables_scanned: 10,
+    },
+];
+
+pub fn case_names() -> Vec<String> {
+    MULTI_TABLE_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct MultiTableSuite;
+
+impl Suite for MultiTableSuite {
+    fn name(&self) -> &'static str {
+        "multi_table"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    let tables = multi_table_urls(fixtures_dir, scale, storage)?;
+
+    let mut out = Vec::new();
+    for case in MULTI_TABLE_CASES {
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let storage = storage.clone();
+                let tables = tables.clone();
+                async move {
+                    register_all_tables(&storage, tables)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+            |ctx| async move { run_query(ctx, case).await.map_err(|e| e.to_string()) },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+fn multi_table_urls(
+    fixtures_dir: &Path,
+    scale: &str,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<(&'static str, Url)>> {
+    let mut tables = NARROW_TABLES
+        .iter()
+        .map(|(table_name, table_url)| Ok((*table_name, table_url(fixtures_dir, scale, storage)?)))
+        .collect::<BenchResult<Vec<_>>>()?;
+    let resolve = tpcds_table_resolver(fixtures_dir, scale, storage, TpcdsLayout::Unpartitioned);
+    for table_name in TPCDS_TABLES {
+        tables.push((table_name, resolve(table_name)?));
+    }
+    Ok(tables)
+}
+
+/// Every case sees the same ten-table catalog in one `SessionContext`, registered during
+/// untimed setup, so the timed section covers planning across providers and the scans.
+async fn register_all_tables(
+    storage: &StorageConfig,
+    tables: Vec<(&'static str, Url)>,
+) -> BenchResult<SessionContext> {
+    let ctx = SessionContext::new();
+    for (table_name, table_url) in tables {
+        register_table(&ctx, storage, table_name, table_url).await?;
+    }
+    Ok(ctx)
+}
+
+async fn run_query(ctx: SessionContext, case: MultiTableCase) -> BenchResult<SampleMetrics> {
+    let df = ctx.sql(case.sql).await?;
+    let task_ctx = Arc::new(df.task_ctx());
+    let plan = df.create_physical_plan().await?;
+    let batches = collect(Arc::clone(&plan), task_ctx).await?;
+    let rows = batches
+        .iter()
+        .map(|batch| batch.num_rows() as u64)
+        .sum::<u64>();
+    if rows == 0 {
+        return Err(BenchError::InvalidArgument(format!(
+            "{} returned no rows",
+            case.name
+        )));
+    }
+
+    // Group counts are fixed by the fixture generators (six regions, ten item categories, one
+    // row per table), so the hash stays stable across scales without pinning every aggregate.
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "tables_scanned": case.tables_scanned,
+        "result_rows": rows,
+    }))?;
+    let schema_hash = hash_json(&json!([
+        "operation:string",
+        "tables_scanned:u64",
+        "result_rows:u64",
+    ]))?;
+
+    Ok(extract_scan_metrics(&plan, None)
+        .apply(SampleMetrics::base(Some(rows), None, Some(1), None))
+        .with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: None,
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest: None,
+            validation_summary: None,
+        }))
+}
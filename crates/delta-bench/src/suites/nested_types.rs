@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use deltalake_core::arrow::record_batch::RecordBatch;
+use deltalake_core::protocol::SaveMode;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::{fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::TimingPhase;
+use crate::data::fixtures::{nested_table_batch, nested_table_path, nested_table_url};
+use crate::data::scales::scale_definition;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, run_case_async_with_timing_phase, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::suites::scan::run_shape_hashed_query;
+use crate::version_compat::optional_table_version_to_u64;
+
+const NESTED_WRITE_CHUNK_ROWS: usize = 8_192;
+
+const NESTED_SCAN_CASES: [(&str, &str); 3] = [
+    ("nested_full_scan", "SELECT * FROM bench"),
+    (
+        "nested_projection_struct_field",
+        "SELECT attrs['region'] FROM bench",
+    ),
+    (
+        "nested_projection_list_element",
+        "SELECT tags[1] FROM bench",
+    ),
+];
+
+pub fn case_names() -> Vec<String> {
+    let mut names = NESTED_SCAN_CASES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect::<Vec<_>>();
+    names.push("nested_write".to_string());
+    names
+}
+
+pub struct NestedTypesSuite;
+
+impl Suite for NestedTypesSuite {
+    fn name(&self) -> &'static str {
+        "nested_types"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+struct NestedWriteSetup {
+    _temp: tempfile::TempDir,
+    table: DeltaTable,
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if storage.is_local()
+        && !nested_table_path(fixtures_dir, scale)
+            .join("_delta_log")
+            .exists()
+    {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing nested types fixture table; run bench data first",
+        ));
+    }
+
+    let table_url = nested_table_url(fixtures_dir, scale, storage)?;
+    let mut out = Vec::new();
+    for (name, sql) in NESTED_SCAN_CASES {
+        let c = run_case_async_with_timing_phase(
+            name,
+            warmup,
+            iterations,
+            hooks,
+            TimingPhase::Execute,
+            || {
+                let storage = storage.clone();
+                let table_url = table_url.clone();
+                async move {
+                    run_shape_hashed_query(&storage, table_url, sql, name)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    if !storage.is_local() {
+        out.extend(fixture_error_cases(
+            vec!["nested_write".to_string()],
+            "nested_write does not support non-local storage backend yet",
+        ));
+        return Ok(out);
+    }
+
+    let rows = scale_definition(scale)?.nested_table_rows();
+    let batches = (0..rows)
+        .step_by(NESTED_WRITE_CHUNK_ROWS)
+        .map(|start| nested_table_batch(start..(start + NESTED_WRITE_CHUNK_ROWS).min(rows)))
+        .collect::<BenchResult<Vec<_>>>()?;
+    let c = run_case_async_with_async_setup(
+        "nested_write",
+        warmup,
+        iterations,
+        hooks,
+        || async { prepare_write_target().await.map_err(|e| e.to_string()) },
+        |setup| {
+            let batches = batches.clone();
+            async move {
+                run_nested_write(setup, batches)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+    )
+    .await;
+    out.push(into_case_result(c));
+
+    Ok(out)
+}
+
+async fn prepare_write_target() -> BenchResult<NestedWriteSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_url = Url::from_directory_path(temp.path()).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create URL for {}",
+            temp.path().display()
+        ))
+    })?;
+    let table = DeltaTable::try_from_url(table_url).await?;
+    Ok(NestedWriteSetup { _temp: temp, table })
+}
+
+async fn run_nested_write(
+    setup: NestedWriteSetup,
+    batches: Vec<RecordBatch>,
+) -> BenchResult<SampleMetrics> {
+    let _keep_temp = setup._temp;
+    let rows = batches
+        .iter()
+        .map(|batch| batch.num_rows() as u64)
+        .sum::<u64>();
+    let table = setup
+        .table
+        .write(batches)
+        .with_save_mode(SaveMode::Overwrite)
+        .await?;
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let result_hash = hash_json(&json!({
+        "rows_processed": rows,
+        "operations": 1_u64,
+        "table_version": table_version,
+    }))?;
+    let schema_hash = hash_json(&json!([
+        "rows_processed:u64",
+        "operations:u64",
+        "table_version:u64",
+    ]))?;
+
+    Ok(
+        SampleMetrics::base(Some(rows), None, Some(1), table_version).with_runtime_io(
+            RuntimeIOMetrics {
+                peak_rss_mb: None,
+                cpu_time_ms: None,
+                bytes_read: None,
+                bytes_written: None,
+                files_touched: None,
+                files_skipped: None,
+                spill_bytes: None,
+                result_hash: Some(result_hash),
+                schema_hash: Some(schema_hash),
+                semantic_state_digest: None,
+                validation_summary: None,
+            },
+        ),
+    )
+}
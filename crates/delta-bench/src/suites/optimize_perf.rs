@@ -1,46 +1,115 @@
 use std::path::Path;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use serde_json::json;
 use url::Url;
 
+use deltalake_core::datafusion::logical_expr::col;
+use deltalake_core::datafusion::physical_plan::collect;
+use deltalake_core::datafusion::prelude::SessionContext;
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 
-use super::optimize_vacuum::{run_optimize_case, run_vacuum_case, OPTIMIZE_COMPACT_TARGET_SIZE};
-use super::{copy_dir_all, fixture_error_cases, into_case_result};
+use super::delete_update::IntoOptionalRowCount;
+use super::optimize_vacuum::{
+    normalize_target_size, run_optimize_case, run_vacuum_case, OPTIMIZE_COMPACT_TARGET_SIZE,
+};
+use super::scan_metrics::extract_scan_metrics;
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
+use crate::data::datasets::NarrowSaleRow;
 use crate::data::fixtures::{
-    optimize_compacted_table_path, optimize_small_files_table_path, vacuum_ready_table_path,
+    optimize_compacted_table_path, optimize_small_files_table_path, rows_to_batch,
+    vacuum_ready_table_path,
 };
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
-use crate::results::CaseResult;
-use crate::runner::run_case_async_with_async_setup;
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, MaintenanceCycleMetrics, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
 use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
 
 const OPTIMIZE_PERF_DELAY_ENV: &str = "DELTA_BENCH_OPTIMIZE_PERF_DELAY_MS";
 const OPTIMIZE_PERF_ALLOW_DELAY_ENV: &str = "DELTA_BENCH_ALLOW_OPTIMIZE_PERF_DELAY";
 const OPTIMIZE_PERF_VALIDATION_CANARY_CASE_ID: &str = "optimize_perf_compact_small_files";
+const OPTIMIZE_PERF_AFTER_DML_CYCLE_CASE_ID: &str = "optimize_perf_after_dml_cycle";
 
 struct IterationSetup {
     _temp: tempfile::TempDir,
     table: DeltaTable,
 }
 
+/// Table state left behind by the untimed delete + update + merge cycle.
+struct DmlCycleOutcome {
+    table: DeltaTable,
+    dml_ms: u64,
+    rows_deleted: Option<u64>,
+    rows_updated: u64,
+    rows_inserted: u64,
+}
+
 pub fn case_names() -> Vec<String> {
     vec![
         "optimize_perf_compact_small_files".to_string(),
         "optimize_perf_noop_already_compact".to_string(),
         "vacuum_perf_execute_lite".to_string(),
+        OPTIMIZE_PERF_AFTER_DML_CYCLE_CASE_ID.to_string(),
     ]
 }
 
+pub struct OptimizePerfSuite;
+
+impl Suite for OptimizePerfSuite {
+    fn name(&self) -> &'static str {
+        "optimize_perf"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
 ) -> BenchResult<Vec<CaseResult>> {
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
+        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    };
+    let optimize_seed_rows = Arc::new(
+        rows.iter()
+            .take((rows.len() / 2).max(2048))
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+
     if storage.is_local() {
         let optimize_source = optimize_small_files_table_path(fixtures_dir, scale);
         let optimize_compacted_source = optimize_compacted_table_path(fixtures_dir, scale);
@@ -62,6 +131,7 @@ pub async fn run(
             "optimize_perf_compact_small_files",
             warmup,
             iterations,
+            hooks,
             || {
                 let source = optimize_source.clone();
                 let storage = storage.clone();
@@ -88,6 +158,7 @@ pub async fn run(
             "optimize_perf_noop_already_compact",
             warmup,
             iterations,
+            hooks,
             || {
                 let source = optimize_compacted_source.clone();
                 let storage = storage.clone();
@@ -111,6 +182,7 @@ pub async fn run(
             "vacuum_perf_execute_lite",
             warmup,
             iterations,
+            hooks,
             || {
                 let source = vacuum_source.clone();
                 let storage = storage.clone();
@@ -130,20 +202,39 @@ pub async fn run(
         .await;
         out.push(into_case_result(execute));
 
+        let after_dml = run_case_async_with_async_setup(
+            OPTIMIZE_PERF_AFTER_DML_CYCLE_CASE_ID,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let source = optimize_source.clone();
+                let rows = Arc::clone(&optimize_seed_rows);
+                let storage = storage.clone();
+                async move {
+                    let setup = prepare_iteration(&source, &storage)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    let cycle = run_dml_cycle(setup.table, rows.as_slice())
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    Ok::<(tempfile::TempDir, DmlCycleOutcome), String>((setup._temp, cycle))
+                }
+            },
+            |(temp, cycle)| async move {
+                let _keep_temp = temp;
+                run_optimize_after_dml_case(cycle, lane)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+        )
+        .await;
+        out.push(into_case_result(after_dml));
+
         return Ok(out);
     }
 
-    let rows = match crate::data::fixtures::load_rows(fixtures_dir, scale) {
-        Ok(rows) => std::sync::Arc::new(rows),
-        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
-    };
-    let optimize_seed_rows = std::sync::Arc::new(
-        rows.iter()
-            .take((rows.len() / 2).max(2048))
-            .cloned()
-            .collect::<Vec<_>>(),
-    );
-    let vacuum_seed_rows = std::sync::Arc::new(
+    let vacuum_seed_rows = Arc::new(
         rows.iter()
             .take((rows.len() / 3).max(1024))
             .cloned()
@@ -155,9 +246,10 @@ pub async fn run(
         "optimize_perf_compact_small_files",
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
-            let rows = std::sync::Arc::clone(&optimize_seed_rows);
+            let rows = Arc::clone(&optimize_seed_rows);
             async move {
                 let table_url = storage
                     .isolated_table_url(
@@ -197,9 +289,10 @@ pub async fn run(
         "optimize_perf_noop_already_compact",
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
-            let rows = std::sync::Arc::clone(&optimize_seed_rows);
+            let rows = Arc::clone(&optimize_seed_rows);
             async move {
                 let table_url = storage
                     .isolated_table_url(
@@ -235,9 +328,10 @@ pub async fn run(
         "vacuum_perf_execute_lite",
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
-            let rows = std::sync::Arc::clone(&vacuum_seed_rows);
+            let rows = Arc::clone(&vacuum_seed_rows);
             async move {
                 let table_url = storage
                     .isolated_table_url(scale, "vacuum_ready_delta", "vacuum_perf_execute_lite")
@@ -265,6 +359,48 @@ pub async fn run(
     .await;
     out.push(into_case_result(execute));
 
+    let after_dml = run_case_async_with_async_setup(
+        OPTIMIZE_PERF_AFTER_DML_CYCLE_CASE_ID,
+        warmup,
+        iterations,
+        hooks,
+        || {
+            let storage = storage.clone();
+            let rows = Arc::clone(&optimize_seed_rows);
+            async move {
+                let table_url = storage
+                    .isolated_table_url(
+                        scale,
+                        "optimize_small_files_delta",
+                        OPTIMIZE_PERF_AFTER_DML_CYCLE_CASE_ID,
+                    )
+                    .map_err(|e| e.to_string())?;
+                crate::data::fixtures::write_delta_table_small_files(
+                    table_url.clone(),
+                    rows.as_slice(),
+                    128,
+                    &storage,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+                let table = storage
+                    .open_table(table_url)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                run_dml_cycle(table, rows.as_slice())
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+        |cycle| async move {
+            run_optimize_after_dml_case(cycle, lane)
+                .await
+                .map_err(|e| e.to_string())
+        },
+    )
+    .await;
+    out.push(into_case_result(after_dml));
+
     Ok(out)
 }
 
@@ -274,7 +410,7 @@ async fn prepare_iteration(
 ) -> BenchResult<IterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("table");
-    copy_dir_all(source_table_path, &table_dir)?;
+    copy_dir_with(source_table_path, &table_dir, storage.fixture_copy())?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
@@ -285,6 +421,157 @@ async fn prepare_iteration(
     Ok(IterationSetup { _temp: temp, table })
 }
 
+/// Runs the DML cycle a maintenance window typically follows: delete 5%, update 5%, then
+/// merge a CDC batch that updates another 5% and inserts 5% new ids.
+async fn run_dml_cycle(table: DeltaTable, rows: &[NarrowSaleRow]) -> BenchResult<DmlCycleOutcome> {
+    let mut merge_source_rows = Vec::new();
+    for row in rows {
+        match row.id % 20 {
+            2 => {
+                let mut next = row.clone();
+                next.value_i64 = next.value_i64.map(|value| value + 7);
+                next.flag = next.flag.map(|flag| !flag);
+                merge_source_rows.push(next);
+            }
+            3 => {
+                let mut next = row.clone();
+                next.id = next.id.saturating_add(1_000_000_000);
+                merge_source_rows.push(next);
+            }
+            _ => {}
+        }
+    }
+    let merge_source = SessionContext::new().read_batch(rows_to_batch(&merge_source_rows)?)?;
+
+    let start = Instant::now();
+    let (table, delete_metrics) = table.delete().with_predicate("id % 20 = 0").await?;
+    let (table, update_metrics) = table
+        .update()
+        .with_predicate("id % 20 = 1")
+        .with_update("value_i64", "value_i64 + 1")
+        .await?;
+    let (table, merge_metrics) = table
+        .merge(merge_source, col("target.id").eq(col("source.id")))
+        .with_source_alias("source")
+        .with_target_alias("target")
+        .when_matched_update(|update| {
+            update
+                .update("value_i64", col("source.value_i64"))
+                .update("amount", col("source.amount"))
+                .update("flag", col("source.flag"))
+        })?
+        .when_not_matched_insert(|insert| {
+            insert
+                .set("id", col("source.id"))
+                .set("ts_ms", col("source.ts_ms"))
+                .set("region", col("source.region"))
+                .set("value_i64", col("source.value_i64"))
+                .set("flag", col("source.flag"))
+                .set("ts", col("source.ts"))
+                .set("event_date", col("source.event_date"))
+                .set("amount", col("source.amount"))
+        })?
+        .await?;
+    let dml_ms = start.elapsed().as_millis() as u64;
+
+    Ok(DmlCycleOutcome {
+        table,
+        dml_ms,
+        rows_deleted: delete_metrics
+            .num_deleted_rows
+            .into_optional_row_count()
+            .map(|count| count as u64),
+        rows_updated: (update_metrics.num_updated_rows + merge_metrics.num_target_rows_updated)
+            as u64,
+        rows_inserted: merge_metrics.num_target_rows_inserted as u64,
+    })
+}
+
+async fn run_optimize_after_dml_case(
+    cycle: DmlCycleOutcome,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
+    let files_before_optimize = cycle.table.snapshot()?.log_data().num_files() as u64;
+
+    let optimize_start = Instant::now();
+    let (table, optimize_metrics) = cycle
+        .table
+        .optimize()
+        .with_target_size(normalize_target_size(OPTIMIZE_COMPACT_TARGET_SIZE)?.into())
+        .await?;
+    let optimize_ms = optimize_start.elapsed().as_millis() as u64;
+    let files_after_optimize = table.snapshot()?.log_data().num_files() as u64;
+
+    let scan_start = Instant::now();
+    let ctx = SessionContext::new();
+    ctx.register_table("bench", table.table_provider().await?)?;
+    let df = ctx.sql("SELECT * FROM bench").await?;
+    let task_ctx = Arc::new(df.task_ctx());
+    let plan = df.create_physical_plan().await?;
+    let batches = collect(plan.clone(), task_ctx).await?;
+    let scan_ms = scan_start.elapsed().as_millis() as u64;
+    let rows_scanned = batches
+        .iter()
+        .map(|batch| batch.num_rows() as u64)
+        .sum::<u64>();
+    let scan_metrics = extract_scan_metrics(&plan, None);
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    // The cycle's file layout depends on the writer, so only logical row counts are pinned.
+    let result_hash = hash_json(&json!({
+        "operation": OPTIMIZE_PERF_AFTER_DML_CYCLE_CASE_ID,
+        "rows_deleted": cycle.rows_deleted,
+        "rows_updated": cycle.rows_updated,
+        "rows_inserted": cycle.rows_inserted,
+        "rows_scanned": rows_scanned,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "rows_deleted:optional<u64>",
+        "rows_updated:u64",
+        "rows_inserted:u64",
+        "rows_scanned:u64",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        let validation = validate_table_state(&table).await?;
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+
+    Ok(scan_metrics
+        .apply(SampleMetrics::base(
+            Some(rows_scanned),
+            None,
+            Some(optimize_metrics.num_files_added + optimize_metrics.num_files_removed),
+            table_version,
+        ))
+        .with_maintenance_cycle(MaintenanceCycleMetrics {
+            dml_ms: cycle.dml_ms,
+            optimize_ms,
+            scan_ms,
+            files_before_optimize,
+            files_after_optimize,
+        })
+        .with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: None,
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest,
+            validation_summary,
+        }))
+}
+
 async fn apply_validation_delay(case_id: &str) -> BenchResult<()> {
     let Some(delay) = parse_validation_delay(case_id)? else {
         return Ok(());
@@ -1,53 +1,134 @@
 use std::num::NonZeroU64;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 use chrono::Duration as ChronoDuration;
 use serde_json::json;
+use tokio::task::JoinHandle;
 use url::Url;
 
+use deltalake_core::datafusion::prelude::SessionContext;
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 
-use super::{copy_dir_all, fixture_error_cases, into_case_result};
+use super::{
+    copy_dir_with, dependency_failed_case, fixture_error_cases, into_case_result, Suite,
+    SuiteRunArgs, TableStateHandoff,
+};
 use crate::cli::BenchmarkLane;
 use crate::data::fixtures::{
-    load_rows, optimize_compacted_table_path, optimize_small_files_table_path,
-    vacuum_ready_table_path, write_delta_table, write_delta_table_small_files,
-    write_vacuum_ready_table,
+    optimize_compacted_table_path, optimize_small_files_table_path, vacuum_ready_table_path,
+    write_delta_table, write_delta_table_small_files, write_vacuum_ready_table,
 };
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
-use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
-use crate::runner::run_case_async_with_async_setup;
+use crate::results::{
+    CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics, VacuumReadMetrics,
+};
+use crate::runner::{
+    run_case_async_with_async_setup, run_case_async_with_async_setup_custom_timing,
+    unsupported_case_result, CaseHooks,
+};
+use crate::stats::nearest_rank;
 use crate::storage::StorageConfig;
+use crate::trace::TraceOperation;
 use crate::validation::{lane_requires_semantic_validation, validate_table_state};
 use crate::version_compat::optional_table_version_to_u64;
 
 pub(crate) const OPTIMIZE_COMPACT_TARGET_SIZE: u64 = 1_000_000;
 const OPTIMIZE_HEAVY_TARGET_SIZE: u64 = 64_000;
+const OPTIMIZE_HEAVY_CASE: &str = "optimize_heavy_compaction";
+/// Depends on [`OPTIMIZE_HEAVY_CASE`]: vacuums the files its last iteration compacted away.
+const VACUUM_AFTER_HEAVY_CASE: &str = "vacuum_after_heavy_compaction";
+const VACUUM_CONCURRENT_READS_CASE: &str = "vacuum_execute_with_concurrent_reads";
+const VACUUM_READER_COUNT: usize = 2;
+/// Reads per reader before the vacuum starts; their median is the latency baseline.
+const VACUUM_BASELINE_READS_PER_READER: usize = 3;
 
 struct IterationSetup {
     _temp: tempfile::TempDir,
+    table_dir: PathBuf,
     table: DeltaTable,
+    table_url: Url,
+}
+
+struct ReadOutcome {
+    elapsed_ms: f64,
+    rows: Result<u64, String>,
+}
+
+/// Compaction cases as single-step replay traces. The no-op case only differs from
+/// `optimize_compact_small_files` by fixture, and vacuum has no trace equivalent.
+pub(crate) fn trace_operations() -> Vec<(&'static str, Vec<TraceOperation>)> {
+    vec![
+        (
+            "optimize_compact_small_files",
+            vec![TraceOperation::Optimize {
+                target_size: Some(OPTIMIZE_COMPACT_TARGET_SIZE),
+            }],
+        ),
+        (
+            OPTIMIZE_HEAVY_CASE,
+            vec![TraceOperation::Optimize {
+                target_size: Some(OPTIMIZE_HEAVY_TARGET_SIZE),
+            }],
+        ),
+    ]
 }
 
 pub fn case_names() -> Vec<String> {
     vec![
         "optimize_compact_small_files".to_string(),
         "optimize_noop_already_compact".to_string(),
-        "optimize_heavy_compaction".to_string(),
+        OPTIMIZE_HEAVY_CASE.to_string(),
+        VACUUM_AFTER_HEAVY_CASE.to_string(),
         "vacuum_dry_run_lite".to_string(),
         "vacuum_execute_lite".to_string(),
+        VACUUM_CONCURRENT_READS_CASE.to_string(),
     ]
 }
 
+pub struct OptimizeVacuumSuite;
+
+impl Suite for OptimizeVacuumSuite {
+    fn name(&self) -> &'static str {
+        "optimize_vacuum"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
 ) -> BenchResult<Vec<CaseResult>> {
     if storage.is_local() {
         let optimize_source = optimize_small_files_table_path(fixtures_dir, scale);
@@ -70,6 +151,7 @@ pub async fn run(
             "optimize_compact_small_files",
             warmup,
             iterations,
+            hooks,
             || {
                 let source = optimize_source.clone();
                 let storage = storage.clone();
@@ -93,6 +175,7 @@ pub async fn run(
             "optimize_noop_already_compact",
             warmup,
             iterations,
+            hooks,
             || {
                 let source = optimize_compacted_source.clone();
                 let storage = storage.clone();
@@ -112,10 +195,12 @@ pub async fn run(
         .await;
         out.push(into_case_result(noop));
 
+        let heavy_state = TableStateHandoff::default();
         let heavy = run_case_async_with_async_setup(
-            "optimize_heavy_compaction",
+            OPTIMIZE_HEAVY_CASE,
             warmup,
             iterations,
+            hooks,
             || {
                 let source = optimize_source.clone();
                 let storage = storage.clone();
@@ -125,20 +210,58 @@ pub async fn run(
                         .map_err(|e| e.to_string())
                 }
             },
-            |setup| async move {
-                let _keep_temp = setup._temp;
-                run_optimize_case(setup.table, OPTIMIZE_HEAVY_TARGET_SIZE, lane)
-                    .await
-                    .map_err(|e| e.to_string())
+            |setup| {
+                let heavy_state = heavy_state.clone();
+                async move {
+                    let metrics = run_optimize_case(setup.table, OPTIMIZE_HEAVY_TARGET_SIZE, lane)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    heavy_state.keep(setup._temp, setup.table_dir);
+                    Ok::<SampleMetrics, String>(metrics)
+                }
             },
         )
         .await;
-        out.push(into_case_result(heavy));
+        let heavy = into_case_result(heavy);
+
+        let after_heavy = if heavy.success {
+            let after_heavy = run_case_async_with_async_setup(
+                VACUUM_AFTER_HEAVY_CASE,
+                warmup,
+                iterations,
+                hooks,
+                || {
+                    let heavy_state = heavy_state.clone();
+                    let storage = storage.clone();
+                    async move {
+                        let source = heavy_state
+                            .table_dir(VACUUM_AFTER_HEAVY_CASE)
+                            .map_err(|e| e.to_string())?;
+                        prepare_iteration(&source, &storage)
+                            .await
+                            .map_err(|e| e.to_string())
+                    }
+                },
+                |setup| async move {
+                    let _keep_temp = setup._temp;
+                    run_vacuum_case(setup.table, false, lane)
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+            )
+            .await;
+            into_case_result(after_heavy)
+        } else {
+            dependency_failed_case(VACUUM_AFTER_HEAVY_CASE, OPTIMIZE_HEAVY_CASE)
+        };
+        out.push(heavy);
+        out.push(after_heavy);
 
         let dry_run = run_case_async_with_async_setup(
             "vacuum_dry_run_lite",
             warmup,
             iterations,
+            hooks,
             || {
                 let source = vacuum_source.clone();
                 let storage = storage.clone();
@@ -162,6 +285,7 @@ pub async fn run(
             "vacuum_execute_lite",
             warmup,
             iterations,
+            hooks,
             || {
                 let source = vacuum_source.clone();
                 let storage = storage.clone();
@@ -181,11 +305,38 @@ pub async fn run(
         .await;
         out.push(into_case_result(execute));
 
+        let concurrent_reads = run_case_async_with_async_setup_custom_timing(
+            VACUUM_CONCURRENT_READS_CASE,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let source = vacuum_source.clone();
+                let storage = storage.clone();
+                async move {
+                    prepare_iteration(&source, &storage)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+            |setup| {
+                let storage = storage.clone();
+                async move {
+                    let _keep_temp = setup._temp;
+                    run_vacuum_with_concurrent_reads(setup.table, setup.table_url, &storage, lane)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await;
+        out.push(into_case_result(concurrent_reads));
+
         return Ok(out);
     }
 
-    let rows = match load_rows(fixtures_dir, scale) {
-        Ok(rows) => Arc::new(rows),
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
         Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
     };
     let optimize_seed_rows = Arc::new(
@@ -206,6 +357,7 @@ pub async fn run(
         "optimize_compact_small_files",
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
             let rows = Arc::clone(&optimize_seed_rows);
@@ -240,6 +392,7 @@ pub async fn run(
         "optimize_noop_already_compact",
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
             let rows = Arc::clone(&optimize_seed_rows);
@@ -271,19 +424,16 @@ pub async fn run(
     out.push(into_case_result(noop));
 
     let heavy = run_case_async_with_async_setup(
-        "optimize_heavy_compaction",
+        OPTIMIZE_HEAVY_CASE,
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
             let rows = Arc::clone(&optimize_seed_rows);
             async move {
                 let table_url = storage
-                    .isolated_table_url(
-                        scale,
-                        "optimize_small_files_delta",
-                        "optimize_heavy_compaction",
-                    )
+                    .isolated_table_url(scale, "optimize_small_files_delta", OPTIMIZE_HEAVY_CASE)
                     .map_err(|e| e.to_string())?;
                 write_delta_table_small_files(table_url.clone(), rows.as_slice(), 128, &storage)
                     .await
@@ -303,11 +453,19 @@ pub async fn run(
     )
     .await;
     out.push(into_case_result(heavy));
+    out.push(unsupported_case_result(
+        VACUUM_AFTER_HEAVY_CASE,
+        Vec::new(),
+        format!(
+            "skipped: {VACUUM_AFTER_HEAVY_CASE} starts from the table {OPTIMIZE_HEAVY_CASE} leaves in a local directory; run it on the local backend"
+        ),
+    ));
 
     let dry_run = run_case_async_with_async_setup(
         "vacuum_dry_run_lite",
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
             let rows = Arc::clone(&vacuum_seed_rows);
@@ -338,6 +496,7 @@ pub async fn run(
         "vacuum_execute_lite",
         warmup,
         iterations,
+        hooks,
         || {
             let storage = storage.clone();
             let rows = Arc::clone(&vacuum_seed_rows);
@@ -364,6 +523,40 @@ pub async fn run(
     .await;
     out.push(into_case_result(execute));
 
+    let concurrent_reads = run_case_async_with_async_setup_custom_timing(
+        VACUUM_CONCURRENT_READS_CASE,
+        warmup,
+        iterations,
+        hooks,
+        || {
+            let storage = storage.clone();
+            let rows = Arc::clone(&vacuum_seed_rows);
+            async move {
+                let table_url = storage
+                    .isolated_table_url(scale, "vacuum_ready_delta", VACUUM_CONCURRENT_READS_CASE)
+                    .map_err(|e| e.to_string())?;
+                write_vacuum_ready_table(table_url.clone(), rows.as_slice(), &storage)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let table = storage
+                    .open_table(table_url.clone())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok::<(DeltaTable, Url), String>((table, table_url))
+            }
+        },
+        |(table, table_url)| {
+            let storage = storage.clone();
+            async move {
+                run_vacuum_with_concurrent_reads(table, table_url, &storage, lane)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+    )
+    .await;
+    out.push(into_case_result(concurrent_reads));
+
     Ok(out)
 }
 
@@ -431,7 +624,7 @@ pub(crate) async fn run_optimize_case(
     }))
 }
 
-fn normalize_target_size(target_size: u64) -> BenchResult<NonZeroU64> {
+pub(crate) fn normalize_target_size(target_size: u64) -> BenchResult<NonZeroU64> {
     NonZeroU64::new(target_size).ok_or_else(|| {
         BenchError::InvalidArgument("target size must be greater than zero".to_string())
     })
@@ -442,17 +635,31 @@ pub(crate) async fn run_vacuum_case(
     dry_run: bool,
     lane: BenchmarkLane,
 ) -> BenchResult<SampleMetrics> {
+    let (table, files_deleted) = vacuum_zero_retention(table, dry_run).await?;
+    vacuum_sample_metrics(&table, dry_run, files_deleted, lane).await
+}
+
+async fn vacuum_zero_retention(table: DeltaTable, dry_run: bool) -> BenchResult<(DeltaTable, u64)> {
     let (table, metrics) = table
         .vacuum()
         .with_dry_run(dry_run)
         .with_retention_period(ChronoDuration::seconds(0))
         .with_enforce_retention_duration(false)
         .await?;
+    Ok((table, metrics.files_deleted.len() as u64))
+}
+
+async fn vacuum_sample_metrics(
+    table: &DeltaTable,
+    dry_run: bool,
+    files_deleted: u64,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
     let table_version = optional_table_version_to_u64(table.version())?;
     let result_hash = hash_json(&json!({
         "operation": "vacuum",
         "dry_run": dry_run,
-        "files_deleted": metrics.files_deleted.len() as u64,
+        "files_deleted": files_deleted,
         "table_version": table_version,
     }))?;
     let mut schema_hash = hash_json(&json!([
@@ -464,30 +671,168 @@ pub(crate) async fn run_vacuum_case(
     let mut semantic_state_digest = None;
     let mut validation_summary = None;
     if lane_requires_semantic_validation(lane) {
-        let validation = validate_table_state(&table).await?;
+        let validation = validate_table_state(table).await?;
         schema_hash = validation.schema_hash;
         semantic_state_digest = Some(validation.digest);
         validation_summary = Some(validation.summary);
     }
-    Ok(SampleMetrics::base(
-        Some(metrics.files_deleted.len() as u64),
-        None,
-        Some(1),
-        table_version,
+    Ok(
+        SampleMetrics::base(Some(files_deleted), None, Some(1), table_version).with_runtime_io(
+            RuntimeIOMetrics {
+                peak_rss_mb: None,
+                cpu_time_ms: None,
+                bytes_read: None,
+                bytes_written: None,
+                files_touched: None,
+                files_skipped: None,
+                spill_bytes: None,
+                result_hash: Some(result_hash),
+                schema_hash: Some(schema_hash),
+                semantic_state_digest,
+                validation_summary,
+            },
+        ),
     )
-    .with_runtime_io(RuntimeIOMetrics {
-        peak_rss_mb: None,
-        cpu_time_ms: None,
-        bytes_read: None,
-        bytes_written: None,
-        files_touched: None,
-        files_skipped: None,
-        spill_bytes: None,
-        result_hash: Some(result_hash),
-        schema_hash: Some(schema_hash),
-        semantic_state_digest,
-        validation_summary,
-    }))
+}
+
+/// Readers always open the latest snapshot, which references only files a zero-retention
+/// vacuum must keep, so every read has to succeed and return the same rows. The sample's elapsed
+/// time is the vacuum's wall time under that read load.
+async fn run_vacuum_with_concurrent_reads(
+    table: DeltaTable,
+    table_url: Url,
+    storage: &StorageConfig,
+    lane: BenchmarkLane,
+) -> BenchResult<(SampleMetrics, Option<f64>)> {
+    let baseline = join_readers(spawn_readers(
+        storage,
+        &table_url,
+        Some(VACUUM_BASELINE_READS_PER_READER),
+        Arc::new(AtomicBool::new(false)),
+    ))
+    .await?;
+    let (rows_per_read, baseline_latencies) = summarize_reads("baseline", &baseline, None)?;
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let readers = spawn_readers(storage, &table_url, None, Arc::clone(&stop));
+    let vacuum_start = Instant::now();
+    let vacuumed = vacuum_zero_retention(table, false).await;
+    let vacuum_ms = vacuum_start.elapsed().as_secs_f64() * 1000.0;
+    stop.store(true, Ordering::Release);
+    let during = join_readers(readers).await?;
+    let (table, files_deleted) = vacuumed?;
+    let (_, during_latencies) = summarize_reads("during-vacuum", &during, Some(rows_per_read))?;
+
+    let baseline_p50_ms = nearest_rank(&baseline_latencies, 50);
+    let during_p50_ms = nearest_rank(&during_latencies, 50);
+    let vacuum_reads = VacuumReadMetrics {
+        readers: VACUUM_READER_COUNT as u64,
+        baseline_read_p50_ms: baseline_p50_ms,
+        reads_during_vacuum: during.len() as u64,
+        read_p50_ms_during_vacuum: during_p50_ms,
+        read_max_ms_during_vacuum: during_latencies.last().copied().unwrap_or(0.0),
+        read_latency_inflation: if baseline_p50_ms > 0.0 {
+            during_p50_ms / baseline_p50_ms
+        } else {
+            0.0
+        },
+    };
+    let metrics = vacuum_sample_metrics(&table, false, files_deleted, lane)
+        .await?
+        .with_vacuum_reads(vacuum_reads);
+    Ok((metrics, Some(vacuum_ms)))
+}
+
+/// Each reader reads at least once, then keeps going until it has done `max_reads` or `stop`
+/// is set.
+fn spawn_readers(
+    storage: &StorageConfig,
+    table_url: &Url,
+    max_reads: Option<usize>,
+    stop: Arc<AtomicBool>,
+) -> Vec<JoinHandle<Vec<ReadOutcome>>> {
+    (0..VACUUM_READER_COUNT)
+        .map(|_| {
+            let storage = storage.clone();
+            let table_url = table_url.clone();
+            let stop = Arc::clone(&stop);
+            tokio::spawn(async move {
+                let mut outcomes = Vec::new();
+                while max_reads.is_none_or(|max| outcomes.len() < max)
+                    && (outcomes.is_empty() || !stop.load(Ordering::Acquire))
+                {
+                    let start = Instant::now();
+                    let rows = read_latest_rows(&storage, table_url.clone())
+                        .await
+                        .map_err(|e| e.to_string());
+                    outcomes.push(ReadOutcome {
+                        elapsed_ms: start.elapsed().as_secs_f64() * 1000.0,
+                        rows,
+                    });
+                }
+                outcomes
+            })
+        })
+        .collect()
+}
+
+async fn join_readers(handles: Vec<JoinHandle<Vec<ReadOutcome>>>) -> BenchResult<Vec<ReadOutcome>> {
+    let mut out = Vec::new();
+    for handle in handles {
+        out.extend(handle.await.map_err(|error| {
+            BenchError::InvalidArgument(format!("vacuum reader task failed: {error}"))
+        })?);
+    }
+    Ok(out)
+}
+
+async fn read_latest_rows(storage: &StorageConfig, table_url: Url) -> BenchResult<u64> {
+    let table = storage.open_table(table_url).await?;
+    let ctx = SessionContext::new();
+    ctx.register_table("bench", table.table_provider().await?)?;
+    Ok(ctx
+        .sql("SELECT * FROM bench")
+        .await?
+        .collect()
+        .await?
+        .iter()
+        .map(|batch| batch.num_rows() as u64)
+        .sum())
+}
+
+/// Fails on any read error or row-count drift and returns the row count with sorted latencies.
+fn summarize_reads(
+    phase: &str,
+    outcomes: &[ReadOutcome],
+    expected_rows: Option<u64>,
+) -> BenchResult<(u64, Vec<f64>)> {
+    let failures = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.rows.as_ref().err())
+        .collect::<Vec<_>>();
+    if let Some(first) = failures.first() {
+        return Err(BenchError::InvalidArgument(format!(
+            "{VACUUM_CONCURRENT_READS_CASE}: {} of {} {phase} reads failed; first error: {first}",
+            failures.len(),
+            outcomes.len()
+        )));
+    }
+    let rows = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.rows.as_ref().ok().copied())
+        .collect::<Vec<_>>();
+    let expected = expected_rows.or(rows.first().copied()).unwrap_or(0);
+    if rows.iter().any(|&count| count != expected) {
+        return Err(BenchError::InvalidArgument(format!(
+            "{VACUUM_CONCURRENT_READS_CASE}: {phase} reads returned {rows:?} rows, expected {expected}"
+        )));
+    }
+    let mut latencies = outcomes
+        .iter()
+        .map(|outcome| outcome.elapsed_ms)
+        .collect::<Vec<_>>();
+    latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    Ok((expected, latencies))
 }
 
 #[cfg(test)]
@@ -517,13 +862,18 @@ async fn prepare_iteration(
 ) -> BenchResult<IterationSetup> {
     let temp = tempfile::tempdir()?;
     let table_dir = temp.path().join("table");
-    copy_dir_all(source_table_path, &table_dir)?;
+    copy_dir_with(source_table_path, &table_dir, storage.fixture_copy())?;
     let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
         BenchError::InvalidArgument(format!(
             "failed to create table URL for {}",
             table_dir.display()
         ))
     })?;
-    let table = storage.open_table(table_url).await?;
-    Ok(IterationSetup { _temp: temp, table })
+    let table = storage.open_table(table_url.clone()).await?;
+    Ok(IterationSetup {
+        _temp: temp,
+        table_dir,
+        table,
+        table_url,
+    })
 }
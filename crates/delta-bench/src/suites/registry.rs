@@ -0,0 +1,192 @@
+//! Suite dispatch: every `--target` is a [`Suite`] looked up by name in a [`SuiteRegistry`].
+//!
+//! The built-in suites are always registered. With the `custom-suites` feature, crates
+//! embedding the harness register their own suites and plan and run with the registry in
+//! [`SuiteOptions::registry`]; such a suite plans every case it lists, without manifest entries.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use futures::future::LocalBoxFuture;
+
+use super::{
+    checkpoint, concurrency, convert_to_delta, crash_recovery, custom_sql, data_skipping,
+    delete_update, delete_update_perf, deletion_vectors, interop_py, late_arriving, log_replay,
+    merge, merge_perf, metadata, metadata_perf, multi_table, nested_types, optimize_perf,
+    optimize_vacuum, replay, restore, scan, scan_concurrency, scenario, snapshot_isolation, tpcds,
+    wide_table, write, write_perf, MatrixPoint, SuiteOptions, DEFAULT_ALL_TARGETS,
+};
+use crate::cli::{BenchmarkLane, TimingPhase};
+use crate::data::row_cache::FixtureRowCache;
+use crate::error::{BenchError, BenchResult};
+use crate::results::CaseResult;
+use crate::runner::{CaseHooks, CaseSeeds};
+use crate::storage::StorageConfig;
+
+/// What one suite execution runs against, as given to [`Suite::run`].
+#[derive(Clone, Copy)]
+pub struct SuiteRunArgs<'a> {
+    pub fixtures_dir: &'a Path,
+    pub scale: &'a str,
+    pub requested_lane: BenchmarkLane,
+    pub timing_phase: TimingPhase,
+    pub warmup: u32,
+    pub iterations: u32,
+    /// Case budgets and other settings the shared case runners apply to every case.
+    pub hooks: &'a CaseHooks,
+    pub storage: &'a StorageConfig,
+    /// Narrow-sales rows shared with the other suites of the run.
+    pub fixture_rows: &'a FixtureRowCache,
+    /// Planned manifest matrix points, keyed by generated case id.
+    pub matrix_points: &'a BTreeMap<String, MatrixPoint>,
+    pub seeds: &'a CaseSeeds,
+    pub options: &'a SuiteOptions,
+}
+
+pub trait Suite: Send + Sync {
+    /// Target name accepted by `--target`.
+    fn name(&self) -> &'static str;
+
+    /// Every case [`Suite::run`] may return, in run order.
+    fn case_names(&self) -> BenchResult<Vec<String>>;
+
+    /// Whether `--timing-phase` values other than `execute` select a phase of this suite.
+    fn phase_aware(&self) -> bool {
+        false
+    }
+
+    /// Runs the suite's cases. Cases missing from a planned run are skipped by the caller, so
+    /// a suite may return all of its cases every time.
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>>;
+}
+
+/// Suites by target name.
+pub struct SuiteRegistry {
+    suites: Vec<Box<dyn Suite>>,
+    /// How many of `suites`, from the front, are built in.
+    builtin: usize,
+}
+
+impl Default for SuiteRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl fmt::Debug for SuiteRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl SuiteRegistry {
+    /// Every suite that ships with the harness, in `--target` listing order.
+    pub fn builtin() -> Self {
+        let suites: Vec<Box<dyn Suite>> = vec![
+            Box::new(scan::ScanSuite),
+            Box::new(scan_concurrency::ScanConcurrencySuite),
+            Box::new(multi_table::MultiTableSuite),
+            Box::new(data_skipping::DataSkippingSuite),
+            Box::new(wide_table::WideTableSuite),
+            Box::new(nested_types::NestedTypesSuite),
+            Box::new(write::WriteSuite),
+            Box::new(write_perf::WritePerfSuite),
+            Box::new(delete_update::DeleteUpdateSuite),
+            Box::new(delete_update_perf::DeleteUpdatePerfSuite),
+            Box::new(merge::MergeSuite),
+            Box::new(merge_perf::MergePerfSuite),
+            Box::new(deletion_vectors::DeletionVectorsSuite),
+            Box::new(metadata::MetadataSuite),
+            Box::new(metadata_perf::MetadataPerfSuite),
+            Box::new(log_replay::LogReplaySuite),
+            Box::new(checkpoint::CheckpointSuite),
+            Box::new(late_arriving::LateArrivingSuite),
+            Box::new(restore::RestoreSuite),
+            Box::new(convert_to_delta::ConvertToDeltaSuite),
+            Box::new(optimize_perf::OptimizePerfSuite),
+            Box::new(optimize_vacuum::OptimizeVacuumSuite),
+            Box::new(scenario::ScenarioSuite),
+            Box::new(concurrency::ConcurrencySuite),
+            Box::new(crash_recovery::CrashRecoverySuite),
+            Box::new(snapshot_isolation::SnapshotIsolationSuite),
+            Box::new(replay::ReplaySuite),
+            Box::new(tpcds::TpcdsSuite),
+            Box::new(custom_sql::CustomSqlSuite),
+            Box::new(interop_py::InteropPySuite),
+        ];
+        Self {
+            builtin: suites.len(),
+            suites,
+        }
+    }
+
+    /// Adds a suite; names must be unique lowercase `snake_case` ids, so a built-in suite
+    /// cannot be replaced.
+    #[cfg(feature = "custom-suites")]
+    pub fn register(&mut self, suite: Box<dyn Suite>) -> BenchResult<()> {
+        let name = suite.name();
+        crate::manifests::validate_case_id(name).map_err(|_| {
+            BenchError::InvalidArgument(format!(
+                "suite target '{name}' must be lowercase snake_case"
+            ))
+        })?;
+        if name == "all" || self.names().contains(&name) {
+            return Err(BenchError::InvalidArgument(format!(
+                "suite target '{name}' is already registered"
+            )));
+        }
+        self.suites.push(suite);
+        Ok(())
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.suites.iter().map(|suite| suite.name()).collect()
+    }
+
+    /// The `--target` values this registry accepts: every suite, then `all`.
+    pub fn targets(&self) -> Vec<&'static str> {
+        let mut targets = self.names();
+        targets.push("all");
+        targets
+    }
+
+    /// Every case `target` may run; `all` lists the cases of the suites `--target all` runs.
+    pub fn list_cases(&self, target: &str) -> BenchResult<Vec<String>> {
+        if target == "all" {
+            let mut names = Vec::new();
+            for suite in DEFAULT_ALL_TARGETS {
+                names.extend(self.list_cases(suite)?);
+            }
+            return Ok(names);
+        }
+        self.get(target)?.case_names()
+    }
+
+    /// Whether `name` is a suite that ships with the harness, planned from the manifests.
+    pub(crate) fn is_builtin(&self, name: &str) -> bool {
+        self.suites[..self.builtin]
+            .iter()
+            .any(|suite| suite.name() == name)
+    }
+
+    pub fn get(&self, name: &str) -> BenchResult<&dyn Suite> {
+        self.suites
+            .iter()
+            .find(|suite| suite.name() == name)
+            .map(|suite| suite.as_ref())
+            .ok_or_else(|| BenchError::InvalidArgument(format!("unknown suite target: {name}")))
+    }
+}
+
+/// The built-in suites, built once and shared by every [`SuiteOptions::default`].
+pub(crate) fn builtin_suite_registry() -> Arc<SuiteRegistry> {
+    static BUILTIN: OnceLock<Arc<SuiteRegistry>> = OnceLock::new();
+    BUILTIN
+        .get_or_init(|| Arc::new(SuiteRegistry::builtin()))
+        .clone()
+}
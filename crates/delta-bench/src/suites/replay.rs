@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use deltalake_core::datafusion::logical_expr::col;
+use deltalake_core::datafusion::prelude::SessionContext;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::delete_update::IntoOptionalRowCount;
+use super::fixture_copy::FixtureCopyStrategy;
+use super::optimize_vacuum::normalize_target_size;
+use super::{copy_dir_with, fixture_error_cases, Suite, SuiteOptions, SuiteRunArgs};
+use crate::data::datasets::NarrowSaleRow;
+use crate::data::fixtures::{narrow_sales_table_path, rows_to_batch};
+use crate::data::row_cache::FixtureRowCache;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, IterationSample, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{failure_case_result, success_case_result, unsupported_case_result};
+use crate::storage::StorageConfig;
+use crate::trace::{parse_trace, TraceOperation, TraceStep};
+use crate::version_compat::optional_table_version_to_u64;
+
+/// Offset for ids of appended and unmatched merge rows, so they never collide with fixture ids.
+const REPLAY_NEW_ID_BASE: i64 = 1_000_000_000;
+
+const REPLAY_CASES: [(&str, &str); 4] = [
+    ("append", "replay_append"),
+    ("merge", "replay_merge"),
+    ("delete", "replay_delete"),
+    ("optimize", "replay_optimize"),
+];
+
+pub fn case_names() -> Vec<String> {
+    REPLAY_CASES
+        .iter()
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+pub struct ReplaySuite;
+
+impl Suite for ReplaySuite {
+    fn name(&self) -> &'static str {
+        "replay"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.storage,
+            args.fixture_rows,
+            args.options,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
+    options: &SuiteOptions,
+) -> BenchResult<Vec<CaseResult>> {
+    let Some(trace_path) = options.replay_trace.as_deref() else {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "target replay requires --replay-trace, a JSONL operation trace",
+        ));
+    };
+    run_with_trace(
+        fixtures_dir,
+        scale,
+        trace_path,
+        warmup,
+        iterations,
+        storage,
+        fixture_rows,
+    )
+    .await
+}
+
+/// Replays the trace once per warmup and iteration pass, each on a fresh copy of the
+/// `narrow_sales_delta` fixture. Every operation becomes one sample of its operation's case.
+pub async fn run_with_trace(
+    fixtures_dir: &Path,
+    scale: &str,
+    trace_path: &Path,
+    warmup: u32,
+    iterations: u32,
+    storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "replay suite does not support non-local storage backend yet",
+        ));
+    }
+    let steps = parse_trace(&std::fs::read_to_string(trace_path).map_err(|error| {
+        BenchError::InvalidArgument(format!(
+            "failed to read replay trace '{}': {error}",
+            trace_path.display()
+        ))
+    })?)?;
+    let source = narrow_sales_table_path(fixtures_dir, scale)?;
+    let base_rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) if source.join("_delta_log").exists() && !rows.is_empty() => rows,
+        Ok(_) => {
+            return Ok(fixture_error_cases(
+                case_names(),
+                "missing narrow_sales fixture table; run bench data first",
+            ))
+        }
+        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    };
+
+    let mut samples = BTreeMap::<&str, Vec<IterationSample>>::new();
+    for pass in 0..warmup + iterations {
+        let recorded = match replay_pass(&source, storage.fixture_copy(), &steps, &base_rows).await
+        {
+            Ok(recorded) => recorded,
+            Err(error) => {
+                let message = format!("replay pass {} failed: {error}", pass + 1);
+                return Ok(REPLAY_CASES
+                    .iter()
+                    .map(|(kind, name)| {
+                        failure_case_result(
+                            name,
+                            samples.remove(kind).unwrap_or_default(),
+                            message.clone(),
+                        )
+                    })
+                    .collect());
+            }
+        };
+        if pass >= warmup {
+            for (kind, sample) in recorded {
+                samples.entry(kind).or_default().push(sample);
+            }
+        }
+    }
+
+    Ok(REPLAY_CASES
+        .iter()
+        .map(|(kind, name)| match samples.remove(kind) {
+            Some(samples) => success_case_result(name, samples),
+            None => unsupported_case_result(
+                name,
+                Vec::new(),
+                format!("skipped: replay trace has no {kind} operations"),
+            ),
+        })
+        .collect())
+}
+
+async fn replay_pass(
+    source: &Path,
+    fixture_copy: FixtureCopyStrategy,
+    steps: &[TraceStep],
+    base_rows: &[NarrowSaleRow],
+) -> BenchResult<Vec<(&'static str, IterationSample)>> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("table");
+    copy_dir_with(source, &table_dir, fixture_copy)?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    let mut table = StorageConfig::local().open_table(table_url).await?;
+
+    let mut cursor = ReplayRowCursor::default();
+    let mut recorded = Vec::with_capacity(steps.len());
+    for (idx, step) in steps.iter().enumerate() {
+        if step.think_time_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(step.think_time_ms)).await;
+        }
+        let start = Instant::now();
+        let (next, rows) = apply_operation(table, &step.operation, base_rows, &mut cursor)
+            .await
+            .map_err(|error| {
+                BenchError::InvalidArgument(format!(
+                    "trace operation {} ({}): {error}",
+                    idx + 1,
+                    step.operation.kind()
+                ))
+            })?;
+        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+        table = next;
+
+        let metrics = replay_sample_metrics(step.operation.kind(), rows, &table)?;
+        recorded.push((
+            step.operation.kind(),
+            IterationSample {
+                elapsed_ms,
+                rows: metrics.rows_processed,
+                bytes: None,
+                metrics: Some(metrics),
+            },
+        ));
+    }
+    Ok(recorded)
+}
+
+/// Hands out fixture rows for merges to match and fresh ids for new rows, so repeated
+/// operations in one pass touch different rows.
+#[derive(Default)]
+struct ReplayRowCursor {
+    next_matched: usize,
+    next_new_id: i64,
+}
+
+impl ReplayRowCursor {
+    fn matched_rows(&mut self, base_rows: &[NarrowSaleRow], count: usize) -> Vec<NarrowSaleRow> {
+        (0..count)
+            .map(|_| {
+                let mut row = base_rows[self.next_matched % base_rows.len()].clone();
+                self.next_matched += 1;
+                row.value_i64 = row.value_i64.map(|value| value + 7);
+                row
+            })
+            .collect()
+    }
+
+    fn new_rows(&mut self, base_rows: &[NarrowSaleRow], count: usize) -> Vec<NarrowSaleRow> {
+        (0..count)
+            .map(|offset| {
+                let mut row = base_rows[offset % base_rows.len()].clone();
+                row.id = REPLAY_NEW_ID_BASE + self.next_new_id;
+                self.next_new_id += 1;
+                row
+            })
+            .collect()
+    }
+}
+
+async fn apply_operation(
+    table: DeltaTable,
+    operation: &TraceOperation,
+    base_rows: &[NarrowSaleRow],
+    cursor: &mut ReplayRowCursor,
+) -> BenchResult<(DeltaTable, Option<u64>)> {
+    match operation {
+        TraceOperation::Append { rows } => {
+            let batch = rows_to_batch(&cursor.new_rows(base_rows, *rows))?;
+            let table = table.write(vec![batch]).await?;
+            Ok((table, Some(*rows as u64)))
+        }
+        TraceOperation::Merge {
+            rows,
+            matched_fraction,
+        } => {
+            let matched = ((*rows as f64) * matched_fraction).round() as usize;
+            let mut source_rows = cursor.matched_rows(base_rows, matched);
+            source_rows.extend(cursor.new_rows(base_rows, rows - matched));
+            let source = SessionContext::new().read_batch(rows_to_batch(&source_rows)?)?;
+            let (table, _) = table
+                .merge(source, col("target.id").eq(col("source.id")))
+                .with_source_alias("source")
+                .with_target_alias("target")
+                .when_matched_update(|update| {
+                    update
+                        .update("value_i64", col("source.value_i64"))
+                        .update("amount", col("source.amount"))
+                        .update("flag", col("source.flag"))
+                })?
+                .when_not_matched_insert(|insert| {
+                    insert
+                        .set("id", col("source.id"))
+                        .set("ts_ms", col("source.ts_ms"))
+                        .set("region", col("source.region"))
+                        .set("value_i64", col("source.value_i64"))
+                        .set("flag", col("source.flag"))
+                        .set("ts", col("source.ts"))
+                        .set("event_date", col("source.event_date"))
+                        .set("amount", col("source.amount"))
+                })?
+                .await?;
+            Ok((table, Some(*rows as u64)))
+        }
+        TraceOperation::Delete { predicate } => {
+            let (table, metrics) = table.delete().with_predicate(predicate.as_str()).await?;
+            let rows = metrics
+                .num_deleted_rows
+                .into_optional_row_count()
+                .map(|rows| rows as u64);
+            Ok((table, rows))
+        }
+        TraceOperation::Optimize { target_size } => {
+            let mut optimize = table.optimize();
+            if let Some(target_size) = target_size {
+                optimize = optimize.with_target_size(normalize_target_size(*target_size)?.into());
+            }
+            let (table, metrics) = optimize.await?;
+            Ok((table, Some(metrics.total_considered_files as u64)))
+        }
+    }
+}
+
+/// The hash only asserts that the operation replayed; row counts depend on the trace.
+fn replay_sample_metrics(
+    kind: &str,
+    rows: Option<u64>,
+    table: &DeltaTable,
+) -> BenchResult<SampleMetrics> {
+    let result_hash = hash_json(&json!({
+        "operation": format!("replay_{kind}"),
+        "replayed": true,
+    }))?;
+    let schema_hash = hash_json(&json!(["operation:string", "replayed:bool"]))?;
+    let table_version = optional_table_version_to_u64(table.version())?;
+    Ok(
+        SampleMetrics::base(rows, None, Some(1), table_version).with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: None,
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest: None,
+            validation_summary: None,
+        }),
+    )
+}
@@ -0,0 +1,392 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::Duration as ChronoDuration;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::BenchmarkLane;
+use crate::data::fixtures::metadata_long_history_table_path;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RestoreVacuumMetrics, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{
+    run_case_async_with_async_setup, run_case_async_with_async_setup_custom_timing, CaseHooks,
+};
+use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
+
+#[derive(Clone, Copy)]
+enum RestoreTarget {
+    /// Restore to `head - n`, undoing the most recent commits.
+    VersionsBack(u64),
+    /// Restore to the initial commit, undoing the whole history.
+    InitialVersion,
+}
+
+#[derive(Clone, Copy)]
+struct RestoreCase {
+    name: &'static str,
+    target: RestoreTarget,
+    /// Follow the restore with a zero-retention vacuum and check the restored snapshot survives.
+    vacuum: bool,
+}
+
+const RESTORE_CASES: [RestoreCase; 4] = [
+    RestoreCase {
+        name: "restore_shallow_1_version",
+        target: RestoreTarget::VersionsBack(1),
+        vacuum: false,
+    },
+    RestoreCase {
+        name: "restore_mid_history_24_versions",
+        target: RestoreTarget::VersionsBack(24),
+        vacuum: false,
+    },
+    RestoreCase {
+        name: "restore_deep_to_version_0",
+        target: RestoreTarget::InitialVersion,
+        vacuum: false,
+    },
+    RestoreCase {
+        name: "restore_then_vacuum_24_versions",
+        target: RestoreTarget::VersionsBack(24),
+        vacuum: true,
+    },
+];
+
+struct RestoreIterationSetup {
+    _temp: tempfile::TempDir,
+    table_dir: PathBuf,
+    table: DeltaTable,
+}
+
+struct RestoredTable {
+    table: DeltaTable,
+    restored_version: u64,
+    files_removed: u64,
+    files_restored: u64,
+}
+
+pub fn case_names() -> Vec<String> {
+    RESTORE_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct RestoreSuite;
+
+impl Suite for RestoreSuite {
+    fn name(&self) -> &'static str {
+        "restore"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    lane: BenchmarkLane,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "restore suite does not support non-local storage backend yet",
+        ));
+    }
+
+    let source = metadata_long_history_table_path(fixtures_dir, scale);
+    if !source.join("_delta_log").exists() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing long-history fixture table; run bench data --dataset-id many_versions first",
+        ));
+    }
+
+    let mut out = Vec::new();
+    for case in RESTORE_CASES {
+        let setup = || {
+            let source = source.clone();
+            let storage = storage.clone();
+            async move {
+                prepare_restore_iteration(&source, &storage)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        };
+        let c = if case.vacuum {
+            run_case_async_with_async_setup_custom_timing(
+                case.name,
+                warmup,
+                iterations,
+                hooks,
+                setup,
+                |setup| async move {
+                    let _keep_temp = setup._temp;
+                    run_restore_then_vacuum_case(setup.table, &setup.table_dir, case, lane)
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+            )
+            .await
+        } else {
+            run_case_async_with_async_setup(
+                case.name,
+                warmup,
+                iterations,
+                hooks,
+                setup,
+                |setup| async move {
+                    let _keep_temp = setup._temp;
+                    run_restore_case(setup.table, case, lane)
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+            )
+            .await
+        };
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+async fn prepare_restore_iteration(
+    source_table_path: &Path,
+    storage: &StorageConfig,
+) -> BenchResult<RestoreIterationSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("table");
+    copy_dir_with(source_table_path, &table_dir, storage.fixture_copy())?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    let table = storage.open_table(table_url).await?;
+    Ok(RestoreIterationSetup {
+        _temp: temp,
+        table_dir,
+        table,
+    })
+}
+
+async fn restore_table(table: DeltaTable, case: RestoreCase) -> BenchResult<RestoredTable> {
+    let head_version = optional_table_version_to_u64(table.version())?.ok_or_else(|| {
+        BenchError::InvalidArgument(format!("{} requires a versioned table", case.name))
+    })?;
+    let restored_version = match case.target {
+        RestoreTarget::VersionsBack(distance) => {
+            head_version.checked_sub(distance).ok_or_else(|| {
+                BenchError::InvalidArgument(format!(
+                    "{} needs at least {distance} versions of history but head is version {head_version}",
+                    case.name
+                ))
+            })?
+        }
+        RestoreTarget::InitialVersion => 0,
+    };
+    let restore_version = i64::try_from(restored_version).map_err(|_| {
+        BenchError::InvalidArgument(format!(
+            "restore version {restored_version} exceeds i64::MAX"
+        ))
+    })?;
+
+    let (table, metrics) = table
+        .restore()
+        .with_version_to_restore(restore_version)
+        .await?;
+
+    Ok(RestoredTable {
+        table,
+        restored_version,
+        files_removed: metrics.num_removed_file as u64,
+        files_restored: metrics.num_restored_file as u64,
+    })
+}
+
+async fn run_restore_case(
+    table: DeltaTable,
+    case: RestoreCase,
+    lane: BenchmarkLane,
+) -> BenchResult<SampleMetrics> {
+    let RestoredTable {
+        table,
+        restored_version,
+        files_removed,
+        files_restored,
+    } = restore_table(table, case).await?;
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "restored_version": restored_version,
+        "files_removed": files_removed,
+        "files_restored": files_restored,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "restored_version:u64",
+        "files_removed:u64",
+        "files_restored:u64",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        let validation = validate_table_state(&table).await?;
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+
+    Ok(
+        SampleMetrics::base(None, None, Some(1), table_version).with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: Some(files_removed + files_restored),
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest,
+            validation_summary,
+        }),
+    )
+}
+
+/// Times `RESTORE` followed by a zero-retention vacuum. The vacuum may only delete the files the
+/// restore dropped, so the check afterwards fails the case if any file the restored snapshot
+/// still references is gone. That check stays outside the reported elapsed time.
+async fn run_restore_then_vacuum_case(
+    table: DeltaTable,
+    table_dir: &Path,
+    case: RestoreCase,
+    lane: BenchmarkLane,
+) -> BenchResult<(SampleMetrics, Option<f64>)> {
+    let restore_start = Instant::now();
+    let RestoredTable {
+        table,
+        restored_version,
+        files_removed,
+        ..
+    } = restore_table(table, case).await?;
+    let restore_elapsed = restore_start.elapsed();
+
+    let vacuum_start = Instant::now();
+    let (table, metrics) = table
+        .vacuum()
+        .with_retention_period(ChronoDuration::seconds(0))
+        .with_enforce_retention_duration(false)
+        .await?;
+    let vacuum_elapsed = vacuum_start.elapsed();
+
+    let files_vacuumed = metrics.files_deleted.len() as u64;
+    let files_referenced = table.snapshot()?.log_data().num_files() as u64;
+    let files_on_disk = count_data_files(table_dir)?;
+    if files_on_disk != files_referenced {
+        return Err(BenchError::InvalidArgument(format!(
+            "{}: restored snapshot references {files_referenced} data files but {files_on_disk} remain on disk after vacuum",
+            case.name
+        )));
+    }
+    // Reading every row proves each referenced file is still present and readable.
+    let validation = validate_table_state(&table).await.map_err(|error| {
+        BenchError::InvalidArgument(format!(
+            "{}: restored snapshot is unreadable after vacuum: {error}",
+            case.name
+        ))
+    })?;
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "restored_version": restored_version,
+        "files_removed": files_removed,
+        "files_vacuumed": files_vacuumed,
+        "files_referenced": files_referenced,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "restored_version:u64",
+        "files_removed:u64",
+        "files_vacuumed:u64",
+        "files_referenced:u64",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+
+    let metrics = SampleMetrics::base(None, None, Some(2), table_version)
+        .with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: Some(files_removed + files_vacuumed),
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest,
+            validation_summary,
+        })
+        .with_restore_vacuum(RestoreVacuumMetrics {
+            restore_ms: restore_elapsed.as_millis() as u64,
+            vacuum_ms: vacuum_elapsed.as_millis() as u64,
+            files_vacuumed,
+            files_referenced,
+        });
+    let elapsed_ms = (restore_elapsed + vacuum_elapsed).as_secs_f64() * 1000.0;
+    Ok((metrics, Some(elapsed_ms)))
+}
+
+/// Counts Parquet data files at the root of an unpartitioned table directory.
+fn count_data_files(table_dir: &Path) -> BenchResult<u64> {
+    let mut count = 0_u64;
+    for entry in fs::read_dir(table_dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "parquet") {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
@@ -2,20 +2,31 @@ use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
+use deltalake_core::arrow::array::{Array, Int64Array};
 use deltalake_core::arrow::record_batch::RecordBatch;
 use deltalake_core::datafusion::execution::context::TaskContext;
 use deltalake_core::datafusion::physical_plan::collect;
 use deltalake_core::datafusion::physical_plan::ExecutionPlan;
 use deltalake_core::datafusion::prelude::SessionContext;
+use futures::future::LocalBoxFuture;
+use serde::Serialize;
+use serde_json::json;
 use url::Url;
 
+use super::{Suite, SuiteRunArgs};
 use crate::cli::TimingPhase;
-use crate::data::fixtures::{narrow_sales_table_url, read_partitioned_table_url};
+use crate::data::fixtures::{
+    fixture_distribution, narrow_sales_table_url, read_partitioned_table_url, scale_to_row_count,
+};
+use crate::data::generator::DataDistribution;
 use crate::error::{BenchError, BenchResult};
-use crate::fingerprint::{hash_arrow_schema, hash_record_batches_unordered};
-use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics};
+use crate::fingerprint::{hash_arrow_schema, hash_json, hash_record_batches_unordered};
+use crate::results::{
+    CaseResult, ElapsedStats, RuntimeIOMetrics, SampleMetrics, FAILURE_KIND_UNSUPPORTED,
+};
 use crate::runner::{
-    run_case_async_with_timing_phase, CaseExecutionResult, PhaseTiming, TimedSample,
+    record_physical_plan, record_plan_metrics, run_case_async_with_timing_phase,
+    unsupported_case_result, CaseExecutionResult, CaseHooks, PhaseTiming, TimedSample,
 };
 use crate::storage::StorageConfig;
 use crate::suites::scan_metrics::extract_scan_metrics;
@@ -26,14 +37,171 @@ const EXECUTE_DELAY_ENV: &str = "DELTA_BENCH_SCAN_DELAY_EXECUTE_MS";
 const VALIDATE_DELAY_ENV: &str = "DELTA_BENCH_SCAN_DELAY_VALIDATE_MS";
 const ALLOW_DELAY_ENV: &str = "DELTA_BENCH_ALLOW_SCAN_PHASE_DELAY";
 
+/// One step of the `id < threshold` selectivity sweep over `narrow_sales`.
+#[derive(Clone, Copy)]
+struct SelectivityStep {
+    name: &'static str,
+    /// Matching fraction of the table in parts per million.
+    ppm: u64,
+}
+
+const SELECTIVITY_SWEEP: [SelectivityStep; 6] = [
+    SelectivityStep {
+        name: "scan_selectivity_0_001pct",
+        ppm: 10,
+    },
+    SelectivityStep {
+        name: "scan_selectivity_0_01pct",
+        ppm: 100,
+    },
+    SelectivityStep {
+        name: "scan_selectivity_0_1pct",
+        ppm: 1_000,
+    },
+    SelectivityStep {
+        name: "scan_selectivity_1pct",
+        ppm: 10_000,
+    },
+    SelectivityStep {
+        name: "scan_selectivity_10pct",
+        ppm: 100_000,
+    },
+    SelectivityStep {
+        name: "scan_selectivity_50pct",
+        ppm: 500_000,
+    },
+];
+
+impl SelectivityStep {
+    fn selectivity_pct(self) -> f64 {
+        self.ppm as f64 / 10_000.0
+    }
+
+    /// The step's query, or why it is skipped: a step matching less than one row of the scale
+    /// would need its threshold rounded up, repeating the next step's point on the curve, and
+    /// a skewed `id` no longer matches the step's fraction below any threshold.
+    fn sql(self, rows: usize, distribution: &DataDistribution) -> Result<String, String> {
+        if distribution.id_skew > 0.0 {
+            return Err(format!(
+                "skipped: {} needs dense ids, but the fixtures were generated with id skew {}",
+                self.name, distribution.id_skew
+            ));
+        }
+        // `id` is dense over 0..rows, so a range bound matches an exact fraction of the table.
+        let threshold = rows as u64 * self.ppm / 1_000_000;
+        if threshold == 0 {
+            return Err(format!(
+                "skipped: {} matches less than one of the scale's {rows} rows",
+                self.name
+            ));
+        }
+        Ok(format!("SELECT COUNT(*) FROM bench WHERE id < {threshold}"))
+    }
+}
+
+/// Filters on the typed `narrow_sales` columns, so date, timestamp, and decimal statistics take
+/// part in pruning. Rows are one minute apart from 2023-11-14T22:13:20Z, so the date and
+/// timestamp windows match 1,440 and 360 rows at every scale from `sf1` up, and no `amount`
+/// reaches 1000.00, so decimal statistics prune every file.
+const TYPED_FILTER_CASES: [(&str, &str); 3] = [
+    (
+        "scan_filter_event_date",
+        "SELECT COUNT(*) FROM bench WHERE event_date = DATE '2023-11-15'",
+    ),
+    (
+        "scan_filter_ts_range",
+        "SELECT COUNT(*) FROM bench WHERE ts >= TIMESTAMP '2023-11-15T00:00:00Z' \
+         AND ts < TIMESTAMP '2023-11-15T06:00:00Z'",
+    ),
+    (
+        "scan_filter_amount",
+        "SELECT COUNT(*) FROM bench WHERE amount > 1000.00",
+    ),
+];
+
+/// Rust counterpart of the `pyarrow_dataset_scan_perf` interop case, which sums `value_i64`
+/// over the `flag = true AND value_i64 > 0` rows among the first 5,000 rows of `rows.parquet`.
+/// Rows are one minute apart, so those are exactly the rows before the `ts` bound below,
+/// whatever the dataset's id distribution.
+const INTEROP_REFERENCE_CASE: &str = "scan_interop_reference";
+const INTEROP_REFERENCE_SQL: &str = "SELECT COUNT(*), \
+     COUNT(CASE WHEN flag AND value_i64 > 0 THEN 1 END), \
+     COALESCE(SUM(CASE WHEN flag AND value_i64 > 0 THEN value_i64 END), 0) \
+     FROM bench WHERE ts < TIMESTAMP '2023-11-18T09:33:20Z'";
+
+/// One point of the selectivity-vs-latency curve written next to scan results.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SelectivityCurvePoint {
+    pub case: String,
+    pub selectivity_pct: f64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_stats: Option<ElapsedStats>,
+}
+
 pub fn case_names() -> Vec<String> {
-    vec![
+    let mut names = vec![
         "scan_full_narrow".to_string(),
         "scan_projection_region".to_string(),
         "scan_filter_flag".to_string(),
         "scan_pruning_hit".to_string(),
         "scan_pruning_miss".to_string(),
-    ]
+    ];
+    names.extend(SELECTIVITY_SWEEP.iter().map(|step| step.name.to_string()));
+    names.extend(TYPED_FILTER_CASES.iter().map(|(name, _)| name.to_string()));
+    names.push(INTEROP_REFERENCE_CASE.to_string());
+    names
+}
+
+pub struct ScanSuite;
+
+impl Suite for ScanSuite {
+    fn name(&self) -> &'static str {
+        "scan"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn phase_aware(&self) -> bool {
+        true
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.timing_phase,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+/// Collects the selectivity sweep cases from a run in ascending selectivity order, leaving out
+/// steps the scale was too small to run.
+pub fn selectivity_curve(cases: &[CaseResult]) -> Vec<SelectivityCurvePoint> {
+    SELECTIVITY_SWEEP
+        .iter()
+        .filter_map(|step| {
+            let case = cases.iter().find(|case| {
+                case.case == step.name
+                    && case.failure_kind.as_deref() != Some(FAILURE_KIND_UNSUPPORTED)
+            })?;
+            Some(SelectivityCurvePoint {
+                case: case.case.clone(),
+                selectivity_pct: step.selectivity_pct(),
+                success: case.success,
+                elapsed_stats: case.elapsed_stats.clone(),
+            })
+        })
+        .collect()
 }
 
 #[doc(hidden)]
@@ -61,7 +229,13 @@ pub struct ExecutedSqlQuery {
     plan: Arc<dyn ExecutionPlan>,
     batches: Vec<RecordBatch>,
     total_active_files: Option<u64>,
-    execution_elapsed_ms: f64,
+    pub(crate) execution_elapsed_ms: f64,
+}
+
+impl ExecutedSqlQuery {
+    fn output_columns(&self) -> usize {
+        self.plan.schema().fields().len()
+    }
 }
 
 pub async fn run(
@@ -70,6 +244,7 @@ pub async fn run(
     timing_phase: TimingPhase,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
 ) -> BenchResult<Vec<CaseResult>> {
     let table_url = narrow_sales_table_url(fixtures_dir, scale, storage)?;
@@ -82,6 +257,7 @@ pub async fn run(
         timing_phase,
         warmup,
         iterations,
+        hooks,
         storage,
         table_url.clone(),
         "SELECT COUNT(*) FROM bench",
@@ -94,6 +270,7 @@ pub async fn run(
         timing_phase,
         warmup,
         iterations,
+        hooks,
         storage,
         table_url.clone(),
         "SELECT region, SUM(value_i64) FROM bench GROUP BY region",
@@ -106,6 +283,7 @@ pub async fn run(
         timing_phase,
         warmup,
         iterations,
+        hooks,
         storage,
         table_url.clone(),
         "SELECT COUNT(*) FROM bench WHERE flag = true AND value_i64 > 0",
@@ -118,6 +296,7 @@ pub async fn run(
         timing_phase,
         warmup,
         iterations,
+        hooks,
         storage,
         partitioned_table_url.clone(),
         "SELECT COUNT(*) FROM bench WHERE region = 'us'",
@@ -130,6 +309,7 @@ pub async fn run(
         timing_phase,
         warmup,
         iterations,
+        hooks,
         storage,
         partitioned_table_url,
         "SELECT COUNT(*) FROM bench",
@@ -137,6 +317,50 @@ pub async fn run(
     .await;
     results.push(into_case_result(partition_miss));
 
+    let rows = scale_to_row_count(scale)?;
+    let distribution = fixture_distribution(fixtures_dir, scale)?;
+    for step in SELECTIVITY_SWEEP {
+        let sql = match step.sql(rows, &distribution) {
+            Ok(sql) => sql,
+            Err(reason) => {
+                results.push(unsupported_case_result(step.name, Vec::new(), reason));
+                continue;
+            }
+        };
+        let sweep = run_query_case(
+            step.name,
+            timing_phase,
+            warmup,
+            iterations,
+            hooks,
+            storage,
+            table_url.clone(),
+            &sql,
+        )
+        .await;
+        results.push(into_case_result(sweep));
+    }
+
+    for (name, sql) in TYPED_FILTER_CASES {
+        let typed = run_query_case(
+            name,
+            timing_phase,
+            warmup,
+            iterations,
+            hooks,
+            storage,
+            table_url.clone(),
+            sql,
+        )
+        .await;
+        results.push(into_case_result(typed));
+    }
+
+    let interop_reference =
+        run_interop_reference_case(timing_phase, warmup, iterations, hooks, storage, table_url)
+            .await;
+    results.push(into_case_result(interop_reference));
+
     Ok(results)
 }
 
@@ -147,10 +371,55 @@ pub async fn run_single_case(
     timing_phase: TimingPhase,
     storage: &StorageConfig,
 ) -> BenchResult<CaseResult> {
+    if let Some(step) = SELECTIVITY_SWEEP.iter().find(|step| step.name == case_name) {
+        let distribution = fixture_distribution(fixtures_dir, scale)?;
+        let sql = match step.sql(scale_to_row_count(scale)?, &distribution) {
+            Ok(sql) => sql,
+            Err(reason) => return Ok(unsupported_case_result(case_name, Vec::new(), reason)),
+        };
+        let table_url = narrow_sales_table_url(fixtures_dir, scale, storage)?;
+        return Ok(into_case_result(
+            run_query_case(
+                case_name,
+                timing_phase,
+                0,
+                1,
+                &CaseHooks::default(),
+                storage,
+                table_url,
+                &sql,
+            )
+            .await,
+        ));
+    }
+    if case_name == INTEROP_REFERENCE_CASE {
+        let table_url = narrow_sales_table_url(fixtures_dir, scale, storage)?;
+        return Ok(into_case_result(
+            run_interop_reference_case(
+                timing_phase,
+                0,
+                1,
+                &CaseHooks::default(),
+                storage,
+                table_url,
+            )
+            .await,
+        ));
+    }
     let (table_url, sql) = resolve_case_spec(fixtures_dir, scale, case_name, storage)?;
 
     Ok(into_case_result(
-        run_query_case(case_name, timing_phase, 0, 1, storage, table_url, sql).await,
+        run_query_case(
+            case_name,
+            timing_phase,
+            0,
+            1,
+            &CaseHooks::default(),
+            storage,
+            table_url,
+            sql,
+        )
+        .await,
     ))
 }
 
@@ -224,22 +493,27 @@ fn resolve_case_spec(
             read_partitioned_table_url(fixtures_dir, scale, storage)?,
             "SELECT COUNT(*) FROM bench",
         )),
-        other => Err(crate::error::BenchError::InvalidArgument(format!(
-            "unknown scan case '{other}'"
-        ))),
+        other => match TYPED_FILTER_CASES.iter().find(|(name, _)| *name == other) {
+            Some((_, sql)) => Ok((narrow_sales_table_url(fixtures_dir, scale, storage)?, *sql)),
+            None => Err(crate::error::BenchError::InvalidArgument(format!(
+                "unknown scan case '{other}'"
+            ))),
+        },
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn run_query_case(
     case_name: &str,
     timing_phase: TimingPhase,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
     table_url: Url,
-    sql: &'static str,
+    sql: &str,
 ) -> CaseExecutionResult {
-    run_case_async_with_timing_phase(case_name, warmup, iterations, timing_phase, || {
+    run_case_async_with_timing_phase(case_name, warmup, iterations, hooks, timing_phase, || {
         let storage = storage.clone();
         let table_url = table_url.clone();
         async move {
@@ -254,11 +528,13 @@ async fn run_query_case(
                 .await
                 .map_err(|e| e.to_string())?;
             let planning_elapsed_ms = planning_start.elapsed().as_secs_f64() * 1000.0;
+            record_physical_plan(hooks, prepared.plan.as_ref());
 
             let executed = execute_prepared_query(prepared)
                 .await
                 .map_err(|e| e.to_string())?;
             let execution_elapsed_ms = executed.execution_elapsed_ms;
+            record_plan_metrics(hooks, executed.plan.as_ref());
 
             let (metrics, validate_elapsed_ms) = validate_executed_query(executed)
                 .await
@@ -276,7 +552,129 @@ async fn run_query_case(
     .await
 }
 
-async fn load_sql_query_context(
+async fn run_interop_reference_case(
+    timing_phase: TimingPhase,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    table_url: Url,
+) -> CaseExecutionResult {
+    run_case_async_with_timing_phase(
+        INTEROP_REFERENCE_CASE,
+        warmup,
+        iterations,
+        hooks,
+        timing_phase,
+        || {
+            let storage = storage.clone();
+            let table_url = table_url.clone();
+            async move {
+                run_interop_reference_query(&storage, table_url)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+    )
+    .await
+}
+
+/// Runs the interop reference query, then reports its answer the way the Python case does:
+/// `rows_processed` counts the rows read and `result_hash` covers only the sum and the matching
+/// row count, so both lanes' results can be compared directly.
+async fn run_interop_reference_query(
+    storage: &StorageConfig,
+    table_url: Url,
+) -> BenchResult<TimedSample<SampleMetrics>> {
+    let load_start = std::time::Instant::now();
+    let loaded = load_sql_query_context(storage, table_url).await?;
+    let load_elapsed_ms = load_start.elapsed().as_secs_f64() * 1000.0;
+
+    let planning_start = std::time::Instant::now();
+    let prepared = plan_loaded_sql_query(loaded, INTEROP_REFERENCE_SQL).await?;
+    let planning_elapsed_ms = planning_start.elapsed().as_secs_f64() * 1000.0;
+
+    let executed = execute_prepared_query(prepared).await?;
+    let execution_elapsed_ms = executed.execution_elapsed_ms;
+    let rows_read = first_row_i64(&executed.batches, 0)?;
+    let matched_rows = first_row_i64(&executed.batches, 1)?;
+    let matched_sum = first_row_i64(&executed.batches, 2)?;
+
+    let (mut metrics, validate_elapsed_ms) = validate_executed_query(executed).await?;
+    metrics.rows_processed = Some(rows_read as u64);
+    metrics.result_hash = Some(hash_json(&json!({
+        "sum": matched_sum,
+        "rows": matched_rows,
+    }))?);
+    metrics.schema_hash = Some(hash_json(&json!(["sum:int", "rows:int"]))?);
+
+    Ok(TimedSample::new(
+        metrics,
+        PhaseTiming::default()
+            .with_load_ms(load_elapsed_ms)
+            .with_plan_ms(planning_elapsed_ms)
+            .with_execute_ms(execution_elapsed_ms)
+            .with_validate_ms(validate_elapsed_ms),
+    ))
+}
+
+fn first_row_i64(batches: &[RecordBatch], column: usize) -> BenchResult<i64> {
+    batches
+        .iter()
+        .find(|batch| batch.num_rows() > 0)
+        .and_then(|batch| batch.column(column).as_any().downcast_ref::<Int64Array>())
+        .filter(|values| !values.is_null(0))
+        .map(|values| values.value(0))
+        .ok_or_else(|| {
+            BenchError::InvalidArgument(format!(
+                "{INTEROP_REFERENCE_CASE} returned no Int64 value in column {column}"
+            ))
+        })
+}
+
+/// Runs `sql` like a scan case, but hashes the result shape (operation, rows, columns) instead
+/// of every cell. For wide or nested fixtures whose cell rendering is not worth pinning.
+pub(crate) async fn run_shape_hashed_query(
+    storage: &StorageConfig,
+    table_url: Url,
+    sql: &str,
+    case_name: &str,
+) -> BenchResult<TimedSample<SampleMetrics>> {
+    let load_start = std::time::Instant::now();
+    let loaded = load_sql_query_context(storage, table_url).await?;
+    let load_elapsed_ms = load_start.elapsed().as_secs_f64() * 1000.0;
+
+    let planning_start = std::time::Instant::now();
+    let prepared = plan_loaded_sql_query(loaded, sql).await?;
+    let planning_elapsed_ms = planning_start.elapsed().as_secs_f64() * 1000.0;
+
+    let executed = execute_prepared_query(prepared).await?;
+    let execution_elapsed_ms = executed.execution_elapsed_ms;
+    let output_columns = executed.output_columns();
+
+    let (mut metrics, validate_elapsed_ms) = validate_executed_query(executed).await?;
+    metrics.result_hash = Some(hash_json(&json!({
+        "operation": case_name,
+        "rows_processed": metrics.rows_processed.unwrap_or(0),
+        "columns": output_columns,
+    }))?);
+    metrics.schema_hash = Some(hash_json(&json!([
+        "operation:string",
+        "rows_processed:u64",
+        "columns:u64",
+    ]))?);
+
+    Ok(TimedSample::new(
+        metrics,
+        PhaseTiming::default()
+            .with_load_ms(load_elapsed_ms)
+            .with_plan_ms(planning_elapsed_ms)
+            .with_execute_ms(execution_elapsed_ms)
+            .with_validate_ms(validate_elapsed_ms),
+    ))
+}
+
+pub(crate) async fn load_sql_query_context(
     storage: &StorageConfig,
     table_url: Url,
 ) -> BenchResult<LoadedSqlQuery> {
@@ -295,7 +693,10 @@ async fn load_sql_query_context(
     })
 }
 
-async fn plan_loaded_sql_query(loaded: LoadedSqlQuery, sql: &str) -> BenchResult<PreparedSqlQuery> {
+pub(crate) async fn plan_loaded_sql_query(
+    loaded: LoadedSqlQuery,
+    sql: &str,
+) -> BenchResult<PreparedSqlQuery> {
     apply_phase_delay(PLAN_DELAY_ENV).await?;
     let df = loaded.ctx.sql(sql).await?;
     let task_ctx = Arc::new(df.task_ctx());
@@ -308,7 +709,9 @@ async fn plan_loaded_sql_query(loaded: LoadedSqlQuery, sql: &str) -> BenchResult
     })
 }
 
-async fn execute_prepared_query(prepared: PreparedSqlQuery) -> BenchResult<ExecutedSqlQuery> {
+pub(crate) async fn execute_prepared_query(
+    prepared: PreparedSqlQuery,
+) -> BenchResult<ExecutedSqlQuery> {
     let query_start = std::time::Instant::now();
     apply_phase_delay(EXECUTE_DELAY_ENV).await?;
     let batches = collect(prepared.plan.clone(), prepared.task_ctx).await?;
@@ -322,7 +725,9 @@ async fn execute_prepared_query(prepared: PreparedSqlQuery) -> BenchResult<Execu
     })
 }
 
-async fn validate_executed_query(executed: ExecutedSqlQuery) -> BenchResult<(SampleMetrics, f64)> {
+pub(crate) async fn validate_executed_query(
+    executed: ExecutedSqlQuery,
+) -> BenchResult<(SampleMetrics, f64)> {
     let validate_start = std::time::Instant::now();
     apply_phase_delay(VALIDATE_DELAY_ENV).await?;
     let rows_processed = executed
@@ -330,32 +735,14 @@ async fn validate_executed_query(executed: ExecutedSqlQuery) -> BenchResult<(Sam
         .iter()
         .map(|b| b.num_rows() as u64)
         .sum::<u64>();
-    let scan_metrics = extract_scan_metrics(&executed.plan);
-    let files_scanned = scan_metrics.files_scanned.or_else(|| {
-        executed.total_active_files.and_then(|total| {
-            scan_metrics
-                .files_pruned
-                .and_then(|pruned| total.checked_sub(pruned))
-        })
-    });
-    let files_pruned = scan_metrics.files_pruned.or_else(|| {
-        executed
-            .total_active_files
-            .and_then(|total| files_scanned.and_then(|scanned| total.checked_sub(scanned)))
-    });
+    let scan_metrics = extract_scan_metrics(&executed.plan, executed.total_active_files);
     let result_hash = hash_record_batches_unordered(&executed.batches)?;
     let schema_hash = hash_arrow_schema(executed.plan.schema().as_ref())?;
     let validate_elapsed_ms = validate_start.elapsed().as_secs_f64() * 1000.0;
 
     Ok((
-        SampleMetrics::base(Some(rows_processed), None, None, None)
-            .with_scan_rewrite(ScanRewriteMetrics {
-                files_scanned,
-                files_pruned,
-                bytes_scanned: scan_metrics.bytes_scanned,
-                scan_time_ms: scan_metrics.scan_time_ms,
-                rewrite_time_ms: None,
-            })
+        scan_metrics
+            .apply(SampleMetrics::base(Some(rows_processed), None, None, None))
             .with_runtime_io(RuntimeIOMetrics {
                 peak_rss_mb: None,
                 cpu_time_ms: None,
@@ -0,0 +1,317 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use deltalake_core::datafusion::physical_plan::execute_stream;
+use deltalake_core::datafusion::prelude::SessionContext;
+use futures::future::LocalBoxFuture;
+use futures::TryStreamExt;
+use serde_json::json;
+use tokio::sync::Barrier;
+use url::Url;
+
+use super::into_case_result;
+use super::{Suite, SuiteRunArgs};
+use crate::data::fixtures::read_partitioned_table_url;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{
+    CaseFailure, CaseResult, PerfStatus, ReadConcurrencyMetrics, RuntimeIOMetrics, SampleMetrics,
+    WorkerMetrics, FAILURE_KIND_UNSUPPORTED,
+};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::stats::nearest_rank;
+use crate::storage::{StorageConfig, MAX_CONCURRENCY_KEY};
+use crate::version_compat::optional_table_version_to_u64;
+
+/// Projects every row of the partitioned fixture so each session decodes the whole table.
+const SCAN_CONCURRENCY_SQL: &str = "SELECT id, region, value_i64 FROM bench";
+
+#[derive(Clone, Copy)]
+struct ScanConcurrencyCase {
+    name: &'static str,
+    sessions: usize,
+}
+
+const SCAN_CONCURRENCY_CASES: [ScanConcurrencyCase; 3] = [
+    ScanConcurrencyCase {
+        name: "scan_concurrent_sessions_1",
+        sessions: 1,
+    },
+    ScanConcurrencyCase {
+        name: "scan_concurrent_sessions_4",
+        sessions: 4,
+    },
+    ScanConcurrencyCase {
+        name: "scan_concurrent_sessions_16",
+        sessions: 16,
+    },
+];
+
+struct ReaderSession {
+    ctx: SessionContext,
+    table_version: Option<u64>,
+}
+
+struct SessionOutcome {
+    elapsed_ms: f64,
+    rows: u64,
+    table_version: Option<u64>,
+}
+
+pub fn case_names() -> Vec<String> {
+    SCAN_CONCURRENCY_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct ScanConcurrencySuite;
+
+impl Suite for ScanConcurrencySuite {
+    fn name(&self) -> &'static str {
+        "scan_concurrency"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    let table_url = read_partitioned_table_url(fixtures_dir, scale, storage)?;
+
+    let mut out = Vec::new();
+    for case in SCAN_CONCURRENCY_CASES {
+        if let Some(cap) = storage.max_concurrency().filter(|cap| case.sessions > *cap) {
+            out.push(over_cap_case_result(case, cap));
+            continue;
+        }
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let storage = storage.clone();
+                let table_url = table_url.clone();
+                async move {
+                    open_reader_sessions(&storage, table_url, case.sessions)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+            |sessions| async move {
+                run_concurrent_scans(sessions, case)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    Ok(out)
+}
+
+/// Running fewer sessions than the case name promises would mislabel the result, and running
+/// all of them would trip the backend's throttling, so the case is skipped instead.
+fn over_cap_case_result(case: ScanConcurrencyCase, cap: usize) -> CaseResult {
+    CaseResult {
+        case: case.name.to_string(),
+        success: false,
+        validation_passed: false,
+        perf_status: PerfStatus::Invalid,
+        classification: "supported".to_string(),
+        samples: Vec::new(),
+        elapsed_stats: None,
+        run_summary: None,
+        run_summaries: None,
+        suite_manifest_hash: None,
+        case_definition_hash: None,
+        compatibility_key: None,
+        supports_decision: None,
+        required_runs: None,
+        decision_threshold_pct: None,
+        decision_metric: None,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
+        failure_kind: Some(FAILURE_KIND_UNSUPPORTED.to_string()),
+        failure: Some(CaseFailure {
+            message: format!(
+                "skipped: {} sessions exceed backend {MAX_CONCURRENCY_KEY}={cap}",
+                case.sessions
+            ),
+        }),
+    }
+}
+
+/// Each session gets its own table handle and `SessionContext`, so the timed section shares
+/// nothing but the object store underneath.
+async fn open_reader_sessions(
+    storage: &StorageConfig,
+    table_url: Url,
+    sessions: usize,
+) -> BenchResult<Vec<ReaderSession>> {
+    let mut out = Vec::with_capacity(sessions);
+    for _ in 0..sessions {
+        let table = storage.open_table(table_url.clone()).await?;
+        let table_version = optional_table_version_to_u64(table.version())?;
+        let ctx = SessionContext::new();
+        ctx.register_table("bench", table.table_provider().await?)?;
+        out.push(ReaderSession { ctx, table_version });
+    }
+    Ok(out)
+}
+
+async fn run_concurrent_scans(
+    sessions: Vec<ReaderSession>,
+    case: ScanConcurrencyCase,
+) -> BenchResult<SampleMetrics> {
+    let barrier = Arc::new(Barrier::new(sessions.len()));
+    let start = Instant::now();
+    let handles = sessions
+        .into_iter()
+        .map(|session| {
+            let barrier = Arc::clone(&barrier);
+            tokio::spawn(async move {
+                barrier.wait().await;
+                scan_session(session).await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut outcomes = Vec::with_capacity(handles.len());
+    for handle in handles {
+        outcomes.push(handle.await.map_err(|error| {
+            BenchError::InvalidArgument(format!("scan session task failed: {error}"))
+        })??);
+    }
+    let wall_secs = start.elapsed().as_secs_f64();
+
+    let rows_per_session = outcomes.first().map_or(0, |outcome| outcome.rows);
+    if outcomes
+        .iter()
+        .any(|outcome| outcome.rows != rows_per_session)
+    {
+        return Err(BenchError::InvalidArgument(format!(
+            "{} sessions returned different row counts: {:?}",
+            case.name,
+            outcomes
+                .iter()
+                .map(|outcome| outcome.rows)
+                .collect::<Vec<_>>()
+        )));
+    }
+    let total_rows = rows_per_session * outcomes.len() as u64;
+    let table_version = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.table_version)
+        .max();
+
+    let mut latencies = outcomes
+        .iter()
+        .map(|outcome| outcome.elapsed_ms)
+        .collect::<Vec<_>>();
+    latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let read_concurrency = ReadConcurrencyMetrics {
+        sessions: outcomes.len() as u64,
+        rows_per_sec: if wall_secs > 0.0 {
+            total_rows as f64 / wall_secs
+        } else {
+            0.0
+        },
+        session_p50_ms: nearest_rank(&latencies, 50),
+        session_max_ms: latencies.last().copied().unwrap_or(0.0),
+    };
+    let workers = outcomes
+        .iter()
+        .enumerate()
+        .map(|(idx, outcome)| WorkerMetrics {
+            worker: idx as u64,
+            elapsed_ms: outcome.elapsed_ms,
+            succeeded: true,
+            table_version: outcome.table_version,
+            commit_retries: None,
+        })
+        .collect();
+
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "sessions": case.sessions,
+        "rows_per_session": rows_per_session,
+    }))?;
+    let schema_hash = hash_json(&json!([
+        "operation:string",
+        "sessions:u64",
+        "rows_per_session:u64",
+    ]))?;
+
+    Ok(SampleMetrics::base(
+        Some(total_rows),
+        None,
+        Some(case.sessions as u64),
+        table_version,
+    )
+    .with_runtime_io(RuntimeIOMetrics {
+        peak_rss_mb: None,
+        cpu_time_ms: None,
+        bytes_read: None,
+        bytes_written: None,
+        files_touched: None,
+        files_skipped: None,
+        spill_bytes: None,
+        result_hash: Some(result_hash),
+        schema_hash: Some(schema_hash),
+        semantic_state_digest: None,
+        validation_summary: None,
+    })
+    .with_read_concurrency(read_concurrency)
+    .with_workers(workers))
+}
+
+async fn scan_session(session: ReaderSession) -> BenchResult<SessionOutcome> {
+    let start = Instant::now();
+    let df = session.ctx.sql(SCAN_CONCURRENCY_SQL).await?;
+    let task_ctx = Arc::new(df.task_ctx());
+    let plan = df.create_physical_plan().await?;
+    // Count rows as batches stream in; holding 16 copies of the table would measure memory
+    // pressure rather than the scan path.
+    let rows = execute_stream(plan, task_ctx)?
+        .try_fold(0_u64, |rows, batch| async move {
+            Ok(rows + batch.num_rows() as u64)
+        })
+        .await?;
+    Ok(SessionOutcome {
+        elapsed_ms: start.elapsed().as_secs_f64() * 1000.0,
+        rows,
+        table_version: session.table_version,
+    })
+}
@@ -3,109 +3,178 @@ use std::sync::Arc;
 use deltalake_core::datafusion::physical_plan::metrics::{MetricValue, MetricsSet};
 use deltalake_core::datafusion::physical_plan::ExecutionPlan;
 
+use crate::results::{SampleMetrics, ScanOperatorMetrics, ScanRewriteMetrics};
+
 #[derive(Default)]
 pub(crate) struct ScanMetrics {
     pub(crate) files_scanned: Option<u64>,
     pub(crate) files_pruned: Option<u64>,
     pub(crate) bytes_scanned: Option<u64>,
     pub(crate) scan_time_ms: Option<u64>,
+    pub(crate) row_groups_pruned: Option<u64>,
+    pub(crate) row_groups_matched: Option<u64>,
+    /// Every scan operator of the plan, in plan order.
+    pub(crate) operators: Vec<ScanOperatorMetrics>,
+}
+
+impl ScanMetrics {
+    /// Records the scan totals, row group pruning, and per-operator breakdown on `metrics`.
+    pub(crate) fn apply(self, metrics: SampleMetrics) -> SampleMetrics {
+        metrics
+            .with_scan_rewrite(ScanRewriteMetrics {
+                files_scanned: self.files_scanned,
+                files_pruned: self.files_pruned,
+                bytes_scanned: self.bytes_scanned,
+                scan_time_ms: self.scan_time_ms,
+                rewrite_time_ms: None,
+            })
+            .with_row_group_pruning(self.row_groups_pruned, self.row_groups_matched)
+            .with_scan_operators(self.operators)
+    }
 }
 
-pub(crate) fn extract_scan_metrics(plan: &Arc<dyn ExecutionPlan>) -> ScanMetrics {
-    let mut files_scanned_total = 0_u64;
-    let mut files_scanned_seen = false;
-    let mut files_pruned_total = 0_u64;
-    let mut files_pruned_seen = false;
-    let mut bytes_scanned_total = 0_u64;
-    let mut bytes_scanned_seen = false;
-    let mut scan_elapsed_nanos_total = 0_u64;
-    let mut scan_elapsed_seen = false;
-
-    collect_scan_metrics(
-        plan,
-        &mut files_scanned_total,
-        &mut files_scanned_seen,
-        &mut files_pruned_total,
-        &mut files_pruned_seen,
-        &mut bytes_scanned_total,
-        &mut bytes_scanned_seen,
-        &mut scan_elapsed_nanos_total,
-        &mut scan_elapsed_seen,
-    );
+/// Sums the scan operators of an executed `plan`. When the table's `total_active_files` is
+/// known, a files_scanned or files_pruned the scan did not report is derived from the other.
+pub(crate) fn extract_scan_metrics(
+    plan: &Arc<dyn ExecutionPlan>,
+    total_active_files: Option<u64>,
+) -> ScanMetrics {
+    let mut totals = ScanTotals::default();
+    collect_scan_operators(plan, &mut totals);
 
+    let files_scanned = totals.files_scanned.or_else(|| {
+        total_active_files.and_then(|total| {
+            totals
+                .files_pruned
+                .and_then(|pruned| total.checked_sub(pruned))
+        })
+    });
+    let files_pruned = totals.files_pruned.or_else(|| {
+        total_active_files
+            .and_then(|total| files_scanned.and_then(|scanned| total.checked_sub(scanned)))
+    });
     ScanMetrics {
-        files_scanned: files_scanned_seen.then_some(files_scanned_total),
-        files_pruned: files_pruned_seen.then_some(files_pruned_total),
-        bytes_scanned: bytes_scanned_seen.then_some(bytes_scanned_total),
-        scan_time_ms: scan_elapsed_seen.then_some(scan_elapsed_nanos_total / 1_000_000),
+        files_scanned,
+        files_pruned,
+        bytes_scanned: totals.bytes_scanned,
+        scan_time_ms: totals.scan_elapsed_nanos.map(|nanos| nanos / 1_000_000),
+        row_groups_pruned: totals.row_groups_pruned,
+        row_groups_matched: totals.row_groups_matched,
+        operators: totals.operators,
     }
 }
 
-// Recursive aggregation updates eight independent accumulators in-place.
-#[allow(clippy::too_many_arguments)]
-fn collect_scan_metrics(
-    plan: &Arc<dyn ExecutionPlan>,
-    files_scanned_total: &mut u64,
-    files_scanned_seen: &mut bool,
-    files_pruned_total: &mut u64,
-    files_pruned_seen: &mut bool,
-    bytes_scanned_total: &mut u64,
-    bytes_scanned_seen: &mut bool,
-    scan_elapsed_nanos_total: &mut u64,
-    scan_elapsed_seen: &mut bool,
-) {
-    if let Some(metrics) = plan.metrics() {
-        if let Some(v) = sum_count_metrics(&metrics, &["files_scanned", "count_files_scanned"]) {
-            *files_scanned_total = files_scanned_total.saturating_add(v);
-            *files_scanned_seen = true;
-        }
-        if let Some(v) = sum_count_metrics(&metrics, &["files_pruned", "count_files_pruned"]) {
-            *files_pruned_total = files_pruned_total.saturating_add(v);
-            *files_pruned_seen = true;
-        }
-        if let Some(v) = sum_pruned_metrics(&metrics, &["files_ranges_pruned_statistics"]) {
-            *files_pruned_total = files_pruned_total.saturating_add(v);
-            *files_pruned_seen = true;
-        }
-        if let Some(v) = sum_count_metrics(&metrics, &["bytes_scanned"]) {
-            *bytes_scanned_total = bytes_scanned_total.saturating_add(v);
-            *bytes_scanned_seen = true;
+#[derive(Default)]
+struct ScanTotals {
+    files_scanned: Option<u64>,
+    files_pruned: Option<u64>,
+    bytes_scanned: Option<u64>,
+    scan_elapsed_nanos: Option<u64>,
+    row_groups_pruned: Option<u64>,
+    row_groups_matched: Option<u64>,
+    operators: Vec<ScanOperatorMetrics>,
+}
+
+impl ScanTotals {
+    fn add(&mut self, operator: ScanOperatorMetrics, elapsed_nanos: Option<u64>) {
+        add_optional(&mut self.files_scanned, operator.files_scanned);
+        add_optional(&mut self.files_pruned, operator.files_pruned);
+        add_optional(&mut self.bytes_scanned, operator.bytes_scanned);
+        add_optional(&mut self.scan_elapsed_nanos, elapsed_nanos);
+        if operator.row_groups_matched.is_some() {
+            add_optional(
+                &mut self.row_groups_pruned,
+                Some(
+                    operator
+                        .row_groups_pruned_statistics
+                        .unwrap_or(0)
+                        .saturating_add(operator.row_groups_pruned_bloom_filter.unwrap_or(0)),
+                ),
+            );
+            add_optional(&mut self.row_groups_matched, operator.row_groups_matched);
         }
+        self.operators.push(operator);
+    }
+}
 
-        let is_scan_node = has_metric_name(&metrics, &["files_scanned", "count_files_scanned"])
-            || has_metric_name(&metrics, &["bytes_scanned"])
-            || has_metric_name(&metrics, &["files_ranges_pruned_statistics"]);
-        if is_scan_node {
-            if let Some(elapsed_nanos) = metrics.elapsed_compute() {
-                *scan_elapsed_nanos_total =
-                    scan_elapsed_nanos_total.saturating_add(elapsed_nanos as u64);
-                *scan_elapsed_seen = true;
-            }
+fn add_optional(total: &mut Option<u64>, value: Option<u64>) {
+    if let Some(value) = value {
+        *total = Some(total.unwrap_or(0).saturating_add(value));
+    }
+}
+
+fn collect_scan_operators(plan: &Arc<dyn ExecutionPlan>, totals: &mut ScanTotals) {
+    if let Some(metrics) = plan.metrics() {
+        if let Some(operator) = scan_operator_metrics(plan.name(), &metrics) {
+            let elapsed_nanos = metrics.elapsed_compute().map(|nanos| nanos as u64);
+            totals.add(operator, elapsed_nanos);
         }
     }
 
     for child in plan.children() {
-        collect_scan_metrics(
-            child,
-            files_scanned_total,
-            files_scanned_seen,
-            files_pruned_total,
-            files_pruned_seen,
-            bytes_scanned_total,
-            bytes_scanned_seen,
-            scan_elapsed_nanos_total,
-            scan_elapsed_seen,
-        );
+        collect_scan_operators(child, totals);
     }
 }
 
-fn has_metric_name(metrics: &MetricsSet, names: &[&str]) -> bool {
-    metrics.iter().any(|metric| {
-        let name = metric.value().name();
-        names.contains(&name)
+/// The scan metrics of one operator, or `None` when it reports none and so is not a scan.
+fn scan_operator_metrics(operator: &str, metrics: &MetricsSet) -> Option<ScanOperatorMetrics> {
+    let files_scanned = sum_count_metrics(metrics, &["files_scanned", "count_files_scanned"]);
+    let files_pruned = match (
+        sum_count_metrics(metrics, &["files_pruned", "count_files_pruned"]),
+        sum_pruned_metrics(metrics, &["files_ranges_pruned_statistics"]),
+    ) {
+        (None, None) => None,
+        (counted, ranges) => Some(counted.unwrap_or(0).saturating_add(ranges.unwrap_or(0))),
+    };
+    let bytes_scanned = sum_count_metrics(metrics, &["bytes_scanned"]);
+
+    // Parquet scans prune row groups by statistics first and then by bloom filter,
+    // so only row groups surviving both stages count as matched.
+    let statistics = pruning_metric_totals(metrics, "row_groups_pruned_statistics");
+    let bloom_filter = pruning_metric_totals(metrics, "row_groups_pruned_bloom_filter");
+    let row_groups_matched = statistics
+        .map(|(_, matched)| matched.saturating_sub(bloom_filter.map_or(0, |(pruned, _)| pruned)));
+    let page_index = pruning_metric_totals(metrics, "page_index_rows_pruned");
+    let pushdown_rows_pruned = sum_count_metrics(metrics, &["pushdown_rows_pruned"]);
+
+    let is_scan = files_scanned.is_some()
+        || files_pruned.is_some()
+        || bytes_scanned.is_some()
+        || statistics.is_some();
+    is_scan.then(|| ScanOperatorMetrics {
+        operator: operator.to_string(),
+        files_scanned,
+        files_pruned,
+        bytes_scanned,
+        row_groups_pruned_statistics: statistics.map(|(pruned, _)| pruned),
+        row_groups_pruned_bloom_filter: bloom_filter.map(|(pruned, _)| pruned),
+        row_groups_matched,
+        page_index_rows_pruned: page_index.map(|(pruned, _)| pruned),
+        page_index_rows_matched: page_index.map(|(_, matched)| matched),
+        pushdown_rows_pruned,
     })
 }
 
+fn pruning_metric_totals(metrics: &MetricsSet, metric_name: &str) -> Option<(u64, u64)> {
+    let mut pruned = 0_u64;
+    let mut matched = 0_u64;
+    let mut seen = false;
+    for metric in metrics.iter() {
+        if let MetricValue::PruningMetrics {
+            name,
+            pruning_metrics,
+        } = metric.value()
+        {
+            if name.as_ref() == metric_name {
+                pruned = pruned.saturating_add(pruning_metrics.pruned() as u64);
+                matched = matched.saturating_add(pruning_metrics.matched() as u64);
+                seen = true;
+            }
+        }
+    }
+    seen.then_some((pruned, matched))
+}
+
 fn sum_count_metrics(metrics: &MetricsSet, names: &[&str]) -> Option<u64> {
     let mut total = 0_u64;
     let mut seen = false;
@@ -137,3 +206,49 @@ pub(crate) fn sum_pruned_metrics(metrics: &MetricsSet, names: &[&str]) -> Option
     }
     seen.then_some(total)
 }
+
+#[cfg(test)]
+mod tests {
+    use deltalake_core::datafusion::physical_plan::metrics::{
+        ExecutionPlanMetricsSet, MetricBuilder,
+    };
+
+    use super::scan_operator_metrics;
+
+    #[test]
+    fn scan_operator_metrics_break_down_row_group_and_page_index_pruning() {
+        let metrics = ExecutionPlanMetricsSet::new();
+        MetricBuilder::new(&metrics)
+            .counter("files_scanned", 0)
+            .add(3);
+        let statistics =
+            MetricBuilder::new(&metrics).pruning_metrics("row_groups_pruned_statistics", 0);
+        statistics.add_pruned(5);
+        statistics.add_matched(7);
+        let bloom_filter =
+            MetricBuilder::new(&metrics).pruning_metrics("row_groups_pruned_bloom_filter", 0);
+        bloom_filter.add_pruned(2);
+        bloom_filter.add_matched(5);
+        let page_index = MetricBuilder::new(&metrics).pruning_metrics("page_index_rows_pruned", 0);
+        page_index.add_pruned(400);
+        page_index.add_matched(100);
+
+        let operator =
+            scan_operator_metrics("DataSourceExec", &metrics.clone_inner()).expect("scan operator");
+        assert_eq!(operator.operator, "DataSourceExec");
+        assert_eq!(operator.files_scanned, Some(3));
+        assert_eq!(operator.files_pruned, None);
+        assert_eq!(operator.row_groups_pruned_statistics, Some(5));
+        assert_eq!(operator.row_groups_pruned_bloom_filter, Some(2));
+        assert_eq!(operator.row_groups_matched, Some(5));
+        assert_eq!(operator.page_index_rows_pruned, Some(400));
+        assert_eq!(operator.page_index_rows_matched, Some(100));
+    }
+
+    #[test]
+    fn operators_without_scan_metrics_are_not_scans() {
+        let metrics = ExecutionPlanMetricsSet::new();
+        MetricBuilder::new(&metrics).output_rows(0).add(10);
+        assert!(scan_operator_metrics("FilterExec", &metrics.clone_inner()).is_none());
+    }
+}
@@ -0,0 +1,328 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use deltalake_core::datafusion::logical_expr::col;
+use deltalake_core::datafusion::prelude::SessionContext;
+use deltalake_core::protocol::SaveMode;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::delete_update::{usize_metric_to_u64, IntoOptionalRowCount};
+use super::optimize_vacuum::{normalize_target_size, OPTIMIZE_COMPACT_TARGET_SIZE};
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::BenchmarkLane;
+use crate::data::datasets::NarrowSaleRow;
+use crate::data::fixtures::{read_partitioned_table_path, rows_to_batch};
+use crate::data::row_cache::FixtureRowCache;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_scenario, ScenarioStep};
+use crate::storage::StorageConfig;
+use crate::validation::{lane_requires_semantic_validation, validate_table_state};
+use crate::version_compat::optional_table_version_to_u64;
+
+/// Every `INGEST_STRIDE`th fixture row is re-keyed past the fixture's ids and ingested as new
+/// rows, so the ingest batch is 5% of the table.
+const INGEST_STRIDE: usize = 20;
+/// Retention removes the oldest 10% of the fixture's ids.
+const RETENTION_FRACTION: f64 = 0.10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PipelineOperation {
+    /// Append the ingest batch.
+    Ingest,
+    /// Upsert corrections to every other ingested row.
+    MergeCorrections,
+    /// Delete rows whose id falls below the retention cutoff.
+    RetentionDelete,
+    /// Compact the files the earlier steps left behind.
+    Compact,
+}
+
+/// The ingest pipeline, in the order its steps run against one table. Each step commits one
+/// version on top of the previous step's.
+const PIPELINE_STEPS: [ScenarioStep<PipelineOperation>; 4] = [
+    ScenarioStep {
+        name: "pipeline_ingest_append",
+        commits: 1,
+        operation: PipelineOperation::Ingest,
+    },
+    ScenarioStep {
+        name: "pipeline_merge_corrections",
+        commits: 1,
+        operation: PipelineOperation::MergeCorrections,
+    },
+    ScenarioStep {
+        name: "pipeline_retention_delete",
+        commits: 1,
+        operation: PipelineOperation::RetentionDelete,
+    },
+    ScenarioStep {
+        name: "pipeline_compact",
+        commits: 1,
+        operation: PipelineOperation::Compact,
+    },
+];
+
+struct PipelineInputs {
+    ingest: Vec<NarrowSaleRow>,
+    corrections: Vec<NarrowSaleRow>,
+    retention_cutoff: i64,
+}
+
+struct PipelineTable {
+    _temp: tempfile::TempDir,
+    table: DeltaTable,
+}
+
+pub fn case_names() -> Vec<String> {
+    PIPELINE_STEPS
+        .iter()
+        .map(|step| step.name.to_string())
+        .collect()
+}
+
+pub struct ScenarioSuite;
+
+impl Suite for ScenarioSuite {
+    fn name(&self) -> &'static str {
+        "scenario"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    lane: BenchmarkLane,
+    warmup: u32,
+    iterations: u32,
+    storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "scenario suite does not support non-local storage backend yet",
+        ));
+    }
+
+    let source = read_partitioned_table_path(fixtures_dir, scale);
+    if !source.join("_delta_log").exists() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing read_partitioned fixture table; run bench data first",
+        ));
+    }
+    let inputs = fixture_rows
+        .load(fixtures_dir, scale)
+        .and_then(|rows| pipeline_inputs(&rows));
+    let inputs = match inputs {
+        Ok(inputs) => Arc::new(inputs),
+        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    };
+
+    let results = run_scenario(
+        &PIPELINE_STEPS,
+        warmup,
+        iterations,
+        || {
+            let source = source.clone();
+            let storage = storage.clone();
+            async move {
+                prepare_pipeline_table(&source, &storage)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+        |step, state| {
+            let inputs = Arc::clone(&inputs);
+            async move {
+                run_pipeline_step(state, step, &inputs, lane)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+        },
+    )
+    .await;
+
+    Ok(results.into_iter().map(into_case_result).collect())
+}
+
+fn pipeline_inputs(rows: &[NarrowSaleRow]) -> BenchResult<PipelineInputs> {
+    let (Some(min_id), Some(max_id)) = (
+        rows.iter().map(|row| row.id).min(),
+        rows.iter().map(|row| row.id).max(),
+    ) else {
+        return Err(BenchError::InvalidArgument(
+            "scenario suite needs a non-empty narrow_sales fixture".to_string(),
+        ));
+    };
+
+    let ingest = rows
+        .iter()
+        .step_by(INGEST_STRIDE)
+        .enumerate()
+        .map(|(offset, row)| NarrowSaleRow {
+            id: max_id + 1 + offset as i64,
+            ..row.clone()
+        })
+        .collect::<Vec<_>>();
+    let corrections = ingest
+        .iter()
+        .step_by(2)
+        .map(|row| NarrowSaleRow {
+            ts_ms: row.ts_ms + 1,
+            value_i64: row.value_i64.map(|value| value + 1),
+            ..row.clone()
+        })
+        .collect();
+    let retention_rows = ((max_id - min_id + 1) as f64 * RETENTION_FRACTION).round() as i64;
+
+    Ok(PipelineInputs {
+        ingest,
+        corrections,
+        retention_cutoff: min_id + retention_rows.max(1),
+    })
+}
+
+async fn prepare_pipeline_table(
+    source: &Path,
+    storage: &StorageConfig,
+) -> BenchResult<(PipelineTable, u64)> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("pipeline");
+    copy_dir_with(source, &table_dir, storage.fixture_copy())?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    let table = storage.open_table(table_url).await?;
+    let version = optional_table_version_to_u64(table.version())?.ok_or_else(|| {
+        BenchError::InvalidArgument("scenario suite requires a versioned table".to_string())
+    })?;
+    Ok((PipelineTable { _temp: temp, table }, version))
+}
+
+async fn run_pipeline_step(
+    state: PipelineTable,
+    step: ScenarioStep<PipelineOperation>,
+    inputs: &PipelineInputs,
+    lane: BenchmarkLane,
+) -> BenchResult<(PipelineTable, SampleMetrics)> {
+    let PipelineTable { _temp, table } = state;
+    let (table, rows_affected, file_operations) = match step.operation {
+        PipelineOperation::Ingest => {
+            let table = table
+                .write(vec![rows_to_batch(&inputs.ingest)?])
+                .with_save_mode(SaveMode::Append)
+                .await?;
+            (table, Some(inputs.ingest.len() as u64), None)
+        }
+        PipelineOperation::MergeCorrections => {
+            let source = SessionContext::new().read_batch(rows_to_batch(&inputs.corrections)?)?;
+            let (table, metrics) = table
+                .merge(source, col("target.id").eq(col("source.id")))
+                .with_source_alias("source")
+                .with_target_alias("target")
+                .when_matched_update(|update| {
+                    update
+                        .update("ts_ms", col("source.ts_ms"))
+                        .update("ts", col("source.ts"))
+                        .update("event_date", col("source.event_date"))
+                        .update("value_i64", col("source.value_i64"))
+                        .update("amount", col("source.amount"))
+                })?
+                .await?;
+            (
+                table,
+                Some(metrics.num_target_rows_updated as u64),
+                Some((metrics.num_target_files_added + metrics.num_target_files_removed) as u64),
+            )
+        }
+        PipelineOperation::RetentionDelete => {
+            let predicate = format!("id < {}", inputs.retention_cutoff);
+            let (table, metrics) = table.delete().with_predicate(predicate.as_str()).await?;
+            let rows_affected = metrics
+                .num_deleted_rows
+                .into_optional_row_count()
+                .map(|rows| usize_metric_to_u64("rows_affected", rows))
+                .transpose()?;
+            let file_operations = usize_metric_to_u64(
+                "file_operations",
+                metrics.num_added_files + metrics.num_removed_files,
+            )?;
+            (table, rows_affected, Some(file_operations))
+        }
+        PipelineOperation::Compact => {
+            let (table, metrics) = table
+                .optimize()
+                .with_target_size(normalize_target_size(OPTIMIZE_COMPACT_TARGET_SIZE)?.into())
+                .await?;
+            (
+                table,
+                None,
+                Some(metrics.num_files_added + metrics.num_files_removed),
+            )
+        }
+    };
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let result_hash = hash_json(&json!({
+        "operation": step.name,
+        "rows_affected": rows_affected,
+        "table_version": table_version,
+    }))?;
+    let mut schema_hash = hash_json(&json!([
+        "operation:string",
+        "rows_affected:optional<u64>",
+        "table_version:u64",
+    ]))?;
+    let mut semantic_state_digest = None;
+    let mut validation_summary = None;
+    if lane_requires_semantic_validation(lane) {
+        let validation = validate_table_state(&table).await?;
+        schema_hash = validation.schema_hash;
+        semantic_state_digest = Some(validation.digest);
+        validation_summary = Some(validation.summary);
+    }
+
+    let metrics = SampleMetrics::base(rows_affected, None, file_operations, table_version)
+        .with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: None,
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest,
+            validation_summary,
+        });
+    Ok((PipelineTable { _temp, table }, metrics))
+}
@@ -0,0 +1,303 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use deltalake_core::arrow::record_batch::RecordBatch;
+use deltalake_core::datafusion::physical_plan::execute_stream;
+use deltalake_core::datafusion::prelude::{SessionConfig, SessionContext};
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use futures::TryStreamExt;
+use serde_json::json;
+use url::Url;
+
+use super::fixture_copy::FixtureCopyStrategy;
+use super::scan_metrics::extract_scan_metrics;
+use super::{copy_dir_with, fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::data::fixtures::delete_update_small_files_table_path;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::{hash_json, hash_record_batches_unordered};
+use crate::results::{CaseResult, PerfStatus, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::version_compat::optional_table_version_to_u64;
+
+/// Prefix for failures where a scan observed rows committed after its snapshot was pinned.
+/// Cases failing with it are reported as `expected_failure` so the result records which
+/// delta-rs versions provide snapshot isolation instead of failing the run.
+pub const SNAPSHOT_ISOLATION_UNSUPPORTED: &str = "snapshot isolation unsupported by delta-rs";
+
+const SNAPSHOT_SCAN_SQL: &str =
+    "SELECT id, ts_ms, region, value_i64, flag, ts, event_date, amount FROM bench";
+/// A single partition with small batches keeps the scan streaming file by file, so most of the
+/// table is still unread when the mutation commits.
+const SNAPSHOT_SCAN_BATCH_SIZE: usize = 64;
+
+#[derive(Clone, Copy)]
+enum SnapshotMutation {
+    Delete,
+    Update,
+}
+
+impl SnapshotMutation {
+    fn label(self) -> &'static str {
+        match self {
+            SnapshotMutation::Delete => "delete",
+            SnapshotMutation::Update => "update",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct SnapshotIsolationCase {
+    name: &'static str,
+    mutation: SnapshotMutation,
+}
+
+const SNAPSHOT_ISOLATION_CASES: [SnapshotIsolationCase; 2] = [
+    SnapshotIsolationCase {
+        name: "snapshot_scan_during_delete",
+        mutation: SnapshotMutation::Delete,
+    },
+    SnapshotIsolationCase {
+        name: "snapshot_scan_during_update",
+        mutation: SnapshotMutation::Update,
+    },
+];
+
+struct IterationSetup {
+    _temp: tempfile::TempDir,
+    table_url: Url,
+    table: DeltaTable,
+    expected_hash: String,
+}
+
+pub fn case_names() -> Vec<String> {
+    SNAPSHOT_ISOLATION_CASES
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect()
+}
+
+pub struct SnapshotIsolationSuite;
+
+impl Suite for SnapshotIsolationSuite {
+    fn name(&self) -> &'static str {
+        "snapshot_isolation"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if !storage.is_local() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "snapshot_isolation suite does not support non-local storage backend yet",
+        ));
+    }
+    let source = delete_update_small_files_table_path(fixtures_dir, scale);
+    if !source.join("_delta_log").exists() {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing delete/update small-files fixture table; run bench data first",
+        ));
+    }
+
+    let fixture_copy = storage.fixture_copy();
+    let mut out = Vec::new();
+    for case in SNAPSHOT_ISOLATION_CASES {
+        let c = run_case_async_with_async_setup(
+            case.name,
+            warmup,
+            iterations,
+            hooks,
+            || {
+                let source = source.clone();
+                async move {
+                    prepare_iteration(&source, fixture_copy)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+            |setup| async move {
+                run_snapshot_isolation_case(setup, case)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+        )
+        .await;
+        out.push(classify_isolation_result(into_case_result(c)));
+    }
+    Ok(out)
+}
+
+/// Turns an observed isolation violation into an `expected_failure` capability signal; any
+/// other failure stays a real failure.
+fn classify_isolation_result(mut case: CaseResult) -> CaseResult {
+    let violated = case
+        .failure
+        .as_ref()
+        .is_some_and(|failure| failure.message.contains(SNAPSHOT_ISOLATION_UNSUPPORTED));
+    if violated {
+        case.success = true;
+        case.validation_passed = true;
+        case.perf_status = PerfStatus::ValidationOnly;
+        case.classification = "expected_failure".to_string();
+    }
+    case
+}
+
+async fn prepare_iteration(
+    source: &Path,
+    fixture_copy: FixtureCopyStrategy,
+) -> BenchResult<IterationSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_dir = temp.path().join("table");
+    copy_dir_with(source, &table_dir, fixture_copy)?;
+    let table_url = Url::from_directory_path(&table_dir).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create table URL for {}",
+            table_dir.display()
+        ))
+    })?;
+    let table = StorageConfig::local().open_table(table_url.clone()).await?;
+    let expected_hash = hash_record_batches_unordered(&scan_all(&table).await?)?;
+    Ok(IterationSetup {
+        _temp: temp,
+        table_url,
+        table,
+        expected_hash,
+    })
+}
+
+/// Starts a streaming scan on the pinned snapshot, commits the mutation through a second handle
+/// after the first batch arrives, then drains the scan. The drained rows must hash the same as
+/// the scan taken before the mutation.
+async fn run_snapshot_isolation_case(
+    setup: IterationSetup,
+    case: SnapshotIsolationCase,
+) -> BenchResult<SampleMetrics> {
+    let _keep_temp = setup._temp;
+    let pinned_version = optional_table_version_to_u64(setup.table.version())?;
+
+    let ctx = snapshot_scan_context();
+    ctx.register_table("bench", setup.table.table_provider().await?)?;
+    let df = ctx.sql(SNAPSHOT_SCAN_SQL).await?;
+    let task_ctx = Arc::new(df.task_ctx());
+    let plan = df.create_physical_plan().await?;
+    let mut stream = execute_stream(Arc::clone(&plan), task_ctx)?;
+    let first = stream.try_next().await?.ok_or_else(|| {
+        BenchError::InvalidArgument(format!(
+            "{}: scan finished before the mutation could start",
+            case.name
+        ))
+    })?;
+
+    let writer = StorageConfig::local()
+        .open_table(setup.table_url.clone())
+        .await?;
+    let writer = match case.mutation {
+        SnapshotMutation::Delete => writer.delete().with_predicate("id % 2 = 0").await?.0,
+        SnapshotMutation::Update => {
+            writer
+                .update()
+                .with_predicate("id % 2 = 0")
+                .with_update("value_i64", "value_i64 + 1")
+                .await?
+                .0
+        }
+    };
+    let mutated_version = optional_table_version_to_u64(writer.version())?;
+    if mutated_version <= pinned_version {
+        return Err(BenchError::InvalidArgument(format!(
+            "{}: {} did not commit a new version (pinned {pinned_version:?}, found {mutated_version:?})",
+            case.name,
+            case.mutation.label()
+        )));
+    }
+
+    let mut batches = vec![first];
+    batches.extend(stream.try_collect::<Vec<_>>().await?);
+    let rows = batches
+        .iter()
+        .map(|batch| batch.num_rows() as u64)
+        .sum::<u64>();
+    let found_hash = hash_record_batches_unordered(&batches)?;
+    if found_hash != setup.expected_hash {
+        return Err(BenchError::InvalidArgument(format!(
+            "{SNAPSHOT_ISOLATION_UNSUPPORTED}: {}: scan pinned at version {pinned_version:?} observed the mid-scan {} (expected {}, found {found_hash})",
+            case.name,
+            case.mutation.label(),
+            setup.expected_hash
+        )));
+    }
+
+    let result_hash = hash_json(&json!({
+        "operation": case.name,
+        "mutation": case.mutation.label(),
+        "snapshot_isolated": true,
+    }))?;
+    let schema_hash = hash_json(&json!([
+        "operation:string",
+        "mutation:string",
+        "snapshot_isolated:bool",
+    ]))?;
+
+    Ok(extract_scan_metrics(&plan, None)
+        .apply(SampleMetrics::base(
+            Some(rows),
+            None,
+            Some(2),
+            pinned_version,
+        ))
+        .with_runtime_io(RuntimeIOMetrics {
+            peak_rss_mb: None,
+            cpu_time_ms: None,
+            bytes_read: None,
+            bytes_written: None,
+            files_touched: None,
+            files_skipped: None,
+            spill_bytes: None,
+            result_hash: Some(result_hash),
+            schema_hash: Some(schema_hash),
+            semantic_state_digest: None,
+            validation_summary: None,
+        }))
+}
+
+fn snapshot_scan_context() -> SessionContext {
+    SessionContext::new_with_config(
+        SessionConfig::new()
+            .with_target_partitions(1)
+            .with_batch_size(SNAPSHOT_SCAN_BATCH_SIZE),
+    )
+}
+
+async fn scan_all(table: &DeltaTable) -> BenchResult<Vec<RecordBatch>> {
+    let ctx = snapshot_scan_context();
+    ctx.register_table("bench", table.table_provider().await?)?;
+    Ok(ctx.sql(SNAPSHOT_SCAN_SQL).await?.collect().await?)
+}
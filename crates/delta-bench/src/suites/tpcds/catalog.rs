@@ -1,38 +1,174 @@
+/// Phase the runner executes when `--tpcds-phase` is not given.
+pub const DEFAULT_TPCDS_PHASE: u8 = 1;
+/// Highest phase any cataloged query is scheduled for. The unported queries are disabled rather
+/// than scheduled, so this stays at 1 until a query is ported for a later phase.
+pub const MAX_TPCDS_PHASE: u8 = 1;
+
+const NOT_PORTED_REASON: &str =
+    "not yet ported: fixtures only provision store_sales and this query needs the wider TPC-DS schema";
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TpcdsQuerySpec {
     pub id: &'static str,
     pub sql_file: &'static str,
+    /// Rollout phase the query first runs in; later phases include every earlier one.
+    pub phase: u8,
     pub enabled: bool,
     pub skip_reason: Option<&'static str>,
 }
 
-pub fn phase1_query_catalog() -> Vec<TpcdsQuerySpec> {
-    vec![
-        TpcdsQuerySpec {
-            id: "q03",
-            sql_file: "q03.sql",
-            enabled: true,
-            skip_reason: None,
-        },
-        TpcdsQuerySpec {
-            id: "q07",
-            sql_file: "q07.sql",
-            enabled: true,
-            skip_reason: None,
-        },
-        TpcdsQuerySpec {
-            id: "q64",
-            sql_file: "q64.sql",
+impl TpcdsQuerySpec {
+    const fn enabled(id: &'static str, sql_file: &'static str, phase: u8) -> Self {
+        Self {
+            id,
+            sql_file,
+            phase,
             enabled: true,
             skip_reason: None,
-        },
-        TpcdsQuerySpec {
-            id: "q72",
-            sql_file: "q72.sql",
+        }
+    }
+
+    const fn disabled(
+        id: &'static str,
+        sql_file: &'static str,
+        phase: u8,
+        skip_reason: &'static str,
+    ) -> Self {
+        Self {
+            id,
+            sql_file,
+            phase,
             enabled: false,
-            skip_reason: Some(
-                "blocked pending DataFusion issue-tracker parity for TPC-DS q72 semantics",
-            ),
-        },
+            skip_reason: Some(skip_reason),
+        }
+    }
+
+    /// Why the query is reported as skipped under `selected_phase`, or `None` when it executes.
+    pub fn skip_reason_for_phase(&self, selected_phase: u8) -> Option<String> {
+        if !self.enabled {
+            return Some(
+                self.skip_reason
+                    .unwrap_or("query disabled in current TPC-DS phase")
+                    .to_string(),
+            );
+        }
+        if self.phase > selected_phase {
+            return Some(format!(
+                "scheduled for TPC-DS phase {} (running phase {selected_phase})",
+                self.phase
+            ));
+        }
+        None
+    }
+}
+
+/// Full TPC-DS query set in id order. Queries stay cataloged while disabled so runs report them
+/// as skipped instead of silently dropping them.
+pub fn query_catalog() -> Vec<TpcdsQuerySpec> {
+    vec![
+        TpcdsQuerySpec::disabled("q01", "q01.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q02", "q02.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::enabled("q03", "q03.sql", 1),
+        TpcdsQuerySpec::disabled("q04", "q04.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q05", "q05.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q06", "q06.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::enabled("q07", "q07.sql", 1),
+        TpcdsQuerySpec::disabled("q08", "q08.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q09", "q09.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q10", "q10.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q11", "q11.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q12", "q12.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q13", "q13.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q14", "q14.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q15", "q15.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q16", "q16.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q17", "q17.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q18", "q18.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q19", "q19.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q20", "q20.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q21", "q21.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q22", "q22.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q23", "q23.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q24", "q24.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q25", "q25.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q26", "q26.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q27", "q27.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q28", "q28.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q29", "q29.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q30", "q30.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q31", "q31.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q32", "q32.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q33", "q33.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q34", "q34.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q35", "q35.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q36", "q36.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q37", "q37.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q38", "q38.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q39", "q39.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q40", "q40.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q41", "q41.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q42", "q42.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q43", "q43.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q44", "q44.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q45", "q45.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q46", "q46.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q47", "q47.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q48", "q48.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q49", "q49.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q50", "q50.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q51", "q51.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q52", "q52.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q53", "q53.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q54", "q54.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q55", "q55.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q56", "q56.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q57", "q57.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q58", "q58.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q59", "q59.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q60", "q60.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q61", "q61.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q62", "q62.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q63", "q63.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::enabled("q64", "q64.sql", 1),
+        TpcdsQuerySpec::disabled("q65", "q65.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q66", "q66.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q67", "q67.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q68", "q68.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q69", "q69.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q70", "q70.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q71", "q71.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled(
+            "q72",
+            "q72.sql",
+            1,
+            "blocked pending DataFusion issue-tracker parity for TPC-DS q72 semantics",
+        ),
+        TpcdsQuerySpec::disabled("q73", "q73.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q74", "q74.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q75", "q75.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q76", "q76.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q77", "q77.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q78", "q78.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q79", "q79.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q80", "q80.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q81", "q81.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q82", "q82.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q83", "q83.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q84", "q84.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q85", "q85.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q86", "q86.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q87", "q87.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q88", "q88.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q89", "q89.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q90", "q90.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q91", "q91.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q92", "q92.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q93", "q93.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q94", "q94.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q95", "q95.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q96", "q96.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q97", "q97.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q98", "q98.sql", 1, NOT_PORTED_REASON),
+        TpcdsQuerySpec::disabled("q99", "q99.sql", 1, NOT_PORTED_REASON),
     ]
 }
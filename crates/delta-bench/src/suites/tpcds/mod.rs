@@ -6,15 +6,20 @@ use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::LocalBoxFuture;
+use serde::Serialize;
+
+use super::{Suite, SuiteOptions, SuiteRunArgs};
 use crate::cli::TimingPhase;
-use crate::error::BenchResult;
+use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::{hash_arrow_schema, hash_record_batches_unordered};
 use crate::results::{
-    CaseFailure, CaseResult, PerfStatus, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics,
+    CaseFailure, CaseResult, PerfStatus, RuntimeIOMetrics, SampleMetrics,
     FAILURE_KIND_EXECUTION_ERROR, FAILURE_KIND_UNSUPPORTED,
 };
 use crate::runner::{
-    run_case_async_with_timing_phase, CaseExecutionResult, PhaseTiming, TimedSample,
+    record_physical_plan, record_plan_metrics, run_case_async_with_timing_phase,
+    run_case_open_loop, CaseExecutionResult, CaseHooks, OpenLoopConfig, PhaseTiming, TimedSample,
 };
 use crate::storage::StorageConfig;
 use crate::suites::scan_metrics::extract_scan_metrics;
@@ -23,6 +28,7 @@ use deltalake_core::datafusion::execution::context::TaskContext;
 use deltalake_core::datafusion::physical_plan::collect;
 use deltalake_core::datafusion::physical_plan::ExecutionPlan;
 use deltalake_core::datafusion::prelude::SessionContext;
+use registration::{TableUrlResolver, TpcdsLayout};
 
 const TPCDS_DELAY_ENV: &str = "DELTA_BENCH_TPCDS_DELAY_MS";
 const TPCDS_ALLOW_DELAY_ENV: &str = "DELTA_BENCH_ALLOW_TPCDS_DELAY";
@@ -43,52 +49,140 @@ struct ExecutedTpcdsQuery {
     execution_elapsed_ms: f64,
 }
 
+/// Partition pruning outcome of one executed TPC-DS query, written next to the suite results.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TpcdsPruningPoint {
+    pub case: String,
+    pub layout: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_scanned: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_pruned: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_pruned_ratio: Option<f64>,
+}
+
 pub fn case_names() -> Vec<String> {
-    catalog::phase1_query_catalog()
+    catalog::query_catalog()
         .into_iter()
         .map(|spec| format!("tpcds_{}", spec.id))
         .collect()
 }
 
+pub struct TpcdsSuite;
+
+impl Suite for TpcdsSuite {
+    fn name(&self) -> &'static str {
+        "tpcds"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn phase_aware(&self) -> bool {
+        true
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.timing_phase,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.options,
+        ))
+    }
+}
+
+/// Collects files scanned and pruned per executed TPC-DS query so runs against each
+/// [`TpcdsLayout`] can be compared query by query. Skipped queries are left out.
+pub fn pruning_report(cases: &[CaseResult], layout: TpcdsLayout) -> Vec<TpcdsPruningPoint> {
+    let names = case_names();
+    cases
+        .iter()
+        .filter(|case| names.contains(&case.case) && !case.samples.is_empty())
+        .map(|case| {
+            let metrics = case.samples[0].metrics.as_ref();
+            let files_scanned = metrics.and_then(|metrics| metrics.files_scanned);
+            let files_pruned = metrics.and_then(|metrics| metrics.files_pruned);
+            let files_pruned_ratio = match (files_scanned, files_pruned) {
+                (Some(scanned), Some(pruned)) if scanned + pruned > 0 => {
+                    Some(pruned as f64 / (scanned + pruned) as f64)
+                }
+                _ => None,
+            };
+            TpcdsPruningPoint {
+                case: case.case.clone(),
+                layout: layout.as_str().to_string(),
+                success: case.success,
+                files_scanned,
+                files_pruned,
+                files_pruned_ratio,
+            }
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     timing_phase: TimingPhase,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    options: &SuiteOptions,
 ) -> BenchResult<Vec<CaseResult>> {
-    let specs = catalog::phase1_query_catalog();
+    let specs = catalog::query_catalog();
     run_with_specs_and_sql_dir(
         fixtures_dir,
         scale,
         timing_phase,
+        options.tpcds_phase,
         warmup,
         iterations,
+        hooks,
         storage,
+        options.tpcds_layout,
+        open_loop_config(options, timing_phase)?,
         &specs,
         &sql_loader::default_sql_dir(),
     )
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn run_with_specs_and_sql_dir(
     fixtures_dir: &Path,
     scale: &str,
     timing_phase: TimingPhase,
+    tpcds_phase: u8,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    layout: TpcdsLayout,
+    load: Option<OpenLoopConfig>,
     specs: &[catalog::TpcdsQuerySpec],
     sql_dir: &Path,
 ) -> BenchResult<Vec<CaseResult>> {
     let specs = specs.to_vec();
+    let resolve = registration::tpcds_table_resolver(fixtures_dir, scale, storage, layout);
 
     let mut out = Vec::new();
     for spec in specs {
         let case_name = format!("tpcds_{}", spec.id);
-        if !spec.enabled {
-            out.push(skipped_case_result(case_name, spec.skip_reason));
+        if let Some(skip_reason) = spec.skip_reason_for_phase(tpcds_phase) {
+            out.push(skipped_case_result(case_name, &skip_reason));
             continue;
         }
 
@@ -112,6 +206,14 @@ pub(crate) async fn run_with_specs_and_sql_dir(
                     required_runs: None,
                     decision_threshold_pct: None,
                     decision_metric: None,
+                    seed: None,
+                    physical_plan: None,
+                    plan_metrics_file: None,
+                    plan_metrics: Vec::new(),
+                    profile_file: None,
+                    flamegraph: None,
+                    heap_profile: None,
+                    warnings: Vec::new(),
                     failure_kind: Some(FAILURE_KIND_EXECUTION_ERROR.to_string()),
                     failure: Some(CaseFailure {
                         message: format!(
@@ -124,54 +226,109 @@ pub(crate) async fn run_with_specs_and_sql_dir(
             }
         };
 
-        let fixture_root = fixtures_dir.to_path_buf();
-        let scale = scale.to_string();
-        let storage = storage.clone();
-        let run_case_name = case_name.clone();
-        let result =
-            run_case_async_with_timing_phase(&case_name, warmup, iterations, timing_phase, || {
-                let case_name = run_case_name.clone();
-                let sql = sql.clone();
-                let fixture_root = fixture_root.clone();
-                let scale = scale.clone();
-                let storage = storage.clone();
-                async move {
-                    let load_start = std::time::Instant::now();
-                    let loaded = load_query_context(&fixture_root, &scale, &storage, &sql)
-                        .await
-                        .map_err(|err| err.to_string())?;
-                    let load_elapsed_ms = load_start.elapsed().as_secs_f64() * 1000.0;
-
-                    let planning_start = std::time::Instant::now();
-                    let prepared = plan_loaded_query(loaded, &sql)
-                        .await
-                        .map_err(|err| err.to_string())?;
-                    let planning_elapsed_ms = planning_start.elapsed().as_secs_f64() * 1000.0;
-
-                    let executed = execute_prepared_query(&case_name, prepared)
-                        .await
-                        .map_err(|err| err.to_string())?;
-                    let execution_elapsed_ms = executed.execution_elapsed_ms;
-                    let (metrics, validate_elapsed_ms) = validate_executed_query(executed)
-                        .await
-                        .map_err(|err| err.to_string())?;
-                    Ok::<TimedSample<SampleMetrics>, String>(TimedSample::new(
-                        metrics,
-                        PhaseTiming::default()
-                            .with_load_ms(load_elapsed_ms)
-                            .with_plan_ms(planning_elapsed_ms)
-                            .with_execute_ms(execution_elapsed_ms)
-                            .with_validate_ms(validate_elapsed_ms),
-                    ))
-                }
-            })
-            .await;
-        out.push(into_case_result(result));
+        out.push(
+            run_sql_query_case(
+                &case_name,
+                &sql,
+                timing_phase,
+                warmup,
+                iterations,
+                hooks,
+                storage,
+                &resolve,
+                load,
+            )
+            .await,
+        );
     }
 
     Ok(out)
 }
 
+/// The run's open-loop load settings. Load windows time whole queries, so they only combine
+/// with `timing_phase=execute`.
+pub(crate) fn open_loop_config(
+    options: &SuiteOptions,
+    timing_phase: TimingPhase,
+) -> BenchResult<Option<OpenLoopConfig>> {
+    let load = options.load;
+    if load.is_some() && timing_phase != TimingPhase::Execute {
+        return Err(BenchError::InvalidArgument(format!(
+            "open-loop load mode requires timing_phase=execute (found {})",
+            timing_phase.as_str()
+        )));
+    }
+    Ok(load)
+}
+
+/// Benchmarks one SQL query whose tables come from `resolve`. Every iteration registers the
+/// tables in a fresh session, so `load` timing covers opening each referenced table. With
+/// `load` set, each measured iteration is an open-loop window of overlapping query runs.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn run_sql_query_case(
+    case_name: &str,
+    sql: &str,
+    timing_phase: TimingPhase,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+    resolve: &TableUrlResolver,
+    load: Option<OpenLoopConfig>,
+) -> CaseResult {
+    let op = || {
+        let case_name = case_name.to_string();
+        let sql = sql.to_string();
+        let storage = storage.clone();
+        let resolve = Arc::clone(resolve);
+        async move {
+            let load_start = std::time::Instant::now();
+            let loaded = load_query_context(&storage, &resolve, &sql)
+                .await
+                .map_err(|err| err.to_string())?;
+            let load_elapsed_ms = load_start.elapsed().as_secs_f64() * 1000.0;
+
+            let planning_start = std::time::Instant::now();
+            let prepared = plan_loaded_query(loaded, &sql)
+                .await
+                .map_err(|err| err.to_string())?;
+            let planning_elapsed_ms = planning_start.elapsed().as_secs_f64() * 1000.0;
+            record_physical_plan(hooks, prepared.plan.as_ref());
+
+            let executed = execute_prepared_query(&case_name, prepared)
+                .await
+                .map_err(|err| err.to_string())?;
+            let execution_elapsed_ms = executed.execution_elapsed_ms;
+            record_plan_metrics(hooks, executed.plan.as_ref());
+            let (metrics, validate_elapsed_ms) = validate_executed_query(executed)
+                .await
+                .map_err(|err| err.to_string())?;
+            Ok::<TimedSample<SampleMetrics>, String>(TimedSample::new(
+                metrics,
+                PhaseTiming::default()
+                    .with_load_ms(load_elapsed_ms)
+                    .with_plan_ms(planning_elapsed_ms)
+                    .with_execute_ms(execution_elapsed_ms)
+                    .with_validate_ms(validate_elapsed_ms),
+            ))
+        }
+    };
+    let result = match load {
+        Some(config) => {
+            run_case_open_loop(case_name, warmup, iterations, hooks, config, || {
+                let query = op();
+                async move { query.await.map(|sample| sample.metrics) }
+            })
+            .await
+        }
+        None => {
+            run_case_async_with_timing_phase(case_name, warmup, iterations, hooks, timing_phase, op)
+                .await
+        }
+    };
+    into_case_result(result)
+}
+
 fn load_case_sql(spec: &catalog::TpcdsQuerySpec, sql_dir: &Path) -> BenchResult<String> {
     let loaded = sql_loader::load_enabled_queries_from_dir(std::slice::from_ref(spec), sql_dir)?;
     let Some(query) = loaded.into_iter().next() else {
@@ -184,13 +341,12 @@ fn load_case_sql(spec: &catalog::TpcdsQuerySpec, sql_dir: &Path) -> BenchResult<
 }
 
 async fn load_query_context(
-    fixtures_dir: &Path,
-    scale: &str,
     storage: &StorageConfig,
+    resolve: &TableUrlResolver,
     sql: &str,
 ) -> BenchResult<LoadedTpcdsQuery> {
     let ctx = SessionContext::new();
-    registration::register_tables_for_sql(&ctx, fixtures_dir, scale, storage, sql).await?;
+    registration::register_tables_for_sql(&ctx, storage, resolve, sql).await?;
 
     Ok(LoadedTpcdsQuery { ctx })
 }
@@ -228,20 +384,13 @@ async fn validate_executed_query(
         .iter()
         .map(|batch| batch.num_rows() as u64)
         .sum();
-    let scan = extract_scan_metrics(&executed.plan);
+    let scan = extract_scan_metrics(&executed.plan, None);
     let result_hash = hash_record_batches_unordered(&executed.batches)?;
     let schema_hash = hash_arrow_schema(executed.plan.schema().as_ref())?;
     let validate_elapsed_ms = validate_start.elapsed().as_secs_f64() * 1000.0;
 
     Ok((
-        SampleMetrics::base(Some(rows_processed), None, None, None)
-            .with_scan_rewrite(ScanRewriteMetrics {
-                files_scanned: scan.files_scanned,
-                files_pruned: scan.files_pruned,
-                bytes_scanned: scan.bytes_scanned,
-                scan_time_ms: scan.scan_time_ms,
-                rewrite_time_ms: None,
-            })
+        scan.apply(SampleMetrics::base(Some(rows_processed), None, None, None))
             .with_runtime_io(RuntimeIOMetrics {
                 peak_rss_mb: None,
                 cpu_time_ms: None,
@@ -291,7 +440,7 @@ fn parse_validation_delay(case_id: &str) -> BenchResult<Option<Duration>> {
     Ok(Some(Duration::from_millis(delay_ms)))
 }
 
-fn skipped_case_result(case: String, skip_reason: Option<&str>) -> CaseResult {
+fn skipped_case_result(case: String, skip_reason: &str) -> CaseResult {
     CaseResult {
         case,
         success: false,
@@ -309,12 +458,17 @@ fn skipped_case_result(case: String, skip_reason: Option<&str>) -> CaseResult {
         required_runs: None,
         decision_threshold_pct: None,
         decision_metric: None,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: Some(FAILURE_KIND_UNSUPPORTED.to_string()),
         failure: Some(CaseFailure {
-            message: format!(
-                "skipped: {}",
-                skip_reason.unwrap_or("query disabled in current TPC-DS phase")
-            ),
+            message: format!("skipped: {skip_reason}"),
         }),
     }
 }
@@ -332,15 +486,19 @@ mod tests {
     use std::time::Duration;
 
     use super::{
-        catalog::TpcdsQuerySpec, execute_prepared_query, load_query_context,
-        parse_validation_delay, plan_loaded_query, run_with_specs_and_sql_dir,
-        validate_executed_query, TPCDS_ALLOW_DELAY_ENV, TPCDS_DELAY_ENV,
-        TPCDS_VALIDATION_CANARY_CASE_ID,
+        catalog::{TpcdsQuerySpec, DEFAULT_TPCDS_PHASE},
+        execute_prepared_query, load_query_context, open_loop_config, parse_validation_delay,
+        plan_loaded_query,
+        registration::tpcds_table_resolver,
+        run_with_specs_and_sql_dir, validate_executed_query, TpcdsLayout, TPCDS_ALLOW_DELAY_ENV,
+        TPCDS_DELAY_ENV, TPCDS_VALIDATION_CANARY_CASE_ID,
     };
     use crate::cli::TimingPhase;
     use crate::data::fixtures::generate_fixtures;
+    use crate::runner::{CaseHooks, OpenLoopConfig};
     use crate::storage::StorageConfig;
     use crate::suites::scan_metrics::sum_pruned_metrics;
+    use crate::suites::SuiteOptions;
     use deltalake_core::datafusion::physical_plan::metrics::{
         ExecutionPlanMetricsSet, MetricBuilder,
     };
@@ -403,9 +561,8 @@ mod tests {
             .expect("generate fixtures");
 
         let loaded = load_query_context(
-            temp.path(),
-            "sf1",
             &storage,
+            &tpcds_table_resolver(temp.path(), "sf1", &storage, TpcdsLayout::Unpartitioned),
             "SELECT COUNT(*) FROM store_sales",
         )
         .await
@@ -426,11 +583,46 @@ mod tests {
         assert!(metrics.rows_processed.unwrap_or(0) > 0);
     }
 
+    #[tokio::test]
+    async fn date_partitioned_layout_prunes_fact_files_for_date_filters() {
+        let temp = tempfile::tempdir().expect("fixtures tempdir");
+        let storage = StorageConfig::local();
+        generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+            .await
+            .expect("generate fixtures");
+        let sql = "SELECT COUNT(*) FROM store_sales WHERE ss_sold_date_sk = 2451545";
+
+        let mut layouts = Vec::new();
+        for layout in [TpcdsLayout::Unpartitioned, TpcdsLayout::DatePartitioned] {
+            let resolve = tpcds_table_resolver(temp.path(), "sf1", &storage, layout);
+            let loaded = load_query_context(&storage, &resolve, sql)
+                .await
+                .expect("load query context");
+            let prepared = plan_loaded_query(loaded, sql).await.expect("plan query");
+            let executed = execute_prepared_query("tpcds_q03", prepared)
+                .await
+                .expect("execute query");
+            let (metrics, _) = validate_executed_query(executed)
+                .await
+                .expect("validate query");
+            layouts.push(metrics);
+        }
+
+        let (unpartitioned, partitioned) = (&layouts[0], &layouts[1]);
+        assert_eq!(
+            partitioned.result_hash, unpartitioned.result_hash,
+            "layouts must return identical results"
+        );
+        assert_eq!(partitioned.files_scanned, Some(1));
+        assert!(partitioned.files_pruned.unwrap_or(0) > 0);
+    }
+
     #[tokio::test]
     async fn missing_sql_is_reported_as_case_failure_not_suite_error() {
         let specs = vec![TpcdsQuerySpec {
             id: "q99",
             sql_file: "q99.sql",
+            phase: 1,
             enabled: true,
             skip_reason: None,
         }];
@@ -442,9 +634,13 @@ mod tests {
             temp_fixtures.path(),
             "sf1",
             TimingPhase::Execute,
+            DEFAULT_TPCDS_PHASE,
             0,
             1,
+            &CaseHooks::default(),
             &storage,
+            TpcdsLayout::Unpartitioned,
+            None,
             &specs,
             temp_sql.path(),
         )
@@ -498,4 +694,30 @@ mod tests {
         assert_eq!(skipped, None);
         assert_eq!(selected, Some(Duration::from_millis(25)));
     }
+
+    #[test]
+    fn open_loop_load_mode_requires_execute_timing() {
+        let options = SuiteOptions {
+            load: Some(
+                OpenLoopConfig::new(Duration::from_secs(10), 2.5).expect("valid load settings"),
+            ),
+            ..SuiteOptions::default()
+        };
+
+        let load = open_loop_config(&options, TimingPhase::Execute)
+            .expect("execute timing accepts load mode")
+            .expect("load mode enabled");
+        assert_eq!(load.duration, Duration::from_secs(10));
+        assert_eq!(load.target_ops_per_sec, 2.5);
+        let error = open_loop_config(&options, TimingPhase::Plan)
+            .expect_err("load mode should reject plan timing");
+        assert!(
+            error.to_string().contains("timing_phase=execute"),
+            "{error}"
+        );
+        assert_eq!(
+            open_loop_config(&SuiteOptions::default(), TimingPhase::Plan).expect("closed loop"),
+            None
+        );
+    }
 }
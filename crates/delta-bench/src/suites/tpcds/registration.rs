@@ -1,5 +1,6 @@
 use std::collections::{BTreeSet, HashSet};
 use std::path::Path;
+use std::sync::Arc;
 
 use deltalake_core::datafusion::prelude::SessionContext;
 use deltalake_core::datafusion::sql::sqlparser::ast::{
@@ -7,42 +8,96 @@ use deltalake_core::datafusion::sql::sqlparser::ast::{
 };
 use deltalake_core::datafusion::sql::sqlparser::dialect::GenericDialect;
 use deltalake_core::datafusion::sql::sqlparser::parser::Parser;
+use url::Url;
 
 use crate::error::{BenchError, BenchResult};
 use crate::storage::StorageConfig;
 
 const TPCDS_DIR: &str = "tpcds";
+const TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR: &str = "store_sales_date_partitioned";
 
-pub async fn register_tables_for_sql(
-    ctx: &SessionContext,
+/// Physical layout the TPC-DS fact table is registered from. Queries always see the same
+/// `store_sales` name and schema, so results stay comparable across layouts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TpcdsLayout {
+    #[default]
+    Unpartitioned,
+    DatePartitioned,
+}
+
+impl TpcdsLayout {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Unpartitioned => "unpartitioned",
+            Self::DatePartitioned => "date_partitioned",
+        }
+    }
+
+    pub fn parse(raw: &str) -> BenchResult<Self> {
+        match raw.trim() {
+            "unpartitioned" => Ok(Self::Unpartitioned),
+            "date_partitioned" => Ok(Self::DatePartitioned),
+            other => Err(BenchError::InvalidArgument(format!(
+                "unknown TPC-DS layout '{other}'; expected unpartitioned or date_partitioned"
+            ))),
+        }
+    }
+
+    fn table_dir(self, table_name: &str) -> &str {
+        match (self, table_name) {
+            (Self::DatePartitioned, "store_sales") => TPCDS_DATE_PARTITIONED_STORE_SALES_TABLE_DIR,
+            _ => table_name,
+        }
+    }
+}
+
+/// Maps a table name referenced by a query to the URL of the Delta table registered under it.
+pub type TableUrlResolver = Arc<dyn Fn(&str) -> BenchResult<Url> + Send + Sync>;
+
+/// Resolves TPC-DS table names to `<fixtures>/<scale>/tpcds/<table>` for the given layout.
+pub fn tpcds_table_resolver(
     fixtures_dir: &Path,
     scale: &str,
     storage: &StorageConfig,
+    layout: TpcdsLayout,
+) -> TableUrlResolver {
+    let fixtures_dir = fixtures_dir.to_path_buf();
+    let scale = scale.to_string();
+    let storage = storage.clone();
+    Arc::new(move |table_name| {
+        let table_dir = layout.table_dir(table_name);
+        let local_table_path = fixtures_dir.join(&scale).join(TPCDS_DIR).join(table_dir);
+        let remote_table_name = format!("{TPCDS_DIR}/{table_dir}");
+        storage.table_url_for(&local_table_path, &scale, &remote_table_name)
+    })
+}
+
+/// Registers every base table `sql` references, skipping CTE and derived-table aliases.
+pub async fn register_tables_for_sql(
+    ctx: &SessionContext,
+    storage: &StorageConfig,
+    resolve: &TableUrlResolver,
     sql: &str,
 ) -> BenchResult<()> {
     let table_names = referenced_table_names(sql)?;
     if table_names.is_empty() {
         return Err(BenchError::InvalidArgument(
-            "no table references found in TPC-DS SQL".to_string(),
+            "no table references found in SQL".to_string(),
         ));
     }
 
     for table_name in table_names {
-        register_table(ctx, fixtures_dir, scale, storage, &table_name).await?;
+        register_table(ctx, storage, &table_name, resolve(&table_name)?).await?;
     }
     Ok(())
 }
 
-async fn register_table(
+pub(crate) async fn register_table(
     ctx: &SessionContext,
-    fixtures_dir: &Path,
-    scale: &str,
     storage: &StorageConfig,
     table_name: &str,
+    table_url: Url,
 ) -> BenchResult<()> {
-    let local_table_path = fixtures_dir.join(scale).join(TPCDS_DIR).join(table_name);
-    let remote_table_name = format!("{TPCDS_DIR}/{table_name}");
-    let table_url = storage.table_url_for(&local_table_path, scale, &remote_table_name)?;
     let table = storage.open_table(table_url).await?;
     let provider = table.table_provider().await?;
     ctx.register_table(table_name, provider)?;
@@ -53,9 +108,7 @@ fn referenced_table_names(sql: &str) -> BenchResult<Vec<String>> {
     let mut names = BTreeSet::new();
     let dialect = GenericDialect {};
     let statements = Parser::parse_sql(&dialect, sql).map_err(|err| {
-        BenchError::InvalidArgument(format!(
-            "failed to parse TPC-DS SQL for table registration: {err}"
-        ))
+        BenchError::InvalidArgument(format!("failed to parse SQL for table registration: {err}"))
     })?;
     let mut cte_scopes = Vec::<HashSet<String>>::new();
 
@@ -177,7 +230,7 @@ fn is_cte_alias(name: &str, cte_scopes: &[HashSet<String>]) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::referenced_table_names;
+    use super::{referenced_table_names, TpcdsLayout};
 
     #[test]
     fn extracts_unique_sorted_tables_from_from_and_join_clauses() {
@@ -256,4 +309,19 @@ mod tests {
             vec!["date_dim".to_string(), "store_sales".to_string()]
         );
     }
+
+    #[test]
+    fn date_partitioned_layout_only_redirects_the_fact_table() {
+        let layout = TpcdsLayout::parse("date_partitioned").expect("parse layout");
+        assert_eq!(
+            layout.table_dir("store_sales"),
+            "store_sales_date_partitioned"
+        );
+        assert_eq!(layout.table_dir("date_dim"), "date_dim");
+        assert_eq!(
+            TpcdsLayout::Unpartitioned.table_dir("store_sales"),
+            "store_sales"
+        );
+        assert!(TpcdsLayout::parse("hive").is_err());
+    }
 }
@@ -0,0 +1,223 @@
+use std::path::Path;
+
+use deltalake_core::arrow::record_batch::RecordBatch;
+use deltalake_core::protocol::SaveMode;
+use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
+use serde_json::json;
+use url::Url;
+
+use super::{fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
+use crate::cli::TimingPhase;
+use crate::data::fixtures::{
+    fixture_wide_table_columns, wide_table_batch, wide_table_column_name, wide_table_path,
+    wide_table_url,
+};
+use crate::data::scales::scale_definition;
+use crate::error::{BenchError, BenchResult};
+use crate::fingerprint::hash_json;
+use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
+use crate::runner::{run_case_async_with_async_setup, run_case_async_with_timing_phase, CaseHooks};
+use crate::storage::StorageConfig;
+use crate::suites::scan::run_shape_hashed_query;
+use crate::version_compat::optional_table_version_to_u64;
+
+/// Rows written to the append target before the timed append, so the commit lands on an
+/// existing wide schema.
+const WIDE_APPEND_SEED_ROWS: usize = 1_024;
+const WIDE_APPEND_ROWS: usize = 4_096;
+/// Cases whose rows and columns follow the fixture's column count. The append case writes its
+/// own table, so its result does not.
+pub(crate) const COLUMN_SHAPED_CASES: [&str; 2] =
+    ["wide_table_full_scan", "wide_table_projection_3col"];
+
+pub fn case_names() -> Vec<String> {
+    vec![
+        "wide_table_full_scan".to_string(),
+        "wide_table_projection_3col".to_string(),
+        "wide_table_append".to_string(),
+    ]
+}
+
+pub struct WideTableSuite;
+
+impl Suite for WideTableSuite {
+    fn name(&self) -> &'static str {
+        "wide_table"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
+struct WideAppendSetup {
+    _temp: tempfile::TempDir,
+    table: DeltaTable,
+}
+
+pub async fn run(
+    fixtures_dir: &Path,
+    scale: &str,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<Vec<CaseResult>> {
+    if storage.is_local()
+        && !wide_table_path(fixtures_dir, scale)
+            .join("_delta_log")
+            .exists()
+    {
+        return Ok(fixture_error_cases(
+            case_names(),
+            "missing wide table fixture; run bench data first",
+        ));
+    }
+    let columns = match fixture_wide_table_columns(fixtures_dir, scale) {
+        Ok(columns) => columns,
+        Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
+    };
+
+    let table_url = wide_table_url(fixtures_dir, scale, storage)?;
+    let scan_cases = [
+        ("wide_table_full_scan", "SELECT * FROM bench".to_string()),
+        (
+            "wide_table_projection_3col",
+            projection_sql(&[1, columns / 2, columns - 1]),
+        ),
+    ];
+    let mut out = Vec::new();
+    for (name, sql) in scan_cases {
+        let c = run_case_async_with_timing_phase(
+            name,
+            warmup,
+            iterations,
+            hooks,
+            TimingPhase::Execute,
+            || {
+                let storage = storage.clone();
+                let table_url = table_url.clone();
+                let sql = sql.clone();
+                async move {
+                    run_shape_hashed_query(&storage, table_url, &sql, name)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    }
+
+    if storage.is_local() {
+        let rows = scale_definition(scale)?.wide_table_rows();
+        let append_batch = wide_table_batch(rows..rows + WIDE_APPEND_ROWS, columns)?;
+        let c = run_case_async_with_async_setup(
+            "wide_table_append",
+            warmup,
+            iterations,
+            hooks,
+            || async move {
+                prepare_append_target(columns)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+            |setup| {
+                let batch = append_batch.clone();
+                async move {
+                    run_wide_append(setup, batch)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            },
+        )
+        .await;
+        out.push(into_case_result(c));
+    } else {
+        out.extend(fixture_error_cases(
+            vec!["wide_table_append".to_string()],
+            "wide_table_append does not support non-local storage backend yet",
+        ));
+    }
+
+    Ok(out)
+}
+
+fn projection_sql(column_indexes: &[usize]) -> String {
+    let columns = column_indexes
+        .iter()
+        .map(|idx| wide_table_column_name(*idx))
+        .collect::<Vec<_>>();
+    format!("SELECT {} FROM bench", columns.join(", "))
+}
+
+async fn prepare_append_target(columns: usize) -> BenchResult<WideAppendSetup> {
+    let temp = tempfile::tempdir()?;
+    let table_url = Url::from_directory_path(temp.path()).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create URL for {}",
+            temp.path().display()
+        ))
+    })?;
+    let table = DeltaTable::try_from_url(table_url)
+        .await?
+        .write(vec![wide_table_batch(0..WIDE_APPEND_SEED_ROWS, columns)?])
+        .with_save_mode(SaveMode::Overwrite)
+        .await?;
+    Ok(WideAppendSetup { _temp: temp, table })
+}
+
+async fn run_wide_append(setup: WideAppendSetup, batch: RecordBatch) -> BenchResult<SampleMetrics> {
+    let _keep_temp = setup._temp;
+    let rows = batch.num_rows() as u64;
+    let table = setup
+        .table
+        .write(vec![batch])
+        .with_save_mode(SaveMode::Append)
+        .await?;
+
+    let table_version = optional_table_version_to_u64(table.version())?;
+    let result_hash = hash_json(&json!({
+        "rows_processed": rows,
+        "operations": 1_u64,
+        "table_version": table_version,
+    }))?;
+    let schema_hash = hash_json(&json!([
+        "rows_processed:u64",
+        "operations:u64",
+        "table_version:u64",
+    ]))?;
+
+    Ok(
+        SampleMetrics::base(Some(rows), None, Some(1), table_version).with_runtime_io(
+            RuntimeIOMetrics {
+                peak_rss_mb: None,
+                cpu_time_ms: None,
+                bytes_read: None,
+                bytes_written: None,
+                files_touched: None,
+                files_skipped: None,
+                spill_bytes: None,
+                result_hash: Some(result_hash),
+                schema_hash: Some(schema_hash),
+                semantic_state_digest: None,
+                validation_summary: None,
+            },
+        ),
+    )
+}
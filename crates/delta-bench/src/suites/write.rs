@@ -3,20 +3,26 @@ use std::sync::Arc;
 
 use deltalake_core::protocol::SaveMode;
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 use serde_json::json;
 use url::Url;
 
-use super::{fixture_error_cases, into_case_result};
+use super::{fixture_error_cases, into_case_result, Suite, SuiteRunArgs};
 use crate::cli::BenchmarkLane;
-use crate::data::fixtures::{load_rows, rows_to_batch};
+use crate::data::fixtures::rows_to_batch;
+use crate::data::row_cache::FixtureRowCache;
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
 use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
-use crate::runner::run_case_async_with_async_setup;
+use crate::runner::{run_case_async_with_async_setup, CaseHooks};
 use crate::storage::StorageConfig;
+use crate::trace::TraceOperation;
 use crate::validation::{lane_requires_semantic_validation, validate_table_state};
 use crate::version_compat::optional_table_version_to_u64;
 
+const WRITE_APPEND_SMALL_CHUNK_ROWS: usize = 128;
+const WRITE_APPEND_LARGE_CHUNK_ROWS: usize = 4096;
+
 pub fn case_names() -> Vec<String> {
     vec![
         "write_append_small".to_string(),
@@ -25,18 +31,69 @@ pub fn case_names() -> Vec<String> {
     ]
 }
 
+pub struct WriteSuite;
+
+impl Suite for WriteSuite {
+    fn name(&self) -> &'static str {
+        "write"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.requested_lane,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+            args.fixture_rows,
+        ))
+    }
+}
+
+/// Append cases as replay traces: one append per chunk the case commits. `write_overwrite` has
+/// no trace equivalent.
+pub(crate) fn trace_operations(rows: usize) -> Vec<(&'static str, Vec<TraceOperation>)> {
+    [
+        ("write_append_small", WRITE_APPEND_SMALL_CHUNK_ROWS),
+        ("write_append_large", WRITE_APPEND_LARGE_CHUNK_ROWS),
+    ]
+    .into_iter()
+    .map(|(case, chunk)| {
+        let operations = (0..rows)
+            .step_by(chunk)
+            .map(|start| TraceOperation::Append {
+                rows: chunk.min(rows - start),
+            })
+            .collect();
+        (case, operations)
+    })
+    .collect()
+}
+
 struct WriteIterationSetup {
     _temp: tempfile::TempDir,
     table: DeltaTable,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     fixtures_dir: &Path,
     scale: &str,
     lane: BenchmarkLane,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
+    fixture_rows: &FixtureRowCache,
 ) -> BenchResult<Vec<CaseResult>> {
     if !storage.is_local() {
         return Ok(fixture_error_cases(
@@ -45,8 +102,8 @@ pub async fn run(
         ));
     }
 
-    let rows = match load_rows(fixtures_dir, scale) {
-        Ok(rows) => Arc::new(rows),
+    let rows = match fixture_rows.load(fixtures_dir, scale) {
+        Ok(rows) => rows,
         Err(e) => return Ok(fixture_error_cases(case_names(), &e.to_string())),
     };
     let mut results = Vec::new();
@@ -55,11 +112,12 @@ pub async fn run(
         "write_append_small",
         warmup,
         iterations,
+        hooks,
         || async { prepare_write_iteration().await.map_err(|e| e.to_string()) },
         |setup| {
             let rows = Arc::clone(&rows);
             async move {
-                run_append_case(setup, rows.as_slice(), 128, lane)
+                run_append_case(setup, rows.as_slice(), WRITE_APPEND_SMALL_CHUNK_ROWS, lane)
                     .await
                     .map_err(|e| e.to_string())
             }
@@ -72,11 +130,12 @@ pub async fn run(
         "write_append_large",
         warmup,
         iterations,
+        hooks,
         || async { prepare_write_iteration().await.map_err(|e| e.to_string()) },
         |setup| {
             let rows = Arc::clone(&rows);
             async move {
-                run_append_case(setup, rows.as_slice(), 4096, lane)
+                run_append_case(setup, rows.as_slice(), WRITE_APPEND_LARGE_CHUNK_ROWS, lane)
                     .await
                     .map_err(|e| e.to_string())
             }
@@ -89,6 +148,7 @@ pub async fn run(
         "write_overwrite",
         warmup,
         iterations,
+        hooks,
         || async { prepare_write_iteration().await.map_err(|e| e.to_string()) },
         |setup| {
             let rows = Arc::clone(&rows);
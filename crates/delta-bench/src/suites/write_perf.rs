@@ -1,21 +1,27 @@
 use std::cmp::min;
+use std::fs;
 use std::path::Path;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use deltalake_core::arrow::array::{Array, BooleanArray, Int32Array, Int64Array};
 use deltalake_core::arrow::datatypes::{DataType, Field, Schema};
 use deltalake_core::arrow::record_batch::RecordBatch;
-use deltalake_core::protocol::SaveMode;
+use deltalake_core::kernel::transaction::CommitBuilder;
+use deltalake_core::kernel::{Action, Add};
+use deltalake_core::parquet::arrow::ArrowWriter;
+use deltalake_core::protocol::{DeltaOperation, SaveMode};
 use deltalake_core::DeltaTable;
+use futures::future::LocalBoxFuture;
 use serde_json::json;
 use url::Url;
 
 use super::into_case_result;
+use super::{Suite, SuiteRunArgs};
 use crate::error::{BenchError, BenchResult};
 use crate::fingerprint::hash_json;
 use crate::results::{CaseResult, RuntimeIOMetrics, SampleMetrics};
-use crate::runner::run_case_async_with_async_setup;
+use crate::runner::{run_case_async_with_async_setup, unsupported_case_result, CaseHooks};
 use crate::storage::StorageConfig;
 use crate::version_compat::optional_table_version_to_u64;
 
@@ -30,28 +36,42 @@ struct WritePerfCaseSpec {
     id: &'static str,
     rows: usize,
     partition_count: Option<usize>,
+    /// Stage the data files and their add actions in setup and time only the commit.
+    commit_only: bool,
 }
 
-const WRITE_PERF_CASES: [WritePerfCaseSpec; 4] = [
+const WRITE_PERF_CASES: [WritePerfCaseSpec; 5] = [
+    // One small file per partition, written in setup, so the timed commit carries 4,096 add
+    // actions and measures only commit serialization plus the log write.
+    WritePerfCaseSpec {
+        id: "write_perf_commit_4096_adds",
+        rows: 409_600,
+        partition_count: Some(4_096),
+        commit_only: true,
+    },
     WritePerfCaseSpec {
         id: "write_perf_partitioned_1m_parts_010",
         rows: 1_000_000,
         partition_count: Some(10),
+        commit_only: false,
     },
     WritePerfCaseSpec {
         id: "write_perf_partitioned_1m_parts_100",
         rows: 1_000_000,
         partition_count: Some(100),
+        commit_only: false,
     },
     WritePerfCaseSpec {
         id: "write_perf_partitioned_5m_parts_010",
         rows: 5_000_000,
         partition_count: Some(10),
+        commit_only: false,
     },
     WritePerfCaseSpec {
         id: "write_perf_unpartitioned_1m",
         rows: 1_000_000,
         partition_count: None,
+        commit_only: false,
     },
 ];
 
@@ -62,6 +82,32 @@ pub fn case_names() -> Vec<String> {
         .collect()
 }
 
+pub struct WritePerfSuite;
+
+impl Suite for WritePerfSuite {
+    fn name(&self) -> &'static str {
+        "write_perf"
+    }
+
+    fn case_names(&self) -> BenchResult<Vec<String>> {
+        Ok(case_names())
+    }
+
+    fn run<'a>(
+        &'a self,
+        args: SuiteRunArgs<'a>,
+    ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+        Box::pin(run(
+            args.fixtures_dir,
+            args.scale,
+            args.warmup,
+            args.iterations,
+            args.hooks,
+            args.storage,
+        ))
+    }
+}
+
 struct WritePerfIterationSetup {
     _temp: Option<tempfile::TempDir>,
     table: DeltaTable,
@@ -69,20 +115,34 @@ struct WritePerfIterationSetup {
     spec: WritePerfCaseSpec,
 }
 
+struct CommitIterationSetup {
+    _temp: tempfile::TempDir,
+    table: DeltaTable,
+    adds: Vec<Add>,
+    input_batches: usize,
+    spec: WritePerfCaseSpec,
+}
+
 pub async fn run(
     _fixtures_dir: &Path,
     scale: &str,
     warmup: u32,
     iterations: u32,
+    hooks: &CaseHooks,
     storage: &StorageConfig,
 ) -> BenchResult<Vec<CaseResult>> {
     let mut results = Vec::with_capacity(WRITE_PERF_CASES.len());
     for spec in WRITE_PERF_CASES {
+        if spec.commit_only {
+            results.push(run_commit_case(spec, warmup, iterations, hooks, storage).await?);
+            continue;
+        }
         let batches = Arc::new(generate_write_perf_batches(spec)?);
         let case = run_case_async_with_async_setup(
             spec.id,
             warmup,
             iterations,
+            hooks,
             || {
                 let batches = Arc::clone(&batches);
                 let storage = storage.clone();
@@ -102,6 +162,130 @@ pub async fn run(
     Ok(results)
 }
 
+async fn run_commit_case(
+    spec: WritePerfCaseSpec,
+    warmup: u32,
+    iterations: u32,
+    hooks: &CaseHooks,
+    storage: &StorageConfig,
+) -> BenchResult<CaseResult> {
+    if !storage.is_local() {
+        return Ok(unsupported_case_result(
+            spec.id,
+            Vec::new(),
+            format!(
+                "skipped: {} stages its data files on local disk; run it on the local backend",
+                spec.id
+            ),
+        ));
+    }
+    let input_batches = generate_write_perf_batches(spec)?.len();
+    let case = run_case_async_with_async_setup(
+        spec.id,
+        warmup,
+        iterations,
+        hooks,
+        || async move {
+            prepare_commit_iteration(spec, input_batches)
+                .await
+                .map_err(|e| e.to_string())
+        },
+        |setup| async move { run_commit_only_case(setup).await.map_err(|e| e.to_string()) },
+    )
+    .await;
+    Ok(into_case_result(case))
+}
+
+/// Creates the table empty and writes one data file per partition next to it, returning the
+/// add actions a write of the same rows would commit.
+async fn prepare_commit_iteration(
+    spec: WritePerfCaseSpec,
+    input_batches: usize,
+) -> BenchResult<CommitIterationSetup> {
+    let partition_count = spec.partition_count.ok_or_else(|| {
+        BenchError::InvalidArgument(format!("{} needs a partition count", spec.id))
+    })?;
+    let temp = tempfile::tempdir()?;
+    let table_url = Url::from_directory_path(temp.path()).map_err(|()| {
+        BenchError::InvalidArgument(format!(
+            "failed to create URL for {}",
+            temp.path().display()
+        ))
+    })?;
+    let table = DeltaTable::try_from_url(table_url)
+        .await?
+        .write(vec![RecordBatch::new_empty(write_perf_schema(true))])
+        .with_save_mode(SaveMode::Overwrite)
+        .with_partition_columns([PARTITION_COLUMN_NAME])
+        .await?;
+
+    let modification_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|error| BenchError::InvalidArgument(error.to_string()))?
+        .as_millis() as i64;
+    let mut adds = Vec::with_capacity(partition_count);
+    for partition in 0..partition_count {
+        let batch = generate_partition_file_batch(spec.rows, partition, partition_count)?;
+        let relative_path = format!("{PARTITION_COLUMN_NAME}={partition}/part-00000.parquet");
+        let path = temp.path().join(&relative_path);
+        fs::create_dir_all(path.parent().unwrap_or(temp.path()))?;
+        let mut writer = ArrowWriter::try_new(fs::File::create(&path)?, batch.schema(), None)
+            .map_err(|error| BenchError::Delta(error.into()))?;
+        writer
+            .write(&batch)
+            .map_err(|error| BenchError::Delta(error.into()))?;
+        writer
+            .close()
+            .map_err(|error| BenchError::Delta(error.into()))?;
+        adds.push(Add {
+            path: relative_path,
+            partition_values: [(
+                PARTITION_COLUMN_NAME.to_string(),
+                Some(partition.to_string()),
+            )]
+            .into_iter()
+            .collect(),
+            size: fs::metadata(&path)?.len() as i64,
+            modification_time,
+            data_change: true,
+            stats: Some(partition_file_stats(&batch)?),
+            ..Default::default()
+        });
+    }
+    Ok(CommitIterationSetup {
+        _temp: temp,
+        table,
+        adds,
+        input_batches,
+        spec,
+    })
+}
+
+async fn run_commit_only_case(setup: CommitIterationSetup) -> BenchResult<SampleMetrics> {
+    let files_added = setup.adds.len() as u64;
+    let operation = DeltaOperation::Write {
+        mode: SaveMode::Append,
+        partition_by: Some(vec![PARTITION_COLUMN_NAME.to_string()]),
+        predicate: None,
+    };
+    let commit = CommitBuilder::default()
+        .with_actions(setup.adds.into_iter().map(Action::Add).collect())
+        .build(
+            Some(setup.table.snapshot()?),
+            setup.table.log_store(),
+            operation,
+        )
+        .await?;
+
+    let table_version = Some(commit.version() as u64);
+    write_perf_metrics(
+        setup.spec,
+        table_version,
+        files_added,
+        setup.input_batches as u64,
+    )
+}
+
 async fn prepare_write_perf_iteration(
     spec: WritePerfCaseSpec,
     batches: Arc<Vec<RecordBatch>>,
@@ -141,13 +325,28 @@ async fn run_write_perf_case(setup: WritePerfIterationSetup) -> BenchResult<Samp
     let table = builder.await?;
 
     let table_version = optional_table_version_to_u64(table.version())?;
+    let files_added = table.snapshot()?.log_data().num_files() as u64;
+    write_perf_metrics(
+        setup.spec,
+        table_version,
+        files_added,
+        setup.batches.len() as u64,
+    )
+}
+
+fn write_perf_metrics(
+    spec: WritePerfCaseSpec,
+    table_version: Option<u64>,
+    files_added: u64,
+    input_batches: u64,
+) -> BenchResult<SampleMetrics> {
     let result_hash = hash_json(&json!({
-        "rows_processed": setup.spec.rows as u64,
+        "rows_processed": spec.rows as u64,
         "operations": 1_u64,
         "table_version": table_version,
-        "partitioned": setup.spec.partition_count.is_some(),
-        "partition_count": setup.spec.partition_count.unwrap_or_default() as u64,
-        "input_batches": setup.batches.len() as u64,
+        "partitioned": spec.partition_count.is_some(),
+        "partition_count": spec.partition_count.unwrap_or_default() as u64,
+        "input_batches": input_batches,
     }))?;
     let schema_hash = hash_json(&json!([
         "rows_processed:u64",
@@ -159,20 +358,21 @@ async fn run_write_perf_case(setup: WritePerfIterationSetup) -> BenchResult<Samp
     ]))?;
 
     Ok(
-        SampleMetrics::base(Some(setup.spec.rows as u64), None, Some(1), table_version)
-            .with_runtime_io(RuntimeIOMetrics {
+        SampleMetrics::base(Some(spec.rows as u64), None, Some(1), table_version).with_runtime_io(
+            RuntimeIOMetrics {
                 peak_rss_mb: None,
                 cpu_time_ms: None,
                 bytes_read: None,
                 bytes_written: None,
-                files_touched: None,
+                files_touched: Some(files_added),
                 files_skipped: None,
                 spill_bytes: None,
                 result_hash: Some(result_hash),
                 schema_hash: Some(schema_hash),
                 semantic_state_digest: None,
                 validation_summary: None,
-            }),
+            },
+        ),
     )
 }
 
@@ -253,7 +453,7 @@ fn generate_write_perf_batch(
     for idx in 0..rows {
         let absolute = row_offset + idx;
         ids.push(absolute as i64);
-        values.push(((absolute as i64 * 13) % 100_000) - 50_000);
+        values.push(write_perf_value(absolute));
         flags.push(absolute % 2 == 0);
         if let Some(partitions) = partitions.as_mut() {
             let partition_total = partition_count.expect("partition count");
@@ -273,16 +473,76 @@ fn generate_write_perf_batch(
     RecordBatch::try_new(schema, columns).map_err(Into::into)
 }
 
+fn write_perf_value(absolute: usize) -> i64 {
+    ((absolute as i64 * 13) % 100_000) - 50_000
+}
+
+/// The rows of one partition as written to its data file, which leaves the partition column
+/// out. They are the rows [`generate_write_perf_batches`] puts in that partition.
+fn generate_partition_file_batch(
+    rows: usize,
+    partition: usize,
+    partition_count: usize,
+) -> BenchResult<RecordBatch> {
+    let absolute = (partition..rows)
+        .step_by(partition_count)
+        .collect::<Vec<_>>();
+    RecordBatch::try_new(
+        write_perf_schema(false),
+        vec![
+            Arc::new(Int64Array::from_iter_values(
+                absolute.iter().map(|row| *row as i64),
+            )),
+            Arc::new(Int64Array::from_iter_values(
+                absolute.iter().map(|row| write_perf_value(*row)),
+            )),
+            Arc::new(BooleanArray::from_iter(
+                absolute.iter().map(|row| Some(row % 2 == 0)),
+            )),
+        ],
+    )
+    .map_err(Into::into)
+}
+
+/// File statistics in the shape delta-rs writes them, so the commit carries add actions of a
+/// realistic size.
+fn partition_file_stats(batch: &RecordBatch) -> BenchResult<String> {
+    let column = |name: &str| {
+        batch
+            .column_by_name(name)
+            .and_then(|column| column.as_any().downcast_ref::<Int64Array>())
+            .ok_or_else(|| BenchError::InvalidArgument(format!("missing {name} column")))
+    };
+    let (ids, values) = (column("id")?, column("value_i64")?);
+    let bounds = |array: &Int64Array| {
+        (
+            array.values().iter().min().copied(),
+            array.values().iter().max().copied(),
+        )
+    };
+    let ((min_id, max_id), (min_value, max_value)) = (bounds(ids), bounds(values));
+    Ok(json!({
+        "numRecords": batch.num_rows(),
+        "minValues": {"id": min_id, "value_i64": min_value},
+        "maxValues": {"id": max_id, "value_i64": max_value},
+        "nullCount": {"id": 0, "value_i64": 0, "flag": 0},
+    })
+    .to_string())
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
     use std::ffi::OsString;
     use std::sync::{Mutex, OnceLock};
     use std::time::Duration;
 
+    use deltalake_core::arrow::array::Int32Array;
+
     use super::{
-        generate_write_perf_batches, parse_validation_delay, WritePerfCaseSpec,
-        WRITE_PERF_ALLOW_DELAY_ENV, WRITE_PERF_BATCH_ROWS, WRITE_PERF_DELAY_ENV,
-        WRITE_PERF_VALIDATION_CANARY_CASE_ID,
+        generate_partition_file_batch, generate_write_perf_batches, parse_validation_delay,
+        partition_file_stats, WritePerfCaseSpec, WRITE_PERF_ALLOW_DELAY_ENV, WRITE_PERF_BATCH_ROWS,
+        WRITE_PERF_CASES, WRITE_PERF_DELAY_ENV, WRITE_PERF_VALIDATION_CANARY_CASE_ID,
     };
 
     struct EnvRestoreGuard {
@@ -328,6 +588,7 @@ mod tests {
             id: "test",
             rows: 10,
             partition_count: Some(3),
+            commit_only: false,
         };
         let batches = generate_write_perf_batches(spec).expect("generate batches");
         let schema = batches[0].schema();
@@ -340,6 +601,7 @@ mod tests {
             id: "test",
             rows: 10,
             partition_count: None,
+            commit_only: false,
         };
         let batches = generate_write_perf_batches(spec).expect("generate batches");
         let schema = batches[0].schema();
@@ -352,6 +614,7 @@ mod tests {
             id: "test",
             rows: WRITE_PERF_BATCH_ROWS + 1,
             partition_count: Some(10),
+            commit_only: false,
         };
         let batches = generate_write_perf_batches(spec).expect("generate batches");
         assert_eq!(batches.len(), 2);
@@ -359,6 +622,40 @@ mod tests {
         assert_eq!(batches[1].num_rows(), 1);
     }
 
+    #[test]
+    fn large_commit_case_spreads_rows_across_every_partition() {
+        let spec = WRITE_PERF_CASES
+            .into_iter()
+            .find(|case| case.id == "write_perf_commit_4096_adds")
+            .expect("large commit case");
+        let batches = generate_write_perf_batches(spec).expect("generate batches");
+        let partitions = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column_by_name("part")
+                    .and_then(|column| column.as_any().downcast_ref::<Int32Array>())
+                    .expect("part column")
+                    .values()
+                    .to_vec()
+            })
+            .collect::<HashSet<_>>();
+        assert_eq!(partitions.len(), 4_096);
+    }
+
+    #[test]
+    fn partition_files_hold_the_rows_the_write_puts_in_each_partition() {
+        let batch = generate_partition_file_batch(409_600, 7, 4_096).expect("partition file");
+        assert_eq!(batch.num_rows(), 100);
+        assert!(batch.schema().field_with_name("part").is_err());
+
+        let stats: serde_json::Value =
+            serde_json::from_str(&partition_file_stats(&batch).expect("stats")).expect("json");
+        assert_eq!(stats["numRecords"], 100);
+        assert_eq!(stats["minValues"]["id"], 7);
+        assert_eq!(stats["maxValues"]["id"], 7 + 99 * 4_096);
+    }
+
     #[test]
     fn write_perf_delay_requires_explicit_validation_opt_in() {
         let _env_guard = env_mutex().lock().expect("env mutex");
@@ -1,9 +1,13 @@
+use deltalake_core::datafusion::prelude::SessionConfig;
 use serde_json::Value;
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::manifests::benchmark_repo_root;
+
 pub fn host_name() -> String {
     if let Ok(output) = Command::new("uname").arg("-n").output() {
         let v = String::from_utf8_lossy(&output.stdout).trim().to_string();
@@ -365,3 +369,122 @@ pub fn delta_rs_checkout_info(path_override: Option<&Path>) -> DeltaRsCheckoutIn
         core_present,
     }
 }
+
+/// `--git-sha` / `--harness-revision` value that asks for the revision to be detected.
+pub const GIT_SHA_AUTO: &str = "auto";
+/// Harness checkout to read `HEAD` from when the harness runs inside a delta-rs checkout.
+pub const HARNESS_ROOT_ENV: &str = "DELTA_BENCH_HARNESS_ROOT";
+/// The crate manifest as built, so the pinned delta-rs rev survives moving the binary.
+const CRATE_MANIFEST: &str = include_str!("../Cargo.toml");
+
+/// A checkout's `HEAD` and whether tracked files differ from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRevision {
+    pub sha: String,
+    pub dirty: bool,
+}
+
+/// Reads `HEAD` of the git checkout at `dir`. Untracked files do not make it dirty, so the
+/// harness overlaid into a delta-rs checkout leaves that checkout clean.
+pub fn git_revision(dir: &Path) -> Option<GitRevision> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let sha = git(&["rev-parse", "HEAD"]).filter(|sha| !sha.is_empty())?;
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+    Some(GitRevision { sha, dirty })
+}
+
+/// Revision of the delta-rs sources the harness was built against: the enclosing checkout when
+/// the harness is overlaid into delta-rs, otherwise the `deltalake-core` rev pinned in the crate
+/// manifest, which cannot be dirty.
+pub fn detect_delta_rs_revision() -> Option<GitRevision> {
+    let workspace = benchmark_repo_root();
+    if workspace.join("crates/core").exists() {
+        return git_revision(&workspace);
+    }
+    pinned_deltalake_core_rev(CRATE_MANIFEST).map(|sha| GitRevision { sha, dirty: false })
+}
+
+/// Revision of the harness repository: [`HARNESS_ROOT_ENV`] when set, otherwise the workspace
+/// itself unless it is a delta-rs checkout.
+pub fn detect_harness_revision() -> Option<GitRevision> {
+    match std::env::var(HARNESS_ROOT_ENV) {
+        Ok(root) if !root.trim().is_empty() => git_revision(Path::new(root.trim())),
+        _ => {
+            let workspace = benchmark_repo_root();
+            if workspace.join("crates/core").exists() {
+                None
+            } else {
+                git_revision(&workspace)
+            }
+        }
+    }
+}
+
+/// Resolves a revision flag into the recorded sha and dirty flag. A missing, empty, or
+/// [`GIT_SHA_AUTO`] value runs `detect`; an explicit sha is recorded as given, with an unknown
+/// dirty state.
+pub fn resolve_revision(
+    requested: Option<&str>,
+    detect: impl FnOnce() -> Option<GitRevision>,
+) -> (Option<String>, Option<bool>) {
+    match requested.map(str::trim) {
+        Some(sha) if !sha.is_empty() && sha != GIT_SHA_AUTO => (Some(sha.to_string()), None),
+        _ => match detect() {
+            Some(revision) => (Some(revision.sha), Some(revision.dirty)),
+            None => (None, None),
+        },
+    }
+}
+
+pub fn pinned_deltalake_core_rev(cargo_toml: &str) -> Option<String> {
+    let line = cargo_toml
+        .lines()
+        .find(|line| line.trim_start().starts_with("deltalake-core"))?;
+    let (_, rest) = line.split_once("rev = \"")?;
+    let (rev, _) = rest.split_once('"')?;
+    Some(rev.to_string())
+}
+
+/// Session options that shape how DataFusion plans and executes a scan or query, recorded so a
+/// read-path shift can be traced to a DataFusion default changing between versions.
+pub const DATAFUSION_CONFIG_KEYS: [&str; 11] = [
+    "datafusion.execution.target_partitions",
+    "datafusion.execution.batch_size",
+    "datafusion.execution.collect_statistics",
+    "datafusion.execution.parquet.pruning",
+    "datafusion.execution.parquet.pushdown_filters",
+    "datafusion.execution.parquet.reorder_filters",
+    "datafusion.execution.parquet.enable_page_index",
+    "datafusion.optimizer.repartition_joins",
+    "datafusion.optimizer.repartition_aggregations",
+    "datafusion.optimizer.repartition_file_scans",
+    "datafusion.optimizer.prefer_hash_join",
+];
+
+/// The DataFusion release deltalake-core was built against.
+pub fn datafusion_version() -> &'static str {
+    deltalake_core::datafusion::DATAFUSION_VERSION
+}
+
+/// [`DATAFUSION_CONFIG_KEYS`] as the SQL-executing suites' `SessionContext::new()` resolves
+/// them on this host; `target_partitions` follows the core count.
+pub fn datafusion_session_config() -> BTreeMap<String, String> {
+    SessionConfig::new()
+        .options()
+        .entries()
+        .into_iter()
+        .filter(|entry| DATAFUSION_CONFIG_KEYS.contains(&entry.key.as_str()))
+        .map(|entry| (entry.key, entry.value.unwrap_or_default()))
+        .collect()
+}
@@ -0,0 +1,245 @@
+//! JSONL operation traces replayed by the `replay` suite.
+//!
+//! A trace is one [`TraceStep`] per line. Recorded production traces and traces generated from
+//! existing suite cases use the same schema, so both run through the same replay path and land
+//! in the same result format.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::fixtures::scale_to_row_count;
+use crate::error::{BenchError, BenchResult};
+use crate::suites::{delete_update, merge, optimize_vacuum, write};
+
+/// One trace line. `think_time_ms` is slept before the operation and never timed.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct TraceStep {
+    #[serde(flatten)]
+    pub operation: TraceOperation,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub think_time_ms: u64,
+}
+
+impl From<TraceOperation> for TraceStep {
+    fn from(operation: TraceOperation) -> Self {
+        Self {
+            operation,
+            think_time_ms: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum TraceOperation {
+    Append {
+        rows: usize,
+    },
+    /// Upserts `rows` source rows; `matched_fraction` of them reuse existing fixture ids.
+    Merge {
+        rows: usize,
+        #[serde(default)]
+        matched_fraction: f64,
+    },
+    Delete {
+        predicate: String,
+    },
+    Optimize {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_size: Option<u64>,
+    },
+}
+
+impl TraceOperation {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TraceOperation::Append { .. } => "append",
+            TraceOperation::Merge { .. } => "merge",
+            TraceOperation::Delete { .. } => "delete",
+            TraceOperation::Optimize { .. } => "optimize",
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            TraceOperation::Append { rows } | TraceOperation::Merge { rows, .. } if *rows == 0 => {
+                Err("rows must be greater than zero".to_string())
+            }
+            TraceOperation::Merge {
+                matched_fraction, ..
+            } if !(0.0..=1.0).contains(matched_fraction) => {
+                Err("matched_fraction must be within [0, 1]".to_string())
+            }
+            TraceOperation::Delete { predicate } if predicate.trim().is_empty() => {
+                Err("predicate must not be empty".to_string())
+            }
+            TraceOperation::Optimize {
+                target_size: Some(0),
+            } => Err("target_size must be greater than zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+pub fn parse_trace(contents: &str) -> BenchResult<Vec<TraceStep>> {
+    let mut steps = Vec::new();
+    for (idx, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let step = serde_json::from_str::<TraceStep>(line)
+            .map_err(|error| error.to_string())
+            .and_then(|step| step.operation.validate().map(|()| step))
+            .map_err(|error| {
+                BenchError::InvalidArgument(format!("invalid trace line {}: {error}", idx + 1))
+            })?;
+        steps.push(step);
+    }
+    if steps.is_empty() {
+        return Err(BenchError::InvalidArgument(
+            "trace contains no operations".to_string(),
+        ));
+    }
+    Ok(steps)
+}
+
+pub fn render_trace(steps: &[TraceStep]) -> BenchResult<String> {
+    let mut out = String::new();
+    for step in steps {
+        out.push_str(&serde_json::to_string(step)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Traces for every suite case whose workload the trace schema can express, in suite order.
+///
+/// Each trace reproduces the case's operation sequence and sizes for `scale`. Replay runs it
+/// against `narrow_sales_delta`, not the case's own fixture, so absolute timings are not
+/// comparable with the source case.
+pub fn suite_case_traces(scale: &str) -> BenchResult<Vec<(String, Vec<TraceStep>)>> {
+    let rows = scale_to_row_count(scale)?;
+    Ok(write::trace_operations(rows)
+        .into_iter()
+        .chain(delete_update::trace_operations())
+        .chain(merge::trace_operations(rows))
+        .chain(optimize_vacuum::trace_operations())
+        .map(|(case, operations)| {
+            (
+                case.to_string(),
+                operations.into_iter().map(TraceStep::from).collect(),
+            )
+        })
+        .collect())
+}
+
+/// Writes `<out_dir>/<case>.jsonl` for each generated trace whose case name contains
+/// `case_filter`, returning the written paths.
+pub fn write_suite_case_traces(
+    scale: &str,
+    case_filter: Option<&str>,
+    out_dir: &Path,
+) -> BenchResult<Vec<PathBuf>> {
+    let mut traces = suite_case_traces(scale)?;
+    if let Some(filter) = case_filter.map(str::trim).filter(|value| !value.is_empty()) {
+        traces.retain(|(case, _)| case.contains(filter));
+    }
+    if traces.is_empty() {
+        return Err(BenchError::InvalidArgument(format!(
+            "no traceable suite cases match filter '{}'",
+            case_filter.unwrap_or_default()
+        )));
+    }
+
+    std::fs::create_dir_all(out_dir)?;
+    let mut written = Vec::with_capacity(traces.len());
+    for (case, steps) in traces {
+        let path = out_dir.join(format!("{case}.jsonl"));
+        std::fs::write(&path, render_trace(&steps)?)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_trace, render_trace, suite_case_traces, TraceOperation, TraceStep};
+
+    #[test]
+    fn parses_operations_and_think_times() {
+        let trace = r#"
+            {"op":"append","rows":100,"think_time_ms":5}
+
+            {"op":"merge","rows":50,"matched_fraction":0.5}
+            {"op":"delete","predicate":"id % 20 = 0"}
+            {"op":"optimize"}
+        "#;
+        assert_eq!(
+            parse_trace(trace).expect("trace should parse"),
+            vec![
+                TraceStep {
+                    operation: TraceOperation::Append { rows: 100 },
+                    think_time_ms: 5,
+                },
+                TraceStep::from(TraceOperation::Merge {
+                    rows: 50,
+                    matched_fraction: 0.5,
+                }),
+                TraceStep::from(TraceOperation::Delete {
+                    predicate: "id % 20 = 0".to_string(),
+                }),
+                TraceStep::from(TraceOperation::Optimize { target_size: None }),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_lines_with_line_numbers() {
+        for (trace, needle) in [
+            ("{\"op\":\"vacuum\"}", "line 1"),
+            (
+                "{\"op\":\"append\",\"rows\":1}\n{\"op\":\"append\",\"rows\":0}",
+                "line 2",
+            ),
+            (
+                "{\"op\":\"merge\",\"rows\":10,\"matched_fraction\":1.5}",
+                "matched_fraction",
+            ),
+            ("{\"op\":\"delete\",\"predicate\":\" \"}", "predicate"),
+            ("\n\n", "no operations"),
+        ] {
+            let err = parse_trace(trace).expect_err("trace should be rejected");
+            assert!(err.to_string().contains(needle), "{err}");
+        }
+    }
+
+    #[test]
+    fn generated_suite_traces_round_trip_through_the_parser() {
+        let traces = suite_case_traces("sf1").expect("generate traces");
+        assert!(!traces.is_empty());
+        for (case, steps) in &traces {
+            let rendered = render_trace(steps).expect("render trace");
+            assert_eq!(&parse_trace(&rendered).expect(case), steps, "{case}");
+        }
+
+        let append_small = &traces
+            .iter()
+            .find(|(case, _)| case == "write_append_small")
+            .expect("write_append_small trace")
+            .1;
+        let appended = append_small
+            .iter()
+            .map(|step| match step.operation {
+                TraceOperation::Append { rows } => rows,
+                _ => panic!("write_append_small should only append"),
+            })
+            .sum::<usize>();
+        assert_eq!(appended, 10_000);
+    }
+}
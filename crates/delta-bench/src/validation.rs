@@ -3,7 +3,7 @@ use deltalake_core::DeltaTable;
 
 use crate::cli::BenchmarkLane;
 use crate::error::BenchResult;
-use crate::fingerprint::{hash_record_batch_schema, hash_record_batches_unordered};
+use crate::fingerprint::{hash_arrow_schema, hash_record_batches_unordered};
 use crate::version_compat::optional_table_version_to_u64;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -21,13 +21,15 @@ pub async fn validate_table_state(table: &DeltaTable) -> BenchResult<SemanticVal
     let ctx = SessionContext::new();
     ctx.register_table("bench", table.table_provider().await?)?;
     let df = ctx.sql("SELECT * FROM bench").await?;
+    // Hashed from the plan rather than the batches so a table the case emptied still has a
+    // schema to compare.
+    let schema_hash = hash_arrow_schema(df.schema().as_arrow())?;
     let batches = df.collect().await?;
     let row_count = batches
         .iter()
         .map(|batch| batch.num_rows() as u64)
         .sum::<u64>();
     let digest = hash_record_batches_unordered(&batches)?;
-    let schema_hash = hash_record_batch_schema(&batches)?;
     let summary = match optional_table_version_to_u64(table.version())? {
         Some(version) => format!("rows={row_count};table_version={version}"),
         None => format!("rows={row_count};table_version=unknown"),
@@ -38,3 +40,11 @@ pub async fn validate_table_state(table: &DeltaTable) -> BenchResult<SemanticVal
         summary,
     })
 }
+
+/// The post-run row count `validate_table_state` recorded in a sample's validation summary.
+pub fn validation_summary_rows(summary: &str) -> Option<u64> {
+    summary
+        .split(';')
+        .find_map(|field| field.strip_prefix("rows="))
+        .and_then(|rows| rows.parse().ok())
+}
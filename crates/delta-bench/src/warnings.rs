@@ -0,0 +1,214 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::data::fixtures::fixture_root;
+use crate::results::{
+    BenchContext, CaseResult, RunWarning, WARNING_KIND_ASSERTION_WARNED,
+    WARNING_KIND_DEGRADED_FIDELITY, WARNING_KIND_FIXTURE_REGENERATED,
+    WARNING_KIND_OUTLIERS_DETECTED, WARNING_KIND_PARTIAL_METRICS, WARNING_KIND_RETRIES_OCCURRED,
+};
+
+/// CPU steal above this share of the host's time means a noisy neighbour took measurable time
+/// away from the run.
+pub const DEGRADED_CPU_STEAL_PCT: f64 = 5.0;
+/// Fixtures written this recently are still settling in the page cache, so the first cases
+/// read them warmer than a run against long-lived fixtures would.
+pub const FRESH_FIXTURE_WINDOW: Duration = Duration::from_secs(10 * 60);
+/// Tukey fences, matching the compare tooling's sample distributions.
+const OUTLIER_IQR_FACTOR: f64 = 1.5;
+/// Quartiles of fewer samples are too coarse to call any of them an outlier.
+const MIN_OUTLIER_SAMPLES: usize = 4;
+
+/// Soft issues with a finished run, in a stable order: run-wide warnings first, then per-case
+/// warnings in case order.
+pub fn collect_run_warnings(
+    context: &BenchContext,
+    cases: &[CaseResult],
+    fixture_age: Option<Duration>,
+) -> Vec<RunWarning> {
+    let mut warnings = fidelity_warnings(context);
+    if let Some(age) = fixture_age.filter(|age| *age < FRESH_FIXTURE_WINDOW) {
+        warnings.push(RunWarning {
+            kind: WARNING_KIND_FIXTURE_REGENERATED.to_string(),
+            case: None,
+            message: format!(
+                "fixtures for scale '{}' were regenerated {}s before the run; early cases may read them from a warm page cache",
+                context.scale,
+                age.as_secs()
+            ),
+        });
+    }
+    for case in cases {
+        warnings.extend(retry_warning(case));
+        warnings.extend(outlier_warning(case));
+        warnings.extend(partial_metrics_warning(case));
+        warnings.extend(case.warnings.iter().map(|message| RunWarning {
+            kind: WARNING_KIND_ASSERTION_WARNED.to_string(),
+            case: Some(case.case.clone()),
+            message: message.clone(),
+        }));
+    }
+    warnings
+}
+
+/// How long ago the fixtures of `scale` were last written, from their manifest's mtime.
+pub fn fixture_age(fixtures_dir: &Path, scale: &str) -> Option<Duration> {
+    let modified = fs::metadata(fixture_root(fixtures_dir, scale).join("manifest.json"))
+        .and_then(|metadata| metadata.modified())
+        .ok()?;
+    modified.elapsed().ok()
+}
+
+pub fn render_run_warnings(warnings: &[RunWarning]) -> String {
+    let mut output = format!("warnings ({}):", warnings.len());
+    for warning in warnings {
+        output.push_str(&format!("\n  [{}] ", warning.kind));
+        if let Some(case) = warning.case.as_deref() {
+            output.push_str(&format!("{case}: "));
+        }
+        output.push_str(&warning.message);
+    }
+    output
+}
+
+fn fidelity_warnings(context: &BenchContext) -> Vec<RunWarning> {
+    let mut messages = Vec::new();
+    if let Some(steal) = context
+        .cpu_steal_pct
+        .filter(|steal| *steal > DEGRADED_CPU_STEAL_PCT)
+    {
+        messages.push(format!(
+            "cpu steal was {steal:.1}%, above {DEGRADED_CPU_STEAL_PCT:.1}%; timings include time the hypervisor gave to other guests"
+        ));
+    }
+    if context.git_dirty == Some(true) {
+        messages.push(format!(
+            "delta-rs checkout {} had uncommitted changes, so the run cannot be reproduced from its revision",
+            context.git_sha.as_deref().unwrap_or("unknown")
+        ));
+    }
+    if context.harness_dirty == Some(true) {
+        messages.push(format!(
+            "harness checkout {} had uncommitted changes, so the run cannot be reproduced from its revision",
+            context.harness_revision.as_deref().unwrap_or("unknown")
+        ));
+    }
+    messages
+        .into_iter()
+        .map(|message| RunWarning {
+            kind: WARNING_KIND_DEGRADED_FIDELITY.to_string(),
+            case: None,
+            message,
+        })
+        .collect()
+}
+
+fn retry_warning(case: &CaseResult) -> Option<RunWarning> {
+    let (retries, retry_ms) = case
+        .samples
+        .iter()
+        .filter_map(|sample| sample.metrics.as_ref()?.interop_attempts.as_ref())
+        .fold((0_u64, 0.0_f64), |(retries, retry_ms), attempts| {
+            (
+                retries + attempts.attempts.saturating_sub(1),
+                retry_ms + attempts.retry_ms,
+            )
+        });
+    (retries > 0).then(|| RunWarning {
+        kind: WARNING_KIND_RETRIES_OCCURRED.to_string(),
+        case: Some(case.case.clone()),
+        message: format!(
+            "{retries} attempt(s) failed and were retried, spending {retry_ms:.1} ms; see metrics.interop_attempts"
+        ),
+    })
+}
+
+fn outlier_warning(case: &CaseResult) -> Option<RunWarning> {
+    if !case.perf_status.is_trusted() || case.samples.len() < MIN_OUTLIER_SAMPLES {
+        return None;
+    }
+    let mut elapsed = case
+        .samples
+        .iter()
+        .map(|sample| sample.elapsed_ms)
+        .collect::<Vec<_>>();
+    elapsed.sort_by(f64::total_cmp);
+    let q1 = quantile(&elapsed, 0.25);
+    let q3 = quantile(&elapsed, 0.75);
+    let low = q1 - OUTLIER_IQR_FACTOR * (q3 - q1);
+    let high = q3 + OUTLIER_IQR_FACTOR * (q3 - q1);
+    let outliers = elapsed
+        .iter()
+        .filter(|value| **value < low || **value > high)
+        .map(|value| format!("{value:.3}"))
+        .collect::<Vec<_>>();
+    (!outliers.is_empty()).then(|| RunWarning {
+        kind: WARNING_KIND_OUTLIERS_DETECTED.to_string(),
+        case: Some(case.case.clone()),
+        message: format!(
+            "{} of {} samples fall outside the {OUTLIER_IQR_FACTOR} IQR fences [{low:.3}, {high:.3}] ms: {}",
+            outliers.len(),
+            elapsed.len(),
+            outliers.join(", ")
+        ),
+    })
+}
+
+/// Linear interpolation between closest ranks of ascending `sorted`, as Python's
+/// `statistics.quantiles(method="inclusive")` computes them.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// Metrics one sample reports but another of the same case lacks point at an extraction that
+/// only worked some of the time.
+fn partial_metrics_warning(case: &CaseResult) -> Option<RunWarning> {
+    if !case.success || case.samples.len() < 2 {
+        return None;
+    }
+    let reported = case
+        .samples
+        .iter()
+        .map(|sample| {
+            sample
+                .metrics
+                .as_ref()
+                .and_then(|metrics| serde_json::to_value(metrics).ok())
+                .and_then(|value| value.as_object().cloned())
+                .map(|fields| {
+                    fields
+                        .into_iter()
+                        .filter(|(_, value)| !value.is_null())
+                        .map(|(name, _)| name)
+                        .collect::<BTreeSet<_>>()
+                })
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let any = reported.iter().flatten().cloned().collect::<BTreeSet<_>>();
+    let partial = any
+        .into_iter()
+        .filter(|name| !reported.iter().all(|fields| fields.contains(name)))
+        .collect::<Vec<_>>();
+    if partial.is_empty() {
+        return None;
+    }
+    let lacking = reported
+        .iter()
+        .filter(|fields| partial.iter().any(|name| !fields.contains(name)))
+        .count();
+    Some(RunWarning {
+        kind: WARNING_KIND_PARTIAL_METRICS.to_string(),
+        case: Some(case.case.clone()),
+        message: format!(
+            "{lacking} of {} samples lack metrics reported by the others: {}",
+            reported.len(),
+            partial.join(", ")
+        ),
+    })
+}
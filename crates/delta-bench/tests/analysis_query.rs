@@ -0,0 +1,142 @@
+use std::path::Path;
+
+use delta_bench::analysis::{query, ResultsIndex};
+use deltalake_core::arrow::array::{Array, Float64Array, StringArray, UInt64Array};
+
+fn write_result(results_dir: &Path, label: &str, created_at: &str, elapsed_ms: [f64; 2]) {
+    let run_dir = results_dir.join(label);
+    std::fs::create_dir_all(&run_dir).expect("create run dir");
+    let result = format!(
+        r#"{{
+  "schema_version": 5,
+  "context": {{
+    "schema_version": 5,
+    "label": "{label}",
+    "git_sha": "abc123",
+    "created_at": "{created_at}",
+    "host": "bench-runner",
+    "suite": "scan",
+    "scale": "sf1",
+    "iterations": 2,
+    "warmup": 1
+  }},
+  "cases": [
+    {{
+      "case": "scan_full_narrow",
+      "success": true,
+      "validation_passed": true,
+      "perf_status": "trusted",
+      "classification": "supported",
+      "samples": [
+        {{"elapsed_ms": {first}, "rows": 10000, "bytes": null, "metrics": {{"files_scanned": 4}}}},
+        {{"elapsed_ms": {second}, "rows": 10000, "bytes": null, "metrics": null}}
+      ],
+      "elapsed_stats": {{
+        "min_ms": {first},
+        "max_ms": {second},
+        "mean_ms": {first},
+        "median_ms": {first},
+        "stddev_ms": 0.5
+      }},
+      "failure": null
+    }},
+    {{
+      "case": "scan_filter_flag",
+      "success": false,
+      "validation_passed": false,
+      "perf_status": "invalid",
+      "classification": "supported",
+      "samples": [],
+      "failure_kind": "execution_error",
+      "failure": {{"message": "filter failed"}}
+    }}
+  ]
+}}"#,
+        first = elapsed_ms[0],
+        second = elapsed_ms[1],
+    );
+    std::fs::write(run_dir.join("scan.json"), result).expect("write result");
+    std::fs::write(run_dir.join("scan.selectivity_curve.json"), "[]").expect("write sidecar");
+}
+
+#[tokio::test]
+async fn query_joins_runs_cases_and_samples() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_result(temp.path(), "base", "2026-02-27T10:00:00Z", [10.0, 11.0]);
+    write_result(temp.path(), "candidate", "2026-02-28T10:00:00Z", [8.0, 9.0]);
+
+    let index = ResultsIndex::load(temp.path()).expect("index results");
+    assert_eq!(index.entries.len(), 2, "sidecars are not indexed");
+
+    let result = query(
+        temp.path(),
+        "SELECT r.label, c.median_ms FROM cases c JOIN runs r USING (label, suite) \
+         WHERE c.case = 'scan_full_narrow' ORDER BY r.created_at DESC",
+    )
+    .await
+    .expect("query cases");
+    assert_eq!(result.num_rows(), 2);
+    let batch = &result.batches[0];
+    let labels = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("label column");
+    let medians = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .expect("median column");
+    assert_eq!(labels.value(0), "candidate");
+    assert_eq!(medians.value(0), 8.0);
+    assert_eq!(labels.value(1), "base");
+
+    let result = query(
+        temp.path(),
+        "SELECT COUNT(*) AS samples, SUM(files_scanned) AS files_scanned FROM samples",
+    )
+    .await
+    .expect("query samples");
+    let batch = &result.batches[0];
+    assert_eq!(batch.column(0).len(), 1);
+    let files_scanned = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .expect("files_scanned column");
+    assert_eq!(files_scanned.value(0), 8);
+    assert!(result
+        .to_table_string()
+        .expect("render table")
+        .contains("files_scanned"));
+
+    let out = temp.path().join("exports/failures.parquet");
+    let failures = query(
+        temp.path(),
+        "SELECT label, failure_message FROM cases WHERE NOT success",
+    )
+    .await
+    .expect("query failures");
+    assert_eq!(failures.num_rows(), 2);
+    failures.write_parquet(&out).expect("write parquet");
+    assert!(out.is_file());
+}
+
+#[tokio::test]
+async fn query_rejects_missing_dirs_and_unparseable_results() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let err = query(&temp.path().join("missing"), "SELECT 1")
+        .await
+        .expect_err("missing results dir");
+    assert!(err.to_string().contains("does not exist"), "{err}");
+
+    std::fs::create_dir_all(temp.path().join("broken")).expect("create run dir");
+    std::fs::write(temp.path().join("broken/scan.json"), "{}").expect("write result");
+    let err = query(temp.path(), "SELECT * FROM runs")
+        .await
+        .expect_err("unparseable result");
+    assert!(
+        err.to_string().contains("failed to parse run result"),
+        "{err}"
+    );
+}
@@ -1,6 +1,7 @@
-use delta_bench::assertions::{apply_case_assertions, CaseAssertion};
+use delta_bench::assertions::{apply_case_assertions, observe_case_assertions, CaseAssertion};
 use delta_bench::results::{
-    CaseFailure, CaseResult, IterationSample, PerfStatus, RuntimeIOMetrics, SampleMetrics,
+    CaseFailure, CaseResult, ElapsedStats, IterationSample, PerfStatus, RuntimeIOMetrics,
+    SampleMetrics,
 };
 
 fn sample_with_hashes(
@@ -59,6 +60,14 @@ fn case_result(
         required_runs: None,
         decision_threshold_pct: None,
         decision_metric: None,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
         failure_kind: None,
         failure,
     }
@@ -234,3 +243,265 @@ fn version_monotonicity_assertion_fails_on_decrease() {
         .unwrap_or("");
     assert!(message.contains("version monotonicity"));
 }
+
+fn timed_case(elapsed_ms: &[f64]) -> CaseResult {
+    let mut case = case_result(
+        true,
+        "supported",
+        elapsed_ms
+            .iter()
+            .map(|elapsed| IterationSample {
+                elapsed_ms: *elapsed,
+                ..sample_with_hashes(None, None, None)
+            })
+            .collect(),
+        None,
+    );
+    let mut sorted = elapsed_ms.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    case.elapsed_stats = Some(ElapsedStats {
+        min_ms: sorted[0],
+        max_ms: sorted[sorted.len() - 1],
+        mean_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        median_ms: sorted[sorted.len() / 2],
+        stddev_ms: 0.0,
+        cv_pct: None,
+    });
+    case
+}
+
+#[test]
+fn latency_budget_assertions_pass_within_budget() {
+    let mut case = timed_case(&[10.0, 11.0, 12.0, 13.0, 40.0]);
+
+    apply_case_assertions(
+        &mut case,
+        &[
+            CaseAssertion::MaxMedianMs(12.0),
+            CaseAssertion::MaxP95Ms(40.0),
+        ],
+    );
+
+    assert!(case.success, "{:?}", case.failure);
+    assert_eq!(case.perf_status, PerfStatus::Trusted);
+    assert!(case.elapsed_stats.is_some());
+}
+
+#[test]
+fn latency_budget_assertions_fail_over_budget() {
+    for (assertion, expected) in [
+        (
+            CaseAssertion::MaxMedianMs(11.5),
+            "max_median_ms assertion failed: median 12.000 ms exceeds the budget of 11.500 ms",
+        ),
+        (
+            CaseAssertion::MaxP95Ms(20.0),
+            "max_p95_ms assertion failed: p95 40.000 ms exceeds the budget of 20.000 ms",
+        ),
+    ] {
+        let mut case = timed_case(&[10.0, 11.0, 12.0, 13.0, 40.0]);
+
+        apply_case_assertions(&mut case, &[assertion]);
+
+        assert!(!case.success);
+        assert_eq!(case.perf_status, PerfStatus::Invalid);
+        assert_eq!(case.failure_kind.as_deref(), Some("assertion_mismatch"));
+        assert_eq!(
+            case.failure.as_ref().map(|f| f.message.as_str()),
+            Some(expected)
+        );
+    }
+}
+
+#[test]
+fn latency_budget_assertions_skip_failed_cases() {
+    let mut case = case_result(
+        false,
+        "supported",
+        Vec::new(),
+        Some(CaseFailure {
+            message: "scan failed".to_string(),
+        }),
+    );
+
+    apply_case_assertions(&mut case, &[CaseAssertion::MaxMedianMs(1.0)]);
+
+    assert_eq!(
+        case.failure.as_ref().map(|f| f.message.as_str()),
+        Some("scan failed")
+    );
+    assert_eq!(case.failure_kind, None);
+}
+
+fn scan_sample(files_scanned: Option<u64>, files_pruned: Option<u64>) -> IterationSample {
+    let mut metrics = SampleMetrics::base(Some(1), None, Some(1), None);
+    metrics.files_scanned = files_scanned;
+    metrics.files_pruned = files_pruned;
+    metrics.bytes_scanned = files_scanned.map(|files| files * 1_024);
+    IterationSample {
+        elapsed_ms: 1.0,
+        rows: Some(1),
+        bytes: None,
+        metrics: Some(metrics),
+    }
+}
+
+#[test]
+fn metric_bound_assertions_pass_when_every_sample_is_within_bounds() {
+    let mut case = case_result(
+        true,
+        "supported",
+        vec![scan_sample(Some(2), Some(6)), scan_sample(Some(3), Some(5))],
+        None,
+    );
+
+    apply_case_assertions(
+        &mut case,
+        &[
+            CaseAssertion::MinFilesPruned(5),
+            CaseAssertion::MaxFilesScanned(3),
+            CaseAssertion::MaxBytesScanned(3_072),
+        ],
+    );
+
+    assert!(case.success, "{:?}", case.failure);
+}
+
+#[test]
+fn metric_bound_assertions_fail_out_of_bounds_or_unreported_samples() {
+    for (assertion, expected) in [
+        (
+            CaseAssertion::MinFilesPruned(6),
+            "min_files_pruned assertion failed at sample 2: expected files_pruned >= 6, found 5",
+        ),
+        (
+            CaseAssertion::MaxFilesScanned(2),
+            "max_files_scanned assertion failed at sample 2: expected files_scanned <= 2, found 3",
+        ),
+        (
+            CaseAssertion::MaxBytesScanned(1_024),
+            "max_bytes_scanned assertion failed at sample 1: expected bytes_scanned <= 1024, found 2048",
+        ),
+    ] {
+        let mut case = case_result(
+            true,
+            "supported",
+            vec![
+                scan_sample(Some(2), Some(6)),
+                scan_sample(Some(3), Some(5)),
+            ],
+            None,
+        );
+
+        apply_case_assertions(&mut case, &[assertion]);
+
+        assert!(!case.success);
+        assert_eq!(case.failure_kind.as_deref(), Some("assertion_mismatch"));
+        assert_eq!(
+            case.failure.as_ref().map(|f| f.message.as_str()),
+            Some(expected)
+        );
+    }
+
+    let mut case = case_result(true, "supported", vec![scan_sample(None, None)], None);
+    apply_case_assertions(&mut case, &[CaseAssertion::MinFilesPruned(1)]);
+    assert_eq!(
+        case.failure.as_ref().map(|f| f.message.as_str()),
+        Some(
+            "min_files_pruned assertion failed at sample 1: expected files_pruned >= 1, found none"
+        )
+    );
+}
+
+fn dml_sample(rows_processed: u64, validation_summary: Option<&str>) -> IterationSample {
+    let mut metrics = SampleMetrics::base(Some(rows_processed), None, Some(1), Some(2));
+    metrics.validation_summary = validation_summary.map(ToOwned::to_owned);
+    IterationSample {
+        elapsed_ms: 1.0,
+        rows: Some(rows_processed),
+        bytes: None,
+        metrics: Some(metrics),
+    }
+}
+
+#[test]
+fn row_count_assertion_prefers_the_post_run_table_row_count() {
+    let mut case = case_result(
+        true,
+        "supported",
+        vec![dml_sample(50, Some("rows=950;table_version=2"))],
+        None,
+    );
+    apply_case_assertions(&mut case, &[CaseAssertion::RowCountEquals(950)]);
+    assert!(case.success, "{:?}", case.failure);
+
+    let mut case = case_result(
+        true,
+        "supported",
+        vec![
+            dml_sample(50, Some("rows=950;table_version=2")),
+            dml_sample(50, Some("rows=1000;table_version=2")),
+        ],
+        None,
+    );
+    apply_case_assertions(&mut case, &[CaseAssertion::RowCountEquals(950)]);
+    assert!(!case.success);
+    assert_eq!(case.failure_kind.as_deref(), Some("assertion_mismatch"));
+    assert_eq!(
+        case.failure.as_ref().map(|f| f.message.as_str()),
+        Some(
+            "row_count_equals assertion failed at sample 2: expected 950 rows in the post-run table, found 1000"
+        )
+    );
+}
+
+#[test]
+fn row_count_assertion_falls_back_to_rows_processed() {
+    let mut case = case_result(true, "supported", vec![dml_sample(50, None)], None);
+    apply_case_assertions(&mut case, &[CaseAssertion::RowCountEquals(50)]);
+    assert!(case.success, "{:?}", case.failure);
+
+    let mut case = case_result(true, "supported", vec![dml_sample(40, None)], None);
+    apply_case_assertions(&mut case, &[CaseAssertion::RowCountEquals(50)]);
+    assert_eq!(
+        case.failure.as_ref().map(|f| f.message.as_str()),
+        Some("row_count_equals assertion failed at sample 1: expected 50 rows processed, found 40")
+    );
+}
+
+#[test]
+fn observed_assertions_record_violations_as_warnings_without_failing() {
+    let mut case = case_result(true, "supported", vec![dml_sample(40, None)], None);
+    observe_case_assertions(
+        &mut case,
+        &[
+            CaseAssertion::RowCountEquals(40),
+            CaseAssertion::RowCountEquals(50),
+            CaseAssertion::MinFilesPruned(1),
+        ],
+    );
+
+    assert!(case.success);
+    assert!(case.validation_passed);
+    assert_eq!(case.perf_status, PerfStatus::Trusted);
+    assert!(case.failure.is_none());
+    assert_eq!(
+        case.warnings,
+        [
+            "row_count_equals assertion failed at sample 1: expected 50 rows processed, found 40",
+            "min_files_pruned assertion failed at sample 1: expected files_pruned >= 1, found none",
+        ]
+    );
+
+    let mut failed = case_result(
+        false,
+        "supported",
+        vec![dml_sample(40, None)],
+        Some(CaseFailure {
+            message: "boom".to_string(),
+        }),
+    );
+    observe_case_assertions(&mut failed, &[CaseAssertion::RowCountEquals(50)]);
+    assert!(failed.warnings.is_empty());
+    assert_eq!(failed.failure.map(|f| f.message).as_deref(), Some("boom"));
+}
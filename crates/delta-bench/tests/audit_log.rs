@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use delta_bench::cli::StorageBackend;
+use delta_bench::runner::{
+    redact_table_url, run_case, run_case_async, AuditLog, CaseExecutionResult, CaseHooks,
+    AUDIT_LOG_FILE_NAME,
+};
+use delta_bench::storage::StorageConfig;
+use serde_json::Value;
+use url::Url;
+
+fn read_records(path: &std::path::Path) -> Vec<Value> {
+    std::fs::read_to_string(path)
+        .expect("read audit log")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("audit record is json"))
+        .collect()
+}
+
+#[test]
+fn table_urls_are_redacted_of_credentials_and_tokens() {
+    let url =
+        Url::parse("s3://key:secret@bucket/root/sf1/narrow?X-Amz-Signature=abc#frag").expect("url");
+    assert_eq!(redact_table_url(&url), "s3://bucket/root/sf1/narrow");
+
+    let url = Url::parse("file:///tmp/fixtures/sf1/narrow/").expect("url");
+    assert_eq!(redact_table_url(&url), "file:///tmp/fixtures/sf1/narrow/");
+}
+
+#[tokio::test]
+async fn audited_cases_append_start_and_finish_records() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let path = temp.path().join(AUDIT_LOG_FILE_NAME);
+    std::fs::write(
+        &path,
+        "{\"event\":\"case_start\",\"case\":\"earlier_run\"}\n",
+    )
+    .expect("seed audit log");
+    let mut options = HashMap::new();
+    options.insert(
+        "table_root".to_string(),
+        "s3://bucket/root?X-Amz-Signature=abc".to_string(),
+    );
+    let log = Arc::new(AuditLog::open(&path).expect("open audit log"));
+    let storage = StorageConfig::new(StorageBackend::S3, options)
+        .expect("storage config")
+        .with_audit_log(Arc::clone(&log));
+    let hooks = CaseHooks::new().with_audit_log(log);
+
+    let ok = run_case_async("remote_case", 0, 1, &hooks, || async {
+        storage
+            .fixture_table_url("sf1", "narrow_sales")
+            .map(|_| 1_u64)
+            .map_err(|error| error.to_string())
+    })
+    .await;
+    assert!(matches!(ok, CaseExecutionResult::Success(_)));
+    let failed = run_case("failing_case", 0, 1, &hooks, || Err::<u64, _>("boom"));
+    assert!(matches!(failed, CaseExecutionResult::Failure(_)));
+
+    let records = read_records(&path);
+    assert_eq!(records.len(), 5, "{records:?}");
+    assert_eq!(
+        records[0]["case"], "earlier_run",
+        "earlier records are kept"
+    );
+    let events = records[1..]
+        .iter()
+        .map(|record| {
+            format!(
+                "{}:{}",
+                record["event"].as_str().unwrap(),
+                record["case"].as_str().unwrap()
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [
+            "case_start:remote_case",
+            "case_finish:remote_case",
+            "case_start:failing_case",
+            "case_finish:failing_case",
+        ]
+    );
+    assert!(records[1]["at"].is_string());
+    assert_eq!(records[2]["outcome"], "ok");
+    assert_eq!(
+        records[2]["table_urls"],
+        serde_json::json!(["s3://bucket/root/sf1/narrow_sales"])
+    );
+    assert!(records[2]["elapsed_ms"].as_f64().is_some());
+    assert_eq!(records[4]["outcome"], "failed");
+    assert_eq!(records[4]["failure_kind"], "execution_error");
+    assert_eq!(records[4]["message"], "boom");
+    assert_eq!(records[4]["table_urls"], serde_json::json!([]));
+}
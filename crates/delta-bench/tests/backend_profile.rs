@@ -70,3 +70,80 @@ fn profile_name_rejects_parent_traversal() {
         "unexpected error: {err}"
     );
 }
+
+#[test]
+fn profile_resolves_env_secret_references() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let backends = temp.path().join("backends");
+    fs::create_dir_all(&backends).expect("create backends dir");
+    std::env::set_var("DELTA_BENCH_TEST_PROFILE_SECRET", "s3cr3t-value");
+    fs::write(
+        backends.join("s3_secret.env"),
+        "AWS_REGION=us-east-1\nAWS_SECRET_ACCESS_KEY=secret://env/DELTA_BENCH_TEST_PROFILE_SECRET\n",
+    )
+    .expect("write profile file");
+
+    let options = load_backend_profile_options_from_root(Some("s3_secret"), temp.path())
+        .expect("secret reference should resolve");
+
+    assert_eq!(
+        options.get("AWS_SECRET_ACCESS_KEY").map(String::as_str),
+        Some("s3cr3t-value")
+    );
+    assert_eq!(
+        options.get("AWS_REGION").map(String::as_str),
+        Some("us-east-1")
+    );
+}
+
+#[test]
+fn profile_secret_reference_errors_name_the_option() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let backends = temp.path().join("backends");
+    fs::create_dir_all(&backends).expect("create backends dir");
+    fs::write(
+        backends.join("s3_missing_secret.env"),
+        "AWS_SECRET_ACCESS_KEY=secret://env/DELTA_BENCH_TEST_PROFILE_SECRET_UNSET\n",
+    )
+    .expect("write profile file");
+    fs::write(
+        backends.join("s3_unknown_provider.env"),
+        "AWS_SECRET_ACCESS_KEY=secret://vault/kv/bench\n",
+    )
+    .expect("write profile file");
+
+    let err = load_backend_profile_options_from_root(Some("s3_missing_secret"), temp.path())
+        .expect_err("unset env secret should error");
+    let message = err.to_string();
+    assert!(
+        message.contains("AWS_SECRET_ACCESS_KEY")
+            && message.contains("DELTA_BENCH_TEST_PROFILE_SECRET_UNSET"),
+        "unexpected error: {message}"
+    );
+
+    let err = load_backend_profile_options_from_root(Some("s3_unknown_provider"), temp.path())
+        .expect_err("unknown provider should error");
+    assert!(
+        err.to_string().contains("unknown secret provider 'vault'"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn profile_sops_reference_requires_existing_file() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let backends = temp.path().join("backends");
+    fs::create_dir_all(&backends).expect("create backends dir");
+    fs::write(
+        backends.join("s3_sops.env"),
+        "AWS_SECRET_ACCESS_KEY=secret://sops/backends/missing.enc.yaml#AWS_SECRET_ACCESS_KEY\n",
+    )
+    .expect("write profile file");
+
+    let err = load_backend_profile_options_from_root(Some("s3_sops"), temp.path())
+        .expect_err("missing sops file should error");
+    assert!(
+        err.to_string().contains("missing sops file"),
+        "unexpected error: {err}"
+    );
+}
@@ -0,0 +1,342 @@
+use std::sync::{Arc, Mutex};
+
+use delta_bench::cli::{BenchmarkLane, BenchmarkMode, PlanFormat, PlanMetricsDetail, RunnerMode};
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::data::scales::SMOKE_SCALE;
+use delta_bench::results::{CapturedPlan, PerfStatus};
+use delta_bench::runner::{
+    render_physical_plan, summarize_dhat_profile, write_flamegraphs, write_heap_profiles,
+    write_plan_metrics_files, BenchRunner,
+};
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::plan_run_cases;
+use deltalake_core::arrow::datatypes::Schema;
+use deltalake_core::datafusion::physical_plan::empty::EmptyExec;
+
+#[tokio::test]
+async fn builder_runs_a_plan_and_reports_each_case() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    generate_fixtures(temp.path(), SMOKE_SCALE, 42, true, &StorageConfig::local())
+        .await
+        .expect("generate fixtures");
+    let plan = plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan");
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&seen);
+    let result = BenchRunner::builder()
+        .fixtures_dir(temp.path())
+        .plan(plan)
+        .scale(SMOKE_SCALE)
+        .target("scan")
+        .runner(RunnerMode::Rust)
+        .lane(BenchmarkLane::Smoke)
+        .warmup(3)
+        .iterations(7)
+        .label("embedded")
+        .on_case(move |case| recorded.lock().expect("lock").push(case.case.clone()))
+        .build()
+        .expect("build")
+        .run()
+        .await
+        .expect("run");
+
+    assert_eq!(result.context.label, "embedded");
+    assert_eq!(result.context.suite, "scan");
+    assert_eq!(result.context.scale, SMOKE_SCALE);
+    assert_eq!(result.context.lane.as_deref(), Some("smoke"));
+    assert_eq!(
+        (result.context.warmup, result.context.iterations),
+        (0, 1),
+        "the smoke lane runs one unwarmed iteration"
+    );
+    assert_eq!(result.cases.len(), 1);
+    let case = &result.cases[0];
+    assert!(case.success, "{:?}", case.failure);
+    assert_eq!(case.perf_status, PerfStatus::ValidationOnly);
+    assert!(case.compatibility_key.is_some());
+    assert_eq!(*seen.lock().expect("lock"), vec!["scan_full_narrow"]);
+}
+
+#[tokio::test]
+async fn plan_capture_records_the_plan_and_full_metrics_of_query_cases() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    generate_fixtures(temp.path(), SMOKE_SCALE, 42, true, &StorageConfig::local())
+        .await
+        .expect("generate fixtures");
+    let plan = plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan");
+
+    let mut result = BenchRunner::builder()
+        .fixtures_dir(temp.path())
+        .plan(plan)
+        .scale(SMOKE_SCALE)
+        .target("scan")
+        .runner(RunnerMode::Rust)
+        .lane(BenchmarkLane::Smoke)
+        .capture_plan(PlanFormat::Text)
+        .plan_metrics(PlanMetricsDetail::Full)
+        .build()
+        .expect("build")
+        .run()
+        .await
+        .expect("run");
+
+    let case = &result.cases[0];
+    assert!(case.success, "{:?}", case.failure);
+    match &case.physical_plan {
+        Some(CapturedPlan::Text(plan)) => assert!(plan.contains("Exec"), "{plan}"),
+        other => panic!("expected a text plan, got {other:?}"),
+    }
+    assert_eq!(case.plan_metrics.len(), 1, "one measured iteration");
+
+    let out_dir = temp.path().join("results");
+    let written = write_plan_metrics_files(&mut result, &out_dir, "scan").expect("write metrics");
+    assert_eq!(
+        written,
+        vec![out_dir.join("scan.plan_metrics/scan_full_narrow.json")]
+    );
+    let case = &result.cases[0];
+    assert_eq!(
+        case.plan_metrics_file.as_deref(),
+        Some("scan.plan_metrics/scan_full_narrow.json")
+    );
+    assert!(case.plan_metrics.is_empty());
+    let dump: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&written[0]).expect("read metrics"))
+            .expect("parse metrics");
+    assert_eq!(dump["case"], "scan_full_narrow");
+    assert!(dump["iterations"][0]["operator"].is_string(), "{dump}");
+}
+
+#[tokio::test]
+async fn flamegraphs_are_written_next_to_the_result() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    generate_fixtures(temp.path(), SMOKE_SCALE, 42, true, &StorageConfig::local())
+        .await
+        .expect("generate fixtures");
+    let plan = plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan");
+    let mut result = BenchRunner::builder()
+        .fixtures_dir(temp.path())
+        .plan(plan)
+        .scale(SMOKE_SCALE)
+        .target("scan")
+        .runner(RunnerMode::Rust)
+        .lane(BenchmarkLane::Smoke)
+        .build()
+        .expect("build")
+        .run()
+        .await
+        .expect("run");
+    assert!(result.cases[0].flamegraph.is_none(), "not profiled");
+    result.cases[0].flamegraph = Some(b"<svg/>".to_vec());
+
+    let out_dir = temp.path().join("results");
+    let written = write_flamegraphs(&mut result, &out_dir, "scan").expect("write flamegraphs");
+    assert_eq!(
+        written,
+        vec![out_dir.join("scan.profiles/scan_full_narrow.svg")]
+    );
+    let case = &result.cases[0];
+    assert_eq!(
+        case.profile_file.as_deref(),
+        Some("scan.profiles/scan_full_narrow.svg")
+    );
+    assert!(case.flamegraph.is_none());
+    assert_eq!(std::fs::read(&written[0]).expect("read svg"), b"<svg/>");
+}
+
+#[test]
+fn dhat_profiles_report_the_peak_and_the_largest_sites_at_it() {
+    let dhat_json = serde_json::json!({
+        "dhatFileVersion": 2,
+        "mode": "rust-heap",
+        "pps": [
+            {"tb": 4096, "tbk": 4, "gb": 0, "gbk": 0, "fs": [1]},
+            {"tb": 1024, "tbk": 1, "gb": 1024, "gbk": 1, "fs": [2, 3]},
+            {"tb": 512, "tbk": 2, "gb": 256, "gbk": 1, "fs": [3]},
+        ],
+        "ftbl": [
+            "[root]",
+            "0x55d1c2a3: delta_bench::suites::merge::stage (src/suites/merge.rs:10:5)",
+            "0x55d1c2b4: arrow_buffer::buffer::MutableBuffer::new (src/buffer.rs:1:1)",
+            "0x55d1c2c5: delta_bench::suites::optimize_perf::compact (optimize_perf.rs:3:9)",
+        ],
+    });
+    let bytes = serde_json::to_vec(&dhat_json).expect("encode");
+
+    let heap = summarize_dhat_profile(bytes.clone()).expect("summarize");
+    assert_eq!(
+        (
+            heap.peak_bytes,
+            heap.peak_blocks,
+            heap.total_bytes,
+            heap.total_blocks
+        ),
+        (1280, 2, 5632, 7)
+    );
+    let peaks = heap
+        .top_sites
+        .iter()
+        .map(|site| site.peak_bytes)
+        .collect::<Vec<_>>();
+    assert_eq!(peaks, [1024, 256, 0], "largest at the peak first");
+    assert_eq!(
+        heap.top_sites[0].frames,
+        [
+            "arrow_buffer::buffer::MutableBuffer::new (src/buffer.rs:1:1)",
+            "delta_bench::suites::optimize_perf::compact (optimize_perf.rs:3:9)",
+        ],
+        "addresses change every run and are dropped"
+    );
+    assert_eq!(heap.dhat_json, bytes);
+    assert!(summarize_dhat_profile(b"not json".to_vec()).is_err());
+}
+
+#[tokio::test]
+async fn heap_profiles_are_written_next_to_the_result() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    generate_fixtures(temp.path(), SMOKE_SCALE, 42, true, &StorageConfig::local())
+        .await
+        .expect("generate fixtures");
+    let plan = plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan");
+    let mut result = BenchRunner::builder()
+        .fixtures_dir(temp.path())
+        .plan(plan)
+        .scale(SMOKE_SCALE)
+        .target("scan")
+        .runner(RunnerMode::Rust)
+        .lane(BenchmarkLane::Smoke)
+        .build()
+        .expect("build")
+        .run()
+        .await
+        .expect("run");
+    assert!(result.cases[0].heap_profile.is_none(), "not heap-profiled");
+    let dhat_json = br#"{"pps":[{"tb":8,"tbk":1,"gb":8,"gbk":1,"fs":[]}],"ftbl":["[root]"]}"#;
+    result.cases[0].heap_profile =
+        Some(summarize_dhat_profile(dhat_json.to_vec()).expect("summarize"));
+
+    let out_dir = temp.path().join("results");
+    let written = write_heap_profiles(&mut result, &out_dir, "scan").expect("write profiles");
+    assert_eq!(
+        written,
+        vec![out_dir.join("scan.heap/scan_full_narrow.json")]
+    );
+    let heap = result.cases[0].heap_profile.as_ref().expect("heap profile");
+    assert_eq!(
+        heap.file.as_deref(),
+        Some("scan.heap/scan_full_narrow.json")
+    );
+    assert!(heap.dhat_json.is_empty());
+    assert_eq!(std::fs::read(&written[0]).expect("read profile"), dhat_json);
+    let serialized = serde_json::to_value(&result.cases[0]).expect("serialize case");
+    assert_eq!(serialized["heap_profile"]["peak_bytes"], 8);
+    assert!(serialized["heap_profile"].get("dhat_json").is_none());
+}
+
+#[cfg(feature = "profiling")]
+#[tokio::test]
+async fn profiled_cases_keep_a_flamegraph_titled_with_the_case() {
+    use delta_bench::runner::{run_case_async, CaseExecutionResult, CaseHooks};
+
+    let hooks = CaseHooks::new().with_profiling();
+    let result = run_case_async("busy_case", 0, 1, &hooks, || async {
+        let start = std::time::Instant::now();
+        let mut acc = 0_u64;
+        while start.elapsed() < std::time::Duration::from_millis(200) {
+            acc = std::hint::black_box(acc.wrapping_mul(31).wrapping_add(7));
+        }
+        Ok::<_, String>(acc)
+    })
+    .await;
+
+    let CaseExecutionResult::Success(case) = result else {
+        panic!("busy case must succeed");
+    };
+    let svg = String::from_utf8(case.flamegraph.expect("flamegraph")).expect("utf8 svg");
+    assert!(svg.contains("<svg"), "{svg}");
+    assert!(svg.contains("busy_case"), "the case names the flamegraph");
+}
+
+#[test]
+fn json_plans_nest_operators_with_their_children() {
+    let plan = EmptyExec::new(Arc::new(Schema::empty()));
+    let CapturedPlan::Json(json) = render_physical_plan(&plan, PlanFormat::Json) else {
+        panic!("expected a json plan");
+    };
+    assert_eq!(json["operator"], "EmptyExec");
+    assert_eq!(json["description"], "EmptyExec");
+    assert_eq!(json["children"], serde_json::json!([]));
+}
+
+#[test]
+fn builder_rejects_incomplete_or_contradictory_settings() {
+    let plan = plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan");
+
+    let err = BenchRunner::builder()
+        .plan(plan.clone())
+        .build()
+        .err()
+        .expect("missing fixtures_dir must fail");
+    assert!(err.to_string().contains("requires a fixtures_dir"), "{err}");
+
+    let err = BenchRunner::builder()
+        .fixtures_dir("fixtures")
+        .build()
+        .err()
+        .expect("missing plan must fail");
+    assert!(err.to_string().contains("requires a plan"), "{err}");
+
+    let err = BenchRunner::builder()
+        .fixtures_dir("fixtures")
+        .plan(plan)
+        .benchmark_mode(BenchmarkMode::Assert)
+        .build()
+        .err()
+        .expect("assert mode on the macro lane must fail");
+    assert!(
+        err.to_string()
+            .contains("--mode assert requires --lane correctness"),
+        "{err}"
+    );
+
+    #[cfg(not(feature = "profiling"))]
+    {
+        let err = BenchRunner::builder()
+            .fixtures_dir("fixtures")
+            .plan(plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan"))
+            .profile(true)
+            .build()
+            .err()
+            .expect("profiling without the feature must fail");
+        assert!(err.to_string().contains("`profiling` feature"), "{err}");
+    }
+
+    #[cfg(not(feature = "heap-profiling"))]
+    {
+        let err = BenchRunner::builder()
+            .fixtures_dir("fixtures")
+            .plan(plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan"))
+            .heap_profile(true)
+            .build()
+            .err()
+            .expect("heap profiling without the feature must fail");
+        assert!(
+            err.to_string().contains("`heap-profiling` feature"),
+            "{err}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn missing_fixtures_fail_the_run() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let plan = plan_run_cases("scan", RunnerMode::Rust, Some("scan_full_narrow")).expect("plan");
+
+    let runner = BenchRunner::builder()
+        .fixtures_dir(temp.path())
+        .plan(plan)
+        .build()
+        .expect("build");
+
+    assert!(runner.run().await.is_err());
+}
@@ -0,0 +1,79 @@
+use delta_bench::cli::BenchmarkLane;
+use delta_bench::data::fixtures::{generate_fixtures_with_profile, FixtureProfile};
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::checkpoint;
+
+#[tokio::test]
+async fn checkpoint_suite_runs_against_many_versions_fixtures() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures_with_profile(
+        temp.path(),
+        "sf1",
+        42,
+        true,
+        FixtureProfile::ManyVersions,
+        &storage,
+    )
+    .await
+    .expect("fixtures should be generated");
+
+    let cases = checkpoint::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("checkpoint suite should run");
+
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "checkpoint_write_long_history",
+            "checkpoint_load_with_checkpoint",
+            "checkpoint_load_without_checkpoint",
+        ]
+    );
+    assert!(
+        cases.iter().all(|case| case.success),
+        "checkpoint failures: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn checkpoint_suite_reports_fixture_error_without_many_versions_profile() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = checkpoint::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("checkpoint suite should return fixture errors");
+
+    assert_eq!(cases.len(), 3);
+    assert!(cases.iter().all(|case| !case.success));
+    assert!(cases.iter().all(|case| case
+        .failure
+        .as_ref()
+        .is_some_and(|failure| failure.message.contains("many_versions"))));
+}
@@ -1,5 +1,11 @@
 use clap::Parser;
-use delta_bench::cli::{Args, BenchmarkMode, Command, RunnerMode};
+use delta_bench::cli::{
+    Args, BenchmarkMode, Command, DataArchiveCommand, LogFormat, PlanFormat, PlanMetricsDetail,
+    RunnerMode,
+};
+use delta_bench::data::fixtures::{DEFAULT_SMALL_FILES_COUNT, DEFAULT_WIDE_TABLE_COLUMNS};
+use delta_bench::suites::tpcds::catalog::{DEFAULT_TPCDS_PHASE, MAX_TPCDS_PHASE};
+use delta_bench::suites::tpcds::registration::TpcdsLayout;
 
 #[test]
 fn run_command_accepts_new_selector_flags() {
@@ -54,6 +60,75 @@ fn data_command_accepts_dataset_id() {
     }
 }
 
+#[test]
+fn data_and_data_import_accept_trash_flag() {
+    let args = Args::parse_from(["delta-bench", "data", "--force", "--trash"]);
+    assert!(matches!(args.command, Command::Data { trash: true, .. }));
+
+    let args = Args::parse_from([
+        "delta-bench",
+        "data",
+        "import",
+        "--archive",
+        "fixtures.tar.zst",
+        "--trash",
+    ]);
+    assert!(matches!(
+        args.command,
+        Command::Data {
+            archive: Some(DataArchiveCommand::Import { trash: true, .. }),
+            ..
+        }
+    ));
+
+    let args = Args::parse_from(["delta-bench", "data"]);
+    assert!(matches!(args.command, Command::Data { trash: false, .. }));
+}
+
+#[test]
+fn data_command_accepts_wide_table_columns() {
+    let args = Args::parse_from(["delta-bench", "data", "--wide-table-columns", "16"]);
+    assert!(matches!(
+        args.command,
+        Command::Data {
+            wide_table_columns: 16,
+            ..
+        }
+    ));
+
+    let args = Args::parse_from(["delta-bench", "data"]);
+    assert!(matches!(
+        args.command,
+        Command::Data {
+            wide_table_columns: DEFAULT_WIDE_TABLE_COLUMNS,
+            small_files_count: DEFAULT_SMALL_FILES_COUNT,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn data_command_accepts_small_files_shape() {
+    let args = Args::parse_from([
+        "delta-bench",
+        "data",
+        "--dataset-id",
+        "small_files",
+        "--small-files-count",
+        "12",
+        "--small-files-rows-per-file",
+        "50",
+    ]);
+    assert!(matches!(
+        args.command,
+        Command::Data {
+            small_files_count: 12,
+            small_files_rows_per_file: 50,
+            ..
+        }
+    ));
+}
+
 #[test]
 fn run_command_accepts_tpcds_duckdb_dataset_id() {
     let args = Args::parse_from([
@@ -76,6 +151,75 @@ fn run_command_accepts_tpcds_duckdb_dataset_id() {
     }
 }
 
+#[test]
+fn run_command_accepts_tpcds_phase_within_catalog_range() {
+    let args = Args::parse_from(["delta-bench", "run", "--target", "tpcds"]);
+    match args.command {
+        Command::Run { tpcds_phase, .. } => assert_eq!(tpcds_phase, DEFAULT_TPCDS_PHASE),
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    let max = MAX_TPCDS_PHASE.to_string();
+    let args = Args::parse_from(["delta-bench", "run", "--tpcds-phase", max.as_str()]);
+    match args.command {
+        Command::Run { tpcds_phase, .. } => assert_eq!(tpcds_phase, MAX_TPCDS_PHASE),
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    let beyond = (MAX_TPCDS_PHASE + 1).to_string();
+    for phase in ["0", beyond.as_str()] {
+        assert!(
+            Args::try_parse_from(["delta-bench", "run", "--tpcds-phase", phase]).is_err(),
+            "--tpcds-phase {phase} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn run_command_parses_tpcds_layout() {
+    let args = Args::parse_from(["delta-bench", "run", "--target", "tpcds"]);
+    match args.command {
+        Command::Run { tpcds_layout, .. } => assert_eq!(tpcds_layout, TpcdsLayout::Unpartitioned),
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    let args = Args::parse_from(["delta-bench", "run", "--tpcds-layout", "date_partitioned"]);
+    match args.command {
+        Command::Run { tpcds_layout, .. } => {
+            assert_eq!(tpcds_layout, TpcdsLayout::DatePartitioned)
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    assert!(Args::try_parse_from(["delta-bench", "run", "--tpcds-layout", "hive"]).is_err());
+}
+
+#[test]
+fn run_command_requires_both_open_loop_load_flags() {
+    let args = Args::parse_from([
+        "delta-bench",
+        "run",
+        "--load-duration-secs",
+        "10",
+        "--target-ops-per-sec",
+        "2.5",
+    ]);
+    match args.command {
+        Command::Run {
+            load_duration_secs,
+            target_ops_per_sec,
+            ..
+        } => {
+            assert_eq!(load_duration_secs, Some(10));
+            assert_eq!(target_ops_per_sec, Some(2.5));
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+
+    assert!(Args::try_parse_from(["delta-bench", "run", "--load-duration-secs", "10"]).is_err());
+    assert!(Args::try_parse_from(["delta-bench", "run", "--target-ops-per-sec", "2.5"]).is_err());
+}
+
 #[test]
 fn run_command_defaults_runner_to_all() {
     let args = Args::parse_from(["delta-bench", "run"]);
@@ -103,6 +247,90 @@ fn run_command_accepts_no_summary_table_flag() {
     }
 }
 
+#[test]
+fn run_command_accepts_progress_flag() {
+    let args = Args::parse_from(["delta-bench", "run"]);
+    assert!(matches!(
+        args.command,
+        Command::Run {
+            progress: false,
+            ..
+        }
+    ));
+
+    let args = Args::parse_from(["delta-bench", "run", "--progress"]);
+    assert!(matches!(args.command, Command::Run { progress: true, .. }));
+}
+
+#[test]
+fn run_command_accepts_capture_plan_format() {
+    let args = Args::parse_from(["delta-bench", "run", "--capture-plan", "json"]);
+    assert!(matches!(
+        args.command,
+        Command::Run {
+            capture_plan: Some(PlanFormat::Json),
+            ..
+        }
+    ));
+    assert!(Args::try_parse_from(["delta-bench", "run", "--capture-plan", "dot"]).is_err());
+}
+
+#[test]
+fn run_command_defaults_to_curated_plan_metrics() {
+    let args = Args::parse_from(["delta-bench", "run"]);
+    assert!(matches!(
+        args.command,
+        Command::Run {
+            capture_plan_metrics: PlanMetricsDetail::Curated,
+            ..
+        }
+    ));
+
+    let args = Args::parse_from(["delta-bench", "run", "--capture-plan-metrics", "full"]);
+    assert!(matches!(
+        args.command,
+        Command::Run {
+            capture_plan_metrics: PlanMetricsDetail::Full,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn log_format_defaults_to_text_and_accepts_json() {
+    let args = Args::parse_from(["delta-bench", "run"]);
+    assert_eq!(args.log_format, LogFormat::Text);
+
+    let args = Args::parse_from(["delta-bench", "--log-format", "json", "run"]);
+    assert_eq!(args.log_format, LogFormat::Json);
+    assert!(Args::try_parse_from(["delta-bench", "--log-format", "yaml", "run"]).is_err());
+}
+
+#[test]
+fn plan_command_accepts_against_previous_run() {
+    let args = Args::parse_from([
+        "delta-bench",
+        "plan",
+        "--target",
+        "restore",
+        "--against",
+        "results/base",
+    ]);
+    match args.command {
+        Command::Plan {
+            target,
+            runner,
+            against,
+            ..
+        } => {
+            assert_eq!(target, "restore");
+            assert_eq!(runner, None);
+            assert_eq!(against, Some(std::path::PathBuf::from("results/base")));
+        }
+        other => panic!("unexpected command: {other:?}"),
+    }
+}
+
 #[test]
 fn run_command_accepts_assert_mode() {
     let args = Args::parse_from(["delta-bench", "run", "--mode", "assert"]);
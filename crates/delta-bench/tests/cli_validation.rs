@@ -2,7 +2,8 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
-use delta_bench::cli::validate_label;
+use clap::Parser;
+use delta_bench::cli::{expand_label_template, parse_ref_issues, validate_label, Args, Command};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -42,3 +43,80 @@ fn cli_validation_rejects_contract_invalid_labels() {
         );
     }
 }
+
+#[test]
+fn ref_issues_accept_owner_repo_number_and_drop_repeats() {
+    let refs = parse_ref_issues(&[
+        "delta-io/delta-rs#1234".to_string(),
+        "apache/arrow-rs#42".to_string(),
+        "delta-io/delta-rs#1234".to_string(),
+    ])
+    .expect("valid refs");
+    assert_eq!(refs, ["delta-io/delta-rs#1234", "apache/arrow-rs#42"]);
+
+    for invalid in [
+        "1234",
+        "delta-rs#1234",
+        "delta-io/delta-rs",
+        "delta-io/delta-rs#",
+        "delta-io/delta-rs#0",
+        "delta-io/delta-rs#+12",
+        "delta io/delta-rs#12",
+        "https://github.com/delta-io/delta-rs/pull/12",
+    ] {
+        let err = parse_ref_issues(&[invalid.to_string()]).expect_err("ref should be rejected");
+        assert!(
+            err.to_string().contains("OWNER/REPO#NUMBER"),
+            "unexpected error for '{invalid}': {err}"
+        );
+    }
+}
+
+fn template_values() -> Vec<(&'static str, String)> {
+    vec![
+        ("date", "20260301".to_string()),
+        ("git_sha", "0123456789ab".to_string()),
+        ("backend", "s3".to_string()),
+        ("host", "runner 7/eu".to_string()),
+    ]
+}
+
+#[test]
+fn label_templates_expand_placeholders_into_valid_labels() {
+    let label = expand_label_template("nightly-{date}-{git_sha}-{backend}", &template_values())
+        .expect("expand template");
+    assert_eq!(label, "nightly-20260301-0123456789ab-s3");
+    validate_label(&label).expect("expanded label should be valid");
+
+    let label = expand_label_template("{host}", &template_values()).expect("expand host");
+    assert_eq!(label, "runner_7_eu");
+    validate_label(&label).expect("sanitized value should be valid");
+}
+
+#[test]
+fn label_templates_reject_unknown_and_unclosed_placeholders() {
+    let err = expand_label_template("{date}-{branch}", &template_values())
+        .expect_err("unknown placeholder");
+    assert!(
+        err.to_string().contains(
+            "unknown label template placeholder '{branch}' (expected one of: {date}, {git_sha}, {backend}, {host})"
+        ),
+        "{err}"
+    );
+    let err = expand_label_template("{date", &template_values()).expect_err("unclosed brace");
+    assert!(err.to_string().contains("unclosed"), "{err}");
+}
+
+#[test]
+fn run_accepts_a_label_template() {
+    let args = Args::parse_from([
+        "delta-bench",
+        "run",
+        "--label-template",
+        "{date}-{git_sha}-{backend}",
+    ]);
+    assert!(matches!(
+        args.command,
+        Command::Run { label_template: Some(ref template), .. } if template == "{date}-{git_sha}-{backend}"
+    ));
+}
@@ -1,10 +1,13 @@
 use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::data::row_cache::FixtureRowCache;
 use delta_bench::storage::StorageConfig;
 use delta_bench::suites::concurrency;
 
 const CREATE_WORKERS: u64 = 4;
 const APPEND_WORKERS: u64 = 4;
 const CONTENDED_ATTEMPTS: u64 = 6;
+const INGEST_CONTENDED_ATTEMPTS: u64 = 15;
+const MIXED_WORKERS: u64 = 8;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn generated_standard_fixtures_support_concurrency_suite() {
@@ -14,10 +17,10 @@ async fn generated_standard_fixtures_support_concurrency_suite() {
         .await
         .expect("generate fixtures");
 
-    let cases = concurrency::run(temp.path(), "sf1", 0, 1, &storage)
+    let cases = concurrency::run(temp.path(), "sf1", 0, 1, &storage, &FixtureRowCache::new())
         .await
         .expect("concurrency suite run");
-    assert_eq!(cases.len(), 5, "expected exact concurrency case list");
+    assert_eq!(cases.len(), 10, "expected exact concurrency case list");
     assert!(
         cases.iter().all(|case| case.success),
         "concurrency failures: {:?}",
@@ -49,9 +52,15 @@ async fn generated_standard_fixtures_support_concurrency_suite() {
         let expected_attempts = match case.case.as_str() {
             "concurrent_table_create" => CREATE_WORKERS,
             "concurrent_append_multi" => APPEND_WORKERS,
+            "concurrent_append_writers_2" => 2,
+            "concurrent_append_writers_4" => 4,
+            "concurrent_append_writers_8" => 8,
             "update_vs_compaction" | "delete_vs_compaction" | "optimize_vs_optimize_overlap" => {
                 CONTENDED_ATTEMPTS
             }
+            "optimize_vs_append_ingest" => INGEST_CONTENDED_ATTEMPTS,
+            // Workers loop for a fixed duration, so the operation count varies.
+            "mixed_oltp_like" => contention.ops_attempted,
             other => panic!("unexpected case id: {other}"),
         };
         assert_eq!(
@@ -65,6 +74,36 @@ async fn generated_standard_fixtures_support_concurrency_suite() {
                 "shared-table concurrency case '{}' should report table_version",
                 case.case
             ),
+            "concurrent_append_writers_2"
+            | "concurrent_append_writers_4"
+            | "concurrent_append_writers_8" => {
+                assert_eq!(
+                    metrics.table_version,
+                    Some(expected_attempts),
+                    "every writer in '{}' should land one commit on the shared table",
+                    case.case
+                );
+                let workers = metrics
+                    .workers
+                    .as_ref()
+                    .expect("per-writer metrics should be present");
+                assert_eq!(workers.len() as u64, expected_attempts);
+                let mut committed = workers
+                    .iter()
+                    .map(|worker| worker.table_version.expect("writer should commit"))
+                    .collect::<Vec<_>>();
+                committed.sort_unstable();
+                assert_eq!(committed, (1..=expected_attempts).collect::<Vec<_>>());
+                assert_eq!(
+                    workers
+                        .iter()
+                        .filter_map(|worker| worker.commit_retries)
+                        .sum::<u64>(),
+                    (0..expected_attempts).sum::<u64>(),
+                    "retries should account for every lost race in '{}'",
+                    case.case
+                );
+            }
             "update_vs_compaction" | "delete_vs_compaction" | "optimize_vs_optimize_overlap" => {
                 assert!(
                     metrics.table_version.is_none(),
@@ -82,6 +121,39 @@ async fn generated_standard_fixtures_support_concurrency_suite() {
                     contention.ops_succeeded,
                 );
             }
+            "optimize_vs_append_ingest" => {
+                assert!(metrics.table_version.is_none());
+                let workers = metrics
+                    .workers
+                    .as_ref()
+                    .expect("per-worker metrics should be present");
+                assert_eq!(
+                    workers.len(),
+                    6,
+                    "one optimize and one ingest worker per race"
+                );
+                assert!(metrics.rows_processed.is_some_and(|rows| rows > 0));
+            }
+            "mixed_oltp_like" => {
+                let mixed = metrics
+                    .mixed_workload
+                    .as_ref()
+                    .expect("mixed workload metrics should be present");
+                assert_eq!(mixed.workers, MIXED_WORKERS);
+                assert_eq!(mixed.duration_ms, 60_000);
+                assert_eq!(contention.ops_failed, 0);
+                assert_eq!(
+                    mixed.reads.ops + mixed.appends.ops,
+                    contention.ops_attempted
+                );
+                assert!(
+                    mixed.appends.ops > 0 && mixed.reads.ops >= 9 * mixed.appends.ops,
+                    "expected a 90/10 read/append mix, got {} reads and {} appends",
+                    mixed.reads.ops,
+                    mixed.appends.ops
+                );
+                assert_eq!(metrics.table_version, Some(1 + mixed.appends.ops));
+            }
             other => panic!("unexpected case id: {other}"),
         }
     }
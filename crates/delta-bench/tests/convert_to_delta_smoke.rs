@@ -0,0 +1,83 @@
+use delta_bench::cli::BenchmarkLane;
+use delta_bench::data::fixtures::{generate_fixtures, raw_parquet_partitioned_path};
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::convert_to_delta;
+
+#[tokio::test]
+async fn convert_to_delta_suite_registers_every_raw_parquet_file() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("fixtures should be generated");
+
+    let raw_dir = raw_parquet_partitioned_path(temp.path(), "sf1");
+    assert!(
+        !raw_dir.join("_delta_log").exists(),
+        "raw Parquet fixture must not be a Delta table"
+    );
+    let partitions = std::fs::read_dir(&raw_dir)
+        .expect("raw Parquet fixture dir should exist")
+        .map(|entry| entry.expect("dir entry").file_name())
+        .collect::<Vec<_>>();
+    assert_eq!(partitions.len(), 6);
+    assert!(partitions
+        .iter()
+        .all(|name| name.to_string_lossy().starts_with("region=")));
+
+    let cases = convert_to_delta::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Correctness,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("convert_to_delta suite should run");
+
+    assert_eq!(cases.len(), 1);
+    let case = &cases[0];
+    assert_eq!(case.case, "convert_to_delta_partitioned");
+    assert!(case.success, "convert_to_delta failure: {:?}", case.failure);
+
+    let metrics = case.samples[0]
+        .metrics
+        .as_ref()
+        .expect("sample metrics should be present");
+    assert_eq!(metrics.table_version, Some(0));
+    assert_eq!(metrics.files_touched, Some(48));
+    assert!(metrics.semantic_state_digest.is_some());
+    assert!(
+        !raw_dir.join("_delta_log").exists(),
+        "conversion must run against a copy of the fixture"
+    );
+}
+
+#[tokio::test]
+async fn convert_to_delta_suite_reports_fixture_error_without_raw_parquet_dir() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = convert_to_delta::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("convert_to_delta suite should return fixture errors");
+
+    assert_eq!(cases.len(), 1);
+    assert!(!cases[0].success);
+    assert!(cases[0]
+        .failure
+        .as_ref()
+        .is_some_and(|failure| failure.message.contains("raw Parquet fixture")));
+}
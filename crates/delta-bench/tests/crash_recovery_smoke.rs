@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use delta_bench::cli::StorageBackend;
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::crash_recovery;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn crash_recovery_reopens_consistent_table_after_writer_kill() {
+    // Safety: this is the only test in the binary that touches the environment.
+    unsafe {
+        std::env::set_var(
+            "DELTA_BENCH_CRASH_WRITER_BIN",
+            env!("CARGO_BIN_EXE_delta-bench"),
+        )
+    };
+    let temp = tempfile::tempdir().expect("tempdir");
+
+    let cases = crash_recovery::run(
+        temp.path(),
+        "sf1",
+        0,
+        3,
+        &CaseHooks::default(),
+        &StorageConfig::local(),
+    )
+    .await
+    .expect("crash_recovery suite run");
+    assert_eq!(cases.len(), 1);
+    let case = &cases[0];
+    assert!(case.success, "crash recovery failure: {:?}", case.failure);
+    assert_eq!(case.samples.len(), 3);
+    for sample in &case.samples {
+        let metrics = sample.metrics.as_ref().expect("sample metrics");
+        let version = metrics.table_version.expect("recovered table version");
+        assert_eq!(metrics.rows_processed, Some(4_096 * (version + 1)));
+    }
+}
+
+#[tokio::test]
+async fn crash_recovery_rejects_non_local_backend() {
+    let storage = StorageConfig::new(
+        StorageBackend::S3,
+        HashMap::from([(
+            "table_root".to_string(),
+            "s3://bench-bucket/prefix".to_string(),
+        )]),
+    )
+    .expect("s3 storage config");
+    let temp = tempfile::tempdir().expect("tempdir");
+
+    let cases = crash_recovery::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("crash_recovery suite run");
+    assert!(cases.iter().all(|case| !case.success));
+}
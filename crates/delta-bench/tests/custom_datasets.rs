@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use delta_bench::data::datasets::{DatasetGenerator, DatasetTableLayout};
+use delta_bench::data::fixtures::{
+    custom_dataset_table_url, generate_custom_dataset_fixtures, load_manifest,
+    verify_fixture_manifest,
+};
+use delta_bench::error::BenchResult;
+use delta_bench::storage::StorageConfig;
+use deltalake_core::arrow::array::{Float64Array, Int64Array, StringArray};
+use deltalake_core::arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use deltalake_core::arrow::record_batch::RecordBatch;
+
+const SITES: [&str; 3] = ["north", "south", "west"];
+
+struct IotTimeSeries {
+    id: &'static str,
+    tables: Vec<DatasetTableLayout>,
+}
+
+impl IotTimeSeries {
+    fn new() -> Self {
+        Self {
+            id: "iot_timeseries",
+            tables: vec![
+                DatasetTableLayout::new("readings_delta").partitioned_by(["site"]),
+                DatasetTableLayout::new("recent_readings_delta").with_rows(500),
+            ],
+        }
+    }
+}
+
+impl DatasetGenerator for IotTimeSeries {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("device_id", DataType::Int64, false),
+            Field::new("site", DataType::Utf8, false),
+            Field::new("ts_ms", DataType::Int64, false),
+            Field::new("temperature", DataType::Float64, false),
+        ]))
+    }
+
+    fn generate_batch(&self, seed: u64, offset: usize, len: usize) -> BenchResult<RecordBatch> {
+        let rows = (offset..offset + len).map(|row| row as i64);
+        Ok(RecordBatch::try_new(
+            self.schema(),
+            vec![
+                Arc::new(Int64Array::from_iter_values(
+                    rows.clone().map(|row| row % 16),
+                )),
+                Arc::new(StringArray::from_iter_values(
+                    rows.clone().map(|row| SITES[row as usize % SITES.len()]),
+                )),
+                Arc::new(Int64Array::from_iter_values(
+                    rows.clone().map(|row| 1_700_000_000_000 + row * 1_000),
+                )),
+                Arc::new(Float64Array::from_iter_values(
+                    rows.map(|row| ((seed as i64 + row) % 400) as f64 / 10.0),
+                )),
+            ],
+        )?)
+    }
+
+    fn tables(&self) -> Vec<DatasetTableLayout> {
+        self.tables.clone()
+    }
+}
+
+#[tokio::test]
+async fn custom_dataset_writes_its_layout_and_manifest() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    let dataset = IotTimeSeries::new();
+
+    let manifest =
+        generate_custom_dataset_fixtures(temp.path(), &dataset, "sf1", 7, false, &storage)
+            .await
+            .expect("generate custom dataset");
+    assert_eq!(manifest.profile, "iot_timeseries");
+    assert_eq!(manifest.rows, 10_000);
+    assert_eq!(
+        manifest.table_inventory,
+        vec!["readings_delta", "recent_readings_delta"]
+    );
+    let rows = manifest
+        .table_summaries
+        .iter()
+        .map(|summary| (summary.table.as_str(), summary.rows))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![("readings_delta", 10_000), ("recent_readings_delta", 500)]
+    );
+
+    let dataset_dir = temp.path().join("iot_timeseries");
+    let loaded = load_manifest(&dataset_dir, "sf1").expect("load manifest");
+    assert_eq!(loaded, manifest);
+    verify_fixture_manifest(&loaded, None, Some(7)).expect("manifest matches its fingerprint");
+
+    let table_url = custom_dataset_table_url(
+        temp.path(),
+        "iot_timeseries",
+        "sf1",
+        "readings_delta",
+        &storage,
+    )
+    .expect("table url");
+    assert!(table_url
+        .path()
+        .ends_with("/iot_timeseries/sf1/readings_delta/"));
+    let table = storage.open_table(table_url).await.expect("open readings");
+    assert_eq!(
+        table
+            .snapshot()
+            .expect("snapshot")
+            .metadata()
+            .partition_columns()
+            .to_vec(),
+        vec!["site".to_string()]
+    );
+
+    let again = generate_custom_dataset_fixtures(temp.path(), &dataset, "sf1", 7, false, &storage)
+        .await
+        .expect("reuse custom dataset");
+    assert_eq!(again, manifest);
+    let reseeded =
+        generate_custom_dataset_fixtures(temp.path(), &dataset, "sf1", 8, false, &storage)
+            .await
+            .expect("regenerate with a new seed");
+    assert_ne!(reseeded.dataset_fingerprint, manifest.dataset_fingerprint);
+}
+
+#[tokio::test]
+async fn invalid_custom_datasets_are_rejected() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    for (id, tables, expected) in [
+        (
+            "tiny_smoke",
+            vec![DatasetTableLayout::new("readings_delta")],
+            "is a built-in dataset",
+        ),
+        (
+            "sf10",
+            vec![DatasetTableLayout::new("readings_delta")],
+            "collides with a scale name",
+        ),
+        (
+            "IoT",
+            vec![DatasetTableLayout::new("readings_delta")],
+            "must be a lowercase identifier",
+        ),
+        ("iot", Vec::new(), "defines no tables"),
+        (
+            "iot",
+            vec![DatasetTableLayout::new("../readings")],
+            "must be a lowercase identifier",
+        ),
+        (
+            "iot",
+            vec![
+                DatasetTableLayout::new("readings_delta"),
+                DatasetTableLayout::new("readings_delta"),
+            ],
+            "lists table 'readings_delta' more than once",
+        ),
+        (
+            "iot",
+            vec![DatasetTableLayout::new("readings_delta").partitioned_by(["region"])],
+            "partitioned by 'region', which is not in the dataset schema",
+        ),
+    ] {
+        let dataset = IotTimeSeries { id, tables };
+        let err =
+            generate_custom_dataset_fixtures(temp.path(), &dataset, "sf1", 7, false, &storage)
+                .await
+                .expect_err("invalid dataset");
+        assert!(err.to_string().contains(expected), "{id}: {err}");
+    }
+}
+
+#[cfg(feature = "custom-datasets")]
+#[test]
+fn custom_datasets_register_by_id() {
+    use delta_bench::data::datasets::DatasetRegistry;
+
+    let mut registry = DatasetRegistry::default();
+    let err = registry
+        .get("iot_timeseries")
+        .err()
+        .expect("empty registry");
+    assert!(err.to_string().contains("registered: none"), "{err}");
+
+    registry
+        .register(Box::new(IotTimeSeries::new()))
+        .expect("register");
+    assert_eq!(registry.names(), vec!["iot_timeseries"]);
+    assert_eq!(
+        registry
+            .get("iot_timeseries")
+            .expect("lookup")
+            .tables()
+            .len(),
+        2
+    );
+    let err = registry
+        .register(Box::new(IotTimeSeries::new()))
+        .expect_err("duplicate id");
+    assert!(err.to_string().contains("already registered"), "{err}");
+}
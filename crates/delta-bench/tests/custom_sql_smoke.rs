@@ -0,0 +1,149 @@
+use std::path::Path;
+
+use delta_bench::cli::{RunnerMode, TimingPhase};
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::custom_sql::{self, CustomSqlWorkload};
+use delta_bench::suites::{
+    plan_run_cases, plan_run_cases_with_options, CaseTagFilter, SuiteOptions,
+};
+
+fn write_workload(dir: &Path, tables_yaml: &str, queries: &[(&str, &str)]) {
+    std::fs::create_dir_all(dir).expect("create workload dir");
+    std::fs::write(dir.join("tables.yaml"), tables_yaml).expect("write tables.yaml");
+    for (name, sql) in queries {
+        std::fs::write(dir.join(name), sql).expect("write query");
+    }
+}
+
+#[tokio::test]
+async fn custom_sql_benchmarks_every_query_in_the_directory() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+    let workload_dir = temp.path().join("queries");
+    write_workload(
+        &workload_dir,
+        "tables:\n  Sales: narrow_sales_delta\n",
+        &[
+            (
+                "regions.sql",
+                "SELECT region, COUNT(*) AS n FROM sales GROUP BY region",
+            ),
+            ("count.sql", "SELECT COUNT(*) AS n FROM SALES"),
+            ("notes.txt", "not a query"),
+        ],
+    );
+
+    let workload = CustomSqlWorkload::load(&workload_dir).expect("load workload");
+    assert_eq!(
+        workload.case_names(),
+        vec!["custom_sql_count", "custom_sql_regions"]
+    );
+    let cases = custom_sql::run_workload(
+        &workload,
+        temp.path(),
+        "sf1",
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        None,
+    )
+    .await
+    .expect("custom_sql suite run");
+    assert_eq!(cases.len(), 2);
+    for case in &cases {
+        assert!(case.success, "{} failure: {:?}", case.case, case.failure);
+        assert_eq!(case.samples.len(), 1, "{}", case.case);
+    }
+    let count = cases[0].samples[0]
+        .metrics
+        .as_ref()
+        .expect("sample metrics");
+    assert_eq!(count.rows_processed, Some(1));
+}
+
+#[tokio::test]
+async fn custom_sql_fails_queries_that_reference_unmapped_tables() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+    let workload_dir = temp.path().join("queries");
+    write_workload(
+        &workload_dir,
+        "tables:\n  sales: narrow_sales_delta\n",
+        &[("orders.sql", "SELECT COUNT(*) FROM orders")],
+    );
+
+    let workload = CustomSqlWorkload::load(&workload_dir).expect("load workload");
+    let cases = custom_sql::run_workload(
+        &workload,
+        temp.path(),
+        "sf1",
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        None,
+    )
+    .await
+    .expect("custom_sql suite run");
+    assert!(!cases[0].success);
+    let failure = cases[0].failure.as_ref().expect("failure");
+    assert!(failure.message.contains("'orders'"), "{}", failure.message);
+}
+
+#[test]
+fn custom_sql_rejects_directories_without_queries_or_mapping() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let err = CustomSqlWorkload::load(temp.path()).expect_err("missing tables.yaml");
+    assert!(err.to_string().contains("tables.yaml"), "{err}");
+
+    write_workload(temp.path(), "tables:\n  sales: narrow_sales_delta\n", &[]);
+    let err = CustomSqlWorkload::load(temp.path()).expect_err("no queries");
+    assert!(err.to_string().contains("no .sql files"), "{err}");
+
+    write_workload(temp.path(), "tables: {}\n", &[("bad name.sql", "SELECT 1")]);
+    let err = CustomSqlWorkload::load(temp.path()).expect_err("invalid case id");
+    assert!(err.to_string().contains("valid case id"), "{err}");
+}
+
+#[test]
+fn custom_sql_is_planned_from_the_query_directory_option() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_workload(
+        temp.path(),
+        "tables:\n  sales: narrow_sales_delta\n",
+        &[("count.sql", "SELECT COUNT(*) FROM sales")],
+    );
+
+    let err = plan_run_cases("custom_sql", RunnerMode::Rust, None).expect_err("no directory");
+    assert!(err.to_string().contains("--custom-sql-dir"), "{err}");
+
+    let planned = plan_run_cases_with_options(
+        "custom_sql",
+        RunnerMode::Rust,
+        None,
+        &CaseTagFilter::default(),
+        &SuiteOptions {
+            custom_sql_dir: Some(temp.path().to_path_buf()),
+            ..SuiteOptions::default()
+        },
+    )
+    .expect("plan");
+    assert_eq!(
+        planned
+            .iter()
+            .map(|case| case.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["custom_sql_count"]
+    );
+}
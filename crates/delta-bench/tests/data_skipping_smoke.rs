@@ -0,0 +1,81 @@
+use delta_bench::data::fixtures::{
+    data_skipping_files_matching, generate_fixtures, DataSkippingCardinality,
+    DATA_SKIPPING_FILE_COUNT,
+};
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::data_skipping;
+
+#[test]
+fn data_skipping_layout_narrows_with_cardinality() {
+    let rows = 10_000;
+    let files = [
+        DataSkippingCardinality::Low,
+        DataSkippingCardinality::Medium,
+        DataSkippingCardinality::High,
+    ]
+    .map(|cardinality| {
+        data_skipping_files_matching(rows, cardinality, cardinality.probe_value(rows))
+    });
+    assert_eq!(files, [16, 4, 1]);
+    assert!(files
+        .iter()
+        .all(|files| *files < DATA_SKIPPING_FILE_COUNT as u64));
+}
+
+#[tokio::test]
+async fn data_skipping_suite_reports_files_pruned_per_cardinality() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("fixtures should be generated");
+
+    let cases = data_skipping::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("data_skipping suite should run");
+
+    assert_eq!(cases.len(), 3);
+    for case in &cases {
+        assert!(case.success, "{} failure: {:?}", case.case, case.failure);
+    }
+
+    let report = data_skipping::pruning_report(&cases);
+    assert_eq!(
+        report
+            .iter()
+            .map(|point| (point.column.as_str(), point.files_scanned))
+            .collect::<Vec<_>>(),
+        vec![
+            ("card_low", Some(16)),
+            ("card_medium", Some(4)),
+            ("card_high", Some(1)),
+        ]
+    );
+    let ratios = report
+        .iter()
+        .map(|point| {
+            point
+                .files_pruned_ratio
+                .expect("files_pruned should be reported")
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        ratios.windows(2).all(|pair| pair[0] < pair[1]),
+        "pruning should improve with cardinality: {ratios:?}"
+    );
+}
+
+#[tokio::test]
+async fn data_skipping_suite_reports_fixture_error_without_table() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = data_skipping::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("data_skipping suite should return fixture errors");
+
+    assert_eq!(cases.len(), 3);
+    assert!(cases.iter().all(|case| !case.success));
+}
@@ -0,0 +1,72 @@
+use delta_bench::cli::BenchmarkLane;
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::data::row_cache::FixtureRowCache;
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::deletion_vectors::{self, DELETION_VECTORS_UNSUPPORTED};
+
+#[tokio::test]
+async fn deletion_vectors_suite_runs_copy_on_write_and_tracks_dv_support() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("fixtures should be generated");
+
+    let cases = deletion_vectors::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("deletion vectors suite should run");
+
+    assert_eq!(cases.len(), 4);
+    for case in &cases {
+        if case.case.ends_with("_copy_on_write") {
+            assert!(
+                case.success,
+                "copy-on-write case {} failed: {:?}",
+                case.case, case.failure
+            );
+            continue;
+        }
+        // Deletion-vector cases either succeed or fail with the tracked unsupported marker.
+        if let Some(failure) = case.failure.as_ref() {
+            assert!(
+                failure.message.contains(DELETION_VECTORS_UNSUPPORTED),
+                "unexpected deletion vector failure for {}: {}",
+                case.case,
+                failure.message
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn deletion_vectors_suite_reports_fixture_error_without_fixtures() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = deletion_vectors::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("deletion vectors suite should return fixture errors");
+
+    assert_eq!(cases.len(), 4);
+    assert!(cases.iter().all(|case| !case.success));
+}
@@ -1,4 +1,4 @@
-use delta_bench::data::generator::generate_narrow_sales_rows;
+use delta_bench::data::generator::{generate_narrow_sales_rows, DataDistribution, NarrowSalesRows};
 
 #[test]
 fn same_seed_produces_same_rows() {
@@ -13,3 +13,63 @@ fn different_seed_produces_different_rows() {
     let b = generate_narrow_sales_rows(43, 16);
     assert_ne!(a, b);
 }
+
+#[test]
+fn cloned_row_stream_replays_the_same_rows() {
+    let stream = NarrowSalesRows::new(42, 16);
+    assert_eq!(stream.len(), 16);
+    let replay = stream.clone().collect::<Vec<_>>();
+    assert_eq!(stream.collect::<Vec<_>>(), replay);
+    assert_eq!(replay, generate_narrow_sales_rows(42, 16));
+}
+
+#[test]
+fn uniform_distribution_keeps_the_default_rows() {
+    let rows = NarrowSalesRows::new(42, 64)
+        .with_distribution(&DataDistribution::default())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, generate_narrow_sales_rows(42, 64));
+}
+
+#[test]
+fn id_skew_repeats_hot_ids_and_null_probabilities_apply_per_column() {
+    let distribution = DataDistribution {
+        id_skew: 1.2,
+        flag_null_probability: 1.0,
+        ..DataDistribution::default()
+    };
+    let rows = NarrowSalesRows::new(42, 1_000)
+        .with_distribution(&distribution)
+        .collect::<Vec<_>>();
+    let hot_ids = rows.iter().filter(|row| row.id == 0).count();
+    assert!(hot_ids > 10, "id 0 drawn {hot_ids} times");
+    assert!(rows.iter().all(|row| (0..1_000).contains(&row.id)));
+    assert!(rows.iter().all(|row| row.flag.is_none()));
+    assert!(rows.iter().all(|row| row.value_i64.is_some()));
+}
+
+#[test]
+fn distribution_rejects_invalid_settings() {
+    let mut distribution = DataDistribution::default();
+    distribution
+        .set_null_probability("value_i64=0.25")
+        .expect("valid spec");
+    assert_eq!(distribution.value_i64_null_probability, 0.25);
+    assert!(distribution.validate().is_ok());
+
+    let err = distribution
+        .set_null_probability("region=0.1")
+        .expect_err("region stays non-null");
+    assert!(err.to_string().contains("cannot be null"), "{err}");
+    assert!(distribution.set_null_probability("flag").is_err());
+
+    distribution
+        .set_null_probability("flag=1.5")
+        .expect("parses");
+    assert!(distribution.validate().is_err());
+    let skewed = DataDistribution {
+        region_skew: -1.0,
+        ..DataDistribution::default()
+    };
+    assert!(skewed.validate().is_err());
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 #[path = "support/env_lock.rs"]
 mod env_lock_support;
 #[path = "support/env_vars.rs"]
@@ -6,12 +8,15 @@ mod env_vars_support;
 use delta_bench::assertions::CaseAssertion;
 use delta_bench::cli::{BenchmarkLane, RunnerMode, TimingPhase};
 use delta_bench::data::fixtures::{
-    generate_fixtures, generate_fixtures_with_profile, FixtureProfile,
+    generate_fixtures, generate_fixtures_with_profile, FixtureProfile, DEFAULT_WIDE_TABLE_COLUMNS,
 };
 use delta_bench::manifests::DatasetId;
+use delta_bench::runner::CaseBudget;
 use delta_bench::storage::StorageConfig;
 use delta_bench::suites::{
-    apply_dataset_assertion_policy, plan_run_cases, run_planned_cases, run_target, PlannedCase,
+    apply_dataset_assertion_policy, apply_fixture_assertion_policy, diff_case_sets,
+    plan_replay_case, plan_run_cases, plan_run_cases_with_tags, run_planned_cases, run_target,
+    CaseTagFilter, PlanDiff, PlannedCase, SuiteOptions,
 };
 
 use env_lock_support::env_lock;
@@ -23,12 +28,18 @@ fn planned_case(id: &str, target: &str, assertions: Vec<CaseAssertion>) -> Plann
         target: target.to_string(),
         lane: "macro".to_string(),
         assertions,
+        warn_assertions: Vec::new(),
         suite_manifest_hash: "sha256:manifest".to_string(),
         case_definition_hash: format!("sha256:{id}-def"),
         supports_decision: false,
         required_runs: None,
         decision_threshold_pct: None,
         decision_metric: None,
+        depends_on: Vec::new(),
+        budget: CaseBudget::default(),
+        tags: Vec::new(),
+        interop_workload: None,
+        matrix: None,
     }
 }
 
@@ -42,6 +53,137 @@ fn case_filter_requires_at_least_one_matching_case() {
     );
 }
 
+#[test]
+fn replay_case_plans_only_the_exact_case_id() {
+    let plan = plan_replay_case(
+        "all",
+        RunnerMode::Rust,
+        "scan_full_narrow",
+        &SuiteOptions::default(),
+    )
+    .expect("replay plan");
+    assert_eq!(
+        plan.iter().map(|case| case.id.as_str()).collect::<Vec<_>>(),
+        vec!["scan_full_narrow"]
+    );
+
+    let err = plan_replay_case(
+        "all",
+        RunnerMode::Rust,
+        "scan_full",
+        &SuiteOptions::default(),
+    )
+    .expect_err("a partial id must not select a replay case");
+    assert!(err.to_string().contains("replay case"), "{err}");
+}
+
+#[test]
+fn case_filter_pulls_in_dependencies_ahead_of_their_dependents() {
+    let plan = plan_run_cases("optimize_vacuum", RunnerMode::Rust, Some("vacuum_after"))
+        .expect("plan should build");
+    assert_eq!(
+        plan.iter().map(|case| case.id.as_str()).collect::<Vec<_>>(),
+        vec!["optimize_heavy_compaction", "vacuum_after_heavy_compaction"]
+    );
+    assert_eq!(plan[1].depends_on, vec!["optimize_heavy_compaction"]);
+
+    let replay = plan_replay_case(
+        "optimize_vacuum",
+        RunnerMode::Rust,
+        "vacuum_after_heavy_compaction",
+        &SuiteOptions::default(),
+    )
+    .expect("replay plan");
+    assert_eq!(
+        replay
+            .iter()
+            .map(|case| case.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["optimize_heavy_compaction", "vacuum_after_heavy_compaction"]
+    );
+}
+
+#[test]
+fn manifest_case_budgets_are_carried_into_the_plan() {
+    let plan = plan_run_cases("optimize_vacuum", RunnerMode::Rust, Some("vacuum_after"))
+        .expect("plan should build");
+    assert_eq!(
+        plan[0].budget,
+        CaseBudget {
+            warmup: Some(0),
+            iterations: Some(2),
+            timeout_ms: None,
+        }
+    );
+    assert!(plan[1].budget.is_empty());
+}
+
+#[test]
+fn tags_select_and_exclude_cases_but_keep_dependencies() {
+    let smoke = CaseTagFilter::new(&ids(&["smoke"]), &[]).expect("tag filter");
+    let plan =
+        plan_run_cases_with_tags("all", RunnerMode::Rust, None, &smoke).expect("plan should build");
+    assert_eq!(
+        plan.iter().map(|case| case.id.as_str()).collect::<Vec<_>>(),
+        vec![
+            "scan_full_narrow",
+            "write_append_small",
+            "merge_upsert_10pct",
+            "metadata_load"
+        ]
+    );
+    assert!(plan.iter().all(|case| case.tags == ids(&["smoke"])));
+
+    let not_slow = CaseTagFilter::new(&[], &ids(&["slow"])).expect("tag filter");
+    let plan = plan_run_cases_with_tags("all", RunnerMode::Rust, None, &not_slow)
+        .expect("plan should build");
+    let planned = plan.iter().map(|case| case.id.as_str()).collect::<Vec<_>>();
+    assert!(!planned.contains(&"tpcds_q64"));
+    assert!(planned.contains(&"scan_full_narrow"));
+
+    let plan = plan_run_cases_with_tags(
+        "optimize_vacuum",
+        RunnerMode::Rust,
+        Some("vacuum_after"),
+        &not_slow,
+    )
+    .expect("plan should build");
+    assert_eq!(
+        plan.iter().map(|case| case.id.as_str()).collect::<Vec<_>>(),
+        vec!["optimize_heavy_compaction", "vacuum_after_heavy_compaction"]
+    );
+
+    let err = plan_run_cases_with_tags(
+        "scan",
+        RunnerMode::Rust,
+        None,
+        &CaseTagFilter::new(&ids(&["no-such-tag"]), &[]).expect("tag filter"),
+    )
+    .expect_err("unknown tag matches nothing");
+    assert!(
+        err.to_string()
+            .contains("case filter and tags matched no cases"),
+        "{err}"
+    );
+}
+
+#[test]
+fn tag_filters_reject_malformed_and_conflicting_tags() {
+    let err = CaseTagFilter::new(&ids(&["Smoke"]), &[]).expect_err("uppercase tag");
+    assert!(err.to_string().contains("case tag 'Smoke'"), "{err}");
+
+    let err = CaseTagFilter::new(&ids(&["slow"]), &ids(&[" slow "])).expect_err("conflict");
+    assert!(
+        err.to_string()
+            .contains("tag 'slow' is both included and excluded"),
+        "{err}"
+    );
+
+    let filter = CaseTagFilter::new(&ids(&["smoke", "", "smoke"]), &[]).expect("tag filter");
+    assert_eq!(filter.include, ids(&["smoke"]));
+    assert!(CaseTagFilter::new(&[], &[]).expect("empty").is_empty());
+}
+
 #[test]
 fn all_runner_plan_includes_python_manifest_cases() {
     let plan = plan_run_cases("all", RunnerMode::All, None).expect("plan should build");
@@ -75,6 +217,72 @@ fn all_runner_plan_is_manifest_ordered() {
     );
 }
 
+fn ids(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn plan_diff_reports_added_removed_and_renamed_cases() {
+    let previous = ids(&["scan_full_narrow", "scan_pruning_hit", "merge_upsert_10pct"]);
+    let planned = ids(&[
+        "scan_full_narrow",
+        "scan_selectivity_10pct",
+        "convert_to_delta_partitioned",
+    ]);
+    let aliases = BTreeMap::from([(
+        "scan_pruning_hit".to_string(),
+        "scan_selectivity_10pct".to_string(),
+    )]);
+
+    assert_eq!(
+        diff_case_sets(&previous, &planned, &aliases),
+        PlanDiff {
+            added: ids(&["convert_to_delta_partitioned"]),
+            removed: ids(&["merge_upsert_10pct"]),
+            renamed: vec![(
+                "scan_pruning_hit".to_string(),
+                "scan_selectivity_10pct".to_string(),
+            )],
+        }
+    );
+}
+
+#[test]
+fn plan_diff_does_not_infer_renames_from_similar_ids() {
+    let previous = ids(&["restore_mid_history_24_versions", "scan_pruning_hit"]);
+    let planned = ids(&["restore_mid_history_12_versions", "scan_selectivity_1pct"]);
+
+    let diff = diff_case_sets(&previous, &planned, &BTreeMap::new());
+    assert!(diff.renamed.is_empty(), "unexpected renames: {diff:?}");
+    assert_eq!(diff.removed, previous);
+    assert_eq!(diff.added, planned);
+}
+
+#[test]
+fn plan_diff_ignores_aliases_whose_target_is_not_planned() {
+    let previous = ids(&["scan_pruning_hit"]);
+    let planned = ids(&["scan_selectivity_1pct"]);
+    let aliases = BTreeMap::from([(
+        "scan_pruning_hit".to_string(),
+        "scan_selectivity_10pct".to_string(),
+    )]);
+
+    assert_eq!(
+        diff_case_sets(&previous, &planned, &aliases),
+        PlanDiff {
+            added: planned.clone(),
+            removed: previous.clone(),
+            renamed: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn plan_diff_is_empty_for_identical_case_sets() {
+    let cases = ids(&["scan_full_narrow", "scan_filter_flag"]);
+    assert!(diff_case_sets(&cases, &cases, &BTreeMap::new()).is_empty());
+}
+
 #[test]
 fn write_perf_case_filter_can_select_single_scenario() {
     let plan = plan_run_cases(
@@ -367,19 +575,24 @@ fn medium_selective_dataset_policy_drops_exact_hash_for_non_default_scan_cases()
         vec![
             CaseAssertion::ExactResultHash("sha256:expected".to_string()),
             CaseAssertion::SchemaHash("sha256:schema".to_string()),
+            CaseAssertion::RowCountEquals(10_000),
         ],
     )];
+    planned[0].warn_assertions = vec![CaseAssertion::ExactResultHash(
+        "sha256:observed".to_string(),
+    )];
 
     apply_dataset_assertion_policy(&mut planned, Some(DatasetId::MediumSelective));
 
     assert_eq!(planned.len(), 1);
     assert_eq!(planned[0].assertions.len(), 1);
+    assert!(planned[0].warn_assertions.is_empty());
     assert!(
-        planned[0]
-            .assertions
-            .iter()
-            .all(|assertion| !matches!(assertion, CaseAssertion::ExactResultHash(_))),
-        "non-default datasets should not enforce authoritative exact-result hashes"
+        planned[0].assertions.iter().all(|assertion| !matches!(
+            assertion,
+            CaseAssertion::ExactResultHash(_) | CaseAssertion::RowCountEquals(_)
+        )),
+        "non-default datasets should not enforce authoritative exact-result hashes or row counts"
     );
     assert!(
         planned[0]
@@ -390,6 +603,44 @@ fn medium_selective_dataset_policy_drops_exact_hash_for_non_default_scan_cases()
     );
 }
 
+#[test]
+fn non_default_wide_table_columns_relax_the_wide_scan_hashes() {
+    let hashes = || {
+        vec![
+            CaseAssertion::ExactResultHash("sha256:expected".to_string()),
+            CaseAssertion::SchemaHash("sha256:schema".to_string()),
+        ]
+    };
+    let plan = || {
+        vec![
+            planned_case("wide_table_full_scan", "wide_table", hashes()),
+            planned_case("wide_table_projection_3col", "wide_table", hashes()),
+            planned_case("wide_table_append", "wide_table", hashes()),
+            planned_case("scan_full_narrow", "scan", hashes()),
+        ]
+    };
+
+    let mut planned = plan();
+    apply_fixture_assertion_policy(&mut planned, Some(DEFAULT_WIDE_TABLE_COLUMNS));
+    assert!(planned.iter().all(|case| case.assertions.len() == 2));
+
+    let mut planned = plan();
+    apply_fixture_assertion_policy(&mut planned, Some(64));
+    let assertion_counts = planned
+        .iter()
+        .map(|case| (case.id.as_str(), case.assertions.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        assertion_counts,
+        vec![
+            ("wide_table_full_scan", 0),
+            ("wide_table_projection_3col", 0),
+            ("wide_table_append", 2),
+            ("scan_full_narrow", 2),
+        ]
+    );
+}
+
 #[test]
 fn tiny_smoke_dataset_policy_keeps_exact_hash_assertions() {
     let mut planned = vec![planned_case(
@@ -3,10 +3,14 @@ mod env_lock_support;
 #[path = "support/env_vars.rs"]
 mod env_vars_support;
 
+use std::collections::BTreeMap;
+
 use delta_bench::cli::BenchmarkLane;
 use delta_bench::data::fixtures::{
     generate_fixtures, generate_fixtures_with_profile, FixtureProfile,
 };
+use delta_bench::data::row_cache::FixtureRowCache;
+use delta_bench::runner::CaseHooks;
 use delta_bench::storage::StorageConfig;
 use delta_bench::suites::{concurrency, delete_update, interop_py, merge, optimize_vacuum, write};
 
@@ -17,9 +21,18 @@ use env_vars_support::with_env_vars;
 async fn write_suite_missing_fixtures_returns_case_failures() {
     let temp = tempfile::tempdir().expect("tempdir");
     let storage = StorageConfig::local();
-    let cases = write::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("suite should not hard-fail");
+    let cases = write::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("suite should not hard-fail");
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| !c.success));
 }
@@ -35,9 +48,18 @@ async fn write_suite_non_local_backend_returns_explicit_failures() {
     let storage = StorageConfig::new(delta_bench::cli::StorageBackend::S3, options)
         .expect("valid s3 storage config");
 
-    let cases = write::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("suite should not hard-fail");
+    let cases = write::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("suite should not hard-fail");
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| !c.success));
     for case in cases {
@@ -63,7 +85,7 @@ async fn concurrency_suite_non_local_backend_returns_explicit_failures() {
     let storage = StorageConfig::new(delta_bench::cli::StorageBackend::S3, options)
         .expect("valid s3 storage config");
 
-    let cases = concurrency::run(temp.path(), "sf1", 0, 1, &storage)
+    let cases = concurrency::run(temp.path(), "sf1", 0, 1, &storage, &FixtureRowCache::new())
         .await
         .expect("suite should not hard-fail");
     assert!(!cases.is_empty());
@@ -84,9 +106,19 @@ async fn concurrency_suite_non_local_backend_returns_explicit_failures() {
 async fn merge_suite_missing_fixtures_returns_case_failures() {
     let temp = tempfile::tempdir().expect("tempdir");
     let storage = StorageConfig::local();
-    let cases = merge::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("suite should not hard-fail");
+    let cases = merge::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+        &BTreeMap::new(),
+    )
+    .await
+    .expect("suite should not hard-fail");
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| !c.success));
 }
@@ -105,9 +137,19 @@ async fn merge_suite_missing_partitioned_fixture_returns_fixture_failures_for_al
     )
     .expect("remove partitioned merge fixture");
 
-    let cases = merge::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("suite should not hard-fail");
+    let cases = merge::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+        &BTreeMap::new(),
+    )
+    .await
+    .expect("suite should not hard-fail");
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| !c.success));
     for case in cases {
@@ -127,9 +169,18 @@ async fn merge_suite_missing_partitioned_fixture_returns_fixture_failures_for_al
 async fn optimize_vacuum_suite_missing_fixtures_returns_case_failures() {
     let temp = tempfile::tempdir().expect("tempdir");
     let storage = StorageConfig::local();
-    let cases = optimize_vacuum::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("suite should not hard-fail");
+    let cases = optimize_vacuum::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("suite should not hard-fail");
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| !c.success));
 }
@@ -138,9 +189,18 @@ async fn optimize_vacuum_suite_missing_fixtures_returns_case_failures() {
 async fn delete_update_suite_missing_fixtures_returns_case_failures() {
     let temp = tempfile::tempdir().expect("tempdir");
     let storage = StorageConfig::local();
-    let cases = delete_update::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("suite should not hard-fail");
+    let cases = delete_update::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("suite should not hard-fail");
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| !c.success));
 }
@@ -161,10 +221,10 @@ async fn concurrency_suite_missing_contended_fixtures_returns_targeted_case_fail
     std::fs::remove_dir_all(temp.path().join("sf1").join("optimize_small_files_delta"))
         .expect("remove optimize fixture");
 
-    let cases = concurrency::run(temp.path(), "sf1", 0, 1, &storage)
+    let cases = concurrency::run(temp.path(), "sf1", 0, 1, &storage, &FixtureRowCache::new())
         .await
         .expect("suite should not hard-fail");
-    assert_eq!(cases.len(), 5);
+    assert_eq!(cases.len(), 10);
 
     let create_case = cases
         .iter()
@@ -172,16 +232,25 @@ async fn concurrency_suite_missing_contended_fixtures_returns_targeted_case_fail
         .expect("create case should be present");
     assert!(create_case.success, "create case should still succeed");
 
-    let append_case = cases
-        .iter()
-        .find(|case| case.case == "concurrent_append_multi")
-        .expect("append case should be present");
-    assert!(append_case.success, "append case should still succeed");
+    for case_name in [
+        "concurrent_append_multi",
+        "concurrent_append_writers_2",
+        "concurrent_append_writers_4",
+        "concurrent_append_writers_8",
+        "mixed_oltp_like",
+    ] {
+        let append_case = cases
+            .iter()
+            .find(|case| case.case == case_name)
+            .unwrap_or_else(|| panic!("missing concurrency case '{case_name}'"));
+        assert!(append_case.success, "{case_name} should still succeed");
+    }
 
     for case_name in [
         "update_vs_compaction",
         "delete_vs_compaction",
         "optimize_vs_optimize_overlap",
+        "optimize_vs_append_ingest",
     ] {
         let case = cases
             .iter()
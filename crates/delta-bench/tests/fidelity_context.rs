@@ -1,6 +1,10 @@
 use chrono::Utc;
-use delta_bench::results::BenchContext;
-use delta_bench::system::{benchmark_fidelity_info, FidelityEnvOverrides};
+use delta_bench::results::{BenchContext, FixtureTableStats};
+use delta_bench::system::{
+    benchmark_fidelity_info, datafusion_session_config, datafusion_version, FidelityEnvOverrides,
+    DATAFUSION_CONFIG_KEYS,
+};
+use std::collections::BTreeMap;
 use std::fs;
 
 #[test]
@@ -9,6 +13,7 @@ fn bench_context_serializes_optional_fidelity_fields() {
         schema_version: 5,
         label: "local".to_string(),
         git_sha: Some("deadbeef".to_string()),
+        git_dirty: None,
         created_at: Utc::now(),
         host: "runner-01".to_string(),
         suite: "all".to_string(),
@@ -26,7 +31,21 @@ fn bench_context_serializes_optional_fidelity_fields() {
         validation_level: None,
         run_id: None,
         harness_revision: None,
+        harness_dirty: None,
         fixture_recipe_hash: Some("sha256:recipe".to_string()),
+        fixture_tables: vec![FixtureTableStats {
+            table: "narrow_sales_delta".to_string(),
+            rows: 10_000,
+            files: 4,
+            bytes: Some(412_672),
+        }],
+        fixtures_dir: Some("/srv/bench/fixtures".to_string()),
+        results_dir: Some("/srv/bench/results".to_string()),
+        datafusion_version: Some("49.0.0".to_string()),
+        datafusion_config: BTreeMap::from([(
+            "datafusion.execution.target_partitions".to_string(),
+            "8".to_string(),
+        )]),
         fidelity_fingerprint: Some("sha256:fidelity".to_string()),
         backend_profile: Some("local".to_string()),
         image_version: Some("image-2026-02-27".to_string()),
@@ -41,6 +60,7 @@ fn bench_context_serializes_optional_fidelity_fields() {
         egress_policy_sha256: Some("egress-sha".to_string()),
         run_mode: Some("run-mode".to_string()),
         maintenance_window_id: Some("weekly-sat-0200z".to_string()),
+        ref_issues: vec!["delta-io/delta-rs#1234".to_string()],
     };
 
     let raw = serde_json::to_value(ctx).expect("serialize bench context");
@@ -54,6 +74,11 @@ fn bench_context_serializes_optional_fidelity_fields() {
         "storage_backend",
         "benchmark_mode",
         "fixture_recipe_hash",
+        "fixture_tables",
+        "fixtures_dir",
+        "results_dir",
+        "datafusion_version",
+        "datafusion_config",
         "fidelity_fingerprint",
         "cpu_model",
         "cpu_microcode",
@@ -64,9 +89,16 @@ fn bench_context_serializes_optional_fidelity_fields() {
         "egress_policy_sha256",
         "run_mode",
         "maintenance_window_id",
+        "ref_issues",
     ] {
         assert!(obj.contains_key(key), "missing key: {key}");
     }
+    assert_eq!(
+        obj["fixture_tables"],
+        serde_json::json!([
+            {"table": "narrow_sales_delta", "rows": 10_000, "files": 4, "bytes": 412_672}
+        ])
+    );
 }
 
 #[test]
@@ -100,3 +132,19 @@ fn fidelity_info_uses_overrides_for_hash_and_mode_fields() {
     assert_eq!(info.run_mode.as_deref(), Some("run-mode"));
     assert_eq!(info.maintenance_window_id.as_deref(), Some("mw-1"));
 }
+
+#[test]
+fn datafusion_session_config_records_every_tracked_option() {
+    assert!(!datafusion_version().is_empty());
+    let config = datafusion_session_config();
+    let mut tracked = DATAFUSION_CONFIG_KEYS.to_vec();
+    tracked.sort_unstable();
+    assert_eq!(
+        config.keys().map(String::as_str).collect::<Vec<_>>(),
+        tracked
+    );
+    let target_partitions = config["datafusion.execution.target_partitions"]
+        .parse::<usize>()
+        .expect("target_partitions is a count");
+    assert!(target_partitions > 0);
+}
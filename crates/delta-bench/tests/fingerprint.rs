@@ -1,4 +1,5 @@
-use delta_bench::fingerprint::hash_arrow_schema;
+use delta_bench::data::generator::generate_narrow_sales_rows;
+use delta_bench::fingerprint::{hash_arrow_schema, hash_json, hash_json_seq};
 use deltalake_core::arrow::datatypes::{DataType, Field, Schema};
 
 #[test]
@@ -28,3 +29,16 @@ fn hash_arrow_schema_changes_when_schema_changes() {
     let right_hash = hash_arrow_schema(&right).expect("hash should succeed");
     assert_ne!(left_hash, right_hash);
 }
+
+#[test]
+fn hash_json_seq_matches_hash_json_of_collected_items() {
+    let rows = generate_narrow_sales_rows(42, 300);
+    assert_eq!(
+        hash_json_seq(rows.iter()).expect("streamed hash"),
+        hash_json(&rows).expect("collected hash")
+    );
+    assert_eq!(
+        hash_json_seq(Vec::<u64>::new()).expect("empty streamed hash"),
+        hash_json(&Vec::<u64>::new()).expect("empty collected hash")
+    );
+}
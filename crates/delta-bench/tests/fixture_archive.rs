@@ -0,0 +1,148 @@
+use std::io::Read;
+use std::path::Path;
+
+use delta_bench::data::archive::{
+    export_fixture_archive, import_fixture_archive, FixtureArchiveIndex, FIXTURE_ARCHIVE_INDEX,
+};
+use delta_bench::data::fixtures::{generate_fixtures, load_manifest, load_rows};
+use delta_bench::storage::StorageConfig;
+
+#[tokio::test]
+async fn exported_archive_imports_into_an_identical_fixture_tree() {
+    let source = tempfile::tempdir().expect("tempdir");
+    let target = tempfile::tempdir().expect("tempdir");
+    generate_fixtures(source.path(), "sf1", 42, true, &StorageConfig::local())
+        .await
+        .expect("generate fixtures");
+    let archive = source.path().join("archives/fixtures-sf1.tar.zst");
+
+    let exported =
+        export_fixture_archive(source.path(), "sf1", None, &archive).expect("export archive");
+    let manifest = load_manifest(source.path(), "sf1").expect("load manifest");
+    assert_eq!(exported.scale, "sf1");
+    assert_eq!(exported.dataset_fingerprint, manifest.dataset_fingerprint);
+    assert!(exported
+        .files
+        .iter()
+        .any(|file| file.path == "narrow_sales_delta/_delta_log/00000000000000000000.json"));
+
+    let imported = import_fixture_archive(target.path(), &archive, false, false)
+        .await
+        .expect("import archive");
+    assert_eq!(imported, exported);
+    let imported_manifest = load_manifest(target.path(), "sf1").expect("load imported manifest");
+    assert_eq!(
+        imported_manifest.dataset_fingerprint,
+        manifest.dataset_fingerprint
+    );
+    assert_eq!(
+        load_rows(target.path(), "sf1").expect("imported rows"),
+        load_rows(source.path(), "sf1").expect("source rows")
+    );
+
+    let err = import_fixture_archive(target.path(), &archive, false, false)
+        .await
+        .expect_err("existing fixtures");
+    assert!(err.to_string().contains("pass --force"), "{err}");
+    import_fixture_archive(target.path(), &archive, true, false)
+        .await
+        .expect("forced import");
+}
+
+#[tokio::test]
+async fn import_rejects_files_that_differ_from_their_checksum() {
+    let source = tempfile::tempdir().expect("tempdir");
+    let target = tempfile::tempdir().expect("tempdir");
+    generate_fixtures(source.path(), "sf1", 42, true, &StorageConfig::local())
+        .await
+        .expect("generate fixtures");
+    let archive = source.path().join("fixtures-sf1.tar.zst");
+    export_fixture_archive(source.path(), "sf1", None, &archive).expect("export archive");
+
+    let tampered = source.path().join("tampered.tar.zst");
+    rewrite_archive(&archive, &tampered, |path, data| {
+        if path == "sf1/manifest.json" {
+            data.extend_from_slice(b"\n");
+        }
+    });
+    let err = import_fixture_archive(target.path(), &tampered, false, false)
+        .await
+        .expect_err("tampered manifest");
+    assert!(
+        err.to_string()
+            .contains("checksum mismatch for 'manifest.json'"),
+        "{err}"
+    );
+    assert!(
+        !target.path().join("sf1").exists(),
+        "a rejected archive leaves no fixtures behind"
+    );
+
+    let escaping = source.path().join("escaping.tar.zst");
+    rewrite_archive(&archive, &escaping, |_, _| {});
+    append_entry(&escaping, "sf10/manifest.json", b"{}");
+    let err = import_fixture_archive(target.path(), &escaping, false, false)
+        .await
+        .expect_err("entry outside the scale");
+    assert!(
+        err.to_string().contains("outside the sf1/ directory"),
+        "{err}"
+    );
+}
+
+/// Copies `from` to `to`, letting `edit` change each entry's bytes; `archive.json` is kept.
+fn rewrite_archive(from: &Path, to: &Path, mut edit: impl FnMut(&str, &mut Vec<u8>)) {
+    let entries = read_entries(from);
+    write_entries(
+        to,
+        entries.into_iter().map(|(path, mut data)| {
+            if path != FIXTURE_ARCHIVE_INDEX {
+                edit(&path, &mut data);
+            }
+            (path, data)
+        }),
+    );
+}
+
+fn append_entry(archive: &Path, path: &str, data: &[u8]) {
+    let mut entries = read_entries(archive);
+    entries.push((path.to_string(), data.to_vec()));
+    write_entries(archive, entries);
+}
+
+fn read_entries(archive: &Path) -> Vec<(String, Vec<u8>)> {
+    let file = std::fs::File::open(archive).expect("open archive");
+    let mut archive = tar::Archive::new(zstd::Decoder::new(file).expect("zstd decoder"));
+    let entries = archive
+        .entries()
+        .expect("entries")
+        .map(|entry| {
+            let mut entry = entry.expect("entry");
+            let path = entry.path().expect("path").to_string_lossy().into_owned();
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).expect("read entry");
+            (path, data)
+        })
+        .collect::<Vec<_>>();
+    let index = serde_json::from_slice::<FixtureArchiveIndex>(&entries[0].1).expect("index");
+    assert_eq!(index.files.len() + 1, entries.len());
+    entries
+}
+
+fn write_entries(archive: &Path, entries: impl IntoIterator<Item = (String, Vec<u8>)>) {
+    let file = std::fs::File::create(archive).expect("create archive");
+    let mut builder = tar::Builder::new(zstd::Encoder::new(file, 0).expect("zstd encoder"));
+    for (path, data) in entries {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        builder
+            .append_data(&mut header, path, data.as_slice())
+            .expect("append entry");
+    }
+    builder
+        .into_inner()
+        .expect("finish tar")
+        .finish()
+        .expect("finish zstd");
+}
@@ -0,0 +1,91 @@
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::fixture_copy::{copy_dir_with, FixtureCopyStrategy};
+
+fn write_fixture_tree(root: &std::path::Path) {
+    fs::create_dir_all(root.join("_delta_log")).expect("create log dir");
+    fs::write(
+        root.join("_delta_log/00000000000000000000.json"),
+        b"{\"commitInfo\":{}}\n",
+    )
+    .expect("write commit");
+    fs::write(root.join("part-00000.parquet"), b"parquet bytes").expect("write data file");
+}
+
+#[test]
+fn fixture_copy_strategy_parses_known_values() {
+    for strategy in [
+        FixtureCopyStrategy::Auto,
+        FixtureCopyStrategy::Reflink,
+        FixtureCopyStrategy::Hardlink,
+        FixtureCopyStrategy::Copy,
+    ] {
+        assert_eq!(
+            FixtureCopyStrategy::parse(strategy.as_str()).expect("known strategy"),
+            strategy
+        );
+    }
+    assert_eq!(FixtureCopyStrategy::default(), FixtureCopyStrategy::Auto);
+    assert_eq!(
+        StorageConfig::local().fixture_copy(),
+        FixtureCopyStrategy::Auto
+    );
+    assert_eq!(
+        StorageConfig::local()
+            .with_fixture_copy(FixtureCopyStrategy::Hardlink)
+            .fixture_copy(),
+        FixtureCopyStrategy::Hardlink
+    );
+
+    let err = FixtureCopyStrategy::parse("symlink").expect_err("unknown strategy");
+    assert!(err.to_string().contains("fixture copy strategy"), "{err}");
+}
+
+#[test]
+fn every_portable_strategy_reproduces_the_fixture_tree() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let source = temp.path().join("source");
+    write_fixture_tree(&source);
+
+    for strategy in [
+        FixtureCopyStrategy::Auto,
+        FixtureCopyStrategy::Hardlink,
+        FixtureCopyStrategy::Copy,
+    ] {
+        let dest = temp.path().join(strategy.as_str());
+        copy_dir_with(&source, &dest, strategy).expect("copy fixture tree");
+        assert_eq!(
+            fs::read(dest.join("_delta_log/00000000000000000000.json")).expect("read commit"),
+            b"{\"commitInfo\":{}}\n"
+        );
+        assert_eq!(
+            fs::read(dest.join("part-00000.parquet")).expect("read data file"),
+            b"parquet bytes"
+        );
+    }
+}
+
+#[test]
+fn hardlink_strategy_shares_inodes_and_copy_does_not() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let source = temp.path().join("source");
+    write_fixture_tree(&source);
+    let source_inode = fs::metadata(source.join("part-00000.parquet"))
+        .expect("source metadata")
+        .ino();
+
+    let linked = temp.path().join("linked");
+    copy_dir_with(&source, &linked, FixtureCopyStrategy::Hardlink).expect("hard-link tree");
+    let copied = temp.path().join("copied");
+    copy_dir_with(&source, &copied, FixtureCopyStrategy::Copy).expect("copy tree");
+
+    let inode = |root: &std::path::Path| {
+        fs::metadata(root.join("part-00000.parquet"))
+            .expect("metadata")
+            .ino()
+    };
+    assert_eq!(inode(&linked), source_inode);
+    assert_ne!(inode(&copied), source_inode);
+}
@@ -4,11 +4,21 @@
 mod env_vars;
 
 use delta_bench::data::fixtures::{
-    generate_fixtures, generate_fixtures_with_profile, load_manifest, narrow_sales_table_url,
-    FixtureProfile,
+    dataset_fixtures_dir, export_rows_jsonl, fixture_small_files_count, fixture_wide_table_columns,
+    generate_fixtures, generate_fixtures_for_scales, generate_fixtures_with_profile, load_manifest,
+    load_row_batches, load_rows, narrow_sales_table_url, parse_scale_list, tpcds_table_path,
+    verify_fixture_manifest, verify_fixture_tables, FixtureProfile, FixtureScaleTimings,
+    FixtureShape, FixtureTableCheck, SmallFilesShape,
 };
+use delta_bench::data::generator::{generate_narrow_sales_rows, DataDistribution};
+use delta_bench::data::row_cache::FixtureRowCache;
+use delta_bench::error::BenchResult;
+use delta_bench::manifests::DatasetId;
 use delta_bench::storage::StorageConfig;
-use std::sync::{Mutex, OnceLock};
+use deltalake_core::datafusion::prelude::SessionContext;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+use url::Url;
 
 #[tokio::test]
 async fn regenerates_when_requested_seed_changes_without_force() {
@@ -28,6 +38,29 @@ async fn regenerates_when_requested_seed_changes_without_force() {
     assert_eq!(second.seed, 43);
 }
 
+#[tokio::test]
+async fn datasets_sharing_a_scale_keep_separate_fixture_roots() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    let tiny_smoke = dataset_fixtures_dir(temp.path(), Some(DatasetId::TinySmoke));
+    let small_files = dataset_fixtures_dir(temp.path(), Some(DatasetId::SmallFiles));
+    assert_eq!(tiny_smoke, temp.path().join("tiny_smoke"));
+    assert_eq!(small_files, temp.path().join("small_files"));
+    assert_eq!(dataset_fixtures_dir(temp.path(), None), temp.path());
+
+    generate_fixtures(&tiny_smoke, "sf1", 42, false, &storage)
+        .await
+        .expect("generate tiny_smoke fixtures");
+    generate_fixtures(&small_files, "sf1", 43, false, &storage)
+        .await
+        .expect("generate small_files fixtures");
+
+    let tiny_smoke_manifest = load_manifest(&tiny_smoke, "sf1").expect("tiny_smoke manifest");
+    let small_files_manifest = load_manifest(&small_files, "sf1").expect("small_files manifest");
+    assert_eq!(tiny_smoke_manifest.seed, 42);
+    assert_eq!(small_files_manifest.seed, 43);
+}
+
 #[tokio::test]
 async fn rejects_unknown_scale() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -108,6 +141,73 @@ async fn regenerates_when_wave1_fixture_tables_are_missing_without_force() {
     }
 }
 
+#[tokio::test]
+async fn fixture_rows_round_trip_through_parquet_snapshot() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+
+    let dataset_dir = temp.path().join("sf1").join("narrow_sales");
+    assert!(dataset_dir.join("rows.parquet").exists());
+    assert!(
+        !dataset_dir.join("rows.jsonl").exists(),
+        "JSONL rows are a debug export only"
+    );
+    let rows = load_rows(temp.path(), "sf1").expect("load rows");
+    assert_eq!(rows, generate_narrow_sales_rows(42, 10_000));
+    let batched_rows = load_row_batches(temp.path(), "sf1")
+        .expect("open row batches")
+        .map(|batch| batch.expect("row batch").num_rows())
+        .sum::<usize>();
+    assert_eq!(batched_rows, rows.len());
+
+    let exported = export_rows_jsonl(temp.path(), "sf1").expect("export rows");
+    assert_eq!(exported, dataset_dir.join("rows.jsonl"));
+    let exported = std::fs::read_to_string(exported).expect("read export");
+    assert_eq!(exported.lines().count(), rows.len());
+
+    // A fixture set from before the Parquet snapshot regenerates without --force.
+    std::fs::remove_file(dataset_dir.join("rows.parquet")).expect("remove rows.parquet");
+    generate_fixtures(temp.path(), "sf1", 42, false, &storage)
+        .await
+        .expect("regenerate fixtures");
+    assert_eq!(load_rows(temp.path(), "sf1").expect("reload rows"), rows);
+}
+
+#[tokio::test]
+async fn fixture_row_cache_reads_the_rows_once_per_scale() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+    let rows_file = temp
+        .path()
+        .join("sf1")
+        .join("narrow_sales")
+        .join("rows.parquet");
+
+    let cache = FixtureRowCache::new();
+    let first = cache.load(temp.path(), "sf1").expect("first load");
+    std::fs::remove_file(&rows_file).expect("remove rows.parquet");
+    let second = cache.load(temp.path(), "sf1").expect("cached load");
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(*first, generate_narrow_sales_rows(42, 10_000));
+    assert!(Arc::ptr_eq(
+        &cache
+            .clone()
+            .load(temp.path(), "sf1")
+            .expect("load from a clone of the cache"),
+        &first
+    ));
+    assert!(
+        FixtureRowCache::new().load(temp.path(), "sf1").is_err(),
+        "a fresh cache reads the rows again"
+    );
+}
+
 #[tokio::test]
 async fn generates_tpcds_store_sales_fixture_table() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -130,6 +230,45 @@ async fn generates_tpcds_store_sales_fixture_table() {
     );
 }
 
+#[tokio::test]
+async fn generated_tpcds_dimension_tables_cover_store_sales_keys() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+
+    let ctx = SessionContext::new();
+    for table in ["store_sales", "date_dim", "item", "customer"] {
+        let url = Url::from_directory_path(tpcds_table_path(temp.path(), "sf1", table))
+            .expect("table url");
+        let delta = storage.open_table(url).await.expect("open TPC-DS table");
+        ctx.register_table(table, delta.table_provider().await.expect("provider"))
+            .expect("register table");
+    }
+
+    let batches = ctx
+        .sql(
+            "SELECT COUNT(*) FROM store_sales \
+             JOIN date_dim ON ss_sold_date_sk = d_date_sk \
+             JOIN item ON ss_item_sk = i_item_sk \
+             JOIN customer ON ss_customer_sk = c_customer_sk",
+        )
+        .await
+        .expect("plan join")
+        .collect()
+        .await
+        .expect("collect join");
+    let joined = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<deltalake_core::arrow::array::Int64Array>()
+        .expect("count column")
+        .value(0);
+    assert_eq!(joined, 10_000);
+}
+
 #[tokio::test]
 async fn many_versions_profile_writes_multiple_narrow_sales_table_versions() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -218,6 +357,111 @@ path.write_text(
     assert!(manifest.generator_version > 0);
 }
 
+#[test]
+fn parses_comma_separated_scale_lists() {
+    assert_eq!(
+        parse_scale_list("sf1,sf10").expect("scale list"),
+        vec!["sf1".to_string(), "sf10".to_string()]
+    );
+    assert_eq!(
+        parse_scale_list("sf100").expect("single scale"),
+        vec!["sf100".to_string()]
+    );
+    let err = parse_scale_list("sf1,sf1").expect_err("duplicate scale should fail");
+    assert!(err.to_string().contains("duplicate scale"), "{err}");
+    let err = parse_scale_list("sf1,sf-nope").expect_err("unknown scale should fail");
+    assert!(err.to_string().contains("unknown scale"), "{err}");
+}
+
+#[test]
+fn smaller_scale_rows_are_a_prefix_of_larger_scale_rows() {
+    let small = generate_narrow_sales_rows(42, 1_000);
+    let large = generate_narrow_sales_rows(42, 10_000);
+    assert_eq!(small.as_slice(), &large[..small.len()]);
+}
+
+#[tokio::test]
+async fn multi_scale_generation_matches_single_scale_fingerprint() {
+    let single = tempfile::tempdir().expect("tempdir");
+    let shared = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(single.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate single-scale fixtures");
+    generate_fixtures_for_scales(
+        shared.path(),
+        &["sf1".to_string()],
+        42,
+        true,
+        false,
+        FixtureProfile::Standard,
+        &DataDistribution::default(),
+        &FixtureShape::default(),
+        &storage,
+    )
+    .await
+    .expect("generate multi-scale fixtures");
+
+    let single = load_manifest(single.path(), "sf1").expect("load single manifest");
+    let shared = load_manifest(shared.path(), "sf1").expect("load shared manifest");
+    assert_eq!(single.dataset_fingerprint, shared.dataset_fingerprint);
+    assert_eq!(single.fixture_recipe_hash, shared.fixture_recipe_hash);
+}
+
+#[tokio::test]
+async fn sequential_and_parallel_generation_match_and_report_table_timings() {
+    let _env_lock = env_lock();
+    let sequential = tempfile::tempdir().expect("tempdir");
+    let parallel = tempfile::tempdir().expect("tempdir");
+
+    with_env_var("DELTA_BENCH_FIXTURE_PARALLELISM", "1", || async {
+        generate_standard_sf1(sequential.path(), true)
+            .await
+            .expect("generate sequentially");
+    })
+    .await;
+    let timings = env_vars::with_env_vars(&[("DELTA_BENCH_FIXTURE_PARALLELISM", "8")], || {
+        generate_standard_sf1(parallel.path(), true)
+    })
+    .await
+    .expect("generate in parallel");
+
+    let sequential_manifest = load_manifest(sequential.path(), "sf1").expect("load manifest");
+    let parallel_manifest = load_manifest(parallel.path(), "sf1").expect("load manifest");
+    assert_eq!(
+        sequential_manifest.dataset_fingerprint,
+        parallel_manifest.dataset_fingerprint
+    );
+    assert_eq!(timings.len(), 1);
+    assert_eq!(timings[0].scale, "sf1");
+    for table in &parallel_manifest.table_inventory {
+        assert!(
+            timings[0]
+                .tables
+                .iter()
+                .any(|timing| timing.table.split(", ").any(|name| name == table)),
+            "no timing for {table}"
+        );
+    }
+
+    let cached = generate_standard_sf1(parallel.path(), false)
+        .await
+        .expect("reuse fixtures");
+    assert!(cached.is_empty(), "up-to-date scales report no timings");
+
+    let err = env_vars::with_env_vars(&[("DELTA_BENCH_FIXTURE_PARALLELISM", "0")], || {
+        generate_standard_sf1(parallel.path(), true)
+    })
+    .await
+    .expect_err("zero parallelism");
+    assert!(
+        err.to_string()
+            .contains("DELTA_BENCH_FIXTURE_PARALLELISM must be > 0"),
+        "{err}"
+    );
+}
+
 #[tokio::test]
 async fn fixture_manifest_records_stable_dataset_fingerprint() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -238,6 +482,140 @@ async fn fixture_manifest_records_stable_dataset_fingerprint() {
     assert!(!first.table_inventory.is_empty());
 }
 
+#[tokio::test]
+async fn fixture_manifest_summarizes_tables_and_verifies_requested_dataset() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+    let manifest = load_manifest(temp.path(), "sf1").expect("load manifest");
+    assert!(!manifest.rows_hash.is_empty());
+    let narrow = manifest
+        .table_summaries
+        .iter()
+        .find(|summary| summary.table == "narrow_sales_delta")
+        .expect("narrow_sales_delta summary");
+    assert_eq!(narrow.rows, manifest.rows as u64);
+    assert!(narrow.files > 0);
+    assert!(narrow.bytes.is_some_and(|bytes| bytes > 0), "{narrow:?}");
+    assert!(manifest
+        .table_summaries
+        .iter()
+        .all(|summary| summary.table != "raw_parquet_partitioned"));
+
+    verify_fixture_manifest(&manifest, None, Some(42)).expect("matching seed");
+    verify_fixture_manifest(&manifest, Some(DatasetId::TinySmoke), None)
+        .expect("tiny_smoke matches standard sf1 fixtures");
+    let err = verify_fixture_manifest(&manifest, None, Some(7)).expect_err("seed mismatch");
+    assert!(err.to_string().contains("seed 42"), "{err}");
+    let err = verify_fixture_manifest(&manifest, Some(DatasetId::ManyVersions), None)
+        .expect_err("profile mismatch");
+    assert!(err.to_string().contains("many_versions"), "{err}");
+
+    let mut resized = manifest.clone();
+    resized.table_summaries[0].bytes = None;
+    verify_fixture_manifest(&resized, None, None)
+        .expect("byte sizes are not part of the dataset fingerprint");
+
+    let mut drifted = manifest.clone();
+    drifted.table_summaries[0].rows += 1;
+    let err = verify_fixture_manifest(&drifted, None, None).expect_err("fingerprint mismatch");
+    assert!(err.to_string().contains("dataset_fingerprint"), "{err}");
+}
+
+#[tokio::test]
+async fn verify_fixture_tables_reports_drifted_and_unreadable_tables() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+    let manifest = load_manifest(temp.path(), "sf1").expect("load manifest");
+    let checks = verify_fixture_tables(temp.path(), &manifest, &storage)
+        .await
+        .expect("verify fixtures");
+    assert_eq!(checks.len(), manifest.table_inventory.len());
+    assert!(checks.iter().all(FixtureTableCheck::passed), "{checks:?}");
+    let partitioned = checks
+        .iter()
+        .find(|check| check.table == "read_partitioned_delta")
+        .expect("read_partitioned_delta check");
+    assert_eq!(partitioned.partition_columns, ["region"]);
+    assert!(partitioned.files > Some(1));
+
+    let mut drifted = manifest.clone();
+    drifted
+        .table_summaries
+        .iter_mut()
+        .find(|summary| summary.table == "narrow_sales_delta")
+        .expect("narrow_sales_delta summary")
+        .rows += 1;
+    std::fs::remove_dir_all(temp.path().join("sf1/wide_delta/_delta_log"))
+        .expect("remove wide_delta log");
+    let checks = verify_fixture_tables(temp.path(), &drifted, &storage)
+        .await
+        .expect("verify drifted fixtures");
+    let failed = checks
+        .iter()
+        .filter(|check| !check.passed())
+        .map(|check| (check.table.as_str(), check.problems.join("; ")))
+        .collect::<Vec<_>>();
+    assert_eq!(failed.len(), 2, "{failed:?}");
+    assert_eq!(failed[0].0, "narrow_sales_delta");
+    assert!(failed[0].1.contains("manifest records 10001"), "{failed:?}");
+    assert_eq!(failed[1].0, "wide_delta");
+    assert!(failed[1].1.contains("failed to read table"), "{failed:?}");
+}
+
+#[tokio::test]
+async fn small_files_profile_writes_requested_file_count_and_records_it() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    let shape = FixtureShape {
+        small_files: SmallFilesShape::new(12, 50).expect("small-files shape"),
+        ..FixtureShape::default()
+    };
+
+    generate_fixtures_for_scales(
+        temp.path(),
+        &["sf1".to_string()],
+        42,
+        true,
+        false,
+        FixtureProfile::SmallFiles,
+        &DataDistribution::default(),
+        &shape,
+        &storage,
+    )
+    .await
+    .expect("generate small-files fixtures");
+
+    let manifest = load_manifest(temp.path(), "sf1").expect("load manifest");
+    assert_eq!(manifest.profile, "small_files");
+    let recipe = manifest.fixture_recipe.as_ref().expect("fixture recipe");
+    assert_eq!(recipe.small_files_count, Some(12));
+    assert_eq!(recipe.small_files_rows_per_file, Some(50));
+    let summary = manifest
+        .table_summaries
+        .iter()
+        .find(|summary| summary.table == "small_files_delta")
+        .expect("small_files_delta summary");
+    assert_eq!(summary.rows, 600);
+    assert_eq!(summary.files, 12);
+    assert_eq!(
+        fixture_small_files_count(temp.path(), "sf1").expect("recorded file count"),
+        12
+    );
+    verify_fixture_manifest(&manifest, Some(DatasetId::SmallFiles), None)
+        .expect("small_files matches small-files fixtures");
+
+    let err = SmallFilesShape::new(0, 50).expect_err("zero files");
+    assert!(err.to_string().contains("at least one file"), "{err}");
+}
+
 #[tokio::test]
 async fn fixture_manifest_fingerprint_changes_with_profile() {
     let standard = tempfile::tempdir().expect("standard tempdir");
@@ -289,6 +667,128 @@ async fn fixture_manifest_records_recipe_hash() {
     assert!(manifest.fixture_recipe.is_some());
 }
 
+#[tokio::test]
+async fn skewed_nullable_distribution_is_recorded_and_round_trips() {
+    let uniform = tempfile::tempdir().expect("tempdir");
+    let skewed = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    let distribution = DataDistribution {
+        region_skew: 1.5,
+        value_i64_null_probability: 0.2,
+        ..DataDistribution::default()
+    };
+
+    generate_fixtures(uniform.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate uniform fixtures");
+    generate_fixtures_for_scales(
+        skewed.path(),
+        &["sf1".to_string()],
+        42,
+        true,
+        false,
+        FixtureProfile::Standard,
+        &distribution,
+        &FixtureShape::default(),
+        &storage,
+    )
+    .await
+    .expect("generate skewed fixtures");
+
+    let uniform_manifest = load_manifest(uniform.path(), "sf1").expect("load uniform manifest");
+    let manifest = load_manifest(skewed.path(), "sf1").expect("load skewed manifest");
+    assert_eq!(
+        manifest
+            .fixture_recipe
+            .as_ref()
+            .map(|recipe| &recipe.distribution),
+        Some(&distribution)
+    );
+    assert_ne!(
+        uniform_manifest.fixture_recipe_hash,
+        manifest.fixture_recipe_hash
+    );
+
+    let rows = load_rows(skewed.path(), "sf1").expect("load rows");
+    let nulls = rows.iter().filter(|row| row.value_i64.is_none()).count();
+    assert!((1_500..2_500).contains(&nulls), "{nulls} null values");
+    assert!(rows.iter().all(|row| row.flag.is_some()));
+    let us_rows = rows.iter().filter(|row| row.region == "us").count();
+    assert!(
+        us_rows > rows.len() / 3,
+        "{us_rows} rows in the hottest region"
+    );
+
+    let ctx = SessionContext::new();
+    let table = storage
+        .open_table(narrow_sales_table_url(skewed.path(), "sf1", &storage).expect("table url"))
+        .await
+        .expect("open narrow_sales");
+    ctx.register_table(
+        "narrow_sales",
+        table.table_provider().await.expect("provider"),
+    )
+    .expect("register narrow_sales");
+    let batches = ctx
+        .sql("SELECT COUNT(*) AS n FROM narrow_sales WHERE value_i64 IS NULL")
+        .await
+        .expect("plan null count")
+        .collect()
+        .await
+        .expect("count nulls");
+    let counted = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<deltalake_core::arrow::array::Int64Array>()
+        .expect("count column")
+        .value(0);
+    assert_eq!(counted as usize, nulls);
+}
+
+#[tokio::test]
+async fn wide_table_columns_shape_is_recorded_and_validated() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    let shape = FixtureShape {
+        wide_table_columns: 8,
+    };
+
+    generate_fixtures_for_scales(
+        temp.path(),
+        &["sf1".to_string()],
+        42,
+        true,
+        false,
+        FixtureProfile::Standard,
+        &DataDistribution::default(),
+        &shape,
+        &storage,
+    )
+    .await
+    .expect("generate narrow wide_delta");
+    assert_eq!(
+        fixture_wide_table_columns(temp.path(), "sf1").expect("manifest should record width"),
+        8
+    );
+
+    let err = generate_fixtures_for_scales(
+        temp.path(),
+        &["sf1".to_string()],
+        42,
+        true,
+        false,
+        FixtureProfile::Standard,
+        &DataDistribution::default(),
+        &FixtureShape {
+            wide_table_columns: 3,
+        },
+        &storage,
+    )
+    .await
+    .expect_err("three columns cannot hold the projected ones");
+    assert!(err.to_string().contains("must be >= 4"), "{err}");
+}
+
 #[tokio::test]
 async fn fixture_recipe_hash_changes_with_profile() {
     let standard = tempfile::tempdir().expect("standard tempdir");
@@ -491,6 +991,104 @@ async fn matching_standard_fixtures_skip_lock_wait_on_cache_hit() {
     .await;
 }
 
+#[tokio::test]
+async fn fixture_regeneration_refuses_directories_that_do_not_hold_fixtures() {
+    let _env_lock = env_lock();
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    let root = temp.path().join("sf1");
+    std::fs::create_dir_all(&root).expect("create scale dir");
+    std::fs::write(root.join("notes.txt"), "not a fixture").expect("write unrelated file");
+
+    let error = generate_fixtures(temp.path(), "sf1", 42, false, &storage)
+        .await
+        .expect_err("directory without a fixture manifest must not be deleted");
+    assert!(
+        error
+            .to_string()
+            .contains("has no readable fixture manifest"),
+        "unexpected error: {error}"
+    );
+    assert!(root.join("notes.txt").exists());
+
+    #[cfg(unix)]
+    {
+        let fixtures = tempfile::tempdir().expect("tempdir");
+        std::os::unix::fs::symlink(&root, fixtures.path().join("sf1")).expect("symlink scale");
+        let error = generate_fixtures(fixtures.path(), "sf1", 42, true, &storage)
+            .await
+            .expect_err("scale dir outside the fixtures dir must not be deleted");
+        assert!(
+            error.to_string().contains("outside the fixtures dir"),
+            "unexpected error: {error}"
+        );
+        assert!(root.join("notes.txt").exists());
+    }
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("--force replaces the directory");
+    assert!(!root.join("notes.txt").exists());
+    load_manifest(temp.path(), "sf1").expect("regenerated manifest");
+}
+
+#[tokio::test]
+async fn trash_mode_moves_replaced_fixtures_aside() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, false, &storage)
+        .await
+        .expect("generate fixtures");
+
+    generate_fixtures_for_scales(
+        temp.path(),
+        &["sf1".to_string()],
+        7,
+        false,
+        true,
+        FixtureProfile::Standard,
+        &DataDistribution::default(),
+        &FixtureShape::default(),
+        &storage,
+    )
+    .await
+    .expect("regenerate with a new seed");
+
+    assert_eq!(
+        load_manifest(temp.path(), "sf1")
+            .expect("new manifest")
+            .seed,
+        7
+    );
+    let trashed = std::fs::read_dir(temp.path().join(".delta_bench_trash"))
+        .expect("trash dir")
+        .map(|entry| entry.expect("trash entry").path())
+        .collect::<Vec<_>>();
+    assert_eq!(trashed.len(), 1, "{trashed:?}");
+    let name = trashed[0].file_name().expect("name").to_string_lossy();
+    assert!(name.starts_with("sf1-"), "{name}");
+    let old: serde_json::Value = serde_json::from_slice(
+        &std::fs::read(trashed[0].join("manifest.json")).expect("trashed manifest"),
+    )
+    .expect("parse trashed manifest");
+    assert_eq!(old["seed"], 42);
+}
+
+async fn generate_standard_sf1(dir: &Path, force: bool) -> BenchResult<Vec<FixtureScaleTimings>> {
+    generate_fixtures_for_scales(
+        dir,
+        &["sf1".to_string()],
+        42,
+        force,
+        false,
+        FixtureProfile::Standard,
+        &DataDistribution::default(),
+        &FixtureShape::default(),
+        &StorageConfig::local(),
+    )
+    .await
+}
+
 fn env_lock() -> std::sync::MutexGuard<'static, ()> {
     static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
     LOCK.get_or_init(|| Mutex::new(()))
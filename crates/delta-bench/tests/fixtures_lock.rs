@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use clap::Parser;
+use delta_bench::cli::{Args, Command};
+use delta_bench::data::lock::{fixtures_dir_lock_path, lock_fixtures_dir, FixturesLockMode};
+
+#[tokio::test]
+async fn exclusive_lock_rejects_other_holders_and_names_itself() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let held = lock_fixtures_dir(temp.path(), FixturesLockMode::Exclusive, "data", false)
+        .await
+        .expect("first exclusive lock");
+    let holder = std::fs::read_to_string(fixtures_dir_lock_path(temp.path())).expect("holder");
+    assert!(
+        holder.contains(&format!("pid {}", std::process::id())) && holder.contains("bench data"),
+        "unexpected holder record: {holder}"
+    );
+
+    for mode in [FixturesLockMode::Shared, FixturesLockMode::Exclusive] {
+        let error = lock_fixtures_dir(temp.path(), mode, "run", false)
+            .await
+            .expect_err("conflicting lock should fail without --wait");
+        let message = error.to_string();
+        assert!(
+            message.contains("bench data"),
+            "unexpected error: {message}"
+        );
+        assert!(message.contains("--wait"), "unexpected error: {message}");
+    }
+
+    drop(held);
+    lock_fixtures_dir(temp.path(), FixturesLockMode::Exclusive, "data", false)
+        .await
+        .expect("lock should be free after the holder drops it");
+}
+
+#[tokio::test]
+async fn shared_locks_coexist_but_exclude_writers() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let _first = lock_fixtures_dir(temp.path(), FixturesLockMode::Shared, "run", false)
+        .await
+        .expect("first shared lock");
+    let _second = lock_fixtures_dir(temp.path(), FixturesLockMode::Shared, "run", false)
+        .await
+        .expect("readers should share the lock");
+
+    let error = lock_fixtures_dir(temp.path(), FixturesLockMode::Exclusive, "data", false)
+        .await
+        .expect_err("writer should be rejected while readers hold the lock");
+    assert!(
+        error
+            .to_string()
+            .contains("another bench process reading it"),
+        "unexpected error: {error}"
+    );
+}
+
+#[tokio::test]
+async fn wait_blocks_until_the_holder_releases_the_lock() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let held = lock_fixtures_dir(temp.path(), FixturesLockMode::Exclusive, "data", false)
+        .await
+        .expect("first exclusive lock");
+    let release = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        drop(held);
+    });
+
+    let waited = tokio::time::timeout(
+        Duration::from_secs(10),
+        lock_fixtures_dir(temp.path(), FixturesLockMode::Shared, "run", true),
+    )
+    .await
+    .expect("waiting lock should be granted once released")
+    .expect("waiting lock");
+    release.await.expect("release task");
+    drop(waited);
+}
+
+#[test]
+fn data_and_run_accept_wait_flag() {
+    let args = Args::parse_from(["delta-bench", "data", "--wait"]);
+    assert!(matches!(args.command, Command::Data { wait: true, .. }));
+
+    let args = Args::parse_from([
+        "delta-bench",
+        "data",
+        "import",
+        "--archive",
+        "fixtures.tar.zst",
+        "--wait",
+    ]);
+    assert!(matches!(args.command, Command::Data { wait: true, .. }));
+
+    let args = Args::parse_from(["delta-bench", "run", "--wait"]);
+    assert!(matches!(args.command, Command::Run { wait: true, .. }));
+
+    let args = Args::parse_from(["delta-bench", "run"]);
+    assert!(matches!(args.command, Command::Run { wait: false, .. }));
+}
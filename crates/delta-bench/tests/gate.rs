@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+
+use delta_bench::gate::{evaluate, evaluate_with_aliases, Aggregation, GatePolicy, Verdict};
+use delta_bench::results::BenchRunResult;
+use serde_json::{json, Value};
+
+fn case(name: &str, elapsed_ms: &[f64]) -> Value {
+    json!({
+        "case": name,
+        "success": true,
+        "validation_passed": true,
+        "perf_status": "trusted",
+        "classification": "supported",
+        "samples": elapsed_ms
+            .iter()
+            .map(|elapsed_ms| json!({
+                "elapsed_ms": elapsed_ms,
+                "rows": null,
+                "bytes": null,
+                "metrics": null
+            }))
+            .collect::<Vec<_>>(),
+        "failure": null
+    })
+}
+
+fn run(label: &str, scale: &str, cases: Vec<Value>) -> BenchRunResult {
+    serde_json::from_value(json!({
+        "schema_version": 5,
+        "context": {
+            "schema_version": 5,
+            "label": label,
+            "git_sha": "abc123",
+            "created_at": "2026-02-27T10:00:00Z",
+            "host": "bench-runner",
+            "suite": "scan",
+            "scale": scale,
+            "iterations": 3,
+            "warmup": 1
+        },
+        "cases": cases
+    }))
+    .expect("parse run result")
+}
+
+#[test]
+fn evaluate_returns_one_typed_verdict_per_case() {
+    let mut invalid = case("scan_invalid", &[10.0]);
+    invalid["perf_status"] = json!("invalid");
+    let baseline = run(
+        "base",
+        "sf1",
+        vec![
+            case("scan_faster", &[100.0, 101.0, 102.0]),
+            case("scan_invalid", &[10.0]),
+            case("scan_removed", &[10.0]),
+            case("scan_slower", &[100.0, 101.0, 102.0]),
+            case("scan_steady", &[100.0, 101.0, 102.0]),
+        ],
+    );
+    let candidate = run(
+        "cand",
+        "sf1",
+        vec![
+            case("scan_added", &[10.0]),
+            case("scan_faster", &[80.0, 81.0, 82.0]),
+            invalid,
+            case("scan_slower", &[120.0, 121.0, 122.0]),
+            case("scan_steady", &[103.0, 104.0, 105.0]),
+        ],
+    );
+
+    let report = evaluate(&baseline, &candidate, &GatePolicy::default()).expect("evaluate");
+
+    let verdicts = report
+        .cases
+        .iter()
+        .map(|case| (case.case.as_str(), case.verdict))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        verdicts,
+        [
+            ("scan_added", Verdict::InsufficientData),
+            ("scan_faster", Verdict::Improve),
+            ("scan_invalid", Verdict::InsufficientData),
+            ("scan_removed", Verdict::InsufficientData),
+            ("scan_slower", Verdict::Regress),
+            ("scan_steady", Verdict::Pass),
+        ]
+    );
+    assert!(!report.passed());
+    assert_eq!(report.count(Verdict::InsufficientData), 3);
+    let slower = report
+        .with_verdict(Verdict::Regress)
+        .next()
+        .expect("regression");
+    assert_eq!(slower.baseline_ms, Some(101.0));
+    assert_eq!(slower.candidate_ms, Some(121.0));
+    assert!((slower.change_pct.expect("change") - 19.80).abs() < 0.01);
+    assert_eq!(
+        report.cases[0].reason.as_deref(),
+        Some("case is missing from the baseline run")
+    );
+    assert_eq!(
+        report.cases[2].reason.as_deref(),
+        Some("candidate perf_status is invalid")
+    );
+
+    let serialized = serde_json::to_value(&report).expect("serialize report");
+    assert_eq!(serialized["cases"][0]["verdict"], "insufficient_data");
+}
+
+#[test]
+fn evaluate_applies_the_policy_threshold_aggregation_and_sample_floor() {
+    let baseline = run(
+        "base",
+        "sf1",
+        vec![case("scan_case", &[100.0, 100.0, 300.0])],
+    );
+    let candidate = run(
+        "cand",
+        "sf1",
+        vec![case("scan_case", &[90.0, 104.0, 200.0])],
+    );
+
+    let lenient = GatePolicy {
+        threshold_pct: 10.0,
+        ..GatePolicy::default()
+    };
+    let report = evaluate(&baseline, &candidate, &lenient).expect("evaluate");
+    assert_eq!(report.cases[0].verdict, Verdict::Pass);
+    assert!(report.passed());
+
+    let by_p95 = GatePolicy {
+        aggregation: Aggregation::P95,
+        ..GatePolicy::default()
+    };
+    let report = evaluate(&baseline, &candidate, &by_p95).expect("evaluate");
+    assert_eq!(report.cases[0].verdict, Verdict::Improve);
+
+    let strict_samples = GatePolicy {
+        min_samples: 5,
+        ..GatePolicy::default()
+    };
+    let report = evaluate(&baseline, &candidate, &strict_samples).expect("evaluate");
+    assert_eq!(report.cases[0].verdict, Verdict::InsufficientData);
+    assert_eq!(
+        report.cases[0].reason.as_deref(),
+        Some("baseline has 3 sample(s), fewer than the 5 required")
+    );
+}
+
+#[test]
+fn evaluate_rejects_mismatched_runs_and_invalid_policies() {
+    let baseline = run("base", "sf1", vec![case("scan_case", &[1.0])]);
+    let candidate = run("cand", "sf10", vec![case("scan_case", &[1.0])]);
+    let err = evaluate(&baseline, &candidate, &GatePolicy::default())
+        .expect_err("scale mismatch must fail");
+    assert!(
+        err.to_string()
+            .contains("context mismatch across runs: scale='sf1'!='sf10'"),
+        "{err}"
+    );
+
+    let policy = GatePolicy {
+        threshold_pct: f64::NAN,
+        ..GatePolicy::default()
+    };
+    let err = evaluate(&baseline, &baseline, &policy).expect_err("NaN threshold must fail");
+    assert!(err.to_string().contains("non-negative percentage"), "{err}");
+}
+
+#[test]
+fn evaluate_matches_renamed_cases_through_aliases() {
+    let aliases = BTreeMap::from([("scan_old".to_string(), "scan_new".to_string())]);
+    let baseline = run("base", "sf1", vec![case("scan_old", &[100.0])]);
+    let candidate = run("cand", "sf1", vec![case("scan_new", &[101.0])]);
+
+    let report = evaluate_with_aliases(&baseline, &candidate, &GatePolicy::default(), &aliases)
+        .expect("evaluate");
+
+    assert_eq!(report.cases.len(), 1);
+    assert_eq!(report.cases[0].case, "scan_new");
+    assert_eq!(report.cases[0].verdict, Verdict::Pass);
+
+    let both = run(
+        "cand",
+        "sf1",
+        vec![case("scan_old", &[100.0]), case("scan_new", &[101.0])],
+    );
+    let err = evaluate_with_aliases(&baseline, &both, &GatePolicy::default(), &aliases)
+        .expect_err("alias and current id in one run must fail");
+    assert!(
+        err.to_string()
+            .contains("candidate has both 'scan_old' and 'scan_new' for case 'scan_new'"),
+        "{err}"
+    );
+}
@@ -36,7 +36,7 @@ fn core_rust_manifest_uses_refreshed_authoritative_regression_hashes() {
             .assertions
             .iter()
             .find_map(|assertion| {
-                if let ManifestAssertion::ExactResultHash { value } = assertion {
+                if let ManifestAssertion::ExactResultHash { value } = &assertion.assertion {
                     Some(value.clone())
                 } else {
                     None
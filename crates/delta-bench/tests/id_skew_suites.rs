@@ -0,0 +1,67 @@
+use delta_bench::cli::{BenchmarkLane, TimingPhase};
+use delta_bench::data::fixtures::{generate_fixtures_for_scales, FixtureProfile, FixtureShape};
+use delta_bench::data::generator::DataDistribution;
+use delta_bench::results::FAILURE_KIND_UNSUPPORTED;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::run_target;
+use delta_bench::suites::scan::run_single_case;
+
+#[tokio::test]
+async fn id_skewed_fixtures_skip_the_selectivity_sweep_and_still_merge() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+    let distribution = DataDistribution {
+        id_skew: 1.2,
+        ..DataDistribution::default()
+    };
+
+    generate_fixtures_for_scales(
+        temp.path(),
+        &["sf1".to_string()],
+        42,
+        true,
+        false,
+        FixtureProfile::Standard,
+        &distribution,
+        &FixtureShape::default(),
+        &storage,
+    )
+    .await
+    .expect("skewed fixtures should be generated");
+
+    let sweep = run_single_case(
+        temp.path(),
+        "sf1",
+        "scan_selectivity_1pct",
+        TimingPhase::Execute,
+        &storage,
+    )
+    .await
+    .expect("sweep case should resolve");
+    assert!(!sweep.success);
+    assert_eq!(
+        sweep.failure_kind.as_deref(),
+        Some(FAILURE_KIND_UNSUPPORTED)
+    );
+
+    let cases = run_target(
+        temp.path(),
+        "merge",
+        "sf1",
+        BenchmarkLane::Macro,
+        TimingPhase::Execute,
+        0,
+        1,
+        &storage,
+    )
+    .await
+    .expect("merge suite should run");
+    assert!(
+        cases.iter().all(|case| case.success),
+        "merge failures on skewed ids: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,200 @@
+use delta_bench::cli::RunnerMode;
+use delta_bench::results::{
+    CaseResult, IterationSample, PerfStatus, SampleMetrics, FAILURE_KIND_ASSERTION_MISMATCH,
+};
+use delta_bench::runner::CaseBudget;
+use delta_bench::suites::interop_consistency::{
+    interop_consistency_case, INTEROP_CONSISTENCY_CASE,
+};
+use delta_bench::suites::{plan_run_cases, PlannedCase};
+
+fn planned_case(id: &str, target: &str, workload: Option<&str>) -> PlannedCase {
+    PlannedCase {
+        id: id.to_string(),
+        target: target.to_string(),
+        lane: "macro".to_string(),
+        assertions: Vec::new(),
+        warn_assertions: Vec::new(),
+        suite_manifest_hash: "sha256:manifest".to_string(),
+        case_definition_hash: format!("sha256:{id}-def"),
+        supports_decision: false,
+        required_runs: None,
+        decision_threshold_pct: None,
+        decision_metric: None,
+        depends_on: Vec::new(),
+        budget: CaseBudget::default(),
+        tags: Vec::new(),
+        interop_workload: workload.map(str::to_string),
+        matrix: None,
+    }
+}
+
+fn case_result(id: &str, result_hash: &str, rows_processed: u64) -> CaseResult {
+    let mut metrics = SampleMetrics::base(Some(rows_processed), None, None, None);
+    metrics.result_hash = Some(result_hash.to_string());
+    CaseResult {
+        case: id.to_string(),
+        success: true,
+        validation_passed: true,
+        perf_status: PerfStatus::Trusted,
+        classification: "supported".to_string(),
+        samples: vec![IterationSample {
+            elapsed_ms: 1.0,
+            rows: Some(rows_processed),
+            bytes: None,
+            metrics: Some(metrics),
+        }],
+        elapsed_stats: None,
+        run_summary: None,
+        run_summaries: None,
+        suite_manifest_hash: None,
+        case_definition_hash: None,
+        compatibility_key: None,
+        supports_decision: None,
+        required_runs: None,
+        decision_threshold_pct: None,
+        decision_metric: None,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
+        failure_kind: None,
+        failure: None,
+    }
+}
+
+fn paired_plan() -> Vec<PlannedCase> {
+    vec![
+        planned_case("scan_interop_reference", "scan", Some("flag_sum")),
+        planned_case("scan_full_narrow", "scan", None),
+        planned_case("pyarrow_dataset_scan_perf", "interop_py", Some("flag_sum")),
+    ]
+}
+
+#[test]
+fn runner_all_plans_both_lanes_of_the_shared_scan_workload() {
+    let planned = plan_run_cases("all", RunnerMode::All, None).expect("plan");
+    let workload_of = |id: &str| {
+        planned
+            .iter()
+            .find(|case| case.id == id)
+            .and_then(|case| case.interop_workload.clone())
+    };
+    assert_eq!(
+        workload_of("scan_interop_reference"),
+        Some("narrow_sales_flag_sum".to_string())
+    );
+    assert_eq!(
+        workload_of("pyarrow_dataset_scan_perf"),
+        Some("narrow_sales_flag_sum".to_string())
+    );
+}
+
+#[test]
+fn matching_lanes_pass_as_validation_only() {
+    let cases = vec![
+        case_result("scan_interop_reference", "sha256:same", 5_000),
+        case_result("scan_full_narrow", "sha256:other", 10_000),
+        case_result("pyarrow_dataset_scan_perf", "sha256:same", 5_000),
+    ];
+
+    let case = interop_consistency_case(&paired_plan(), &cases).expect("pair was compared");
+
+    assert_eq!(case.case, INTEROP_CONSISTENCY_CASE);
+    assert!(case.success, "{:?}", case.failure);
+    assert_eq!(case.perf_status, PerfStatus::ValidationOnly);
+    assert!(case.samples.is_empty());
+}
+
+#[test]
+fn diverging_lanes_fail_with_both_values() {
+    let cases = vec![
+        case_result("scan_interop_reference", "sha256:rust", 5_000),
+        case_result("scan_full_narrow", "sha256:other", 10_000),
+        case_result("pyarrow_dataset_scan_perf", "sha256:python", 4_999),
+    ];
+
+    let case = interop_consistency_case(&paired_plan(), &cases).expect("pair was compared");
+
+    assert!(!case.success);
+    assert_eq!(case.perf_status, PerfStatus::Invalid);
+    assert_eq!(
+        case.failure_kind.as_deref(),
+        Some(FAILURE_KIND_ASSERTION_MISMATCH)
+    );
+    let message = case.failure.expect("failure").message;
+    assert!(
+        message.contains(
+            "workload 'flag_sum': result_hash sha256:rust (scan_interop_reference) != sha256:python (pyarrow_dataset_scan_perf)"
+        ),
+        "{message}"
+    );
+    assert!(
+        message.contains("rows_processed 5000 (scan_interop_reference) != 4999"),
+        "{message}"
+    );
+}
+
+#[test]
+fn unpaired_or_failed_lanes_are_not_compared() {
+    let rust_only = vec![case_result("scan_interop_reference", "sha256:rust", 5_000)];
+    assert!(interop_consistency_case(&paired_plan()[..2], &rust_only).is_none());
+
+    let mut python = case_result("pyarrow_dataset_scan_perf", "sha256:python", 5_000);
+    python.classification = "expected_failure".to_string();
+    let cases = vec![
+        case_result("scan_interop_reference", "sha256:rust", 5_000),
+        python,
+    ];
+    assert!(
+        interop_consistency_case(&paired_plan(), &cases).is_none(),
+        "a Python lane without its dependencies has nothing to compare"
+    );
+}
+
+#[test]
+fn lanes_that_both_leave_a_value_unset_do_not_match() {
+    let mut rust = case_result("scan_interop_reference", "sha256:same", 5_000);
+    let mut python = case_result("pyarrow_dataset_scan_perf", "sha256:same", 5_000);
+    for case in [&mut rust, &mut python] {
+        let metrics = case.samples[0].metrics.as_mut().expect("metrics");
+        metrics.result_hash = None;
+    }
+
+    let case = interop_consistency_case(&paired_plan(), &[rust, python]).expect("pair compared");
+
+    assert!(!case.success);
+    let message = case.failure.expect("failure").message;
+    assert!(
+        message.contains("result_hash missing (scan_interop_reference) != missing"),
+        "{message}"
+    );
+}
+
+#[test]
+fn every_case_sharing_a_workload_is_compared() {
+    let mut planned = paired_plan();
+    planned.push(planned_case(
+        "scan_interop_reference_copy",
+        "scan",
+        Some("flag_sum"),
+    ));
+    let cases = vec![
+        case_result("scan_interop_reference", "sha256:same", 5_000),
+        case_result("pyarrow_dataset_scan_perf", "sha256:same", 5_000),
+        case_result("scan_interop_reference_copy", "sha256:drifted", 5_000),
+    ];
+
+    let case = interop_consistency_case(&planned, &cases).expect("pairs compared");
+
+    assert!(!case.success);
+    let message = case.failure.expect("failure").message;
+    assert!(
+        message.contains("sha256:drifted (scan_interop_reference_copy)"),
+        "{message}"
+    );
+}
@@ -0,0 +1,95 @@
+use delta_bench::cli::BenchmarkLane;
+use delta_bench::data::fixtures::{generate_fixtures_with_profile, FixtureProfile};
+use delta_bench::data::generator::generate_late_arriving_rows;
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::late_arriving;
+
+#[test]
+fn late_arriving_rows_split_by_id_and_arrive_out_of_order() {
+    let rows = generate_late_arriving_rows(42, 10_000);
+
+    assert_eq!(rows.landed.len(), 9_000);
+    assert_eq!(rows.backfill.len(), 1_500);
+    assert!(rows.landed.iter().all(|row| row.id % 10 != 3));
+    assert!(
+        rows.landed
+            .windows(2)
+            .any(|pair| pair[1].ts_ms < pair[0].ts_ms),
+        "landed event times should jitter out of order"
+    );
+    assert_eq!(rows, generate_late_arriving_rows(42, 10_000));
+}
+
+#[tokio::test]
+async fn late_arriving_suite_runs_against_late_arriving_fixtures() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures_with_profile(
+        temp.path(),
+        "sf1",
+        42,
+        true,
+        FixtureProfile::LateArriving,
+        &storage,
+    )
+    .await
+    .expect("fixtures should be generated");
+
+    let cases = late_arriving::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("late_arriving suite should run");
+
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "late_arriving_append_backfill",
+            "late_arriving_merge_upsert_backfill",
+            "late_arriving_merge_insert_only_backfill",
+        ]
+    );
+    assert!(
+        cases.iter().all(|case| case.success),
+        "late_arriving failures: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn late_arriving_suite_reports_fixture_error_without_late_arriving_profile() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = late_arriving::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("late_arriving suite should return fixture errors");
+
+    assert_eq!(cases.len(), 3);
+    assert!(cases.iter().all(|case| case
+        .failure
+        .as_ref()
+        .is_some_and(|failure| failure.message.contains("late_arriving"))));
+}
@@ -0,0 +1,35 @@
+use delta_bench::log_reads::{classify_log_path, LogFileKind};
+
+#[test]
+fn classifies_commits_and_checkpoint_parts_under_delta_log() {
+    let cases = [
+        (
+            "table/_delta_log/00000000000000000007.json",
+            Some(LogFileKind::Commit),
+        ),
+        (
+            "_delta_log/00000000000000000010.checkpoint.parquet",
+            Some(LogFileKind::CheckpointPart),
+        ),
+        (
+            "table/_delta_log/00000000000000000010.checkpoint.0000000001.0000000002.parquet",
+            Some(LogFileKind::CheckpointPart),
+        ),
+        (
+            "table/_delta_log/00000000000000000010.checkpoint.80a083e8-7026-4e79-81be-64bd76c43a11.json",
+            Some(LogFileKind::CheckpointPart),
+        ),
+        (
+            "table/_delta_log/_sidecars/3a0d65cd-4056-49b8-937b-95f9e3ee90e5.parquet",
+            Some(LogFileKind::CheckpointPart),
+        ),
+        ("table/_delta_log/_last_checkpoint", None),
+        ("table/_delta_log/00000000000000000007.crc", None),
+        ("table/_delta_log/00000000000000000007.json.tmp", None),
+        ("table/00000000000000000007.json", None),
+        ("table/part-00000-0.snappy.parquet", None),
+    ];
+    for (path, expected) in cases {
+        assert_eq!(classify_log_path(path), expected, "{path}");
+    }
+}
@@ -0,0 +1,103 @@
+use delta_bench::data::fixtures::{generate_fixtures_with_profile, FixtureProfile};
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::log_replay;
+
+#[tokio::test]
+async fn log_replay_suite_runs_against_many_versions_fixtures() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures_with_profile(
+        temp.path(),
+        "sf1",
+        42,
+        true,
+        FixtureProfile::ManyVersions,
+        &storage,
+    )
+    .await
+    .expect("fixtures should be generated");
+
+    let cases = log_replay::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("log_replay suite should run");
+    assert!(
+        cases.iter().all(|case| case.success),
+        "log_replay failures: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+
+    let observed = cases
+        .iter()
+        .map(|case| {
+            let metrics = case.samples[0]
+                .metrics
+                .as_ref()
+                .expect("sample metrics should be present");
+            (
+                case.case.as_str(),
+                metrics.table_version,
+                metrics.files_touched,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        observed,
+        vec![
+            ("log_replay_snapshot_checkpointed", Some(24), None),
+            ("log_replay_snapshot_uncheckpointed", Some(24), None),
+            ("log_replay_snapshot_deep_history", Some(512), None),
+            ("log_replay_enumerate_adds_checkpointed", Some(24), Some(25)),
+            (
+                "log_replay_enumerate_adds_uncheckpointed",
+                Some(24),
+                Some(25)
+            ),
+            (
+                "log_replay_enumerate_adds_deep_history",
+                Some(512),
+                Some(513)
+            ),
+        ]
+    );
+
+    let log_reads = |name: &str| {
+        cases
+            .iter()
+            .find(|case| case.case == name)
+            .and_then(|case| case.samples[0].metrics.as_ref())
+            .and_then(|metrics| metrics.log_reads.clone())
+            .unwrap_or_else(|| panic!("{name} should report log reads"))
+    };
+    let checkpointed = log_reads("log_replay_snapshot_checkpointed");
+    let uncheckpointed = log_reads("log_replay_snapshot_uncheckpointed");
+    let deep_history = log_reads("log_replay_snapshot_deep_history");
+    assert!(checkpointed.checkpoint_parts_read >= 1, "{checkpointed:?}");
+    assert!(
+        checkpointed.commit_files_read < uncheckpointed.commit_files_read,
+        "checkpoint should spare commit reads: {checkpointed:?} vs {uncheckpointed:?}"
+    );
+    assert_eq!(uncheckpointed.checkpoint_parts_read, 0);
+    assert!(uncheckpointed.commit_files_read > 0);
+    assert!(deep_history.commit_files_read > uncheckpointed.commit_files_read);
+}
+
+#[tokio::test]
+async fn log_replay_suite_reports_fixture_error_without_many_versions_profile() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = log_replay::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("log_replay suite should return fixture errors");
+
+    assert_eq!(cases.len(), 6);
+    assert!(cases.iter().all(|case| case
+        .failure
+        .as_ref()
+        .is_some_and(|failure| failure.message.contains("many_versions"))));
+}
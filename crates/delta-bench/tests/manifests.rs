@@ -1,9 +1,13 @@
 use std::path::{Path, PathBuf};
 
+use delta_bench::assertions::CaseAssertion;
 use delta_bench::fingerprint::hash_json;
-use delta_bench::manifests::{load_manifest, DatasetId, ManifestAssertion};
+use delta_bench::manifests::{
+    expand_case_matrix, load_manifest, validate_case_id, AssertionSeverity, DatasetId,
+    ManifestAssertion, MAX_CASE_ID_LEN,
+};
 use delta_bench::suites::list_cases_for_target;
-use delta_bench::suites::tpcds::catalog::phase1_query_catalog;
+use delta_bench::suites::tpcds::catalog::{query_catalog, DEFAULT_TPCDS_PHASE};
 use serde_json::json;
 
 fn repo_root() -> PathBuf {
@@ -36,9 +40,37 @@ fn loads_p0_rust_manifest_in_file_order() {
             "scan_filter_flag",
             "scan_pruning_hit",
             "scan_pruning_miss",
+            "scan_selectivity_0_001pct",
+            "scan_selectivity_0_01pct",
+            "scan_selectivity_0_1pct",
+            "scan_selectivity_1pct",
+            "scan_selectivity_10pct",
+            "scan_selectivity_50pct",
+            "scan_filter_event_date",
+            "scan_filter_ts_range",
+            "scan_filter_amount",
+            "scan_interop_reference",
+            "scan_concurrent_sessions_1",
+            "scan_concurrent_sessions_4",
+            "scan_concurrent_sessions_16",
+            "multi_table_union_all",
+            "multi_table_join",
+            "multi_table_star_join",
+            "multi_table_count_all",
+            "data_skipping_low_cardinality",
+            "data_skipping_medium_cardinality",
+            "data_skipping_high_cardinality",
+            "wide_table_full_scan",
+            "wide_table_projection_3col",
+            "wide_table_append",
+            "nested_full_scan",
+            "nested_projection_struct_field",
+            "nested_projection_list_element",
+            "nested_write",
             "write_append_small",
             "write_append_large",
             "write_overwrite",
+            "write_perf_commit_4096_adds",
             "write_perf_partitioned_1m_parts_010",
             "write_perf_partitioned_1m_parts_100",
             "write_perf_partitioned_5m_parts_010",
@@ -64,25 +96,73 @@ fn loads_p0_rust_manifest_in_file_order() {
             "merge_perf_upsert_50pct",
             "merge_perf_localized_1pct",
             "merge_perf_delete_5pct",
+            "merge_perf_upsert_10pct_schema_evolution",
+            "merge_perf_upsert_50pct_schema_evolution",
+            "merge_perf_micro_upsert_100x10",
+            "dv_delete_5pct_copy_on_write",
+            "dv_delete_5pct_deletion_vectors",
+            "dv_merge_delete_5pct_copy_on_write",
+            "dv_merge_delete_5pct_deletion_vectors",
             "metadata_load",
             "metadata_time_travel_v0",
             "metadata_perf_load_head_long_history",
             "metadata_perf_time_travel_v0_long_history",
             "metadata_perf_load_checkpointed_head",
             "metadata_perf_load_uncheckpointed_head",
+            "metadata_perf_load_head_deep_history",
+            "metadata_perf_time_travel_v0_deep_history",
+            "metadata_perf_time_travel_v256_deep_history",
+            "metadata_perf_history_long_history",
+            "metadata_perf_history_deep_history",
+            "log_replay_snapshot_checkpointed",
+            "log_replay_snapshot_uncheckpointed",
+            "log_replay_snapshot_deep_history",
+            "log_replay_enumerate_adds_checkpointed",
+            "log_replay_enumerate_adds_uncheckpointed",
+            "log_replay_enumerate_adds_deep_history",
+            "checkpoint_write_long_history",
+            "checkpoint_load_with_checkpoint",
+            "checkpoint_load_without_checkpoint",
+            "late_arriving_append_backfill",
+            "late_arriving_merge_upsert_backfill",
+            "late_arriving_merge_insert_only_backfill",
+            "restore_shallow_1_version",
+            "restore_mid_history_24_versions",
+            "restore_deep_to_version_0",
+            "restore_then_vacuum_24_versions",
+            "convert_to_delta_partitioned",
             "optimize_compact_small_files",
             "optimize_noop_already_compact",
             "optimize_heavy_compaction",
+            "vacuum_after_heavy_compaction",
             "vacuum_dry_run_lite",
             "vacuum_execute_lite",
+            "vacuum_execute_with_concurrent_reads",
+            "pipeline_ingest_append",
+            "pipeline_merge_corrections",
+            "pipeline_retention_delete",
+            "pipeline_compact",
             "optimize_perf_compact_small_files",
             "optimize_perf_noop_already_compact",
             "vacuum_perf_execute_lite",
+            "optimize_perf_after_dml_cycle",
             "concurrent_table_create",
             "concurrent_append_multi",
+            "concurrent_append_writers_2",
+            "concurrent_append_writers_4",
+            "concurrent_append_writers_8",
             "update_vs_compaction",
             "delete_vs_compaction",
             "optimize_vs_optimize_overlap",
+            "optimize_vs_append_ingest",
+            "mixed_oltp_like",
+            "crash_kill_writer_mid_commit",
+            "snapshot_scan_during_delete",
+            "snapshot_scan_during_update",
+            "replay_append",
+            "replay_merge",
+            "replay_delete",
+            "replay_optimize",
             "tpcds_q03",
             "tpcds_q07",
             "tpcds_q64",
@@ -207,10 +287,27 @@ fn dataset_id_parsing_supports_p0_catalog() {
         DatasetId::parse("tpcds_duckdb").expect("tpcds_duckdb"),
         DatasetId::TpcdsDuckdb
     );
+    assert_eq!(
+        DatasetId::parse("late_arriving").expect("late_arriving"),
+        DatasetId::LateArriving
+    );
     assert_eq!(DatasetId::TinySmoke.fixture_profile(), "standard");
+    assert_eq!(DatasetId::SmallFiles.fixture_profile(), "small_files");
     assert_eq!(DatasetId::ManyVersions.fixture_profile(), "many_versions");
     assert_eq!(DatasetId::TpcdsDuckdb.fixture_profile(), "tpcds_duckdb");
     assert_eq!(DatasetId::TpcdsDuckdb.scale(), "sf1");
+    assert_eq!(DatasetId::LateArriving.fixture_profile(), "late_arriving");
+    assert_eq!(DatasetId::LateArriving.scale(), "sf1");
+    for id in [
+        "tiny_smoke",
+        "medium_selective",
+        "small_files",
+        "many_versions",
+        "tpcds_duckdb",
+        "late_arriving",
+    ] {
+        assert_eq!(DatasetId::parse(id).expect("known dataset id").as_str(), id);
+    }
 }
 
 #[test]
@@ -258,15 +355,224 @@ cases:
     assert_eq!(manifest.cases[0].decision_metric.as_deref(), Some("median"));
     assert_eq!(manifest.cases[0].assertions.len(), 2);
     assert!(matches!(
-        manifest.cases[0].assertions[0],
+        manifest.cases[0].assertions[0].assertion,
         ManifestAssertion::ExpectedErrorContains { .. }
     ));
     assert!(matches!(
-        manifest.cases[0].assertions[1],
+        manifest.cases[0].assertions[1].assertion,
         ManifestAssertion::VersionMonotonicity
     ));
 }
 
+#[test]
+fn manifest_latency_budget_assertions_parse_and_must_be_positive() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("manifest.yaml");
+    let write = |median: &str| {
+        std::fs::write(
+            &file,
+            format!(
+                r#"
+id: test
+description: test manifest
+cases:
+  - id: scan_full_narrow
+    target: scan
+    assertions:
+      - type: max_median_ms
+        value: {median}
+      - type: max_p95_ms
+        value: 250
+"#
+            ),
+        )
+        .expect("write manifest");
+    };
+
+    write("120.5");
+    let manifest = load_manifest(&file).expect("manifest should parse");
+    let assertions = manifest.cases[0]
+        .assertions
+        .iter()
+        .map(|entry| entry.assertion.to_case_assertion())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        assertions,
+        vec![
+            CaseAssertion::MaxMedianMs(120.5),
+            CaseAssertion::MaxP95Ms(250.0)
+        ]
+    );
+
+    for invalid in ["0", "-5", ".nan"] {
+        write(invalid);
+        let err = load_manifest(&file).expect_err("non-positive budget must fail");
+        assert!(
+            err.to_string()
+                .contains("max_median_ms assertion must be a positive number of milliseconds"),
+            "{invalid}: {err}"
+        );
+    }
+}
+
+#[test]
+fn manifest_metric_bound_assertions_parse_and_reject_vacuous_minimums() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("manifest.yaml");
+    let write = |min_pruned: u64| {
+        std::fs::write(
+            &file,
+            format!(
+                r#"
+id: test
+description: test manifest
+cases:
+  - id: merge_localized_1pct
+    target: merge
+    assertions:
+      - type: min_files_pruned
+        value: {min_pruned}
+      - type: max_files_scanned
+        value: 8
+      - type: max_bytes_scanned
+        value: 1048576
+      - type: row_count_equals
+        value: 9900
+"#
+            ),
+        )
+        .expect("write manifest");
+    };
+
+    write(1);
+    let manifest = load_manifest(&file).expect("manifest should parse");
+    let assertions = manifest.cases[0]
+        .assertions
+        .iter()
+        .map(|entry| entry.assertion.to_case_assertion())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        assertions,
+        vec![
+            CaseAssertion::MinFilesPruned(1),
+            CaseAssertion::MaxFilesScanned(8),
+            CaseAssertion::MaxBytesScanned(1_048_576),
+            CaseAssertion::RowCountEquals(9_900),
+        ]
+    );
+
+    write(0);
+    let err = load_manifest(&file).expect_err("min_files_pruned of 0 must fail");
+    assert!(
+        err.to_string()
+            .contains("min_files_pruned assertion must be > 0"),
+        "{err}"
+    );
+}
+
+#[test]
+fn manifest_assertion_severity_defaults_to_fail_and_warn_cannot_expect_errors() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("manifest.yaml");
+    let write = |assertion: &str, value: &str| {
+        std::fs::write(
+            &file,
+            format!(
+                r#"
+id: test
+description: test manifest
+cases:
+  - id: merge_localized_1pct
+    target: merge
+    assertions:
+      - type: row_count_equals
+        value: 9900
+      - type: {assertion}
+        value: {value}
+        severity: warn
+"#
+            ),
+        )
+        .expect("write manifest");
+    };
+
+    write("min_files_pruned", "1");
+    let manifest = load_manifest(&file).expect("manifest should parse");
+    let severities = manifest.cases[0]
+        .assertions
+        .iter()
+        .map(|entry| (entry.assertion.to_case_assertion(), entry.severity))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        severities,
+        vec![
+            (
+                CaseAssertion::RowCountEquals(9_900),
+                AssertionSeverity::Fail
+            ),
+            (CaseAssertion::MinFilesPruned(1), AssertionSeverity::Warn),
+        ]
+    );
+    let serialized = serde_json::to_value(&manifest.cases[0].assertions).expect("serialize");
+    assert_eq!(
+        serialized,
+        json!([
+            {"type": "row_count_equals", "value": 9900},
+            {"type": "min_files_pruned", "value": 1, "severity": "warn"},
+        ])
+    );
+
+    write("expected_error_contains", "conflict");
+    let err = load_manifest(&file).expect_err("observe-only expected errors must fail");
+    assert!(
+        err.to_string()
+            .contains("expected_error_contains assertion cannot have severity warn"),
+        "{err}"
+    );
+}
+
+#[test]
+fn manifest_interop_workload_pairs_one_case_per_runner() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("manifest.yaml");
+    let write = |second_runner: &str| {
+        std::fs::write(
+            &file,
+            format!(
+                r#"
+id: test
+description: test manifest
+cases:
+  - id: scan_interop_reference
+    target: scan
+    interop_workload: narrow_sales_flag_sum
+  - id: pyarrow_dataset_scan_perf
+    target: interop_py
+    runner: {second_runner}
+    interop_workload: narrow_sales_flag_sum
+"#
+            ),
+        )
+        .expect("write manifest");
+    };
+
+    write("python");
+    let manifest = load_manifest(&file).expect("one case per runner is valid");
+    assert_eq!(
+        manifest.cases[0].interop_workload.as_deref(),
+        Some("narrow_sales_flag_sum")
+    );
+
+    write("rust");
+    let err = load_manifest(&file).expect_err("two rust cases cannot share a workload");
+    assert!(
+        err.to_string().contains(
+            "cases 'scan_interop_reference' and 'pyarrow_dataset_scan_perf' both have interop_workload 'narrow_sales_flag_sum'"
+        ),
+        "{err}"
+    );
+}
+
 #[test]
 fn manifest_rejects_unknown_lane_values() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -301,6 +607,263 @@ cases:
     );
 }
 
+#[test]
+fn case_id_validation_accepts_snake_case_with_parameter_suffixes() {
+    for id in [
+        "scan_full_narrow",
+        "scan_selectivity_0_001pct",
+        "delete_perf_scattered_5pct_small_files",
+        "metadata_perf_time_travel_v0_long_history",
+        "tpcds_q03",
+    ] {
+        validate_case_id(id).unwrap_or_else(|error| panic!("'{id}' should be valid: {error}"));
+    }
+}
+
+#[test]
+fn case_id_validation_rejects_names_that_break_paths_or_labels() {
+    let too_long = "a".repeat(MAX_CASE_ID_LEN + 1);
+    for id in [
+        "",
+        "Scan_Full",
+        "scan-full",
+        "scan full",
+        "scan/full",
+        "_scan",
+        "scan_",
+        "scan__full",
+        "5pct_scan",
+        too_long.as_str(),
+    ] {
+        assert!(
+            validate_case_id(id).is_err(),
+            "'{id}' should be rejected by case id validation"
+        );
+    }
+}
+
+#[test]
+fn manifest_rejects_case_ids_outside_naming_convention() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("manifest.yaml");
+    std::fs::write(
+        &file,
+        r#"
+id: test
+description: invalid case id manifest
+cases:
+  - id: Write-Append/Small
+    target: write
+    runner: rust
+"#,
+    )
+    .expect("write manifest");
+
+    let err = load_manifest(&file).expect_err("invalid case id must fail");
+    let message = err.to_string();
+    assert!(
+        message.contains("Write-Append/Small"),
+        "case id should be named: {message}"
+    );
+    assert!(
+        message.contains("snake_case"),
+        "naming convention should be documented: {message}"
+    );
+}
+
+#[test]
+fn manifest_aliases_map_retired_ids_to_current_cases() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("manifest.yaml");
+    std::fs::write(
+        &file,
+        r#"
+id: test
+description: aliased manifest
+cases:
+  - id: restore_mid_history_12_versions
+    target: restore
+    runner: rust
+aliases:
+  restore_mid_history_24_versions: restore_mid_history_12_versions
+"#,
+    )
+    .expect("write manifest");
+
+    let manifest = load_manifest(&file).expect("aliased manifest should load");
+    assert_eq!(
+        manifest
+            .aliases
+            .get("restore_mid_history_24_versions")
+            .map(String::as_str),
+        Some("restore_mid_history_12_versions")
+    );
+}
+
+#[test]
+fn manifest_rejects_aliases_to_unknown_or_live_cases() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    for (aliases, expected) in [
+        (
+            "scan_pruning_hit: scan_selectivity_1pct",
+            "unknown case 'scan_selectivity_1pct'",
+        ),
+        ("scan_full_narrow: scan_filter_flag", "still a case id"),
+    ] {
+        let file = temp.path().join("manifest.yaml");
+        std::fs::write(
+            &file,
+            format!(
+                r#"
+id: test
+description: invalid alias manifest
+cases:
+  - id: scan_full_narrow
+    target: scan
+    runner: rust
+  - id: scan_filter_flag
+    target: scan
+    runner: rust
+aliases:
+  {aliases}
+"#
+            ),
+        )
+        .expect("write manifest");
+
+        let err = load_manifest(&file).expect_err("invalid alias must fail");
+        let message = err.to_string();
+        assert!(message.contains(expected), "{expected}: {message}");
+    }
+}
+
+#[test]
+fn manifest_rejects_unknown_cross_target_and_circular_dependencies() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    for (dependencies, expected) in [
+        (
+            ("[scan_missing]", "[]"),
+            "depends on unknown case 'scan_missing'",
+        ),
+        (("[write_append]", "[]"), "different target or runner"),
+        (
+            ("[scan_filter_flag]", "[scan_full_narrow]"),
+            "cycle: scan_full_narrow -> scan_filter_flag -> scan_full_narrow",
+        ),
+    ] {
+        let file = temp.path().join("manifest.yaml");
+        std::fs::write(
+            &file,
+            format!(
+                r#"
+id: test
+description: dependency manifest
+cases:
+  - id: scan_full_narrow
+    target: scan
+    depends_on: {}
+  - id: scan_filter_flag
+    target: scan
+    depends_on: {}
+  - id: write_append
+    target: write
+"#,
+                dependencies.0, dependencies.1
+            ),
+        )
+        .expect("write manifest");
+
+        let err = load_manifest(&file).expect_err("invalid dependency must fail");
+        let message = err.to_string();
+        assert!(message.contains(expected), "{expected}: {message}");
+    }
+}
+
+#[test]
+fn manifest_matrix_cases_expand_into_one_id_per_combination() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("manifest.yaml");
+    std::fs::write(
+        &file,
+        r#"
+id: test
+description: matrix manifest
+cases:
+  - id: merge_upsert
+    target: merge
+    matrix:
+      match_ratio: [0.05, 0.5, 1]
+"#,
+    )
+    .expect("write manifest");
+
+    let manifest = load_manifest(&file).expect("matrix manifest loads");
+    let expanded = expand_case_matrix(&manifest.cases[0]);
+    assert_eq!(
+        expanded
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "merge_upsert_match_ratio_0p05",
+            "merge_upsert_match_ratio_0p5",
+            "merge_upsert_match_ratio_1",
+        ]
+    );
+    assert_eq!(expanded[1].1.get("match_ratio"), Some(&0.5));
+}
+
+#[test]
+fn manifest_rejects_invalid_case_matrices() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    for (case, expected) in [
+        (
+            "matrix:\n      match_ratio: []",
+            "matrix parameter 'match_ratio' has no values",
+        ),
+        (
+            "matrix:\n      match_ratio: [0.5, 0.5]",
+            "lists 0.5 more than once",
+        ),
+        (
+            "matrix:\n      MatchRatio: [0.5]",
+            "must be lowercase snake_case",
+        ),
+        (
+            "depends_on: [merge_delete_1pct]\n    matrix:\n      match_ratio: [0.5]",
+            "has a matrix and cannot use depends_on",
+        ),
+        (
+            "matrix:\n      match_ratio: [0.1]",
+            "generates id 'merge_upsert_match_ratio_0p1', which is already a case id",
+        ),
+    ] {
+        let file = temp.path().join("manifest.yaml");
+        std::fs::write(
+            &file,
+            format!(
+                r#"
+id: test
+description: matrix manifest
+cases:
+  - id: merge_delete_1pct
+    target: merge
+  - id: merge_upsert_match_ratio_0p1
+    target: merge
+  - id: merge_upsert
+    target: merge
+    {case}
+"#
+            ),
+        )
+        .expect("write manifest");
+
+        let err = load_manifest(&file).expect_err("invalid matrix must fail");
+        let message = err.to_string();
+        assert!(message.contains(expected), "{expected}: {message}");
+    }
+}
+
 #[test]
 fn p0_rust_manifest_includes_all_delete_update_cases() {
     let manifest_path = rust_manifest_path();
@@ -330,14 +893,15 @@ fn p0_rust_manifest_enforces_hash_assertions_for_every_enabled_case() {
         .iter()
         .filter(|case| case.enabled && case.runner == "rust")
         .filter(|case| {
-            let has_result_hash = case
-                .assertions
-                .iter()
-                .any(|assertion| matches!(assertion, ManifestAssertion::ExactResultHash { .. }));
-            let has_schema_hash = case
-                .assertions
-                .iter()
-                .any(|assertion| matches!(assertion, ManifestAssertion::SchemaHash { .. }));
+            let has_result_hash = case.assertions.iter().any(|assertion| {
+                matches!(
+                    assertion.assertion,
+                    ManifestAssertion::ExactResultHash { .. }
+                )
+            });
+            let has_schema_hash = case.assertions.iter().any(|assertion| {
+                matches!(assertion.assertion, ManifestAssertion::SchemaHash { .. })
+            });
             !has_schema_hash || (case.target != "concurrency" && !has_result_hash)
         })
         .map(|case| case.id.clone())
@@ -405,7 +969,7 @@ fn scan_pruning_hit_manifest_uses_requalified_exact_result_hash() {
     let exact_result_hash = scan_pruning_hit
         .assertions
         .iter()
-        .find_map(|assertion| match assertion {
+        .find_map(|assertion| match &assertion.assertion {
             ManifestAssertion::ExactResultHash { value } => Some(value.as_str()),
             _ => None,
         })
@@ -433,6 +997,20 @@ fn merge_perf_manifest_uses_perf_metric_hash_contract() {
         ("merge_perf_upsert_50pct", 5500_u64, 1_u64, 1_u64, 0_u64),
         ("merge_perf_localized_1pct", 18_u64, 40_u64, 39_u64, 199_u64),
         ("merge_perf_delete_5pct", 500_u64, 1_u64, 1_u64, 0_u64),
+        (
+            "merge_perf_upsert_10pct_schema_evolution",
+            1100_u64,
+            1_u64,
+            1_u64,
+            0_u64,
+        ),
+        (
+            "merge_perf_upsert_50pct_schema_evolution",
+            5500_u64,
+            1_u64,
+            1_u64,
+            0_u64,
+        ),
     ];
 
     for (case_id, source_rows, table_version, files_scanned, files_pruned) in expected_cases {
@@ -444,7 +1022,7 @@ fn merge_perf_manifest_uses_perf_metric_hash_contract() {
         let exact_result_hash = manifest_case
             .assertions
             .iter()
-            .find_map(|assertion| match assertion {
+            .find_map(|assertion| match &assertion.assertion {
                 ManifestAssertion::ExactResultHash { value } => Some(value.as_str()),
                 _ => None,
             })
@@ -452,7 +1030,7 @@ fn merge_perf_manifest_uses_perf_metric_hash_contract() {
         let schema_hash = manifest_case
             .assertions
             .iter()
-            .find_map(|assertion| match assertion {
+            .find_map(|assertion| match &assertion.assertion {
                 ManifestAssertion::SchemaHash { value } => Some(value.as_str()),
                 _ => None,
             })
@@ -527,7 +1105,7 @@ fn optimize_perf_manifest_uses_perf_metric_hash_contract() {
         let exact_result_hash = manifest_case
             .assertions
             .iter()
-            .find_map(|assertion| match assertion {
+            .find_map(|assertion| match &assertion.assertion {
                 ManifestAssertion::ExactResultHash { value } => Some(value.as_str()),
                 _ => None,
             })
@@ -535,7 +1113,7 @@ fn optimize_perf_manifest_uses_perf_metric_hash_contract() {
         let schema_hash = manifest_case
             .assertions
             .iter()
-            .find_map(|assertion| match assertion {
+            .find_map(|assertion| match &assertion.assertion {
                 ManifestAssertion::SchemaHash { value } => Some(value.as_str()),
                 _ => None,
             })
@@ -570,7 +1148,7 @@ fn optimize_perf_manifest_uses_perf_metric_hash_contract() {
     let vacuum_result_hash = vacuum_case
         .assertions
         .iter()
-        .find_map(|assertion| match assertion {
+        .find_map(|assertion| match &assertion.assertion {
             ManifestAssertion::ExactResultHash { value } => Some(value.as_str()),
             _ => None,
         })
@@ -578,7 +1156,7 @@ fn optimize_perf_manifest_uses_perf_metric_hash_contract() {
     let vacuum_case_schema_hash = vacuum_case
         .assertions
         .iter()
-        .find_map(|assertion| match assertion {
+        .find_map(|assertion| match &assertion.assertion {
             ManifestAssertion::SchemaHash { value } => Some(value.as_str()),
             _ => None,
         })
@@ -644,9 +1222,9 @@ fn p0_rust_manifest_includes_enabled_tpcds_cases() {
     let manifest_path = rust_manifest_path();
     let manifest = load_manifest(&manifest_path).expect("manifest should load");
 
-    for spec in phase1_query_catalog()
+    for spec in query_catalog()
         .into_iter()
-        .filter(|spec| spec.enabled)
+        .filter(|spec| spec.skip_reason_for_phase(DEFAULT_TPCDS_PHASE).is_none())
     {
         let case_id = format!("tpcds_{}", spec.id);
         let present = manifest
@@ -694,10 +1272,12 @@ fn p0_rust_manifest_does_not_require_exact_hash_assertions_for_concurrency_cases
     );
     assert!(
         concurrency_entries.iter().all(|entry| {
-            entry
-                .assertions
-                .iter()
-                .all(|assertion| !matches!(assertion, ManifestAssertion::ExactResultHash { .. }))
+            entry.assertions.iter().all(|assertion| {
+                !matches!(
+                    assertion.assertion,
+                    ManifestAssertion::ExactResultHash { .. }
+                )
+            })
         }),
         "concurrency cases should not use exact_result_hash assertions"
     );
@@ -708,7 +1288,13 @@ fn p0_rust_manifest_scopes_version_monotonicity_to_shared_table_concurrency_case
     let manifest_path = rust_manifest_path();
     let manifest = load_manifest(&manifest_path).expect("manifest should load");
 
-    let required_cases = ["concurrent_table_create", "concurrent_append_multi"];
+    let required_cases = [
+        "concurrent_table_create",
+        "concurrent_append_multi",
+        "concurrent_append_writers_2",
+        "concurrent_append_writers_4",
+        "concurrent_append_writers_8",
+    ];
     let missing = manifest
         .cases
         .iter()
@@ -716,10 +1302,9 @@ fn p0_rust_manifest_scopes_version_monotonicity_to_shared_table_concurrency_case
             entry.target == "concurrency" && required_cases.contains(&entry.id.as_str())
         })
         .filter(|entry| {
-            !entry
-                .assertions
-                .iter()
-                .any(|assertion| matches!(assertion, ManifestAssertion::VersionMonotonicity))
+            !entry.assertions.iter().any(|assertion| {
+                matches!(assertion.assertion, ManifestAssertion::VersionMonotonicity)
+            })
         })
         .map(|entry| entry.id.clone())
         .collect::<Vec<_>>();
@@ -736,10 +1321,9 @@ fn p0_rust_manifest_scopes_version_monotonicity_to_shared_table_concurrency_case
             entry.target == "concurrency" && !required_cases.contains(&entry.id.as_str())
         })
         .filter(|entry| {
-            entry
-                .assertions
-                .iter()
-                .any(|assertion| matches!(assertion, ManifestAssertion::VersionMonotonicity))
+            entry.assertions.iter().any(|assertion| {
+                matches!(assertion.assertion, ManifestAssertion::VersionMonotonicity)
+            })
         })
         .map(|entry| entry.id.clone())
         .collect::<Vec<_>>();
@@ -776,14 +1360,15 @@ fn p0_python_manifest_enforces_hash_assertions_for_every_enabled_case() {
         .iter()
         .filter(|case| case.enabled && case.runner == "python")
         .filter(|case| {
-            let has_result_hash = case
-                .assertions
-                .iter()
-                .any(|assertion| matches!(assertion, ManifestAssertion::ExactResultHash { .. }));
-            let has_schema_hash = case
-                .assertions
-                .iter()
-                .any(|assertion| matches!(assertion, ManifestAssertion::SchemaHash { .. }));
+            let has_result_hash = case.assertions.iter().any(|assertion| {
+                matches!(
+                    assertion.assertion,
+                    ManifestAssertion::ExactResultHash { .. }
+                )
+            });
+            let has_schema_hash = case.assertions.iter().any(|assertion| {
+                matches!(assertion.assertion, ManifestAssertion::SchemaHash { .. })
+            });
             !(has_result_hash && has_schema_hash)
         })
         .map(|case| case.id.clone())
@@ -794,3 +1379,156 @@ fn p0_python_manifest_enforces_hash_assertions_for_every_enabled_case() {
         "every enabled python case in core_python.yaml should include both exact_result_hash and schema_hash assertions, missing={missing:?}"
     );
 }
+
+#[test]
+fn manifest_rejects_zero_iteration_and_timeout_overrides() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    for field in ["iterations", "timeout_ms"] {
+        let file = temp.path().join("manifest.yaml");
+        std::fs::write(
+            &file,
+            format!(
+                "id: test\ndescription: budget manifest\ncases:\n  - id: scan_full_narrow\n    target: scan\n    {field}: 0\n"
+            ),
+        )
+        .expect("write manifest");
+
+        let err = load_manifest(&file).expect_err("zero override must fail");
+        assert!(
+            err.to_string()
+                .contains(&format!("case 'scan_full_narrow' must have {field} > 0")),
+            "{err}"
+        );
+    }
+}
+
+#[test]
+fn manifest_rejects_malformed_and_duplicate_tags() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    for (tags, expected) in [
+        (
+            "[Slow]",
+            "case tag 'Slow' must be lowercase letters, digits, '-', or '_', starting with a letter",
+        ),
+        ("[1st]", "case tag '1st'"),
+        ("[smoke, smoke]", "lists tag 'smoke' more than once"),
+    ] {
+        let file = temp.path().join("manifest.yaml");
+        std::fs::write(
+            &file,
+            format!(
+                "id: test\ndescription: tag manifest\ncases:\n  - id: scan_full_narrow\n    target: scan\n    tags: {tags}\n"
+            ),
+        )
+        .expect("write manifest");
+
+        let err = load_manifest(&file).expect_err("bad tags must fail");
+        assert!(err.to_string().contains(expected), "{tags}: {err}");
+    }
+}
+
+#[test]
+fn manifest_includes_merge_shared_cases_ahead_of_local_ones() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let common = temp.path().join("common-read.yaml");
+    let extra = temp.path().join("extra.yaml");
+    let file = temp.path().join("manifest.yaml");
+    std::fs::write(
+        &common,
+        "id: common-read\ndescription: shared reads\ncases:\n  - id: scan_full_narrow\n    target: scan\naliases:\n  scan_full: scan_full_narrow\n",
+    )
+    .expect("write common manifest");
+    std::fs::write(
+        &extra,
+        format!(
+            "id: extra\ndescription: extra reads\nincludes:\n  - {}\ncases:\n  - id: scan_filter_flag\n    target: scan\n",
+            common.display()
+        ),
+    )
+    .expect("write extra manifest");
+    std::fs::write(
+        &file,
+        format!(
+            "id: test\ndescription: composed\nincludes:\n  - {}\n  - {}\ncases:\n  - id: metadata_load\n    target: metadata\n",
+            common.display(),
+            extra.display()
+        ),
+    )
+    .expect("write manifest");
+
+    let manifest = load_manifest(&file).expect("composed manifest");
+    assert_eq!(
+        manifest
+            .cases
+            .iter()
+            .map(|case| case.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["scan_full_narrow", "scan_filter_flag", "metadata_load"],
+        "an include reached twice is merged once"
+    );
+    assert_eq!(
+        manifest.aliases.get("scan_full").map(String::as_str),
+        Some("scan_full_narrow")
+    );
+    assert_eq!(manifest.source_files.len(), 3);
+}
+
+#[test]
+fn manifest_includes_reject_cycles_missing_files_and_duplicate_cases() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let first = temp.path().join("first.yaml");
+    let second = temp.path().join("second.yaml");
+    std::fs::write(
+        &first,
+        format!(
+            "id: first\ndescription: cycle\nincludes:\n  - {}\n",
+            second.display()
+        ),
+    )
+    .expect("write first manifest");
+    std::fs::write(
+        &second,
+        format!(
+            "id: second\ndescription: cycle\nincludes:\n  - {}\n",
+            first.display()
+        ),
+    )
+    .expect("write second manifest");
+    let err = load_manifest(&first).expect_err("include cycle must fail");
+    assert!(
+        err.to_string().contains("manifest includes form a cycle"),
+        "{err}"
+    );
+
+    std::fs::write(
+        &first,
+        "id: first\ndescription: missing\nincludes:\n  - bench/manifests/missing.yaml\n",
+    )
+    .expect("write first manifest");
+    let err = load_manifest(&first).expect_err("missing include must fail");
+    assert!(
+        err.to_string()
+            .contains("include 'bench/manifests/missing.yaml' does not exist"),
+        "{err}"
+    );
+
+    std::fs::write(
+        &second,
+        "id: second\ndescription: shared\ncases:\n  - id: scan_full_narrow\n    target: scan\n",
+    )
+    .expect("write second manifest");
+    std::fs::write(
+        &first,
+        format!(
+            "id: first\ndescription: duplicate\nincludes:\n  - {}\ncases:\n  - id: scan_full_narrow\n    target: scan\n",
+            second.display()
+        ),
+    )
+    .expect("write first manifest");
+    let err = load_manifest(&first).expect_err("duplicate case must fail");
+    assert!(
+        err.to_string()
+            .contains("case 'scan_full_narrow' is defined more than once"),
+        "{err}"
+    );
+}
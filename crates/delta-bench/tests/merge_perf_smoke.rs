@@ -35,6 +35,9 @@ async fn merge_perf_smoke_runs_the_perf_owned_case_set() {
             "merge_perf_upsert_50pct",
             "merge_perf_localized_1pct",
             "merge_perf_delete_5pct",
+            "merge_perf_upsert_10pct_schema_evolution",
+            "merge_perf_upsert_50pct_schema_evolution",
+            "merge_perf_micro_upsert_100x10",
         ]
     );
     assert!(
@@ -45,4 +48,15 @@ async fn merge_perf_smoke_runs_the_perf_owned_case_set() {
             .map(|case| (&case.case, &case.failure))
             .collect::<Vec<_>>()
     );
+
+    let micro_upsert = cases
+        .iter()
+        .find(|case| case.case == "merge_perf_micro_upsert_100x10")
+        .expect("micro upsert case should run");
+    let metrics = micro_upsert.samples[0]
+        .metrics
+        .as_ref()
+        .expect("sample metrics should be present");
+    assert!(metrics.table_file_count.is_some_and(|files| files > 0));
+    assert_eq!(metrics.files_touched, None);
 }
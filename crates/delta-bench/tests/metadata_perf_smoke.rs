@@ -30,6 +30,11 @@ async fn metadata_perf_smoke_runs_the_dedicated_perf_owned_case_set() {
             "metadata_perf_time_travel_v0_long_history".to_string(),
             "metadata_perf_load_checkpointed_head".to_string(),
             "metadata_perf_load_uncheckpointed_head".to_string(),
+            "metadata_perf_load_head_deep_history".to_string(),
+            "metadata_perf_time_travel_v0_deep_history".to_string(),
+            "metadata_perf_time_travel_v256_deep_history".to_string(),
+            "metadata_perf_history_long_history".to_string(),
+            "metadata_perf_history_deep_history".to_string(),
         ]
     );
     let planned = plan_run_cases(
@@ -77,6 +82,11 @@ async fn metadata_perf_smoke_runs_the_dedicated_perf_owned_case_set() {
             "metadata_perf_time_travel_v0_long_history",
             "metadata_perf_load_checkpointed_head",
             "metadata_perf_load_uncheckpointed_head",
+            "metadata_perf_load_head_deep_history",
+            "metadata_perf_time_travel_v0_deep_history",
+            "metadata_perf_time_travel_v256_deep_history",
+            "metadata_perf_history_long_history",
+            "metadata_perf_history_deep_history",
         ]
     );
     assert!(
@@ -1,5 +1,9 @@
+use std::collections::BTreeMap;
+
 use delta_bench::cli::{BenchmarkLane, TimingPhase};
 use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::data::row_cache::FixtureRowCache;
+use delta_bench::runner::CaseHooks;
 use delta_bench::storage::StorageConfig;
 use delta_bench::suites::{merge, metadata, scan, write};
 
@@ -11,9 +15,18 @@ async fn write_samples_include_normalized_metrics() {
         .await
         .expect("generate fixtures");
 
-    let cases = write::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("run write suite");
+    let cases = write::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("run write suite");
     let first_sample = &cases[0].samples[0];
     let metrics = first_sample
         .metrics
@@ -32,9 +45,17 @@ async fn metadata_samples_report_table_version_metric() {
         .await
         .expect("generate fixtures");
 
-    let cases = metadata::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("run metadata suite");
+    let cases = metadata::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("run metadata suite");
     assert!(!cases.is_empty());
     assert!(
         cases[0].success,
@@ -45,6 +66,11 @@ async fn metadata_samples_report_table_version_metric() {
     let metrics = sample.metrics.as_ref().expect("metrics should be present");
     assert!(metrics.table_version.is_some());
     assert_eq!(sample.rows, metrics.rows_processed);
+    let log_reads = metrics
+        .log_reads
+        .as_ref()
+        .expect("table open should report log reads");
+    assert!(log_reads.commit_files_read > 0, "{log_reads:?}");
 }
 
 #[tokio::test]
@@ -55,9 +81,19 @@ async fn merge_samples_include_merge_scan_and_rewrite_metrics() {
         .await
         .expect("generate fixtures");
 
-    let cases = merge::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("run merge suite");
+    let cases = merge::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+        &BTreeMap::new(),
+    )
+    .await
+    .expect("run merge suite");
     assert!(
         cases.iter().any(|case| case.case == "merge_localized_1pct"),
         "expected merge_localized_1pct case; cases={:?}",
@@ -91,9 +127,17 @@ async fn scan_samples_do_not_populate_write_or_version_metrics() {
         .await
         .expect("generate fixtures");
 
-    let cases = scan::run(temp.path(), "sf1", TimingPhase::Execute, 0, 1, &storage)
-        .await
-        .expect("run scan suite");
+    let cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("run scan suite");
     let sample_metrics = cases
         .iter()
         .filter(|case| case.success)
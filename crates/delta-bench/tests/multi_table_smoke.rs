@@ -0,0 +1,64 @@
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::multi_table;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn generated_standard_fixtures_support_multi_table_suite() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+
+    let cases = multi_table::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("multi_table suite run");
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "multi_table_union_all",
+            "multi_table_join",
+            "multi_table_star_join",
+            "multi_table_count_all",
+        ]
+    );
+    assert!(
+        cases.iter().all(|case| case.success),
+        "multi_table failures: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+
+    // Six regions, ten item categories, and one row per registered table.
+    for (case, rows) in cases.iter().zip([6_u64, 6, 10, 10]) {
+        let metrics = case.samples[0]
+            .metrics
+            .as_ref()
+            .expect("sample metrics should be present");
+        assert_eq!(metrics.rows_processed, Some(rows), "{}", case.case);
+        assert!(metrics.result_hash.is_some(), "{}", case.case);
+    }
+}
+
+#[tokio::test]
+async fn multi_table_reports_missing_fixtures_as_case_failures() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let cases = multi_table::run(
+        temp.path(),
+        "sf1",
+        0,
+        1,
+        &CaseHooks::default(),
+        &StorageConfig::local(),
+    )
+    .await
+    .expect("multi_table suite should not hard-fail");
+    assert_eq!(cases.len(), 4);
+    assert!(cases.iter().all(|case| !case.success));
+}
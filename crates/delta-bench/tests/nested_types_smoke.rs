@@ -0,0 +1,62 @@
+use delta_bench::data::fixtures::{generate_fixtures, nested_table_batch};
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::nested_types;
+use deltalake_core::arrow::datatypes::DataType;
+
+#[test]
+fn nested_table_batch_has_struct_list_and_map_columns() {
+    let batch = nested_table_batch(0..8).expect("nested batch should build");
+    assert_eq!(batch.num_rows(), 8);
+    let schema = batch.schema();
+    assert!(matches!(
+        schema.field_with_name("attrs").unwrap().data_type(),
+        DataType::Struct(_)
+    ));
+    assert!(matches!(
+        schema.field_with_name("tags").unwrap().data_type(),
+        DataType::List(_)
+    ));
+    assert!(matches!(
+        schema.field_with_name("props").unwrap().data_type(),
+        DataType::Map(_, _)
+    ));
+}
+
+#[tokio::test]
+async fn nested_types_suite_scans_projects_and_writes() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("fixtures should be generated");
+
+    let cases = nested_types::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("nested_types suite should run");
+
+    assert_eq!(cases.len(), 4);
+    for case in &cases {
+        assert!(case.success, "{} failure: {:?}", case.case, case.failure);
+        let rows = case
+            .samples
+            .first()
+            .and_then(|sample| sample.metrics.as_ref())
+            .and_then(|metrics| metrics.rows_processed);
+        assert_eq!(rows, Some(10_000), "{}", case.case);
+    }
+}
+
+#[tokio::test]
+async fn nested_types_suite_reports_fixture_error_without_table() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = nested_types::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("nested_types suite should return fixture errors");
+
+    assert_eq!(cases.len(), 4);
+    assert!(cases.iter().all(|case| !case.success));
+}
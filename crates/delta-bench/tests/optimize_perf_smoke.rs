@@ -34,6 +34,7 @@ async fn optimize_perf_smoke_runs_the_perf_owned_case_set() {
             "optimize_perf_compact_small_files",
             "optimize_perf_noop_already_compact",
             "vacuum_perf_execute_lite",
+            "optimize_perf_after_dml_cycle",
         ]
     );
     assert!(
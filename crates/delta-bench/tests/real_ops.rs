@@ -1,7 +1,13 @@
+use std::collections::BTreeMap;
+
 use delta_bench::cli::{BenchmarkLane, TimingPhase};
-use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::data::fixtures::{generate_fixtures, load_rows};
+use delta_bench::data::row_cache::FixtureRowCache;
+use delta_bench::fingerprint::hash_json;
+use delta_bench::runner::CaseHooks;
 use delta_bench::storage::StorageConfig;
-use delta_bench::suites::{merge, optimize_vacuum, run_target, scan};
+use delta_bench::suites::{merge, optimize_vacuum, run_target, scan, MatrixPoint};
+use serde_json::json;
 
 const REQUALIFIED_SCAN_PRUNING_HIT_RESULT_HASH: &str =
     "sha256:b333362484714c71fa268b017d1c773a466e417959ec16336a749be670961eea";
@@ -14,9 +20,17 @@ async fn generated_fixtures_support_real_scan_suite() {
         .await
         .expect("generate fixtures");
 
-    let cases = scan::run(temp.path(), "sf1", TimingPhase::Execute, 0, 1, &storage)
-        .await
-        .expect("scan suite run");
+    let cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("scan suite run");
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| c.success));
 }
@@ -29,9 +43,17 @@ async fn scan_samples_include_physical_scan_metrics() {
         .await
         .expect("generate fixtures");
 
-    let cases = scan::run(temp.path(), "sf1", TimingPhase::Execute, 0, 1, &storage)
-        .await
-        .expect("scan suite run");
+    let cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("scan suite run");
     assert!(!cases.is_empty());
     let sample_metrics = cases
         .iter()
@@ -70,9 +92,17 @@ async fn scan_pruning_hit_scans_fewer_files_than_miss() {
         .await
         .expect("generate fixtures");
 
-    let cases = scan::run(temp.path(), "sf1", TimingPhase::Execute, 0, 1, &storage)
-        .await
-        .expect("scan suite run");
+    let cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("scan suite run");
 
     let hit_case = cases
         .iter()
@@ -148,25 +178,95 @@ async fn scan_pruning_hit_scans_fewer_files_than_miss() {
 }
 
 #[tokio::test]
-async fn scan_plan_phase_preserves_case_identity_and_hashes() {
+async fn scan_interop_reference_matches_the_python_scan_of_rows_parquet() {
     let temp = tempfile::tempdir().expect("tempdir");
     let storage = StorageConfig::local();
     generate_fixtures(temp.path(), "sf1", 42, true, &storage)
         .await
         .expect("generate fixtures");
 
-    let load_cases = scan::run(temp.path(), "sf1", TimingPhase::Load, 0, 1, &storage)
-        .await
-        .expect("scan suite run");
-    let plan_cases = scan::run(temp.path(), "sf1", TimingPhase::Plan, 0, 1, &storage)
-        .await
-        .expect("scan suite run");
-    let execute_cases = scan::run(temp.path(), "sf1", TimingPhase::Execute, 0, 1, &storage)
-        .await
-        .expect("scan suite run");
-    let validate_cases = scan::run(temp.path(), "sf1", TimingPhase::Validate, 0, 1, &storage)
+    // What python/delta_bench_interop/run_case.py computes for pyarrow_dataset_scan_perf.
+    let rows = load_rows(temp.path(), "sf1").expect("load rows");
+    let first_rows = &rows[..rows.len().min(5_000)];
+    let matched = first_rows
+        .iter()
+        .filter(|row| row.flag == Some(true))
+        .filter_map(|row| row.value_i64)
+        .filter(|value| *value > 0)
+        .collect::<Vec<_>>();
+    let expected_hash = hash_json(&json!({
+        "sum": matched.iter().sum::<i64>(),
+        "rows": matched.len(),
+    }))
+    .expect("hash");
+
+    let case = scan::run_single_case(
+        temp.path(),
+        "sf1",
+        "scan_interop_reference",
+        TimingPhase::Execute,
+        &storage,
+    )
+    .await
+    .expect("scan case run");
+    assert!(case.success, "{:?}", case.failure);
+    let metrics = case.samples[0].metrics.as_ref().expect("metrics");
+    assert_eq!(metrics.rows_processed, Some(first_rows.len() as u64));
+    assert_eq!(metrics.result_hash, Some(expected_hash));
+}
+
+#[tokio::test]
+async fn scan_plan_phase_preserves_case_identity_and_hashes() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
         .await
-        .expect("scan suite run");
+        .expect("generate fixtures");
+
+    let load_cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Load,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("scan suite run");
+    let plan_cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Plan,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("scan suite run");
+    let execute_cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Execute,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("scan suite run");
+    let validate_cases = scan::run(
+        temp.path(),
+        "sf1",
+        TimingPhase::Validate,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("scan suite run");
 
     assert!(
         plan_cases.iter().all(|case| !case.case.contains("_plan_")),
@@ -350,10 +450,19 @@ async fn generated_fixtures_support_optimize_vacuum_suite() {
         .await
         .expect("generate fixtures");
 
-    let cases = optimize_vacuum::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("optimize_vacuum suite run");
-    assert_eq!(cases.len(), 5);
+    let cases = optimize_vacuum::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("optimize_vacuum suite run");
+    assert_eq!(cases.len(), 7);
     assert!(
         cases.iter().all(|c| c.success),
         "optimize_vacuum failures: {:?}",
@@ -429,6 +538,32 @@ async fn generated_fixtures_support_optimize_vacuum_suite() {
         heavy_metrics.files_scanned.unwrap_or(0) >= heavy_metrics.files_pruned.unwrap_or(0),
         "heavy optimize should not prune more files than it scanned"
     );
+
+    let after_heavy_metrics = cases
+        .iter()
+        .find(|c| c.case == "vacuum_after_heavy_compaction")
+        .and_then(|case| case.samples.first())
+        .and_then(|sample| sample.metrics.as_ref())
+        .expect("vacuum after heavy compaction metrics should exist");
+    assert!(
+        after_heavy_metrics.rows_processed.unwrap_or(0) > 0,
+        "vacuum should delete the files heavy compaction replaced"
+    );
+
+    let vacuum_metrics = cases
+        .iter()
+        .find(|c| c.case == "vacuum_execute_with_concurrent_reads")
+        .and_then(|case| case.samples.first())
+        .and_then(|sample| sample.metrics.as_ref())
+        .expect("vacuum with concurrent reads metrics should exist");
+    let vacuum_reads = vacuum_metrics
+        .vacuum_reads
+        .as_ref()
+        .expect("vacuum_reads metrics should be present");
+    assert_eq!(vacuum_reads.readers, 2);
+    assert!(vacuum_reads.reads_during_vacuum >= vacuum_reads.readers);
+    assert!(vacuum_reads.baseline_read_p50_ms > 0.0);
+    assert!(vacuum_reads.read_latency_inflation > 0.0);
 }
 
 #[tokio::test]
@@ -439,9 +574,19 @@ async fn merge_partition_localized_case_reports_pruned_files() {
         .await
         .expect("generate fixtures");
 
-    let cases = merge::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("run merge suite");
+    let cases = merge::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+        &BTreeMap::new(),
+    )
+    .await
+    .expect("run merge suite");
     let localized = cases
         .iter()
         .find(|case| case.case == "merge_localized_1pct")
@@ -466,3 +611,62 @@ async fn merge_partition_localized_case_reports_pruned_files() {
         metrics.files_pruned
     );
 }
+
+#[tokio::test]
+async fn merge_matrix_points_run_alongside_builtin_cases() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+
+    let points = [0.25, 1.5]
+        .into_iter()
+        .map(|ratio: f64| {
+            (
+                format!(
+                    "merge_upsert_match_ratio_{}",
+                    ratio.to_string().replace('.', "p")
+                ),
+                MatrixPoint {
+                    template: "merge_upsert".to_string(),
+                    parameters: BTreeMap::from([("match_ratio".to_string(), ratio)]),
+                },
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    let cases = merge::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+        &FixtureRowCache::new(),
+        &BTreeMap::new(),
+        &points,
+    )
+    .await
+    .expect("run merge suite");
+
+    assert!(cases.iter().any(|case| case.case == "merge_upsert_10pct"));
+    let point = cases
+        .iter()
+        .find(|case| case.case == "merge_upsert_match_ratio_0p25")
+        .expect("expected matrix point case");
+    assert!(point.success, "matrix point failed: {:?}", point.failure);
+    let out_of_range = cases
+        .iter()
+        .find(|case| case.case == "merge_upsert_match_ratio_1p5")
+        .expect("expected out-of-range matrix point case");
+    assert!(!out_of_range.success);
+    assert!(
+        out_of_range
+            .failure
+            .as_ref()
+            .is_some_and(|failure| failure.message.contains("match_ratio")),
+        "{:?}",
+        out_of_range.failure
+    );
+}
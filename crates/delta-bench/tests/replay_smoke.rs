@@ -0,0 +1,140 @@
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::data::row_cache::FixtureRowCache;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::{replay, SuiteOptions};
+use delta_bench::trace::write_suite_case_traces;
+
+const TRACE: &str = r#"{"op":"append","rows":256,"think_time_ms":1}
+{"op":"merge","rows":128,"matched_fraction":0.5}
+{"op":"append","rows":64}
+{"op":"delete","predicate":"id % 20 = 0"}
+"#;
+
+#[tokio::test]
+async fn replay_records_one_sample_per_traced_operation() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+    let trace_path = temp.path().join("trace.jsonl");
+    std::fs::write(&trace_path, TRACE).expect("write trace");
+
+    let cases = replay::run_with_trace(
+        temp.path(),
+        "sf1",
+        &trace_path,
+        0,
+        2,
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("replay suite run");
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "replay_append",
+            "replay_merge",
+            "replay_delete",
+            "replay_optimize"
+        ]
+    );
+
+    for (case, samples) in cases.iter().zip([4, 2, 2]) {
+        assert!(case.success, "{} failure: {:?}", case.case, case.failure);
+        assert_eq!(case.samples.len(), samples, "{}", case.case);
+    }
+    let append_rows = cases[0]
+        .samples
+        .iter()
+        .map(|sample| sample.rows)
+        .collect::<Vec<_>>();
+    assert_eq!(append_rows, vec![Some(256), Some(64), Some(256), Some(64)]);
+
+    let optimize = &cases[3];
+    assert!(!optimize.success);
+    assert_eq!(optimize.failure_kind.as_deref(), Some("unsupported"));
+}
+
+#[tokio::test]
+async fn replay_runs_traces_generated_from_suite_cases() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+    let trace_dir = temp.path().join("traces");
+    let written = write_suite_case_traces("sf1", Some("delete_5pct_scattered"), &trace_dir)
+        .expect("write generated traces");
+    assert_eq!(written, vec![trace_dir.join("delete_5pct_scattered.jsonl")]);
+
+    let cases = replay::run_with_trace(
+        temp.path(),
+        "sf1",
+        &written[0],
+        0,
+        1,
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("replay suite run");
+    let delete = cases
+        .iter()
+        .find(|case| case.case == "replay_delete")
+        .expect("replay_delete case");
+    assert!(
+        delete.success,
+        "replay_delete failure: {:?}",
+        delete.failure
+    );
+    assert_eq!(delete.samples.len(), 1);
+    assert_eq!(delete.samples[0].rows, Some(500));
+}
+
+#[tokio::test]
+async fn replay_rejects_malformed_trace() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let trace_path = temp.path().join("trace.jsonl");
+    std::fs::write(&trace_path, "{\"op\":\"append\"}\n").expect("write trace");
+
+    let err = replay::run_with_trace(
+        temp.path(),
+        "sf1",
+        &trace_path,
+        0,
+        1,
+        &StorageConfig::local(),
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect_err("malformed trace should fail");
+    assert!(err.to_string().contains("line 1"), "{err}");
+}
+
+#[tokio::test]
+async fn replay_without_a_trace_option_fails_every_case() {
+    let temp = tempfile::tempdir().expect("tempdir");
+
+    let cases = replay::run(
+        temp.path(),
+        "sf1",
+        0,
+        1,
+        &StorageConfig::local(),
+        &FixtureRowCache::new(),
+        &SuiteOptions::default(),
+    )
+    .await
+    .expect("replay suite run");
+    assert_eq!(cases.len(), replay::case_names().len());
+    for case in &cases {
+        assert!(!case.success);
+        let message = &case.failure.as_ref().expect("failure").message;
+        assert!(message.contains("--replay-trace"), "{message}");
+    }
+}
@@ -0,0 +1,92 @@
+use delta_bench::cli::BenchmarkLane;
+use delta_bench::data::fixtures::{generate_fixtures_with_profile, FixtureProfile};
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::restore;
+
+#[tokio::test]
+async fn restore_suite_runs_against_many_versions_fixtures() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures_with_profile(
+        temp.path(),
+        "sf1",
+        42,
+        true,
+        FixtureProfile::ManyVersions,
+        &storage,
+    )
+    .await
+    .expect("fixtures should be generated");
+
+    let cases = restore::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("restore suite should run");
+
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "restore_shallow_1_version",
+            "restore_mid_history_24_versions",
+            "restore_deep_to_version_0",
+            "restore_then_vacuum_24_versions",
+        ]
+    );
+    assert!(
+        cases.iter().all(|case| case.success),
+        "restore failures: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+
+    let vacuum_case = cases
+        .iter()
+        .find(|case| case.case == "restore_then_vacuum_24_versions")
+        .expect("restore-then-vacuum case should run");
+    let restore_vacuum = vacuum_case.samples[0]
+        .metrics
+        .as_ref()
+        .and_then(|metrics| metrics.restore_vacuum.as_ref())
+        .expect("restore_vacuum metrics should be present");
+    assert_eq!(restore_vacuum.files_vacuumed, 24);
+    assert_eq!(restore_vacuum.files_referenced, 25);
+}
+
+#[tokio::test]
+async fn restore_suite_reports_fixture_error_without_many_versions_profile() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = restore::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("restore suite should return fixture errors");
+
+    assert_eq!(cases.len(), 4);
+    assert!(cases.iter().all(|case| !case.success));
+    assert!(cases.iter().all(|case| case
+        .failure
+        .as_ref()
+        .is_some_and(|failure| failure.message.contains("many_versions"))));
+}
@@ -0,0 +1,134 @@
+use delta_bench::export::{export_run_result, redact_text, REDACTED};
+use delta_bench::results::{BenchRunResult, CapturedPlan};
+
+const RESULT: &str = r#"
+{
+  "schema_version": 5,
+  "context": {
+    "schema_version": 5,
+    "label": "nightly-infra-7",
+    "git_sha": "abc123",
+    "created_at": "2026-02-27T22:48:22.208400Z",
+    "host": "bench-runner-07.internal",
+    "suite": "scan",
+    "scale": "sf1",
+    "iterations": 1,
+    "warmup": 0,
+    "dataset_fingerprint": "sha256:dataset",
+    "storage_backend": "s3",
+    "run_id": "run-123",
+    "fixture_recipe_hash": "sha256:recipe",
+    "fidelity_fingerprint": "sha256:fidelity",
+    "backend_profile": "minio-lab",
+    "hardening_profile_id": "lab-hardening",
+    "hardening_profile_sha256": "sha256:hardening",
+    "boot_params": "root=/dev/nvme0n1p2 isolcpus=2-7",
+    "fixtures_dir": "/srv/corp-bench/fixtures/tiny_smoke",
+    "results_dir": "/srv/corp-bench/results"
+  },
+  "cases": [
+    {
+      "case": "scan_full_narrow",
+      "success": false,
+      "validation_passed": false,
+      "perf_status": "invalid",
+      "classification": "supported",
+      "compatibility_key": "sha256:compat",
+      "run_summary": {
+        "sample_count": 1,
+        "median_ms": 10.2,
+        "host_label": "bench-runner-07.internal",
+        "fidelity_fingerprint": "sha256:fidelity"
+      },
+      "samples": [{"elapsed_ms": 10.2, "rows": 12, "bytes": null, "metrics": null}],
+      "physical_plan": {
+        "format": "text",
+        "plan": "DataSourceExec: file_groups={1 group: [[srv/corp-bench/fixtures/sf1/part-0.parquet]]}, file_type=parquet\n"
+      },
+      "failure_kind": "execution_error",
+      "failure": {
+        "message": "failed to open 's3://corp-bench/sf1/narrow_sales_delta' via endpoint=http://10.0.0.5:9000 (cache /home/ci/fixtures/sf1)"
+      }
+    }
+  ]
+}
+"#;
+
+#[test]
+fn redacted_export_strips_infrastructure_and_keeps_metrics_and_hashes() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let input = temp.path().join("scan.json");
+    let out = temp.path().join("shared/scan.json");
+    std::fs::write(&input, RESULT).expect("write result");
+
+    export_run_result(&input, &out, true).expect("export");
+    let exported = std::fs::read_to_string(&out).expect("read export");
+    for leaked in [
+        "nightly-infra-7",
+        "bench-runner-07",
+        "minio-lab",
+        "corp-bench",
+        "10.0.0.5",
+    ] {
+        assert!(!exported.contains(leaked), "{leaked} leaked: {exported}");
+    }
+    assert!(!exported.contains("/home/ci"), "{exported}");
+
+    let result = serde_json::from_str::<BenchRunResult>(&exported).expect("parse export");
+    assert_eq!(result.context.label, REDACTED);
+    assert_eq!(result.context.host, REDACTED);
+    assert_eq!(result.context.run_id, None);
+    assert_eq!(result.context.boot_params, None);
+    assert_eq!(result.context.fixtures_dir, None);
+    assert_eq!(result.context.results_dir, None);
+    assert_eq!(
+        result.context.fidelity_fingerprint.as_deref(),
+        Some("sha256:fidelity")
+    );
+    assert_eq!(
+        result.context.hardening_profile_sha256.as_deref(),
+        Some("sha256:hardening")
+    );
+    assert_eq!(result.context.storage_backend.as_deref(), Some("s3"));
+    let case = &result.cases[0];
+    assert_eq!(case.compatibility_key.as_deref(), Some("sha256:compat"));
+    assert_eq!(case.samples[0].elapsed_ms, 10.2);
+    let summary = case.run_summary.as_ref().expect("run summary");
+    assert_eq!(summary.host_label, None);
+    assert_eq!(summary.median_ms, Some(10.2));
+    assert_eq!(case.physical_plan, None);
+    assert_eq!(
+        case.failure.as_ref().expect("failure").message,
+        "failed to open '<redacted>' via endpoint=<redacted> (cache <redacted>)"
+    );
+}
+
+#[test]
+fn plain_export_keeps_the_result_unchanged() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let input = temp.path().join("scan.json");
+    let out = temp.path().join("scan.export.json");
+    std::fs::write(&input, RESULT).expect("write result");
+
+    export_run_result(&input, &out, false).expect("export");
+    let result = serde_json::from_slice::<BenchRunResult>(&std::fs::read(&out).expect("read"))
+        .expect("parse export");
+    assert_eq!(result.context.host, "bench-runner-07.internal");
+    assert_eq!(result.context.backend_profile.as_deref(), Some("minio-lab"));
+    assert!(matches!(
+        result.cases[0].physical_plan,
+        Some(CapturedPlan::Text(_))
+    ));
+}
+
+#[test]
+fn redact_text_leaves_relative_names_and_plain_words() {
+    assert_eq!(
+        redact_text("table narrow_sales_delta at version 3: ratio=0.5"),
+        "table narrow_sales_delta at version 3: ratio=0.5"
+    );
+    assert_eq!(
+        redact_text("path=\"/tmp/x\", url https://host/a?b=c."),
+        "path=\"<redacted>\", url <redacted>."
+    );
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+use delta_bench::runner::{
+    render_progress_event, run_case, run_case_async, CaseExecutionResult, CaseHooks,
+    ProgressCallback, ProgressEvent,
+};
+
+#[tokio::test]
+async fn cases_report_start_iterations_and_finish_in_order() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    let callback: ProgressCallback = Arc::new(move |event: &ProgressEvent| {
+        recorded.lock().expect("lock").push(event.clone());
+    });
+
+    let hooks = CaseHooks::new().with_progress(callback);
+    let ok = run_case_async("async_case", 1, 2, &hooks, || async {
+        Ok::<_, String>(1_u64)
+    })
+    .await;
+    assert!(matches!(ok, CaseExecutionResult::Success(_)));
+    let failed = run_case("failing_case", 0, 1, &hooks, || Err::<u64, _>("boom"));
+    assert!(matches!(failed, CaseExecutionResult::Failure(_)));
+
+    let events = events.lock().expect("lock");
+    let rendered = events
+        .iter()
+        .map(|event| match event {
+            ProgressEvent::CaseStarted { case } => format!("start:{case}"),
+            ProgressEvent::IterationFinished {
+                case,
+                iteration,
+                iterations,
+                ..
+            } => format!("iteration:{case}:{iteration}/{iterations}"),
+            ProgressEvent::CaseFinished { case, outcome, .. } => {
+                format!("finish:{case}:{outcome}")
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rendered,
+        [
+            "start:async_case",
+            "iteration:async_case:1/2",
+            "iteration:async_case:2/2",
+            "finish:async_case:ok",
+            "start:failing_case",
+            "finish:failing_case:failed",
+        ],
+        "warmup iterations are not reported"
+    );
+    assert!(matches!(
+        events[3],
+        ProgressEvent::CaseFinished {
+            elapsed_ms: Some(_),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn progress_lines_name_the_case_and_step() {
+    assert_eq!(
+        render_progress_event(&ProgressEvent::IterationFinished {
+            case: "scan_full_narrow".to_string(),
+            iteration: 2,
+            iterations: 5,
+            elapsed_ms: 12.345,
+        }),
+        "[progress] scan_full_narrow: iteration 2/5 12.3 ms"
+    );
+    assert_eq!(
+        render_progress_event(&ProgressEvent::CaseFinished {
+            case: "scan_full_narrow".to_string(),
+            outcome: "failed",
+            elapsed_ms: None,
+        }),
+        "[progress] scan_full_narrow: failed"
+    );
+}
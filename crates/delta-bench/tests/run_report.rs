@@ -0,0 +1,272 @@
+use delta_bench::pushgateway::{parse_push_gateway, push_url};
+use delta_bench::report::{render_run_report, ReporterRegistry};
+use delta_bench::results::{BenchRunResult, RunWarning};
+
+const RESULT: &str = r#"
+{
+  "schema_version": 5,
+  "context": {
+    "schema_version": 5,
+    "label": "pr-4120",
+    "git_sha": "abc123",
+    "created_at": "2026-02-27T22:48:22.208400Z",
+    "host": "bench-runner",
+    "suite": "scan",
+    "scale": "sf1",
+    "iterations": 3,
+    "warmup": 1,
+    "ref_issues": ["delta-io/delta-rs#4120"]
+  },
+  "cases": [
+    {
+      "case": "scan_full_narrow",
+      "success": true,
+      "validation_passed": true,
+      "perf_status": "trusted",
+      "classification": "supported",
+      "samples": [{"elapsed_ms": 10.0, "rows": 12, "bytes": null, "metrics": null}],
+      "elapsed_stats": {
+        "min_ms": 9.5,
+        "max_ms": 10.5,
+        "mean_ms": 10.0,
+        "median_ms": 10.0,
+        "stddev_ms": 0.5,
+        "cv_pct": 5.0
+      },
+      "failure": null
+    },
+    {
+      "case": "scan_filter_<region>",
+      "success": false,
+      "validation_passed": false,
+      "perf_status": "invalid",
+      "classification": "supported",
+      "samples": [],
+      "failure_kind": "execution_error",
+      "failure": {"message": "filter failed: \"region\" is missing, a|b"}
+    }
+  ]
+}
+"#;
+
+fn result() -> BenchRunResult {
+    serde_json::from_str(RESULT).expect("parse result")
+}
+
+#[test]
+fn builtin_registry_lists_formats_and_rejects_unknown_ones() {
+    let registry = ReporterRegistry::builtin();
+    assert_eq!(
+        registry.names(),
+        vec!["markdown", "html", "csv", "gh-comment", "prometheus"]
+    );
+    let err = registry
+        .render("pdf", &result())
+        .expect_err("unknown format");
+    assert!(
+        err.to_string()
+            .contains("expected one of: markdown, html, csv, gh-comment, prometheus"),
+        "{err}"
+    );
+}
+
+#[test]
+fn markdown_and_gh_comment_reports_show_cases_and_failures() {
+    let registry = ReporterRegistry::builtin();
+    let markdown = registry.render("markdown", &result()).expect("markdown");
+    assert!(markdown.starts_with("## delta-bench scan (sf1, pr-4120)\n"));
+    assert!(
+        markdown.contains("| scan_full_narrow | ok | 10.000 | 9.500 | 10.500 | 0.500 | 5.000 |")
+    );
+    assert!(markdown.contains("| scan_filter_<region> | invalid | - | - | - | - | - |"));
+    assert!(markdown.contains("- ref_issues: `delta-io/delta-rs#4120`"));
+    assert!(
+        markdown.contains("- `scan_filter_<region>`: filter failed: \"region\" is missing, a\\|b")
+    );
+
+    let comment = registry
+        .render("gh-comment", &result())
+        .expect("gh-comment");
+    assert!(comment.starts_with("<!-- delta-bench:scan:sf1 -->\n"));
+    assert!(comment.contains("**2 case(s):** 1 ok, 1 invalid"));
+    assert!(comment.contains("Revision: `abc123`\n"));
+    assert!(comment.contains("Refs: delta-io/delta-rs#4120"));
+    assert!(comment.contains("<summary>Failures</summary>"));
+}
+
+#[test]
+fn reports_flag_a_dirty_revision() {
+    let mut result = result();
+    result.context.git_dirty = Some(true);
+    let registry = ReporterRegistry::builtin();
+
+    let markdown = registry.render("markdown", &result).expect("markdown");
+    assert!(
+        markdown.contains("- git_sha: `abc123 (dirty)`"),
+        "{markdown}"
+    );
+    let comment = registry.render("gh-comment", &result).expect("gh-comment");
+    assert!(comment.contains("Revision: `abc123` (dirty)"), "{comment}");
+}
+
+#[test]
+fn reports_list_run_warnings_only_when_present() {
+    let registry = ReporterRegistry::builtin();
+    let markdown = registry.render("markdown", &result()).expect("markdown");
+    assert!(!markdown.contains("Warnings"), "{markdown}");
+
+    let mut result = result();
+    result.warnings = vec![
+        RunWarning {
+            kind: "degraded_fidelity".to_string(),
+            case: None,
+            message: "cpu steal was 7.5%".to_string(),
+        },
+        RunWarning {
+            kind: "outliers_detected".to_string(),
+            case: Some("scan_full_narrow".to_string()),
+            message: "1 of 5 samples <fall> outside".to_string(),
+        },
+    ];
+    let round_trip: BenchRunResult =
+        serde_json::from_slice(&serde_json::to_vec(&result).expect("serialize result"))
+            .expect("parse result");
+    assert_eq!(round_trip.warnings, result.warnings);
+
+    let markdown = registry.render("markdown", &result).expect("markdown");
+    assert!(markdown.contains("### Warnings\n\n- degraded_fidelity: cpu steal was 7.5%\n"));
+    assert!(markdown.contains("- outliers_detected (scan_full_narrow): 1 of 5 samples"));
+    let comment = registry.render("gh-comment", &result).expect("gh-comment");
+    assert!(
+        comment.contains("<summary>Warnings (2)</summary>"),
+        "{comment}"
+    );
+    let html = registry.render("html", &result).expect("html");
+    assert!(html.contains("<h2>Warnings</h2>"));
+    assert!(html.contains("1 of 5 samples &lt;fall&gt; outside"));
+}
+
+#[test]
+fn csv_and_html_reports_escape_their_values() {
+    let registry = ReporterRegistry::builtin();
+    let csv = registry.render("csv", &result()).expect("csv");
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "suite,scale,label,case,status,mean_ms,min_ms,max_ms,stddev_ms,cv_pct,failure"
+    );
+    assert_eq!(
+        lines[1],
+        "scan,sf1,pr-4120,scan_full_narrow,ok,10.000,9.500,10.500,0.500,5.000,"
+    );
+    assert_eq!(
+        lines[2],
+        "scan,sf1,pr-4120,scan_filter_<region>,invalid,,,,,,\"filter failed: \"\"region\"\" is missing, a|b\""
+    );
+
+    let html = registry.render("html", &result()).expect("html");
+    assert!(html.contains("<td>scan_filter_&lt;region&gt;</td>"));
+    assert!(html.contains("filter failed: &quot;region&quot; is missing"));
+    assert!(!html.contains("scan_filter_<region>"));
+}
+
+#[test]
+fn prometheus_report_labels_trusted_case_medians_and_throughput() {
+    let mut result = result();
+    result.context.storage_backend = Some("s3".to_string());
+    result.cases[0].case = "scan_\"quoted\"".to_string();
+    let metrics = ReporterRegistry::builtin()
+        .render("prometheus", &result)
+        .expect("prometheus");
+    let labels =
+        r#"{suite="scan",case="scan_\"quoted\"",scale="sf1",backend="s3",label="pr-4120"}"#;
+    assert!(
+        metrics.contains("# TYPE delta_bench_case_median_ms gauge\n"),
+        "{metrics}"
+    );
+    assert!(
+        metrics.contains(&format!("delta_bench_case_median_ms{labels} 10\n")),
+        "{metrics}"
+    );
+    assert!(
+        metrics.contains(&format!(
+            "delta_bench_case_throughput_rows_per_second{labels} 1200\n"
+        )),
+        "{metrics}"
+    );
+    assert!(
+        !metrics.contains("scan_filter"),
+        "untrusted cases are not exported: {metrics}"
+    );
+}
+
+#[test]
+fn push_gateway_urls_group_by_suite_and_label() {
+    let gateway = parse_push_gateway("http://pushgateway:9091/").expect("gateway");
+    assert_eq!(
+        push_url(&gateway, &result()).as_str(),
+        "http://pushgateway:9091/metrics/job/delta_bench/suite/scan/label/pr-4120"
+    );
+    let err = parse_push_gateway("pushgateway:9091").expect_err("no scheme");
+    assert!(err.to_string().contains("must use http or https"), "{err}");
+}
+
+#[test]
+fn render_run_report_checks_the_format_before_reading_the_input() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let missing = temp.path().join("missing.json");
+    let err = render_run_report(&ReporterRegistry::builtin(), &missing, "pdf")
+        .expect_err("unknown format");
+    assert!(
+        err.to_string().contains("unknown report format 'pdf'"),
+        "{err}"
+    );
+
+    let input = temp.path().join("scan.json");
+    std::fs::write(&input, RESULT).expect("write result");
+    let rendered =
+        render_run_report(&ReporterRegistry::builtin(), &input, "csv").expect("render csv");
+    assert_eq!(rendered.lines().count(), 3);
+}
+
+#[cfg(feature = "custom-reporters")]
+#[test]
+fn custom_reporters_register_alongside_builtin_formats() {
+    use delta_bench::error::BenchResult;
+    use delta_bench::report::Reporter;
+
+    struct CaseCount;
+
+    impl Reporter for CaseCount {
+        fn name(&self) -> &'static str {
+            "case-count"
+        }
+
+        fn render(&self, result: &BenchRunResult) -> BenchResult<String> {
+            Ok(result.cases.len().to_string())
+        }
+    }
+
+    struct ShadowMarkdown;
+
+    impl Reporter for ShadowMarkdown {
+        fn name(&self) -> &'static str {
+            "markdown"
+        }
+
+        fn render(&self, _result: &BenchRunResult) -> BenchResult<String> {
+            Ok(String::new())
+        }
+    }
+
+    let mut registry = ReporterRegistry::builtin();
+    registry.register(Box::new(CaseCount)).expect("register");
+    assert_eq!(
+        registry.render("case-count", &result()).expect("render"),
+        "2"
+    );
+    let err = registry
+        .register(Box::new(ShadowMarkdown))
+        .expect_err("duplicate format");
+    assert!(err.to_string().contains("already registered"), "{err}");
+}
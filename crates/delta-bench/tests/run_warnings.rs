@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use delta_bench::results::{BenchContext, CaseResult};
+use delta_bench::warnings::{collect_run_warnings, FRESH_FIXTURE_WINDOW};
+use serde_json::json;
+
+fn context(extra: serde_json::Value) -> BenchContext {
+    let mut context = json!({
+        "schema_version": 5,
+        "label": "nightly",
+        "git_sha": "abc123",
+        "created_at": "2026-03-02T10:00:00Z",
+        "host": "bench-runner",
+        "suite": "interop_py",
+        "scale": "sf1",
+        "iterations": 5,
+        "warmup": 1
+    });
+    for (key, value) in extra.as_object().expect("object").clone() {
+        context[key] = value;
+    }
+    serde_json::from_value(context).expect("parse context")
+}
+
+fn case(name: &str, samples: serde_json::Value) -> CaseResult {
+    serde_json::from_value(json!({
+        "case": name,
+        "success": true,
+        "validation_passed": true,
+        "perf_status": "trusted",
+        "classification": "supported",
+        "samples": samples,
+        "failure": null
+    }))
+    .expect("parse case")
+}
+
+fn timed(elapsed_ms: &[f64]) -> serde_json::Value {
+    elapsed_ms
+        .iter()
+        .map(|elapsed| json!({"elapsed_ms": elapsed, "rows": 1, "bytes": null, "metrics": null}))
+        .collect()
+}
+
+#[test]
+fn clean_run_has_no_warnings() {
+    let cases = [case(
+        "scan_full_narrow",
+        timed(&[10.0, 10.5, 11.0, 10.2, 10.8]),
+    )];
+
+    let warnings = collect_run_warnings(
+        &context(json!({"cpu_steal_pct": 0.4, "git_dirty": false})),
+        &cases,
+        Some(FRESH_FIXTURE_WINDOW * 2),
+    );
+
+    assert!(warnings.is_empty(), "{warnings:?}");
+}
+
+#[test]
+fn run_wide_warnings_cover_fidelity_and_fresh_fixtures() {
+    let warnings = collect_run_warnings(
+        &context(json!({"cpu_steal_pct": 12.5, "git_dirty": true})),
+        &[],
+        Some(Duration::from_secs(42)),
+    );
+
+    let summary = warnings
+        .iter()
+        .map(|warning| (warning.kind.as_str(), warning.case.as_deref()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            ("degraded_fidelity", None),
+            ("degraded_fidelity", None),
+            ("fixture_regenerated", None),
+        ]
+    );
+    assert!(warnings[0].message.contains("cpu steal was 12.5%"));
+    assert!(warnings[1]
+        .message
+        .contains("abc123 had uncommitted changes"));
+    assert!(warnings[2]
+        .message
+        .contains("regenerated 42s before the run"));
+}
+
+#[test]
+fn case_warnings_cover_retries_outliers_and_partial_metrics() {
+    let cases = [
+        case(
+            "interop_write",
+            json!([
+                {"elapsed_ms": 10.0, "rows": 1, "bytes": null, "metrics": {
+                    "interop_attempts": {"attempts": 3, "retry_ms": 12.5, "failures": ["a", "b"]}
+                }},
+                {"elapsed_ms": 10.0, "rows": 1, "bytes": null, "metrics": {
+                    "interop_attempts": {"attempts": 1, "retry_ms": 0.0, "failures": []}
+                }}
+            ]),
+        ),
+        case("scan_full_narrow", timed(&[10.0, 10.5, 11.0, 10.2, 95.0])),
+        case(
+            "scan_filter_flag",
+            json!([
+                {"elapsed_ms": 10.0, "rows": 1, "bytes": null, "metrics": {"files_scanned": 4, "files_pruned": 2}},
+                {"elapsed_ms": 10.0, "rows": 1, "bytes": null, "metrics": {"files_scanned": 4}}
+            ]),
+        ),
+    ];
+
+    let warnings = collect_run_warnings(&context(json!({})), &cases, None);
+
+    let summary = warnings
+        .iter()
+        .map(|warning| (warning.kind.as_str(), warning.case.as_deref()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            ("retries_occurred", Some("interop_write")),
+            ("outliers_detected", Some("scan_full_narrow")),
+            ("partial_metrics", Some("scan_filter_flag")),
+        ]
+    );
+    assert!(warnings[0]
+        .message
+        .starts_with("2 attempt(s) failed and were retried, spending 12.5 ms"));
+    assert!(
+        warnings[1].message.starts_with("1 of 5 samples")
+            && warnings[1].message.ends_with("ms: 95.000"),
+        "{}",
+        warnings[1].message
+    );
+    assert_eq!(
+        warnings[2].message,
+        "1 of 2 samples lack metrics reported by the others: files_pruned"
+    );
+}
+
+#[test]
+fn observe_only_assertion_violations_are_surfaced_as_run_warnings() {
+    let mut observed = case("merge_localized_1pct", timed(&[10.0]));
+    observed.warnings = vec![
+        "min_files_pruned assertion failed at sample 1: expected files_pruned >= 1, found 0"
+            .to_string(),
+    ];
+
+    let warnings = collect_run_warnings(&context(json!({})), &[observed], None);
+
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert_eq!(warnings[0].kind, "assertion_warned");
+    assert_eq!(warnings[0].case.as_deref(), Some("merge_localized_1pct"));
+    assert!(warnings[0]
+        .message
+        .starts_with("min_files_pruned assertion failed"));
+}
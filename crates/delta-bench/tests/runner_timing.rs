@@ -1,10 +1,14 @@
+use std::collections::BTreeMap;
 use std::thread;
 use std::time::Duration;
 
+use delta_bench::results::SampleMetrics;
 use delta_bench::runner::{
     run_case, run_case_async, run_case_async_custom_timing, run_case_async_with_async_setup,
     run_case_async_with_async_setup_custom_timing, run_case_async_with_setup,
-    run_case_async_with_timing_phase, CaseExecutionResult, PhaseTiming, TimedSample, TimingPhase,
+    run_case_async_with_timing_phase, run_case_open_loop, run_scenario, CaseBudget,
+    CaseExecutionResult, CaseHooks, CaseSeeds, OpenLoopConfig, PhaseTiming, ScenarioStep,
+    TimedSample, TimingPhase,
 };
 
 #[tokio::test]
@@ -13,6 +17,7 @@ async fn setup_delay_is_not_counted_in_iteration_elapsed_time() {
         "timing_case",
         0,
         1,
+        &CaseHooks::default(),
         || -> Result<(), String> {
             thread::sleep(Duration::from_millis(25));
             Ok(())
@@ -31,6 +36,15 @@ async fn setup_delay_is_not_counted_in_iteration_elapsed_time() {
         "setup delay leaked into measured time: {} ms (expected < 50 ms)",
         case.samples[0].elapsed_ms
     );
+    let setup_ms = case.samples[0]
+        .metrics
+        .as_ref()
+        .and_then(|metrics| metrics.setup_ms)
+        .expect("setup time should be recorded separately");
+    assert!(
+        setup_ms >= 25.0,
+        "setup_ms {setup_ms} should cover the setup delay"
+    );
 }
 
 #[tokio::test]
@@ -39,6 +53,7 @@ async fn async_setup_delay_is_not_counted_in_iteration_elapsed_time() {
         "timing_case_async_setup",
         0,
         1,
+        &CaseHooks::default(),
         || async {
             tokio::time::sleep(Duration::from_millis(25)).await;
             Ok::<(), String>(())
@@ -57,6 +72,15 @@ async fn async_setup_delay_is_not_counted_in_iteration_elapsed_time() {
         "async setup delay leaked into measured time: {} ms (expected < 50 ms)",
         case.samples[0].elapsed_ms
     );
+    let setup_ms = case.samples[0]
+        .metrics
+        .as_ref()
+        .and_then(|metrics| metrics.setup_ms)
+        .expect("setup time should be recorded separately");
+    assert!(
+        setup_ms >= 25.0,
+        "setup_ms {setup_ms} should cover the setup delay"
+    );
 }
 
 #[tokio::test]
@@ -65,6 +89,7 @@ async fn successful_case_includes_elapsed_stats() {
         "timing_case_stats",
         0,
         3,
+        &CaseHooks::default(),
         || -> Result<(), String> { Ok(()) },
         |_| async {
             tokio::time::sleep(Duration::from_millis(2)).await;
@@ -89,14 +114,20 @@ async fn successful_case_includes_elapsed_stats() {
 #[test]
 fn partial_failure_case_does_not_expose_perf_stats_for_collected_samples() {
     let mut attempts = 0_u32;
-    let result = run_case("timing_case_failure_stats", 0, 3, || {
-        attempts += 1;
-        if attempts < 3 {
-            Ok::<u64, &'static str>(1)
-        } else {
-            Err::<u64, &'static str>("boom")
-        }
-    });
+    let result = run_case(
+        "timing_case_failure_stats",
+        0,
+        3,
+        &CaseHooks::default(),
+        || {
+            attempts += 1;
+            if attempts < 3 {
+                Ok::<u64, &'static str>(1)
+            } else {
+                Err::<u64, &'static str>("boom")
+            }
+        },
+    );
 
     let case = match result {
         CaseExecutionResult::Success(case) => panic!("expected failure, got success: {:?}", case),
@@ -112,7 +143,7 @@ fn partial_failure_case_does_not_expose_perf_stats_for_collected_samples() {
 #[test]
 fn warmup_failure_in_sync_runner_is_reported_as_case_failure() {
     let mut attempts = 0_u32;
-    let result = run_case("warmup_sync_failure", 1, 1, || {
+    let result = run_case("warmup_sync_failure", 1, 1, &CaseHooks::default(), || {
         attempts += 1;
         if attempts == 1 {
             Err::<u64, &'static str>("warmup boom")
@@ -145,7 +176,7 @@ fn warmup_failure_in_sync_runner_is_reported_as_case_failure() {
 #[tokio::test]
 async fn warmup_failure_in_async_runner_is_reported_as_case_failure() {
     let mut attempts = 0_u32;
-    let result = run_case_async("warmup_async_failure", 1, 1, || {
+    let result = run_case_async("warmup_async_failure", 1, 1, &CaseHooks::default(), || {
         attempts += 1;
         async move {
             if attempts == 1 {
@@ -180,6 +211,7 @@ async fn warmup_setup_failure_in_async_setup_runner_is_reported_as_case_failure(
         "warmup_setup_failure",
         1,
         1,
+        &CaseHooks::default(),
         || {
             attempts += 1;
             if attempts == 1 {
@@ -215,6 +247,7 @@ async fn warmup_setup_failure_in_async_async_setup_runner_is_reported_as_case_fa
         "warmup_async_setup_failure",
         1,
         1,
+        &CaseHooks::default(),
         || {
             attempts += 1;
             async move {
@@ -247,10 +280,16 @@ async fn warmup_setup_failure_in_async_async_setup_runner_is_reported_as_case_fa
 
 #[tokio::test]
 async fn custom_timing_override_controls_elapsed_for_async_case() {
-    let result = run_case_async_custom_timing("timing_override_async", 0, 1, || async {
-        tokio::time::sleep(Duration::from_millis(25)).await;
-        Ok::<(u64, Option<f64>), String>((1, Some(1.25)))
-    })
+    let result = run_case_async_custom_timing(
+        "timing_override_async",
+        0,
+        1,
+        &CaseHooks::default(),
+        || async {
+            tokio::time::sleep(Duration::from_millis(25)).await;
+            Ok::<(u64, Option<f64>), String>((1, Some(1.25)))
+        },
+    )
     .await;
 
     let case = match result {
@@ -271,6 +310,7 @@ async fn custom_timing_override_controls_elapsed_for_async_setup_case() {
         "timing_override_async_setup",
         0,
         1,
+        &CaseHooks::default(),
         || async { Ok::<(), String>(()) },
         |_| async {
             tokio::time::sleep(Duration::from_millis(25)).await;
@@ -293,8 +333,13 @@ async fn custom_timing_override_controls_elapsed_for_async_setup_case() {
 
 #[tokio::test]
 async fn runner_selects_requested_timing_phase() {
-    let load_result =
-        run_case_async_with_timing_phase("timing_phase_load", 0, 1, TimingPhase::Load, || async {
+    let load_result = run_case_async_with_timing_phase(
+        "timing_phase_load",
+        0,
+        1,
+        &CaseHooks::default(),
+        TimingPhase::Load,
+        || async {
             Ok::<TimedSample<u64>, String>(TimedSample::new(
                 1,
                 PhaseTiming::default()
@@ -303,10 +348,16 @@ async fn runner_selects_requested_timing_phase() {
                     .with_execute_ms(9.75)
                     .with_validate_ms(3.5),
             ))
-        })
-        .await;
-    let plan_result =
-        run_case_async_with_timing_phase("timing_phase_plan", 0, 1, TimingPhase::Plan, || async {
+        },
+    )
+    .await;
+    let plan_result = run_case_async_with_timing_phase(
+        "timing_phase_plan",
+        0,
+        1,
+        &CaseHooks::default(),
+        TimingPhase::Plan,
+        || async {
             Ok::<TimedSample<u64>, String>(TimedSample::new(
                 1,
                 PhaseTiming::default()
@@ -315,13 +366,15 @@ async fn runner_selects_requested_timing_phase() {
                     .with_execute_ms(9.75)
                     .with_validate_ms(3.5),
             ))
-        })
-        .await;
+        },
+    )
+    .await;
 
     let execute_result = run_case_async_with_timing_phase(
         "timing_phase_execute",
         0,
         1,
+        &CaseHooks::default(),
         TimingPhase::Execute,
         || async {
             Ok::<TimedSample<u64>, String>(TimedSample::new(
@@ -339,6 +392,7 @@ async fn runner_selects_requested_timing_phase() {
         "timing_phase_validate",
         0,
         1,
+        &CaseHooks::default(),
         TimingPhase::Validate,
         || async {
             Ok::<TimedSample<u64>, String>(TimedSample::new(
@@ -398,6 +452,7 @@ async fn missing_requested_timing_phase_fails_case() {
         "timing_phase_missing",
         0,
         1,
+        &CaseHooks::default(),
         TimingPhase::Validate,
         || async {
             Ok::<TimedSample<u64>, String>(TimedSample::new(
@@ -422,3 +477,259 @@ async fn missing_requested_timing_phase_fails_case() {
         failure.message
     );
 }
+
+#[tokio::test]
+async fn open_loop_issues_operations_on_schedule_while_earlier_ones_run() {
+    let config = OpenLoopConfig::new(Duration::from_millis(200), 50.0).expect("load config");
+    let result = run_case_open_loop(
+        "open_loop_case",
+        0,
+        1,
+        &CaseHooks::default(),
+        config,
+        || async {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            Ok::<u64, String>(1)
+        },
+    )
+    .await;
+
+    let case = match result {
+        CaseExecutionResult::Success(case) => case,
+        CaseExecutionResult::Failure(case) => panic!("unexpected failure: {:?}", case.failure),
+    };
+    assert_eq!(case.samples.len(), 1);
+    let load = case.samples[0]
+        .metrics
+        .as_ref()
+        .and_then(|metrics| metrics.open_loop.as_ref())
+        .expect("open-loop metrics");
+    assert_eq!(load.ops_completed, 10);
+    assert!(
+        load.max_in_flight > 1,
+        "operations should overlap: {load:?}"
+    );
+    assert!(load.latency_p50_ms >= 30.0, "{load:?}");
+    assert!(load.latency_p50_ms <= load.latency_p99_ms, "{load:?}");
+    assert_eq!(case.samples[0].elapsed_ms, load.latency_p50_ms);
+}
+
+#[tokio::test]
+async fn open_loop_fails_case_when_any_operation_fails() {
+    let config = OpenLoopConfig::new(Duration::from_millis(50), 100.0).expect("load config");
+    let mut calls = 0_u32;
+    let result = run_case_open_loop(
+        "open_loop_failure",
+        0,
+        1,
+        &CaseHooks::default(),
+        config,
+        || {
+            calls += 1;
+            let fail = calls == 3;
+            async move {
+                if fail {
+                    Err("boom".to_string())
+                } else {
+                    Ok::<u64, String>(1)
+                }
+            }
+        },
+    )
+    .await;
+
+    let case = match result {
+        CaseExecutionResult::Success(case) => panic!("expected failure, got success: {:?}", case),
+        CaseExecutionResult::Failure(case) => case,
+    };
+    let message = &case.failure.as_ref().expect("failure payload").message;
+    assert!(message.contains("1 of 5 operations failed"), "{message}");
+    assert!(message.contains("boom"), "{message}");
+}
+
+#[test]
+fn open_loop_config_rejects_non_positive_settings() {
+    assert!(OpenLoopConfig::new(Duration::ZERO, 10.0).is_err());
+    assert!(OpenLoopConfig::new(Duration::from_secs(1), 0.0).is_err());
+    assert!(OpenLoopConfig::new(Duration::from_secs(1), f64::NAN).is_err());
+}
+
+const SCENARIO_STEPS: [ScenarioStep<u64>; 3] = [
+    ScenarioStep {
+        name: "scenario_first",
+        commits: 1,
+        operation: 1,
+    },
+    ScenarioStep {
+        name: "scenario_second",
+        commits: 2,
+        operation: 2,
+    },
+    ScenarioStep {
+        name: "scenario_third",
+        commits: 1,
+        operation: 1,
+    },
+];
+
+#[tokio::test]
+async fn scenario_steps_share_one_table_per_pass() {
+    let mut setups = 0_u32;
+    let mut seen = Vec::new();
+    let results = run_scenario(
+        &SCENARIO_STEPS,
+        1,
+        2,
+        || {
+            setups += 1;
+            async { Ok::<(u64, u64), String>((10, 10)) }
+        },
+        |step, version| {
+            seen.push((step.name, version));
+            let next = version + step.operation;
+            async move {
+                Ok::<_, String>((next, SampleMetrics::base(None, None, None, Some(next))))
+            }
+        },
+    )
+    .await;
+
+    assert_eq!(setups, 3, "one setup per warmup or measured pass");
+    assert_eq!(
+        &seen[..3],
+        &[
+            ("scenario_first", 10),
+            ("scenario_second", 11),
+            ("scenario_third", 13),
+        ]
+    );
+    assert_eq!(results.len(), 3);
+    for result in results {
+        let case = match result {
+            CaseExecutionResult::Success(case) => case,
+            CaseExecutionResult::Failure(case) => panic!("unexpected failure: {:?}", case.failure),
+        };
+        assert_eq!(case.samples.len(), 2, "{}", case.case);
+    }
+}
+
+#[tokio::test]
+async fn scenario_step_with_unexpected_version_fails_and_skips_later_steps() {
+    let results = run_scenario(
+        &SCENARIO_STEPS,
+        0,
+        1,
+        || async { Ok::<(u64, u64), String>((0, 0)) },
+        |_, version| async move {
+            // Every step commits once, so the second step falls one version short.
+            let next = version + 1;
+            Ok::<_, String>((next, SampleMetrics::base(None, None, None, Some(next))))
+        },
+    )
+    .await;
+
+    let cases = results
+        .into_iter()
+        .map(|result| match result {
+            CaseExecutionResult::Success(case) | CaseExecutionResult::Failure(case) => case,
+        })
+        .collect::<Vec<_>>();
+    assert!(cases[0].success);
+    assert_eq!(cases[0].samples.len(), 1);
+    assert!(!cases[1].success);
+    let message = &cases[1].failure.as_ref().expect("failure payload").message;
+    assert!(
+        message.contains("expected table version 3 after scenario_second"),
+        "{message}"
+    );
+    assert!(!cases[2].success);
+    let message = &cases[2].failure.as_ref().expect("failure payload").message;
+    assert!(
+        message.contains("depends on 'scenario_second'"),
+        "{message}"
+    );
+}
+
+#[test]
+fn case_seeds_are_stable_per_case_and_pinned_by_replay() {
+    let seeds = CaseSeeds::new(7);
+    let kill = seeds.for_case("crash_kill_writer_mid_commit");
+    assert_eq!(
+        kill,
+        CaseSeeds::new(7).for_case("crash_kill_writer_mid_commit")
+    );
+    assert_ne!(kill, seeds.for_case("scan_full_narrow"));
+    assert_ne!(
+        kill,
+        CaseSeeds::new(8).for_case("crash_kill_writer_mid_commit")
+    );
+
+    let replay = CaseSeeds::replay("crash_kill_writer_mid_commit", kill);
+    assert_eq!(replay.for_case("crash_kill_writer_mid_commit"), kill);
+    assert_eq!(
+        CaseSeeds::replay("crash_kill_writer_mid_commit", 99)
+            .for_case("crash_kill_writer_mid_commit"),
+        99
+    );
+}
+
+#[tokio::test]
+async fn case_budgets_override_warmup_and_iterations_by_case_name() {
+    let budgets = BTreeMap::from([(
+        "budgeted_case".to_string(),
+        CaseBudget {
+            warmup: Some(0),
+            iterations: Some(2),
+            timeout_ms: None,
+        },
+    )]);
+    let hooks = CaseHooks::new().with_case_budgets(budgets);
+    let mut calls = 0_u32;
+    let budgeted = run_case_async("budgeted_case", 3, 5, &hooks, || {
+        calls += 1;
+        async { Ok::<u64, String>(1) }
+    })
+    .await;
+    let unbudgeted = run_case_async("other_case", 1, 4, &hooks, || async {
+        Ok::<u64, String>(1)
+    })
+    .await;
+    let sync = run_case("budgeted_case", 1, 1, &hooks, || Ok::<u64, String>(1));
+
+    for (result, expected_samples) in [(budgeted, 2), (unbudgeted, 4), (sync, 2)] {
+        let case = match result {
+            CaseExecutionResult::Success(case) => case,
+            CaseExecutionResult::Failure(case) => panic!("unexpected failure: {:?}", case.failure),
+        };
+        assert_eq!(case.samples.len(), expected_samples, "{}", case.case);
+    }
+    assert_eq!(calls, 2, "budgeted warmup of 0 should skip warmup");
+}
+
+#[tokio::test]
+async fn case_budget_timeout_fails_slow_iterations() {
+    let budgets = BTreeMap::from([(
+        "slow_case".to_string(),
+        CaseBudget {
+            timeout_ms: Some(10),
+            ..CaseBudget::default()
+        },
+    )]);
+    let hooks = CaseHooks::new().with_case_budgets(budgets);
+    let result = run_case_async("slow_case", 0, 2, &hooks, || async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        Ok::<u64, String>(1)
+    })
+    .await;
+
+    let case = match result {
+        CaseExecutionResult::Failure(case) => case,
+        CaseExecutionResult::Success(_) => panic!("slow iteration should time out"),
+    };
+    assert!(case.samples.is_empty());
+    let message = case.failure.expect("failure").message;
+    assert!(
+        message.contains("exceeded the case timeout of 10 ms"),
+        "{message}"
+    );
+}
@@ -1,5 +1,6 @@
 use delta_bench::results::{
-    ContentionMetrics, RuntimeIOMetrics, SampleMetrics, ScanRewriteMetrics,
+    ContentionMetrics, MaintenanceCycleMetrics, RuntimeIOMetrics, SampleMetrics,
+    ScanRewriteMetrics, WorkerMetrics,
 };
 
 #[test]
@@ -41,6 +42,22 @@ fn scan_rewrite_builder_populates_optional_fields() {
     assert_eq!(metrics.rewrite_time_ms, Some(22));
 }
 
+#[test]
+fn row_group_pruning_builder_populates_optional_fields() {
+    let metrics =
+        SampleMetrics::base(Some(20), None, None, None).with_row_group_pruning(Some(6), Some(2));
+
+    assert_eq!(metrics.row_groups_pruned, Some(6));
+    assert_eq!(metrics.row_groups_matched, Some(2));
+
+    let encoded = serde_json::to_value(SampleMetrics::base(Some(1), None, None, None))
+        .expect("metrics should serialize");
+    assert!(
+        encoded.get("row_groups_pruned").is_none() && encoded.get("row_groups_matched").is_none(),
+        "absent row-group pruning metrics should be omitted from JSON"
+    );
+}
+
 #[test]
 fn runtime_io_builder_populates_result_and_schema_hashes() {
     let metrics = SampleMetrics::base(Some(3), Some(99), Some(1), Some(2)).with_runtime_io(
@@ -103,3 +120,57 @@ fn contention_builder_populates_nested_metrics() {
     assert_eq!(contention.max_commit_attempts_exceeded, 1);
     assert_eq!(contention.other_errors, 0);
 }
+
+#[test]
+fn maintenance_cycle_builder_populates_nested_metrics() {
+    let metrics = SampleMetrics::base(Some(5000), None, Some(4), Some(43)).with_maintenance_cycle(
+        MaintenanceCycleMetrics {
+            dml_ms: 120,
+            optimize_ms: 45,
+            scan_ms: 8,
+            files_before_optimize: 43,
+            files_after_optimize: 1,
+        },
+    );
+
+    let cycle = metrics
+        .maintenance_cycle
+        .as_ref()
+        .expect("maintenance cycle metrics should be present");
+    assert_eq!(cycle.dml_ms, 120);
+    assert_eq!(cycle.optimize_ms, 45);
+    assert_eq!(cycle.scan_ms, 8);
+    assert_eq!(cycle.files_before_optimize, 43);
+    assert_eq!(cycle.files_after_optimize, 1);
+    assert!(metrics.contention.is_none());
+}
+
+#[test]
+fn workers_builder_populates_per_worker_metrics() {
+    let metrics = SampleMetrics::base(Some(1024), None, Some(2), Some(2)).with_workers(vec![
+        WorkerMetrics {
+            worker: 0,
+            elapsed_ms: 12.5,
+            succeeded: true,
+            table_version: Some(1),
+            commit_retries: Some(0),
+        },
+        WorkerMetrics {
+            worker: 1,
+            elapsed_ms: 18.0,
+            succeeded: true,
+            table_version: Some(2),
+            commit_retries: Some(1),
+        },
+    ]);
+
+    let workers = metrics
+        .workers
+        .as_ref()
+        .expect("per-worker metrics should be present");
+    assert_eq!(workers.len(), 2);
+    assert_eq!(workers[1].worker, 1);
+    assert_eq!(workers[1].table_version, Some(2));
+    assert_eq!(workers[1].commit_retries, Some(1));
+    assert!(metrics.contention.is_none());
+}
@@ -0,0 +1,108 @@
+use delta_bench::data::fixtures::scale_to_row_count;
+use delta_bench::data::scales::{load_scale_catalog, scale_catalog, scale_catalog_path};
+
+fn write_catalog(body: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let path = temp.path().join("scales.yaml");
+    std::fs::write(&path, body).expect("write catalog");
+    (temp, path)
+}
+
+#[test]
+fn repo_catalog_defines_the_default_scales() {
+    let catalog = load_scale_catalog(scale_catalog_path()).expect("load repo scale catalog");
+    assert_eq!(catalog.names(), vec!["sf1", "sf10", "sf100"]);
+    assert_eq!(scale_to_row_count("sf1").expect("sf1"), 10_000);
+    assert_eq!(scale_to_row_count("sf10").expect("sf10"), 100_000);
+    assert_eq!(scale_to_row_count("sf100").expect("sf100"), 1_000_000);
+
+    let sf100 = scale_catalog()
+        .expect("cached catalog")
+        .get("sf100")
+        .expect("sf100");
+    assert_eq!(sf100.commit_max_rows(), 1_048_576);
+    assert_eq!(sf100.wide_table_rows(), 100_000);
+    assert_eq!(sf100.nested_table_rows(), 100_000);
+
+    let err = scale_to_row_count("sf3").expect_err("unknown scale");
+    assert!(
+        err.to_string()
+            .contains("unknown scale 'sf3' (expected one of: sf1, sf10, sf100)"),
+        "{err}"
+    );
+}
+
+#[test]
+fn catalog_entries_can_add_scales_and_override_sizes() {
+    let (_temp, path) = write_catalog(
+        "schema_version: 1
+scales:
+  - name: sf1
+    rows: 10000
+  - name: sf1000
+    rows: 10000000
+    commit_max_rows: 2000000
+    wide_table_rows: 250000
+",
+    );
+    let catalog = load_scale_catalog(&path).expect("load catalog");
+    let sf1000 = catalog.get("sf1000").expect("sf1000");
+    assert_eq!(sf1000.rows, 10_000_000);
+    assert_eq!(sf1000.commit_max_rows(), 2_000_000);
+    assert_eq!(sf1000.wide_table_rows(), 250_000);
+    assert_eq!(sf1000.nested_table_rows(), 100_000);
+    assert_eq!(catalog.get("sf1").expect("sf1").wide_table_rows(), 10_000);
+}
+
+#[test]
+fn invalid_catalogs_are_rejected() {
+    for (body, expected) in [
+        (
+            "schema_version: 2\nscales:\n  - name: sf1\n    rows: 1\n",
+            "unsupported schema_version 2",
+        ),
+        ("schema_version: 1\nscales: []\n", "no scales defined"),
+        (
+            "schema_version: 1\nscales:\n  - name: SF1\n    rows: 1\n",
+            "must be a lowercase identifier",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: ../sf1\n    rows: 1\n",
+            "must be a lowercase identifier",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: micro\n    rows: 1\n",
+            "reserved for the built-in smoke scale",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: tiny_smoke\n    rows: 1\n",
+            "collides with a dataset id",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: sf1\n    rows: 1\n  - name: sf1\n    rows: 2\n",
+            "duplicate scale 'sf1'",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: sf1\n    rows: 0\n",
+            "must have rows > 0",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: sf1\n    rows: 1\n    commit_max_rows: 0\n",
+            "must have commit_max_rows > 0",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: sf10\n    rows: 10\n  - name: sf1\n    rows: 1\n",
+            "must be larger than the scale before it",
+        ),
+        (
+            "schema_version: 1\nscales:\n  - name: sf1\n    rows: 1\n    row_count: 1\n",
+            "unknown field",
+        ),
+    ] {
+        let (_temp, path) = write_catalog(body);
+        let err = load_scale_catalog(&path).expect_err("invalid catalog");
+        let message = err.to_string();
+        assert!(message.contains("invalid scale catalog"), "{message}");
+        assert!(message.contains(expected), "{body}: {message}");
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use delta_bench::cli::StorageBackend;
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::{StorageConfig, MAX_CONCURRENCY_KEY};
+use delta_bench::suites::scan_concurrency;
+
+const SF1_ROWS: u64 = 10_000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn generated_standard_fixtures_support_scan_concurrency_suite() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+
+    let cases = scan_concurrency::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("scan_concurrency suite run");
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "scan_concurrent_sessions_1",
+            "scan_concurrent_sessions_4",
+            "scan_concurrent_sessions_16",
+        ]
+    );
+    assert!(
+        cases.iter().all(|case| case.success),
+        "scan_concurrency failures: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+
+    for (case, sessions) in cases.iter().zip([1_u64, 4, 16]) {
+        let metrics = case.samples[0]
+            .metrics
+            .as_ref()
+            .expect("sample metrics should be present");
+        assert_eq!(metrics.operations, Some(sessions));
+        assert_eq!(metrics.rows_processed, Some(SF1_ROWS * sessions));
+
+        let read = metrics
+            .read_concurrency
+            .as_ref()
+            .expect("read concurrency metrics should be present");
+        assert_eq!(read.sessions, sessions);
+        assert!(read.rows_per_sec > 0.0, "{} rows_per_sec", case.case);
+        assert!(
+            read.session_p50_ms <= read.session_max_ms,
+            "{} p50 should not exceed max",
+            case.case
+        );
+
+        let workers = metrics
+            .workers
+            .as_ref()
+            .expect("per-session metrics should be present");
+        assert_eq!(workers.len() as u64, sessions);
+        assert!(workers.iter().all(|worker| worker.succeeded));
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scan_concurrency_skips_cases_above_backend_max_concurrency() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    generate_fixtures(temp.path(), "sf1", 42, true, &StorageConfig::local())
+        .await
+        .expect("generate fixtures");
+    let storage = StorageConfig::new(
+        StorageBackend::Local,
+        HashMap::from([(MAX_CONCURRENCY_KEY.to_string(), "4".to_string())]),
+    )
+    .expect("capped storage config");
+
+    let cases = scan_concurrency::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("scan_concurrency suite run");
+    assert!(cases[0].success && cases[1].success);
+    let skipped = &cases[2];
+    assert!(!skipped.success);
+    assert_eq!(skipped.failure_kind.as_deref(), Some("unsupported"));
+    assert!(skipped
+        .failure
+        .as_ref()
+        .is_some_and(|failure| failure.message.contains("max_concurrency=4")));
+}
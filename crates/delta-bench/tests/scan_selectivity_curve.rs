@@ -0,0 +1,96 @@
+use delta_bench::results::{CaseResult, ElapsedStats, PerfStatus, FAILURE_KIND_UNSUPPORTED};
+use delta_bench::suites::scan::selectivity_curve;
+
+fn case_result(name: &str, median_ms: f64) -> CaseResult {
+    CaseResult {
+        case: name.to_string(),
+        success: true,
+        validation_passed: true,
+        perf_status: PerfStatus::Trusted,
+        classification: "supported".to_string(),
+        samples: Vec::new(),
+        elapsed_stats: Some(ElapsedStats {
+            min_ms: median_ms,
+            max_ms: median_ms,
+            mean_ms: median_ms,
+            median_ms,
+            stddev_ms: 0.0,
+            cv_pct: None,
+        }),
+        run_summary: None,
+        run_summaries: None,
+        suite_manifest_hash: None,
+        case_definition_hash: None,
+        compatibility_key: None,
+        supports_decision: None,
+        required_runs: None,
+        decision_threshold_pct: None,
+        decision_metric: None,
+        seed: None,
+        physical_plan: None,
+        plan_metrics_file: None,
+        plan_metrics: Vec::new(),
+        profile_file: None,
+        flamegraph: None,
+        heap_profile: None,
+        warnings: Vec::new(),
+        failure_kind: None,
+        failure: None,
+    }
+}
+
+#[test]
+fn selectivity_curve_orders_sweep_cases_and_skips_fixed_cases() {
+    let cases = vec![
+        case_result("scan_full_narrow", 9.0),
+        case_result("scan_selectivity_50pct", 4.0),
+        case_result("scan_selectivity_0_001pct", 1.0),
+        case_result("scan_selectivity_1pct", 2.0),
+    ];
+
+    let curve = selectivity_curve(&cases);
+
+    let points = curve
+        .iter()
+        .map(|point| {
+            (
+                point.case.as_str(),
+                point.selectivity_pct,
+                point.elapsed_stats.as_ref().map(|stats| stats.median_ms),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        points,
+        vec![
+            ("scan_selectivity_0_001pct", 0.001, Some(1.0)),
+            ("scan_selectivity_1pct", 1.0, Some(2.0)),
+            ("scan_selectivity_50pct", 50.0, Some(4.0)),
+        ]
+    );
+}
+
+#[test]
+fn selectivity_curve_is_empty_without_sweep_cases() {
+    let cases = vec![case_result("scan_full_narrow", 9.0)];
+    assert!(selectivity_curve(&cases).is_empty());
+}
+
+#[test]
+fn selectivity_curve_leaves_out_steps_too_small_for_the_scale() {
+    let mut skipped = case_result("scan_selectivity_0_001pct", 1.0);
+    skipped.success = false;
+    skipped.elapsed_stats = None;
+    skipped.failure_kind = Some(FAILURE_KIND_UNSUPPORTED.to_string());
+    let cases = vec![skipped, case_result("scan_selectivity_0_01pct", 1.5)];
+
+    let curve = selectivity_curve(&cases);
+
+    assert_eq!(
+        curve
+            .iter()
+            .map(|point| point.case.as_str())
+            .collect::<Vec<_>>(),
+        vec!["scan_selectivity_0_01pct"]
+    );
+}
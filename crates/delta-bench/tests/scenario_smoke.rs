@@ -0,0 +1,86 @@
+use delta_bench::cli::BenchmarkLane;
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::data::row_cache::FixtureRowCache;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::scenario;
+
+#[tokio::test]
+async fn pipeline_steps_advance_one_table_a_version_at_a_time() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("fixtures should be generated");
+
+    let cases = scenario::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        2,
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("scenario suite should run");
+
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "pipeline_ingest_append",
+            "pipeline_merge_corrections",
+            "pipeline_retention_delete",
+            "pipeline_compact",
+        ]
+    );
+    assert!(
+        cases.iter().all(|case| case.success),
+        "scenario failures: {:?}",
+        cases
+            .iter()
+            .map(|case| (&case.case, &case.failure))
+            .collect::<Vec<_>>()
+    );
+
+    let versions = cases
+        .iter()
+        .map(|case| {
+            assert_eq!(case.samples.len(), 2, "{}", case.case);
+            case.samples[0]
+                .metrics
+                .as_ref()
+                .and_then(|metrics| metrics.table_version)
+                .expect("table version")
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        versions.windows(2).all(|pair| pair[1] == pair[0] + 1),
+        "each step should commit on top of the previous one: {versions:?}"
+    );
+    let merged = cases[1].samples[0].metrics.as_ref().expect("merge metrics");
+    assert!(merged.rows_processed.is_some_and(|rows| rows > 0));
+}
+
+#[tokio::test]
+async fn scenario_suite_reports_fixture_error_without_fixtures() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = scenario::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &storage,
+        &FixtureRowCache::new(),
+    )
+    .await
+    .expect("scenario suite should return fixture errors");
+
+    assert_eq!(cases.len(), 4);
+    assert!(cases.iter().all(|case| !case.success));
+}
@@ -0,0 +1,31 @@
+use delta_bench::data::fixtures::{load_manifest, scale_to_row_count};
+use delta_bench::data::scales::SMOKE_SCALE;
+use delta_bench::smoke::{run_smoke, SMOKE_CASES};
+
+#[tokio::test]
+async fn smoke_runs_each_case_on_micro_fixtures() {
+    let temp = tempfile::tempdir().expect("tempdir");
+
+    let report = run_smoke(temp.path()).await.expect("smoke check");
+    let cases = report
+        .cases
+        .iter()
+        .map(|case| case.case.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        cases,
+        SMOKE_CASES
+            .iter()
+            .map(|(_, case)| *case)
+            .collect::<Vec<_>>()
+    );
+    assert!(report.passed(), "{:?}", report.cases);
+    assert!(report.fixtures_ms <= report.elapsed_ms);
+
+    let manifest = load_manifest(&temp.path().join("fixtures"), SMOKE_SCALE).expect("manifest");
+    assert_eq!(
+        manifest.rows,
+        scale_to_row_count(SMOKE_SCALE).expect("micro")
+    );
+    assert_eq!(manifest.rows, 1_024);
+}
@@ -1,9 +1,11 @@
-use delta_bench::runner::{run_case, CaseExecutionResult};
+use delta_bench::runner::{run_case, CaseExecutionResult, CaseHooks};
 
 #[test]
 fn runner_records_failures_and_continues() {
-    let ok = run_case("ok", 0, 2, || Ok::<u64, String>(10));
-    let fail = run_case("fail", 0, 2, || Err::<u64, String>("boom".to_string()));
+    let ok = run_case("ok", 0, 2, &CaseHooks::default(), || Ok::<u64, String>(10));
+    let fail = run_case("fail", 0, 2, &CaseHooks::default(), || {
+        Err::<u64, String>("boom".to_string())
+    });
 
     match ok {
         CaseExecutionResult::Success(case) => {
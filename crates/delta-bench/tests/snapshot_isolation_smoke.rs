@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use delta_bench::cli::StorageBackend;
+use delta_bench::data::fixtures::generate_fixtures;
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::snapshot_isolation::{self, SNAPSHOT_ISOLATION_UNSUPPORTED};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn snapshot_isolation_reports_capability_for_mid_scan_dml() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let storage = StorageConfig::local();
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("generate fixtures");
+
+    let cases = snapshot_isolation::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("snapshot_isolation suite run");
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec!["snapshot_scan_during_delete", "snapshot_scan_during_update"]
+    );
+    for case in &cases {
+        assert!(case.success, "{} failure: {:?}", case.case, case.failure);
+        match case.classification.as_str() {
+            "supported" => {
+                let metrics = case.samples[0].metrics.as_ref().expect("sample metrics");
+                assert_eq!(metrics.rows_processed, Some(10_000), "{}", case.case);
+            }
+            "expected_failure" => assert!(case
+                .failure
+                .as_ref()
+                .is_some_and(|failure| failure.message.contains(SNAPSHOT_ISOLATION_UNSUPPORTED))),
+            other => panic!("{} has unexpected classification {other}", case.case),
+        }
+    }
+}
+
+#[tokio::test]
+async fn snapshot_isolation_rejects_non_local_backend() {
+    let storage = StorageConfig::new(
+        StorageBackend::S3,
+        HashMap::from([(
+            "table_root".to_string(),
+            "s3://bench-bucket/prefix".to_string(),
+        )]),
+    )
+    .expect("s3 storage config");
+    let temp = tempfile::tempdir().expect("tempdir");
+
+    let cases = snapshot_isolation::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("snapshot_isolation suite run");
+    assert_eq!(cases.len(), 2);
+    assert!(cases.iter().all(|case| !case.success));
+}
@@ -0,0 +1,90 @@
+#[path = "support/env_lock.rs"]
+mod env_lock_support;
+#[path = "support/env_vars.rs"]
+mod env_vars_support;
+
+use std::path::{Path, PathBuf};
+
+use delta_bench::paths::{expand_home, prepare_writable_dir};
+use env_lock_support::env_lock;
+use env_vars_support::with_env_vars;
+
+#[tokio::test]
+async fn leading_tilde_expands_to_home() {
+    let _env_lock = env_lock();
+    with_env_vars(&[("HOME", "/home/bench")], || async {
+        assert_eq!(
+            expand_home(Path::new("~/fixtures")).expect("expand"),
+            PathBuf::from("/home/bench/fixtures")
+        );
+        assert_eq!(
+            expand_home(Path::new("~")).expect("expand"),
+            PathBuf::from("/home/bench")
+        );
+        for unchanged in ["results/~", "~other/results", "/srv/results"] {
+            assert_eq!(
+                expand_home(Path::new(unchanged)).expect("expand"),
+                PathBuf::from(unchanged)
+            );
+        }
+    })
+    .await;
+
+    with_env_vars(&[("HOME", "")], || async {
+        let err = expand_home(Path::new("~/fixtures")).expect_err("empty HOME must fail");
+        assert!(
+            err.to_string()
+                .contains("cannot expand '~' in '~/fixtures': HOME is not set"),
+            "{err}"
+        );
+    })
+    .await;
+}
+
+#[test]
+fn writable_dirs_are_created_and_canonicalized() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let root = temp.path().canonicalize().expect("canonical tempdir");
+
+    let nested = root.join("results/nightly");
+    assert_eq!(
+        prepare_writable_dir("results", &nested).expect("prepare"),
+        nested
+    );
+    assert!(nested.is_dir());
+    assert_eq!(
+        std::fs::read_dir(&nested).expect("read dir").count(),
+        0,
+        "the write check must not leave files behind"
+    );
+
+    #[cfg(unix)]
+    {
+        let link = root.join("fixtures-link");
+        std::os::unix::fs::symlink(&nested, &link).expect("symlink");
+        assert_eq!(
+            prepare_writable_dir("fixtures", &link).expect("prepare"),
+            nested
+        );
+
+        let dangling = root.join("dangling");
+        std::os::unix::fs::symlink(root.join("missing"), &dangling).expect("symlink");
+        let err = prepare_writable_dir("fixtures", &dangling).expect_err("dangling link");
+        assert!(
+            err.to_string().contains(&format!(
+                "fixtures directory '{}' cannot be",
+                dangling.display()
+            )),
+            "{err}"
+        );
+    }
+
+    let file = root.join("not-a-dir");
+    std::fs::write(&file, "").expect("write file");
+    let err = prepare_writable_dir("results", &file).expect_err("file must fail");
+    assert!(
+        err.to_string()
+            .contains(&format!("results directory '{}'", file.display())),
+        "{err}"
+    );
+}
@@ -58,6 +58,22 @@ fn list_targets_includes_concurrency() {
     );
 }
 
+#[test]
+fn list_targets_includes_scan_concurrency() {
+    assert!(
+        list_targets().contains(&"scan_concurrency"),
+        "scan_concurrency target missing from list_targets"
+    );
+}
+
+#[test]
+fn list_targets_includes_multi_table() {
+    assert!(
+        list_targets().contains(&"multi_table"),
+        "multi_table target missing from list_targets"
+    );
+}
+
 #[test]
 fn list_targets_excludes_scan_planning() {
     assert!(
@@ -75,8 +91,10 @@ fn optimize_vacuum_case_list_is_exact() {
             "optimize_compact_small_files".to_string(),
             "optimize_noop_already_compact".to_string(),
             "optimize_heavy_compaction".to_string(),
+            "vacuum_after_heavy_compaction".to_string(),
             "vacuum_dry_run_lite".to_string(),
             "vacuum_execute_lite".to_string(),
+            "vacuum_execute_with_concurrent_reads".to_string(),
         ]
     );
 }
@@ -87,6 +105,7 @@ fn write_perf_case_list_is_exact() {
     assert_eq!(
         cases,
         vec![
+            "write_perf_commit_4096_adds".to_string(),
             "write_perf_partitioned_1m_parts_010".to_string(),
             "write_perf_partitioned_1m_parts_100".to_string(),
             "write_perf_partitioned_5m_parts_010".to_string(),
@@ -119,6 +138,9 @@ fn merge_perf_case_list_is_exact() {
             "merge_perf_upsert_50pct".to_string(),
             "merge_perf_localized_1pct".to_string(),
             "merge_perf_delete_5pct".to_string(),
+            "merge_perf_upsert_10pct_schema_evolution".to_string(),
+            "merge_perf_upsert_50pct_schema_evolution".to_string(),
+            "merge_perf_micro_upsert_100x10".to_string(),
         ]
     );
 }
@@ -132,6 +154,7 @@ fn optimize_perf_case_list_is_exact() {
             "optimize_perf_compact_small_files".to_string(),
             "optimize_perf_noop_already_compact".to_string(),
             "vacuum_perf_execute_lite".to_string(),
+            "optimize_perf_after_dml_cycle".to_string(),
         ]
     );
 }
@@ -147,6 +170,43 @@ fn scan_case_list_is_exact() {
             "scan_filter_flag".to_string(),
             "scan_pruning_hit".to_string(),
             "scan_pruning_miss".to_string(),
+            "scan_selectivity_0_001pct".to_string(),
+            "scan_selectivity_0_01pct".to_string(),
+            "scan_selectivity_0_1pct".to_string(),
+            "scan_selectivity_1pct".to_string(),
+            "scan_selectivity_10pct".to_string(),
+            "scan_selectivity_50pct".to_string(),
+            "scan_filter_event_date".to_string(),
+            "scan_filter_ts_range".to_string(),
+            "scan_filter_amount".to_string(),
+            "scan_interop_reference".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn scan_concurrency_case_list_is_exact() {
+    let cases = list_cases_for_target("scan_concurrency").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "scan_concurrent_sessions_1".to_string(),
+            "scan_concurrent_sessions_4".to_string(),
+            "scan_concurrent_sessions_16".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn multi_table_case_list_is_exact() {
+    let cases = list_cases_for_target("multi_table").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "multi_table_union_all".to_string(),
+            "multi_table_join".to_string(),
+            "multi_table_star_join".to_string(),
+            "multi_table_count_all".to_string(),
         ]
     );
 }
@@ -183,12 +243,9 @@ fn tpcds_case_list_is_exact() {
     let cases = list_cases_for_target("tpcds").expect("known target should work");
     assert_eq!(
         cases,
-        vec![
-            "tpcds_q03".to_string(),
-            "tpcds_q07".to_string(),
-            "tpcds_q64".to_string(),
-            "tpcds_q72".to_string(),
-        ]
+        (1..=99)
+            .map(|query| format!("tpcds_q{query:02}"))
+            .collect::<Vec<_>>()
     );
 }
 
@@ -213,9 +270,176 @@ fn concurrency_case_list_is_exact() {
         vec![
             "concurrent_table_create".to_string(),
             "concurrent_append_multi".to_string(),
+            "concurrent_append_writers_2".to_string(),
+            "concurrent_append_writers_4".to_string(),
+            "concurrent_append_writers_8".to_string(),
             "update_vs_compaction".to_string(),
             "delete_vs_compaction".to_string(),
             "optimize_vs_optimize_overlap".to_string(),
+            "optimize_vs_append_ingest".to_string(),
+            "mixed_oltp_like".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn crash_recovery_case_list_is_exact() {
+    let cases = list_cases_for_target("crash_recovery").expect("known target should work");
+    assert_eq!(cases, vec!["crash_kill_writer_mid_commit".to_string()]);
+}
+
+#[test]
+fn replay_case_list_is_exact() {
+    let cases = list_cases_for_target("replay").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "replay_append".to_string(),
+            "replay_merge".to_string(),
+            "replay_delete".to_string(),
+            "replay_optimize".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn snapshot_isolation_case_list_is_exact() {
+    let cases = list_cases_for_target("snapshot_isolation").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "snapshot_scan_during_delete".to_string(),
+            "snapshot_scan_during_update".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn deletion_vectors_case_list_is_exact() {
+    let cases = list_cases_for_target("deletion_vectors").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "dv_delete_5pct_copy_on_write".to_string(),
+            "dv_delete_5pct_deletion_vectors".to_string(),
+            "dv_merge_delete_5pct_copy_on_write".to_string(),
+            "dv_merge_delete_5pct_deletion_vectors".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn checkpoint_case_list_is_exact() {
+    let cases = list_cases_for_target("checkpoint").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "checkpoint_write_long_history".to_string(),
+            "checkpoint_load_with_checkpoint".to_string(),
+            "checkpoint_load_without_checkpoint".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn log_replay_case_list_is_exact() {
+    let cases = list_cases_for_target("log_replay").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "log_replay_snapshot_checkpointed".to_string(),
+            "log_replay_snapshot_uncheckpointed".to_string(),
+            "log_replay_snapshot_deep_history".to_string(),
+            "log_replay_enumerate_adds_checkpointed".to_string(),
+            "log_replay_enumerate_adds_uncheckpointed".to_string(),
+            "log_replay_enumerate_adds_deep_history".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn late_arriving_case_list_is_exact() {
+    let cases = list_cases_for_target("late_arriving").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "late_arriving_append_backfill".to_string(),
+            "late_arriving_merge_upsert_backfill".to_string(),
+            "late_arriving_merge_insert_only_backfill".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn restore_case_list_is_exact() {
+    let cases = list_cases_for_target("restore").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "restore_shallow_1_version".to_string(),
+            "restore_mid_history_24_versions".to_string(),
+            "restore_deep_to_version_0".to_string(),
+            "restore_then_vacuum_24_versions".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn scenario_case_list_is_in_step_order() {
+    let cases = list_cases_for_target("scenario").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "pipeline_ingest_append".to_string(),
+            "pipeline_merge_corrections".to_string(),
+            "pipeline_retention_delete".to_string(),
+            "pipeline_compact".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn convert_to_delta_case_list_is_exact() {
+    let cases = list_cases_for_target("convert_to_delta").expect("known target should work");
+    assert_eq!(cases, vec!["convert_to_delta_partitioned".to_string()]);
+}
+
+#[test]
+fn data_skipping_case_list_is_exact() {
+    let cases = list_cases_for_target("data_skipping").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "data_skipping_low_cardinality".to_string(),
+            "data_skipping_medium_cardinality".to_string(),
+            "data_skipping_high_cardinality".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn wide_table_case_list_is_exact() {
+    let cases = list_cases_for_target("wide_table").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "wide_table_full_scan".to_string(),
+            "wide_table_projection_3col".to_string(),
+            "wide_table_append".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn nested_types_case_list_is_exact() {
+    let cases = list_cases_for_target("nested_types").expect("known target should work");
+    assert_eq!(
+        cases,
+        vec![
+            "nested_full_scan".to_string(),
+            "nested_projection_struct_field".to_string(),
+            "nested_projection_list_element".to_string(),
+            "nested_write".to_string(),
         ]
     );
 }
@@ -238,6 +462,17 @@ fn all_case_list_excludes_write_perf_cases() {
     );
 }
 
+#[test]
+fn all_case_list_excludes_scan_concurrency_cases() {
+    let cases = list_cases_for_target("all").expect("known target should work");
+    assert!(
+        cases
+            .iter()
+            .all(|case| !case.starts_with("scan_concurrent_sessions_")),
+        "all target should exclude opt-in scan_concurrency cases"
+    );
+}
+
 #[test]
 fn all_case_list_excludes_new_perf_owned_dml_and_maintenance_cases() {
     let cases = list_cases_for_target("all").expect("known target should work");
@@ -293,3 +528,109 @@ fn legacy_merge_target_alias_is_rejected() {
         "unexpected error: {err}"
     );
 }
+
+#[cfg(feature = "custom-suites")]
+#[tokio::test]
+async fn custom_suites_plan_and_run_inside_their_registry() {
+    use std::sync::Arc;
+
+    use delta_bench::cli::{BenchmarkLane, RunnerMode, TimingPhase};
+    use delta_bench::error::BenchResult;
+    use delta_bench::results::{CaseResult, SampleMetrics};
+    use delta_bench::runner::{run_case_async, CaseExecutionResult};
+    use delta_bench::runner::{CaseHooks, CaseSeeds};
+    use delta_bench::storage::StorageConfig;
+    use delta_bench::suites::{
+        plan_run_cases_with_options, run_planned_cases_with_seeds, CaseTagFilter, Suite,
+        SuiteOptions, SuiteRegistry, SuiteRunArgs,
+    };
+    use futures::future::LocalBoxFuture;
+
+    struct Proprietary(&'static str);
+
+    impl Suite for Proprietary {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn case_names(&self) -> BenchResult<Vec<String>> {
+            Ok(vec!["proprietary_lookup".to_string()])
+        }
+
+        fn run<'a>(
+            &'a self,
+            args: SuiteRunArgs<'a>,
+        ) -> LocalBoxFuture<'a, BenchResult<Vec<CaseResult>>> {
+            Box::pin(async move {
+                let result = run_case_async(
+                    "proprietary_lookup",
+                    args.warmup,
+                    args.iterations,
+                    args.hooks,
+                    || async { Ok::<_, String>(SampleMetrics::base(Some(3), None, None, None)) },
+                )
+                .await;
+                match result {
+                    CaseExecutionResult::Success(case) | CaseExecutionResult::Failure(case) => {
+                        Ok(vec![case])
+                    }
+                }
+            })
+        }
+    }
+
+    let mut registry = SuiteRegistry::builtin();
+    registry
+        .register(Box::new(Proprietary("proprietary")))
+        .expect("register");
+    for (name, expected) in [
+        ("scan", "already registered"),
+        ("all", "already registered"),
+        ("Proprietary", "lowercase snake_case"),
+    ] {
+        let err = registry
+            .register(Box::new(Proprietary(name)))
+            .expect_err("invalid suite name");
+        assert!(err.to_string().contains(expected), "{name}: {err}");
+    }
+
+    assert!(!list_targets().contains(&"proprietary"));
+    assert!(registry.targets().contains(&"proprietary"));
+    assert_eq!(
+        registry.list_cases("proprietary").expect("cases"),
+        vec!["proprietary_lookup".to_string()]
+    );
+    let options = SuiteOptions {
+        registry: Arc::new(registry),
+        ..SuiteOptions::default()
+    };
+    let planned = plan_run_cases_with_options(
+        "proprietary",
+        RunnerMode::Rust,
+        None,
+        &CaseTagFilter::default(),
+        &options,
+    )
+    .expect("plan");
+    assert_eq!(planned.len(), 1);
+    assert!(planned[0].assertions.is_empty());
+    let temp = tempfile::tempdir().expect("tempdir");
+    let cases = run_planned_cases_with_seeds(
+        temp.path(),
+        &planned,
+        "sf1",
+        BenchmarkLane::Macro,
+        TimingPhase::Execute,
+        0,
+        2,
+        &CaseHooks::default(),
+        &StorageConfig::local(),
+        &CaseSeeds::default(),
+        &options,
+    )
+    .await
+    .expect("run");
+    assert_eq!(cases.len(), 1);
+    assert!(cases[0].success, "{:?}", cases[0].failure);
+    assert_eq!(cases[0].samples.len(), 2);
+}
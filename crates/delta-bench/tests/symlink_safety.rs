@@ -4,6 +4,7 @@ use std::os::unix::fs::symlink;
 
 use delta_bench::cli::BenchmarkLane;
 use delta_bench::data::fixtures::{generate_fixtures, narrow_sales_table_path};
+use delta_bench::runner::CaseHooks;
 use delta_bench::storage::StorageConfig;
 use delta_bench::suites::metadata;
 
@@ -19,9 +20,17 @@ async fn metadata_suite_rejects_symlink_entries_in_fixture_tree() {
     let link_path = table_path.join("symlink_escape");
     symlink(temp.path(), &link_path).expect("create symlink");
 
-    let cases = metadata::run(temp.path(), "sf1", BenchmarkLane::Macro, 0, 1, &storage)
-        .await
-        .expect("metadata suite should produce per-case failures");
+    let cases = metadata::run(
+        temp.path(),
+        "sf1",
+        BenchmarkLane::Macro,
+        0,
+        1,
+        &CaseHooks::default(),
+        &storage,
+    )
+    .await
+    .expect("metadata suite should produce per-case failures");
 
     assert!(!cases.is_empty());
     assert!(cases.iter().all(|c| !c.success));
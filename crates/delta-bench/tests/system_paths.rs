@@ -1,6 +1,9 @@
 use std::fs;
 
-use delta_bench::system::delta_rs_checkout_info;
+use delta_bench::system::{
+    delta_rs_checkout_info, git_revision, pinned_deltalake_core_rev, resolve_revision, GitRevision,
+    GIT_SHA_AUTO,
+};
 
 #[test]
 fn delta_rs_checkout_info_uses_env_override() {
@@ -12,3 +15,80 @@ fn delta_rs_checkout_info_uses_env_override() {
     assert!(info.checkout_present);
     assert!(info.core_present);
 }
+
+fn git(dir: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args([
+            "-c",
+            "user.name=bench",
+            "-c",
+            "user.email=bench@example.com",
+        ])
+        .args(args)
+        .status()
+        .expect("run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn git_revision_reports_head_and_ignores_untracked_files() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let repo = tmp.path();
+    assert_eq!(git_revision(repo), None);
+
+    git(repo, &["init", "-q"]);
+    fs::write(repo.join("tracked.txt"), "one\n").expect("write tracked");
+    git(repo, &["add", "tracked.txt"]);
+    git(repo, &["commit", "-q", "-m", "initial"]);
+
+    let clean = git_revision(repo).expect("revision");
+    assert_eq!(clean.sha.len(), 40, "{clean:?}");
+    assert!(!clean.dirty);
+
+    fs::write(repo.join("untracked.txt"), "overlay\n").expect("write untracked");
+    assert!(!git_revision(repo).expect("revision").dirty);
+
+    fs::write(repo.join("tracked.txt"), "two\n").expect("modify tracked");
+    let dirty = git_revision(repo).expect("revision");
+    assert_eq!(dirty.sha, clean.sha);
+    assert!(dirty.dirty);
+}
+
+#[test]
+fn resolve_revision_detects_unless_a_sha_is_given() {
+    let detected = || {
+        Some(GitRevision {
+            sha: "0123abc".to_string(),
+            dirty: true,
+        })
+    };
+    for requested in [None, Some(""), Some(GIT_SHA_AUTO)] {
+        assert_eq!(
+            resolve_revision(requested, detected),
+            (Some("0123abc".to_string()), Some(true))
+        );
+    }
+    assert_eq!(resolve_revision(None, || None), (None, None));
+    assert_eq!(
+        resolve_revision(Some("feedbeef"), || panic!("explicit sha must not detect")),
+        (Some("feedbeef".to_string()), None)
+    );
+}
+
+#[test]
+fn pinned_deltalake_core_rev_reads_the_git_dependency() {
+    let manifest = r#"
+[dependencies]
+deltalake-core = { git = "https://github.com/delta-io/delta-rs.git", rev = "b1cb1388", features = ["datafusion"] }
+"#;
+    assert_eq!(
+        pinned_deltalake_core_rev(manifest),
+        Some("b1cb1388".to_string())
+    );
+    assert_eq!(
+        pinned_deltalake_core_rev(r#"deltalake-core = { path = "../core" }"#),
+        None
+    );
+}
@@ -1,8 +1,8 @@
-use delta_bench::suites::tpcds::catalog::phase1_query_catalog;
+use delta_bench::suites::tpcds::catalog::{query_catalog, DEFAULT_TPCDS_PHASE, MAX_TPCDS_PHASE};
 
 #[test]
 fn query_ids_are_stable_sorted_and_unique() {
-    let specs = phase1_query_catalog();
+    let specs = query_catalog();
     let ids = specs.iter().map(|spec| spec.id).collect::<Vec<_>>();
 
     let mut sorted = ids.clone();
@@ -22,7 +22,7 @@ fn query_ids_are_stable_sorted_and_unique() {
 
 #[test]
 fn q72_is_present_but_disabled_with_explicit_datafusion_issue_reason() {
-    let specs = phase1_query_catalog();
+    let specs = query_catalog();
     let q72 = specs
         .iter()
         .find(|spec| spec.id == "q72")
@@ -40,10 +40,63 @@ fn q72_is_present_but_disabled_with_explicit_datafusion_issue_reason() {
 }
 
 #[test]
-fn phase1_catalog_contains_at_least_one_enabled_query() {
-    let specs = phase1_query_catalog();
+fn catalog_covers_all_99_queries() {
+    let ids = query_catalog()
+        .iter()
+        .map(|spec| spec.id.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        (1..=99)
+            .map(|query| format!("q{query:02}"))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn default_phase_contains_at_least_one_enabled_query() {
+    let specs = query_catalog();
+    assert!(
+        specs
+            .iter()
+            .any(|spec| spec.skip_reason_for_phase(DEFAULT_TPCDS_PHASE).is_none()),
+        "default phase should contain at least one executable query"
+    );
+}
+
+#[test]
+fn every_query_has_a_valid_phase_and_disabled_queries_explain_why() {
+    for spec in query_catalog() {
+        assert!(
+            (1..=MAX_TPCDS_PHASE).contains(&spec.phase),
+            "{} has out-of-range phase {}",
+            spec.id,
+            spec.phase
+        );
+        assert_eq!(
+            spec.enabled,
+            spec.skip_reason.is_none(),
+            "{} must carry a skip reason exactly when disabled",
+            spec.id
+        );
+    }
+}
+
+#[test]
+fn queries_scheduled_for_later_phase_are_skipped_until_selected() {
+    let mut spec = query_catalog()
+        .into_iter()
+        .find(|spec| spec.id == "q03")
+        .expect("q03 should be cataloged");
+    let later_phase = MAX_TPCDS_PHASE + 1;
+    spec.phase = later_phase;
+
+    let reason = spec
+        .skip_reason_for_phase(MAX_TPCDS_PHASE)
+        .expect("query beyond the selected phase should be skipped");
     assert!(
-        specs.iter().any(|spec| spec.enabled),
-        "phase 1 catalog should contain at least one executable query"
+        reason.contains(&format!("phase {later_phase}")),
+        "skip reason should name the scheduled phase; got: {reason}"
     );
+    assert_eq!(spec.skip_reason_for_phase(later_phase), None);
 }
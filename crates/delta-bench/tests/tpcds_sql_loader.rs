@@ -1,11 +1,11 @@
 use std::fs;
 
-use delta_bench::suites::tpcds::catalog::{phase1_query_catalog, TpcdsQuerySpec};
+use delta_bench::suites::tpcds::catalog::{query_catalog, TpcdsQuerySpec};
 use delta_bench::suites::tpcds::sql_loader::{load_enabled_queries, load_enabled_queries_from_dir};
 
 #[test]
-fn loader_returns_sql_for_enabled_phase1_queries() {
-    let loaded = load_enabled_queries(&phase1_query_catalog()).expect("load phase1 sql");
+fn loader_returns_sql_for_enabled_catalog_queries() {
+    let loaded = load_enabled_queries(&query_catalog()).expect("load catalog sql");
     assert!(!loaded.is_empty(), "expected at least one enabled query");
     assert!(loaded.iter().all(|query| !query.sql.trim().is_empty()));
     assert!(
@@ -20,6 +20,7 @@ fn missing_sql_file_returns_actionable_error() {
     let specs = vec![TpcdsQuerySpec {
         id: "q99",
         sql_file: "q99.sql",
+        phase: 1,
         enabled: true,
         skip_reason: None,
     }];
@@ -44,12 +45,14 @@ fn disabled_queries_are_not_loaded_or_required() {
         TpcdsQuerySpec {
             id: "q03",
             sql_file: "q03.sql",
+            phase: 1,
             enabled: true,
             skip_reason: None,
         },
         TpcdsQuerySpec {
             id: "q72",
             sql_file: "q72.sql",
+            phase: 1,
             enabled: false,
             skip_reason: Some("known issue"),
         },
@@ -62,7 +65,7 @@ fn disabled_queries_are_not_loaded_or_required() {
 
 #[test]
 fn q07_sql_has_deterministic_tie_break_ordering() {
-    let loaded = load_enabled_queries(&phase1_query_catalog()).expect("load phase1 sql");
+    let loaded = load_enabled_queries(&query_catalog()).expect("load catalog sql");
     let q07 = loaded
         .iter()
         .find(|query| query.id == "q07")
@@ -0,0 +1,72 @@
+use delta_bench::data::fixtures::{
+    fixture_wide_table_columns, generate_fixtures, wide_table_batch, DEFAULT_WIDE_TABLE_COLUMNS,
+};
+use delta_bench::runner::CaseHooks;
+use delta_bench::storage::StorageConfig;
+use delta_bench::suites::wide_table;
+
+#[test]
+fn wide_table_batch_has_requested_width() {
+    let batch = wide_table_batch(0..16, 12).expect("wide batch should build");
+    assert_eq!(batch.num_columns(), 12);
+    assert_eq!(batch.num_rows(), 16);
+    let schema = batch.schema();
+    assert_eq!(schema.field(0).name(), "id");
+    assert_eq!(schema.field(11).name(), "c011");
+}
+
+#[tokio::test]
+async fn wide_table_suite_scans_and_appends_default_width() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    generate_fixtures(temp.path(), "sf1", 42, true, &storage)
+        .await
+        .expect("fixtures should be generated");
+    assert_eq!(
+        fixture_wide_table_columns(temp.path(), "sf1").expect("manifest should record width"),
+        DEFAULT_WIDE_TABLE_COLUMNS
+    );
+
+    let cases = wide_table::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("wide_table suite should run");
+
+    assert_eq!(
+        cases
+            .iter()
+            .map(|case| case.case.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "wide_table_full_scan",
+            "wide_table_projection_3col",
+            "wide_table_append",
+        ]
+    );
+    for case in &cases {
+        assert!(case.success, "{} failure: {:?}", case.case, case.failure);
+    }
+    let rows = |idx: usize| {
+        cases[idx]
+            .samples
+            .first()
+            .and_then(|sample| sample.metrics.as_ref())
+            .and_then(|metrics| metrics.rows_processed)
+    };
+    assert_eq!(rows(0), Some(10_000));
+    assert_eq!(rows(1), Some(10_000));
+    assert_eq!(rows(2), Some(4_096));
+}
+
+#[tokio::test]
+async fn wide_table_suite_reports_fixture_error_without_table() {
+    let temp = tempfile::tempdir().expect("tempdir should be created");
+    let storage = StorageConfig::local();
+
+    let cases = wide_table::run(temp.path(), "sf1", 0, 1, &CaseHooks::default(), &storage)
+        .await
+        .expect("wide_table suite should return fixture errors");
+
+    assert_eq!(cases.len(), 3);
+    assert!(cases.iter().all(|case| !case.success));
+}
@@ -202,6 +202,13 @@ def _aggregate_payloads_v5(
         out_cases.append(merged)
 
     first["cases"] = out_cases
+    warnings: list[dict[str, Any]] = []
+    for payload in payloads:
+        for warning in payload.get("warnings") or []:
+            if warning not in warnings:
+                warnings.append(warning)
+    if warnings:
+        first["warnings"] = warnings
     return first
 
 
@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+from .registry import repo_root
+
+try:
+    import yaml
+except ImportError:  # pragma: no cover - PyYAML is expected in benchmark environments
+    yaml = None  # type: ignore[assignment]
+
+DEFAULT_MANIFEST_PATHS = (
+    repo_root() / "bench" / "manifests" / "core_rust.yaml",
+    repo_root() / "bench" / "manifests" / "core_python.yaml",
+)
+
+
+def load_case_aliases(paths: tuple[Path, ...] | None = None) -> dict[str, str]:
+    """Merge the `aliases` maps (retired case id -> current case id) of the manifests."""
+    if yaml is None:  # pragma: no cover - covered only when dependency missing
+        raise RuntimeError(
+            "PyYAML is required to load manifest case aliases; install 'PyYAML' in the benchmark Python environment"
+        )
+    aliases: dict[str, str] = {}
+    for path in paths or DEFAULT_MANIFEST_PATHS:
+        if not path.exists():
+            continue
+        _merge_manifest_aliases(path, aliases, seen=set())
+    return aliases
+
+
+def _merge_manifest_aliases(
+    path: Path, aliases: dict[str, str], *, seen: set[Path]
+) -> None:
+    """Merge `path`'s aliases after those of the manifests it `includes`."""
+    resolved = path.resolve()
+    if resolved in seen:
+        return
+    seen.add(resolved)
+    payload = yaml.safe_load(path.read_text(encoding="utf-8")) or {}
+    for include in payload.get("includes") or []:
+        include_path = Path(include)
+        if not include_path.is_absolute():
+            include_path = repo_root() / include_path
+        if not include_path.exists():
+            raise ValueError(f"{path}: include '{include}' does not exist")
+        _merge_manifest_aliases(include_path, aliases, seen=seen)
+    raw = payload.get("aliases") or {}
+    if not isinstance(raw, dict):
+        raise ValueError(f"{path}: aliases must be a mapping")
+    for old_id, new_id in raw.items():
+        if not isinstance(old_id, str) or not isinstance(new_id, str):
+            raise ValueError(f"{path}: alias entries must map case id strings")
+        existing = aliases.get(old_id)
+        if existing is not None and existing != new_id:
+            raise ValueError(
+                f"{path}: alias '{old_id}' maps to both '{existing}' and '{new_id}'"
+            )
+        aliases[old_id] = new_id
+
+
+def canonical_case_name(name: str, aliases: dict[str, str] | None) -> str:
+    if not aliases:
+        return name
+    return aliases.get(name, name)
@@ -8,6 +8,7 @@ import statistics
 import sys
 from pathlib import Path
 
+from .case_aliases import canonical_case_name, load_case_aliases
 from .formatting import (
     render_markdown as render_markdown_output,
 )
@@ -23,6 +24,14 @@ from .model import (
     SampleMetricSnapshot,
     Summary,
 )
+from .notify import (
+    VALID_NOTIFY_FORMATS,
+    build_gate_failure_payload,
+    post_webhook,
+    render_slack_payload,
+    validate_webhook_url,
+)
+from .samples import render_sample_diff
 from .schema import (
     case_classification,
     case_perf_status,
@@ -347,6 +356,21 @@ def _is_out_of_scope_micro_only_row(row: ComparisonRow) -> bool:
     )
 
 
+def _cases_by_canonical_name(
+    payload: dict, side: str, case_aliases: dict[str, str] | None
+) -> dict[str, dict]:
+    cases: dict[str, dict] = {}
+    for case in payload.get("cases", []):
+        name = canonical_case_name(case["case"], case_aliases)
+        if name in cases:
+            raise ValueError(
+                f"{side} has both '{cases[name]['case']}' and '{case['case']}' for case '{name}'; "
+                "a retired alias and its current id cannot appear in the same run"
+            )
+        cases[name] = case
+    return cases
+
+
 def compare_runs(
     baseline: dict,
     candidate: dict,
@@ -356,6 +380,7 @@ def compare_runs(
     spread_metric: str | None = None,
     sub_ms_threshold_ms: float | None = None,
     sub_ms_policy: str | None = None,
+    case_aliases: dict[str, str] | None = None,
 ) -> Comparison:
     if mode not in VALID_COMPARE_MODES:
         raise ValueError(
@@ -380,8 +405,8 @@ def compare_runs(
 
     ensure_matching_contexts(baseline, candidate)
 
-    baseline_cases = {c["case"]: c for c in baseline.get("cases", [])}
-    candidate_cases = {c["case"]: c for c in candidate.get("cases", [])}
+    baseline_cases = _cases_by_canonical_name(baseline, "baseline", case_aliases)
+    candidate_cases = _cases_by_canonical_name(candidate, "candidate", case_aliases)
     invalid_cases = invalid_perf_case_names((baseline, candidate))
     if invalid_cases and mode == "decision":
         raise ValueError(
@@ -673,7 +698,18 @@ def compare_runs(
         new=new,
         removed=removed,
     )
-    return Comparison(rows=rows, summary=summary)
+    return Comparison(
+        rows=rows, summary=summary, ref_issues=_ref_issues(baseline, candidate)
+    )
+
+
+def _ref_issues(*payloads: dict) -> list[str]:
+    refs: list[str] = []
+    for payload in payloads:
+        for ref in (payload.get("context") or {}).get("ref_issues") or []:
+            if str(ref) not in refs:
+                refs.append(str(ref))
+    return refs
 
 
 def _load(path: Path) -> dict:
@@ -688,6 +724,27 @@ def render_markdown(comparison: Comparison, include_metrics: bool = False) -> st
     return render_markdown_output(comparison, include_metrics=include_metrics)
 
 
+def render_samples(
+    baseline: dict,
+    candidate: dict,
+    case: str,
+    *,
+    case_aliases: dict[str, str] | None = None,
+    markdown: bool = False,
+) -> str:
+    name = canonical_case_name(case, case_aliases)
+    baseline_cases = _cases_by_canonical_name(baseline, "baseline", case_aliases)
+    candidate_cases = _cases_by_canonical_name(candidate, "candidate", case_aliases)
+    baseline_case = baseline_cases.get(name)
+    candidate_case = candidate_cases.get(name)
+    if baseline_case is None and candidate_case is None:
+        raise ValueError(f"case '{case}' is in neither the baseline nor the candidate")
+    output = render_sample_diff(name, baseline_case, candidate_case)
+    if markdown:
+        return f"### Samples: `{name}`\n\n```text\n{output}\n```"
+    return output
+
+
 def build_json_payload(
     comparison: Comparison,
     *,
@@ -788,11 +845,32 @@ def main() -> None:
     parser.add_argument("--sub-ms-threshold-ms", type=float)
     parser.add_argument("--sub-ms-policy", choices=sorted(VALID_SUB_MS_POLICIES))
     parser.add_argument("--include-metrics", action="store_true")
+    parser.add_argument(
+        "--samples",
+        metavar="CASE",
+        help="Show per-iteration samples and boxplots for CASE instead of the report",
+    )
+    parser.add_argument(
+        "--no-case-aliases",
+        action="store_true",
+        help="Match cases by raw id instead of resolving manifest aliases",
+    )
     parser.add_argument(
         "--fail-on",
         default="",
         help="Comma-separated comparison statuses that should force exit code 2",
     )
+    parser.add_argument(
+        "--notify",
+        metavar="WEBHOOK_URL",
+        help="POST a regression summary to this webhook when --fail-on fails the gate",
+    )
+    parser.add_argument(
+        "--notify-format",
+        choices=sorted(VALID_NOTIFY_FORMATS),
+        default="json",
+        help="Webhook body: generic JSON (default) or a Slack incoming-webhook message",
+    )
     parser.add_argument(
         "--color",
         choices=["auto", "always", "never"],
@@ -801,6 +879,10 @@ def main() -> None:
     )
     args = parser.parse_args()
     baseline_path, candidate_path = _resolve_input_paths(parser, args)
+    if args.samples is not None and args.format == "json":
+        parser.error("--samples renders text or markdown, not --format json")
+    if args.notify is not None and not args.fail_on:
+        parser.error("--notify requires --fail-on")
 
     if args.color != "auto":
         from .terminal import set_color_mode
@@ -809,20 +891,39 @@ def main() -> None:
 
     try:
         fail_on_statuses = _parse_fail_on(args.fail_on)
+        if args.notify is not None:
+            validate_webhook_url(args.notify)
+        baseline = _load(baseline_path)
+        candidate = _load(candidate_path)
+        case_aliases = None if args.no_case_aliases else load_case_aliases()
         comparison = compare_runs(
-            _load(baseline_path),
-            _load(candidate_path),
+            baseline,
+            candidate,
             threshold=args.noise_threshold,
             aggregation=args.aggregation,
             mode=args.mode,
             spread_metric=args.spread_metric,
             sub_ms_threshold_ms=args.sub_ms_threshold_ms,
             sub_ms_policy=args.sub_ms_policy,
+            case_aliases=case_aliases,
+        )
+        samples_output = (
+            None
+            if args.samples is None
+            else render_samples(
+                baseline,
+                candidate,
+                args.samples,
+                case_aliases=case_aliases,
+                markdown=args.format == "markdown",
+            )
         )
     except (ValueError, OSError) as exc:
         print(str(exc), file=sys.stderr)
         raise SystemExit(1) from exc
-    if args.format == "json":
+    if samples_output is not None:
+        output = samples_output
+    elif args.format == "json":
         output = json.dumps(
             build_json_payload(
                 comparison,
@@ -840,9 +941,54 @@ def main() -> None:
     else:
         output = render_text(comparison, include_metrics=args.include_metrics)
     print(output)
-    if any(_matches_fail_on(row, fail_on_statuses) for row in comparison.rows):
+    failing_rows = [
+        row for row in comparison.rows if _matches_fail_on(row, fail_on_statuses)
+    ]
+    if failing_rows:
+        if args.notify is not None:
+            _notify_gate_failure(
+                args.notify,
+                args.notify_format,
+                comparison,
+                failing_rows,
+                fail_on_statuses,
+                baseline,
+                candidate,
+            )
         raise SystemExit(2)
 
 
+def _notify_gate_failure(
+    url: str,
+    notify_format: str,
+    comparison: Comparison,
+    failing_rows: list[ComparisonRow],
+    fail_on_statuses: set[str],
+    baseline: dict,
+    candidate: dict,
+) -> None:
+    """A notification that cannot be delivered is reported but never changes the exit code."""
+    payload = build_gate_failure_payload(
+        comparison,
+        failing_rows,
+        fail_on=fail_on_statuses,
+        baseline_label=_context_label(baseline),
+        candidate_label=_context_label(candidate),
+    )
+    body = render_slack_payload(payload) if notify_format == "slack" else payload
+    try:
+        post_webhook(url, body)
+    except OSError as exc:
+        print(f"warning: {exc}", file=sys.stderr)
+
+
+def _context_label(payload: dict) -> str | None:
+    context = payload.get("context")
+    if not isinstance(context, dict):
+        return None
+    label = context.get("label")
+    return label if isinstance(label, str) else None
+
+
 if __name__ == "__main__":
     main()
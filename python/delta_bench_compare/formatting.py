@@ -376,6 +376,8 @@ def render_text_report(comparison: Comparison, include_metrics: bool = False) ->
         parts.append(yellow(f"{s.removed} removed"))
 
     lines = [bold("Summary:"), "  " + "  |  ".join(parts)]
+    if comparison.ref_issues:
+        lines.append(dim("  refs: " + ", ".join(comparison.ref_issues)))
 
     for title, rows in _group_rows(comparison):
         if not rows:
@@ -412,6 +414,8 @@ def render_markdown(comparison: Comparison, include_metrics: bool = False) -> st
         f"| new | {s.new} |",
         f"| removed | {s.removed} |",
     ]
+    if comparison.ref_issues:
+        lines.extend(["", "Refs: " + ", ".join(comparison.ref_issues)])
 
     for title, rows in _group_rows(comparison):
         if not rows:
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 
 VALID_COMPARISON_STATUSES = frozenset(
     {
@@ -119,9 +119,14 @@ class Summary:
 class Comparison:
     rows: list[ComparisonRow]
     summary: Summary
+    # Upstream issues or PRs either run was produced to validate (`OWNER/REPO#NUMBER`).
+    ref_issues: list[str] = field(default_factory=list)
 
     def to_json_dict(self) -> dict[str, object]:
-        return {
+        payload: dict[str, object] = {
             "summary": self.summary.to_json_dict(),
             "rows": [row.to_json_dict() for row in self.rows],
         }
+        if self.ref_issues:
+            payload["ref_issues"] = list(self.ref_issues)
+        return payload
@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import json
+import urllib.error
+import urllib.request
+from urllib.parse import urlparse
+
+from .model import Comparison, ComparisonRow
+
+VALID_NOTIFY_FORMATS = {"json", "slack"}
+NOTIFY_TIMEOUT_SECONDS = 10.0
+# Slack truncates long messages; the JSON payload always lists every failing case.
+SLACK_MAX_CASES = 20
+
+
+def validate_webhook_url(url: str) -> str:
+    parsed = urlparse(url)
+    if parsed.scheme not in {"http", "https"} or not parsed.netloc:
+        raise ValueError(f"--notify expects an http(s) webhook URL, got '{url}'")
+    return url
+
+
+def build_gate_failure_payload(
+    comparison: Comparison,
+    failing_rows: list[ComparisonRow],
+    *,
+    fail_on: set[str],
+    baseline_label: str | None,
+    candidate_label: str | None,
+) -> dict[str, object]:
+    """Generic JSON body posted when the `--fail-on` gate fails."""
+    payload: dict[str, object] = {
+        "event": "delta_bench_gate_failed",
+        "baseline": baseline_label,
+        "candidate": candidate_label,
+        "fail_on": sorted(fail_on),
+        "summary": comparison.summary.to_json_dict(),
+        "failing_cases": [
+            {
+                "case": row.case,
+                "status": row.status,
+                "baseline_ms": row.baseline_ms,
+                "candidate_ms": row.candidate_ms,
+                "delta_pct": row.delta_pct(),
+            }
+            for row in failing_rows
+        ],
+    }
+    if comparison.ref_issues:
+        payload["ref_issues"] = list(comparison.ref_issues)
+    return payload
+
+
+def render_slack_payload(payload: dict[str, object]) -> dict[str, object]:
+    """Slack incoming-webhook body (`{"text": ...}`) for a gate failure payload."""
+    cases = list(payload["failing_cases"])
+    fail_on = list(payload["fail_on"])
+    baseline = payload.get("baseline") or "baseline"
+    candidate = payload.get("candidate") or "candidate"
+    lines = [
+        f":rotating_light: delta-bench gate failed: {len(cases)} case(s) matched "
+        f"--fail-on {','.join(fail_on)} ({baseline} -> {candidate})"
+    ]
+    for case in cases[:SLACK_MAX_CASES]:
+        lines.append(f"- `{case['case']}`: {_describe_case(case)}")
+    if len(cases) > SLACK_MAX_CASES:
+        lines.append(f"...and {len(cases) - SLACK_MAX_CASES} more")
+    return {"text": "\n".join(lines)}
+
+
+def _describe_case(case: dict[str, object]) -> str:
+    status = str(case["status"])
+    baseline_ms = case.get("baseline_ms")
+    candidate_ms = case.get("candidate_ms")
+    delta_pct = case.get("delta_pct")
+    if isinstance(delta_pct, float):
+        status += f" {delta_pct:+.2f}%"
+    if isinstance(baseline_ms, float) and isinstance(candidate_ms, float):
+        status += f" ({baseline_ms:.2f} ms -> {candidate_ms:.2f} ms)"
+    return status
+
+
+def post_webhook(
+    url: str,
+    body: dict[str, object],
+    *,
+    timeout: float = NOTIFY_TIMEOUT_SECONDS,
+) -> None:
+    request = urllib.request.Request(
+        url,
+        data=json.dumps(body).encode("utf-8"),
+        headers={"Content-Type": "application/json"},
+        method="POST",
+    )
+    try:
+        with urllib.request.urlopen(request, timeout=timeout) as response:
+            status = response.status
+    except (urllib.error.URLError, OSError) as exc:
+        raise OSError(f"failed to post gate notification: {exc}") from exc
+    if not 200 <= status < 300:
+        raise OSError(f"gate notification webhook returned HTTP {status}")
@@ -0,0 +1,212 @@
+from __future__ import annotations
+
+import statistics
+from dataclasses import dataclass
+
+BOXPLOT_WIDTH = 60
+# Tukey fences: samples further than this many IQRs outside the box are outliers.
+OUTLIER_IQR_FACTOR = 1.5
+_PLOT_LABEL_WIDTH = len("candidate  ")
+
+
+@dataclass(frozen=True)
+class SampleDistribution:
+    count: int
+    min_ms: float
+    q1_ms: float
+    median_ms: float
+    q3_ms: float
+    max_ms: float
+    low_whisker_ms: float
+    high_whisker_ms: float
+    outliers_ms: tuple[float, ...]
+
+    def inliers(self, values: list[float]) -> list[float]:
+        return [
+            value
+            for value in values
+            if self.low_whisker_ms <= value <= self.high_whisker_ms
+        ]
+
+
+def sample_elapsed_ms(case: dict | None) -> list[float]:
+    """Per-iteration `elapsed_ms` values of `case`, in recorded order."""
+    if case is None:
+        return []
+    return [
+        float(sample["elapsed_ms"])
+        for sample in case.get("samples") or []
+        if sample.get("elapsed_ms") is not None
+    ]
+
+
+def summarize_samples(values: list[float]) -> SampleDistribution | None:
+    if not values:
+        return None
+    ordered = sorted(values)
+    if len(ordered) == 1:
+        q1 = median = q3 = ordered[0]
+    else:
+        q1, median, q3 = statistics.quantiles(ordered, n=4, method="inclusive")
+    low_fence = q1 - OUTLIER_IQR_FACTOR * (q3 - q1)
+    high_fence = q3 + OUTLIER_IQR_FACTOR * (q3 - q1)
+    inliers = [value for value in ordered if low_fence <= value <= high_fence]
+    return SampleDistribution(
+        count=len(ordered),
+        min_ms=ordered[0],
+        q1_ms=q1,
+        median_ms=median,
+        q3_ms=q3,
+        max_ms=ordered[-1],
+        low_whisker_ms=inliers[0],
+        high_whisker_ms=inliers[-1],
+        outliers_ms=tuple(
+            value for value in ordered if value < low_fence or value > high_fence
+        ),
+    )
+
+
+def _fmt_ms(value: float | None) -> str:
+    return "-" if value is None else f"{value:.2f}"
+
+
+def _fmt_delta_pct(baseline_ms: float | None, candidate_ms: float | None) -> str:
+    if baseline_ms is None or candidate_ms is None or baseline_ms <= 0.0:
+        return "-"
+    return f"{(candidate_ms - baseline_ms) / baseline_ms * 100.0:+.2f}%"
+
+
+def render_sample_pairs(baseline: list[float], candidate: list[float]) -> list[str]:
+    """One row per iteration index, pairing the nth baseline and candidate samples."""
+    headers = ["#", "baseline (ms)", "candidate (ms)", "delta %"]
+    rows = []
+    for idx in range(max(len(baseline), len(candidate))):
+        baseline_ms = baseline[idx] if idx < len(baseline) else None
+        candidate_ms = candidate[idx] if idx < len(candidate) else None
+        rows.append(
+            [
+                str(idx + 1),
+                _fmt_ms(baseline_ms),
+                _fmt_ms(candidate_ms),
+                _fmt_delta_pct(baseline_ms, candidate_ms),
+            ]
+        )
+    widths = [
+        max(len(header), *(len(row[col]) for row in rows)) if rows else len(header)
+        for col, header in enumerate(headers)
+    ]
+    return [
+        "  ".join(cell.rjust(width) for cell, width in zip(line, widths))
+        for line in [headers, *rows]
+    ]
+
+
+def _boxplot_line(
+    distribution: SampleDistribution, low_ms: float, high_ms: float, width: int
+) -> str:
+    span = high_ms - low_ms
+
+    def column(value: float) -> int:
+        if span <= 0.0:
+            return 0
+        return round((value - low_ms) / span * (width - 1))
+
+    low_whisker = column(distribution.low_whisker_ms)
+    high_whisker = column(distribution.high_whisker_ms)
+    q1 = column(distribution.q1_ms)
+    q3 = column(distribution.q3_ms)
+    cells = [" "] * width
+    for col in range(low_whisker, high_whisker + 1):
+        cells[col] = "-"
+    for col in range(q1, q3 + 1):
+        cells[col] = "="
+    cells[low_whisker] = "|"
+    cells[high_whisker] = "|"
+    cells[q1] = "["
+    cells[q3] = "]"
+    cells[column(distribution.median_ms)] = "M"
+    for outlier in distribution.outliers_ms:
+        cells[column(outlier)] = "o"
+    return "".join(cells).rstrip()
+
+
+def render_boxplots(
+    baseline: SampleDistribution | None,
+    candidate: SampleDistribution | None,
+    width: int = BOXPLOT_WIDTH,
+) -> list[str]:
+    """Boxplots of both sides on one shared axis, so positions compare directly."""
+    present = [dist for dist in (baseline, candidate) if dist is not None]
+    if not present:
+        return []
+    low_ms = min(dist.min_ms for dist in present)
+    high_ms = max(dist.max_ms for dist in present)
+    lines = []
+    for label, dist in (("baseline", baseline), ("candidate", candidate)):
+        plot = (
+            "(no samples)"
+            if dist is None
+            else _boxplot_line(dist, low_ms, high_ms, width)
+        )
+        lines.append(f"{label:<{_PLOT_LABEL_WIDTH}}{plot}")
+    low_label = f"{low_ms:.2f} ms"
+    high_label = f"{high_ms:.2f} ms"
+    padding = max(1, width - len(low_label) - len(high_label))
+    lines.append(" " * _PLOT_LABEL_WIDTH + low_label + " " * padding + high_label)
+    return lines
+
+
+def _summary_line(label: str, dist: SampleDistribution | None) -> str:
+    if dist is None:
+        return f"{label}: no samples"
+    return (
+        f"{label}: n={dist.count} min={dist.min_ms:.2f} q1={dist.q1_ms:.2f} "
+        f"median={dist.median_ms:.2f} q3={dist.q3_ms:.2f} max={dist.max_ms:.2f} "
+        f"outliers={len(dist.outliers_ms)}"
+    )
+
+
+def render_sample_diff(
+    name: str,
+    baseline_case: dict | None,
+    candidate_case: dict | None,
+    *,
+    width: int = BOXPLOT_WIDTH,
+) -> str:
+    """Raw-sample view of one case for `compare --samples`.
+
+    Pairs the iterations, draws both distributions on a shared axis, and reports the
+    median shift with and without outliers, so a shift carried by one outlier sample
+    can be told apart from a change in the whole distribution.
+    """
+    baseline = sample_elapsed_ms(baseline_case)
+    candidate = sample_elapsed_ms(candidate_case)
+    baseline_dist = summarize_samples(baseline)
+    candidate_dist = summarize_samples(candidate)
+
+    lines = [
+        f"Samples for {name}: {len(baseline)} baseline, {len(candidate)} candidate",
+        "",
+        *render_sample_pairs(baseline, candidate),
+        "",
+        *render_boxplots(baseline_dist, candidate_dist, width=width),
+        "  [=] interquartile range, M median, |-| whiskers within "
+        f"{OUTLIER_IQR_FACTOR} IQR, o outlier",
+        "",
+        _summary_line("baseline", baseline_dist),
+        _summary_line("candidate", candidate_dist),
+    ]
+    if baseline_dist is not None and candidate_dist is not None:
+        lines.append(
+            "median shift: "
+            + _fmt_delta_pct(baseline_dist.median_ms, candidate_dist.median_ms)
+        )
+        if baseline_dist.outliers_ms or candidate_dist.outliers_ms:
+            lines.append(
+                "median shift without outliers: "
+                + _fmt_delta_pct(
+                    statistics.median(baseline_dist.inliers(baseline)),
+                    statistics.median(candidate_dist.inliers(candidate)),
+                )
+            )
+    return "\n".join(lines)
@@ -12,16 +12,17 @@ from typing import Any
 def _load_rows(
     fixtures_dir: Path, scale: str, limit: int = 5000
 ) -> list[dict[str, Any]]:
-    path = fixtures_dir / scale / "narrow_sales" / "rows.jsonl"
+    path = fixtures_dir / scale / "narrow_sales" / "rows.parquet"
+    try:
+        import pyarrow.parquet as pq
+    except ImportError as exc:
+        raise SystemExit(f"reading {path} requires pyarrow: {exc}") from exc
     rows: list[dict[str, Any]] = []
-    with path.open("r", encoding="utf-8") as fh:
-        for line in fh:
-            if not line.strip():
-                continue
-            rows.append(json.loads(line))
-            if len(rows) >= limit:
-                break
-    return rows
+    for batch in pq.ParquetFile(path).iter_batches(batch_size=limit):
+        rows.extend(batch.to_pylist())
+        if len(rows) >= limit:
+            break
+    return rows[:limit]
 
 
 def _approx_bytes(rows: list[dict[str, Any]]) -> int:
@@ -0,0 +1,116 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import date, datetime
+from pathlib import Path
+from typing import Any
+
+try:
+    import yaml
+except ImportError:  # pragma: no cover - PyYAML is expected in benchmark environments
+    yaml = None  # type: ignore[assignment]
+
+
+ANNOTATIONS_FILE = "annotations.yaml"
+MIN_SHA_PREFIX_LEN = 7
+
+
+@dataclass(frozen=True)
+class Annotation:
+    """A known environment change pinned to a revision or to the first run on/after a date."""
+
+    note: str
+    date: str | None = None
+    sha: str | None = None
+
+    @property
+    def label(self) -> str:
+        return self.sha[:MIN_SHA_PREFIX_LEN] if self.sha else str(self.date)
+
+
+def default_annotations_path(store_dir: Path | str) -> Path:
+    return Path(store_dir) / ANNOTATIONS_FILE
+
+
+def load_annotations(path: Path | str | None) -> list[Annotation]:
+    """Loads `annotations:` entries; a missing file means no annotations."""
+    if path is None:
+        return []
+    annotations_path = Path(path)
+    if not annotations_path.exists():
+        return []
+    if yaml is None:  # pragma: no cover - covered only when dependency missing
+        raise RuntimeError(
+            "PyYAML is required to load longitudinal annotations; install 'PyYAML' in the benchmark Python environment"
+        )
+    payload = yaml.safe_load(annotations_path.read_text(encoding="utf-8")) or {}
+    if not isinstance(payload, dict):
+        raise ValueError(f"{annotations_path}: annotations file must be a mapping")
+    entries = payload.get("annotations") or []
+    if not isinstance(entries, list):
+        raise ValueError(f"{annotations_path}: annotations must be a list")
+    return [
+        _parse_annotation(annotations_path, idx, entry)
+        for idx, entry in enumerate(entries)
+    ]
+
+
+def _parse_annotation(path: Path, idx: int, entry: Any) -> Annotation:
+    if not isinstance(entry, dict):
+        raise ValueError(f"{path}: annotations[{idx}] must be a mapping")
+    note = entry.get("note")
+    if not isinstance(note, str) or not note.strip():
+        raise ValueError(f"{path}: annotations[{idx}].note must be a non-empty string")
+    raw_date = entry.get("date")
+    raw_sha = entry.get("sha")
+    if (raw_date is None) == (raw_sha is None):
+        raise ValueError(f"{path}: annotations[{idx}] needs exactly one of date or sha")
+    if raw_sha is not None:
+        sha = str(raw_sha).strip().lower()
+        if len(sha) < MIN_SHA_PREFIX_LEN:
+            raise ValueError(
+                f"{path}: annotations[{idx}].sha must be at least {MIN_SHA_PREFIX_LEN} characters"
+            )
+        return Annotation(note=note.strip(), sha=sha)
+    if isinstance(raw_date, datetime):
+        raw_date = raw_date.date()
+    if isinstance(raw_date, date):
+        parsed = raw_date
+    else:
+        try:
+            parsed = date.fromisoformat(str(raw_date))
+        except ValueError as exc:
+            raise ValueError(
+                f"{path}: annotations[{idx}].date must be YYYY-MM-DD"
+            ) from exc
+    return Annotation(note=note.strip(), date=parsed.isoformat())
+
+
+def annotation_marks(
+    ordered_rows: list[dict[str, Any]], annotations: list[Annotation]
+) -> list[dict[str, Any]]:
+    """Places each annotation on the first point of a series it applies to.
+
+    A sha annotation marks the run of that revision. A date annotation marks the first run
+    whose revision timestamp falls on or after that date, since the change holds from then on.
+    """
+    marks: list[dict[str, Any]] = []
+    for annotation in annotations:
+        for idx, row in enumerate(ordered_rows):
+            if _annotation_applies(annotation, row):
+                marks.append(
+                    {"index": idx, "label": annotation.label, "note": annotation.note}
+                )
+                break
+    marks.sort(key=lambda mark: mark["index"])
+    return marks
+
+
+def _annotation_applies(annotation: Annotation, row: dict[str, Any]) -> bool:
+    if annotation.sha is not None:
+        revision = str(row.get("revision") or row.get("git_sha") or "").lower()
+        return bool(revision) and revision.startswith(annotation.sha)
+    point_time = str(
+        row.get("revision_commit_timestamp") or row.get("benchmark_created_at") or ""
+    )
+    return bool(point_time) and point_time[:10] >= str(annotation.date)
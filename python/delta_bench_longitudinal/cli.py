@@ -6,6 +6,9 @@ from dataclasses import asdict, is_dataclass
 from pathlib import Path
 from typing import Any, Callable, Iterable, Union
 
+from delta_bench_compare.case_aliases import load_case_aliases
+
+from .annotations import default_annotations_path, load_annotations
 from .artifacts import (
     ArtifactBuildMetadata,
     artifact_metadata_path,
@@ -54,6 +57,7 @@ def orchestrate_from_manifest(
     build_fn: BuildFn | None = None,
     matrix_executor=None,
     label_prefix: str = "longitudinal",
+    annotations_path: Path | str | None = None,
 ) -> dict[str, int]:
     manifest = load_manifest(manifest_path)
     chosen_build = build_fn or build_revision_artifact
@@ -126,6 +130,10 @@ def orchestrate_from_manifest(
         regression_threshold=regression_threshold,
         significance_method=significance_method,
         significance_alpha=significance_alpha,
+        case_aliases=load_case_aliases(),
+        annotations=load_annotations(
+            annotations_path or default_annotations_path(store_dir)
+        ),
     )
 
     return {
@@ -289,6 +297,11 @@ def main(argv: list[str] | None = None) -> int:
         default="none",
     )
     report_cmd.add_argument("--significance-alpha", type=float, default=0.05)
+    report_cmd.add_argument(
+        "--annotations",
+        type=Path,
+        help="Annotations YAML overlaid on trend lines (default: <store-dir>/annotations.yaml)",
+    )
 
     prune_cmd = sub.add_parser(
         "prune", help="Apply retention policies to artifacts/store"
@@ -334,6 +347,11 @@ def main(argv: list[str] | None = None) -> int:
     )
     orchestration_cmd.add_argument("--significance-alpha", type=float, default=0.05)
     orchestration_cmd.add_argument("--label-prefix", default="longitudinal")
+    orchestration_cmd.add_argument(
+        "--annotations",
+        type=Path,
+        help="Annotations YAML overlaid on trend lines (default: <store-dir>/annotations.yaml)",
+    )
 
     args = parser.parse_args(argv)
     if args.command == "select-revisions":
@@ -415,6 +433,10 @@ def main(argv: list[str] | None = None) -> int:
             regression_threshold=args.regression_threshold,
             significance_method=args.significance_method,
             significance_alpha=args.significance_alpha,
+            case_aliases=load_case_aliases(),
+            annotations=load_annotations(
+                args.annotations or default_annotations_path(args.store_dir)
+            ),
         )
         print(json.dumps(summary, sort_keys=True))
         return 0
@@ -465,6 +487,7 @@ def main(argv: list[str] | None = None) -> int:
             significance_method=args.significance_method,
             significance_alpha=args.significance_alpha,
             label_prefix=args.label_prefix,
+            annotations_path=args.annotations,
         )
         print(json.dumps(summary, sort_keys=True))
         return 0
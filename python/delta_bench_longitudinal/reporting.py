@@ -6,6 +6,9 @@ import statistics
 from pathlib import Path
 from typing import Any
 
+from delta_bench_compare.case_aliases import canonical_case_name
+
+from .annotations import Annotation, annotation_marks
 from .store import load_longitudinal_rows
 
 
@@ -18,6 +21,8 @@ def generate_trend_reports(
     regression_threshold: float,
     significance_method: str,
     significance_alpha: float,
+    case_aliases: dict[str, str] | None = None,
+    annotations: list[Annotation] | None = None,
 ) -> dict[str, int]:
     if baseline_window <= 0:
         raise ValueError("baseline_window must be > 0")
@@ -28,7 +33,7 @@ def generate_trend_reports(
     if not (0.0 < significance_alpha <= 1.0):
         raise ValueError("significance_alpha must be in (0, 1]")
 
-    grouped, invalid_rows = _load_grouped_rows(Path(store_dir))
+    grouped, invalid_rows = _load_grouped_rows(Path(store_dir), case_aliases)
     if not grouped:
         lines = ["# Longitudinal Benchmark Summary", ""]
         if invalid_rows:
@@ -61,6 +66,8 @@ def generate_trend_reports(
             ),
         )
         medians = [float(row["median_ms"]) for row in ordered]
+        marks = annotation_marks(ordered, annotations or [])
+        window_start = max(len(ordered) - (baseline_window + 1), 0)
         latest = medians[-1]
         baseline_rows = ordered[-(baseline_window + 1) : -1]
         baseline_values = [
@@ -138,6 +145,11 @@ def generate_trend_reports(
             "status": status,
             "p_value": p_value,
             "significant": significant,
+            "annotations": marks,
+            # Known changes between the first baseline point and the latest point.
+            "window_annotations": [
+                mark for mark in marks if mark["index"] > window_start
+            ],
         }
         series_stats.append(item)
         if is_regression:
@@ -238,6 +250,7 @@ def _mann_whitney_one_sided_p_value(
 
 def _load_grouped_rows(
     store_dir: Path,
+    case_aliases: dict[str, str] | None = None,
 ) -> tuple[dict[tuple[str, str, str, str], list[dict[str, Any]]], int]:
     rows = load_longitudinal_rows(store_dir)
     if not rows:
@@ -279,7 +292,7 @@ def _load_grouped_rows(
         key = (
             str(row.get("suite", "unknown")),
             str(row.get("scale", "unknown")),
-            str(row.get("case", "unknown")),
+            canonical_case_name(str(row.get("case", "unknown")), case_aliases),
             series_id,
         )
         grouped.setdefault(key, []).append(row)
@@ -348,6 +361,25 @@ def _markdown_summary(
                 )
             )
     lines.append("")
+    annotated = [item for item in regressions if item.get("window_annotations")]
+    if annotated:
+        lines.extend(
+            [
+                "## Known Changes In Regression Windows",
+                "",
+                "These regressions straddle an annotated environment change; check the note before investigating.",
+                "",
+            ]
+        )
+        for item in annotated:
+            notes = "; ".join(
+                f"{mark['note']} ({mark['label']})"
+                for mark in item["window_annotations"]
+            )
+            lines.append(
+                f"- {item['suite']} / {item['scale']} / {item['case']}: {notes}"
+            )
+        lines.append("")
     return "\n".join(lines)
 
 
@@ -377,7 +409,8 @@ def _html_report(
                 f"<p>Status: <strong>{html.escape(item['status'])}</strong></p>"
                 f"<p>Latest: {item['latest']:.2f} ms</p>"
                 f"{p_line}"
-                f"{_sparkline_svg(item['points'])}"
+                f"{_sparkline_svg(item['points'], item.get('annotations') or [])}"
+                f"{_annotation_list_html(item.get('annotations') or [])}"
                 "</section>"
             )
         )
@@ -413,6 +446,7 @@ def _html_report(
     .card {{ background: var(--surface); border-radius: 12px; padding: 16px; box-shadow: 0 4px 18px rgba(20, 90, 141, 0.08); }}
     .meta {{ color: var(--muted); }}
     svg {{ width: 100%; height: 90px; }}
+    .annotations {{ color: var(--warn); font-size: 0.9em; margin: 8px 0 0; padding-left: 18px; }}
   </style>
 </head>
 <body>
@@ -426,7 +460,19 @@ def _html_report(
 """
 
 
-def _sparkline_svg(values: list[float]) -> str:
+def _annotation_list_html(marks: list[dict[str, Any]]) -> str:
+    if not marks:
+        return ""
+    entries = "".join(
+        f"<li>{html.escape(mark['label'])}: {html.escape(mark['note'])}</li>"
+        for mark in marks
+    )
+    return f"<ul class='annotations'>{entries}</ul>"
+
+
+def _sparkline_svg(
+    values: list[float], marks: list[dict[str, Any]] | None = None
+) -> str:
     if not values:
         return "<svg viewBox='0 0 300 90'><text x='4' y='45'>no data</text></svg>"
 
@@ -444,11 +490,22 @@ def _sparkline_svg(values: list[float]) -> str:
         y = height - (normalized * (height - 10.0)) - 5.0
         points.append(f"{x:.2f},{y:.2f}")
 
+    # Annotated points get a dashed vertical rule with the note as a hover title.
+    rules = "".join(
+        "<line x1='{x:.2f}' y1='0' x2='{x:.2f}' y2='90' stroke='#b24020' stroke-width='1' stroke-dasharray='3,3'>"
+        "<title>{title}</title></line>".format(
+            x=mark["index"] * x_step,
+            title=html.escape(f"{mark['label']}: {mark['note']}"),
+        )
+        for mark in marks or []
+    )
+
     return (
         "<svg viewBox='0 0 300 90' role='img' aria-label='trend chart'>"
+        "{rules}"
         "<polyline fill='none' stroke='#145a8d' stroke-width='2.5' points='{points}' />"
         "</svg>"
-    ).format(points=" ".join(points))
+    ).format(rules=rules, points=" ".join(points))
 
 
 def _empty_html(invalid_rows: int = 0) -> str:
@@ -4307,6 +4307,9 @@ def test_sync_harness_to_delta_rs_waits_for_checkout_lock_before_mutating_checko
         )
 
         (temp_root / "bench" / "manifests").mkdir(parents=True)
+        (temp_root / "bench" / "scales.yaml").write_text(
+            "schema_version: 1\n", encoding="utf-8"
+        )
         (temp_root / "backends").mkdir(parents=True)
         (temp_root / "python" / "delta_bench_interop").mkdir(parents=True)
         (temp_root / "python" / "delta_bench_tpcds").mkdir(parents=True)
@@ -7219,6 +7222,17 @@ def test_bench_wrapper_supports_no_summary_table_passthrough() -> None:
     )
 
 
+def test_bench_wrapper_passes_wait_through_to_data_and_run() -> None:
+    script = (REPO_ROOT / "scripts" / "bench.sh").read_text(encoding="utf-8")
+    assert re.search(r"--wait\)\s+wait_for_lock=\"--wait\"\s+shift 1\s+;;", script)
+    assert re.search(r"--wait\)\s+wait_for_lock=1\s+shift 1\s+;;", script)
+    assert re.search(
+        r"if \(\(\s*wait_for_lock != 0\s*\)\); then\s+run_args\+=\(--wait\)",
+        script,
+    )
+    assert re.search(r"--force \| --wait\)\s+archive_args\+=\(\"\$1\"\)", script)
+
+
 def test_bench_wrapper_suppresses_rust_warnings_by_default() -> None:
     script = (REPO_ROOT / "scripts" / "bench.sh").read_text(encoding="utf-8")
     assert re.search(
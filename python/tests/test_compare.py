@@ -1,18 +1,22 @@
 from __future__ import annotations
 
+import http.server
 import json
 import os
 import subprocess
 import sys
+import threading
 from pathlib import Path
 
 import pytest
 from delta_bench_compare.aggregate import aggregate_payloads
+from delta_bench_compare.case_aliases import load_case_aliases
 from delta_bench_compare.compare import (
     _load,
     compare_runs,
     format_change,
 )
+from delta_bench_compare.samples import render_sample_diff, summarize_samples
 
 
 def _run(
@@ -200,6 +204,70 @@ def test_compare_runs_handles_failures_and_missing_cases() -> None:
     assert comparison.summary.new == 1
 
 
+def test_compare_runs_matches_renamed_cases_through_manifest_aliases() -> None:
+    base = _run([{"case": "scan_pruning_hit", "samples": [{"elapsed_ms": 100.0}]}])
+    cand = _run([{"case": "scan_selectivity_1pct", "samples": [{"elapsed_ms": 90.0}]}])
+
+    comparison = compare_runs(
+        base,
+        cand,
+        threshold=0.05,
+        case_aliases={"scan_pruning_hit": "scan_selectivity_1pct"},
+    )
+
+    assert [row.case for row in comparison.rows] == ["scan_selectivity_1pct"]
+    assert comparison.rows[0].status == "improvement"
+    assert comparison.summary.new == 0
+    assert comparison.summary.removed == 0
+
+
+def test_compare_runs_rejects_alias_and_current_id_in_same_run() -> None:
+    base = _run(
+        [
+            {"case": "scan_pruning_hit", "samples": [{"elapsed_ms": 100.0}]},
+            {"case": "scan_selectivity_1pct", "samples": [{"elapsed_ms": 100.0}]},
+        ]
+    )
+    cand = _run([{"case": "scan_selectivity_1pct", "samples": [{"elapsed_ms": 90.0}]}])
+
+    with pytest.raises(ValueError, match="retired alias"):
+        compare_runs(
+            base,
+            cand,
+            threshold=0.05,
+            case_aliases={"scan_pruning_hit": "scan_selectivity_1pct"},
+        )
+
+
+def test_load_case_aliases_rejects_conflicting_manifest_entries(
+    tmp_path: Path,
+) -> None:
+    rust = tmp_path / "core_rust.yaml"
+    python = tmp_path / "core_python.yaml"
+    rust.write_text("aliases:\n  scan_pruning_hit: scan_selectivity_1pct\n")
+    python.write_text("aliases:\n  scan_pruning_hit: scan_selectivity_10pct\n")
+
+    assert load_case_aliases((rust,)) == {"scan_pruning_hit": "scan_selectivity_1pct"}
+    with pytest.raises(ValueError, match="maps to both"):
+        load_case_aliases((rust, python))
+
+
+def test_load_case_aliases_follows_manifest_includes(tmp_path: Path) -> None:
+    common = tmp_path / "common-read.yaml"
+    rust = tmp_path / "core_rust.yaml"
+    common.write_text(
+        f"includes:\n  - {rust}\naliases:\n  scan_pruning_hit: scan_selectivity_1pct\n"
+    )
+    rust.write_text(
+        f"includes:\n  - {common}\naliases:\n  merge_upsert: merge_upsert_10pct\n"
+    )
+
+    assert load_case_aliases((rust,)) == {
+        "scan_pruning_hit": "scan_selectivity_1pct",
+        "merge_upsert": "merge_upsert_10pct",
+    }
+
+
 def test_compare_runs_rejects_invalid_perf_cases() -> None:
     base = _run(
         [
@@ -584,6 +652,25 @@ def test_aggregate_payloads_merges_samples_and_recomputes_elapsed_stats() -> Non
     assert case["elapsed_stats"]["mean_ms"] == pytest.approx(98.75)
 
 
+def test_aggregate_payloads_keeps_every_runs_warnings_once() -> None:
+    cases = [{"case": "scan_case", "success": True, "samples": [{"elapsed_ms": 1.0}]}]
+    fidelity = {"kind": "degraded_fidelity", "message": "cpu steal was 7.5%"}
+    outliers = {
+        "kind": "outliers_detected",
+        "case": "scan_case",
+        "message": "1 of 5 samples fall outside the fences",
+    }
+    run_a = _run(cases)
+    run_b = _run(cases)
+    run_c = _run(cases)
+    run_a["warnings"] = [fidelity]
+    run_b["warnings"] = [fidelity, outliers]
+
+    aggregated = aggregate_payloads([run_a, run_b, run_c], label="merged-run")
+    assert aggregated["warnings"] == [fidelity, outliers]
+    assert "warnings" not in aggregate_payloads([_run(cases)], label="merged-run")
+
+
 def test_aggregate_payloads_preserves_nested_contention_metrics() -> None:
     run_a = _run(
         [
@@ -1390,6 +1477,73 @@ def test_compare_cli_supports_json_output(tmp_path: Path) -> None:
     assert payload["rows"][0]["case"] == "a"
 
 
+def test_render_sample_diff_separates_single_outlier_from_distribution_shift() -> None:
+    baseline = {"samples": [{"elapsed_ms": v} for v in [100, 101, 99, 102, 100, 98]]}
+    candidate = {"samples": [{"elapsed_ms": v} for v in [100, 102, 99, 101, 100, 150]]}
+
+    output = render_sample_diff("scan_case", baseline, candidate, width=40)
+    lines = output.splitlines()
+
+    assert lines[0] == "Samples for scan_case: 6 baseline, 6 candidate"
+    assert "6          98.00          150.00  +53.06%" in output
+    candidate_plot = next(line for line in lines if line.startswith("candidate  "))
+    assert candidate_plot.endswith("o")
+    assert "candidate: n=6 min=99.00" in output
+    assert "outliers=1" in output
+    assert "median shift: +0.50%" in output
+    assert "median shift without outliers: +0.00%" in output
+
+
+def test_summarize_samples_uses_tukey_fences() -> None:
+    summary = summarize_samples([10.0, 11.0, 12.0, 13.0, 40.0])
+
+    assert summary is not None
+    assert (summary.q1_ms, summary.median_ms, summary.q3_ms) == (11.0, 12.0, 13.0)
+    assert summary.high_whisker_ms == 13.0
+    assert summary.outliers_ms == (40.0,)
+    assert summarize_samples([]) is None
+
+
+def test_compare_cli_samples_renders_one_case(tmp_path: Path) -> None:
+    baseline = _run(
+        [
+            {"case": "a", "samples": [{"elapsed_ms": 100.0}, {"elapsed_ms": 102.0}]},
+            {"case": "b", "samples": [{"elapsed_ms": 5.0}]},
+        ]
+    )
+    candidate = _run(
+        [
+            {"case": "a", "samples": [{"elapsed_ms": 90.0}, {"elapsed_ms": 91.0}]},
+            {"case": "b", "samples": [{"elapsed_ms": 5.0}]},
+        ]
+    )
+    baseline_path = tmp_path / "baseline.json"
+    candidate_path = tmp_path / "candidate.json"
+    baseline_path.write_text(json.dumps(baseline), encoding="utf-8")
+    candidate_path.write_text(json.dumps(candidate), encoding="utf-8")
+
+    result = _run_compare_cli(baseline_path, candidate_path, "--samples", "a")
+    assert result.returncode == 0, result.stderr
+    assert result.stdout.startswith("Samples for a: 2 baseline, 2 candidate")
+    assert "-10.00%" in result.stdout
+
+    markdown = _run_compare_cli(
+        baseline_path, candidate_path, "--samples", "a", "--format", "markdown"
+    )
+    assert markdown.returncode == 0, markdown.stderr
+    assert markdown.stdout.startswith("### Samples: `a`\n\n```text\n")
+
+    missing = _run_compare_cli(baseline_path, candidate_path, "--samples", "zzz")
+    assert missing.returncode == 1
+    assert "case 'zzz' is in neither the baseline nor the candidate" in missing.stderr
+
+    as_json = _run_compare_cli(
+        baseline_path, candidate_path, "--samples", "a", "--format", "json"
+    )
+    assert as_json.returncode != 0
+    assert "--samples renders text or markdown" in as_json.stderr
+
+
 def test_compare_cli_json_output_is_versioned_and_machine_readable(
     tmp_path: Path,
 ) -> None:
@@ -1630,6 +1784,119 @@ def test_compare_cli_rejects_unknown_fail_on_status(tmp_path: Path) -> None:
     assert "regression" in result.stderr
 
 
+class _WebhookRecorder(http.server.BaseHTTPRequestHandler):
+    bodies: list[dict] = []
+
+    def do_POST(self) -> None:  # noqa: N802 - http.server handler name
+        length = int(self.headers["Content-Length"])
+        type(self).bodies.append(json.loads(self.rfile.read(length)))
+        self.send_response(204)
+        self.end_headers()
+
+    def log_message(self, *args: object) -> None:
+        pass
+
+
+def _regression_paths(tmp_path: Path) -> tuple[Path, Path]:
+    baseline = _run_v4([{"case": "scan_case", "samples": [{"elapsed_ms": 1.0}]}])
+    candidate = _run_v4([{"case": "scan_case", "samples": [{"elapsed_ms": 1.0}]}])
+    baseline["context"]["label"] = "base"
+    candidate["context"]["label"] = "cand"
+    baseline["cases"][0]["run_summaries"] = [{"median_ms": 100.0}] * 5
+    candidate["cases"][0]["run_summaries"] = [{"median_ms": 120.0}] * 5
+    baseline_path = tmp_path / "baseline.json"
+    candidate_path = tmp_path / "candidate.json"
+    baseline_path.write_text(json.dumps(baseline), encoding="utf-8")
+    candidate_path.write_text(json.dumps(candidate), encoding="utf-8")
+    return baseline_path, candidate_path
+
+
+@pytest.mark.parametrize("notify_format", ["json", "slack"])
+def test_compare_cli_notify_posts_gate_failure_summary(
+    tmp_path: Path, notify_format: str
+) -> None:
+    _WebhookRecorder.bodies = []
+    server = http.server.HTTPServer(("127.0.0.1", 0), _WebhookRecorder)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    try:
+        baseline_path, candidate_path = _regression_paths(tmp_path)
+        result = _run_compare_cli(
+            baseline_path,
+            candidate_path,
+            "--mode",
+            "decision",
+            "--fail-on",
+            "regression",
+            "--notify",
+            f"http://127.0.0.1:{server.server_address[1]}/hook",
+            "--notify-format",
+            notify_format,
+        )
+    finally:
+        server.shutdown()
+        server.server_close()
+
+    assert result.returncode == 2, result.stderr
+    assert len(_WebhookRecorder.bodies) == 1
+    body = _WebhookRecorder.bodies[0]
+    if notify_format == "slack":
+        assert body["text"].startswith(":rotating_light: delta-bench gate failed: 1 case(s)")
+        assert "(base -> cand)" in body["text"]
+        assert "`scan_case`: regression +20.00% (100.00 ms -> 120.00 ms)" in body["text"]
+    else:
+        assert body["event"] == "delta_bench_gate_failed"
+        assert body["baseline"] == "base"
+        assert body["candidate"] == "cand"
+        assert body["fail_on"] == ["regression"]
+        assert body["failing_cases"] == [
+            {
+                "case": "scan_case",
+                "status": "regression",
+                "baseline_ms": 100.0,
+                "candidate_ms": 120.0,
+                "delta_pct": 20.0,
+            }
+        ]
+
+
+def test_compare_cli_notify_failure_keeps_gate_exit_code(tmp_path: Path) -> None:
+    baseline_path, candidate_path = _regression_paths(tmp_path)
+    result = _run_compare_cli(
+        baseline_path,
+        candidate_path,
+        "--mode",
+        "decision",
+        "--fail-on",
+        "regression",
+        "--notify",
+        "http://127.0.0.1:9/unreachable",
+    )
+
+    assert result.returncode == 2
+    assert "warning: failed to post gate notification" in result.stderr
+
+
+def test_compare_cli_notify_requires_fail_on_and_http_url(tmp_path: Path) -> None:
+    baseline_path, candidate_path = _regression_paths(tmp_path)
+    result = _run_compare_cli(
+        baseline_path, candidate_path, "--notify", "https://example.invalid/hook"
+    )
+    assert result.returncode == 2
+    assert "--notify requires --fail-on" in result.stderr
+
+    result = _run_compare_cli(
+        baseline_path,
+        candidate_path,
+        "--fail-on",
+        "regression",
+        "--notify",
+        "file:///tmp/hook",
+    )
+    assert result.returncode == 1
+    assert "--notify expects an http(s) webhook URL" in result.stderr
+
+
 def test_format_change_handles_zero_baseline() -> None:
     assert format_change(0.0, 0.0, 0.05) == "no change"
     assert format_change(0.0, 1.0, 0.05) == "incomparable"
@@ -1647,6 +1914,22 @@ def test_render_markdown_includes_summary_table() -> None:
     assert "| metric | value |" in out
 
 
+def test_render_markdown_lists_ref_issues_from_either_run() -> None:
+    base = _run([{"case": "a", "success": True, "samples": [{"elapsed_ms": 100.0}]}])
+    cand = _run([{"case": "a", "success": True, "samples": [{"elapsed_ms": 90.0}]}])
+    base["context"]["ref_issues"] = ["delta-io/delta-rs#1234"]
+    cand["context"]["ref_issues"] = ["delta-io/delta-rs#1234", "apache/arrow-rs#42"]
+    from delta_bench_compare.compare import render_markdown
+
+    comparison = compare_runs(base, cand, threshold=0.05)
+    assert comparison.ref_issues == ["delta-io/delta-rs#1234", "apache/arrow-rs#42"]
+    assert "Refs: delta-io/delta-rs#1234, apache/arrow-rs#42" in render_markdown(
+        comparison
+    )
+    assert comparison.to_json_dict()["ref_issues"] == comparison.ref_issues
+    assert "ref_issues" not in compare_runs(_run([]), _run([])).to_json_dict()
+
+
 def test_render_text_default_output_does_not_include_metric_columns() -> None:
     base = _run(
         [
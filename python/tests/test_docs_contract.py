@@ -163,13 +163,18 @@ def test_longitudinal_state_path_uses_matrix_state_json_consistently() -> None:
 def test_reference_docs_cover_concurrency_suite_and_contention_metrics() -> None:
     markdown = (DOCS_DIR / "reference.md").read_text(encoding="utf-8")
 
-    assert "### concurrency (5 cases)" in markdown
+    assert "### concurrency (10 cases)" in markdown
     for case_name in (
         "concurrent_table_create",
         "concurrent_append_multi",
+        "concurrent_append_writers_2",
+        "concurrent_append_writers_4",
+        "concurrent_append_writers_8",
         "update_vs_compaction",
         "delete_vs_compaction",
         "optimize_vs_optimize_overlap",
+        "optimize_vs_append_ingest",
+        "mixed_oltp_like",
     ):
         assert f"`{case_name}`" in markdown
 
@@ -193,6 +198,16 @@ def test_reference_docs_cover_concurrency_suite_and_contention_metrics() -> None
         assert f"`{metric_name}`" in markdown
     assert "table_version: null" in markdown
 
+    assert "### Per-worker metrics" in markdown
+    for metric_name in (
+        "worker",
+        "elapsed_ms",
+        "succeeded",
+        "table_version",
+        "commit_retries",
+    ):
+        assert f"`{metric_name}`" in markdown
+
 
 def test_compare_docs_cover_concurrency_guidance() -> None:
     markdown = (DOCS_DIR / "comparing-branches.md").read_text(encoding="utf-8")
@@ -4,6 +4,12 @@ from contextlib import closing
 import json
 from pathlib import Path
 
+import pytest
+from delta_bench_longitudinal.annotations import (
+    Annotation,
+    annotation_marks,
+    load_annotations,
+)
 from delta_bench_longitudinal.reporting import generate_trend_reports
 from delta_bench_longitudinal.store import (
     _connect_store,
@@ -199,6 +205,99 @@ def test_generate_trend_reports_outputs_markdown_and_html(tmp_path: Path) -> Non
     assert "scan_all" in html
 
 
+def test_generate_trend_reports_keeps_renamed_case_on_one_trend_line(
+    tmp_path: Path,
+) -> None:
+    store_dir = tmp_path / "store"
+    rows = _report_rows()
+    rows[0]["case"] = "scan_everything"
+    _seed_rows(store_dir, rows)
+    markdown_path = tmp_path / "summary.md"
+    html_path = tmp_path / "report.html"
+
+    summary = generate_trend_reports(
+        store_dir=store_dir,
+        markdown_path=markdown_path,
+        html_path=html_path,
+        baseline_window=2,
+        regression_threshold=0.05,
+        significance_method="none",
+        significance_alpha=0.05,
+        case_aliases={"scan_everything": "scan_all"},
+    )
+
+    assert summary["total_series"] == 2
+    assert summary["regressions"] == 1
+    assert "scan_everything" not in markdown_path.read_text(encoding="utf-8")
+
+
+def test_generate_trend_reports_overlays_annotations_on_regression_windows(
+    tmp_path: Path,
+) -> None:
+    store_dir = tmp_path / "store"
+    _seed_rows(store_dir, _report_rows())
+    markdown_path = tmp_path / "summary.md"
+    html_path = tmp_path / "report.html"
+
+    summary = generate_trend_reports(
+        store_dir=store_dir,
+        markdown_path=markdown_path,
+        html_path=html_path,
+        baseline_window=2,
+        regression_threshold=0.05,
+        significance_method="none",
+        significance_alpha=0.05,
+        annotations=[Annotation(note="arrow upgrade", date="2026-01-03")],
+    )
+
+    markdown = markdown_path.read_text(encoding="utf-8")
+    html = html_path.read_text(encoding="utf-8")
+    assert summary["regressions"] == 1
+    assert "Known Changes In Regression Windows" in markdown
+    assert "read_scan / sf1 / scan_all: arrow upgrade (2026-01-03)" in markdown
+    assert "stroke-dasharray" in html
+    assert "2026-01-03: arrow upgrade" in html
+
+
+def test_load_annotations_accepts_dates_and_sha_prefixes(tmp_path: Path) -> None:
+    path = tmp_path / "annotations.yaml"
+    path.write_text(
+        "annotations:\n"
+        "  - date: 2026-01-02\n"
+        "    note: new runner hardware\n"
+        "  - sha: ABCDEF1234\n"
+        "    note: arrow upgrade\n",
+        encoding="utf-8",
+    )
+
+    annotations = load_annotations(path)
+    rows = [
+        {"revision": "0000000aaa", "revision_commit_timestamp": "2026-01-01T00:00:00Z"},
+        {"revision": "abcdef1234", "revision_commit_timestamp": "2026-01-02T00:00:00Z"},
+        {"revision": "1111111bbb", "revision_commit_timestamp": "2026-01-03T00:00:00Z"},
+    ]
+
+    assert annotation_marks(rows, annotations) == [
+        {"index": 1, "label": "2026-01-02", "note": "new runner hardware"},
+        {"index": 1, "label": "abcdef1", "note": "arrow upgrade"},
+    ]
+    assert load_annotations(tmp_path / "missing.yaml") == []
+
+
+def test_load_annotations_requires_exactly_one_anchor(tmp_path: Path) -> None:
+    path = tmp_path / "annotations.yaml"
+    path.write_text(
+        "annotations:\n"
+        "  - date: 2026-01-02\n"
+        "    sha: abcdef1234\n"
+        "    note: ambiguous\n",
+        encoding="utf-8",
+    )
+
+    with pytest.raises(ValueError, match="exactly one of date or sha"):
+        load_annotations(path)
+
+
 def test_generate_trend_reports_splits_series_by_compatibility_identity(
     tmp_path: Path,
 ) -> None:
@@ -6,10 +6,11 @@ ROOT_DIR="$(cd "${SCRIPT_DIR}/.." && pwd)"
 DELTA_RS_DIR="${DELTA_RS_DIR:-${ROOT_DIR}/.delta-rs-under-test}"
 DELTA_BENCH_EXEC_ROOT="${DELTA_BENCH_EXEC_ROOT:-${ROOT_DIR}}"
 
-# Keep wrapper-owned paths anchored to the harness root before switching cwd.
+# Keep wrapper-owned paths anchored to the harness root before switching cwd. A leading `~`
+# is left for delta-bench to expand against HOME.
 resolve_harness_path() {
 	local raw_path="$1"
-	if [[ "${raw_path}" == /* ]]; then
+	if [[ "${raw_path}" == /* || "${raw_path}" == "~" || "${raw_path}" == "~/"* ]]; then
 		printf '%s\n' "${raw_path}"
 		return
 	fi
@@ -24,12 +25,16 @@ DELTA_BENCH_SUPPRESS_RUST_WARNINGS="${DELTA_BENCH_SUPPRESS_RUST_WARNINGS:-1}"
 HARNESS_REVISION="${DELTA_BENCH_HARNESS_REVISION:-}"
 
 run_delta_bench() {
+	local cargo_run=(cargo run)
+	if [[ -n "${DELTA_BENCH_CARGO_FEATURES:-}" ]]; then
+		cargo_run+=(--features "${DELTA_BENCH_CARGO_FEATURES}")
+	fi
 	(
 		cd "${DELTA_BENCH_EXEC_ROOT}"
 		if [[ "${DELTA_BENCH_SUPPRESS_RUST_WARNINGS}" == "1" ]]; then
-			RUSTFLAGS="${RUSTFLAGS:-} -Awarnings" cargo run --quiet -p delta-bench -- "$@"
+			RUSTFLAGS="${RUSTFLAGS:-} -Awarnings" "${cargo_run[@]}" --quiet -p delta-bench -- "$@"
 		else
-			cargo run -p delta-bench -- "$@"
+			"${cargo_run[@]}" -p delta-bench -- "$@"
 		fi
 	)
 }
@@ -51,6 +56,44 @@ harness_overlay_is_complete() {
 	done <"${manifest_path}"
 }
 
+# `data export` / `data import`: share one scale's fixtures as a checksummed archive.
+run_data_archive() {
+	local archive_cmd="$1"
+	shift
+	local archive=""
+	local archive_args=()
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--archive)
+			archive="$2"
+			shift 2
+			;;
+		--scale | --dataset-id)
+			archive_args+=("$1" "$2")
+			shift 2
+			;;
+		--force | --trash | --wait)
+			archive_args+=("$1")
+			shift 1
+			;;
+		*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		esac
+	done
+	if [[ -z "${archive}" ]]; then
+		echo "data ${archive_cmd} requires --archive <FILE.tar.zst>" >&2
+		exit 1
+	fi
+	# The archive path is caller-relative; cargo runs from the exec root.
+	if [[ "${archive}" != /* ]]; then
+		archive="${PWD}/${archive}"
+	fi
+	archive_args+=(--archive "${archive}")
+	run_delta_bench --fixtures-dir "${FIXTURES_DIR}" data "${archive_cmd}" "${archive_args[@]}"
+}
+
 ensure_harness_available() {
 	if [[ "${DELTA_BENCH_EXEC_ROOT}" == "${ROOT_DIR}" ]]; then
 		return
@@ -71,36 +114,116 @@ Commands:
   data    Generate benchmark fixtures.
   run     Execute benchmark suites.
   list    List available targets (default: all).
+  plan    Show the planned case set, optionally diffed against a previous run.
+  clean   Remove expired isolated tables from remote storage.
+  trace   Write replay traces generated from suite cases.
+  export  Copy a run result, optionally redacted for public sharing.
+  report  Render a run result as markdown, html, csv, or a PR comment.
+  query   Run SQL over stored run results (tables: runs, cases, samples).
+  smoke   Run one read, write, merge, and metadata case on throwaway fixtures.
   doctor  Validate local benchmark wiring.
 
 Data command options:
   ./scripts/bench.sh data [options]
-    --scale <sf1|sf10|sf100>
-    --dataset-id <tiny_smoke|medium_selective|small_files|many_versions|tpcds_duckdb>
+    --scale <sf1|sf10|sf100> (comma-separated for multiple, e.g. sf1,sf10)
+    --all-scales
+    --dataset-id <tiny_smoke|medium_selective|small_files|many_versions|tpcds_duckdb|late_arriving>
     --seed <N>
     --force
+    --trash
+    --export-rows-jsonl
+    --verify
+    --wait
+    --region-skew <ZIPF_EXPONENT>
+    --id-skew <ZIPF_EXPONENT>
+    --null-probability <COLUMN=PROBABILITY> (repeatable; value_i64 or flag)
+    --wide-table-columns <N> (minimum 4)
+    --small-files-count <N> (small_files dataset)
+    --small-files-rows-per-file <N>
     --storage-backend <local|s3>
     --storage-option <KEY=VALUE> (repeatable)
     --backend-profile <NAME>
+  ./scripts/bench.sh data export --archive <FILE.tar.zst> [--scale <sf1|sf10|sf100>] [--dataset-id <ID>] [--wait]
+  ./scripts/bench.sh data import --archive <FILE.tar.zst> [--force] [--trash] [--wait]
 
 Run command options:
   ./scripts/bench.sh run [options]
     --scale <sf1>
-    --dataset-id <tiny_smoke|medium_selective|small_files|many_versions|tpcds_duckdb>
-    --suite <scan|write|write_perf|delete_update|delete_update_perf|merge|merge_perf|metadata|metadata_perf|optimize_perf|optimize_vacuum|tpcds|interop_py|all>
+    --dataset-id <tiny_smoke|medium_selective|small_files|many_versions|tpcds_duckdb|late_arriving>
+    --suite <scan|scan_concurrency|multi_table|data_skipping|wide_table|nested_types|write|write_perf|delete_update|delete_update_perf|merge|merge_perf|deletion_vectors|metadata|metadata_perf|log_replay|checkpoint|late_arriving|restore|convert_to_delta|optimize_perf|optimize_vacuum|scenario|crash_recovery|snapshot_isolation|replay|tpcds|custom_sql|interop_py|all>
     --case-filter <SUBSTR>
+    --tags <TAG[,TAG...]>
+    --exclude-tags <TAG[,TAG...]>
+    --replay-case <CASE>
+    --seed <N>
+    --fixture-seed <N>
+    --ref-issue <OWNER/REPO#NUMBER> (repeatable)
     --runner <rust|python|all>
     --lane <smoke|correctness|macro>
     --mode <perf|assert>
     --timing-phase <load|plan|execute|validate>
+    --tpcds-phase <N>
+    --tpcds-layout <unpartitioned|date_partitioned>
+    --replay-trace <PATH>
+    --custom-sql-dir <DIR>
+    --load-duration-secs <N>
+    --target-ops-per-sec <R>
+    --fixture-copy <auto|reflink|hardlink|copy>
     --warmup <N>
     --iters <N>
     --no-summary-table
+    --progress
+    --push-gateway <URL>
+    --capture-plan <text|json>
+    --capture-plan-metrics <curated|full>
+    --profile             (builds with the profiling feature)
+    --heap-profile        (builds with the heap-profiling feature)
+    --wait
     --label <L>
+    --label-template <T>  (e.g. '{date}-{git_sha}-{backend}'; replaces --label)
     --storage-backend <local|s3>
     --storage-option <KEY=VALUE> (repeatable)
     --backend-profile <NAME>
 
+Plan command options:
+  ./scripts/bench.sh plan [options]
+    --suite <suite|all>
+    --case-filter <SUBSTR>
+    --tags <TAG[,TAG...]>
+    --exclude-tags <TAG[,TAG...]>
+    --runner <rust|python|all>
+    --against <results/LABEL>
+
+Clean command options:
+  ./scripts/bench.sh clean --remote [options]
+    --retention-hours <N>
+    --dry-run
+    --storage-backend <s3>
+    --storage-option <KEY=VALUE> (repeatable)
+    --backend-profile <NAME>
+
+Trace command options:
+  ./scripts/bench.sh trace --out-dir <DIR> [options]
+    --scale <sf1|sf10|sf100>
+    --case-filter <SUBSTR>
+
+Export command options:
+  ./scripts/bench.sh export --input <RESULT_JSON> --out <FILE> [options]
+    --redact
+
+Report command options:
+  ./scripts/bench.sh report --input <RESULT_JSON> [options]
+    --format <markdown|html|csv|gh-comment|prometheus>
+    --out <FILE>
+
+Query command options:
+  ./scripts/bench.sh query "<SQL>" [options]
+    --out <FILE>       write the rows as Parquet instead of printing them
+
+Smoke command options:
+  ./scripts/bench.sh smoke [options]
+    --work-dir <DIR>   keep the generated fixtures here instead of a temp dir
+
 Other commands:
   ./scripts/bench.sh list [target]
   ./scripts/bench.sh doctor
@@ -127,10 +250,20 @@ ensure_harness_available
 
 case "${cmd}" in
 data)
+	if [[ "${1:-}" == "export" || "${1:-}" == "import" ]]; then
+		run_data_archive "$@"
+		exit 0
+	fi
 	scale="sf1"
 	dataset_id=""
 	seed="42"
 	force=""
+	trash=""
+	export_rows_jsonl=""
+	verify=""
+	wait_for_lock=""
+	all_scales=""
+	fixture_args=()
 	storage_backend="local"
 	storage_options=()
 	while [[ $# -gt 0 ]]; do
@@ -139,6 +272,10 @@ data)
 			scale="$2"
 			shift 2
 			;;
+		--all-scales)
+			all_scales="--all-scales"
+			shift 1
+			;;
 		--dataset-id)
 			dataset_id="$2"
 			shift 2
@@ -151,6 +288,26 @@ data)
 			force="--force"
 			shift 1
 			;;
+		--trash)
+			trash="--trash"
+			shift 1
+			;;
+		--export-rows-jsonl)
+			export_rows_jsonl="--export-rows-jsonl"
+			shift 1
+			;;
+		--verify)
+			verify="--verify"
+			shift 1
+			;;
+		--wait)
+			wait_for_lock="--wait"
+			shift 1
+			;;
+		--region-skew | --id-skew | --null-probability | --wide-table-columns | --small-files-count | --small-files-rows-per-file)
+			fixture_args+=("$1" "$2")
+			shift 2
+			;;
 		--storage-backend)
 			storage_backend="$2"
 			shift 2
@@ -179,7 +336,11 @@ data)
 			storage_args+=(--storage-option "${option}")
 		done
 	fi
-	data_args=(--scale "${scale}" --seed "${seed}")
+	if [[ -n "${all_scales}" ]]; then
+		data_args=("${all_scales}" --seed "${seed}")
+	else
+		data_args=(--scale "${scale}" --seed "${seed}")
+	fi
 	if [[ -n "${dataset_id}" ]]; then
 		data_args+=(--dataset-id "${dataset_id}")
 	fi
@@ -191,6 +352,21 @@ data)
 	if [[ -n "${force}" ]]; then
 		cmd_args+=("${force}")
 	fi
+	if [[ -n "${trash}" ]]; then
+		cmd_args+=("${trash}")
+	fi
+	if [[ -n "${export_rows_jsonl}" ]]; then
+		cmd_args+=("${export_rows_jsonl}")
+	fi
+	if [[ -n "${verify}" ]]; then
+		cmd_args+=("${verify}")
+	fi
+	if [[ -n "${wait_for_lock}" ]]; then
+		cmd_args+=("${wait_for_lock}")
+	fi
+	if [[ ${#fixture_args[@]} -gt 0 ]]; then
+		cmd_args+=("${fixture_args[@]}")
+	fi
 	run_delta_bench "${cmd_args[@]}"
 	;;
 run)
@@ -198,13 +374,34 @@ run)
 	dataset_id=""
 	suite="all"
 	case_filter=""
+	tags=""
+	exclude_tags=""
+	replay_case=""
+	seed=""
+	fixture_seed=""
+	ref_issue_args=()
 	runner="all"
 	lane="smoke"
 	benchmark_mode="perf"
 	timing_phase="execute"
+	tpcds_phase=""
+	tpcds_layout=""
+	replay_trace=""
+	custom_sql_dir=""
+	load_duration_secs=""
+	target_ops_per_sec=""
+	fixture_copy=""
 	warmup="1"
 	iters="5"
 	no_summary_table=0
+	progress=0
+	push_gateway=""
+	capture_plan=""
+	capture_plan_metrics=""
+	profile=0
+	heap_profile=0
+	wait_for_lock=0
+	label_template=""
 	storage_backend="local"
 	storage_options=()
 
@@ -226,6 +423,30 @@ run)
 			case_filter="$2"
 			shift 2
 			;;
+		--tags)
+			tags="$2"
+			shift 2
+			;;
+		--exclude-tags)
+			exclude_tags="$2"
+			shift 2
+			;;
+		--replay-case)
+			replay_case="$2"
+			shift 2
+			;;
+		--seed)
+			seed="$2"
+			shift 2
+			;;
+		--fixture-seed)
+			fixture_seed="$2"
+			shift 2
+			;;
+		--ref-issue)
+			ref_issue_args+=("$1" "$2")
+			shift 2
+			;;
 		--runner)
 			runner="$2"
 			shift 2
@@ -242,6 +463,34 @@ run)
 			timing_phase="$2"
 			shift 2
 			;;
+		--tpcds-phase)
+			tpcds_phase="$2"
+			shift 2
+			;;
+		--tpcds-layout)
+			tpcds_layout="$2"
+			shift 2
+			;;
+		--replay-trace)
+			replay_trace="$2"
+			shift 2
+			;;
+		--custom-sql-dir)
+			custom_sql_dir="$2"
+			shift 2
+			;;
+		--load-duration-secs)
+			load_duration_secs="$2"
+			shift 2
+			;;
+		--target-ops-per-sec)
+			target_ops_per_sec="$2"
+			shift 2
+			;;
+		--fixture-copy)
+			fixture_copy="$2"
+			shift 2
+			;;
 		--warmup)
 			warmup="$2"
 			shift 2
@@ -254,10 +503,42 @@ run)
 			no_summary_table=1
 			shift 1
 			;;
+		--progress)
+			progress=1
+			shift 1
+			;;
+		--push-gateway)
+			push_gateway="$2"
+			shift 2
+			;;
+		--capture-plan)
+			capture_plan="$2"
+			shift 2
+			;;
+		--capture-plan-metrics)
+			capture_plan_metrics="$2"
+			shift 2
+			;;
+		--profile)
+			profile=1
+			shift 1
+			;;
+		--heap-profile)
+			heap_profile=1
+			shift 1
+			;;
+		--wait)
+			wait_for_lock=1
+			shift 1
+			;;
 		--label)
 			LABEL="$2"
 			shift 2
 			;;
+		--label-template)
+			label_template="$2"
+			shift 2
+			;;
 		--storage-backend)
 			storage_backend="$2"
 			shift 2
@@ -282,16 +563,10 @@ run)
 		exit 1
 	fi
 
-	git_sha=""
+	# delta-bench detects both revisions and their dirty state; it reads the harness checkout
+	# from here because it may be built inside a delta-rs checkout.
 	harness_revision="${HARNESS_REVISION}"
-	if [[ -d "${DELTA_BENCH_EXEC_ROOT}/.git" ]]; then
-		git_sha="$(git -C "${DELTA_BENCH_EXEC_ROOT}" rev-parse HEAD 2>/dev/null || true)"
-	elif [[ -d "${DELTA_RS_DIR}/.git" ]]; then
-		git_sha="$(git -C "${DELTA_RS_DIR}" rev-parse HEAD 2>/dev/null || true)"
-	fi
-	if [[ -z "${harness_revision}" && -d "${ROOT_DIR}/.git" ]]; then
-		harness_revision="$(git -C "${ROOT_DIR}" rev-parse HEAD 2>/dev/null || true)"
-	fi
+	export DELTA_BENCH_HARNESS_ROOT="${ROOT_DIR}"
 
 	storage_args=(--storage-backend "${storage_backend}")
 	profile_args=()
@@ -311,15 +586,89 @@ run)
 	if [[ -n "${case_filter}" ]]; then
 		run_args+=(--case-filter "${case_filter}")
 	fi
+	if [[ -n "${tags}" ]]; then
+		run_args+=(--tags "${tags}")
+	fi
+	if [[ -n "${exclude_tags}" ]]; then
+		run_args+=(--exclude-tags "${exclude_tags}")
+	fi
+	if [[ -n "${replay_case}" ]]; then
+		run_args+=(--replay-case "${replay_case}")
+	fi
+	if [[ -n "${seed}" ]]; then
+		run_args+=(--seed "${seed}")
+	fi
+	if [[ -n "${fixture_seed}" ]]; then
+		run_args+=(--fixture-seed "${fixture_seed}")
+	fi
+	if [[ ${#ref_issue_args[@]} -gt 0 ]]; then
+		run_args+=("${ref_issue_args[@]}")
+	fi
+	if [[ -n "${tpcds_phase}" ]]; then
+		run_args+=(--tpcds-phase "${tpcds_phase}")
+	fi
+	if [[ -n "${tpcds_layout}" ]]; then
+		run_args+=(--tpcds-layout "${tpcds_layout}")
+	fi
+	if [[ -n "${replay_trace}" ]]; then
+		# The trace is caller-relative; cargo runs from the exec root.
+		if [[ "${replay_trace}" != /* ]]; then
+			replay_trace="${PWD}/${replay_trace}"
+		fi
+		run_args+=(--replay-trace "${replay_trace}")
+	fi
+	if [[ -n "${custom_sql_dir}" ]]; then
+		# The directory is caller-relative; cargo runs from the exec root.
+		if [[ "${custom_sql_dir}" != /* ]]; then
+			custom_sql_dir="${PWD}/${custom_sql_dir}"
+		fi
+		run_args+=(--custom-sql-dir "${custom_sql_dir}")
+	fi
+	if [[ -n "${load_duration_secs}" || -n "${target_ops_per_sec}" ]]; then
+		if [[ -z "${load_duration_secs}" || -z "${target_ops_per_sec}" ]]; then
+			echo "--load-duration-secs and --target-ops-per-sec must be set together" >&2
+			exit 1
+		fi
+		run_args+=(--load-duration-secs "${load_duration_secs}" --target-ops-per-sec "${target_ops_per_sec}")
+	fi
+	if [[ -n "${fixture_copy}" ]]; then
+		run_args+=(--fixture-copy "${fixture_copy}")
+	fi
 	if ((no_summary_table != 0)); then
 		run_args+=(--no-summary-table)
 	fi
+	if ((progress != 0)); then
+		run_args+=(--progress)
+	fi
+	if [[ -n "${push_gateway}" ]]; then
+		run_args+=(--push-gateway "${push_gateway}")
+	fi
+	if [[ -n "${capture_plan}" ]]; then
+		run_args+=(--capture-plan "${capture_plan}")
+	fi
+	if [[ -n "${capture_plan_metrics}" ]]; then
+		run_args+=(--capture-plan-metrics "${capture_plan_metrics}")
+	fi
+	if ((profile != 0)); then
+		run_args+=(--profile)
+		DELTA_BENCH_CARGO_FEATURES="${DELTA_BENCH_CARGO_FEATURES:+${DELTA_BENCH_CARGO_FEATURES},}profiling"
+	fi
+	if ((heap_profile != 0)); then
+		run_args+=(--heap-profile)
+		DELTA_BENCH_CARGO_FEATURES="${DELTA_BENCH_CARGO_FEATURES:+${DELTA_BENCH_CARGO_FEATURES},}heap-profiling"
+	fi
+	if ((wait_for_lock != 0)); then
+		run_args+=(--wait)
+	fi
+	if [[ -n "${label_template}" ]]; then
+		run_args+=(--label-template "${label_template}")
+	fi
 
 	cmd_args=(
 		--fixtures-dir "${FIXTURES_DIR}"
 		--results-dir "${RESULTS_DIR}"
 		--label "${LABEL}"
-		--git-sha "${git_sha}"
+		--git-sha auto
 		"${storage_args[@]}"
 	)
 	if [[ -n "${harness_revision}" ]]; then
@@ -331,10 +680,304 @@ run)
 	cmd_args+=(run "${run_args[@]}")
 	run_delta_bench "${cmd_args[@]}"
 	;;
+clean)
+	remote=""
+	retention_hours="24"
+	dry_run=""
+	storage_backend="local"
+	storage_options=()
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--remote)
+			remote="--remote"
+			shift 1
+			;;
+		--retention-hours)
+			retention_hours="$2"
+			shift 2
+			;;
+		--dry-run)
+			dry_run="--dry-run"
+			shift 1
+			;;
+		--storage-backend)
+			storage_backend="$2"
+			shift 2
+			;;
+		--storage-option)
+			storage_options+=("$2")
+			shift 2
+			;;
+		--backend-profile)
+			BACKEND_PROFILE="$2"
+			shift 2
+			;;
+		*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		esac
+	done
+	storage_args=(--storage-backend "${storage_backend}")
+	if [[ -n "${BACKEND_PROFILE}" ]]; then
+		storage_args+=(--backend-profile "${BACKEND_PROFILE}")
+	fi
+	if [[ ${#storage_options[@]} -gt 0 ]]; then
+		for option in "${storage_options[@]}"; do
+			storage_args+=(--storage-option "${option}")
+		done
+	fi
+	clean_args=(--retention-hours "${retention_hours}")
+	if [[ -n "${remote}" ]]; then
+		clean_args+=("${remote}")
+	fi
+	if [[ -n "${dry_run}" ]]; then
+		clean_args+=("${dry_run}")
+	fi
+	run_delta_bench "${storage_args[@]}" clean "${clean_args[@]}"
+	;;
 list)
 	target="${1:-all}"
 	run_delta_bench list "${target}"
 	;;
+plan)
+	suite="all"
+	case_filter=""
+	tags=""
+	exclude_tags=""
+	runner=""
+	against=""
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--suite)
+			suite="$2"
+			shift 2
+			;;
+		--case-filter)
+			case_filter="$2"
+			shift 2
+			;;
+		--tags)
+			tags="$2"
+			shift 2
+			;;
+		--exclude-tags)
+			exclude_tags="$2"
+			shift 2
+			;;
+		--runner)
+			runner="$2"
+			shift 2
+			;;
+		--against)
+			against="$2"
+			shift 2
+			;;
+		*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		esac
+	done
+	plan_args=(--target "${suite}")
+	if [[ -n "${case_filter}" ]]; then
+		plan_args+=(--case-filter "${case_filter}")
+	fi
+	if [[ -n "${tags}" ]]; then
+		plan_args+=(--tags "${tags}")
+	fi
+	if [[ -n "${exclude_tags}" ]]; then
+		plan_args+=(--exclude-tags "${exclude_tags}")
+	fi
+	if [[ -n "${runner}" ]]; then
+		plan_args+=(--runner "${runner}")
+	fi
+	if [[ -n "${against}" ]]; then
+		plan_args+=(--against "$(resolve_harness_path "${against}")")
+	fi
+	run_delta_bench plan "${plan_args[@]}"
+	;;
+trace)
+	scale="sf1"
+	case_filter=""
+	out_dir=""
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--scale)
+			scale="$2"
+			shift 2
+			;;
+		--case-filter)
+			case_filter="$2"
+			shift 2
+			;;
+		--out-dir)
+			out_dir="$2"
+			shift 2
+			;;
+		*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		esac
+	done
+	if [[ -z "${out_dir}" ]]; then
+		echo "trace requires --out-dir <DIR>" >&2
+		exit 1
+	fi
+	# The output directory is caller-relative; cargo runs from the exec root.
+	if [[ "${out_dir}" != /* ]]; then
+		out_dir="${PWD}/${out_dir}"
+	fi
+	trace_args=(--scale "${scale}" --out-dir "${out_dir}")
+	if [[ -n "${case_filter}" ]]; then
+		trace_args+=(--case-filter "${case_filter}")
+	fi
+	run_delta_bench trace "${trace_args[@]}"
+	;;
+export)
+	input=""
+	out=""
+	redact=0
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--input)
+			input="$2"
+			shift 2
+			;;
+		--out)
+			out="$2"
+			shift 2
+			;;
+		--redact)
+			redact=1
+			shift 1
+			;;
+		*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		esac
+	done
+	if [[ -z "${input}" || -z "${out}" ]]; then
+		echo "export requires --input <RESULT_JSON> and --out <FILE>" >&2
+		exit 1
+	fi
+	# Both paths are caller-relative; cargo runs from the exec root.
+	if [[ "${input}" != /* ]]; then
+		input="${PWD}/${input}"
+	fi
+	if [[ "${out}" != /* ]]; then
+		out="${PWD}/${out}"
+	fi
+	export_args=(--input "${input}" --out "${out}")
+	if [[ "${redact}" -eq 1 ]]; then
+		export_args+=(--redact)
+	fi
+	run_delta_bench export "${export_args[@]}"
+	;;
+report)
+	input=""
+	format="markdown"
+	out=""
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--input)
+			input="$2"
+			shift 2
+			;;
+		--format)
+			format="$2"
+			shift 2
+			;;
+		--out)
+			out="$2"
+			shift 2
+			;;
+		*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		esac
+	done
+	if [[ -z "${input}" ]]; then
+		echo "report requires --input <RESULT_JSON>" >&2
+		exit 1
+	fi
+	# Both paths are caller-relative; cargo runs from the exec root.
+	if [[ "${input}" != /* ]]; then
+		input="${PWD}/${input}"
+	fi
+	report_args=(--input "${input}" --format "${format}")
+	if [[ -n "${out}" ]]; then
+		if [[ "${out}" != /* ]]; then
+			out="${PWD}/${out}"
+		fi
+		report_args+=(--out "${out}")
+	fi
+	run_delta_bench report "${report_args[@]}"
+	;;
+query)
+	sql=""
+	out=""
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--out)
+			out="$2"
+			shift 2
+			;;
+		-*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		*)
+			if [[ -n "${sql}" ]]; then
+				echo "query takes one SQL argument; quote the statement" >&2
+				exit 1
+			fi
+			sql="$1"
+			shift
+			;;
+		esac
+	done
+	if [[ -z "${sql}" ]]; then
+		echo "query requires a SQL statement" >&2
+		exit 1
+	fi
+	if [[ -z "${out}" ]]; then
+		run_delta_bench --results-dir "${RESULTS_DIR}" query "${sql}"
+		exit 0
+	fi
+	# Caller-relative; cargo runs from the exec root.
+	if [[ "${out}" != /* ]]; then
+		out="${PWD}/${out}"
+	fi
+	run_delta_bench --results-dir "${RESULTS_DIR}" query "${sql}" --out "${out}"
+	;;
+smoke)
+	work_dir=""
+	while [[ $# -gt 0 ]]; do
+		case "$1" in
+		--work-dir)
+			work_dir="$2"
+			shift 2
+			;;
+		*)
+			echo "unknown arg: $1"
+			exit 1
+			;;
+		esac
+	done
+	if [[ -z "${work_dir}" ]]; then
+		run_delta_bench smoke
+		exit 0
+	fi
+	# Caller-relative; cargo runs from the exec root.
+	if [[ "${work_dir}" != /* ]]; then
+		work_dir="${PWD}/${work_dir}"
+	fi
+	run_delta_bench smoke --work-dir "${work_dir}"
+	;;
 doctor)
 	run_delta_bench doctor
 	;;
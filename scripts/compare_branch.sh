@@ -15,6 +15,8 @@ NOISE_THRESHOLD="${BENCH_NOISE_THRESHOLD:-0.05}"
 AGGREGATION="${BENCH_AGGREGATION:-median}"
 COMPARE_MODE="${BENCH_COMPARE_MODE:-exploratory}"
 COMPARE_FAIL_ON="${BENCH_COMPARE_FAIL_ON:-}"
+COMPARE_NOTIFY="${BENCH_COMPARE_NOTIFY:-}"
+COMPARE_NOTIFY_FORMAT="${BENCH_COMPARE_NOTIFY_FORMAT:-json}"
 BENCH_WARMUP="${BENCH_WARMUP:-2}"
 BENCH_ITERS="${BENCH_ITERS:-9}"
 BENCH_PREWARM_ITERS="${BENCH_PREWARM_ITERS:-1}"
@@ -497,6 +499,8 @@ Options:
   --compare-mode <exploratory|decision>
                                   Compare classification mode passed to compare.py (default: ${COMPARE_MODE})
   --fail-on <statuses>            Comma-separated compare statuses that force exit code 2 (for decision automation)
+  --notify <webhook-url>          POST a regression summary to this webhook when --fail-on fails the gate
+  --notify-format <json|slack>    Webhook body for --notify (default: ${COMPARE_NOTIFY_FORMAT})
   --warmup <N>                    Warmup iterations per benchmark case (default: ${BENCH_WARMUP})
   --iters <N>                     Measured iterations per benchmark case (default: ${BENCH_ITERS})
   --prewarm-iters <N>             Unreported prewarm iterations per ref before measured runs (default: ${BENCH_PREWARM_ITERS})
@@ -579,6 +583,14 @@ while [[ $# -gt 0 ]]; do
 		COMPARE_FAIL_ON="$2"
 		shift 2
 		;;
+	--notify)
+		COMPARE_NOTIFY="$2"
+		shift 2
+		;;
+	--notify-format)
+		COMPARE_NOTIFY_FORMAT="$2"
+		shift 2
+		;;
 	--warmup)
 		BENCH_WARMUP="$2"
 		BENCH_WARMUP_EXPLICIT=1
@@ -1225,6 +1237,7 @@ cleanup_harness_overlay_untracked() {
 	local managed_paths=(
 		"crates/delta-bench"
 		"bench/manifests"
+		"bench/scales.yaml"
 		"backends"
 		"python/delta_bench_interop"
 		"python/delta_bench_tpcds"
@@ -1595,6 +1608,9 @@ compare_args=("${compare_common_args[@]}" --format text)
 if [[ -n "${COMPARE_FAIL_ON}" ]]; then
 	compare_args+=(--fail-on "${COMPARE_FAIL_ON}")
 fi
+if [[ -n "${COMPARE_NOTIFY}" ]]; then
+	compare_args+=(--notify "${COMPARE_NOTIFY}" --notify-format "${COMPARE_NOTIFY_FORMAT}")
+fi
 
 compare_render_args=("${compare_common_args[@]}")
 compare_cmd=(env PYTHONPATH="${RUNNER_ROOT}/python" python3 -m delta_bench_compare.compare "${base_json}" "${cand_json}")
@@ -107,6 +107,7 @@ cleanup_harness_overlay_untracked() {
 	local managed_paths=(
 		"crates/delta-bench"
 		"bench/manifests"
+		"bench/scales.yaml"
 		"backends"
 		"python/delta_bench_interop"
 		"python/delta_bench_tpcds"
@@ -8,6 +8,8 @@ SRC_CRATE="${ROOT_DIR}/crates/delta-bench"
 DEST_CRATE="${DELTA_RS_DIR}/crates/delta-bench"
 SRC_BENCH_MANIFESTS="${ROOT_DIR}/bench/manifests"
 DEST_BENCH_MANIFESTS="${DELTA_RS_DIR}/bench/manifests"
+SRC_SCALE_CATALOG="${ROOT_DIR}/bench/scales.yaml"
+DEST_SCALE_CATALOG="${DELTA_RS_DIR}/bench/scales.yaml"
 SRC_BACKEND_PROFILES="${ROOT_DIR}/backends"
 DEST_BACKEND_PROFILES="${DELTA_RS_DIR}/backends"
 SRC_INTEROP_PY="${ROOT_DIR}/python/delta_bench_interop"
@@ -103,6 +105,7 @@ cp "${SRC_CRATE}/Cargo.toml.delta-rs" "${DEST_CRATE}/Cargo.toml"
 
 mkdir -p "${DEST_BENCH_MANIFESTS}"
 rsync -a --delete "${SRC_BENCH_MANIFESTS}/" "${DEST_BENCH_MANIFESTS}/"
+cp "${SRC_SCALE_CATALOG}" "${DEST_SCALE_CATALOG}"
 
 mkdir -p "${DEST_BACKEND_PROFILES}"
 rsync -a --delete "${SRC_BACKEND_PROFILES}/" "${DEST_BACKEND_PROFILES}/"
@@ -124,6 +127,8 @@ rsync -a --delete "${SRC_TPCDS_PY}/" "${DEST_TPCDS_PY}/"
 		printf '%s\n' "${relative_path}"
 	done < <(find "${SRC_BENCH_MANIFESTS}" -type f | LC_ALL=C sort)
 
+	printf '%s\n' "${SRC_SCALE_CATALOG#${ROOT_DIR}/}"
+
 	while IFS= read -r source_path; do
 		relative_path="${source_path#${ROOT_DIR}/}"
 		printf '%s\n' "${relative_path}"
@@ -1059,7 +1059,8 @@ if validation_plan_includes "${VALIDATION_GATE_LABELS}" "delete_update_perf"; th
 			"merge_perf_upsert_10pct" \
 			"${MERGE_PERF_CANARY_CASE}" \
 			"merge_perf_localized_1pct" \
-			"merge_perf_delete_5pct")"
+			"merge_perf_delete_5pct" \
+			"merge_perf_micro_upsert_100x10")"
 		note "${merge_perf_case_presence_status}"
 
 		merge_perf_same_sha_compare_runs="$(compute_regression_canary_compare_runs \
@@ -1100,7 +1101,8 @@ if validation_plan_includes "${VALIDATION_GATE_LABELS}" "delete_update_perf"; th
 			"merge_perf_upsert_10pct" \
 			"${MERGE_PERF_CANARY_CASE}" \
 			"merge_perf_localized_1pct" \
-			"merge_perf_delete_5pct")"
+			"merge_perf_delete_5pct" \
+			"merge_perf_micro_upsert_100x10")"
 		note "${merge_perf_case_presence_status}"
 		merge_perf_regression_baseline_json="$(json_path_for_label "${merge_perf_base_label}" "merge_perf")"
 	fi
@@ -1143,7 +1145,8 @@ if validation_plan_includes "${VALIDATION_GATE_LABELS}" "delete_update_perf"; th
 			"$(json_path_for_label "${optimize_perf_presence_label}" "optimize_perf")" \
 			"${OPTIMIZE_PERF_CANARY_CASE}" \
 			"optimize_perf_noop_already_compact" \
-			"vacuum_perf_execute_lite")"
+			"vacuum_perf_execute_lite" \
+			"optimize_perf_after_dml_cycle")"
 		note "${optimize_perf_case_presence_status}"
 
 		optimize_perf_same_sha_compare_runs="$(compute_regression_canary_compare_runs \
@@ -1183,7 +1186,8 @@ if validation_plan_includes "${VALIDATION_GATE_LABELS}" "delete_update_perf"; th
 			"$(json_path_for_label "${optimize_perf_base_label}" "optimize_perf")" \
 			"${OPTIMIZE_PERF_CANARY_CASE}" \
 			"optimize_perf_noop_already_compact" \
-			"vacuum_perf_execute_lite")"
+			"vacuum_perf_execute_lite" \
+			"optimize_perf_after_dml_cycle")"
 		note "${optimize_perf_case_presence_status}"
 		optimize_perf_regression_baseline_json="$(json_path_for_label "${optimize_perf_base_label}" "optimize_perf")"
 	fi